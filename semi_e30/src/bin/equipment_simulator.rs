@@ -0,0 +1,111 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # EQUIPMENT SIMULATOR
+//! 
+//! Answers a host according to a [Scenario] loaded from a file, so that host
+//! software can be developed without access to the equipment.
+//! 
+//! ```text
+//! equipment-simulator <scenario> <address> [--active] [--once]
+//! ```
+//! 
+//! The simulator listens at the address given, or with `--active` connects
+//! to it, waits to be selected, and runs the [Scenario]. Once the
+//! connection is lost or the [Scenario] ends, the simulator starts over,
+//! unless `--once` is given.
+//! 
+//! See `scenarios/equipment.scenario` for a script with canned behavior for
+//! Streams 1, 2, 5, and 6.
+//! 
+//! [Scenario]: semi_e30::scenario
+
+use std::{
+  env,
+  process::ExitCode,
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+use semi_e30::scenario::{Scenario, ScenarioSettings, Variables};
+use semi_e37::generic::{Client, ConnectionMode, MessageID, ParameterSettings, SelectionState};
+
+const USAGE: &str = "usage: equipment-simulator <scenario> <address> [--active] [--once]";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let active: bool = arguments.iter().any(|argument| argument == "--active");
+  let once: bool = arguments.iter().any(|argument| argument == "--once");
+  let positional: Vec<&String> = arguments.iter().filter(|argument| !argument.starts_with("--")).collect();
+  let [path, address] = positional.as_slice() else {
+    eprintln!("{USAGE}");
+    return ExitCode::FAILURE
+  };
+  // SCENARIO
+  let scenario: Scenario = match Scenario::load(path.as_str()) {
+    Ok(scenario) => scenario,
+    Err(error) => {
+      eprintln!("equipment-simulator: unable to load {path}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  let connect_mode = if active {ConnectionMode::Active} else {ConnectionMode::Passive};
+  let parameter_settings = ParameterSettings {connect_mode, ..Default::default()};
+  let settings = ScenarioSettings::default();
+  loop {
+    // CONNECT
+    let client: Arc<Client> = Client::new(parameter_settings);
+    let (socket, rx_receiver) = match client.connect(address) {
+      Ok(connection) => connection,
+      Err(error) => {
+        eprintln!("equipment-simulator: unable to connect to {address}: {error}");
+        if once {return ExitCode::FAILURE}
+        thread::sleep(Duration::from_secs(1));
+        continue
+      },
+    };
+    eprintln!("equipment-simulator: connected to {socket}");
+    // SELECT
+    if active {
+      let _ = client.select(MessageID {session: 0xFFFF, system: 0}).join();
+    }
+    let deadline: Instant = Instant::now() + parameter_settings.t7;
+    while client.selection_state() != SelectionState::Selected && Instant::now() < deadline {
+      thread::sleep(Duration::from_millis(10));
+    }
+    if client.selection_state() != SelectionState::Selected {
+      eprintln!("equipment-simulator: not selected within T7");
+      let _ = client.disconnect();
+      if once {return ExitCode::FAILURE}
+      continue
+    }
+    // RUN
+    let result = scenario.run(&client, &rx_receiver, &settings, &mut Variables::new());
+    match &result {
+      Ok(()) => eprintln!("equipment-simulator: scenario complete"),
+      Err(error) => eprintln!("equipment-simulator: {error}"),
+    }
+    let _ = client.disconnect();
+    if once {
+      return if result.is_ok() {ExitCode::SUCCESS} else {ExitCode::FAILURE}
+    }
+  }
+}
@@ -0,0 +1,336 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # DATA COLLECTION PLAN
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A [Data Collection Plan] declares the data a host wishes to collect, as
+//! variables reported with collection events and status variables sampled
+//! by traces, without regard to the messages used to arrange it.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Once given to the [Start Data Collection] function of the [Host], the
+//! plan is carried out as follows, verifying the acknowledgement of each
+//! step:
+//! 
+//! - [S2F33] - Deletes every report, then defines one report for each
+//!   collection event.
+//! - [S2F35] - Links each report to its collection event.
+//! - [S2F37] - Enables each collection event.
+//! - [S2F23] - Initializes each trace.
+//! 
+//! From then on, the [S6F11] and [S6F1] messages resulting from the plan
+//! are acknowledged by the [Host] and provided as [Collected Data], with
+//! each value paired with the ID of its variable.
+//! 
+//! [Data Collection Plan]:  DataCollectionPlan
+//! [Collected Data]:        CollectedData
+//! [Host]:                  crate::host::Host
+//! [Start Data Collection]: crate::host::Host::start_data_collection
+//! [S2F23]:                 s2::TraceInitializeSend
+//! [S2F33]:                 s2::DefineReport
+//! [S2F35]:                 s2::LinkEventReport
+//! [S2F37]:                 s2::EnableDisableEventReport
+//! [S6F1]:                  s6::TraceDataSend
+//! [S6F11]:                 s6::EventReport
+
+use std::{
+  sync::mpsc::Receiver,
+  time::Duration,
+};
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    CollectionEventID,
+    DataID,
+    DataSamplePeriod,
+    ReportID,
+    ReportingGroupSize,
+    SampleNumber,
+    SampleTime,
+    StatusVariableID,
+    TotalSamples,
+    TraceInitializeAcknowledgeCode,
+    TraceRequestID,
+    VariableID,
+    VecList,
+  },
+  messages::{s2, s6},
+};
+use crate::host::CollectionEventSetupAcknowledge;
+
+/// ## TRACE PLAN
+/// 
+/// A trace declared by a [Data Collection Plan].
+/// 
+/// [Data Collection Plan]: DataCollectionPlan
+#[derive(Clone, Debug)]
+pub struct TracePlan {
+  /// ### PERIOD
+  /// 
+  /// The time between samples.
+  pub period: Duration,
+
+  /// ### TOTAL SAMPLES
+  /// 
+  /// The number of samples taken before the trace finishes.
+  pub samples: u32,
+
+  /// ### STATUS VARIABLES
+  pub variables: Vec<StatusVariableID>,
+}
+
+/// ## DATA COLLECTION PLAN
+/// 
+/// The variables to be reported with each collection event, and the status
+/// variables to be traced, as described in the [Module Documentation].
+/// 
+/// [Module Documentation]: crate::collection
+#[derive(Clone, Debug, Default)]
+pub struct DataCollectionPlan {
+  events: Vec<(CollectionEventID, Vec<VariableID>)>,
+  traces: Vec<TracePlan>,
+}
+impl DataCollectionPlan {
+  /// ### NEW DATA COLLECTION PLAN
+  /// 
+  /// Creates a plan which collects nothing.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### COLLECT
+  /// 
+  /// Reports the given variables whenever the collection event occurs.
+  pub fn collect(mut self, event: CollectionEventID, variables: impl IntoIterator<Item = VariableID>) -> Self {
+    self.events.push((event, variables.into_iter().collect()));
+    self
+  }
+
+  /// ### TRACE
+  /// 
+  /// Samples the given status variables at the period given, for the number
+  /// of samples given.
+  pub fn trace(mut self, period: Duration, samples: u32, variables: impl IntoIterator<Item = StatusVariableID>) -> Self {
+    self.traces.push(TracePlan {period, samples, variables: variables.into_iter().collect()});
+    self
+  }
+
+  /// ### EVENTS
+  pub fn events(&self) -> &[(CollectionEventID, Vec<VariableID>)] {
+    &self.events
+  }
+
+  /// ### TRACES
+  pub fn traces(&self) -> &[TracePlan] {
+    &self.traces
+  }
+
+  /// ### DEFINE REPORT
+  /// 
+  /// The [S2F33] defining one report for each collection event, numbered
+  /// from 1 in the order the events were declared.
+  /// 
+  /// [S2F33]: s2::DefineReport
+  pub(crate) fn define_report(&self) -> Result<s2::DefineReport, semi_e5::Error> {
+    let mut builder = s2::DefineReport::builder(DataID::U4(0));
+    for (index, (_, variables)) in self.events.iter().enumerate() {
+      builder = builder.report(Self::report_id(index), variables.iter().cloned());
+    }
+    builder.build()
+  }
+
+  /// ### LINK EVENT REPORT
+  /// 
+  /// The [S2F35] linking each report to its collection event.
+  /// 
+  /// [S2F35]: s2::LinkEventReport
+  pub(crate) fn link_event_report(&self) -> Result<s2::LinkEventReport, semi_e5::Error> {
+    let mut builder = s2::LinkEventReport::builder(DataID::U4(0));
+    for (index, (event, _)) in self.events.iter().enumerate() {
+      builder = builder.link(event.clone(), [Self::report_id(index)]);
+    }
+    builder.build()
+  }
+
+  /// ### TRACE INITIALIZE
+  /// 
+  /// The [S2F23] initializing each trace, numbered from 1 in the order the
+  /// traces were declared, or with no samples, cancelling it.
+  /// 
+  /// Fails with [Invalid Form] if a period cannot be represented.
+  /// 
+  /// [S2F23]:        s2::TraceInitializeSend
+  /// [Invalid Form]: semi_e5::Error::InvalidForm
+  pub(crate) fn trace_initialize(&self, cancel: bool) -> Result<Vec<s2::TraceInitializeSend>, semi_e5::Error> {
+    self.traces.iter().enumerate().map(|(index, trace)| {
+      Ok(s2::TraceInitializeSend((
+        Self::trace_id(index),
+        DataSamplePeriod::from_duration(trace.period).ok_or(semi_e5::Error::InvalidForm)?,
+        TotalSamples::U4(if cancel {0} else {trace.samples}),
+        ReportingGroupSize::U4(1),
+        VecList(trace.variables.clone()),
+      )))
+    }).collect()
+  }
+
+  fn report_id(index: usize) -> ReportID {
+    ReportID::U4(index as u32 + 1)
+  }
+
+  fn trace_id(index: usize) -> TraceRequestID {
+    TraceRequestID::U4(index as u32 + 1)
+  }
+
+  /// ### PEEK
+  /// 
+  /// The item at an index of the list forming the text of a [Message],
+  /// borrowed without converting the rest of it.
+  /// 
+  /// [Message]: Message
+  fn peek(message: &Message, index: usize) -> Option<&Item> {
+    match &message.text {
+      Some(Item::List(items)) => items.get(index),
+      _ => None,
+    }
+  }
+
+  /// ### EVENT ID
+  /// 
+  /// The [CEID] reported by an [S6F11], read without converting or copying
+  /// the rest of the [Message].
+  /// 
+  /// [CEID]:    CollectionEventID
+  /// [S6F11]:   s6::EventReport
+  /// [Message]: Message
+  pub(crate) fn event_id(message: &Message) -> Option<CollectionEventID> {
+    if (message.stream, message.function) != (6, 11) {return None}
+    CollectionEventID::try_from(Self::peek(message, 1)?.clone()).ok()
+  }
+
+  /// ### DECODE
+  /// 
+  /// Converts an [S6F11] or [S6F1] resulting from the plan into
+  /// [Collected Data], or results in [None] if it did not.
+  /// 
+  /// The [Message] is only copied to be converted once its [CEID] or [TRID]
+  /// is found to belong to the plan.
+  /// 
+  /// [Message]:        Message
+  /// [CEID]:           CollectionEventID
+  /// [TRID]:           TraceRequestID
+  /// [Collected Data]: CollectedData
+  /// [S6F1]:           s6::TraceDataSend
+  /// [S6F11]:          s6::EventReport
+  pub fn decode(&self, message: &Message) -> Option<CollectedData> {
+    match (message.stream, message.function) {
+      (6, 11) => {
+        let ceid = Self::event_id(message)?;
+        let index = self.events.iter().position(|(event, _)| *event == ceid)?;
+        let report = s6::DecodedEventReport::try_from(message.clone()).ok()?;
+        let (_, values) = report.reports.into_iter().find(|(id, _)| *id == Self::report_id(index))?;
+        Some(CollectedData::Event {
+          event: report.ceid,
+          values: self.events[index].1.iter().cloned().zip(values).collect(),
+        })
+      },
+      (6, 1) => {
+        let trace = TraceRequestID::try_from(Self::peek(message, 0)?.clone()).ok()?;
+        let index = (0..self.traces.len()).find(|index| Self::trace_id(*index) == trace)?;
+        let s6::TraceDataSend((trace, number, time, values), _) = s6::TraceDataSend::try_from(message.clone()).ok()?;
+        Some(CollectedData::Sample {
+          trace,
+          number,
+          time,
+          values: self.traces[index].variables.iter().cloned().zip(values.0.into_iter().map(Item::from)).collect(),
+        })
+      },
+      _ => None,
+    }
+  }
+}
+
+/// ## COLLECTED DATA
+/// 
+/// An event report or trace sample resulting from a
+/// [Data Collection Plan].
+/// 
+/// [Data Collection Plan]: DataCollectionPlan
+#[derive(Clone, Debug)]
+pub enum CollectedData {
+  /// ### EVENT
+  /// 
+  /// A collection event occurred, with the values of its variables.
+  Event {
+    /// ### COLLECTION EVENT ID
+    event: CollectionEventID,
+
+    /// ### VALUES
+    values: Vec<(VariableID, Item)>,
+  },
+
+  /// ### SAMPLE
+  /// 
+  /// A trace was sampled.
+  Sample {
+    /// ### TRACE REQUEST ID
+    trace: TraceRequestID,
+
+    /// ### SAMPLE NUMBER
+    number: SampleNumber,
+
+    /// ### SAMPLE TIME
+    time: SampleTime,
+
+    /// ### VALUES
+    values: Vec<(StatusVariableID, Item)>,
+  },
+}
+
+/// ## DATA COLLECTION START
+/// 
+/// The outcome of the [Start Data Collection] function, naming the step
+/// which the equipment refused, if any.
+/// 
+/// [Start Data Collection]: crate::host::Host::start_data_collection
+#[derive(Debug)]
+pub enum DataCollectionStart {
+  /// ### STARTED
+  /// 
+  /// Every step was accepted, and [Collected Data] is provided by the
+  /// receiver until the plan is stopped or replaced.
+  /// 
+  /// [Collected Data]: CollectedData
+  Started(Receiver<CollectedData>),
+
+  /// ### EVENTS REFUSED
+  /// 
+  /// The equipment refused to set up the collection events.
+  EventsRefused(CollectionEventSetupAcknowledge),
+
+  /// ### TRACE REFUSED
+  /// 
+  /// The equipment refused to initialize the trace.
+  TraceRefused(TraceRequestID, TraceInitializeAcknowledgeCode),
+}
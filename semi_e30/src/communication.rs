@@ -0,0 +1,231 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # COMMUNICATION STATE MODEL
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Tracks whether communication between the equipment and host has been
+//! established at the application level, by way of the
+//! Establish Communications Request ([S1F13]) and its acknowledge
+//! ([S1F14]).
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Communication State] is changed only by the transitions defined
+//! herein, each named after the event which causes it:
+//! 
+//! - [Enable] and [Disable] - The operator enables or disables
+//!   communication.
+//! - [Receive CR] - An [S1F13] is received from the host.
+//! - [Receive CRA] - An [S1F14] is received from the host.
+//! - [CRA Timeout] - No [S1F14] is received in reply to an [S1F13].
+//! - [Delay Expired] - The Establish Communications Timeout elapses.
+//! - [Communication Failure] - The connection to the host is lost.
+//! 
+//! [S1F13]:                 semi_e5::messages::s1::EquipmentCR
+//! [S1F14]:                 semi_e5::messages::s1::HostCRA
+//! [Communication State]:   CommunicationState
+//! [Enable]:                CommunicationState::enable
+//! [Disable]:               CommunicationState::disable
+//! [Receive CR]:            CommunicationState::receive_cr
+//! [Receive CRA]:           CommunicationState::receive_cra
+//! [CRA Timeout]:           CommunicationState::cra_timeout
+//! [Delay Expired]:         CommunicationState::delay_expired
+//! [Communication Failure]: CommunicationState::communication_failure
+
+use semi_e5::items::CommAck;
+
+/// ## COMMUNICATION STATE
+/// 
+/// The state of communication between the equipment and host.
+/// 
+/// The ENABLED state has two substates, NOT COMMUNICATING and
+/// [COMMUNICATING], and the NOT COMMUNICATING state in turn has two
+/// substates, [WAIT CRA] and [WAIT DELAY].
+/// 
+/// [COMMUNICATING]: CommunicationState::Communicating
+/// [WAIT CRA]:      CommunicationState::WaitCRA
+/// [WAIT DELAY]:    CommunicationState::WaitDelay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommunicationState {
+  /// ### DISABLED
+  /// 
+  /// Communication with the host is not permitted, and messages received
+  /// from the host are ignored.
+  Disabled,
+
+  /// ### WAIT CRA
+  /// 
+  /// An [S1F13] has been sent and the equipment is waiting for the host to
+  /// reply with an [S1F14].
+  /// 
+  /// [S1F13]: semi_e5::messages::s1::EquipmentCR
+  /// [S1F14]: semi_e5::messages::s1::HostCRA
+  WaitCRA,
+
+  /// ### WAIT DELAY
+  /// 
+  /// An attempt to establish communication has failed, and the equipment is
+  /// waiting for the Establish Communications Timeout to elapse before
+  /// trying again.
+  WaitDelay,
+
+  /// ### COMMUNICATING
+  /// 
+  /// Communication with the host has been established, and messages may be
+  /// freely exchanged.
+  Communicating,
+}
+impl Default for CommunicationState {
+  /// ### DEFAULT COMMUNICATION STATE
+  /// 
+  /// Provides the [DISABLED] state by default.
+  /// 
+  /// [DISABLED]: CommunicationState::Disabled
+  fn default() -> Self {
+    CommunicationState::Disabled
+  }
+}
+impl CommunicationState {
+  /// ### IS ENABLED
+  pub fn is_enabled(&self) -> bool {
+    *self != CommunicationState::Disabled
+  }
+
+  /// ### IS COMMUNICATING
+  pub fn is_communicating(&self) -> bool {
+    *self == CommunicationState::Communicating
+  }
+
+  /// ### ENABLE
+  /// 
+  /// From the [DISABLED] state, enters the [WAIT CRA] state, after which an
+  /// [S1F13] should be sent.
+  /// 
+  /// Returns whether the transition occurred.
+  /// 
+  /// [DISABLED]: CommunicationState::Disabled
+  /// [WAIT CRA]: CommunicationState::WaitCRA
+  /// [S1F13]:    semi_e5::messages::s1::EquipmentCR
+  pub fn enable(&mut self) -> bool {
+    if *self == CommunicationState::Disabled {
+      *self = CommunicationState::WaitCRA;
+      true
+    } else {
+      false
+    }
+  }
+
+  /// ### DISABLE
+  /// 
+  /// From any state, enters the [DISABLED] state.
+  /// 
+  /// [DISABLED]: CommunicationState::Disabled
+  pub fn disable(&mut self) {
+    *self = CommunicationState::Disabled;
+  }
+
+  /// ### RECEIVE CR
+  /// 
+  /// Upon receiving an [S1F13] from the host while ENABLED, enters the
+  /// [COMMUNICATING] state.
+  /// 
+  /// Returns the [COMMACK] to send in the [S1F14], or [None] if the
+  /// [S1F13] is to be ignored because communication is [DISABLED].
+  /// 
+  /// [S1F13]:         semi_e5::messages::s1::HostCR
+  /// [S1F14]:         semi_e5::messages::s1::EquipmentCRA
+  /// [COMMACK]:       CommAck
+  /// [COMMUNICATING]: CommunicationState::Communicating
+  /// [DISABLED]:      CommunicationState::Disabled
+  pub fn receive_cr(&mut self) -> Option<CommAck> {
+    match self {
+      CommunicationState::Disabled => None,
+      _ => {
+        *self = CommunicationState::Communicating;
+        Some(CommAck::Accepted)
+      },
+    }
+  }
+
+  /// ### RECEIVE CRA
+  /// 
+  /// Upon receiving an [S1F14] from the host in the [WAIT CRA] state, enters
+  /// the [COMMUNICATING] state if the [COMMACK] is accepted, and otherwise
+  /// enters the [WAIT DELAY] state.
+  /// 
+  /// [S1F14]:         semi_e5::messages::s1::HostCRA
+  /// [COMMACK]:       CommAck
+  /// [WAIT CRA]:      CommunicationState::WaitCRA
+  /// [WAIT DELAY]:    CommunicationState::WaitDelay
+  /// [COMMUNICATING]: CommunicationState::Communicating
+  pub fn receive_cra(&mut self, ack: CommAck) {
+    if *self == CommunicationState::WaitCRA {
+      *self = match ack {
+        CommAck::Accepted => CommunicationState::Communicating,
+        CommAck::Denied   => CommunicationState::WaitDelay,
+      }
+    }
+  }
+
+  /// ### CRA TIMEOUT
+  /// 
+  /// Upon failing to receive an [S1F14] in the [WAIT CRA] state, enters the
+  /// [WAIT DELAY] state.
+  /// 
+  /// [S1F14]:      semi_e5::messages::s1::HostCRA
+  /// [WAIT CRA]:   CommunicationState::WaitCRA
+  /// [WAIT DELAY]: CommunicationState::WaitDelay
+  pub fn cra_timeout(&mut self) {
+    if *self == CommunicationState::WaitCRA {
+      *self = CommunicationState::WaitDelay;
+    }
+  }
+
+  /// ### DELAY EXPIRED
+  /// 
+  /// Upon the Establish Communications Timeout elapsing in the [WAIT DELAY]
+  /// state, enters the [WAIT CRA] state, after which an [S1F13] should be
+  /// sent.
+  /// 
+  /// [S1F13]:      semi_e5::messages::s1::EquipmentCR
+  /// [WAIT CRA]:   CommunicationState::WaitCRA
+  /// [WAIT DELAY]: CommunicationState::WaitDelay
+  pub fn delay_expired(&mut self) {
+    if *self == CommunicationState::WaitDelay {
+      *self = CommunicationState::WaitCRA;
+    }
+  }
+
+  /// ### COMMUNICATION FAILURE
+  /// 
+  /// Upon losing the connection to the host while ENABLED, enters the
+  /// [WAIT DELAY] state, such that communication is re-established once the
+  /// Establish Communications Timeout has elapsed.
+  /// 
+  /// [WAIT DELAY]: CommunicationState::WaitDelay
+  pub fn communication_failure(&mut self) {
+    if self.is_enabled() {
+      *self = CommunicationState::WaitDelay;
+    }
+  }
+}
@@ -0,0 +1,352 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # CONTROL STATE MODEL
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Tracks the level of cooperation between the equipment and host, which
+//! determines the messages the equipment will accept from the host.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Control State] is changed only by the transitions of the
+//! [Control State Machine], which are caused either by the operator or by
+//! the host:
+//! 
+//! - [Operator On-Line] and [Operator Off-Line] - The operator switches the
+//!   equipment ON-LINE or OFF-LINE.
+//! - [Operator Local] and [Operator Remote] - The operator switches the
+//!   equipment between LOCAL and REMOTE control.
+//! - [On-Line Succeeded] and [On-Line Failed] - The host does or does not
+//!   reply to the [S1F1] sent when attempting to go ON-LINE.
+//! - [Receive Off-Line Request] - An [S1F15] is received from the host.
+//! - [Receive On-Line Request] - An [S1F17] is received from the host.
+//! 
+//! [S1F1]:                     semi_e5::messages::s1::AreYouThere
+//! [S1F15]:                    semi_e5::messages::s1::RequestOffLine
+//! [S1F17]:                    semi_e5::messages::s1::RequestOnLine
+//! [Control State]:            ControlState
+//! [Control State Machine]:    ControlStateMachine
+//! [Operator On-Line]:         ControlStateMachine::operator_on_line
+//! [Operator Off-Line]:        ControlStateMachine::operator_off_line
+//! [Operator Local]:           ControlStateMachine::operator_local
+//! [Operator Remote]:          ControlStateMachine::operator_remote
+//! [On-Line Succeeded]:        ControlStateMachine::on_line_succeeded
+//! [On-Line Failed]:           ControlStateMachine::on_line_failed
+//! [Receive Off-Line Request]: ControlStateMachine::receive_off_line_request
+//! [Receive On-Line Request]:  ControlStateMachine::receive_on_line_request
+
+use semi_e5::items::{OffLineAcknowledge, OnLineAcknowledge};
+
+/// ## CONTROL STATE
+/// 
+/// The level of cooperation between the equipment and host.
+/// 
+/// The OFF-LINE state has three substates, [EQUIPMENT OFF-LINE],
+/// [ATTEMPT ON-LINE], and [HOST OFF-LINE], and the ON-LINE state has two
+/// substates, [LOCAL] and [REMOTE].
+/// 
+/// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+/// [ATTEMPT ON-LINE]:    ControlState::AttemptOnLine
+/// [HOST OFF-LINE]:      ControlState::HostOffLine
+/// [LOCAL]:              ControlState::OnLineLocal
+/// [REMOTE]:             ControlState::OnLineRemote
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlState {
+  /// ### EQUIPMENT OFF-LINE
+  /// 
+  /// The operator has placed the equipment OFF-LINE, and only the operator
+  /// may bring it back ON-LINE.
+  EquipmentOffLine,
+
+  /// ### ATTEMPT ON-LINE
+  /// 
+  /// The operator has asked the equipment to go ON-LINE, and it has sent an
+  /// [S1F1] to the host, waiting for a reply.
+  /// 
+  /// [S1F1]: semi_e5::messages::s1::AreYouThere
+  AttemptOnLine,
+
+  /// ### HOST OFF-LINE
+  /// 
+  /// The equipment is OFF-LINE, but the host may bring it back ON-LINE with
+  /// an [S1F17].
+  /// 
+  /// [S1F17]: semi_e5::messages::s1::RequestOnLine
+  HostOffLine,
+
+  /// ### ON-LINE LOCAL
+  /// 
+  /// The equipment is ON-LINE, with the operator retaining control of its
+  /// operation.
+  OnLineLocal,
+
+  /// ### ON-LINE REMOTE
+  /// 
+  /// The equipment is ON-LINE, with the host able to control its operation.
+  OnLineRemote,
+}
+impl ControlState {
+  /// ### IS ON-LINE
+  pub fn is_on_line(&self) -> bool {
+    matches!(self, ControlState::OnLineLocal | ControlState::OnLineRemote)
+  }
+
+  /// ### ACCEPTS
+  /// 
+  /// Whether a primary message of the given [Stream] and [Function] from the
+  /// host is to be accepted in this state.
+  /// 
+  /// While OFF-LINE, only [S1F13] and [S1F17] are accepted, and all other
+  /// primary messages are to be answered with an Abort Transaction.
+  /// 
+  /// [Stream]:   semi_e5::Message::stream
+  /// [Function]: semi_e5::Message::function
+  /// [S1F13]:    semi_e5::messages::s1::HostCR
+  /// [S1F17]:    semi_e5::messages::s1::RequestOnLine
+  pub fn accepts(&self, stream: u8, function: u8) -> bool {
+    self.is_on_line() || matches!((stream, function), (1, 13) | (1, 17))
+  }
+}
+
+/// ## CONTROL SETTINGS
+/// 
+/// The configurable behavior of the [Control State Machine].
+/// 
+/// [Control State Machine]: ControlStateMachine
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ControlSettings {
+  /// ### INITIAL STATE
+  /// 
+  /// The [Control State] entered when the equipment is started.
+  /// 
+  /// [Control State]: ControlState
+  pub initial_state: ControlState,
+
+  /// ### ON-LINE FAILED
+  /// 
+  /// The OFF-LINE substate entered when an attempt to go ON-LINE fails,
+  /// which is [HOST OFF-LINE] if true, and otherwise
+  /// [EQUIPMENT OFF-LINE].
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  /// [HOST OFF-LINE]:      ControlState::HostOffLine
+  pub host_off_line_on_failure: bool,
+
+  /// ### REMOTE
+  /// 
+  /// The initial position of the operator's LOCAL/REMOTE switch, which
+  /// determines the ON-LINE substate entered when going ON-LINE.
+  pub remote: bool,
+}
+impl Default for ControlSettings {
+  /// ### DEFAULT CONTROL SETTINGS
+  /// 
+  /// Starts in the [EQUIPMENT OFF-LINE] state, returns to the same state
+  /// upon a failed attempt to go ON-LINE, and goes ON-LINE under [REMOTE]
+  /// control.
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  /// [REMOTE]:             ControlState::OnLineRemote
+  fn default() -> Self {
+    Self {
+      initial_state: ControlState::EquipmentOffLine,
+      host_off_line_on_failure: false,
+      remote: true,
+    }
+  }
+}
+
+/// ## CONTROL STATE MACHINE
+/// 
+/// Holds the [Control State] along with the position of the operator's
+/// LOCAL/REMOTE switch, and performs the transitions between states.
+/// 
+/// [Control State]: ControlState
+#[derive(Clone, Copy, Debug)]
+pub struct ControlStateMachine {
+  settings: ControlSettings,
+  state: ControlState,
+  remote: bool,
+}
+impl ControlStateMachine {
+  /// ### NEW CONTROL STATE MACHINE
+  /// 
+  /// Creates a [Control State Machine] in the initial state provided by the
+  /// [Control Settings].
+  /// 
+  /// [Control State Machine]: ControlStateMachine
+  /// [Control Settings]:      ControlSettings
+  pub fn new(settings: ControlSettings) -> Self {
+    Self {
+      settings,
+      state: settings.initial_state,
+      remote: settings.remote,
+    }
+  }
+
+  /// ### STATE
+  pub fn state(&self) -> ControlState {
+    self.state
+  }
+
+  /// ### ON-LINE STATE
+  /// 
+  /// The ON-LINE substate selected by the operator's LOCAL/REMOTE switch.
+  fn on_line_state(&self) -> ControlState {
+    if self.remote {ControlState::OnLineRemote} else {ControlState::OnLineLocal}
+  }
+
+  /// ### OPERATOR ON-LINE
+  /// 
+  /// From the [EQUIPMENT OFF-LINE] state, enters the [ATTEMPT ON-LINE]
+  /// state, after which an [S1F1] should be sent.
+  /// 
+  /// Returns whether the transition occurred.
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  /// [ATTEMPT ON-LINE]:    ControlState::AttemptOnLine
+  /// [S1F1]:               semi_e5::messages::s1::AreYouThere
+  pub fn operator_on_line(&mut self) -> bool {
+    if self.state == ControlState::EquipmentOffLine {
+      self.state = ControlState::AttemptOnLine;
+      true
+    } else {
+      false
+    }
+  }
+
+  /// ### ON-LINE SUCCEEDED
+  /// 
+  /// Upon receiving a reply to the [S1F1] in the [ATTEMPT ON-LINE] state,
+  /// enters the ON-LINE substate selected by the operator's LOCAL/REMOTE
+  /// switch.
+  /// 
+  /// [ATTEMPT ON-LINE]: ControlState::AttemptOnLine
+  /// [S1F1]:            semi_e5::messages::s1::AreYouThere
+  pub fn on_line_succeeded(&mut self) {
+    if self.state == ControlState::AttemptOnLine {
+      self.state = self.on_line_state();
+    }
+  }
+
+  /// ### ON-LINE FAILED
+  /// 
+  /// Upon failing to receive a reply to the [S1F1] in the [ATTEMPT ON-LINE]
+  /// state, enters the OFF-LINE substate provided by the
+  /// [Control Settings].
+  /// 
+  /// [ATTEMPT ON-LINE]:  ControlState::AttemptOnLine
+  /// [S1F1]:             semi_e5::messages::s1::AreYouThere
+  /// [Control Settings]: ControlSettings::host_off_line_on_failure
+  pub fn on_line_failed(&mut self) {
+    if self.state == ControlState::AttemptOnLine {
+      self.state = if self.settings.host_off_line_on_failure {
+        ControlState::HostOffLine
+      } else {
+        ControlState::EquipmentOffLine
+      };
+    }
+  }
+
+  /// ### OPERATOR OFF-LINE
+  /// 
+  /// From any state, enters the [EQUIPMENT OFF-LINE] state.
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  pub fn operator_off_line(&mut self) {
+    self.state = ControlState::EquipmentOffLine;
+  }
+
+  /// ### OPERATOR LOCAL
+  /// 
+  /// Moves the operator's LOCAL/REMOTE switch to LOCAL, entering the
+  /// [ON-LINE LOCAL] state if ON-LINE.
+  /// 
+  /// [ON-LINE LOCAL]: ControlState::OnLineLocal
+  pub fn operator_local(&mut self) {
+    self.remote = false;
+    if self.state.is_on_line() {
+      self.state = ControlState::OnLineLocal;
+    }
+  }
+
+  /// ### OPERATOR REMOTE
+  /// 
+  /// Moves the operator's LOCAL/REMOTE switch to REMOTE, entering the
+  /// [ON-LINE REMOTE] state if ON-LINE.
+  /// 
+  /// [ON-LINE REMOTE]: ControlState::OnLineRemote
+  pub fn operator_remote(&mut self) {
+    self.remote = true;
+    if self.state.is_on_line() {
+      self.state = ControlState::OnLineRemote;
+    }
+  }
+
+  /// ### RECEIVE OFF-LINE REQUEST
+  /// 
+  /// Upon receiving an [S1F15] from the host while ON-LINE, enters the
+  /// [HOST OFF-LINE] state.
+  /// 
+  /// Returns the [OFLACK] to send in the [S1F16], or [None] if the
+  /// equipment is already OFF-LINE, in which case the [S1F15] is to be
+  /// answered with an Abort Transaction.
+  /// 
+  /// [S1F15]:         semi_e5::messages::s1::RequestOffLine
+  /// [S1F16]:         semi_e5::messages::s1::OffLineAck
+  /// [OFLACK]:        OffLineAcknowledge
+  /// [HOST OFF-LINE]: ControlState::HostOffLine
+  pub fn receive_off_line_request(&mut self) -> Option<OffLineAcknowledge> {
+    if self.state.is_on_line() {
+      self.state = ControlState::HostOffLine;
+      Some(OffLineAcknowledge::Acknowledge)
+    } else {
+      None
+    }
+  }
+
+  /// ### RECEIVE ON-LINE REQUEST
+  /// 
+  /// Upon receiving an [S1F17] from the host in the [HOST OFF-LINE] state,
+  /// enters the ON-LINE substate selected by the operator's LOCAL/REMOTE
+  /// switch.
+  /// 
+  /// Returns the [ONLACK] to send in the [S1F18], which refuses the request
+  /// if the equipment is in any other OFF-LINE substate, and notes that the
+  /// equipment is already ON-LINE if it is.
+  /// 
+  /// [S1F17]:         semi_e5::messages::s1::RequestOnLine
+  /// [S1F18]:         semi_e5::messages::s1::OnLineAck
+  /// [ONLACK]:        OnLineAcknowledge
+  /// [HOST OFF-LINE]: ControlState::HostOffLine
+  pub fn receive_on_line_request(&mut self) -> OnLineAcknowledge {
+    match self.state {
+      ControlState::HostOffLine => {
+        self.state = self.on_line_state();
+        OnLineAcknowledge::Accepted
+      },
+      ControlState::OnLineLocal | ControlState::OnLineRemote => OnLineAcknowledge::AlreadyOnLine,
+      ControlState::EquipmentOffLine | ControlState::AttemptOnLine => OnLineAcknowledge::NotAllowed,
+    }
+  }
+}
@@ -0,0 +1,303 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # DISCOVERY
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Discovery allows the host to learn the variables and collection events
+//! made available by the equipment, so that they may be referred to by name
+//! rather than by ID.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Discover] function of the [Host] builds an [Equipment Model] from:
+//! 
+//! - [S1F11] - The names and units of the status variables.
+//! - [S1F3] - The formats of the status variables, from their values.
+//! - [S1F21] - The names and units of the data variables.
+//! - [S1F23] - The names of the collection events, and the variables they
+//!   may report.
+//! - [S2F29] - The names, units, limits, and defaults of the equipment
+//!   constants.
+//! 
+//! The [Host] performs discovery each time communication is established if
+//! so configured. As the model rarely changes, it may be [Save]d and
+//! [Load]ed so that it is available before then.
+//! 
+//! [Host]:            crate::host::Host
+//! [Discover]:        crate::host::Host::discover
+//! [Equipment Model]: EquipmentModel
+//! [Save]:            EquipmentModel::save
+//! [Load]:            EquipmentModel::load
+//! [S1F3]:            semi_e5::messages::s1::SelectedEquipmentStatusRequest
+//! [S1F11]:           semi_e5::messages::s1::StatusVariableNamelistRequest
+//! [S1F21]:           semi_e5::messages::s1::DataVariableNamelistRequest
+//! [S1F23]:           semi_e5::messages::s1::CollectionEventNamelistRequest
+//! [S2F29]:           semi_e5::messages::s2::EquipmentConstantNamelistRequest
+
+use std::{
+  fs,
+  io::{Error, ErrorKind},
+  path::Path,
+};
+use semi_e5::{
+  Item,
+  format,
+  items::{
+    Char,
+    CollectionEventID,
+    EquipmentConstantID,
+    StatusVariableID,
+    VariableID,
+  },
+};
+
+/// ## VARIABLE DESCRIPTION
+/// 
+/// The description of a status variable or data variable.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDescription {
+  pub name: String,
+  pub units: String,
+
+  /// ### FORMAT
+  /// 
+  /// The [Format] of the variable's value, if known.
+  /// 
+  /// [Format]: semi_e5::format
+  pub format: Option<u8>,
+}
+
+/// ## EVENT DESCRIPTION
+/// 
+/// The description of a collection event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventDescription {
+  pub name: String,
+
+  /// ### VARIABLES
+  /// 
+  /// The variables which may be reported with the collection event.
+  pub variables: Vec<VariableID>,
+}
+
+/// ## CONSTANT DESCRIPTION
+/// 
+/// The description of an equipment constant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstantDescription {
+  pub name: String,
+  pub units: String,
+  pub minimum: Item,
+  pub maximum: Item,
+  pub default: Item,
+}
+impl ConstantDescription {
+  /// ### FORMAT
+  /// 
+  /// The [Format] of the constant's value, taken from its default.
+  /// 
+  /// [Format]: semi_e5::format
+  pub fn format(&self) -> u8 {
+    format_code(&self.default)
+  }
+}
+
+/// ## EQUIPMENT MODEL
+/// 
+/// The variables and collection events made available by the equipment, in
+/// the order the equipment reported them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EquipmentModel {
+  pub status_variables: Vec<(StatusVariableID, VariableDescription)>,
+  pub data_variables: Vec<(VariableID, VariableDescription)>,
+  pub collection_events: Vec<(CollectionEventID, EventDescription)>,
+  pub equipment_constants: Vec<(EquipmentConstantID, ConstantDescription)>,
+}
+impl EquipmentModel {
+  /// ### STATUS VARIABLE
+  /// 
+  /// The ID and description of the status variable with a name.
+  pub fn status_variable(&self, name: &str) -> Option<&(StatusVariableID, VariableDescription)> {
+    self.status_variables.iter().find(|(_, description)| description.name == name)
+  }
+
+  /// ### DATA VARIABLE
+  /// 
+  /// The ID and description of the data variable with a name.
+  pub fn data_variable(&self, name: &str) -> Option<&(VariableID, VariableDescription)> {
+    self.data_variables.iter().find(|(_, description)| description.name == name)
+  }
+
+  /// ### COLLECTION EVENT
+  /// 
+  /// The ID and description of the collection event with a name.
+  pub fn collection_event(&self, name: &str) -> Option<&(CollectionEventID, EventDescription)> {
+    self.collection_events.iter().find(|(_, description)| description.name == name)
+  }
+
+  /// ### EQUIPMENT CONSTANT
+  /// 
+  /// The ID and description of the equipment constant with a name.
+  pub fn equipment_constant(&self, name: &str) -> Option<&(EquipmentConstantID, ConstantDescription)> {
+    self.equipment_constants.iter().find(|(_, description)| description.name == name)
+  }
+
+  /// ### SAVE
+  /// 
+  /// Writes the model to a file, encoded as a single SECS-II [Item].
+  pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+    fs::write(path, Vec::<u8>::from(Item::from(self)))
+  }
+
+  /// ### LOAD
+  /// 
+  /// Reads a model previously written to a file with the [Save] function.
+  /// 
+  /// [Save]: EquipmentModel::save
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+    let item: Item = Item::try_from(fs::read(path)?).map_err(|_| Error::from(ErrorKind::InvalidData))?;
+    EquipmentModel::try_from(item).map_err(|_| Error::from(ErrorKind::InvalidData))
+  }
+}
+impl From<&EquipmentModel> for Item {
+  fn from(model: &EquipmentModel) -> Self {
+    fn text(text: &str) -> Item {
+      Item::Ascii(Char::safe_str_to_chars(text).into())
+    }
+    fn variable(description: &VariableDescription) -> [Item; 3] {
+      [
+        text(&description.name),
+        text(&description.units),
+        Item::U1(description.format.into_iter().collect::<Vec<u8>>().into()),
+      ]
+    }
+    Item::List(vec![
+      Item::List(model.status_variables.iter().map(|(id, description)| {
+        let [name, units, format] = variable(description);
+        Item::List(vec![id.clone().into(), name, units, format])
+      }).collect()),
+      Item::List(model.data_variables.iter().map(|(id, description)| {
+        let [name, units, format] = variable(description);
+        Item::List(vec![id.clone().into(), name, units, format])
+      }).collect()),
+      Item::List(model.collection_events.iter().map(|(id, description)| Item::List(vec![
+        id.clone().into(),
+        text(&description.name),
+        Item::List(description.variables.iter().cloned().map(Item::from).collect()),
+      ])).collect()),
+      Item::List(model.equipment_constants.iter().map(|(id, description)| Item::List(vec![
+        id.clone().into(),
+        text(&description.name),
+        text(&description.units),
+        description.minimum.clone(),
+        description.maximum.clone(),
+        description.default.clone(),
+      ])).collect()),
+    ])
+  }
+}
+impl TryFrom<Item> for EquipmentModel {
+  type Error = semi_e5::Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    use semi_e5::Error::WrongFormat;
+    fn list(item: Item) -> Result<Vec<Item>, semi_e5::Error> {
+      match item {
+        Item::List(items) => Ok(items),
+        _ => Err(WrongFormat),
+      }
+    }
+    fn text(item: Item) -> Result<String, semi_e5::Error> {
+      match item {
+        Item::Ascii(chars) => Ok(Char::chars_to_str(&chars)),
+        _ => Err(WrongFormat),
+      }
+    }
+    fn variable(name: Item, units: Item, format: Item) -> Result<VariableDescription, semi_e5::Error> {
+      Ok(VariableDescription {
+        name: text(name)?,
+        units: text(units)?,
+        format: match format {
+          Item::U1(values) => values.first().copied(),
+          _ => return Err(WrongFormat),
+        },
+      })
+    }
+    let Ok::<[Item; 4], _>([status, data, events, constants]) = list(item)?.try_into() else {
+      return Err(WrongFormat)
+    };
+    let mut model = EquipmentModel::default();
+    for entry in list(status)? {
+      let Ok::<[Item; 4], _>([id, name, units, format]) = list(entry)?.try_into() else {return Err(WrongFormat)};
+      model.status_variables.push((StatusVariableID::try_from(id)?, variable(name, units, format)?));
+    }
+    for entry in list(data)? {
+      let Ok::<[Item; 4], _>([id, name, units, format]) = list(entry)?.try_into() else {return Err(WrongFormat)};
+      model.data_variables.push((VariableID::try_from(id)?, variable(name, units, format)?));
+    }
+    for entry in list(events)? {
+      let Ok::<[Item; 3], _>([id, name, variables]) = list(entry)?.try_into() else {return Err(WrongFormat)};
+      model.collection_events.push((CollectionEventID::try_from(id)?, EventDescription {
+        name: text(name)?,
+        variables: list(variables)?.into_iter().map(VariableID::try_from).collect::<Result<_, _>>()?,
+      }));
+    }
+    for entry in list(constants)? {
+      let Ok::<[Item; 6], _>([id, name, units, minimum, maximum, default]) = list(entry)?.try_into() else {return Err(WrongFormat)};
+      model.equipment_constants.push((EquipmentConstantID::try_from(id)?, ConstantDescription {
+        name: text(name)?,
+        units: text(units)?,
+        minimum,
+        maximum,
+        default,
+      }));
+    }
+    Ok(model)
+  }
+}
+
+/// ## FORMAT CODE
+/// 
+/// The [Format] of an [Item].
+/// 
+/// [Format]: semi_e5::format
+pub fn format_code(item: &Item) -> u8 {
+  match item {
+    Item::List(_)     => format::LIST,
+    Item::Ascii(_)    => format::ASCII,
+    Item::Jis8(_)     => format::JIS8,
+    Item::Local(_, _) => format::LOCAL,
+    Item::Bin(_)      => format::BIN,
+    Item::Bool(_)     => format::BOOL,
+    Item::I1(_)       => format::I1,
+    Item::I2(_)       => format::I2,
+    Item::I4(_)       => format::I4,
+    Item::I8(_)       => format::I8,
+    Item::U1(_)       => format::U1,
+    Item::U2(_)       => format::U2,
+    Item::U4(_)       => format::U4,
+    Item::U8(_)       => format::U8,
+    Item::F4(_)       => format::F4,
+    Item::F8(_)       => format::F8,
+    Item::Raw(format, _) => *format,
+  }
+}
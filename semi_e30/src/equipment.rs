@@ -0,0 +1,841 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # EQUIPMENT
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Drives the [Communication State Model] and [Control State Model] of the
+//! equipment over an [HSMS] connection, replying on its behalf to the
+//! Stream 1 messages which those models govern.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! To use the [Equipment]:
+//! 
+//! - Create an [Equipment] by providing the [New Equipment] function with
+//!   [Parameter Settings] and [Equipment Settings].
+//! - Connect to the host with the [Connect Procedure], which provides the
+//!   primary messages not handled by the [Equipment], to be replied to with
+//!   the [Reply Procedure].
+//! - Manage the [Communication State] with the [Enable Procedure] and
+//!   [Disable Procedure].
+//! - Manage the [Control State] with the [Operator On-Line],
+//!   [Operator Off-Line], [Operator Local], and [Operator Remote]
+//!   procedures.
+//! - Populate the [Variable Registry], through the [Variables] function.
+//! - Register handlers for the commands the host may send with the
+//!   [Remote Command Dispatcher], through the [Commands] function.
+//! - Register a display handler with the [Terminal Services], through the
+//!   [Terminal] function, and send text from the operator to the host with
+//!   the [Terminal Request] procedure.
+//! - Optionally provide a [Spool] with the [Set Spool] function, and send
+//!   primary messages with the [Send Procedure], which spools them while
+//!   communication is lost.
+//! - Optionally provide a [Recipe Namespace] with the [Set Recipes]
+//!   function, in which the host may store recipes.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Equipment] replies to the following on its own:
+//! 
+//! - [S1F1] - With [S1F2], if ON-LINE.
+//! - [S1F13] - With [S1F14], if communication is enabled.
+//! - [S1F15] - With [S1F16], if ON-LINE.
+//! - [S1F17] - With [S1F18].
+//! - [S1F3], [S1F11], [S1F21], [S1F23], [S2F13], [S2F15], and [S2F29] -
+//!   From the [Variable Registry], if ON-LINE.
+//! - [S2F23] - With [S2F24], if ON-LINE, sending [S6F1]s for each trace
+//!   started, with the [Trace Engine].
+//! - [S2F41] and [S2F49] - From the [Remote Command Dispatcher], if
+//!   ON-LINE, refusing the command unless under REMOTE control.
+//! - [S10F3], [S10F5], and [S10F9] - From the [Terminal Services], if
+//!   ON-LINE.
+//! - [S2F43] - With [S2F44], if a [Spool] has been provided.
+//! - [S6F23] - With [S6F24], if a [Spool] has been provided, transmitting
+//!   the spooled messages afterwards if requested.
+//! - [S7F1], [S7F3], [S7F5], [S7F17], [S7F19], [S15F1], [S15F27], and
+//!   [S15F31] - From the [Recipe Namespace], if one has been provided and
+//!   ON-LINE.
+//! 
+//! Any other primary message requesting a reply is answered with an
+//! Abort Transaction if communication has not been established or the
+//! equipment is OFF-LINE, and is otherwise passed on.
+//! 
+//! [HSMS]:                      semi_e37
+//! [Communication State Model]: crate::communication
+//! [Control State Model]:       crate::control
+//! [Communication State]:       CommunicationState
+//! [Control State]:             ControlState
+//! [Equipment]:                 Equipment
+//! [New Equipment]:             Equipment::new
+//! [Connect Procedure]:         Equipment::connect
+//! [Reply Procedure]:           Equipment::reply
+//! [Enable Procedure]:          Equipment::enable
+//! [Disable Procedure]:         Equipment::disable
+//! [Operator On-Line]:          Equipment::operator_on_line
+//! [Operator Off-Line]:         Equipment::operator_off_line
+//! [Operator Local]:            Equipment::operator_local
+//! [Operator Remote]:           Equipment::operator_remote
+//! [Set Spool]:                 Equipment::set_spool
+//! [Set Recipes]:               Equipment::set_recipes
+//! [Recipe Namespace]:          RecipeNamespace
+//! [Variables]:                 Equipment::variables
+//! [Variable Registry]:         VariableRegistry
+//! [Trace Engine]:              TraceEngine
+//! [Commands]:                  Equipment::commands
+//! [Terminal]:                  Equipment::terminal
+//! [Terminal Request]:          Equipment::terminal_request
+//! [Terminal Services]:         TerminalServices
+//! [Remote Command Dispatcher]: RemoteCommandDispatcher
+//! [Send Procedure]:            Equipment::send
+//! [Spool]:                     Spool
+//! [Parameter Settings]:        ParameterSettings
+//! [Equipment Settings]:        EquipmentSettings
+//! [S1F1]:                      s1::AreYouThere
+//! [S1F2]:                      s1::OnLineDataEquipment
+//! [S1F13]:                     s1::HostCR
+//! [S1F14]:                     s1::EquipmentCRA
+//! [S1F15]:                     s1::RequestOffLine
+//! [S1F16]:                     s1::OffLineAck
+//! [S1F17]:                     s1::RequestOnLine
+//! [S1F18]:                     s1::OnLineAck
+//! [S1F3]:                      s1::SelectedEquipmentStatusRequest
+//! [S1F11]:                     s1::StatusVariableNamelistRequest
+//! [S1F21]:                     s1::DataVariableNamelistRequest
+//! [S1F23]:                     s1::CollectionEventNamelistRequest
+//! [S2F13]:                     s2::EquipmentConstantRequest
+//! [S2F15]:                     s2::NewEquipmentConstantSend
+//! [S2F29]:                     s2::EquipmentConstantNamelistRequest
+//! [S2F23]:                     s2::TraceInitializeSend
+//! [S2F24]:                     s2::TraceInitializeAcknowledge
+//! [S6F1]:                      s6::TraceDataSend
+//! [S2F41]:                     s2::HostCommandSend
+//! [S2F49]:                     s2::EnhancedRemoteCommand
+//! [S10F3]:                     s10::TerminalDisplaySingle
+//! [S10F5]:                     s10::TerminalDisplayMultiBlock
+//! [S10F9]:                     s10::Broadcast
+//! [S2F43]:                     s2::ResetSpoolingStreamsAndFunctions
+//! [S2F44]:                     s2::ResetSpoolingAcknowledge
+//! [S6F23]:                     s6::RequestSpooledData
+//! [S6F24]:                     s6::RequestSpooledDataAcknowledge
+//! [S7F1]:                      semi_e5::messages::s7::ProcessProgramLoadInquire
+//! [S7F3]:                      semi_e5::messages::s7::ProcessProgramSend
+//! [S7F5]:                      semi_e5::messages::s7::ProcessProgramRequest
+//! [S7F17]:                     semi_e5::messages::s7::DeleteProcessProgramSend
+//! [S7F19]:                     semi_e5::messages::s7::CurrentProcessProgramDirectoryRequest
+//! [S15F1]:                     semi_e5::messages::s15::RecipeManagementMultiBlockInquire
+//! [S15F27]:                    semi_e5::messages::s15::RecipeDownloadRequest
+//! [S15F31]:                    semi_e5::messages::s15::RecipeUploadRequest
+
+use std::{
+  io::{Error, ErrorKind},
+  net::SocketAddr,
+  sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+    mpsc::{channel, Receiver},
+  },
+  thread::{self, JoinHandle},
+  time::{Duration, Instant},
+};
+use semi_e5::{
+  Message,
+  items::{ModelName, SoftwareRevision, TerminalID, Text},
+  messages::{Abort, s1, s2, s6, s10},
+};
+use semi_e37::generic::{Client, MessageID, ParameterSettings};
+use crate::{
+  communication::CommunicationState,
+  control::{ControlSettings, ControlState, ControlStateMachine},
+  recipe::RecipeNamespace,
+  remote::RemoteCommandDispatcher,
+  spool::Spool,
+  terminal::TerminalServices,
+  trace::{Trace, TraceEngine, TraceSettings},
+  variables::VariableRegistry,
+};
+
+/// ## EQUIPMENT SETTINGS
+/// 
+/// The configurable behavior of the [Equipment].
+/// 
+/// [Equipment]: Equipment
+#[derive(Clone, Debug)]
+pub struct EquipmentSettings {
+  /// ### MODEL NAME
+  /// 
+  /// The [MDLN] reported to the host in [S1F2] and [S1F14].
+  /// 
+  /// [MDLN]:  ModelName
+  /// [S1F2]:  s1::OnLineDataEquipment
+  /// [S1F14]: s1::EquipmentCRA
+  pub model_name: ModelName,
+
+  /// ### SOFTWARE REVISION
+  /// 
+  /// The [SOFTREV] reported to the host in [S1F2] and [S1F14].
+  /// 
+  /// [SOFTREV]: SoftwareRevision
+  /// [S1F2]:    s1::OnLineDataEquipment
+  /// [S1F14]:   s1::EquipmentCRA
+  pub software_revision: SoftwareRevision,
+
+  /// ### SESSION ID
+  /// 
+  /// The [Session ID] used by primary messages sent by the [Equipment].
+  /// 
+  /// [Session ID]: MessageID::session
+  /// [Equipment]:  Equipment
+  pub session: u16,
+
+  /// ### ESTABLISH COMMUNICATIONS TIMEOUT
+  /// 
+  /// The amount of time waited in the [WAIT DELAY] state before attempting
+  /// to establish communication again.
+  /// 
+  /// [WAIT DELAY]: CommunicationState::WaitDelay
+  pub establish_communications_timeout: Duration,
+
+  /// ### CONTROL SETTINGS
+  pub control: ControlSettings,
+
+  /// ### TRACE SETTINGS
+  pub trace: TraceSettings,
+}
+
+/// ## EQUIPMENT
+/// 
+/// Drives the [Communication State] and [Control State] of the equipment
+/// over an [HSMS Client].
+/// 
+/// [Communication State]: CommunicationState
+/// [Control State]:       ControlState
+/// [HSMS Client]:         Client
+pub struct Equipment {
+  settings: EquipmentSettings,
+  client: Arc<Client>,
+  communication: Mutex<CommunicationState>,
+  generation: Mutex<u64>,
+  control: Mutex<ControlStateMachine>,
+  spool: Mutex<Option<Spool>>,
+  recipes: Mutex<Option<RecipeNamespace>>,
+  variables: Mutex<VariableRegistry>,
+  traces: Mutex<TraceEngine>,
+  commands: Mutex<RemoteCommandDispatcher>,
+  terminal: Mutex<TerminalServices>,
+  system: Mutex<u32>,
+}
+impl Equipment {
+  /// ### NEW EQUIPMENT
+  /// 
+  /// Creates an [Equipment] with communication [DISABLED] and in the initial
+  /// [Control State] provided by the [Equipment Settings].
+  /// 
+  /// [Equipment]:          Equipment
+  /// [DISABLED]:           CommunicationState::Disabled
+  /// [Control State]:      ControlState
+  /// [Equipment Settings]: EquipmentSettings
+  pub fn new(
+    parameter_settings: ParameterSettings,
+    settings: EquipmentSettings,
+  ) -> Arc<Self> {
+    Arc::new(Equipment {
+      client:        Client::new(parameter_settings),
+      communication: Default::default(),
+      generation:    Default::default(),
+      control:       Mutex::new(ControlStateMachine::new(settings.control)),
+      spool:         Default::default(),
+      recipes:       Default::default(),
+      variables:     Default::default(),
+      traces:        Mutex::new(TraceEngine::new(settings.trace)),
+      commands:      Default::default(),
+      terminal:      Default::default(),
+      system:        Default::default(),
+      settings,
+    })
+  }
+
+  /// ### CLIENT
+  /// 
+  /// The [HSMS Client] used by the [Equipment], through which other
+  /// procedures such as the [Select Procedure] may be performed.
+  /// 
+  /// [HSMS Client]:      Client
+  /// [Equipment]:        Equipment
+  /// [Select Procedure]: Client::select
+  pub fn client(&self) -> &Arc<Client> {
+    &self.client
+  }
+
+  /// ### COMMUNICATION STATE
+  pub fn communication_state(&self) -> CommunicationState {
+    *self.communication.lock().unwrap()
+  }
+
+  /// ### CONTROL STATE
+  pub fn control_state(&self) -> ControlState {
+    self.control.lock().unwrap().state()
+  }
+
+  /// ### NEXT MESSAGE ID
+  /// 
+  /// Provides a [Message ID] for a new primary message sent by the
+  /// [Equipment], incrementing its [System Bytes].
+  /// 
+  /// [Message ID]:   MessageID
+  /// [System Bytes]: MessageID::system
+  /// [Equipment]:    Equipment
+  pub fn next_id(&self) -> MessageID {
+    let mut system = self.system.lock().unwrap();
+    *system = system.wrapping_add(1);
+    MessageID {
+      session: self.settings.session,
+      system: *system,
+    }
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// 
+  /// Connects the [HSMS Client] to the host, as with its
+  /// [Connect Procedure].
+  /// 
+  /// Primary messages received from the host are handled by the [Equipment]
+  /// where it is able to, and the rest are provided by the returned
+  /// receiver, to be replied to with the [Reply Procedure].
+  /// 
+  /// Once the connection is lost, a [Communication Failure] occurs.
+  /// 
+  /// [HSMS Client]:           Client
+  /// [Connect Procedure]:     Client::connect
+  /// [Equipment]:             Equipment
+  /// [Reply Procedure]:       Equipment::reply
+  /// [Communication Failure]: CommunicationState::communication_failure
+  pub fn connect(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<(SocketAddr, Receiver<(MessageID, Message)>), Error> {
+    let (socket, rx_receiver) = self.client.connect(entity)?;
+    let (data_sender, data_receiver) = channel::<(MessageID, Message)>();
+    let clone: Arc<Equipment> = self.clone();
+    thread::spawn(move || {
+      for (id, message) in rx_receiver {
+        if let Some(unhandled) = clone.handle(id, message) {
+          if data_sender.send(unhandled).is_err() {break}
+        }
+      }
+      clone.communication_failure();
+    });
+    Ok((socket, data_receiver))
+  }
+
+  /// ### REPLY PROCEDURE
+  /// 
+  /// Sends a reply to a primary message provided by the
+  /// [Connect Procedure], using its [Message ID].
+  /// 
+  /// [Connect Procedure]: Equipment::connect
+  /// [Message ID]:        MessageID
+  pub fn reply(
+    &self,
+    id: MessageID,
+    message: impl Into<Message>,
+  ) -> JoinHandle<Result<Option<Message>, Error>> {
+    self.client.data(id, message.into())
+  }
+
+  /// ### RESPOND
+  /// 
+  /// Sends a reply to a primary message while it is being handled, on the
+  /// thread handling received messages, with the [Respond Procedure] of the
+  /// [HSMS Client] rather than with a thread of its own.
+  /// 
+  /// Results in whether the reply was sent, a failure to send it being a
+  /// [Communication Failure].
+  /// 
+  /// [HSMS Client]:           Client
+  /// [Respond Procedure]:     Client::respond
+  /// [Communication Failure]: CommunicationState::communication_failure
+  fn respond(&self, id: MessageID, message: impl Into<Message>) -> bool {
+    let sent: bool = self.client.respond(id, message.into()).is_ok();
+    if !sent {
+      self.communication_failure();
+    }
+    sent
+  }
+
+  /// ### SET SPOOL
+  /// 
+  /// Provides the [Spool] used while communication is lost, or removes it.
+  /// 
+  /// [Spool]: Spool
+  pub fn set_spool(&self, spool: Option<Spool>) {
+    *self.spool.lock().unwrap() = spool;
+  }
+
+  /// ### SET RECIPES
+  /// 
+  /// Provides the [Recipe Namespace] in which the host may store recipes,
+  /// or removes it.
+  /// 
+  /// [Recipe Namespace]: RecipeNamespace
+  pub fn set_recipes(&self, recipes: Option<RecipeNamespace>) {
+    *self.recipes.lock().unwrap() = recipes;
+  }
+
+  /// ### RECIPES
+  /// 
+  /// The [Recipe Namespace] provided, if any, through which the application
+  /// may retrieve the recipes stored by the host.
+  /// 
+  /// [Recipe Namespace]: RecipeNamespace
+  pub fn recipes(&self) -> MutexGuard<'_, Option<RecipeNamespace>> {
+    self.recipes.lock().unwrap()
+  }
+
+  /// ### VARIABLES
+  /// 
+  /// The [Variable Registry] from which the [Equipment] answers requests
+  /// for its variables, to be populated and kept up to date by the
+  /// application.
+  /// 
+  /// [Variable Registry]: VariableRegistry
+  /// [Equipment]:         Equipment
+  pub fn variables(&self) -> MutexGuard<'_, VariableRegistry> {
+    self.variables.lock().unwrap()
+  }
+
+  /// ### COMMANDS
+  /// 
+  /// The [Remote Command Dispatcher] with which the [Equipment] answers
+  /// commands from the host, with which the application registers its
+  /// handlers.
+  /// 
+  /// The dispatcher remains locked while a handler runs, so handlers must
+  /// not access it themselves.
+  /// 
+  /// [Remote Command Dispatcher]: RemoteCommandDispatcher
+  /// [Equipment]:                 Equipment
+  pub fn commands(&self) -> MutexGuard<'_, RemoteCommandDispatcher> {
+    self.commands.lock().unwrap()
+  }
+
+  /// ### TERMINAL
+  /// 
+  /// The [Terminal Services] with which the [Equipment] answers the host's
+  /// requests to display text to the operator.
+  /// 
+  /// [Terminal Services]: TerminalServices
+  /// [Equipment]:         Equipment
+  pub fn terminal(&self) -> MutexGuard<'_, TerminalServices> {
+    self.terminal.lock().unwrap()
+  }
+
+  /// ### TERMINAL REQUEST
+  /// 
+  /// Sends text entered by the operator at a terminal to the host with an
+  /// [S10F1].
+  /// 
+  /// [S10F1]: s10::TerminalRequest
+  pub fn terminal_request(
+    self: &Arc<Self>,
+    terminal: TerminalID,
+    text: Text,
+  ) -> JoinHandle<Result<s10::TerminalAcknowledge, Error>> {
+    self.client.request(self.next_id(), s10::TerminalRequest((terminal, text)))
+  }
+
+  /// ### SEND PROCEDURE
+  /// 
+  /// Sends a primary message to the host with the [Next Message ID], as
+  /// with the [Data Procedure].
+  /// 
+  /// If communication has not been established, the message is spooled if
+  /// it has been selected for spooling, resulting in no reply, and
+  /// otherwise results in an error of kind [NotConnected].
+  /// 
+  /// [Next Message ID]: Equipment::next_id
+  /// [Data Procedure]:  Client::data
+  /// [NotConnected]:    ErrorKind::NotConnected
+  pub fn send(
+    &self,
+    message: impl Into<Message>,
+  ) -> JoinHandle<Result<Option<Message>, Error>> {
+    let message: Message = message.into();
+    if self.communication_state().is_communicating() {
+      return self.client.data(self.next_id(), message)
+    }
+    let result: Result<bool, Error> = match self.spool.lock().unwrap().as_mut() {
+      Some(spool) => spool.spool(message),
+      None => Ok(false),
+    };
+    thread::spawn(move || {
+      match result? {
+        true => Ok(None),
+        false => Err(Error::from(ErrorKind::NotConnected)),
+      }
+    })
+  }
+
+  /// ### ENABLE PROCEDURE
+  /// 
+  /// Enables communication, and while it remains enabled, repeatedly
+  /// attempts to establish communication by sending an [S1F13] whenever in
+  /// the [WAIT CRA] state, waiting for the Establish Communications Timeout
+  /// between failed attempts.
+  /// 
+  /// Does nothing if communication is already enabled.
+  /// 
+  /// Each call which enables communication starts a new attempt loop, and any
+  /// loop left over from before communication was last disabled exits
+  /// without acting on the state of the new one.
+  /// 
+  /// [S1F13]:    s1::EquipmentCR
+  /// [WAIT CRA]: CommunicationState::WaitCRA
+  pub fn enable(self: &Arc<Self>) {
+    let generation: u64 = {
+      let mut communication = self.communication.lock().unwrap();
+      if !communication.enable() {return}
+      let mut generation = self.generation.lock().unwrap();
+      *generation = generation.wrapping_add(1);
+      *generation
+    };
+    let clone: Arc<Equipment> = self.clone();
+    thread::spawn(move || {
+      loop {
+        let state: CommunicationState = {
+          let communication = clone.communication.lock().unwrap();
+          if !clone.is_current(generation) {break}
+          *communication
+        };
+        match state {
+          CommunicationState::Disabled => break,
+          CommunicationState::WaitCRA => {
+            let request = s1::EquipmentCR((
+              clone.settings.model_name.clone(),
+              clone.settings.software_revision.clone(),
+            ));
+            let result = clone.client.request(clone.next_id(), request).join();
+            let mut communication = clone.communication.lock().unwrap();
+            if !clone.is_current(generation) {break}
+            match result {
+              Ok(Ok(s1::HostCRA((ack, ())))) => communication.receive_cra(ack),
+              _ => communication.cra_timeout(),
+            }
+          },
+          CommunicationState::WaitDelay => {
+            thread::sleep(clone.settings.establish_communications_timeout);
+            let mut communication = clone.communication.lock().unwrap();
+            if !clone.is_current(generation) {break}
+            communication.delay_expired();
+          },
+          CommunicationState::Communicating => {
+            thread::sleep(clone.settings.establish_communications_timeout);
+          },
+        }
+      }
+    });
+  }
+
+  /// ### DISABLE PROCEDURE
+  /// 
+  /// Disables communication, after which messages received from the host
+  /// are ignored.
+  pub fn disable(&self) {
+    self.communication.lock().unwrap().disable();
+  }
+
+  /// ### IS CURRENT
+  /// 
+  /// Whether the attempt loop started when communication was enabled with
+  /// the given generation is still the current one.
+  fn is_current(&self, generation: u64) -> bool {
+    *self.generation.lock().unwrap() == generation
+  }
+
+  /// ### OPERATOR ON-LINE
+  /// 
+  /// Attempts to go ON-LINE from the [EQUIPMENT OFF-LINE] state by sending
+  /// an [S1F1], entering the ON-LINE state if the host replies, and
+  /// otherwise the OFF-LINE substate provided by the [Control Settings].
+  /// 
+  /// Results in the [Control State] once the attempt has finished.
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  /// [S1F1]:               s1::AreYouThere
+  /// [Control Settings]:   ControlSettings
+  /// [Control State]:      ControlState
+  pub fn operator_on_line(self: &Arc<Self>) -> JoinHandle<ControlState> {
+    let attempt: bool = self.control.lock().unwrap().operator_on_line();
+    let clone: Arc<Equipment> = self.clone();
+    thread::spawn(move || {
+      if attempt {
+        let result = clone.client.request(clone.next_id(), s1::AreYouThere).join();
+        let mut control = clone.control.lock().unwrap();
+        match result {
+          Ok(Ok(_)) => control.on_line_succeeded(),
+          _ => control.on_line_failed(),
+        }
+      }
+      clone.control_state()
+    })
+  }
+
+  /// ### OPERATOR OFF-LINE
+  /// 
+  /// Enters the [EQUIPMENT OFF-LINE] state.
+  /// 
+  /// [EQUIPMENT OFF-LINE]: ControlState::EquipmentOffLine
+  pub fn operator_off_line(&self) {
+    self.control.lock().unwrap().operator_off_line();
+  }
+
+  /// ### OPERATOR LOCAL
+  /// 
+  /// Moves the operator's LOCAL/REMOTE switch to LOCAL.
+  pub fn operator_local(&self) {
+    self.control.lock().unwrap().operator_local();
+  }
+
+  /// ### OPERATOR REMOTE
+  /// 
+  /// Moves the operator's LOCAL/REMOTE switch to REMOTE.
+  pub fn operator_remote(&self) {
+    self.control.lock().unwrap().operator_remote();
+  }
+
+  /// ### UNLOAD SPOOL
+  /// 
+  /// Transmits the spooled messages to the host, one at a time, until the
+  /// spool is empty or a message fails to be read or sent, in which case the
+  /// remaining messages stay spooled.
+  fn unload_spool(self: &Arc<Self>) {
+    let clone: Arc<Equipment> = self.clone();
+    thread::spawn(move || {
+      loop {
+        let message: Option<Message> = match clone.spool.lock().unwrap().as_mut() {
+          Some(spool) => match spool.unload() {
+            Ok(message) => message,
+            Err(_) => {
+              spool.stop_unloading();
+              None
+            },
+          },
+          None => None,
+        };
+        let Some(message) = message else {break};
+        let sent: bool = matches!(clone.client.data(clone.next_id(), message).join(), Ok(Ok(_)));
+        let mut spool = clone.spool.lock().unwrap();
+        let Some(spool) = spool.as_mut() else {break};
+        if !sent || spool.confirm().is_err() {
+          spool.stop_unloading();
+          break
+        }
+      }
+    });
+  }
+
+  /// ### RUN TRACE
+  /// 
+  /// Samples a [Trace] at its period until it is finished, sending each
+  /// [S6F1] it produces with the [Send Procedure].
+  /// 
+  /// [Trace]:          Trace
+  /// [S6F1]:           s6::TraceDataSend
+  /// [Send Procedure]: Equipment::send
+  fn run_trace(self: &Arc<Self>, mut trace: Trace) {
+    let clone: Arc<Equipment> = self.clone();
+    thread::spawn(move || {
+      let start: Instant = Instant::now();
+      let mut samples: u32 = 0;
+      while !trace.is_finished() {
+        samples += 1;
+        let next: Instant = start + trace.period() * samples;
+        thread::sleep(next.saturating_duration_since(Instant::now()));
+        if trace.is_cancelled() {break}
+        let data = trace.sample(&clone.variables());
+        if let Some(data) = data {
+          clone.send(data);
+        }
+      }
+      clone.traces.lock().unwrap().finish(&trace);
+    });
+  }
+
+  /// ### COMMUNICATION FAILURE
+  fn communication_failure(&self) {
+    self.communication.lock().unwrap().communication_failure();
+    if let Some(spool) = self.spool.lock().unwrap().as_mut() {
+      spool.stop_unloading();
+    }
+  }
+
+  /// ### HANDLE
+  /// 
+  /// Replies to a primary message received from the host if it is governed
+  /// by the state models, or if it is not accepted in the current state,
+  /// and otherwise returns it to be passed on.
+  fn handle(self: &Arc<Self>, id: MessageID, message: Message) -> Option<(MessageID, Message)> {
+    let (stream, function, w) = (message.stream, message.function, message.w);
+    // DISABLED: Ignore
+    if !self.communication_state().is_enabled() {
+      return None
+    }
+    // S1F13: Establish Communications Request
+    if (stream, function) == (1, 13) {
+      match s1::HostCR::try_from(message) {
+        Ok(_) => {
+          let ack = self.communication.lock().unwrap().receive_cr();
+          if let Some(ack) = ack {
+            self.respond(id, s1::EquipmentCRA((ack, (
+              self.settings.model_name.clone(),
+              self.settings.software_revision.clone(),
+            ))));
+          }
+        },
+        Err(_) => {self.respond(id, Abort::for_stream(stream));},
+      }
+      return None
+    }
+    // NOT COMMUNICATING: Abort
+    if !self.communication_state().is_communicating() {
+      if w {self.respond(id, Abort::for_stream(stream));}
+      return None
+    }
+    // S1F17: Request ON-LINE
+    if (stream, function) == (1, 17) {
+      match s1::RequestOnLine::try_from(message) {
+        Ok(_) => {
+          let ack = self.control.lock().unwrap().receive_on_line_request();
+          self.respond(id, s1::OnLineAck(ack));
+        },
+        Err(_) => {self.respond(id, Abort::for_stream(stream));},
+      }
+      return None
+    }
+    // OFF-LINE: Abort
+    if !self.control_state().accepts(stream, function) {
+      if w {self.respond(id, Abort::for_stream(stream));}
+      return None
+    }
+    match (stream, function) {
+      // S1F1: Are You There
+      (1, 1) => {
+        match s1::AreYouThere::try_from(message) {
+          Ok(_) => {self.respond(id, s1::OnLineDataEquipment((
+            self.settings.model_name.clone(),
+            self.settings.software_revision.clone(),
+          )));},
+          Err(_) => {self.respond(id, Abort::for_stream(stream));},
+        }
+        None
+      },
+      // S1F15: Request OFF-LINE
+      (1, 15) => {
+        let ack = s1::RequestOffLine::try_from(message).ok()
+          .and_then(|_| self.control.lock().unwrap().receive_off_line_request());
+        match ack {
+          Some(ack) => {self.respond(id, s1::OffLineAck(ack));},
+          None => {self.respond(id, Abort::for_stream(stream));},
+        }
+        None
+      },
+      // S2F23: Trace Initialize Send
+      (2, 23) => {
+        match s2::TraceInitializeSend::try_from(message) {
+          Ok(request) => {
+            let (ack, trace) = self.traces.lock().unwrap().initialize(request, &self.variables());
+            self.respond(id, ack);
+            if let Some(trace) = trace {
+              self.run_trace(trace);
+            }
+          },
+          Err(_) => {self.respond(id, Abort::for_stream(stream));},
+        }
+        None
+      },
+      // S2F43: Reset Spooling Streams and Functions
+      (2, 43) if self.spool.lock().unwrap().is_some() => {
+        match s2::ResetSpoolingStreamsAndFunctions::try_from(message) {
+          Ok(request) => {
+            let ack = self.spool.lock().unwrap().as_mut().map(|spool| spool.reset_spooling(request));
+            match ack {
+              Some(ack) => {self.respond(id, ack);},
+              None => {self.respond(id, Abort::for_stream(stream));},
+            }
+          },
+          Err(_) => {self.respond(id, Abort::for_stream(stream));},
+        }
+        None
+      },
+      // S6F23: Request Spooled Data
+      (6, 23) if self.spool.lock().unwrap().is_some() => {
+        let ack = s6::RequestSpooledData::try_from(message).ok()
+          .and_then(|request| self.spool.lock().unwrap().as_mut().map(|spool| spool.request_spooled_data(request)))
+          .and_then(Result::ok);
+        match ack {
+          Some(ack) => {
+            if self.respond(id, ack) {
+              self.unload_spool();
+            }
+          },
+          None => {self.respond(id, Abort::for_stream(stream));},
+        }
+        None
+      },
+      // S7F1, S7F3, S7F5, S7F17, S7F19, S15F1, S15F27, S15F31: Recipe Management
+      (7, 1) | (7, 3) | (7, 5) | (7, 17) | (7, 19)
+      | (15, 1) | (15, 27) | (15, 31) if self.recipes.lock().unwrap().is_some() => {
+        let reply: Option<Message> = self.recipes().as_mut().and_then(|recipes| recipes.service(&message));
+        match reply {
+          Some(reply) => {self.respond(id, reply); None},
+          None => Some((id, message)),
+        }
+      },
+      // S2F41, S2F49: Remote Commands
+      (2, 41) | (2, 49) => {
+        let remote: bool = self.control_state() == ControlState::OnLineRemote;
+        let reply: Option<Message> = self.commands().service(&message, remote);
+        match reply {
+          Some(reply) => {self.respond(id, reply); None},
+          None => Some((id, message)),
+        }
+      },
+      // S10F3, S10F5, S10F9: Terminal Display
+      (10, 3) | (10, 5) | (10, 9) => {
+        let reply: Option<Message> = self.terminal().service(&message);
+        match reply {
+          Some(reply) => {self.respond(id, reply); None},
+          None => Some((id, message)),
+        }
+      },
+      // Other
+      _ => {
+        let reply: Option<Message> = self.variables().service(&message);
+        match reply {
+          Some(reply) => {self.respond(id, reply); None},
+          None => Some((id, message)),
+        }
+      },
+    }
+  }
+}
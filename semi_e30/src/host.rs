@@ -0,0 +1,1125 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HOST
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Drives the host side of the [Communication State Model] over an [HSMS]
+//! connection, keeps track of whether the equipment is ON-LINE, and provides
+//! typed operations for the capabilities a host most commonly uses.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! To use the [Host]:
+//! 
+//! - Create a [Host] by providing the [New Host] function with
+//!   [Parameter Settings] and [Host Settings].
+//! - Connect to the equipment with the [Connect Procedure], which provides
+//!   the primary messages not handled by the [Host], such as [S5F1] and
+//!   [S6F11], to be replied to with the [Reply Procedure].
+//! - Manage the [Communication State] with the [Enable Procedure] and
+//!   [Disable Procedure].
+//! - Once communicating, use the typed operations:
+//!   - Control - [Request On-Line] and [Request Off-Line].
+//!   - Event Reports - [Define Report], [Link Event Report],
+//!     [Enable/Disable Event Report], and [Set Up Collection Events].
+//!   - Data Collection - [Start Data Collection] and
+//!     [Stop Data Collection], carrying out a [Data Collection Plan].
+//!   - Alarms - [Enable Alarm], [Disable Alarm], [List Alarms], and
+//!     [List Enabled Alarms].
+//!   - Remote Commands - [Host Command] and [Enhanced Remote Command].
+//!   - Discovery - [Discover], with the result kept as the
+//!     [Equipment Model] for access to variables by name.
+//!   - Recipes - [Send Process Program], [Request Process Program],
+//!     [Download Recipe], and [Upload Recipe].
+//!   - Terminal Services - [Display], which completes once the operator
+//!     has recognized the message, as reported by the application with
+//!     [Message Recognized] or by the event provided to
+//!     [Set Message Recognition Event].
+//!   - Any other primary message - [Request].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Host] replies to the following on its own:
+//! 
+//! - [S1F1] - With [S1F2], noting that the equipment is ON-LINE.
+//! - [S1F13] - With [S1F14], if communication is enabled.
+//! 
+//! While communicating, the [Host] sends an [S1F1] every Are You There
+//! Interval, which keeps the connection alive and updates the
+//! [Equipment Control State].
+//! 
+//! [HSMS]:                          semi_e37
+//! [Communication State Model]:     crate::communication
+//! [Communication State]:           CommunicationState
+//! [Equipment Control State]:       EquipmentControlState
+//! [Host]:                          Host
+//! [New Host]:                      Host::new
+//! [Connect Procedure]:             Host::connect
+//! [Reply Procedure]:               Host::reply
+//! [Enable Procedure]:              Host::enable
+//! [Disable Procedure]:             Host::disable
+//! [Request On-Line]:               Host::request_on_line
+//! [Request Off-Line]:              Host::request_off_line
+//! [Define Report]:                 Host::define_report
+//! [Link Event Report]:             Host::link_event_report
+//! [Enable/Disable Event Report]:   Host::enable_disable_event_report
+//! [Set Up Collection Events]:      Host::setup_collection_events
+//! [Start Data Collection]:         Host::start_data_collection
+//! [Stop Data Collection]:          Host::stop_data_collection
+//! [Data Collection Plan]:          crate::collection::DataCollectionPlan
+//! [Enable Alarm]:                  Host::enable_alarm
+//! [Disable Alarm]:                 Host::disable_alarm
+//! [List Alarms]:                   Host::list_alarms
+//! [List Enabled Alarms]:           Host::list_enabled_alarms
+//! [Host Command]:                  Host::host_command
+//! [Enhanced Remote Command]:       Host::enhanced_remote_command
+//! [Request]:                       Host::request
+//! [Discover]:                      Host::discover
+//! [Equipment Model]:               Host::equipment_model
+//! [Send Process Program]:          Host::send_process_program
+//! [Request Process Program]:       Host::request_process_program
+//! [Download Recipe]:               Host::download_recipe
+//! [Upload Recipe]:                 Host::upload_recipe
+//! [Display]:                       Host::display
+//! [Message Recognized]:            Host::message_recognized
+//! [Set Message Recognition Event]: Host::set_message_recognition_event
+//! [Parameter Settings]:            ParameterSettings
+//! [Host Settings]:                 HostSettings
+//! [S1F1]:                          s1::AreYouThere
+//! [S1F2]:                          s1::OnLineDataHost
+//! [S1F13]:                         s1::EquipmentCR
+//! [S1F14]:                         s1::HostCRA
+//! [S5F1]:                          semi_e5::messages::s5::AlarmReportSend
+//! [S6F11]:                         semi_e5::messages::s6::EventReport
+
+use std::{
+  io::{Error, ErrorKind},
+  net::SocketAddr,
+  sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+    mpsc::{channel, Receiver, Sender},
+  },
+  thread::{self, JoinHandle},
+  time::Duration,
+};
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    AcknowledgeCode6,
+    AlarmEnableDisable,
+    AlarmID,
+    Char,
+    CodedValue,
+    CollectionEventID,
+    DataID,
+    DefineReportAcknowledgeCode,
+    EnableDisableEventReportAcknowledgeCode,
+    LinkReportAcknowledgeCode,
+    OffLineAcknowledge,
+    OnLineAcknowledge,
+    ProcessProgramBody,
+    ProcessProgramGrant,
+    ProcessProgramID,
+    RecipeManagementGrant,
+    RecipeSpecifier,
+    StatusVariableID,
+    TerminalID,
+    TraceInitializeAcknowledgeCode,
+    TraceRequestID,
+    Length,
+    Text,
+    VecList,
+  },
+  messages::{Abort, MultiBlockMessage, PrimaryMessage, s1, s2, s5, s6, s7, s10, s15},
+};
+use semi_e37::generic::{Client, MessageID, ParameterSettings};
+use crate::{
+  collection::{CollectedData, DataCollectionPlan, DataCollectionStart},
+  communication::CommunicationState,
+  discovery::{format_code, ConstantDescription, EquipmentModel, EventDescription, VariableDescription},
+  recipe::{body_length, RecipeTransfer},
+  terminal::OperatorAck,
+};
+
+/// ## HOST SETTINGS
+/// 
+/// The configurable behavior of the [Host].
+/// 
+/// [Host]: Host
+#[derive(Clone, Copy, Debug)]
+pub struct HostSettings {
+  /// ### SESSION ID
+  /// 
+  /// The [Session ID] used by primary messages sent by the [Host].
+  /// 
+  /// [Session ID]: MessageID::session
+  /// [Host]:       Host
+  pub session: u16,
+
+  /// ### ESTABLISH COMMUNICATIONS TIMEOUT
+  /// 
+  /// The amount of time waited in the [WAIT DELAY] state before attempting
+  /// to establish communication again.
+  /// 
+  /// [WAIT DELAY]: CommunicationState::WaitDelay
+  pub establish_communications_timeout: Duration,
+
+  /// ### ARE YOU THERE INTERVAL
+  /// 
+  /// The amount of time waited between each [S1F1] sent while
+  /// communicating.
+  /// 
+  /// [S1F1]: s1::AreYouThere
+  pub are_you_there_interval: Duration,
+
+  /// ### DISCOVER
+  /// 
+  /// Whether the [Equipment Model] is discovered each time communication
+  /// is established.
+  /// 
+  /// [Equipment Model]: EquipmentModel
+  pub discover: bool,
+}
+
+/// ## EQUIPMENT CONTROL STATE
+/// 
+/// The [Control State] of the equipment, as far as the [Host] is able to
+/// observe it.
+/// 
+/// [Control State]: crate::control::ControlState
+/// [Host]:          Host
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EquipmentControlState {
+  /// ### UNKNOWN
+  /// 
+  /// Communication has not been established since the [Host] was created or
+  /// last lost communication.
+  /// 
+  /// [Host]: Host
+  #[default]
+  Unknown,
+
+  /// ### OFF-LINE
+  /// 
+  /// The equipment has aborted a transaction, as it does while OFF-LINE, or
+  /// has acknowledged a request to go OFF-LINE.
+  OffLine,
+
+  /// ### ON-LINE
+  /// 
+  /// The equipment has replied to an [S1F1], has sent one, or has accepted
+  /// a request to go ON-LINE.
+  /// 
+  /// [S1F1]: s1::AreYouThere
+  OnLine,
+}
+
+/// ## COLLECTION EVENT SETUP ACKNOWLEDGE
+/// 
+/// The outcome of the [Set Up Collection Events] operation, naming the step
+/// which the equipment refused, if any.
+/// 
+/// [Set Up Collection Events]: Host::setup_collection_events
+#[derive(Clone, Copy, Debug)]
+pub enum CollectionEventSetupAcknowledge {
+  /// ### OK
+  /// 
+  /// Every step was accepted.
+  Ok,
+
+  /// ### DEFINE REPORT
+  /// 
+  /// The [S2F33] was refused.
+  /// 
+  /// [S2F33]: s2::DefineReport
+  DefineReport(DefineReportAcknowledgeCode),
+
+  /// ### LINK EVENT REPORT
+  /// 
+  /// The [S2F35] was refused.
+  /// 
+  /// [S2F35]: s2::LinkEventReport
+  LinkEventReport(LinkReportAcknowledgeCode),
+
+  /// ### ENABLE EVENT REPORT
+  /// 
+  /// The [S2F37] was refused.
+  /// 
+  /// [S2F37]: s2::EnableDisableEventReport
+  EnableEventReport(EnableDisableEventReportAcknowledgeCode),
+}
+
+/// ## HOST
+/// 
+/// Drives the host side of the [Communication State] over an
+/// [HSMS Client], and tracks the [Equipment Control State].
+/// 
+/// [Communication State]:     CommunicationState
+/// [Equipment Control State]: EquipmentControlState
+/// [HSMS Client]:             Client
+pub struct Host {
+  settings: HostSettings,
+  client: Arc<Client>,
+  communication: Mutex<CommunicationState>,
+  generation: Mutex<u64>,
+  control: Mutex<EquipmentControlState>,
+  recognition_event: Mutex<Option<CollectionEventID>>,
+  recognitions: Mutex<Vec<Sender<()>>>,
+  model: Mutex<Option<EquipmentModel>>,
+  collection: Mutex<Option<(DataCollectionPlan, Sender<CollectedData>)>>,
+  system: Mutex<u32>,
+}
+impl Host {
+  /// ### NEW HOST
+  /// 
+  /// Creates a [Host] with communication [DISABLED] and the
+  /// [Equipment Control State] unknown.
+  /// 
+  /// [Host]:                    Host
+  /// [DISABLED]:                CommunicationState::Disabled
+  /// [Equipment Control State]: EquipmentControlState
+  pub fn new(
+    parameter_settings: ParameterSettings,
+    settings: HostSettings,
+  ) -> Arc<Self> {
+    Arc::new(Host {
+      client:            Client::new(parameter_settings),
+      communication:     Default::default(),
+      generation:        Default::default(),
+      control:           Default::default(),
+      recognition_event: Default::default(),
+      recognitions:      Default::default(),
+      model:             Default::default(),
+      collection:        Default::default(),
+      system:            Default::default(),
+      settings,
+    })
+  }
+
+  /// ### CLIENT
+  /// 
+  /// The [HSMS Client] used by the [Host], through which other procedures
+  /// such as the [Select Procedure] may be performed.
+  /// 
+  /// [HSMS Client]:      Client
+  /// [Host]:             Host
+  /// [Select Procedure]: Client::select
+  pub fn client(&self) -> &Arc<Client> {
+    &self.client
+  }
+
+  /// ### COMMUNICATION STATE
+  pub fn communication_state(&self) -> CommunicationState {
+    *self.communication.lock().unwrap()
+  }
+
+  /// ### EQUIPMENT CONTROL STATE
+  pub fn control_state(&self) -> EquipmentControlState {
+    *self.control.lock().unwrap()
+  }
+
+  /// ### NEXT MESSAGE ID
+  /// 
+  /// Provides a [Message ID] for a new primary message sent by the [Host],
+  /// incrementing its [System Bytes].
+  /// 
+  /// [Message ID]:   MessageID
+  /// [System Bytes]: MessageID::system
+  /// [Host]:         Host
+  pub fn next_id(&self) -> MessageID {
+    let mut system = self.system.lock().unwrap();
+    *system = system.wrapping_add(1);
+    MessageID {
+      session: self.settings.session,
+      system: *system,
+    }
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// 
+  /// Connects the [HSMS Client] to the equipment, as with its
+  /// [Connect Procedure].
+  /// 
+  /// Primary messages received from the equipment are handled by the [Host]
+  /// where it is able to, and the rest are provided by the returned
+  /// receiver, to be replied to with the [Reply Procedure].
+  /// 
+  /// Once the connection is lost, a [Communication Failure] occurs.
+  /// 
+  /// [HSMS Client]:           Client
+  /// [Connect Procedure]:     Client::connect
+  /// [Host]:                  Host
+  /// [Reply Procedure]:       Host::reply
+  /// [Communication Failure]: CommunicationState::communication_failure
+  pub fn connect(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<(SocketAddr, Receiver<(MessageID, Message)>), Error> {
+    let (socket, rx_receiver) = self.client.connect(entity)?;
+    let (data_sender, data_receiver) = channel::<(MessageID, Message)>();
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      for (id, message) in rx_receiver {
+        if let Some(unhandled) = clone.handle(id, message) {
+          if data_sender.send(unhandled).is_err() {break}
+        }
+      }
+      clone.communication_failure();
+    });
+    Ok((socket, data_receiver))
+  }
+
+  /// ### REPLY PROCEDURE
+  /// 
+  /// Sends a reply to a primary message provided by the
+  /// [Connect Procedure], using its [Message ID].
+  /// 
+  /// [Connect Procedure]: Host::connect
+  /// [Message ID]:        MessageID
+  pub fn reply(
+    &self,
+    id: MessageID,
+    message: impl Into<Message>,
+  ) -> JoinHandle<Result<Option<Message>, Error>> {
+    self.client.data(id, message.into())
+  }
+
+  /// ### RESPOND
+  /// 
+  /// Sends a reply to a primary message while it is being handled, on the
+  /// thread handling received messages, with the [Respond Procedure] of the
+  /// [HSMS Client] rather than with a thread of its own.
+  /// 
+  /// Results in whether the reply was sent, a failure to send it being a
+  /// [Communication Failure].
+  /// 
+  /// [HSMS Client]:           Client
+  /// [Respond Procedure]:     Client::respond
+  /// [Communication Failure]: CommunicationState::communication_failure
+  fn respond(&self, id: MessageID, message: impl Into<Message>) -> bool {
+    let sent: bool = self.client.respond(id, message.into()).is_ok();
+    if !sent {
+      self.communication_failure();
+    }
+    sent
+  }
+
+  /// ### ENABLE PROCEDURE
+  /// 
+  /// Enables communication, and while it remains enabled, repeatedly
+  /// attempts to establish communication by sending an [S1F13] whenever in
+  /// the [WAIT CRA] state, waiting for the Establish Communications Timeout
+  /// between failed attempts, and sends an [S1F1] every Are You There
+  /// Interval while communicating.
+  /// 
+  /// If so configured, the [Discover] procedure is performed each time
+  /// communication is established.
+  /// 
+  /// Does nothing if communication is already enabled.
+  /// 
+  /// Each call which enables communication starts a new attempt loop, and any
+  /// loop left over from before communication was last disabled exits
+  /// without acting on the state of the new one.
+  /// 
+  /// [S1F13]:    s1::HostCR
+  /// [S1F1]:     s1::AreYouThere
+  /// [WAIT CRA]: CommunicationState::WaitCRA
+  /// [Discover]: Host::discover
+  pub fn enable(self: &Arc<Self>) {
+    let generation: u64 = {
+      let mut communication = self.communication.lock().unwrap();
+      if !communication.enable() {return}
+      let mut generation = self.generation.lock().unwrap();
+      *generation = generation.wrapping_add(1);
+      *generation
+    };
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let mut communicating: bool = false;
+      loop {
+        let state: CommunicationState = {
+          let communication = clone.communication.lock().unwrap();
+          if !clone.is_current(generation) {break}
+          *communication
+        };
+        if state.is_communicating() && !communicating && clone.settings.discover {
+          clone.discover();
+        }
+        communicating = state.is_communicating();
+        match state {
+          CommunicationState::Disabled => break,
+          CommunicationState::WaitCRA => {
+            let result = clone.client.request(clone.next_id(), s1::HostCR(())).join();
+            let mut communication = clone.communication.lock().unwrap();
+            if !clone.is_current(generation) {break}
+            match result {
+              Ok(Ok(s1::EquipmentCRA((ack, _)))) => communication.receive_cra(ack),
+              _ => communication.cra_timeout(),
+            }
+          },
+          CommunicationState::WaitDelay => {
+            thread::sleep(clone.settings.establish_communications_timeout);
+            let mut communication = clone.communication.lock().unwrap();
+            if !clone.is_current(generation) {break}
+            communication.delay_expired();
+          },
+          CommunicationState::Communicating => {
+            thread::sleep(clone.settings.are_you_there_interval);
+            if !clone.communication_state().is_communicating() {continue}
+            match clone.request(s1::AreYouThere).join() {
+              Ok(Ok(_)) => {},
+              Ok(Err(error)) if error.kind() == ErrorKind::Interrupted => {},
+              _ if !clone.is_current(generation) => break,
+              _ => clone.communication_failure(),
+            }
+          },
+        }
+      }
+    });
+  }
+
+  /// ### DISABLE PROCEDURE
+  /// 
+  /// Disables communication, after which messages received from the
+  /// equipment are ignored.
+  pub fn disable(&self) {
+    self.communication.lock().unwrap().disable();
+    *self.control.lock().unwrap() = EquipmentControlState::Unknown;
+  }
+
+  /// ### IS CURRENT
+  /// 
+  /// Whether the attempt loop started when communication was enabled with
+  /// the given generation is still the current one.
+  fn is_current(&self, generation: u64) -> bool {
+    *self.generation.lock().unwrap() == generation
+  }
+
+  /// ### REQUEST
+  /// 
+  /// Performs the [Typed Data Procedure] with any [Primary Message], using
+  /// the [Next Message ID].
+  /// 
+  /// An error of kind [Interrupted], produced when the equipment aborts the
+  /// transaction, notes the equipment as [OFF-LINE], and any reply notes it
+  /// as [ON-LINE].
+  /// 
+  /// [Typed Data Procedure]: Client::request
+  /// [Primary Message]:      PrimaryMessage
+  /// [Next Message ID]:      Host::next_id
+  /// [Interrupted]:          ErrorKind::Interrupted
+  /// [OFF-LINE]:             EquipmentControlState::OffLine
+  /// [ON-LINE]:              EquipmentControlState::OnLine
+  pub fn request<P>(self: &Arc<Self>, message: P) -> JoinHandle<Result<P::Reply, Error>>
+  where
+    P: PrimaryMessage,
+    P::Reply: Send + 'static,
+  {
+    let request = self.client.request(self.next_id(), message);
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let result = request.join().map_err(|_| Error::from(ErrorKind::Other))?;
+      match &result {
+        Ok(_) => *clone.control.lock().unwrap() = EquipmentControlState::OnLine,
+        Err(error) if error.kind() == ErrorKind::Interrupted => {
+          *clone.control.lock().unwrap() = EquipmentControlState::OffLine
+        },
+        Err(_) => {},
+      }
+      result
+    })
+  }
+
+  /// ### REQUEST ON-LINE
+  /// 
+  /// Sends an [S1F17], noting the equipment as [ON-LINE] if accepted, and
+  /// as [OFF-LINE] if not allowed.
+  /// 
+  /// [S1F17]:    s1::RequestOnLine
+  /// [ON-LINE]:  EquipmentControlState::OnLine
+  /// [OFF-LINE]: EquipmentControlState::OffLine
+  pub fn request_on_line(self: &Arc<Self>) -> JoinHandle<Result<OnLineAcknowledge, Error>> {
+    let request = self.request(s1::RequestOnLine);
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let s1::OnLineAck(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      if let OnLineAcknowledge::NotAllowed = ack {
+        *clone.control.lock().unwrap() = EquipmentControlState::OffLine;
+      }
+      Ok(ack)
+    })
+  }
+
+  /// ### REQUEST OFF-LINE
+  /// 
+  /// Sends an [S1F15], noting the equipment as [OFF-LINE] once
+  /// acknowledged.
+  /// 
+  /// [S1F15]:    s1::RequestOffLine
+  /// [OFF-LINE]: EquipmentControlState::OffLine
+  pub fn request_off_line(self: &Arc<Self>) -> JoinHandle<Result<OffLineAcknowledge, Error>> {
+    let request = self.request(s1::RequestOffLine);
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let s1::OffLineAck(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      *clone.control.lock().unwrap() = EquipmentControlState::OffLine;
+      Ok(ack)
+    })
+  }
+
+  /// ### DEFINE REPORT
+  /// 
+  /// Sends an [S2F33].
+  /// 
+  /// [S2F33]: s2::DefineReport
+  pub fn define_report(
+    self: &Arc<Self>,
+    message: s2::DefineReport,
+  ) -> JoinHandle<Result<DefineReportAcknowledgeCode, Error>> {
+    let request = self.request(message);
+    thread::spawn(move || {
+      let s2::DefineReportAcknowledge(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      Ok(ack)
+    })
+  }
+
+  /// ### LINK EVENT REPORT
+  /// 
+  /// Sends an [S2F35].
+  /// 
+  /// [S2F35]: s2::LinkEventReport
+  pub fn link_event_report(
+    self: &Arc<Self>,
+    message: s2::LinkEventReport,
+  ) -> JoinHandle<Result<LinkReportAcknowledgeCode, Error>> {
+    let request = self.request(message);
+    thread::spawn(move || {
+      let s2::LinkEventReportAcknowledge(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      Ok(ack)
+    })
+  }
+
+  /// ### ENABLE/DISABLE EVENT REPORT
+  /// 
+  /// Sends an [S2F37].
+  /// 
+  /// [S2F37]: s2::EnableDisableEventReport
+  pub fn enable_disable_event_report(
+    self: &Arc<Self>,
+    message: s2::EnableDisableEventReport,
+  ) -> JoinHandle<Result<EnableDisableEventReportAcknowledgeCode, Error>> {
+    let request = self.request(message);
+    thread::spawn(move || {
+      let s2::EnableDisableEventReportAcknowledge(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      Ok(ack)
+    })
+  }
+
+  /// ### SET UP COLLECTION EVENTS
+  /// 
+  /// Defines reports with an [S2F33], links them to collection events with
+  /// an [S2F35], and enables those collection events with an [S2F37],
+  /// stopping at the first step the equipment refuses.
+  /// 
+  /// [S2F33]: s2::DefineReport
+  /// [S2F35]: s2::LinkEventReport
+  /// [S2F37]: s2::EnableDisableEventReport
+  pub fn setup_collection_events(
+    self: &Arc<Self>,
+    define: s2::DefineReport,
+    link: s2::LinkEventReport,
+  ) -> JoinHandle<Result<CollectionEventSetupAcknowledge, Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let join_error = |_| Error::from(ErrorKind::Other);
+      // S2F33: Define Report
+      let ack = clone.define_report(define).join().map_err(join_error)??;
+      if !matches!(ack, DefineReportAcknowledgeCode::Ok) {
+        return Ok(CollectionEventSetupAcknowledge::DefineReport(ack))
+      }
+      // S2F35: Link Event Report
+      let events: Vec<_> = (link.0).1.0.iter().map(|(event, _)| event.clone()).collect();
+      let ack = clone.link_event_report(link).join().map_err(join_error)??;
+      if !matches!(ack, LinkReportAcknowledgeCode::Ok) {
+        return Ok(CollectionEventSetupAcknowledge::LinkEventReport(ack))
+      }
+      // S2F37: Enable Event Report
+      let ack = clone.enable_disable_event_report(s2::EnableDisableEventReport::enable(events)).join().map_err(join_error)??;
+      if !matches!(ack, EnableDisableEventReportAcknowledgeCode::Ok) {
+        return Ok(CollectionEventSetupAcknowledge::EnableEventReport(ack))
+      }
+      Ok(CollectionEventSetupAcknowledge::Ok)
+    })
+  }
+
+  /// ### START DATA COLLECTION
+  /// 
+  /// Carries out a [Data Collection Plan], replacing any started before,
+  /// and stopping at the first step the equipment refuses.
+  /// 
+  /// Once started, the [S6F11] and [S6F1] messages resulting from the plan
+  /// are acknowledged and provided as [Collected Data] rather than by the
+  /// [Connect Procedure].
+  /// 
+  /// Fails with [InvalidInput] if the plan cannot be expressed in messages.
+  /// 
+  /// [Data Collection Plan]: DataCollectionPlan
+  /// [Collected Data]:       CollectedData
+  /// [Connect Procedure]:    Host::connect
+  /// [InvalidInput]:         ErrorKind::InvalidInput
+  /// [S6F1]:                 s6::TraceDataSend
+  /// [S6F11]:                s6::EventReport
+  pub fn start_data_collection(
+    self: &Arc<Self>,
+    plan: DataCollectionPlan,
+  ) -> JoinHandle<Result<DataCollectionStart, Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let join_error = |_| Error::from(ErrorKind::Other);
+      let invalid = |_| Error::from(ErrorKind::InvalidInput);
+      let define = plan.define_report().map_err(invalid)?;
+      let link = plan.link_event_report().map_err(invalid)?;
+      let traces = plan.trace_initialize(false).map_err(invalid)?;
+      *clone.collection.lock().unwrap() = None;
+      // S2F33: Delete All Reports
+      let ack = clone.define_report(s2::DefineReport::builder(DataID::U4(0)).build().map_err(invalid)?).join().map_err(join_error)??;
+      if !matches!(ack, DefineReportAcknowledgeCode::Ok) {
+        return Ok(DataCollectionStart::EventsRefused(CollectionEventSetupAcknowledge::DefineReport(ack)))
+      }
+      // S2F33, S2F35, S2F37: Set Up Collection Events
+      if !plan.events().is_empty() {
+        let ack = clone.setup_collection_events(define, link).join().map_err(join_error)??;
+        if !matches!(ack, CollectionEventSetupAcknowledge::Ok) {
+          return Ok(DataCollectionStart::EventsRefused(ack))
+        }
+      }
+      // S2F23: Trace Initialize
+      for trace in traces {
+        let id: TraceRequestID = trace.0.0.clone();
+        let s2::TraceInitializeAcknowledge(ack) = clone.request(trace).join().map_err(join_error)??;
+        if !matches!(ack, TraceInitializeAcknowledgeCode::Ok) {
+          return Ok(DataCollectionStart::TraceRefused(id, ack))
+        }
+      }
+      let (sender, receiver) = channel::<CollectedData>();
+      *clone.collection.lock().unwrap() = Some((plan, sender));
+      Ok(DataCollectionStart::Started(receiver))
+    })
+  }
+
+  /// ### STOP DATA COLLECTION
+  /// 
+  /// Undoes the [Data Collection Plan] most recently started, if any,
+  /// disabling its collection events with an [S2F37], deleting every report
+  /// with an [S2F33], and cancelling its traces with an [S2F23] each,
+  /// regardless of whether the equipment refuses any step.
+  /// 
+  /// [Data Collection Plan]: DataCollectionPlan
+  /// [S2F23]:                s2::TraceInitializeSend
+  /// [S2F33]:                s2::DefineReport
+  /// [S2F37]:                s2::EnableDisableEventReport
+  pub fn stop_data_collection(self: &Arc<Self>) -> JoinHandle<Result<(), Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let join_error = |_| Error::from(ErrorKind::Other);
+      let invalid = |_| Error::from(ErrorKind::InvalidInput);
+      let Some((plan, _)) = clone.collection.lock().unwrap().take() else {return Ok(())};
+      if !plan.events().is_empty() {
+        let events = plan.events().iter().map(|(event, _)| event.clone());
+        clone.enable_disable_event_report(s2::EnableDisableEventReport::disable(events)).join().map_err(join_error)??;
+        clone.define_report(s2::DefineReport::builder(DataID::U4(0)).build().map_err(invalid)?).join().map_err(join_error)??;
+      }
+      for trace in plan.trace_initialize(true).map_err(invalid)? {
+        clone.request(trace).join().map_err(join_error)??;
+      }
+      Ok(())
+    })
+  }
+
+  /// ### ENABLE ALARM
+  /// 
+  /// Sends an [S5F3] enabling the alarm.
+  /// 
+  /// [S5F3]: s5::EnableDisableAlarmSend
+  pub fn enable_alarm(
+    self: &Arc<Self>,
+    alarm: AlarmID,
+  ) -> JoinHandle<Result<s5::EnableDisableAlarmAcknowledge, Error>> {
+    self.request(s5::EnableDisableAlarmSend((AlarmEnableDisable::Enable, alarm)))
+  }
+
+  /// ### DISABLE ALARM
+  /// 
+  /// Sends an [S5F3] disabling the alarm.
+  /// 
+  /// [S5F3]: s5::EnableDisableAlarmSend
+  pub fn disable_alarm(
+    self: &Arc<Self>,
+    alarm: AlarmID,
+  ) -> JoinHandle<Result<s5::EnableDisableAlarmAcknowledge, Error>> {
+    self.request(s5::EnableDisableAlarmSend((AlarmEnableDisable::Disable, alarm)))
+  }
+
+  /// ### LIST ALARMS
+  /// 
+  /// Sends an [S5F5] for the alarms provided, or for all alarms if none are
+  /// provided.
+  /// 
+  /// [S5F5]: s5::ListAlarmsRequest
+  pub fn list_alarms(
+    self: &Arc<Self>,
+    alarms: impl IntoIterator<Item = AlarmID>,
+  ) -> JoinHandle<Result<s5::ListAlarmsData, Error>> {
+    self.request(s5::ListAlarmsRequest(VecList(alarms.into_iter().collect())))
+  }
+
+  /// ### LIST ENABLED ALARMS
+  /// 
+  /// Sends an [S5F7].
+  /// 
+  /// [S5F7]: s5::ListEnabledAlarmsRequest
+  pub fn list_enabled_alarms(
+    self: &Arc<Self>,
+  ) -> JoinHandle<Result<s5::ListEnabledAlarmsData, Error>> {
+    self.request(s5::ListEnabledAlarmsRequest)
+  }
+
+  /// ### HOST COMMAND
+  /// 
+  /// Sends an [S2F41].
+  /// 
+  /// [S2F41]: s2::HostCommandSend
+  pub fn host_command(
+    self: &Arc<Self>,
+    message: s2::HostCommandSend,
+  ) -> JoinHandle<Result<s2::HostCommandAcknowledge, Error>> {
+    self.request(message)
+  }
+
+  /// ### ENHANCED REMOTE COMMAND
+  /// 
+  /// Sends an [S2F49].
+  /// 
+  /// [S2F49]: s2::EnhancedRemoteCommand
+  pub fn enhanced_remote_command(
+    self: &Arc<Self>,
+    message: s2::EnhancedRemoteCommand,
+  ) -> JoinHandle<Result<s2::EnhancedRemoteCommandAcknowledge, Error>> {
+    self.request(message)
+  }
+
+  /// ### SEND PROCESS PROGRAM
+  /// 
+  /// Asks for permission to send a recipe with an [S7F1], and once granted,
+  /// sends it with an [S7F3].
+  /// 
+  /// [S7F1]: s7::ProcessProgramLoadInquire
+  /// [S7F3]: s7::ProcessProgramSend
+  pub fn send_process_program(
+    self: &Arc<Self>,
+    id: ProcessProgramID,
+    body: ProcessProgramBody,
+  ) -> JoinHandle<Result<RecipeTransfer, Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let length = Length::U4(body_length(&body).try_into().map_err(|_| Error::from(ErrorKind::InvalidInput))?);
+      let inquire = clone.request(s7::ProcessProgramLoadInquire((id.clone(), length)));
+      let s7::ProcessProgramLoadGrant(grant) = inquire.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      if !grant.is(ProcessProgramGrant::Ok) {
+        return Ok(RecipeTransfer::Refused(grant))
+      }
+      let send = clone.request(s7::ProcessProgramSend((id, body)));
+      let s7::ProcessProgramAcknowledge(ack) = send.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      Ok(RecipeTransfer::Sent(ack))
+    })
+  }
+
+  /// ### REQUEST PROCESS PROGRAM
+  /// 
+  /// Requests a recipe with an [S7F5], resulting in [None] if the request
+  /// was denied.
+  /// 
+  /// [S7F5]: s7::ProcessProgramRequest
+  pub fn request_process_program(
+    self: &Arc<Self>,
+    id: ProcessProgramID,
+  ) -> JoinHandle<Result<Option<ProcessProgramBody>, Error>> {
+    let request = self.request(s7::ProcessProgramRequest(id));
+    thread::spawn(move || {
+      let s7::ProcessProgramData(data) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      Ok(data.0.map(|(_, body)| body))
+    })
+  }
+
+  /// ### DOWNLOAD RECIPE
+  /// 
+  /// Asks for permission to send a recipe with an [S15F1], and once
+  /// granted, sends it with an [S15F27].
+  /// 
+  /// Results in an error of kind [PermissionDenied] if permission to send
+  /// the recipe is not granted.
+  /// 
+  /// [S15F1]:            s15::RecipeManagementMultiBlockInquire
+  /// [S15F27]:           s15::RecipeDownloadRequest
+  /// [PermissionDenied]: ErrorKind::PermissionDenied
+  pub fn download_recipe(
+    self: &Arc<Self>,
+    message: s15::RecipeDownloadRequest,
+  ) -> JoinHandle<Result<s15::RecipeDownloadAcknowledge, Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      let message: Message = message.into();
+      let length: usize = message.text.as_ref().map_or(0, Item::encoded_size);
+      let message = s15::RecipeDownloadRequest::try_from(message).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+      let inquire = clone.request(message.inquire(length));
+      let s15::RecipeManagementMultiBlockGrant(grant) = inquire.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      if !grant.is(RecipeManagementGrant::Granted) {
+        return Err(Error::from(ErrorKind::PermissionDenied))
+      }
+      clone.request(message).join().map_err(|_| Error::from(ErrorKind::Other))?
+    })
+  }
+
+  /// ### UPLOAD RECIPE
+  /// 
+  /// Requests a recipe with an [S15F31].
+  /// 
+  /// [S15F31]: s15::RecipeUploadRequest
+  pub fn upload_recipe(
+    self: &Arc<Self>,
+    spec: RecipeSpecifier,
+  ) -> JoinHandle<Result<s15::RecipeUploadData, Error>> {
+    self.request(s15::RecipeUploadRequest(spec))
+  }
+
+  /// ### DISCOVER
+  /// 
+  /// Builds an [Equipment Model] with an [S1F11], [S1F3], [S1F21], [S1F23],
+  /// and [S2F29], each requesting every variable or collection event, and
+  /// keeps it as the [Host]'s [Equipment Model].
+  /// 
+  /// [Equipment Model]: Host::equipment_model
+  /// [Host]:            Host
+  /// [S1F3]:            s1::SelectedEquipmentStatusRequest
+  /// [S1F11]:           s1::StatusVariableNamelistRequest
+  /// [S1F21]:           s1::DataVariableNamelistRequest
+  /// [S1F23]:           s1::CollectionEventNamelistRequest
+  /// [S2F29]:           s2::EquipmentConstantNamelistRequest
+  pub fn discover(self: &Arc<Self>) -> JoinHandle<Result<EquipmentModel, Error>> {
+    let clone: Arc<Host> = self.clone();
+    thread::spawn(move || {
+      fn join<T>(handle: JoinHandle<Result<T, Error>>) -> Result<T, Error> {
+        handle.join().map_err(|_| Error::from(ErrorKind::Other))?
+      }
+      fn text(chars: &[Char]) -> String {
+        Char::chars_to_str(chars)
+      }
+      let mut model = EquipmentModel::default();
+      let s1::StatusVariableNamelistReply(VecList(status)) = join(clone.request(s1::StatusVariableNamelistRequest(VecList(vec![]))))?;
+      let ids: Vec<StatusVariableID> = status.iter().map(|(id, _, _)| id.clone()).collect();
+      let s1::SelectedEquipmentStatusData(VecList(values)) = join(clone.request(s1::SelectedEquipmentStatusRequest(VecList(ids))))?;
+      for (index, (id, name, units)) in status.into_iter().enumerate() {
+        let format: Option<u8> = values.get(index).map(|value| format_code(&value.clone().into()));
+        model.status_variables.push((id, VariableDescription {name: text(&name.0), units: text(&units.0), format}));
+      }
+      let s1::DataVariableNamelist(VecList(data)) = join(clone.request(s1::DataVariableNamelistRequest(VecList(vec![]))))?;
+      for (id, name, units) in data {
+        model.data_variables.push((id, VariableDescription {name: text(&name.0), units: text(&units.0), format: None}));
+      }
+      let s1::CollectionEventNamelist(VecList(events)) = join(clone.request(s1::CollectionEventNamelistRequest(VecList(vec![]))))?;
+      for (id, name, VecList(variables)) in events {
+        model.collection_events.push((id, EventDescription {name: text(&name.0), variables}));
+      }
+      let s2::EquipmentConstantNamelist(VecList(constants)) = join(clone.request(s2::EquipmentConstantNamelistRequest(VecList(vec![]))))?;
+      for (id, name, minimum, maximum, default, units) in constants {
+        model.equipment_constants.push((id, ConstantDescription {
+          name: text(&name.0),
+          units: text(&units.0),
+          minimum: minimum.into(),
+          maximum: maximum.into(),
+          default: default.into(),
+        }));
+      }
+      *clone.model.lock().unwrap() = Some(model.clone());
+      Ok(model)
+    })
+  }
+
+  /// ### EQUIPMENT MODEL
+  /// 
+  /// The [Equipment Model] most recently discovered or provided, if any.
+  /// 
+  /// [Equipment Model]: EquipmentModel
+  pub fn equipment_model(&self) -> MutexGuard<'_, Option<EquipmentModel>> {
+    self.model.lock().unwrap()
+  }
+
+  /// ### SET EQUIPMENT MODEL
+  /// 
+  /// Provides an [Equipment Model], such as one previously saved, to be
+  /// used until the next discovery.
+  /// 
+  /// [Equipment Model]: EquipmentModel
+  pub fn set_equipment_model(&self, model: Option<EquipmentModel>) {
+    *self.model.lock().unwrap() = model;
+  }
+
+  /// ### DISPLAY
+  /// 
+  /// Sends an [S10F3] displaying text to the operator at a terminal, and
+  /// once it is accepted, waits for the operator to recognize it.
+  /// 
+  /// Results in an error of kind [ConnectionAborted] if communication is
+  /// lost before then.
+  /// 
+  /// [S10F3]:             s10::TerminalDisplaySingle
+  /// [ConnectionAborted]: ErrorKind::ConnectionAborted
+  pub fn display(
+    self: &Arc<Self>,
+    terminal: TerminalID,
+    text: Text,
+  ) -> JoinHandle<Result<OperatorAck, Error>> {
+    let (sender, receiver) = channel::<()>();
+    self.recognitions.lock().unwrap().push(sender);
+    let request = self.request(s10::TerminalDisplaySingle((terminal, text)));
+    thread::spawn(move || {
+      let s10::TerminalDisplaySingleAcknowledge(ack) = request.join().map_err(|_| Error::from(ErrorKind::Other))??;
+      if !ack.is_accepted() {
+        return Ok(OperatorAck::Refused(ack))
+      }
+      receiver.recv().map_err(|_| Error::from(ErrorKind::ConnectionAborted))?;
+      Ok(OperatorAck::Recognized)
+    })
+  }
+
+  /// ### MESSAGE RECOGNIZED
+  /// 
+  /// Notes that the operator has recognized the messages displayed,
+  /// completing every pending [Display].
+  /// 
+  /// [Display]: Host::display
+  pub fn message_recognized(&self) {
+    for sender in self.recognitions.lock().unwrap().drain(..) {
+      sender.send(()).ok();
+    }
+  }
+
+  /// ### SET MESSAGE RECOGNITION EVENT
+  /// 
+  /// Provides the [CEID] of the equipment's Message Recognition collection
+  /// event, upon whose [S6F11] the [Message Recognized] function is
+  /// performed automatically.
+  /// 
+  /// [CEID]:               CollectionEventID
+  /// [S6F11]:              s6::EventReport
+  /// [Message Recognized]: Host::message_recognized
+  pub fn set_message_recognition_event(&self, event: Option<CollectionEventID>) {
+    *self.recognition_event.lock().unwrap() = event;
+  }
+
+  /// ### COMMUNICATION FAILURE
+  fn communication_failure(&self) {
+    self.communication.lock().unwrap().communication_failure();
+    *self.control.lock().unwrap() = EquipmentControlState::Unknown;
+    self.recognitions.lock().unwrap().clear();
+  }
+
+  /// ### HANDLE
+  /// 
+  /// Replies to a primary message received from the equipment if it is
+  /// governed by the [Communication State] or is an [S1F1], and otherwise
+  /// returns it to be passed on.
+  /// 
+  /// An [S6F11] reporting the Message Recognition event is passed on after
+  /// performing the [Message Recognized] function, and an [S6F11] or [S6F1]
+  /// resulting from the [Data Collection Plan] is replied to and provided
+  /// as [Collected Data].
+  /// 
+  /// [Communication State]:  CommunicationState
+  /// [S1F1]:                 s1::AreYouThere
+  /// [S6F1]:                 s6::TraceDataSend
+  /// [S6F11]:                s6::EventReport
+  /// [Message Recognized]:   Host::message_recognized
+  /// [Data Collection Plan]: DataCollectionPlan
+  /// [Collected Data]:       CollectedData
+  fn handle(&self, id: MessageID, message: Message) -> Option<(MessageID, Message)> {
+    let (stream, function, w) = (message.stream, message.function, message.w);
+    // DISABLED: Ignore
+    if !self.communication_state().is_enabled() {
+      return None
+    }
+    // S1F13: Establish Communications Request
+    if (stream, function) == (1, 13) {
+      match s1::EquipmentCR::try_from(message) {
+        Ok(_) => {
+          let ack = self.communication.lock().unwrap().receive_cr();
+          if let Some(ack) = ack {
+            self.respond(id, s1::HostCRA((ack, ())));
+          }
+        },
+        Err(_) => {self.respond(id, Abort::for_stream(stream));},
+      }
+      return None
+    }
+    // NOT COMMUNICATING: Abort
+    if !self.communication_state().is_communicating() {
+      if w {self.respond(id, Abort::for_stream(stream));}
+      return None
+    }
+    // S1F1: Are You There
+    if (stream, function) == (1, 1) {
+      match s1::AreYouThere::try_from(message) {
+        Ok(_) => {
+          *self.control.lock().unwrap() = EquipmentControlState::OnLine;
+          self.respond(id, s1::OnLineDataHost(()));
+        },
+        Err(_) => {self.respond(id, Abort::for_stream(stream));},
+      }
+      return None
+    }
+    // S6F11: Message Recognition
+    if (stream, function) == (6, 11) {
+      // Only the event is read from the message, without copying the rest.
+      let recognized: bool = self.recognition_event.lock().unwrap().as_ref()
+        .is_some_and(|event| Some(event) == DataCollectionPlan::event_id(&message).as_ref());
+      if recognized {
+        self.message_recognized();
+      }
+    }
+    // S6F1, S6F11: Data Collection Plan
+    if let Some((plan, sender)) = self.collection.lock().unwrap().as_ref() {
+      if let Some(data) = plan.decode(&message) {
+        if sender.send(data).is_ok() {
+          let ack = CodedValue::Known(AcknowledgeCode6::Accepted);
+          if (stream, function, w) == (6, 11, true) {self.respond(id, s6::EventReportAcknowledge(ack));}
+          else if (stream, function, w) == (6, 1, true) {self.respond(id, s6::TraceDataAcknowledge(ack));}
+          return None
+        }
+      }
+    }
+    Some((id, message))
+  }
+}
@@ -0,0 +1,118 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # GENERIC EQUIPMENT MODEL (GEM)
+//! 
+//! Copyright © 2024 Nathaniel Hardesty, Licensed under the MIT License
+//! 
+//! This software is created by a third-party and not endorsed or supported by
+//! SEMI.
+//! 
+//! The codebase will be updated to reflect more up-to-date SEMI standards
+//! if/when they can be acquired for this purpose.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! **Based on:**
+//! - **[SEMI E30]**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! GEM defines the behavior expected of semiconductor manufacturing equipment
+//! when communicating with a host, on top of the messages defined by
+//! [SECS-II] ([SEMI E5]) and exchanged over a protocol such as [HSMS]
+//! ([SEMI E37]).
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! For ease of programming and extension, the functionality of the standard
+//! has been divided into a few subsets:
+//! 
+//! - [Communication State Model] - Tracks whether communication with the
+//!   host has been established.
+//! - [Control State Model] - Tracks whether the equipment is OFF-LINE or
+//!   ON-LINE, and if ON-LINE, whether it is under LOCAL or REMOTE control.
+//! - [Data Collection Plan] - Declares the variables to be reported with
+//!   collection events and the status variables to be traced, for the host
+//!   to arrange and to provide as a single stream of decoded data.
+//! - [Discovery] - Learns the variables and collection events made
+//!   available by the equipment, so that the host may refer to them by
+//!   name.
+//! - [Equipment] - Drives both state models over an [HSMS] connection,
+//!   replying to the Stream 1 messages they govern.
+//! - [Host] - Drives the host side of the Communication State Model over an
+//!   [HSMS] connection, tracking whether the equipment is ON-LINE, with
+//!   typed operations for event reports, alarms, and remote commands.
+//! - [Variables] - A registry of status variables, data variables,
+//!   equipment constants, and collection events, answering the host's
+//!   requests for them.
+//! - [Remote Control] - Dispatches commands from the host to the handlers
+//!   registered by the application.
+//! - [Recipe Management] - Keeps the recipes stored by the host in a
+//!   pluggable storage, verifying them and granting permission to send
+//!   large ones.
+//! - [Terminal Services] - Displays text from the host to the operator,
+//!   tracking whether the operator has recognized it.
+//! - [Trace Data Collection] - Samples status variables at a fixed period
+//!   at the host's request, reporting them in groups.
+//! - [Scenario] - Drives an [HSMS] connection from a declarative script of
+//!   expected messages and replies, for simulators and acceptance tests.
+//! - [Spooling] - Keeps primary messages selected by the host while
+//!   communication is lost, to be transmitted or purged once it resumes.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TODO
+//! 
+//! - Equipment side event reports and alarm management
+//! 
+//! [SEMI E5]:  https://store-us.semi.org/products/e00500-semi-e5-specification-for-semi-equipment-communications-standard-2-message-content-secs-ii
+//! [SEMI E30]: https://store-us.semi.org/products/e03000-semi-e30-specification-for-the-generic-model-for-communications-and-control-of-manufacturing-equipment-gem
+//! [SEMI E37]: https://store-us.semi.org/products/e03700-semi-e37-high-speed-secs-message-services-hsms-generic-services
+//! 
+//! [SECS-II]:                   semi_e5
+//! [HSMS]:                      semi_e37
+//! [Communication State Model]: communication
+//! [Control State Model]:       control
+//! [Data Collection Plan]:      collection
+//! [Discovery]:                 discovery
+//! [Equipment]:                 equipment
+//! [Host]:                      host
+//! [Recipe Management]:         recipe
+//! [Remote Control]:            remote
+//! [Scenario]:                  scenario
+//! [Spooling]:                  spool
+//! [Terminal Services]:         terminal
+//! [Variables]:                 variables
+//! [Trace Data Collection]:     trace
+
+pub mod collection;
+pub mod communication;
+pub mod control;
+pub mod discovery;
+pub mod equipment;
+pub mod host;
+pub mod recipe;
+pub mod remote;
+pub mod scenario;
+pub mod spool;
+pub mod terminal;
+pub mod trace;
+pub mod variables;
@@ -0,0 +1,715 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # RECIPE MANAGEMENT
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Recipe management allows the host to store recipes at the equipment, and
+//! to retrieve, list, and delete them, exchanged as unformatted process
+//! programs with the Stream 7 messages, or downloaded and uploaded as
+//! recipes with the Stream 15 messages.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Recipes are kept in a [Recipe Namespace], a named collection of recipes
+//! within a [Recipe Storage], which may be:
+//! 
+//! - [Memory Recipe Storage] - Lost when the equipment is restarted.
+//! - [Disk Recipe Storage] - Kept in a directory, one file per recipe,
+//!   along with a checksum verified whenever the recipe is retrieved.
+//! 
+//! Each recipe stored is first passed to the verifier registered by the
+//! application, if any, which may refuse it.
+//! 
+//! Recipes longer than the [Maximum Unsolicited Length] are only accepted
+//! once the host has been granted permission to send them with [S7F1] or
+//! [S15F1], as they may span many blocks. On the host side, the
+//! [Send Process Program] and [Download Recipe] functions of the [Host]
+//! always ask for permission first.
+//! 
+//! Recipes downloaded with [S15F27] are named by a [RCPSPEC], being either
+//! the recipe ID alone or the name of the [Recipe Namespace] followed by
+//! '>' and the recipe ID. Only the recipe body is kept; recipe attributes
+//! are neither kept nor provided.
+//! 
+//! The [Recipe Namespace] answers the following on its own, through its
+//! [Service] function:
+//! 
+//! - [S7F1] - With [S7F2].
+//! - [S7F3] - With [S7F4].
+//! - [S7F5] - With [S7F6].
+//! - [S7F17] - With [S7F18].
+//! - [S7F19] - With [S7F20].
+//! - [S15F1] - With [S15F2].
+//! - [S15F27] - With [S15F28].
+//! - [S15F31] - With [S15F32].
+//! 
+//! ## TODO
+//! 
+//! - Recipe management with the Stream 19 messages, which have yet to be
+//!   defined
+//! 
+//! [Recipe Namespace]:           RecipeNamespace
+//! [Recipe Storage]:             RecipeStorage
+//! [Memory Recipe Storage]:      MemoryRecipeStorage
+//! [Disk Recipe Storage]:        DiskRecipeStorage
+//! [Maximum Unsolicited Length]: RecipeSettings::max_unsolicited_length
+//! [Service]:                    RecipeNamespace::service
+//! [Host]:                       crate::host::Host
+//! [Send Process Program]:       crate::host::Host::send_process_program
+//! [Download Recipe]:            crate::host::Host::download_recipe
+//! [RCPSPEC]:                    RecipeSpecifier
+//! [S7F1]:                       s7::ProcessProgramLoadInquire
+//! [S7F2]:                       s7::ProcessProgramLoadGrant
+//! [S7F3]:                       s7::ProcessProgramSend
+//! [S7F4]:                       s7::ProcessProgramAcknowledge
+//! [S7F5]:                       s7::ProcessProgramRequest
+//! [S7F6]:                       s7::ProcessProgramData
+//! [S7F17]:                      s7::DeleteProcessProgramSend
+//! [S7F18]:                      s7::DeleteProcessProgramAcknowledge
+//! [S7F19]:                      s7::CurrentProcessProgramDirectoryRequest
+//! [S7F20]:                      s7::CurrentProcessProgramDirectoryData
+//! [S15F1]:                      s15::RecipeManagementMultiBlockInquire
+//! [S15F2]:                      s15::RecipeManagementMultiBlockGrant
+//! [S15F27]:                     s15::RecipeDownloadRequest
+//! [S15F28]:                     s15::RecipeDownloadAcknowledge
+//! [S15F31]:                     s15::RecipeUploadRequest
+//! [S15F32]:                     s15::RecipeUploadData
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  io::{Error, ErrorKind},
+  path::PathBuf,
+};
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    AcknowledgeCode7,
+    Char,
+    CodedValue,
+    DataLength,
+    ErrorCode,
+    ErrorText,
+    KnownErrorCode,
+    Length,
+    OptionItem,
+    ProcessProgramBody,
+    ProcessProgramGrant,
+    ProcessProgramID,
+    RecipeBody,
+    RecipeManagementAcknowledge,
+    RecipeManagementGrant,
+    RecipeSpecifier,
+    VecList,
+  },
+  messages::{Abort, s7, s15},
+};
+
+/// ## RECIPE STORAGE
+/// 
+/// The means by which a [Recipe Namespace] keeps its recipes, by ID.
+/// 
+/// [Recipe Namespace]: RecipeNamespace
+pub trait RecipeStorage: Send {
+  /// ### LIST
+  /// 
+  /// Provides the IDs of every recipe kept, in order.
+  fn list(&self) -> Result<Vec<String>, Error>;
+
+  /// ### LOAD
+  /// 
+  /// Provides a recipe, if it is kept.
+  fn load(&self, id: &str) -> Result<Option<ProcessProgramBody>, Error>;
+
+  /// ### STORE
+  /// 
+  /// Keeps a recipe, replacing any with the same ID.
+  fn store(&mut self, id: &str, body: &ProcessProgramBody) -> Result<(), Error>;
+
+  /// ### DELETE
+  /// 
+  /// Removes a recipe, resulting in whether it was kept.
+  fn delete(&mut self, id: &str) -> Result<bool, Error>;
+
+  /// ### CONTAINS
+  fn contains(&self, id: &str) -> Result<bool, Error> {
+    Ok(self.list()?.iter().any(|kept| kept == id))
+  }
+}
+
+/// ## MEMORY RECIPE STORAGE
+/// 
+/// A [Recipe Storage] keeping its recipes in memory.
+/// 
+/// [Recipe Storage]: RecipeStorage
+#[derive(Clone, Debug, Default)]
+pub struct MemoryRecipeStorage {
+  recipes: BTreeMap<String, ProcessProgramBody>,
+}
+impl RecipeStorage for MemoryRecipeStorage {
+  fn list(&self) -> Result<Vec<String>, Error> {
+    Ok(self.recipes.keys().cloned().collect())
+  }
+
+  fn load(&self, id: &str) -> Result<Option<ProcessProgramBody>, Error> {
+    Ok(self.recipes.get(id).cloned())
+  }
+
+  fn store(&mut self, id: &str, body: &ProcessProgramBody) -> Result<(), Error> {
+    self.recipes.insert(id.to_string(), body.clone());
+    Ok(())
+  }
+
+  fn delete(&mut self, id: &str) -> Result<bool, Error> {
+    Ok(self.recipes.remove(id).is_some())
+  }
+
+  fn contains(&self, id: &str) -> Result<bool, Error> {
+    Ok(self.recipes.contains_key(id))
+  }
+}
+
+/// ## DISK RECIPE STORAGE
+/// 
+/// A [Recipe Storage] keeping each of its recipes in a file within a
+/// directory, so that they survive the equipment being restarted.
+/// 
+/// Each file is named by the hexadecimal representation of the recipe's ID,
+/// and contains a 4-byte checksum of the recipe followed by its binary
+/// text. A recipe whose checksum does not match is reported as
+/// [Invalid Data] when loaded.
+/// 
+/// [Recipe Storage]: RecipeStorage
+/// [Invalid Data]:   ErrorKind::InvalidData
+#[derive(Clone, Debug)]
+pub struct DiskRecipeStorage {
+  directory: PathBuf,
+}
+impl DiskRecipeStorage {
+  /// ### OPEN
+  /// 
+  /// Opens the directory, creating it if it does not exist.
+  pub fn open(directory: impl Into<PathBuf>) -> Result<Self, Error> {
+    let directory: PathBuf = directory.into();
+    fs::create_dir_all(&directory)?;
+    Ok(DiskRecipeStorage {directory})
+  }
+
+  /// ### PATH
+  /// 
+  /// The file in which the recipe with an ID is kept.
+  fn path(&self, id: &str) -> PathBuf {
+    let name: String = id.bytes().map(|byte| format!("{byte:02x}")).collect();
+    self.directory.join(format!("{name}.rcp"))
+  }
+}
+impl RecipeStorage for DiskRecipeStorage {
+  fn list(&self) -> Result<Vec<String>, Error> {
+    let mut ids: Vec<String> = vec![];
+    for entry in fs::read_dir(&self.directory)? {
+      let entry = entry?;
+      if let Some(id) = entry.file_name().to_str().and_then(|name| name.strip_suffix(".rcp")).and_then(unhex) {
+        ids.push(id);
+      }
+    }
+    ids.sort();
+    Ok(ids)
+  }
+
+  fn load(&self, id: &str) -> Result<Option<ProcessProgramBody>, Error> {
+    let mut bytes: Vec<u8> = match fs::read(self.path(id)) {
+      Ok(bytes) => bytes,
+      Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+      Err(error) => return Err(error),
+    };
+    if bytes.len() < 4 {return Err(Error::from(ErrorKind::InvalidData))}
+    let text: Vec<u8> = bytes.split_off(4);
+    if bytes[..] != checksum(&text).to_be_bytes() {
+      return Err(Error::from(ErrorKind::InvalidData))
+    }
+    let item: Item = Item::try_from(text).map_err(|_| Error::from(ErrorKind::InvalidData))?;
+    Ok(Some(ProcessProgramBody::try_from(item).map_err(|_| Error::from(ErrorKind::InvalidData))?))
+  }
+
+  fn store(&mut self, id: &str, body: &ProcessProgramBody) -> Result<(), Error> {
+    let text: Vec<u8> = Item::from(body.clone()).into();
+    let mut bytes: Vec<u8> = checksum(&text).to_be_bytes().to_vec();
+    bytes.extend(text);
+    fs::write(self.path(id), bytes)
+  }
+
+  fn delete(&mut self, id: &str) -> Result<bool, Error> {
+    match fs::remove_file(self.path(id)) {
+      Ok(()) => Ok(true),
+      Err(error) if error.kind() == ErrorKind::NotFound => Ok(false),
+      Err(error) => Err(error),
+    }
+  }
+
+  fn contains(&self, id: &str) -> Result<bool, Error> {
+    Ok(self.path(id).is_file())
+  }
+}
+
+/// ## RECIPE VERIFIER
+/// 
+/// Verifies a recipe before it is stored, resulting in the code
+/// acknowledging it, where anything other than [Accepted] refuses it.
+/// 
+/// [Accepted]: AcknowledgeCode7::Accepted
+pub type RecipeVerifier = Box<dyn Fn(&str, &ProcessProgramBody) -> AcknowledgeCode7 + Send>;
+
+/// ## RECIPE SETTINGS
+/// 
+/// The configurable behavior of a [Recipe Namespace].
+/// 
+/// [Recipe Namespace]: RecipeNamespace
+#[derive(Clone, Copy, Debug)]
+pub struct RecipeSettings {
+  /// ### MAXIMUM RECIPES
+  /// 
+  /// The number of recipes which may be kept.
+  pub max_recipes: usize,
+
+  /// ### MAXIMUM LENGTH
+  /// 
+  /// The length of the longest recipe which may be kept, in bytes.
+  pub max_length: usize,
+
+  /// ### MAXIMUM UNSOLICITED LENGTH
+  /// 
+  /// The length of the longest recipe which may be sent without first
+  /// being granted permission, in bytes.
+  pub max_unsolicited_length: usize,
+}
+impl Default for RecipeSettings {
+  fn default() -> Self {
+    RecipeSettings {
+      max_recipes: usize::MAX,
+      max_length: usize::MAX,
+      max_unsolicited_length: usize::MAX,
+    }
+  }
+}
+
+/// ## RECIPE TRANSFER
+/// 
+/// The outcome of sending a recipe after asking for permission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecipeTransfer {
+  /// ### REFUSED
+  /// 
+  /// Permission to send the recipe was not granted.
+  Refused(CodedValue<ProcessProgramGrant>),
+
+  /// ### SENT
+  /// 
+  /// The recipe was sent, and acknowledged with the code provided.
+  Sent(CodedValue<AcknowledgeCode7>),
+}
+
+/// ## RECIPE NAMESPACE
+/// 
+/// A named collection of recipes kept in a [Recipe Storage].
+/// 
+/// [Recipe Storage]: RecipeStorage
+pub struct RecipeNamespace {
+  name: String,
+  settings: RecipeSettings,
+  storage: Box<dyn RecipeStorage>,
+  verifier: Option<RecipeVerifier>,
+  grants: Vec<(String, usize)>,
+}
+impl RecipeNamespace {
+  /// ### NEW RECIPE NAMESPACE
+  pub fn new(name: &str, storage: impl RecipeStorage + 'static, settings: RecipeSettings) -> Self {
+    RecipeNamespace {
+      name: name.to_string(),
+      settings,
+      storage: Box::new(storage),
+      verifier: None,
+      grants: vec![],
+    }
+  }
+
+  /// ### NAME
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// ### SET VERIFIER
+  pub fn set_verifier(
+    &mut self,
+    verifier: impl Fn(&str, &ProcessProgramBody) -> AcknowledgeCode7 + Send + 'static,
+  ) {
+    self.verifier = Some(Box::new(verifier));
+  }
+
+  /// ### LIST
+  /// 
+  /// Provides the IDs of every recipe kept, in order.
+  pub fn list(&self) -> Result<Vec<String>, Error> {
+    self.storage.list()
+  }
+
+  /// ### RETRIEVE
+  /// 
+  /// Provides a recipe, if it is kept.
+  pub fn retrieve(&self, id: &str) -> Result<Option<ProcessProgramBody>, Error> {
+    self.storage.load(id)
+  }
+
+  /// ### INQUIRE
+  /// 
+  /// Asks for permission to store a recipe of a length in bytes, as though
+  /// requested by an [S7F1], resulting in the [PPGNT] which would be sent in
+  /// reply.
+  /// 
+  /// Once granted, a recipe with the same ID up to the length may be
+  /// stored once, regardless of the [Maximum Unsolicited Length].
+  /// 
+  /// [S7F1]:                       s7::ProcessProgramLoadInquire
+  /// [PPGNT]:                      ProcessProgramGrant
+  /// [Maximum Unsolicited Length]: RecipeSettings::max_unsolicited_length
+  pub fn inquire(&mut self, id: &str, length: usize) -> ProcessProgramGrant {
+    if id.is_empty() {
+      return ProcessProgramGrant::InvalidPPID
+    }
+    if length > self.settings.max_length {
+      return ProcessProgramGrant::NoSpace
+    }
+    match self.storage.contains(id) {
+      Err(_) => return ProcessProgramGrant::WillNotAccept,
+      Ok(false) if self.is_full() => return ProcessProgramGrant::NoSpace,
+      Ok(_) => {},
+    }
+    self.grants.retain(|(granted, _)| granted != id);
+    self.grants.push((id.to_string(), length));
+    ProcessProgramGrant::Ok
+  }
+
+  /// ### STORE
+  /// 
+  /// Verifies and stores a recipe, as though requested by an [S7F3],
+  /// resulting in the [ACKC7] which would be sent in reply.
+  /// 
+  /// [S7F3]:  s7::ProcessProgramSend
+  /// [ACKC7]: AcknowledgeCode7
+  pub fn store(&mut self, id: &str, body: &ProcessProgramBody) -> AcknowledgeCode7 {
+    let ack: AcknowledgeCode7 = self.admit(id, body);
+    if ack != AcknowledgeCode7::Accepted {
+      return ack
+    }
+    if let Some(verifier) = &self.verifier {
+      let ack: AcknowledgeCode7 = verifier(id, body);
+      if ack != AcknowledgeCode7::Accepted {
+        return ack
+      }
+    }
+    match self.storage.store(id, body) {
+      Ok(()) => AcknowledgeCode7::Accepted,
+      Err(_) => AcknowledgeCode7::PermissionNotGranted,
+    }
+  }
+
+  /// ### DOWNLOAD
+  /// 
+  /// Verifies and stores a recipe, as though requested by an [S15F27],
+  /// resulting in the [RMACK] and errors which would be sent in reply.
+  /// 
+  /// Unless the recipe may be overwritten, a recipe with the same ID must
+  /// not already be kept. Recipe attributes are not kept.
+  /// 
+  /// [S15F27]: s15::RecipeDownloadRequest
+  /// [RMACK]:  RecipeManagementAcknowledge
+  pub fn download(
+    &mut self,
+    spec: &RecipeSpecifier,
+    overwrite: bool,
+    body: &RecipeBody,
+  ) -> (RecipeManagementAcknowledge, Vec<(ErrorCode, ErrorText)>) {
+    let id: String = match self.recipe_id(spec) {
+      Some(id) => id,
+      None => return denied(KnownErrorCode::UnknownObjectInObjectSpecifier, "unknown recipe namespace"),
+    };
+    let body: ProcessProgramBody = match body {
+      RecipeBody::Bin(bytes)   => ProcessProgramBody::Bin(bytes.clone()),
+      RecipeBody::Ascii(chars) => ProcessProgramBody::Ascii(chars.clone()),
+    };
+    if !overwrite && self.storage.contains(&id).unwrap_or(true) {
+      return denied(KnownErrorCode::ObjectIdentifierInUse, "recipe already exists")
+    }
+    match self.admit(&id, &body) {
+      AcknowledgeCode7::Accepted => {},
+      AcknowledgeCode7::MatrixOverflow => return denied(KnownErrorCode::NotAvailableForProcessing, "no space for recipe"),
+      AcknowledgeCode7::LengthError => return denied(KnownErrorCode::InvalidParameter, "recipe too long"),
+      _ => return denied(KnownErrorCode::NotAvailableForProcessing, "permission not granted"),
+    }
+    if let Some(verifier) = &self.verifier {
+      if verifier(&id, &body) != AcknowledgeCode7::Accepted {
+        return denied(KnownErrorCode::VerificationError, "recipe verification failed")
+      }
+    }
+    match self.storage.store(&id, &body) {
+      Ok(()) => (RecipeManagementAcknowledge::Completed, vec![]),
+      Err(_) => denied(KnownErrorCode::NotAvailableForProcessing, "recipe could not be stored"),
+    }
+  }
+
+  /// ### UPLOAD
+  /// 
+  /// Provides a recipe, as though requested by an [S15F31], along with the
+  /// [RMACK] and errors which would be sent in reply.
+  /// 
+  /// Recipes kept in a format other than binary or ASCII, as may be stored
+  /// with an [S7F3], cannot be provided.
+  /// 
+  /// [S15F31]: s15::RecipeUploadRequest
+  /// [S7F3]:   s7::ProcessProgramSend
+  /// [RMACK]:  RecipeManagementAcknowledge
+  pub fn upload(
+    &self,
+    spec: &RecipeSpecifier,
+  ) -> (Option<RecipeBody>, RecipeManagementAcknowledge, Vec<(ErrorCode, ErrorText)>) {
+    let body: Option<ProcessProgramBody> = match self.recipe_id(spec) {
+      Some(id) => self.storage.load(&id).ok().flatten(),
+      None => {
+        let (ack, errors) = denied(KnownErrorCode::UnknownObjectInObjectSpecifier, "unknown recipe namespace");
+        return (None, ack, errors)
+      },
+    };
+    let (ack, errors) = match body {
+      Some(ProcessProgramBody::Bin(bytes))   => return (Some(RecipeBody::Bin(bytes)), RecipeManagementAcknowledge::Completed, vec![]),
+      Some(ProcessProgramBody::Ascii(chars)) => return (Some(RecipeBody::Ascii(chars)), RecipeManagementAcknowledge::Completed, vec![]),
+      Some(_) => denied(KnownErrorCode::UnsupportedOptionRequested, "recipe format not supported"),
+      None    => denied(KnownErrorCode::UnknownObjectInstance, "unknown recipe"),
+    };
+    (None, ack, errors)
+  }
+
+  /// ### DELETE
+  /// 
+  /// Deletes recipes, or every recipe if none are specified, as though
+  /// requested by an [S7F17], resulting in the [ACKC7] which would be sent
+  /// in reply.
+  /// 
+  /// If any recipe is not kept, none are deleted.
+  /// 
+  /// [S7F17]: s7::DeleteProcessProgramSend
+  /// [ACKC7]: AcknowledgeCode7
+  pub fn delete(&mut self, ids: &[String]) -> AcknowledgeCode7 {
+    let ids: Vec<String> = match ids.is_empty() {
+      true => match self.storage.list() {
+        Ok(ids) => ids,
+        Err(_) => return AcknowledgeCode7::PermissionNotGranted,
+      },
+      false => ids.to_vec(),
+    };
+    for id in &ids {
+      if !self.storage.contains(id).unwrap_or(false) {
+        return AcknowledgeCode7::PPIDNotFound
+      }
+    }
+    for id in &ids {
+      if self.storage.delete(id).is_err() {
+        return AcknowledgeCode7::PermissionNotGranted
+      }
+    }
+    AcknowledgeCode7::Accepted
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to an [S7F1], [S7F3], [S7F5], [S7F17], [S7F19],
+  /// [S15F1], [S15F27], or [S15F31], or an Abort Transaction if it cannot
+  /// be understood.
+  /// 
+  /// Results in [None] if the message is not one the recipe namespace
+  /// answers.
+  /// 
+  /// [S7F1]:   s7::ProcessProgramLoadInquire
+  /// [S7F3]:   s7::ProcessProgramSend
+  /// [S7F5]:   s7::ProcessProgramRequest
+  /// [S7F17]:  s7::DeleteProcessProgramSend
+  /// [S7F19]:  s7::CurrentProcessProgramDirectoryRequest
+  /// [S15F1]:  s15::RecipeManagementMultiBlockInquire
+  /// [S15F27]: s15::RecipeDownloadRequest
+  /// [S15F31]: s15::RecipeUploadRequest
+  pub fn service(&mut self, message: &Message) -> Option<Message> {
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (7, 1) => s7::ProcessProgramLoadInquire::try_from(message.clone()).ok().map(|request| {
+        let (id, length) = request.0;
+        let grant = match length_value(&length) {
+          Some(length) => self.inquire(&Char::chars_to_str(id.read()), length),
+          None => ProcessProgramGrant::WillNotAccept,
+        };
+        s7::ProcessProgramLoadGrant(CodedValue::Known(grant)).into()
+      }),
+      (7, 3) => s7::ProcessProgramSend::try_from(message.clone()).ok().map(|request| {
+        let (id, body) = request.0;
+        let ack = self.store(&Char::chars_to_str(id.read()), &body);
+        s7::ProcessProgramAcknowledge(CodedValue::Known(ack)).into()
+      }),
+      (7, 5) => s7::ProcessProgramRequest::try_from(message.clone()).ok().map(|request| {
+        let body = self.retrieve(&Char::chars_to_str(request.0.read())).ok().flatten();
+        s7::ProcessProgramData(OptionItem(body.map(|body| (request.0, body)))).into()
+      }),
+      (7, 17) => s7::DeleteProcessProgramSend::try_from(message.clone()).ok().map(|request| {
+        let ids: Vec<String> = request.0.0.iter().map(|id| Char::chars_to_str(id.read())).collect();
+        let ack = self.delete(&ids);
+        s7::DeleteProcessProgramAcknowledge(CodedValue::Known(ack)).into()
+      }),
+      (7, 19) => s7::CurrentProcessProgramDirectoryRequest::try_from(message.clone()).ok().map(|_| {
+        let ids = self.list().unwrap_or_default().iter()
+          .filter_map(|id| ProcessProgramID::new(Char::safe_str_to_chars(id)).ok())
+          .collect();
+        s7::CurrentProcessProgramDirectoryData(VecList(ids)).into()
+      }),
+      (15, 1) => s15::RecipeManagementMultiBlockInquire::try_from(message.clone()).ok().map(|request| {
+        let (_, spec, length) = request.0;
+        let grant = match (self.recipe_id(&spec), data_length_value(&length)) {
+          (Some(id), Some(length)) => match self.inquire(&id, length) {
+            ProcessProgramGrant::Ok      => RecipeManagementGrant::Granted,
+            ProcessProgramGrant::NoSpace => RecipeManagementGrant::NoSpaceAvailable,
+            _                            => RecipeManagementGrant::Busy,
+          },
+          _ => RecipeManagementGrant::Busy,
+        };
+        s15::RecipeManagementMultiBlockGrant(CodedValue::Known(grant)).into()
+      }),
+      (15, 27) => s15::RecipeDownloadRequest::try_from(message.clone()).ok().map(|request| {
+        let (_, overwrite, spec, _, body) = request.0;
+        let (ack, errors) = self.download(&spec, overwrite.0, &body);
+        s15::RecipeDownloadAcknowledge((spec, VecList(vec![]), (CodedValue::Known(ack), VecList(errors)))).into()
+      }),
+      (15, 31) => s15::RecipeUploadRequest::try_from(message.clone()).ok().map(|request| {
+        let (body, ack, errors) = self.upload(&request.0);
+        let body: RecipeBody = body.unwrap_or(RecipeBody::Bin(vec![]));
+        s15::RecipeUploadData((request.0, VecList(vec![]), body, (CodedValue::Known(ack), VecList(errors)))).into()
+      }),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(message.stream).into()))
+
+  }
+
+  /// Checks whether a recipe may be stored, consuming any grant for it.
+  fn admit(&mut self, id: &str, body: &ProcessProgramBody) -> AcknowledgeCode7 {
+    let length: usize = body_length(body);
+    let grant: Option<usize> = self.grants.iter().position(|(granted, _)| granted == id).map(|index| self.grants.remove(index).1);
+    match grant {
+      Some(granted) if length > granted => return AcknowledgeCode7::LengthError,
+      None if length > self.settings.max_unsolicited_length => return AcknowledgeCode7::PermissionNotGranted,
+      _ => {},
+    }
+    if length > self.settings.max_length {
+      return AcknowledgeCode7::LengthError
+    }
+    match self.storage.contains(id) {
+      Err(_) => AcknowledgeCode7::PermissionNotGranted,
+      Ok(false) if self.is_full() => AcknowledgeCode7::MatrixOverflow,
+      Ok(_) => AcknowledgeCode7::Accepted,
+    }
+  }
+
+  /// The ID of the recipe named by a [RCPSPEC], being either the recipe ID
+  /// alone or the name of the recipe namespace followed by '>' and the
+  /// recipe ID.
+  /// 
+  /// [RCPSPEC]: RecipeSpecifier
+  fn recipe_id(&self, spec: &RecipeSpecifier) -> Option<String> {
+    let spec: String = Char::chars_to_str(spec.read());
+    match spec.rsplit_once('>') {
+      Some((namespace, id)) if namespace == self.name => Some(id.to_string()),
+      Some(_) => None,
+      None => Some(spec),
+    }
+  }
+
+  fn is_full(&self) -> bool {
+    self.storage.list().map_or(true, |ids| ids.len() >= self.settings.max_recipes)
+  }
+}
+
+/// ## BODY LENGTH
+/// 
+/// The length of a recipe, in bytes.
+pub fn body_length(body: &ProcessProgramBody) -> usize {
+  match body {
+    ProcessProgramBody::Bin(values)   => values.len(),
+    ProcessProgramBody::Ascii(values) => values.len(),
+    ProcessProgramBody::I1(values)    => values.len(),
+    ProcessProgramBody::I2(values)    => values.len() * 2,
+    ProcessProgramBody::I4(values)    => values.len() * 4,
+    ProcessProgramBody::I8(values)    => values.len() * 8,
+    ProcessProgramBody::U1(values)    => values.len(),
+    ProcessProgramBody::U2(values)    => values.len() * 2,
+    ProcessProgramBody::U4(values)    => values.len() * 4,
+    ProcessProgramBody::U8(values)    => values.len() * 8,
+  }
+}
+
+fn length_value(length: &Length) -> Option<usize> {
+  match *length {
+    Length::I1(value) => value.try_into().ok(),
+    Length::I2(value) => value.try_into().ok(),
+    Length::I4(value) => value.try_into().ok(),
+    Length::I8(value) => value.try_into().ok(),
+    Length::U1(value) => Some(value.into()),
+    Length::U2(value) => Some(value.into()),
+    Length::U4(value) => value.try_into().ok(),
+    Length::U8(value) => value.try_into().ok(),
+  }
+}
+
+fn data_length_value(length: &DataLength) -> Option<usize> {
+  match *length {
+    DataLength::I1(value) => value.try_into().ok(),
+    DataLength::I2(value) => value.try_into().ok(),
+    DataLength::I4(value) => value.try_into().ok(),
+    DataLength::I8(value) => value.try_into().ok(),
+    DataLength::U1(value) => Some(value.into()),
+    DataLength::U2(value) => Some(value.into()),
+    DataLength::U4(value) => value.try_into().ok(),
+    DataLength::U8(value) => value.try_into().ok(),
+  }
+}
+
+/// The [RMACK] and error reported when a recipe operation is refused.
+/// 
+/// [RMACK]: RecipeManagementAcknowledge
+fn denied(code: KnownErrorCode, text: &str) -> (RecipeManagementAcknowledge, Vec<(ErrorCode, ErrorText)>) {
+  let text: ErrorText = ErrorText::new_from_str(text).expect("ERRTEXT within length");
+  (RecipeManagementAcknowledge::Denied, vec![(ErrorCode::Known(code), text)])
+}
+
+/// FNV-1a, used to detect corruption of recipes kept on disk.
+fn checksum(bytes: &[u8]) -> u32 {
+  bytes.iter().fold(0x811c9dc5, |hash: u32, byte| (hash ^ *byte as u32).wrapping_mul(0x01000193))
+}
+
+fn unhex(name: &str) -> Option<String> {
+  if name.len() % 2 != 0 {return None}
+  let bytes: Option<Vec<u8>> = (0..name.len()).step_by(2).map(|index| u8::from_str_radix(name.get(index..index + 2)?, 16).ok()).collect();
+  String::from_utf8(bytes?).ok()
+}
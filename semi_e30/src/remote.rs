@@ -0,0 +1,440 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # REMOTE CONTROL
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Remote control allows the host to instruct the equipment to perform
+//! commands, with [S2F41] or, addressed to a particular object, with
+//! [S2F49].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The application registers a handler for each [RCMD] with the
+//! [Remote Command Dispatcher], optionally for a particular [OBJSPEC]. The
+//! dispatcher decodes the parameters of each command into a map of
+//! [Command Parameters], invokes the handler, and assembles the reply from
+//! the [Command Response] it provides.
+//! 
+//! Commands and parameter names are identified by their text, where ASCII,
+//! or otherwise by their number written in decimal.
+//! 
+//! [S2F41]:                     s2::HostCommandSend
+//! [S2F49]:                     s2::EnhancedRemoteCommand
+//! [RCMD]:                      RemoteCommand
+//! [OBJSPEC]:                   ObjectSpecifier
+//! [Remote Command Dispatcher]: RemoteCommandDispatcher
+//! [Command Parameters]:        CommandParameters
+//! [Command Response]:          CommandResponse
+
+use std::collections::HashMap;
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    Char,
+    CommandEnhancedParameterAcknowledge,
+    CommandEnhancedParameterAcknowledgeCode,
+    CommandParameterAcknowledgeCode,
+    CommandParameterName,
+    HostCommandAcknowledgeCode,
+    ObjectSpecifier,
+    RemoteCommand,
+    VecList,
+  },
+  messages::{Abort, s2},
+};
+
+/// ## COMMAND HANDLER
+/// 
+/// Performs a command, or begins to, resulting in the [Command Response]
+/// sent to the host.
+/// 
+/// Handlers are invoked while the reply is awaited, so commands which take
+/// time to complete should be started in the background and acknowledged
+/// with [To Be Completed].
+/// 
+/// [Command Response]: CommandResponse
+/// [To Be Completed]:  HostCommandAcknowledgeCode::ToBeCompleted
+pub type CommandHandler = Box<dyn Fn(&CommandRequest) -> CommandResponse + Send>;
+
+/// ## COMMAND REQUEST
+/// 
+/// A command received from the host.
+#[derive(Clone, Debug)]
+pub struct CommandRequest {
+  /// ### OBJECT
+  /// 
+  /// The [OBJSPEC] the command is addressed to, if received with [S2F49].
+  /// 
+  /// [OBJSPEC]: ObjectSpecifier
+  /// [S2F49]:   s2::EnhancedRemoteCommand
+  pub object: Option<String>,
+
+  /// ### COMMAND
+  pub command: String,
+
+  /// ### PARAMETERS
+  pub parameters: CommandParameters,
+}
+
+/// ## COMMAND PARAMETERS
+/// 
+/// The [CPVAL]s or [CEPVAL]s of a command, keyed by [CPNAME].
+/// 
+/// [CPNAME]: CommandParameterName
+/// [CPVAL]:  semi_e5::items::CommandParameterValue
+/// [CEPVAL]: semi_e5::items::CommandEnhancedParameterValue
+#[derive(Clone, Debug, Default)]
+pub struct CommandParameters {
+  names: Vec<(String, CommandParameterName)>,
+  values: HashMap<String, Item>,
+}
+impl CommandParameters {
+  /// ### INSERT
+  fn insert(&mut self, name: CommandParameterName, value: Item) {
+    let text: String = parameter_name(&name);
+    if self.values.insert(text.clone(), value).is_none() {
+      self.names.push((text, name));
+    }
+  }
+
+  /// ### GET
+  /// 
+  /// The value of a parameter, converted to the type requested.
+  /// 
+  /// Results in [None] if the parameter is absent or its value cannot be
+  /// converted.
+  pub fn get<T: TryFrom<Item>>(&self, name: &str) -> Option<T> {
+    T::try_from(self.values.get(name)?.clone()).ok()
+  }
+
+  /// ### ITEM
+  /// 
+  /// The value of a parameter, as received.
+  pub fn item(&self, name: &str) -> Option<&Item> {
+    self.values.get(name)
+  }
+
+  /// ### CONTAINS
+  pub fn contains(&self, name: &str) -> bool {
+    self.values.contains_key(name)
+  }
+
+  /// ### NAMES
+  /// 
+  /// The names of the parameters, in the order received.
+  pub fn names(&self) -> impl Iterator<Item = &str> {
+    self.names.iter().map(|(text, _)| text.as_str())
+  }
+
+  /// ### LENGTH
+  pub fn len(&self) -> usize {
+    self.names.len()
+  }
+
+  /// ### IS EMPTY
+  pub fn is_empty(&self) -> bool {
+    self.names.is_empty()
+  }
+
+  /// ### CPNAME
+  /// 
+  /// The [CPNAME] of a parameter as received, or otherwise written in ASCII.
+  /// 
+  /// [CPNAME]: CommandParameterName
+  fn cpname(&self, name: &str) -> CommandParameterName {
+    self.names.iter()
+      .find(|(text, _)| text == name)
+      .map(|(_, cpname)| cpname.clone())
+      .unwrap_or_else(|| CommandParameterName::Ascii(Char::safe_str_to_chars(name)))
+  }
+}
+
+/// ## PARAMETER ERROR
+/// 
+/// The reason a parameter of a command was not accepted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterError {
+  /// ### NAME DOES NOT EXIST
+  NameDoesNotExist,
+
+  /// ### ILLEGAL VALUE
+  IllegalValue,
+
+  /// ### ILLEGAL FORMAT
+  IllegalFormat,
+
+  /// ### NAME NOT VALID AS USED
+  /// 
+  /// Only reported as such with [S2F50], and as an [Illegal Value]
+  /// otherwise.
+  /// 
+  /// [S2F50]:         s2::EnhancedRemoteCommandAcknowledge
+  /// [Illegal Value]: ParameterError::IllegalValue
+  NameNotValidAsUsed,
+}
+impl From<ParameterError> for CommandParameterAcknowledgeCode {
+  fn from(error: ParameterError) -> Self {
+    match error {
+      ParameterError::NameDoesNotExist => Self::ParameterNameDoesNotExist,
+      ParameterError::IllegalValue => Self::IllegalValue,
+      ParameterError::IllegalFormat => Self::IllegalFormat,
+      ParameterError::NameNotValidAsUsed => Self::IllegalValue,
+    }
+  }
+}
+impl From<ParameterError> for CommandEnhancedParameterAcknowledgeCode {
+  fn from(error: ParameterError) -> Self {
+    match error {
+      ParameterError::NameDoesNotExist => Self::ParameterNameDoesNotExist,
+      ParameterError::IllegalValue => Self::IllegalValue,
+      ParameterError::IllegalFormat => Self::IllegalFormat,
+      ParameterError::NameNotValidAsUsed => Self::ParameterNameNotValidAsUsed,
+    }
+  }
+}
+
+/// ## COMMAND RESPONSE
+/// 
+/// The outcome of a command, along with any parameters which were not
+/// accepted.
+#[derive(Clone, Debug)]
+pub struct CommandResponse {
+  pub ack: HostCommandAcknowledgeCode,
+  pub parameters: Vec<(String, ParameterError)>,
+}
+impl CommandResponse {
+  /// ### NEW COMMAND RESPONSE
+  pub fn new(ack: HostCommandAcknowledgeCode) -> Self {
+    CommandResponse {ack, parameters: vec![]}
+  }
+
+  /// ### OK
+  /// 
+  /// The command has been performed.
+  pub fn ok() -> Self {
+    Self::new(HostCommandAcknowledgeCode::Ok)
+  }
+
+  /// ### PARAMETER ERROR
+  /// 
+  /// Reports a parameter which was not accepted, in which case an
+  /// [Ok] acknowledgement becomes [Parameter Invalid].
+  /// 
+  /// [Ok]:                HostCommandAcknowledgeCode::Ok
+  /// [Parameter Invalid]: HostCommandAcknowledgeCode::ParameterInvalid
+  pub fn parameter_error(mut self, name: &str, error: ParameterError) -> Self {
+    if let HostCommandAcknowledgeCode::Ok = self.ack {
+      self.ack = HostCommandAcknowledgeCode::ParameterInvalid;
+    }
+    self.parameters.push((name.to_string(), error));
+    self
+  }
+}
+
+/// ## REMOTE COMMAND DISPATCHER
+/// 
+/// Invokes the [Command Handler] registered for each command received from
+/// the host, and assembles the reply.
+/// 
+/// [Command Handler]: CommandHandler
+#[derive(Default)]
+pub struct RemoteCommandDispatcher {
+  commands: HashMap<String, CommandHandler>,
+  object_commands: HashMap<(String, String), CommandHandler>,
+}
+impl RemoteCommandDispatcher {
+  /// ### NEW REMOTE COMMAND DISPATCHER
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### REGISTER
+  /// 
+  /// Registers the handler of a command, invoked for an [S2F41], and for an
+  /// [S2F49] addressed to an object with no handler of its own registered
+  /// for the command.
+  /// 
+  /// [S2F41]: s2::HostCommandSend
+  /// [S2F49]: s2::EnhancedRemoteCommand
+  pub fn register(
+    &mut self,
+    command: &str,
+    handler: impl Fn(&CommandRequest) -> CommandResponse + Send + 'static,
+  ) {
+    self.commands.insert(command.to_string(), Box::new(handler));
+  }
+
+  /// ### REGISTER OBJECT COMMAND
+  /// 
+  /// Registers the handler of a command addressed to a particular object
+  /// with [S2F49].
+  /// 
+  /// [S2F49]: s2::EnhancedRemoteCommand
+  pub fn register_object(
+    &mut self,
+    object: &str,
+    command: &str,
+    handler: impl Fn(&CommandRequest) -> CommandResponse + Send + 'static,
+  ) {
+    self.object_commands.insert((object.to_string(), command.to_string()), Box::new(handler));
+  }
+
+  /// ### DISPATCH
+  /// 
+  /// Invokes the handler registered for a command, resulting in
+  /// [Command Does Not Exist] if there is none.
+  /// 
+  /// [Command Does Not Exist]: HostCommandAcknowledgeCode::CommandDoesNotExist
+  pub fn dispatch(&self, request: &CommandRequest) -> CommandResponse {
+    let handler: Option<&CommandHandler> = request.object.as_ref()
+      .and_then(|object| self.object_commands.get(&(object.clone(), request.command.clone())))
+      .or_else(|| self.commands.get(&request.command));
+    match handler {
+      Some(handler) => handler(request),
+      None => CommandResponse::new(HostCommandAcknowledgeCode::CommandDoesNotExist),
+    }
+  }
+
+  /// ### HOST COMMAND
+  /// 
+  /// Answers an [S2F41].
+  /// 
+  /// [S2F41]: s2::HostCommandSend
+  pub fn host_command(&self, message: s2::HostCommandSend) -> s2::HostCommandAcknowledge {
+    let (command, VecList(pairs)) = message.0;
+    let mut parameters = CommandParameters::default();
+    for (name, value) in pairs {
+      parameters.insert(name, value.into());
+    }
+    let request = CommandRequest {
+      object: None,
+      command: command_name(&command),
+      parameters,
+    };
+    let response: CommandResponse = self.dispatch(&request);
+    s2::HostCommandAcknowledge((
+      response.ack,
+      VecList(response.parameters.iter().map(|(name, error)| {
+        (request.parameters.cpname(name), (*error).into())
+      }).collect()),
+    ))
+  }
+
+  /// ### ENHANCED REMOTE COMMAND
+  /// 
+  /// Answers an [S2F49].
+  /// 
+  /// [S2F49]: s2::EnhancedRemoteCommand
+  pub fn enhanced_remote_command(&self, message: s2::EnhancedRemoteCommand) -> s2::EnhancedRemoteCommandAcknowledge {
+    let (_data_id, ObjectSpecifier(object), command, VecList(pairs)) = message.0;
+    let mut parameters = CommandParameters::default();
+    for (name, value) in pairs {
+      parameters.insert(name, value.into());
+    }
+    let request = CommandRequest {
+      object: Some(Char::chars_to_str(&object)),
+      command: command_name(&command),
+      parameters,
+    };
+    let response: CommandResponse = self.dispatch(&request);
+    s2::EnhancedRemoteCommandAcknowledge((
+      response.ack,
+      VecList(response.parameters.iter().map(|(name, error)| {
+        (
+          request.parameters.cpname(name),
+          CommandEnhancedParameterAcknowledge::Single((*error).into()),
+        )
+      }).collect()),
+    ))
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to an [S2F41] or [S2F49], or an Abort Transaction
+  /// if it cannot be understood.
+  /// 
+  /// While the equipment is not under REMOTE control, commands are refused
+  /// with [Cannot Perform Now] without invoking their handlers.
+  /// 
+  /// Results in [None] if the message is not one the dispatcher answers.
+  /// 
+  /// [S2F41]:              s2::HostCommandSend
+  /// [S2F49]:              s2::EnhancedRemoteCommand
+  /// [Cannot Perform Now]: HostCommandAcknowledgeCode::CannotPerformNow
+  pub fn service(&self, message: &Message, remote: bool) -> Option<Message> {
+    let stream: u8 = message.stream;
+    let refused = HostCommandAcknowledgeCode::CannotPerformNow;
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (2, 41) => s2::HostCommandSend::try_from(message.clone()).ok().map(|request| {
+        match remote {
+          true => self.host_command(request).into(),
+          false => s2::HostCommandAcknowledge((refused, VecList(vec![]))).into(),
+        }
+      }),
+      (2, 49) => s2::EnhancedRemoteCommand::try_from(message.clone()).ok().map(|request| {
+        match remote {
+          true => self.enhanced_remote_command(request).into(),
+          false => s2::EnhancedRemoteCommandAcknowledge((refused, VecList(vec![]))).into(),
+        }
+      }),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(stream).into()))
+  }
+}
+
+/// ## COMMAND NAME
+/// 
+/// The text of an [RCMD], or its number written in decimal.
+/// 
+/// [RCMD]: RemoteCommand
+fn command_name(command: &RemoteCommand) -> String {
+  match command {
+    RemoteCommand::Ascii(chars) => Char::chars_to_str(chars),
+    RemoteCommand::I1(number) => number.to_string(),
+    RemoteCommand::U1(number) => number.to_string(),
+  }
+}
+
+/// ## PARAMETER NAME
+/// 
+/// The text of a [CPNAME], or its numbers written in decimal.
+/// 
+/// [CPNAME]: CommandParameterName
+fn parameter_name(name: &CommandParameterName) -> String {
+  fn join<T: ToString>(numbers: &[T]) -> String {
+    numbers.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
+  }
+  match name {
+    CommandParameterName::Ascii(chars) => Char::chars_to_str(chars),
+    CommandParameterName::I1(numbers) => join(numbers),
+    CommandParameterName::I2(numbers) => join(numbers),
+    CommandParameterName::I4(numbers) => join(numbers),
+    CommandParameterName::I8(numbers) => join(numbers),
+    CommandParameterName::U1(numbers) => join(numbers),
+    CommandParameterName::U2(numbers) => join(numbers),
+    CommandParameterName::U4(numbers) => join(numbers),
+    CommandParameterName::U8(numbers) => join(numbers),
+  }
+}
@@ -0,0 +1,749 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SCENARIO
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A [Scenario] is a declarative script of the messages expected from the
+//! other side of a connection and of the messages sent in return, used to
+//! drive an [HSMS Client] as an equipment simulator or automated acceptance
+//! test without writing the exchange by hand.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each statement of a script occupies one line, unless a message body spans
+//! several, and everything following a `#` is a comment. Message headers and
+//! bodies are written as SML, the same text written by [Message] and [Item]
+//! when displayed, and may refer to variables by name with a `$`:
+//! 
+//! ```text
+//! set MDLN <A "SIM">
+//! 
+//! # Establish communications when asked to.
+//! expect S1F13 W <L $_ $_>
+//! reply S1F14 <L <B 0x00> <L $MDLN <A "1.0">>>
+//! 
+//! # Report an event, and wait for its acknowledgement.
+//! delay 500ms
+//! send S6F11 W <L <U4 1> <U4 100> <L>>
+//! expect S6F12 $ACKC6
+//! ```
+//! 
+//! - `set NAME BODY` - Assigns a variable.
+//! - `expect SxFy [W] [BODY]` - Waits for the next primary message received,
+//!   or for secondary messages, takes the reply to the last message sent.
+//!   Variables not yet assigned are assigned from the message, those already
+//!   assigned must match, and `$_` matches anything, as does an omitted body.
+//! - `reply SxFy [BODY]` - Replies to the last primary message expected.
+//! - `send SxFy [W] [BODY]` - Sends a primary message, waiting for its reply
+//!   if one is requested.
+//! - `delay N[ms|s]` - Waits, in milliseconds unless otherwise specified.
+//! - `on SxFy [W] [BODY] [after N[ms|s]] ACTION` - From then on, answers
+//!   matching primary messages received while waiting for another, after
+//!   the delay given, with an action of `reply SxFy [BODY]`, `abort`,
+//!   `ignore`, or `disconnect`. Variables assigned while matching are only
+//!   kept while answering, and later rules take precedence.
+//! - `serve` - Answers primary messages with the rules until the connection
+//!   is lost, and any for which there is no rule with an Abort Transaction
+//!   message if a reply is requested.
+//! 
+//! Rules allow canned behavior, such as replying to [S1F1] whenever it is
+//! received, as well as faults, such as slow or missing replies, to be
+//! described alongside the steps:
+//! 
+//! ```text
+//! on S1F1 W reply S1F2 <L $MDLN <A "1.0">>
+//! on S2F41 W after 5s ignore
+//! serve
+//! ```
+//! 
+//! [Scenario]:    Scenario
+//! [HSMS Client]: Client
+//! [Message]:     Message
+//! [Item]:        Item
+//! [S1F1]:        semi_e5::messages::s1::AreYouThere
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  io::{Error, ErrorKind},
+  path::Path,
+  sync::{Arc, mpsc::{Receiver, RecvTimeoutError}},
+  thread,
+  time::{Duration, Instant},
+};
+use semi_e5::{Item, Message, messages::Abort};
+use semi_e37::generic::{Client, MessageID};
+
+/// ## VARIABLES
+/// 
+/// The values assigned to the variables of a [Scenario], by name.
+/// 
+/// [Scenario]: Scenario
+pub type Variables = BTreeMap<String, Item>;
+
+/// ## TEMPLATE
+/// 
+/// A message body which may refer to variables, either to be rendered as an
+/// [Item] or matched against one.
+/// 
+/// [Item]: Item
+#[derive(Clone, Debug, PartialEq)]
+pub enum Template {
+  /// ### ITEM
+  /// 
+  /// Renders as, and matches only, the [Item] given.
+  /// 
+  /// [Item]: Item
+  Item(Item),
+
+  /// ### LIST
+  /// 
+  /// Renders as, and matches, a [List] of the same length whose elements
+  /// match in order.
+  /// 
+  /// [List]: Item::List
+  List(Vec<Template>),
+
+  /// ### VARIABLE
+  /// 
+  /// Renders as the value of the named variable. Matches anything if the
+  /// name is `_`, an [Item] equal to the value if one is assigned, or
+  /// otherwise anything, assigning it.
+  /// 
+  /// [Item]: Item
+  Variable(String),
+}
+impl Template {
+  /// ### RENDER
+  /// 
+  /// Produces the [Item] described, resulting in the name of the first
+  /// variable without a value if unable to.
+  /// 
+  /// [Item]: Item
+  pub fn render(&self, variables: &Variables) -> Result<Item, String> {
+    match self {
+      Template::Item(item) => Ok(item.clone()),
+      Template::List(list) => Ok(Item::List(list.iter().map(|template| template.render(variables)).collect::<Result<_, _>>()?)),
+      Template::Variable(name) => variables.get(name).cloned().ok_or_else(|| name.clone()),
+    }
+  }
+
+  /// ### MATCHES
+  /// 
+  /// Whether the [Item] is described, assigning any variables without a
+  /// value along the way, even when it is not.
+  /// 
+  /// [Item]: Item
+  pub fn matches(&self, item: &Item, variables: &mut Variables) -> bool {
+    match self {
+      Template::Item(expected) => expected == item,
+      Template::List(list) => match item {
+        Item::List(items) => {
+          list.len() == items.len()
+          && list.iter().zip(items).all(|(template, item)| template.matches(item, variables))
+        },
+        _ => false,
+      },
+      Template::Variable(name) => {
+        if name == "_" {return true}
+        match variables.get(name) {
+          Some(value) => value == item,
+          None => {
+            variables.insert(name.clone(), item.clone());
+            true
+          },
+        }
+      },
+    }
+  }
+
+  /// ### PARSE
+  /// 
+  /// Parses a [Template] from the start of the text, providing the text
+  /// remaining after it.
+  /// 
+  /// [Template]: Template
+  fn parse(text: &str) -> Option<(Template, &str)> {
+    let text = text.trim_start();
+    if let Some(rest) = text.strip_prefix('$') {
+      let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+      if end == 0 {return None}
+      return Some((Template::Variable(rest[..end].to_string()), &rest[end..]))
+    }
+    let inner = text.strip_prefix('<')?.trim_start();
+    let is_list = inner.starts_with(['L', 'l'])
+      && !inner[1..].starts_with(|c: char| c.is_ascii_alphanumeric());
+    if is_list {
+      let mut rest = inner[1..].trim_start();
+      if let Some(count) = rest.strip_prefix('[') {
+        rest = &count[count.find(']')? + 1..];
+      }
+      let mut list = vec![];
+      loop {
+        rest = rest.trim_start();
+        if let Some(rest) = rest.strip_prefix('>') {
+          return Some((Template::List(list), rest))
+        }
+        let (template, next) = Template::parse(rest)?;
+        list.push(template);
+        rest = next;
+      }
+    }
+    let mut quoted = false;
+    let end = text.char_indices().find(|(_, c)| {
+      if *c == '"' {quoted = !quoted}
+      *c == '>' && !quoted
+    })?.0;
+    let item: Item = text[..=end].parse().ok()?;
+    Some((Template::Item(item), &text[end + 1..]))
+  }
+}
+
+/// ## MESSAGE TEMPLATE
+/// 
+/// A message to be sent or expected by a [Scenario].
+/// 
+/// [Scenario]: Scenario
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageTemplate {
+  /// ### STREAM
+  pub stream: u8,
+
+  /// ### FUNCTION
+  pub function: u8,
+
+  /// ### REPLY REQUESTED
+  pub w: bool,
+
+  /// ### BODY
+  /// 
+  /// - [None] - A header-only message when sent, or any message when
+  ///   expected.
+  /// - [Some] - A message with a body.
+  pub body: Option<Template>,
+}
+impl MessageTemplate {
+  /// ### RENDER
+  /// 
+  /// Produces the [Message] described, resulting in the name of the first
+  /// variable without a value if unable to.
+  /// 
+  /// [Message]: Message
+  pub fn render(&self, variables: &Variables) -> Result<Message, String> {
+    Ok(Message {
+      stream: self.stream,
+      function: self.function,
+      w: self.w,
+      text: self.body.as_ref().map(|body| body.render(variables)).transpose()?,
+    })
+  }
+
+  /// ### MATCHES
+  /// 
+  /// Whether the [Message] is described, assigning any variables without a
+  /// value only when it is.
+  /// 
+  /// [Message]: Message
+  pub fn matches(&self, message: &Message, variables: &mut Variables) -> bool {
+    if message.stream != self.stream || message.function != self.function || message.w != self.w {
+      return false
+    }
+    let mut assigned: Variables = variables.clone();
+    let matched = match (&self.body, &message.text) {
+      (None, _) => true,
+      (Some(template), Some(item)) => template.matches(item, &mut assigned),
+      (Some(_), None) => false,
+    };
+    if matched {*variables = assigned}
+    matched
+  }
+
+  /// ### PARSE
+  /// 
+  /// Parses a [Message Template] from a message header optionally followed
+  /// by a body.
+  /// 
+  /// [Message Template]: MessageTemplate
+  fn parse(text: &str) -> Option<MessageTemplate> {
+    let split = text.find(['<', '$']).unwrap_or(text.len());
+    let header: Message = text[..split].parse().ok()?;
+    let body = match text[split..].trim() {
+      "" => None,
+      body => match Template::parse(body)? {
+        (template, "") => Some(template),
+        (template, rest) if rest.trim().is_empty() => Some(template),
+        _ => return None,
+      },
+    };
+    Some(MessageTemplate {stream: header.stream, function: header.function, w: header.w, body})
+  }
+}
+
+/// ## STEP
+/// 
+/// A single statement of a [Scenario].
+/// 
+/// [Scenario]: Scenario
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+  /// ### SET
+  /// 
+  /// Assigns the named variable.
+  Set(String, Template),
+
+  /// ### EXPECT
+  /// 
+  /// Waits for the next primary message received, or for secondary
+  /// messages, takes the reply to the last message sent, which must match.
+  Expect(MessageTemplate),
+
+  /// ### REPLY
+  /// 
+  /// Replies to the last primary message expected.
+  Reply(MessageTemplate),
+
+  /// ### SEND
+  /// 
+  /// Sends a primary message, waiting for its reply if one is requested.
+  Send(MessageTemplate),
+
+  /// ### DELAY
+  Delay(Duration),
+
+  /// ### ON
+  /// 
+  /// Adds a [Rule], in effect for the steps which follow.
+  /// 
+  /// [Rule]: Rule
+  On(Rule),
+
+  /// ### SERVE
+  /// 
+  /// Answers primary messages with the [Rule]s in effect until the
+  /// connection is lost.
+  /// 
+  /// [Rule]: Rule
+  Serve,
+}
+
+/// ## RULE
+/// 
+/// Describes how a [Scenario] answers a primary message received while it
+/// waits for another, or while serving.
+/// 
+/// [Scenario]: Scenario
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+  /// ### TRIGGER
+  /// 
+  /// The primary message answered.
+  pub trigger: MessageTemplate,
+
+  /// ### DELAY
+  /// 
+  /// The amount of time waited before answering.
+  pub delay: Duration,
+
+  /// ### ACTION
+  pub action: RuleAction,
+}
+
+/// ## RULE ACTION
+/// 
+/// The way in which a [Rule] answers a primary message.
+/// 
+/// [Rule]: Rule
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleAction {
+  /// ### REPLY
+  Reply(MessageTemplate),
+
+  /// ### ABORT
+  /// 
+  /// Replies with the Abort Transaction message of the same stream.
+  Abort,
+
+  /// ### IGNORE
+  /// 
+  /// Does not reply.
+  Ignore,
+
+  /// ### DISCONNECT
+  /// 
+  /// Breaks the connection.
+  Disconnect,
+}
+
+/// ## SCENARIO ERROR
+/// 
+/// The reason a [Scenario] could not be loaded or run to completion, along
+/// with the line number of the statement responsible.
+/// 
+/// [Scenario]: Scenario
+#[derive(Clone, Debug)]
+pub enum ScenarioError {
+  /// ### SYNTAX
+  /// 
+  /// The statement could not be parsed.
+  Syntax(usize),
+
+  /// ### UNDEFINED VARIABLE
+  /// 
+  /// The named variable was used before being assigned.
+  Undefined(usize, String),
+
+  /// ### UNEXPECTED MESSAGE
+  /// 
+  /// The message received does not match, or no reply was received.
+  Unexpected(usize, Option<Message>),
+
+  /// ### NOTHING TO REPLY TO
+  /// 
+  /// No primary message has been expected since the last reply.
+  NothingToReply(usize),
+
+  /// ### TIMEOUT
+  /// 
+  /// No primary message was received within the [Timeout].
+  /// 
+  /// [Timeout]: ScenarioSettings::timeout
+  Timeout(usize),
+
+  /// ### DISCONNECTED
+  /// 
+  /// The connection was lost while waiting for a primary message.
+  Disconnected(usize),
+
+  /// ### TRANSFER
+  /// 
+  /// The [HSMS Client] failed to send a message.
+  /// 
+  /// [HSMS Client]: Client
+  Transfer(usize, ErrorKind),
+}
+impl std::fmt::Display for ScenarioError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ScenarioError::Syntax(line)                  => write!(f, "line {line}: syntax error"),
+      ScenarioError::Undefined(line, name)         => write!(f, "line {line}: variable {name} is undefined"),
+      ScenarioError::Unexpected(line, Some(found)) => write!(f, "line {line}: unexpected message {found}"),
+      ScenarioError::Unexpected(line, None)        => write!(f, "line {line}: no reply received"),
+      ScenarioError::NothingToReply(line)          => write!(f, "line {line}: no message to reply to"),
+      ScenarioError::Timeout(line)                 => write!(f, "line {line}: timed out waiting for message"),
+      ScenarioError::Disconnected(line)            => write!(f, "line {line}: disconnected"),
+      ScenarioError::Transfer(line, kind)          => write!(f, "line {line}: transfer failed: {kind}"),
+    }
+  }
+}
+impl std::error::Error for ScenarioError {}
+
+/// ## SCENARIO SETTINGS
+/// 
+/// The configurable behavior of a [Scenario] while it is run.
+/// 
+/// [Scenario]: Scenario
+#[derive(Clone, Copy, Debug)]
+pub struct ScenarioSettings {
+  /// ### SESSION ID
+  /// 
+  /// The Session ID used by primary messages sent.
+  pub session: u16,
+
+  /// ### TIMEOUT
+  /// 
+  /// The amount of time waited for each primary message expected.
+  pub timeout: Duration,
+}
+impl Default for ScenarioSettings {
+  fn default() -> Self {
+    Self {
+      session: 0,
+      timeout: Duration::from_secs(45),
+    }
+  }
+}
+
+/// ## SCENARIO
+/// 
+/// A script of the messages expected and sent over a connection, parsed
+/// from text as described in the [Module Documentation].
+/// 
+/// [Module Documentation]: crate::scenario
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scenario {
+  steps: Vec<(usize, Step)>,
+}
+impl Scenario {
+  /// ### LOAD
+  /// 
+  /// Reads and parses the script in the file at the given path.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+    fs::read_to_string(path)?.parse().map_err(|error| Error::new(ErrorKind::InvalidData, error))
+  }
+
+  /// ### STEPS
+  /// 
+  /// Provides each [Step] in order, along with the line on which it begins.
+  /// 
+  /// [Step]: Step
+  pub fn steps(&self) -> &[(usize, Step)] {
+    &self.steps
+  }
+
+  /// ### RUN
+  /// 
+  /// Performs each [Step] in order over a connected and selected
+  /// [HSMS Client], with primary messages received from its
+  /// [Connect Procedure].
+  /// 
+  /// Variables may be assigned beforehand, and are left assigned
+  /// afterwards, so that values received may be inspected.
+  /// 
+  /// [Step]:              Step
+  /// [HSMS Client]:       Client
+  /// [Connect Procedure]: Client::connect
+  pub fn run(
+    &self,
+    client: &Arc<Client>,
+    receiver: &Receiver<(MessageID, Message)>,
+    settings: &ScenarioSettings,
+    variables: &mut Variables,
+  ) -> Result<(), ScenarioError> {
+    let mut system: u32 = 1;
+    let mut primary: Option<MessageID> = None;
+    let mut reply: Option<Message> = None;
+    let mut rules: Vec<(usize, &Rule)> = vec![];
+    for (line, step) in &self.steps {
+      let line = *line;
+      let render = |template: &MessageTemplate, variables: &Variables| {
+        template.render(variables).map_err(|name| ScenarioError::Undefined(line, name))
+      };
+      let transfer = |id: MessageID, message: Message| {
+        client.data(id, message).join()
+          .map_err(|_| ScenarioError::Transfer(line, ErrorKind::Other))?
+          .map_err(|error| ScenarioError::Transfer(line, error.kind()))
+      };
+      match step {
+        Step::Set(name, template) => {
+          let value = template.render(variables).map_err(|name| ScenarioError::Undefined(line, name))?;
+          variables.insert(name.clone(), value);
+        },
+        Step::Expect(template) => {
+          let message: Message = if template.function % 2 == 0 {
+            reply.take().ok_or(ScenarioError::Unexpected(line, None))?
+          } else {
+            let deadline: Instant = Instant::now() + settings.timeout;
+            loop {
+              let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+              let (id, message) = receiver.recv_timeout(remaining).map_err(|error| match error {
+                RecvTimeoutError::Timeout => ScenarioError::Timeout(line),
+                RecvTimeoutError::Disconnected => ScenarioError::Disconnected(line),
+              })?;
+              if template.matches(&message, variables) {
+                primary = Some(id);
+                break message
+              }
+              if !Self::answer(client, &rules, id, &message, variables)? {
+                return Err(ScenarioError::Unexpected(line, Some(message)))
+              }
+            }
+          };
+          if !template.matches(&message, variables) {
+            return Err(ScenarioError::Unexpected(line, Some(message)))
+          }
+        },
+        Step::Reply(template) => {
+          let id = primary.take().ok_or(ScenarioError::NothingToReply(line))?;
+          transfer(id, render(template, variables)?)?;
+        },
+        Step::Send(template) => {
+          let id = MessageID {session: settings.session, system};
+          system = system.wrapping_add(1);
+          reply = transfer(id, render(template, variables)?)?;
+        },
+        Step::Delay(duration) => thread::sleep(*duration),
+        Step::On(rule) => rules.push((line, rule)),
+        Step::Serve => {
+          for (id, message) in receiver.iter() {
+            if !Self::answer(client, &rules, id, &message, variables)? && message.w {
+              transfer(id, Abort::for_stream(message.stream).into())?;
+            }
+          }
+        },
+      }
+    }
+    Ok(())
+  }
+
+  /// ### ANSWER
+  /// 
+  /// Answers a primary message with the last matching [Rule], resulting in
+  /// whether there was one.
+  /// 
+  /// [Rule]: Rule
+  fn answer(
+    client: &Arc<Client>,
+    rules: &[(usize, &Rule)],
+    id: MessageID,
+    message: &Message,
+    variables: &Variables,
+  ) -> Result<bool, ScenarioError> {
+    let mut assigned: Variables = variables.clone();
+    let Some((line, rule)) = rules.iter().rev().find(|(_, rule)| rule.trigger.matches(message, &mut assigned)) else {
+      return Ok(false)
+    };
+    let line = *line;
+    thread::sleep(rule.delay);
+    let reply: Message = match &rule.action {
+      RuleAction::Reply(template) => template.render(&assigned).map_err(|name| ScenarioError::Undefined(line, name))?,
+      RuleAction::Abort => Abort::for_stream(message.stream).into(),
+      RuleAction::Ignore => return Ok(true),
+      RuleAction::Disconnect => {
+        let _ = client.disconnect();
+        return Ok(true)
+      },
+    };
+    client.data(id, reply).join()
+      .map_err(|_| ScenarioError::Transfer(line, ErrorKind::Other))?
+      .map_err(|error| ScenarioError::Transfer(line, error.kind()))?;
+    Ok(true)
+  }
+
+  /// ### PARSE STEP
+  /// 
+  /// Parses a single statement with comments removed.
+  fn parse_step(text: &str) -> Option<Step> {
+    let (keyword, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let rest = rest.trim();
+    match keyword.to_ascii_lowercase().as_str() {
+      "set" => {
+        let (name, value) = rest.split_once(char::is_whitespace)?;
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {return None}
+        match Template::parse(value)? {
+          (template, rest) if rest.trim().is_empty() => Some(Step::Set(name.to_string(), template)),
+          _ => None,
+        }
+      },
+      "expect" => Some(Step::Expect(MessageTemplate::parse(rest)?)),
+      "reply" => {
+        let template = MessageTemplate::parse(rest)?;
+        if template.w || template.function % 2 == 1 {return None}
+        Some(Step::Reply(template))
+      },
+      "send" => {
+        let template = MessageTemplate::parse(rest)?;
+        if template.function % 2 == 0 {return None}
+        Some(Step::Send(template))
+      },
+      "delay" => Some(Step::Delay(Self::parse_duration(rest)?)),
+      "on" => {
+        let (header, mut rest) = rest.split_once(char::is_whitespace)?;
+        let mut header: String = header.to_string();
+        if let Some(after) = rest.trim_start().strip_prefix(['W', 'w']) {
+          if after.is_empty() || after.starts_with(char::is_whitespace) {
+            header.push_str(" W");
+            rest = after;
+          }
+        }
+        rest = rest.trim_start();
+        let body = if rest.starts_with(['<', '$']) {
+          let (template, after) = Template::parse(rest)?;
+          rest = after.trim_start();
+          Some(template)
+        } else {None};
+        let MessageTemplate {stream, function, w, ..} = MessageTemplate::parse(&header)?;
+        if function % 2 == 0 {return None}
+        let trigger = MessageTemplate {stream, function, w, body};
+        let mut delay = Duration::ZERO;
+        if let Some(after) = rest.strip_prefix("after ") {
+          let (amount, after) = after.trim_start().split_once(char::is_whitespace)?;
+          delay = Self::parse_duration(amount)?;
+          rest = after.trim_start();
+        }
+        let (action, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let action = match (action, rest.trim()) {
+          ("reply", reply) => match Self::parse_step(&format!("reply {reply}"))? {
+            Step::Reply(template) => RuleAction::Reply(template),
+            _ => return None,
+          },
+          ("abort", "") => RuleAction::Abort,
+          ("ignore", "") => RuleAction::Ignore,
+          ("disconnect", "") => RuleAction::Disconnect,
+          _ => return None,
+        };
+        Some(Step::On(Rule {trigger, delay, action}))
+      },
+      "serve" if rest.is_empty() => Some(Step::Serve),
+      _ => None,
+    }
+  }
+
+  /// ### PARSE DURATION
+  /// 
+  /// Parses an amount of time, in milliseconds unless followed by `ms` or
+  /// `s`.
+  fn parse_duration(text: &str) -> Option<Duration> {
+    let (digits, scale) = match text.strip_suffix("ms") {
+      Some(digits) => (digits, 1),
+      None => match text.strip_suffix('s') {
+        Some(digits) => (digits, 1000),
+        None => (text, 1),
+      },
+    };
+    let amount: u64 = digits.trim().parse().ok()?;
+    Some(Duration::from_millis(amount.checked_mul(scale)?))
+  }
+}
+impl std::str::FromStr for Scenario {
+  type Err = ScenarioError;
+
+  fn from_str(script: &str) -> Result<Self, Self::Err> {
+    let mut steps = vec![];
+    let mut statement = String::new();
+    let mut start: usize = 0;
+    let mut depth: usize = 0;
+    for (index, line) in script.lines().enumerate() {
+      let mut quoted = false;
+      let mut end = line.len();
+      for (position, c) in line.char_indices() {
+        match c {
+          '"' => quoted = !quoted,
+          '#' if !quoted => {end = position; break},
+          '<' if !quoted => depth += 1,
+          '>' if !quoted => depth = depth.saturating_sub(1),
+          _ => {},
+        }
+      }
+      if statement.is_empty() {start = index + 1}
+      statement.push_str(&line[..end]);
+      statement.push(' ');
+      if depth > 0 {continue}
+      let text = statement.trim();
+      if !text.is_empty() {
+        steps.push((start, Self::parse_step(text).ok_or(ScenarioError::Syntax(start))?));
+      }
+      statement.clear();
+    }
+    if !statement.trim().is_empty() {return Err(ScenarioError::Syntax(start))}
+    Ok(Scenario {steps})
+  }
+}
@@ -0,0 +1,471 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SPOOLING
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Spooling allows the equipment to retain primary messages which it would
+//! have sent to the host while communication is lost, and to transmit or
+//! purge them at the host's request once communication resumes.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The host selects the streams and functions to be spooled with [S2F43],
+//! which the [Spool] answers with the [Reset Spooling] function, and
+//! requests the spooled messages with [S6F23], which the [Spool] answers
+//! with the [Request Spooled Data] function.
+//! 
+//! Messages are kept in a [Spool Storage], which may be:
+//! 
+//! - [Memory Spool Storage] - Lost when the equipment is restarted.
+//! - [Disk Spool Storage] - Kept in a directory, one file per message.
+//! 
+//! [S2F43]:                s2::ResetSpoolingStreamsAndFunctions
+//! [S6F23]:                s6::RequestSpooledData
+//! [Spool]:                Spool
+//! [Reset Spooling]:       Spool::reset_spooling
+//! [Request Spooled Data]: Spool::request_spooled_data
+//! [Spool Storage]:        SpoolStorage
+//! [Memory Spool Storage]: MemorySpoolStorage
+//! [Disk Spool Storage]:   DiskSpoolStorage
+
+use std::{
+  collections::VecDeque,
+  fs,
+  io::{Error, ErrorKind},
+  path::PathBuf,
+};
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    CodedValue,
+    FunctionID,
+    RequestSpoolDataAcknowledge,
+    RequestSpoolDataCode,
+    ResetSpoolingAcknowledgeCode,
+    SpoolStreamAcknowledgeCode,
+    StreamID,
+    VecList,
+  },
+  messages::{s2, s6},
+};
+
+/// ## SPOOL STORAGE
+/// 
+/// The means by which a [Spool] keeps its messages, in the order they were
+/// spooled.
+/// 
+/// [Spool]: Spool
+pub trait SpoolStorage: Send {
+  /// ### PUSH
+  /// 
+  /// Adds a message after all others.
+  fn push(&mut self, message: Message) -> Result<(), Error>;
+
+  /// ### FRONT
+  /// 
+  /// Provides the oldest message, if any, without removing it.
+  fn front(&mut self) -> Result<Option<Message>, Error>;
+
+  /// ### REMOVE FRONT
+  /// 
+  /// Removes the oldest message, if any.
+  fn remove_front(&mut self) -> Result<(), Error>;
+
+  /// ### LENGTH
+  /// 
+  /// The number of messages kept.
+  fn len(&self) -> usize;
+
+  /// ### IS EMPTY
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// ### CLEAR
+  /// 
+  /// Removes every message.
+  fn clear(&mut self) -> Result<(), Error>;
+}
+
+/// ## MEMORY SPOOL STORAGE
+/// 
+/// A [Spool Storage] keeping its messages in memory.
+/// 
+/// [Spool Storage]: SpoolStorage
+#[derive(Clone, Debug, Default)]
+pub struct MemorySpoolStorage {
+  messages: VecDeque<Message>,
+}
+impl SpoolStorage for MemorySpoolStorage {
+  fn push(&mut self, message: Message) -> Result<(), Error> {
+    self.messages.push_back(message);
+    Ok(())
+  }
+
+  fn front(&mut self) -> Result<Option<Message>, Error> {
+    Ok(self.messages.front().cloned())
+  }
+
+  fn remove_front(&mut self) -> Result<(), Error> {
+    self.messages.pop_front();
+    Ok(())
+  }
+
+  fn len(&self) -> usize {
+    self.messages.len()
+  }
+
+  fn clear(&mut self) -> Result<(), Error> {
+    self.messages.clear();
+    Ok(())
+  }
+}
+
+/// ## DISK SPOOL STORAGE
+/// 
+/// A [Spool Storage] keeping each of its messages in a file within a
+/// directory, so that they survive the equipment being restarted.
+/// 
+/// Each file is named by the message's position in the spool, and contains
+/// the stream, the function, the W-Bit, and then the binary text of the
+/// message, if any.
+/// 
+/// [Spool Storage]: SpoolStorage
+#[derive(Clone, Debug)]
+pub struct DiskSpoolStorage {
+  directory: PathBuf,
+  first: u64,
+  next: u64,
+}
+impl DiskSpoolStorage {
+  /// ### OPEN
+  /// 
+  /// Opens the directory, creating it if it does not exist, and resumes
+  /// from any messages already spooled there.
+  /// 
+  /// The files found are renumbered in order from the first position, so
+  /// that gaps left by files removed while the spool was closed are not
+  /// mistaken for messages.
+  pub fn open(directory: impl Into<PathBuf>) -> Result<Self, Error> {
+    let directory: PathBuf = directory.into();
+    fs::create_dir_all(&directory)?;
+    let mut positions: Vec<u64> = vec![];
+    for entry in fs::read_dir(&directory)? {
+      let entry = entry?;
+      if let Some(position) = entry.file_name().to_str().and_then(|name| name.strip_suffix(".msg")).and_then(|number| number.parse().ok()) {
+        positions.push(position);
+      }
+    }
+    positions.sort_unstable();
+    let mut storage: DiskSpoolStorage = DiskSpoolStorage {directory, first: 0, next: 0};
+    for position in positions {
+      if position != storage.next {
+        fs::rename(storage.path(position), storage.path(storage.next))?;
+      }
+      storage.next += 1;
+    }
+    Ok(storage)
+  }
+
+  /// ### PATH
+  /// 
+  /// The file in which the message at a position is kept.
+  fn path(&self, position: u64) -> PathBuf {
+    self.directory.join(format!("{position:020}.msg"))
+  }
+}
+impl SpoolStorage for DiskSpoolStorage {
+  fn push(&mut self, message: Message) -> Result<(), Error> {
+    let mut bytes: Vec<u8> = vec![message.stream, message.function, message.w as u8];
+    if let Some(text) = message.text {
+      bytes.append(&mut Vec::<u8>::from(text));
+    }
+    fs::write(self.path(self.next), bytes)?;
+    self.next += 1;
+    Ok(())
+  }
+
+  fn front(&mut self) -> Result<Option<Message>, Error> {
+    if self.first == self.next {return Ok(None)}
+    let mut bytes: Vec<u8> = fs::read(self.path(self.first))?;
+    if bytes.len() < 3 {return Err(Error::from(ErrorKind::InvalidData))}
+    let text: Vec<u8> = bytes.split_off(3);
+    Ok(Some(Message {
+      stream: bytes[0],
+      function: bytes[1],
+      w: bytes[2] != 0,
+      text: if text.is_empty() {
+        None
+      } else {
+        Some(Item::try_from(text).map_err(|_| Error::from(ErrorKind::InvalidData))?)
+      },
+    }))
+  }
+
+  fn remove_front(&mut self) -> Result<(), Error> {
+    if self.first == self.next {return Ok(())}
+    fs::remove_file(self.path(self.first))?;
+    self.first += 1;
+    Ok(())
+  }
+
+  fn len(&self) -> usize {
+    (self.next - self.first) as usize
+  }
+
+  fn clear(&mut self) -> Result<(), Error> {
+    while self.first != self.next {
+      self.remove_front()?;
+    }
+    self.first = 0;
+    self.next = 0;
+    Ok(())
+  }
+}
+
+/// ## SPOOL SETTINGS
+/// 
+/// The configurable behavior of a [Spool].
+/// 
+/// [Spool]: Spool
+#[derive(Clone, Copy, Debug)]
+pub struct SpoolSettings {
+  /// ### MAXIMUM SPOOL MESSAGES
+  /// 
+  /// The number of messages which may be spooled before the spool is full.
+  pub max_messages: usize,
+
+  /// ### OVERWRITE SPOOL
+  /// 
+  /// Whether the oldest message is discarded to make room for a new one
+  /// once the spool is full, rather than the new message being discarded.
+  pub overwrite: bool,
+}
+impl Default for SpoolSettings {
+  fn default() -> Self {
+    SpoolSettings {
+      max_messages: usize::MAX,
+      overwrite: false,
+    }
+  }
+}
+
+/// ## SPOOL
+/// 
+/// Keeps the primary messages selected for spooling in a [Spool Storage]
+/// while communication is lost.
+/// 
+/// [Spool Storage]: SpoolStorage
+pub struct Spool {
+  settings: SpoolSettings,
+  storage: Box<dyn SpoolStorage>,
+  selection: Vec<(u8, Vec<u8>)>,
+  unloading: bool,
+}
+impl Spool {
+  /// ### NEW SPOOL
+  /// 
+  /// Creates a [Spool] with no streams and functions selected for spooling.
+  /// 
+  /// [Spool]: Spool
+  pub fn new(storage: impl SpoolStorage + 'static, settings: SpoolSettings) -> Self {
+    Spool {
+      settings,
+      storage: Box::new(storage),
+      selection: vec![],
+      unloading: false,
+    }
+  }
+
+  /// ### IS ACTIVE
+  /// 
+  /// Whether any messages are spooled.
+  pub fn is_active(&self) -> bool {
+    !self.storage.is_empty()
+  }
+
+  /// ### IS FULL
+  pub fn is_full(&self) -> bool {
+    self.storage.len() >= self.settings.max_messages
+  }
+
+  /// ### LENGTH
+  /// 
+  /// The number of messages spooled.
+  pub fn len(&self) -> usize {
+    self.storage.len()
+  }
+
+  /// ### IS EMPTY
+  pub fn is_empty(&self) -> bool {
+    self.storage.is_empty()
+  }
+
+  /// ### IS SELECTED
+  /// 
+  /// Whether a message is a primary message whose stream and function have
+  /// been selected for spooling.
+  pub fn is_selected(&self, message: &Message) -> bool {
+    message.function % 2 == 1
+    && message.stream != 1
+    && self.selection.iter().any(|(stream, functions)| {
+      *stream == message.stream
+      && (functions.is_empty() || functions.contains(&message.function))
+    })
+  }
+
+  /// ### SPOOL
+  /// 
+  /// Spools a message if it has been selected for spooling, discarding
+  /// either the oldest message or the new one if the spool is full,
+  /// depending on the [Spool Settings].
+  /// 
+  /// Results in whether the message was spooled.
+  /// 
+  /// [Spool Settings]: SpoolSettings
+  pub fn spool(&mut self, message: Message) -> Result<bool, Error> {
+    if !self.is_selected(&message) {return Ok(false)}
+    if self.is_full() {
+      if !self.settings.overwrite {return Ok(false)}
+      while self.is_full() && !self.storage.is_empty() {
+        self.storage.remove_front()?;
+      }
+    }
+    self.storage.push(message)?;
+    Ok(true)
+  }
+
+  /// ### RESET SPOOLING
+  /// 
+  /// Answers an [S2F43], replacing the streams and functions selected for
+  /// spooling unless any of them is in error.
+  /// 
+  /// [S2F43]: s2::ResetSpoolingStreamsAndFunctions
+  pub fn reset_spooling(
+    &mut self,
+    message: s2::ResetSpoolingStreamsAndFunctions,
+  ) -> s2::ResetSpoolingAcknowledge {
+    let mut errors: Vec<(StreamID, SpoolStreamAcknowledgeCode, VecList<FunctionID>)> = vec![];
+    for (StreamID(stream), VecList(functions)) in &message.0.0 {
+      let code: Option<SpoolStreamAcknowledgeCode> = if *stream == 1 {
+        Some(SpoolStreamAcknowledgeCode::SpoolingDisallowed)
+      } else if *stream == 0 || *stream > 127 {
+        Some(SpoolStreamAcknowledgeCode::StreamUnknown)
+      } else if functions.iter().any(|FunctionID(function)| *function == 0 || *function > 254) {
+        Some(SpoolStreamAcknowledgeCode::FunctionUnknown)
+      } else if functions.iter().any(|FunctionID(function)| function % 2 == 0) {
+        Some(SpoolStreamAcknowledgeCode::SecondaryFunctionDisallowed)
+      } else {
+        None
+      };
+      if let Some(code) = code {
+        let erroneous: Vec<FunctionID> = functions.iter()
+          .filter(|FunctionID(function)| *function == 0 || *function > 254 || function % 2 == 0)
+          .copied()
+          .collect();
+        errors.push((StreamID(*stream), code, VecList(erroneous)));
+      }
+    }
+    if !errors.is_empty() {
+      return s2::ResetSpoolingAcknowledge((ResetSpoolingAcknowledgeCode::Rejected, VecList(errors)))
+    }
+    self.selection = message.0.0.into_iter()
+      .map(|(StreamID(stream), VecList(functions))| {
+        (stream, functions.into_iter().map(|FunctionID(function)| function).collect())
+      })
+      .collect();
+    s2::ResetSpoolingAcknowledge((ResetSpoolingAcknowledgeCode::Ok, VecList(vec![])))
+  }
+
+  /// ### REQUEST SPOOLED DATA
+  /// 
+  /// Answers an [S6F23].
+  /// 
+  /// When asked to purge, discards every spooled message. When asked to
+  /// transmit, begins unloading the spool, after which the spooled messages
+  /// are taken from it with the [Unload] function until it is empty.
+  /// 
+  /// [S6F23]:  s6::RequestSpooledData
+  /// [Unload]: Spool::unload
+  pub fn request_spooled_data(
+    &mut self,
+    message: s6::RequestSpooledData,
+  ) -> Result<s6::RequestSpooledDataAcknowledge, Error> {
+    let ack: RequestSpoolDataAcknowledge = if self.unloading {
+      RequestSpoolDataAcknowledge::Busy
+    } else if self.storage.is_empty() {
+      RequestSpoolDataAcknowledge::NoSpooledData
+    } else {
+      match message.0 {
+        RequestSpoolDataCode::Transmit => self.unloading = true,
+        RequestSpoolDataCode::Purge => self.storage.clear()?,
+      }
+      RequestSpoolDataAcknowledge::Ok
+    };
+    Ok(s6::RequestSpooledDataAcknowledge(CodedValue::Known(ack)))
+  }
+
+  /// ### IS UNLOADING
+  /// 
+  /// Whether the spool is being transmitted to the host.
+  pub fn is_unloading(&self) -> bool {
+    self.unloading
+  }
+
+  /// ### UNLOAD
+  /// 
+  /// While the spool is being transmitted, provides the oldest spooled
+  /// message, which should be [Confirm]ed once it has been sent.
+  /// 
+  /// Once the spool is empty, or the oldest message cannot be read, the
+  /// transmission is finished.
+  /// 
+  /// [Confirm]: Spool::confirm
+  pub fn unload(&mut self) -> Result<Option<Message>, Error> {
+    if !self.unloading {return Ok(None)}
+    let message: Result<Option<Message>, Error> = self.storage.front();
+    if !matches!(message, Ok(Some(_))) {
+      self.unloading = false;
+    }
+    message
+  }
+
+  /// ### CONFIRM
+  /// 
+  /// Removes the message most recently provided by [Unload] once it has
+  /// been sent.
+  /// 
+  /// [Unload]: Spool::unload
+  pub fn confirm(&mut self) -> Result<(), Error> {
+    self.storage.remove_front()
+  }
+
+  /// ### STOP UNLOADING
+  /// 
+  /// Stops the transmission of the spool, such as when communication is
+  /// lost again, leaving the remaining messages spooled.
+  pub fn stop_unloading(&mut self) {
+    self.unloading = false;
+  }
+}
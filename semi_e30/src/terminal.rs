@@ -0,0 +1,219 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # TERMINAL SERVICES
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Terminal services allow the host to display text to the operator at the
+//! equipment with [S10F3], [S10F5], and [S10F9], and the operator to send
+//! text to the host with [S10F1].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! On the equipment side, [Terminal Services] answer the host's display
+//! requests by passing each [Terminal Display] to the handler registered
+//! by the application, and keep the most recent display pending until the
+//! operator recognizes it, at which point the application should report the
+//! Message Recognition collection event.
+//! 
+//! On the host side, the [Display] function of the [Host] sends an [S10F3]
+//! and results in an [Operator Acknowledge] once the operator has
+//! recognized the message.
+//! 
+//! [S10F1]:                s10::TerminalRequest
+//! [S10F3]:                s10::TerminalDisplaySingle
+//! [S10F5]:                s10::TerminalDisplayMultiBlock
+//! [S10F9]:                s10::Broadcast
+//! [Terminal Services]:    TerminalServices
+//! [Terminal Display]:     TerminalDisplay
+//! [Operator Acknowledge]: OperatorAck
+//! [Host]:                 crate::host::Host
+//! [Display]:              crate::host::Host::display
+
+use semi_e5::{
+  Message,
+  items::{
+    AcknowledgeCode10,
+    CodedValue,
+    TerminalID,
+    Text,
+    VecList,
+  },
+  messages::{Abort, s10},
+};
+
+/// ## TERMINAL DISPLAY
+/// 
+/// Text sent by the host to be displayed to the operator.
+#[derive(Clone, Debug)]
+pub struct TerminalDisplay {
+  /// ### TERMINAL
+  /// 
+  /// The terminal to display the text on, or [None] for all terminals.
+  pub terminal: Option<TerminalID>,
+
+  /// ### LINES
+  pub lines: Vec<Text>,
+}
+
+/// ## DISPLAY HANDLER
+/// 
+/// Displays a [Terminal Display] to the operator, resulting in the code
+/// acknowledging the request.
+/// 
+/// [Terminal Display]: TerminalDisplay
+pub type DisplayHandler = Box<dyn Fn(&TerminalDisplay) -> AcknowledgeCode10 + Send>;
+
+/// ## OPERATOR ACKNOWLEDGE
+/// 
+/// The outcome of displaying text to the operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperatorAck {
+  /// ### RECOGNIZED
+  /// 
+  /// The text was displayed, and the operator has recognized it.
+  Recognized,
+
+  /// ### REFUSED
+  /// 
+  /// The equipment did not accept the text for display.
+  Refused(CodedValue<AcknowledgeCode10>),
+}
+
+/// ## TERMINAL SERVICES
+/// 
+/// Answers the host's requests to display text to the operator, and tracks
+/// whether the operator has recognized the text most recently displayed.
+pub struct TerminalServices {
+  handler: Option<DisplayHandler>,
+  multi_block: bool,
+  pending: Option<TerminalDisplay>,
+}
+impl Default for TerminalServices {
+  fn default() -> Self {
+    TerminalServices {
+      handler: None,
+      multi_block: true,
+      pending: None,
+    }
+  }
+}
+impl TerminalServices {
+  /// ### NEW TERMINAL SERVICES
+  /// 
+  /// Creates [Terminal Services] with no display handler, answering every
+  /// request with [Terminal Not Available] until one is registered.
+  /// 
+  /// [Terminal Services]:      TerminalServices
+  /// [Terminal Not Available]: AcknowledgeCode10::TerminalNotAvailable
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### SET DISPLAY HANDLER
+  pub fn set_display_handler(
+    &mut self,
+    handler: impl Fn(&TerminalDisplay) -> AcknowledgeCode10 + Send + 'static,
+  ) {
+    self.handler = Some(Box::new(handler));
+  }
+
+  /// ### SET MULTI-BLOCK ALLOWED
+  /// 
+  /// Sets whether multi-line displays requested with [S10F5] are accepted,
+  /// or answered with [S10F7].
+  /// 
+  /// [S10F5]: s10::TerminalDisplayMultiBlock
+  /// [S10F7]: s10::MultiBlockNotAllowed
+  pub fn set_multi_block_allowed(&mut self, allowed: bool) {
+    self.multi_block = allowed;
+  }
+
+  /// ### PENDING
+  /// 
+  /// The text most recently displayed, if the operator has yet to
+  /// recognize it.
+  pub fn pending(&self) -> Option<&TerminalDisplay> {
+    self.pending.as_ref()
+  }
+
+  /// ### RECOGNIZE
+  /// 
+  /// Notes that the operator has recognized the text pending, resulting in
+  /// it, if any.
+  pub fn recognize(&mut self) -> Option<TerminalDisplay> {
+    self.pending.take()
+  }
+
+  /// ### DISPLAY
+  /// 
+  /// Passes a [Terminal Display] to the handler, keeping it pending if it
+  /// was accepted.
+  /// 
+  /// [Terminal Display]: TerminalDisplay
+  pub fn display(&mut self, display: TerminalDisplay) -> AcknowledgeCode10 {
+    let ack: AcknowledgeCode10 = match &self.handler {
+      Some(handler) => handler(&display),
+      None => AcknowledgeCode10::TerminalNotAvailable,
+    };
+    if ack == AcknowledgeCode10::Accepted {
+      self.pending = Some(display);
+    }
+    ack
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to an [S10F3], [S10F5], or [S10F9], or an Abort
+  /// Transaction if it cannot be understood.
+  /// 
+  /// Results in [None] if the message is not one the terminal services
+  /// answer.
+  /// 
+  /// [S10F3]: s10::TerminalDisplaySingle
+  /// [S10F5]: s10::TerminalDisplayMultiBlock
+  /// [S10F9]: s10::Broadcast
+  pub fn service(&mut self, message: &Message) -> Option<Message> {
+    let stream: u8 = message.stream;
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (10, 3) => s10::TerminalDisplaySingle::try_from(message.clone()).ok().map(|request| {
+        let (terminal, text) = request.0;
+        let ack = self.display(TerminalDisplay {terminal: Some(terminal), lines: vec![text]});
+        s10::TerminalDisplaySingleAcknowledge(CodedValue::Known(ack)).into()
+      }),
+      (10, 5) => s10::TerminalDisplayMultiBlock::try_from(message.clone()).ok().map(|request| {
+        let (terminal, VecList(lines)) = request.0;
+        if !self.multi_block {
+          return s10::MultiBlockNotAllowed(terminal).into()
+        }
+        let ack = self.display(TerminalDisplay {terminal: Some(terminal), lines});
+        s10::TerminalDisplayMultiBlockAcknowledge(CodedValue::Known(ack)).into()
+      }),
+      (10, 9) => s10::Broadcast::try_from(message.clone()).ok().map(|request| {
+        let ack = self.display(TerminalDisplay {terminal: None, lines: vec![request.0]});
+        s10::BroadcastAcknowledge(CodedValue::Known(ack)).into()
+      }),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(stream).into()))
+  }
+}
@@ -0,0 +1,380 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # TRACE DATA COLLECTION
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Trace data collection allows the host to have the equipment sample a set
+//! of status variables at a fixed period, and report the samples in groups
+//! with [S6F1], as requested by an [S2F23].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Trace Engine] validates each [S2F23] against the [Variable Registry]
+//! and the [Trace Settings], keeping track of which traces are running so
+//! that they may be replaced or cancelled. Each accepted request results in
+//! a [Trace], which groups the samples provided to it into [S6F1]s until
+//! the total number of samples requested has been taken.
+//! 
+//! A trace is cancelled by an [S2F23] with the same [TRID] and a [TOTSMP]
+//! of zero.
+//! 
+//! [S2F23]:             s2::TraceInitializeSend
+//! [S6F1]:              s6::TraceDataSend
+//! [TRID]:              TraceRequestID
+//! [TOTSMP]:            semi_e5::items::TotalSamples
+//! [Trace Engine]:      TraceEngine
+//! [Trace Settings]:    TraceSettings
+//! [Trace]:             Trace
+//! [Variable Registry]: VariableRegistry
+
+use std::{
+  collections::HashMap,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering::Relaxed},
+  },
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use semi_e5::{
+  Item,
+  items::{
+    SampleNumber,
+    SampleTime,
+    StatusVariableValue,
+    Time,
+    TraceInitializeAcknowledgeCode,
+    TraceRequestID,
+    VariableID,
+    VecList,
+  },
+  messages::{s2, s6},
+};
+use crate::variables::VariableRegistry;
+
+/// ## TRACE SETTINGS
+/// 
+/// The limits placed on the traces the host may request, and whether a
+/// reply is requested for each [S6F1].
+/// 
+/// [S6F1]: s6::TraceDataSend
+#[derive(Clone, Copy, Debug)]
+pub struct TraceSettings {
+  /// ### MAXIMUM TRACES
+  /// 
+  /// The number of traces which may run at once.
+  pub max_traces: usize,
+
+  /// ### MAXIMUM VARIABLES
+  /// 
+  /// The number of status variables which may be sampled by a trace.
+  pub max_variables: usize,
+
+  /// ### MINIMUM PERIOD
+  /// 
+  /// The shortest sampling period which may be requested.
+  pub min_period: Duration,
+
+  /// ### REPLY REQUESTED
+  /// 
+  /// Whether [S6F1]s are sent with the W-Bit set.
+  /// 
+  /// [S6F1]: s6::TraceDataSend
+  pub reply_requested: bool,
+}
+impl Default for TraceSettings {
+  fn default() -> Self {
+    TraceSettings {
+      max_traces: usize::MAX,
+      max_variables: usize::MAX,
+      min_period: Duration::from_millis(10),
+      reply_requested: false,
+    }
+  }
+}
+
+/// ## TRACE
+/// 
+/// A running trace, which groups the samples provided to it into [S6F1]s.
+/// 
+/// [S6F1]: s6::TraceDataSend
+#[derive(Clone, Debug)]
+pub struct Trace {
+  id: TraceRequestID,
+  period: Duration,
+  total_samples: u64,
+  group_size: u64,
+  variables: Vec<VariableID>,
+  reply_requested: bool,
+  cancelled: Arc<AtomicBool>,
+  sample: u64,
+  group: Vec<StatusVariableValue>,
+  group_time: Option<SampleTime>,
+}
+impl Trace {
+  /// ### TRACE REQUEST ID
+  pub fn id(&self) -> &TraceRequestID {
+    &self.id
+  }
+
+  /// ### SAMPLE PERIOD
+  pub fn period(&self) -> Duration {
+    self.period
+  }
+
+  /// ### VARIABLES
+  /// 
+  /// The status variables sampled, in the order they are reported.
+  pub fn variables(&self) -> &[VariableID] {
+    &self.variables
+  }
+
+  /// ### IS CANCELLED
+  /// 
+  /// Whether the trace has been cancelled or replaced by the host.
+  pub fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Relaxed)
+  }
+
+  /// ### IS FINISHED
+  /// 
+  /// Whether every sample requested has been taken, or the trace has been
+  /// cancelled.
+  pub fn is_finished(&self) -> bool {
+    self.sample >= self.total_samples || self.is_cancelled()
+  }
+
+  /// ### SAMPLE
+  /// 
+  /// Takes the next sample from the [Variable Registry], resulting in an
+  /// [S6F1] once the reporting group is full or the last sample has been
+  /// taken.
+  /// 
+  /// [Variable Registry]: VariableRegistry
+  /// [S6F1]:              s6::TraceDataSend
+  pub fn sample(&mut self, registry: &VariableRegistry) -> Option<s6::TraceDataSend> {
+    if self.is_finished() {return None}
+    self.sample += 1;
+    if self.group_time.is_none() {
+      self.group_time = Some(now());
+    }
+    for id in &self.variables {
+      self.group.push(
+        registry.value::<StatusVariableValue>(id)
+          .unwrap_or(StatusVariableValue::List(vec![]))
+      );
+    }
+    if self.sample % self.group_size != 0 && self.sample < self.total_samples {
+      return None
+    }
+    let sample_number: SampleNumber = match u32::try_from(self.sample) {
+      Ok(sample) => SampleNumber::U4(sample),
+      Err(_) => SampleNumber::U8(self.sample),
+    };
+    Some(s6::TraceDataSend(
+      (
+        self.id.clone(),
+        sample_number,
+        self.group_time.take()?,
+        VecList(std::mem::take(&mut self.group)),
+      ),
+      self.reply_requested,
+    ))
+  }
+}
+
+/// ## TRACE ENGINE
+/// 
+/// Accepts trace requests from the host, and keeps track of the traces
+/// which are running.
+#[derive(Debug, Default)]
+pub struct TraceEngine {
+  settings: TraceSettings,
+  running: HashMap<TraceRequestID, Arc<AtomicBool>>,
+}
+impl TraceEngine {
+  /// ### NEW TRACE ENGINE
+  pub fn new(settings: TraceSettings) -> Self {
+    TraceEngine {
+      settings,
+      running: HashMap::new(),
+    }
+  }
+
+  /// ### RUNNING TRACES
+  /// 
+  /// The number of traces which are running.
+  pub fn running(&self) -> usize {
+    self.running.len()
+  }
+
+  /// ### INITIALIZE
+  /// 
+  /// Answers an [S2F23], resulting in the [Trace] to be sampled at its
+  /// period if one was started.
+  /// 
+  /// A request with the [TRID] of a running trace replaces it, and a
+  /// request with a [TOTSMP] of zero cancels it.
+  /// 
+  /// [S2F23]:  s2::TraceInitializeSend
+  /// [Trace]:  Trace
+  /// [TRID]:   TraceRequestID
+  /// [TOTSMP]: semi_e5::items::TotalSamples
+  pub fn initialize(
+    &mut self,
+    request: s2::TraceInitializeSend,
+    registry: &VariableRegistry,
+  ) -> (s2::TraceInitializeAcknowledge, Option<Trace>) {
+    let (id, period, total_samples, group_size, VecList(svids)) = request.0;
+    // TOTSMP: Has no acknowledge code of its own
+    let Some(total_samples) = count(total_samples.into()) else {
+      return (s2::TraceInitializeAcknowledge(TraceInitializeAcknowledgeCode::InvalidREPGSZ), None)
+    };
+    // CANCEL
+    if total_samples == 0 {
+      self.cancel(&id);
+      return (s2::TraceInitializeAcknowledge(TraceInitializeAcknowledgeCode::Ok), None)
+    }
+    let ack = |code| (s2::TraceInitializeAcknowledge(code), None);
+    let Some(period) = period.to_duration().filter(|period| *period >= self.settings.min_period && !period.is_zero()) else {
+      return ack(TraceInitializeAcknowledgeCode::InvalidPeriod)
+    };
+    let Some(group_size) = count(group_size.into()).filter(|size| (1..=total_samples).contains(size)) else {
+      return ack(TraceInitializeAcknowledgeCode::InvalidREPGSZ)
+    };
+    if svids.len() > self.settings.max_variables {
+      return ack(TraceInitializeAcknowledgeCode::TooManySVID)
+    }
+    let variables: Option<Vec<VariableID>> = svids.into_iter()
+      .map(|svid| VariableID::try_from(Item::from(svid)).ok())
+      .map(|id| id.filter(|id| registry.status_variable(id).is_some()))
+      .collect();
+    let Some(variables) = variables else {
+      return ack(TraceInitializeAcknowledgeCode::UnknownSVID)
+    };
+    if !self.running.contains_key(&id) && self.running.len() >= self.settings.max_traces {
+      return ack(TraceInitializeAcknowledgeCode::TooManyTraces)
+    }
+    // START
+    self.cancel(&id);
+    let cancelled: Arc<AtomicBool> = Default::default();
+    self.running.insert(id.clone(), cancelled.clone());
+    (
+      s2::TraceInitializeAcknowledge(TraceInitializeAcknowledgeCode::Ok),
+      Some(Trace {
+        id,
+        period,
+        total_samples,
+        group_size,
+        variables,
+        reply_requested: self.settings.reply_requested,
+        cancelled,
+        sample: 0,
+        group: vec![],
+        group_time: None,
+      }),
+    )
+  }
+
+  /// ### CANCEL
+  /// 
+  /// Cancels a running trace, if any.
+  pub fn cancel(&mut self, id: &TraceRequestID) {
+    if let Some(cancelled) = self.running.remove(id) {
+      cancelled.store(true, Relaxed);
+    }
+  }
+
+  /// ### CANCEL ALL
+  pub fn cancel_all(&mut self) {
+    for (_, cancelled) in self.running.drain() {
+      cancelled.store(true, Relaxed);
+    }
+  }
+
+  /// ### FINISH
+  /// 
+  /// Stops tracking a [Trace] once it has finished, unless it has already
+  /// been replaced.
+  /// 
+  /// [Trace]: Trace
+  pub fn finish(&mut self, trace: &Trace) {
+    if let Some(cancelled) = self.running.get(&trace.id) {
+      if Arc::ptr_eq(cancelled, &trace.cancelled) {
+        self.running.remove(&trace.id);
+      }
+    }
+  }
+}
+
+/// ## COUNT
+/// 
+/// The value of an [Item] containing a single non-negative integer, which
+/// may be written in ASCII.
+fn count(item: Item) -> Option<u64> {
+  fn single<T: Copy + TryInto<u64>>(values: &[T]) -> Option<u64> {
+    match values {
+      [value] => (*value).try_into().ok(),
+      _ => None,
+    }
+  }
+  match item {
+    Item::Ascii(chars) => chars.iter().map(|c| char::from(*c)).collect::<String>().parse().ok(),
+    Item::I1(values) => single(&values),
+    Item::I2(values) => single(&values),
+    Item::I4(values) => single(&values),
+    Item::I8(values) => single(&values),
+    Item::U1(values) => single(&values),
+    Item::U2(values) => single(&values),
+    Item::U4(values) => single(&values),
+    Item::U8(values) => single(&values),
+    _ => None,
+  }
+}
+
+/// ## NOW
+/// 
+/// The current time in UTC, as a [STIME] in the 16-byte format.
+/// 
+/// [STIME]: SampleTime
+fn now() -> SampleTime {
+  let elapsed: Duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+  let seconds: u64 = elapsed.as_secs();
+  let days: i64 = (seconds / 86_400) as i64;
+  let time: u32 = (seconds % 86_400) as u32;
+  // Civil date from days since 1970-01-01, per Howard Hinnant's algorithm.
+  let z: i64 = days + 719_468;
+  let era: i64 = z.div_euclid(146_097);
+  let doe: i64 = z.rem_euclid(146_097);
+  let yoe: i64 = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+  let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp: i64 = (5 * doy + 2) / 153;
+  let day: u32 = (doy - (153 * mp + 2) / 5 + 1) as u32;
+  let month: u32 = if mp < 10 {mp + 3} else {mp - 9} as u32;
+  let year: i32 = (yoe + era * 400 + (month <= 2) as i64) as i32;
+  let time: Time = Time::long(
+    year, month, day,
+    time / 3600, time / 60 % 60, time % 60,
+    elapsed.subsec_millis() / 10,
+  ).unwrap_or(Time(vec![]));
+  SampleTime(time)
+}
@@ -0,0 +1,592 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # VARIABLES
+//! **Based on SEMI E30**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A registry of the variables made available by the equipment to the host,
+//! along with the collection events which may report them:
+//! 
+//! - [Status Variable]s - Values which are always valid.
+//! - [Data Variable]s - Values which are only valid upon the occurrence of
+//!   particular collection events.
+//! - [Equipment Constant]s - Values which may be changed by the host, within
+//!   limits.
+//! - [Collection Event]s - Events which may be reported to the host.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Variable Registry] answers the following on its own, through its
+//! [Service] function:
+//! 
+//! - [S1F3] - With [S1F4].
+//! - [S1F11] - With [S1F12].
+//! - [S1F21] - With [S1F22].
+//! - [S1F23] - With [S1F24].
+//! - [S2F13] - With [S2F14].
+//! - [S2F15] - With [S2F16].
+//! - [S2F29] - With [S2F30].
+//! 
+//! Values are kept as [Item]s, so that any format may be used, and are
+//! converted to and from the types used by the application with the typed
+//! getters and setters.
+//! 
+//! [Status Variable]:    StatusVariable
+//! [Data Variable]:      DataVariable
+//! [Equipment Constant]: EquipmentConstant
+//! [Collection Event]:   CollectionEvent
+//! [Variable Registry]:  VariableRegistry
+//! [Service]:            VariableRegistry::service
+//! [S1F3]:               s1::SelectedEquipmentStatusRequest
+//! [S1F4]:               s1::SelectedEquipmentStatusData
+//! [S1F11]:              s1::StatusVariableNamelistRequest
+//! [S1F12]:              s1::StatusVariableNamelistReply
+//! [S1F21]:              s1::DataVariableNamelistRequest
+//! [S1F22]:              s1::DataVariableNamelist
+//! [S1F23]:              s1::CollectionEventNamelistRequest
+//! [S1F24]:              s1::CollectionEventNamelist
+//! [S2F13]:              s2::EquipmentConstantRequest
+//! [S2F14]:              s2::EquipmentConstantData
+//! [S2F15]:              s2::NewEquipmentConstantSend
+//! [S2F16]:              s2::NewEquipmentConstantAcknowledge
+//! [S2F29]:              s2::EquipmentConstantNamelistRequest
+//! [S2F30]:              s2::EquipmentConstantNamelist
+
+use std::{
+  cmp::Ordering,
+  collections::HashMap,
+};
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    CollectionEventID,
+    CollectionEventName,
+    DataVariableValueName,
+    EquipmentAcknowledgeCode,
+    EquipmentConstantDefaultValue,
+    EquipmentConstantID,
+    EquipmentConstantMaximumValue,
+    EquipmentConstantMinimumValue,
+    EquipmentConstantName,
+    EquipmentConstantValue,
+    OptionItem,
+    StatusVariableID,
+    StatusVariableName,
+    StatusVariableValue,
+    Units,
+    VariableID,
+    VecList,
+  },
+  messages::{Abort, s1, s2},
+};
+
+/// ## STATUS VARIABLE
+/// 
+/// A value which is always valid, such as the state of the equipment.
+#[derive(Clone, Debug)]
+pub struct StatusVariable {
+  pub name: StatusVariableName,
+  pub units: Units,
+  pub value: Item,
+}
+
+/// ## DATA VARIABLE
+/// 
+/// A value which is only valid upon the occurrence of particular collection
+/// events, and which is otherwise absent.
+#[derive(Clone, Debug)]
+pub struct DataVariable {
+  pub name: DataVariableValueName,
+  pub units: Units,
+  pub value: Option<Item>,
+}
+
+/// ## EQUIPMENT CONSTANT
+/// 
+/// A value which may be changed by the host, which must remain between its
+/// minimum and maximum, where those are numeric.
+#[derive(Clone, Debug)]
+pub struct EquipmentConstant {
+  pub name: EquipmentConstantName,
+  pub units: Units,
+  pub minimum: Item,
+  pub maximum: Item,
+  pub default: Item,
+  pub value: Item,
+}
+impl EquipmentConstant {
+  /// ### IS IN RANGE
+  /// 
+  /// Whether a value lies between the minimum and maximum of the
+  /// [Equipment Constant].
+  /// 
+  /// Values which cannot be compared with the limits, such as those which
+  /// are not numeric, are always considered to be in range.
+  /// 
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn is_in_range(&self, value: &Item) -> bool {
+    compare(value, &self.minimum) != Some(Ordering::Less)
+    && compare(value, &self.maximum) != Some(Ordering::Greater)
+  }
+}
+
+/// ## COLLECTION EVENT
+/// 
+/// An event which may be reported to the host, along with the variables
+/// which may be reported with it.
+#[derive(Clone, Debug)]
+pub struct CollectionEvent {
+  pub name: CollectionEventName,
+  pub variables: Vec<VariableID>,
+}
+
+/// ## VARIABLE REGISTRY
+/// 
+/// The [Status Variable]s, [Data Variable]s, [Equipment Constant]s, and
+/// [Collection Event]s of the equipment, each kept in the order in which it
+/// was added, and identified by a [VID] or [CEID].
+/// 
+/// [Status Variable]:    StatusVariable
+/// [Data Variable]:      DataVariable
+/// [Equipment Constant]: EquipmentConstant
+/// [Collection Event]:   CollectionEvent
+/// [VID]:                VariableID
+/// [CEID]:               CollectionEventID
+#[derive(Clone, Debug, Default)]
+pub struct VariableRegistry {
+  status: Registry<VariableID, StatusVariable>,
+  data: Registry<VariableID, DataVariable>,
+  constants: Registry<VariableID, EquipmentConstant>,
+  events: Registry<CollectionEventID, CollectionEvent>,
+}
+impl VariableRegistry {
+  /// ### NEW VARIABLE REGISTRY
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### ADD STATUS VARIABLE
+  /// 
+  /// Adds or replaces a [Status Variable].
+  /// 
+  /// [Status Variable]: StatusVariable
+  pub fn add_status_variable(&mut self, id: VariableID, variable: StatusVariable) {
+    self.status.insert(id, variable)
+  }
+
+  /// ### ADD DATA VARIABLE
+  /// 
+  /// Adds or replaces a [Data Variable].
+  /// 
+  /// [Data Variable]: DataVariable
+  pub fn add_data_variable(&mut self, id: VariableID, variable: DataVariable) {
+    self.data.insert(id, variable)
+  }
+
+  /// ### ADD EQUIPMENT CONSTANT
+  /// 
+  /// Adds or replaces an [Equipment Constant].
+  /// 
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn add_equipment_constant(&mut self, id: VariableID, constant: EquipmentConstant) {
+    self.constants.insert(id, constant)
+  }
+
+  /// ### ADD COLLECTION EVENT
+  /// 
+  /// Adds or replaces a [Collection Event].
+  /// 
+  /// [Collection Event]: CollectionEvent
+  pub fn add_collection_event(&mut self, id: CollectionEventID, event: CollectionEvent) {
+    self.events.insert(id, event)
+  }
+
+  /// ### STATUS VARIABLE
+  pub fn status_variable(&self, id: &VariableID) -> Option<&StatusVariable> {
+    self.status.get(id)
+  }
+
+  /// ### DATA VARIABLE
+  pub fn data_variable(&self, id: &VariableID) -> Option<&DataVariable> {
+    self.data.get(id)
+  }
+
+  /// ### EQUIPMENT CONSTANT
+  pub fn equipment_constant(&self, id: &VariableID) -> Option<&EquipmentConstant> {
+    self.constants.get(id)
+  }
+
+  /// ### COLLECTION EVENT
+  pub fn collection_event(&self, id: &CollectionEventID) -> Option<&CollectionEvent> {
+    self.events.get(id)
+  }
+
+  /// ### VALUE
+  /// 
+  /// The current value of a [Status Variable], [Data Variable], or
+  /// [Equipment Constant], converted to the type requested.
+  /// 
+  /// Results in [None] if the variable does not exist, has no value, or
+  /// its value cannot be converted.
+  /// 
+  /// [Status Variable]:    StatusVariable
+  /// [Data Variable]:      DataVariable
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn value<T: TryFrom<Item>>(&self, id: &VariableID) -> Option<T> {
+    T::try_from(self.item(id)?.clone()).ok()
+  }
+
+  /// ### SET STATUS VALUE
+  /// 
+  /// Sets the value of a [Status Variable], resulting in whether it exists.
+  /// 
+  /// [Status Variable]: StatusVariable
+  pub fn set_status_value(&mut self, id: &VariableID, value: impl Into<Item>) -> bool {
+    match self.status.get_mut(id) {
+      Some(variable) => {variable.value = value.into(); true},
+      None => false,
+    }
+  }
+
+  /// ### SET DATA VALUE
+  /// 
+  /// Sets or clears the value of a [Data Variable], resulting in whether it
+  /// exists.
+  /// 
+  /// [Data Variable]: DataVariable
+  pub fn set_data_value(&mut self, id: &VariableID, value: Option<impl Into<Item>>) -> bool {
+    match self.data.get_mut(id) {
+      Some(variable) => {variable.value = value.map(Into::into); true},
+      None => false,
+    }
+  }
+
+  /// ### SET CONSTANT VALUE
+  /// 
+  /// Sets the value of an [Equipment Constant], as though requested by an
+  /// [S2F15], resulting in the [EAC] which would be sent in reply.
+  /// 
+  /// [Equipment Constant]: EquipmentConstant
+  /// [S2F15]:              s2::NewEquipmentConstantSend
+  /// [EAC]:                EquipmentAcknowledgeCode
+  pub fn set_constant_value(&mut self, id: &VariableID, value: impl Into<Item>) -> EquipmentAcknowledgeCode {
+    let value: Item = value.into();
+    match self.constants.get_mut(id) {
+      None => EquipmentAcknowledgeCode::DoesNotExist,
+      Some(constant) if !constant.is_in_range(&value) => EquipmentAcknowledgeCode::OutOfRange,
+      Some(constant) => {constant.value = value; EquipmentAcknowledgeCode::Acknowledge},
+    }
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to a primary message which the registry answers on
+  /// its own, or an Abort Transaction if it cannot be understood.
+  /// 
+  /// Results in [None] if the message is not one the registry answers.
+  pub fn service(&mut self, message: &Message) -> Option<Message> {
+    let stream: u8 = message.stream;
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (1, 3) => s1::SelectedEquipmentStatusRequest::try_from(message.clone()).ok()
+        .map(|request| self.selected_equipment_status(request).into()),
+      (1, 11) => s1::StatusVariableNamelistRequest::try_from(message.clone()).ok()
+        .map(|request| self.status_variable_namelist(request).into()),
+      (1, 21) => s1::DataVariableNamelistRequest::try_from(message.clone()).ok()
+        .map(|request| self.data_variable_namelist(request).into()),
+      (1, 23) => s1::CollectionEventNamelistRequest::try_from(message.clone()).ok()
+        .map(|request| self.collection_event_namelist(request).into()),
+      (2, 13) => s2::EquipmentConstantRequest::try_from(message.clone()).ok()
+        .map(|request| self.equipment_constant_data(request).into()),
+      (2, 15) => s2::NewEquipmentConstantSend::try_from(message.clone()).ok()
+        .map(|request| self.new_equipment_constant(request).into()),
+      (2, 29) => s2::EquipmentConstantNamelistRequest::try_from(message.clone()).ok()
+        .map(|request| self.equipment_constant_namelist(request).into()),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(stream).into()))
+  }
+
+  /// ### SELECTED EQUIPMENT STATUS
+  /// 
+  /// Answers an [S1F3], providing every [Status Variable] if none are
+  /// requested, and a zero-length value for those which do not exist.
+  /// 
+  /// [S1F3]:            s1::SelectedEquipmentStatusRequest
+  /// [Status Variable]: StatusVariable
+  pub fn selected_equipment_status(
+    &self,
+    request: s1::SelectedEquipmentStatusRequest,
+  ) -> s1::SelectedEquipmentStatusData {
+    let ids: Vec<VariableID> = self.status.requested(convert_ids(request.0.0));
+    s1::SelectedEquipmentStatusData(VecList(ids.iter().map(|id| {
+      self.status.get(id)
+        .and_then(|variable| StatusVariableValue::try_from(variable.value.clone()).ok())
+        .unwrap_or(StatusVariableValue::List(vec![]))
+    }).collect()))
+  }
+
+  /// ### STATUS VARIABLE NAMELIST
+  /// 
+  /// Answers an [S1F11], providing every [Status Variable] if none are
+  /// requested, and a zero-length name and units for those which do not
+  /// exist.
+  /// 
+  /// [S1F11]:           s1::StatusVariableNamelistRequest
+  /// [Status Variable]: StatusVariable
+  pub fn status_variable_namelist(
+    &self,
+    request: s1::StatusVariableNamelistRequest,
+  ) -> s1::StatusVariableNamelistReply {
+    let ids: Vec<VariableID> = self.status.requested(convert_ids(request.0.0));
+    s1::StatusVariableNamelistReply(VecList(ids.into_iter().filter_map(|id| {
+      let (name, units) = match self.status.get(&id) {
+        Some(variable) => (variable.name.clone(), variable.units.clone()),
+        None => (StatusVariableName(vec![]), Units(vec![])),
+      };
+      Some((convert_id::<_, StatusVariableID>(id)?, name, units))
+    }).collect()))
+  }
+
+  /// ### DATA VARIABLE NAMELIST
+  /// 
+  /// Answers an [S1F21], providing every [Data Variable] if none are
+  /// requested, and a zero-length name and units for those which do not
+  /// exist.
+  /// 
+  /// [S1F21]:         s1::DataVariableNamelistRequest
+  /// [Data Variable]: DataVariable
+  pub fn data_variable_namelist(
+    &self,
+    request: s1::DataVariableNamelistRequest,
+  ) -> s1::DataVariableNamelist {
+    let ids: Vec<VariableID> = self.data.requested(request.0.0);
+    s1::DataVariableNamelist(VecList(ids.into_iter().map(|id| {
+      let (name, units) = match self.data.get(&id) {
+        Some(variable) => (variable.name.clone(), variable.units.clone()),
+        None => (DataVariableValueName(vec![]), Units(vec![])),
+      };
+      (id, name, units)
+    }).collect()))
+  }
+
+  /// ### COLLECTION EVENT NAMELIST
+  /// 
+  /// Answers an [S1F23], providing every [Collection Event] if none are
+  /// requested, and a zero-length name and variable list for those which do
+  /// not exist.
+  /// 
+  /// [S1F23]:            s1::CollectionEventNamelistRequest
+  /// [Collection Event]: CollectionEvent
+  pub fn collection_event_namelist(
+    &self,
+    request: s1::CollectionEventNamelistRequest,
+  ) -> s1::CollectionEventNamelist {
+    let ids: Vec<CollectionEventID> = self.events.requested(request.0.0);
+    s1::CollectionEventNamelist(VecList(ids.into_iter().map(|id| {
+      let (name, variables) = match self.events.get(&id) {
+        Some(event) => (event.name.clone(), event.variables.clone()),
+        None => (CollectionEventName(vec![]), vec![]),
+      };
+      (id, name, VecList(variables))
+    }).collect()))
+  }
+
+  /// ### EQUIPMENT CONSTANT DATA
+  /// 
+  /// Answers an [S2F13], providing every [Equipment Constant] if none are
+  /// requested, and a zero-length value for those which do not exist.
+  /// 
+  /// [S2F13]:              s2::EquipmentConstantRequest
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn equipment_constant_data(
+    &self,
+    request: s2::EquipmentConstantRequest,
+  ) -> s2::EquipmentConstantData {
+    let ids: Vec<VariableID> = self.constants.requested(convert_ids(request.0.0));
+    s2::EquipmentConstantData(VecList(ids.iter().map(|id| {
+      OptionItem(self.constants.get(id).and_then(|constant| {
+        EquipmentConstantValue::try_from(constant.value.clone()).ok()
+      }))
+    }).collect()))
+  }
+
+  /// ### NEW EQUIPMENT CONSTANT
+  /// 
+  /// Answers an [S2F15], changing none of the [Equipment Constant]s unless
+  /// every one of them exists and every new value is in range.
+  /// 
+  /// [S2F15]:              s2::NewEquipmentConstantSend
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn new_equipment_constant(
+    &mut self,
+    request: s2::NewEquipmentConstantSend,
+  ) -> s2::NewEquipmentConstantAcknowledge {
+    let mut changes: Vec<(VariableID, Item)> = vec![];
+    for (id, value) in request.0.0 {
+      let value: Item = value.into();
+      let constant = convert_id::<_, VariableID>(id)
+        .and_then(|id| Some((self.constants.get(&id)?, id)));
+      match constant {
+        None => return s2::NewEquipmentConstantAcknowledge(EquipmentAcknowledgeCode::DoesNotExist),
+        Some((constant, _)) if !constant.is_in_range(&value) => {
+          return s2::NewEquipmentConstantAcknowledge(EquipmentAcknowledgeCode::OutOfRange)
+        },
+        Some((_, id)) => changes.push((id, value)),
+      }
+    }
+    for (id, value) in changes {
+      self.set_constant_value(&id, value);
+    }
+    s2::NewEquipmentConstantAcknowledge(EquipmentAcknowledgeCode::Acknowledge)
+  }
+
+  /// ### EQUIPMENT CONSTANT NAMELIST
+  /// 
+  /// Answers an [S2F29], providing every [Equipment Constant] if none are
+  /// requested, and zero-length attributes for those which do not exist.
+  /// 
+  /// [S2F29]:              s2::EquipmentConstantNamelistRequest
+  /// [Equipment Constant]: EquipmentConstant
+  pub fn equipment_constant_namelist(
+    &self,
+    request: s2::EquipmentConstantNamelistRequest,
+  ) -> s2::EquipmentConstantNamelist {
+    let ids: Vec<VariableID> = self.constants.requested(convert_ids(request.0.0));
+    s2::EquipmentConstantNamelist(VecList(ids.into_iter().filter_map(|id| {
+      let constant: Option<&EquipmentConstant> = self.constants.get(&id);
+      let name = constant.map_or(EquipmentConstantName(vec![]), |constant| constant.name.clone());
+      let units = constant.map_or(Units(vec![]), |constant| constant.units.clone());
+      let attribute = |item: Option<&Item>| item.cloned().unwrap_or(Item::Ascii(vec![].into()));
+      Some((
+        convert_id::<_, EquipmentConstantID>(id)?,
+        name,
+        EquipmentConstantMinimumValue::try_from(attribute(constant.map(|constant| &constant.minimum))).ok()?,
+        EquipmentConstantMaximumValue::try_from(attribute(constant.map(|constant| &constant.maximum))).ok()?,
+        EquipmentConstantDefaultValue::try_from(attribute(constant.map(|constant| &constant.default))).ok()?,
+        units,
+      ))
+    }).collect()))
+  }
+
+  /// ### ITEM
+  /// 
+  /// The current value of any kind of variable.
+  fn item(&self, id: &VariableID) -> Option<&Item> {
+    if let Some(variable) = self.status.get(id) {
+      return Some(&variable.value)
+    }
+    if let Some(variable) = self.data.get(id) {
+      return variable.value.as_ref()
+    }
+    self.constants.get(id).map(|constant| &constant.value)
+  }
+}
+
+/// ## REGISTRY
+/// 
+/// A map which remembers the order in which its keys were first inserted.
+#[derive(Clone, Debug)]
+struct Registry<K, V> {
+  order: Vec<K>,
+  map: HashMap<K, V>,
+}
+impl<K, V> Default for Registry<K, V> {
+  fn default() -> Self {
+    Registry {
+      order: vec![],
+      map: HashMap::new(),
+    }
+  }
+}
+impl<K: Clone + Eq + std::hash::Hash, V> Registry<K, V> {
+  fn insert(&mut self, key: K, value: V) {
+    if self.map.insert(key.clone(), value).is_none() {
+      self.order.push(key);
+    }
+  }
+
+  fn get(&self, key: &K) -> Option<&V> {
+    self.map.get(key)
+  }
+
+  fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+    self.map.get_mut(key)
+  }
+
+  /// ### REQUESTED
+  /// 
+  /// The keys requested, or every key in order if none are requested.
+  fn requested(&self, keys: Vec<K>) -> Vec<K> {
+    if keys.is_empty() {
+      self.order.clone()
+    } else {
+      keys
+    }
+  }
+}
+
+/// ## CONVERT ID
+/// 
+/// Converts between the identifier types which share the format of a [VID].
+/// 
+/// [VID]: VariableID
+fn convert_id<A: Into<Item>, B: TryFrom<Item>>(id: A) -> Option<B> {
+  B::try_from(id.into()).ok()
+}
+
+/// ## CONVERT IDS
+fn convert_ids<A: Into<Item>, B: TryFrom<Item>>(ids: Vec<A>) -> Vec<B> {
+  ids.into_iter().filter_map(convert_id).collect()
+}
+
+/// ## COMPARE
+/// 
+/// Compares two single numeric values, regardless of their formats.
+fn compare(a: &Item, b: &Item) -> Option<Ordering> {
+  number(a)?.partial_cmp(&number(b)?)
+}
+
+/// ## NUMBER
+/// 
+/// The value of an [Item] containing a single number.
+fn number(item: &Item) -> Option<f64> {
+  fn single<T: Copy + Into<f64>>(values: &[T]) -> Option<f64> {
+    match values {
+      [value] => Some((*value).into()),
+      _ => None,
+    }
+  }
+  match item {
+    Item::I1(values) => single(values),
+    Item::I2(values) => single(values),
+    Item::I4(values) => single(values),
+    Item::I8(values) => match &values[..] {[value] => Some(*value as f64), _ => None},
+    Item::U1(values) => single(values),
+    Item::U2(values) => single(values),
+    Item::U4(values) => single(values),
+    Item::U8(values) => match &values[..] {[value] => Some(*value as f64), _ => None},
+    Item::F4(values) => single(values),
+    Item::F8(values) => single(values),
+    _ => None,
+  }
+}
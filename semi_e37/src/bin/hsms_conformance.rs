@@ -0,0 +1,119 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HSMS CONFORMANCE
+//! 
+//! Performs the [Conformance Checks] against a Remote Entity, connecting
+//! actively or passively, and prints the verdict of each along with the
+//! requirement on which it is based. Exits unsuccessfully if any check fails.
+//! 
+//! ```text
+//! hsms-conformance <active|passive> <address> [CHECK...] [OPTION...]
+//! ```
+//! 
+//! All checks are performed unless some are named. The options provide the
+//! timers with which the Remote Entity is configured, in seconds, and the
+//! margin allowed for it to react to their expiry:
+//! 
+//! ```text
+//! --t3=45 --t5=10 --t6=5 --t7=10 --t8=5 --margin=2 --session=0
+//! ```
+//! 
+//! [Conformance Checks]: semi_e37::conformance
+
+use std::{
+  env,
+  process::ExitCode,
+  time::Duration,
+};
+use semi_e37::{
+  conformance::{check, Check, ConformanceSettings, Verdict},
+  generic::ConnectionMode,
+};
+
+const USAGE: &str = "usage: hsms-conformance <active|passive> <address> [CHECK...] [--t3=S] [--t5=S] [--t6=S] [--t7=S] [--t8=S] [--margin=S] [--session=ID]";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let mut settings = ConformanceSettings::default();
+  let mut positional: Vec<&str> = vec![];
+  for argument in &arguments {
+    let Some(option) = argument.strip_prefix("--") else {
+      positional.push(argument);
+      continue
+    };
+    let parsed: Option<()> = option.split_once('=').and_then(|(name, value)| {
+      if name == "session" {
+        settings.session = value.parse().ok()?;
+        return Some(())
+      }
+      let value = Duration::try_from_secs_f64(value.parse().ok()?).ok()?;
+      match name {
+        "t3"     => settings.parameters.t3 = value,
+        "t5"     => settings.parameters.t5 = value,
+        "t6"     => settings.parameters.t6 = value,
+        "t7"     => settings.parameters.t7 = value,
+        "t8"     => settings.parameters.t8 = value,
+        "margin" => settings.margin = value,
+        _ => return None,
+      }
+      Some(())
+    });
+    if parsed.is_none() {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    }
+  }
+  let (address, names) = match positional.as_slice() {
+    ["active", address, names @ ..]  => {settings.parameters.connect_mode = ConnectionMode::Active; (*address, names)},
+    ["passive", address, names @ ..] => {settings.parameters.connect_mode = ConnectionMode::Passive; (*address, names)},
+    _ => {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    },
+  };
+  let checks: Vec<Check> = if names.is_empty() {
+    Check::ALL.to_vec()
+  } else {
+    match names.iter().map(|name| name.parse()).collect::<Result<Vec<Check>, ()>>() {
+      Ok(checks) => checks,
+      Err(()) => {
+        let names: Vec<&str> = Check::ALL.iter().map(Check::name).collect();
+        eprintln!("hsms-conformance: checks are {}", names.join(", "));
+        return ExitCode::FAILURE
+      },
+    }
+  };
+  // CHECK
+  let report = match check(address, &settings, &checks) {
+    Ok(report) => report,
+    Err(error) => {
+      eprintln!("hsms-conformance: unable to listen on {address}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  let mut failed: bool = false;
+  for (check, verdict) in report {
+    failed |= matches!(verdict, Verdict::Fail(_));
+    println!("{:<30} {:<36} {}", check.name(), check.requirement(), verdict);
+  }
+  if failed {ExitCode::FAILURE} else {ExitCode::SUCCESS}
+}
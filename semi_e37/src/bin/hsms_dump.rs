@@ -0,0 +1,135 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HSMS DUMP
+//! 
+//! Connects to a Remote Entity, actively or passively, performs the
+//! [Select Procedure] if active, and prints every message received or
+//! transmitted, with a timestamp (UTC) and direction, data messages being
+//! written in SML.
+//! 
+//! ```text
+//! hsms-dump <active|passive> <address> [--abort]
+//! ```
+//! 
+//! With `--abort`, each primary message requesting a reply is answered with
+//! the Abort Transaction message of its stream, so that the Remote Entity
+//! does not wait for a reply.
+//! 
+//! [Select Procedure]: semi_e37::generic::Client::select
+
+use std::{
+  env,
+  process::ExitCode,
+  sync::{Arc, mpsc::channel},
+  thread,
+  time::{SystemTime, UNIX_EPOCH},
+};
+use semi_e5::messages::Abort;
+use semi_e37::{
+  generic::{Client, ConnectionMode, Direction, Message, MessageContents, MessageID, ParameterSettings},
+  primitive,
+};
+
+const USAGE: &str = "usage: hsms-dump <active|passive> <address> [--abort]";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let abort: bool = arguments.iter().any(|argument| argument == "--abort");
+  let positional: Vec<&String> = arguments.iter().filter(|argument| !argument.starts_with("--")).collect();
+  let (connect_mode, address) = match positional.as_slice() {
+    [mode, address] if mode.as_str() == "active"  => (ConnectionMode::Active, address.as_str()),
+    [mode, address] if mode.as_str() == "passive" => (ConnectionMode::Passive, address.as_str()),
+    _ => {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    },
+  };
+  // CLIENT
+  let client: Arc<Client> = Client::new(ParameterSettings {connect_mode, ..Default::default()});
+  let (monitor, observed) = channel::<(Direction, primitive::Message)>();
+  client.set_monitor(Some(monitor));
+  let printer = thread::spawn(move || {
+    for (direction, message) in observed {
+      print(direction, message);
+    }
+  });
+  // CONNECT
+  let (socket, rx_receiver) = match client.connect(address) {
+    Ok(connection) => connection,
+    Err(error) => {
+      eprintln!("hsms-dump: unable to connect to {address}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  eprintln!("hsms-dump: connected to {socket}");
+  // SELECT
+  if let ConnectionMode::Active = connect_mode {
+    let id = MessageID {session: 0xFFFF, system: 0};
+    match client.select(id).join() {
+      Ok(Ok(())) => {},
+      _ => eprintln!("hsms-dump: select procedure failed"),
+    }
+  }
+  // RECEIVE
+  for (id, message) in rx_receiver {
+    if abort && message.w {
+      let _ = client.data(id, Abort::for_stream(message.stream).into()).join();
+    }
+  }
+  eprintln!("hsms-dump: disconnected");
+  client.set_monitor(None);
+  let _ = printer.join();
+  ExitCode::SUCCESS
+}
+
+/// ### PRINT
+/// 
+/// Writes a message observed by the monitor to standard output.
+fn print(direction: Direction, message: primitive::Message) {
+  let arrow: &str = match direction {
+    Direction::Received    => "<-",
+    Direction::Transmitted => "->",
+  };
+  let header = message.header;
+  let time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+  let seconds = time.as_secs() % 86400;
+  print!(
+    "{:02}:{:02}:{:02}.{:06} {} [{:04X} {:08X}] ",
+    seconds / 3600, seconds / 60 % 60, seconds % 60, time.subsec_micros(),
+    arrow,
+    header.session_id, header.system,
+  );
+  match Message::try_from(message) {
+    Ok(message) => match message.contents {
+      MessageContents::DataMessage(data)            => println!("{data}."),
+      MessageContents::SelectRequest                => println!("Select.req"),
+      MessageContents::SelectResponse(status)       => println!("Select.rsp {status}"),
+      MessageContents::DeselectRequest              => println!("Deselect.req"),
+      MessageContents::DeselectResponse(status)     => println!("Deselect.rsp {status}"),
+      MessageContents::LinktestRequest              => println!("Linktest.req"),
+      MessageContents::LinktestResponse             => println!("Linktest.rsp"),
+      MessageContents::RejectRequest(kind, reason)  => println!("Reject.req {kind} {reason}"),
+      MessageContents::SeparateRequest              => println!("Separate.req"),
+    },
+    Err(_) => println!("{:?}", header),
+  }
+}
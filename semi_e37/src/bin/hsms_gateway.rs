@@ -0,0 +1,109 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HSMS GATEWAY
+//! 
+//! Keeps a connection to a Remote Entity, connecting actively or passively
+//! and reconnecting whenever it is lost, and serves the [Gateway] to
+//! WebSocket clients on the listen address.
+//! 
+//! ```text
+//! hsms-gateway <active|passive> <address> <listen>
+//! ```
+//! 
+//! Once connected actively, the select procedure is performed, and once
+//! connected passively, the Remote Entity is expected to perform it.
+//! 
+//! The [Gateway] performs no authentication, so the listen address should be
+//! a loopback address such as `127.0.0.1:8080`, and a warning is printed if
+//! it is not.
+//! 
+//! [Gateway]: semi_e37::gateway
+
+use std::{
+  env,
+  net::TcpListener,
+  process::ExitCode,
+  sync::Arc,
+  thread,
+};
+use semi_e37::{
+  gateway::Gateway,
+  generic::{Client, ConnectionMode, MessageID, ParameterSettings},
+};
+
+const USAGE: &str = "usage: hsms-gateway <active|passive> <address> <listen>";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let (connect_mode, address, listen) = match arguments.as_slice() {
+    [mode, address, listen] if mode.as_str() == "active"  => (ConnectionMode::Active, address.as_str(), listen.as_str()),
+    [mode, address, listen] if mode.as_str() == "passive" => (ConnectionMode::Passive, address.as_str(), listen.as_str()),
+    _ => {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    },
+  };
+  // SERVE
+  let parameters = ParameterSettings {connect_mode, ..Default::default()};
+  let client: Arc<Client> = Client::new(parameters);
+  let gateway: Arc<Gateway> = Gateway::new(&client);
+  let listener: TcpListener = match TcpListener::bind(listen) {
+    Ok(listener) => listener,
+    Err(error) => {
+      eprintln!("hsms-gateway: unable to listen on {listen}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  eprintln!("hsms-gateway: serving on {listen}");
+  if listener.local_addr().is_ok_and(|address| !address.ip().is_loopback()) {
+    eprintln!("hsms-gateway: warning: {listen} is not a loopback address, and the gateway has no authentication");
+  }
+  let clone: Arc<Gateway> = gateway.clone();
+  thread::spawn(move || {
+    if let Err(error) = clone.serve(listener) {
+      eprintln!("hsms-gateway: unable to accept: {error}");
+    }
+  });
+  // CONNECT
+  loop {
+    let (socket, rx_receiver) = match client.connect(address) {
+      Ok(connection) => connection,
+      Err(error) => {
+        eprintln!("hsms-gateway: unable to connect to {address}: {error}");
+        thread::sleep(parameters.t5);
+        continue
+      },
+    };
+    eprintln!("hsms-gateway: connected to {socket}");
+    if let ConnectionMode::Active = connect_mode {
+      if !matches!(client.select(MessageID {session: 0xFFFF, system: 0}).join(), Ok(Ok(()))) {
+        eprintln!("hsms-gateway: select failed");
+        let _ = client.disconnect();
+        thread::sleep(parameters.t5);
+        continue
+      }
+    }
+    let _ = gateway.attach(rx_receiver).join();
+    let _ = client.disconnect();
+    eprintln!("hsms-gateway: disconnected");
+  }
+}
@@ -0,0 +1,161 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HSMS LOAD
+//! 
+//! Performs [Load Generation] against a Remote Entity, connecting actively or
+//! passively, and prints the latency percentiles and error rate of each
+//! message. Exits unsuccessfully if any transaction fails.
+//! 
+//! ```text
+//! hsms-load <active|passive> <address> <TRAFFIC...> [OPTION...]
+//! ```
+//! 
+//! Each traffic is written as `SxFy[W]:RATE[:BYTES]`, such as
+//! `S6F11W:500:1024` for 500 messages per second with a 1024 byte binary
+//! item requesting a reply. The options provide the duration of the test and
+//! the reply timeout, in seconds, and the Session ID of data messages:
+//! 
+//! ```text
+//! --duration=10 --t3=45 --session=0
+//! ```
+//! 
+//! Once connected actively, the select procedure is performed, and once
+//! connected passively, the Remote Entity is waited for up to T7 to perform
+//! it. Any message received which requests a reply is aborted.
+//! 
+//! [Load Generation]: semi_e37::load
+
+use std::{
+  env,
+  process::ExitCode,
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+use semi_e37::{
+  generic::{Client, ConnectionMode, MessageID, ParameterSettings, SelectionState},
+  load::{generate, LoadSettings, Traffic},
+};
+
+const USAGE: &str = "usage: hsms-load <active|passive> <address> <SxFy[W]:RATE[:BYTES]...> [--duration=S] [--t3=S] [--session=ID]";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let mut settings = LoadSettings::default();
+  let mut parameters = ParameterSettings::default();
+  let mut positional: Vec<&str> = vec![];
+  for argument in &arguments {
+    let Some(option) = argument.strip_prefix("--") else {
+      positional.push(argument);
+      continue
+    };
+    let parsed: Option<()> = option.split_once('=').and_then(|(name, value)| {
+      if name == "session" {
+        settings.session = value.parse().ok()?;
+        return Some(())
+      }
+      let value = Duration::try_from_secs_f64(value.parse().ok()?).ok()?;
+      match name {
+        "duration" => settings.duration = value,
+        "t3"       => parameters.t3 = value,
+        _ => return None,
+      }
+      Some(())
+    });
+    if parsed.is_none() {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    }
+  }
+  let (address, traffic) = match positional.as_slice() {
+    ["active", address, traffic @ ..] if !traffic.is_empty()  => {parameters.connect_mode = ConnectionMode::Active; (*address, traffic)},
+    ["passive", address, traffic @ ..] if !traffic.is_empty() => {parameters.connect_mode = ConnectionMode::Passive; (*address, traffic)},
+    _ => {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    },
+  };
+  for text in traffic {
+    match text.parse::<Traffic>() {
+      Ok(traffic) if traffic.message.check_reply().is_err() => {
+        eprintln!("hsms-load: invalid traffic {text}, reply bit not permitted for {}", traffic.name());
+        return ExitCode::FAILURE
+      },
+      Ok(traffic) => settings.traffic.push(traffic),
+      Err(()) => {
+        eprintln!("hsms-load: invalid traffic {text}, expected SxFy[W]:RATE[:BYTES]");
+        return ExitCode::FAILURE
+      },
+    }
+  }
+  // CONNECT
+  let client: Arc<Client> = Client::new(parameters);
+  let (socket, rx_receiver) = match client.connect(address) {
+    Ok(connection) => connection,
+    Err(error) => {
+      eprintln!("hsms-load: unable to connect to {address}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  eprintln!("hsms-load: connected to {socket}");
+  // RECEIVE
+  let clone: Arc<Client> = client.clone();
+  thread::spawn(move || {
+    for (id, message) in rx_receiver {
+      if message.w {
+        let _ = clone.data(id, semi_e5::Message {stream: message.stream, function: 0, w: false, text: None});
+      }
+    }
+  });
+  // SELECT
+  match parameters.connect_mode {
+    ConnectionMode::Active => {
+      match client.select(MessageID {session: 0xFFFF, system: 0}).join() {
+        Ok(Ok(())) => {},
+        Ok(Err(error)) => {
+          eprintln!("hsms-load: select failed: {error}");
+          return ExitCode::FAILURE
+        },
+        Err(_) => {
+          eprintln!("hsms-load: select failed");
+          return ExitCode::FAILURE
+        },
+      }
+    },
+    ConnectionMode::Passive => {
+      let deadline: Instant = Instant::now() + parameters.t7;
+      while client.selection_state() != SelectionState::Selected {
+        if Instant::now() > deadline {
+          eprintln!("hsms-load: not selected within T7");
+          return ExitCode::FAILURE
+        }
+        thread::sleep(Duration::from_millis(10));
+      }
+    },
+  }
+  // GENERATE
+  eprintln!("hsms-load: sending for {:?}", settings.duration);
+  let report = generate(&client, &settings);
+  let _ = client.disconnect();
+  print!("{report}");
+  if report.total().failed() > 0 {ExitCode::FAILURE} else {ExitCode::SUCCESS}
+}
@@ -0,0 +1,223 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # HSMS REPL
+//! 
+//! Connects to a Remote Entity, actively or passively, performs the
+//! [Select Procedure] if active, and reads messages written in SML from
+//! standard input, sending each and printing its reply when it arrives, such
+//! that further messages may be sent while waiting. Messages received from
+//! the Remote Entity are printed as they arrive.
+//! 
+//! ```text
+//! hsms-repl <active|passive> <address>
+//! ```
+//! 
+//! A message may span several lines, and ends with a period once all of its
+//! items are closed, such as `S1F1 W.` or `S1F3 W <L [1] <U4 1>>.`. Lines
+//! beginning with a colon are commands, listed by `:help`.
+//! 
+//! [Select Procedure]: semi_e37::generic::Client::select
+
+use std::{
+  env,
+  io::{stdin, stdout, BufRead, Write},
+  process::ExitCode,
+  sync::{Arc, Mutex},
+  thread,
+};
+use semi_e37::generic::{Client, ConnectionMode, MessageID, ParameterSettings};
+
+const USAGE: &str = "usage: hsms-repl <active|passive> <address>";
+
+const HELP: &str = "\
+S1F1 W.             send a message written in SML, ending with a period
+:reply [SYSTEM]     send the next message as the reply to a received message,
+                    by default the last one which requested a reply
+:session ID         set the session ID of data messages (default 0)
+:select             perform the select procedure
+:linktest           perform the linktest procedure
+:separate           perform the separate procedure
+:help               print this help
+:quit               disconnect and exit";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let (connect_mode, address) = match arguments.as_slice() {
+    [mode, address] if mode.as_str() == "active"  => (ConnectionMode::Active, address.as_str()),
+    [mode, address] if mode.as_str() == "passive" => (ConnectionMode::Passive, address.as_str()),
+    _ => {
+      eprintln!("{USAGE}");
+      return ExitCode::FAILURE
+    },
+  };
+  // CONNECT
+  let client: Arc<Client> = Client::new(ParameterSettings {connect_mode, ..Default::default()});
+  let (socket, rx_receiver) = match client.connect(address) {
+    Ok(connection) => connection,
+    Err(error) => {
+      eprintln!("hsms-repl: unable to connect to {address}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  eprintln!("hsms-repl: connected to {socket}, :help for commands");
+  let mut system: u32 = 1;
+  if let ConnectionMode::Active = connect_mode {
+    select(&client, &mut system);
+  }
+  // RECEIVE
+  let pending: Arc<Mutex<Vec<MessageID>>> = Default::default();
+  let pending_clone = pending.clone();
+  thread::spawn(move || {
+    for (id, message) in rx_receiver {
+      println!("<- [{:04X} {:08X}] {message}.", id.session, id.system);
+      if message.w {
+        pending_clone.lock().unwrap().push(id);
+      }
+    }
+    eprintln!("hsms-repl: disconnected");
+  });
+  // READ
+  let mut session: u16 = 0;
+  let mut reply: Option<MessageID> = None;
+  let mut text: String = String::new();
+  let mut depth: i64 = 0;
+  prompt(&text);
+  for line in stdin().lock().lines() {
+    let Ok(line) = line else {break};
+    // COMMAND
+    if text.is_empty() && line.trim_start().starts_with(':') {
+      let words: Vec<&str> = line.split_whitespace().collect();
+      match words.as_slice() {
+        [":help"] => println!("{HELP}"),
+        [":quit"] => break,
+        [":select"] => select(&client, &mut system),
+        [":linktest"] => {
+          system += 1;
+          match client.linktest(system).join() {
+            Ok(Ok(())) => println!("linktest succeeded"),
+            Ok(Err(error)) => println!("linktest failed: {error}"),
+            Err(_) => println!("linktest failed"),
+          }
+        },
+        [":separate"] => {
+          system += 1;
+          let _ = client.separate(MessageID {session: 0xFFFF, system}).join();
+        },
+        [":session", id] => match id.parse() {
+          Ok(id) => session = id,
+          Err(_) => println!("invalid session ID"),
+        },
+        [":reply"] => match pending.lock().unwrap().pop() {
+          Some(id) => reply = Some(id),
+          None => println!("no received message requests a reply"),
+        },
+        [":reply", id] => {
+          let id: Option<u32> = match id.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => id.parse().ok(),
+          };
+          let mut pending = pending.lock().unwrap();
+          match pending.iter().position(|pending| Some(pending.system) == id) {
+            Some(index) => reply = Some(pending.remove(index)),
+            None => println!("no received message with those system bytes requests a reply"),
+          }
+        },
+        _ => println!("unknown command, :help for commands"),
+      }
+      prompt(&text);
+      continue
+    }
+    // MESSAGE
+    depth += depth_of(&line);
+    text.push_str(&line);
+    text.push('\n');
+    if depth > 0 || !text.trim_end().ends_with('.') {
+      if text.trim().is_empty() {text.clear()}
+      prompt(&text);
+      continue
+    }
+    let message: Result<semi_e5::Message, _> = text.parse();
+    text.clear();
+    depth = 0;
+    let Ok(message) = message else {
+      println!("invalid message");
+      prompt(&text);
+      continue
+    };
+    let id: MessageID = match reply.take() {
+      Some(id) => id,
+      None => {
+        system += 1;
+        MessageID {session, system}
+      },
+    };
+    println!("-> [{:04X} {:08X}] {message}.", id.session, id.system);
+    let transaction = client.data(id, message);
+    thread::spawn(move || match transaction.join() {
+      Ok(Ok(Some(reply))) => println!("<- [{:04X} {:08X}] {reply}.", id.session, id.system),
+      Ok(Ok(None)) => {},
+      Ok(Err(error)) => println!("transaction failed: {error}"),
+      Err(_) => println!("transaction failed"),
+    });
+    prompt(&text);
+  }
+  let _ = client.disconnect();
+  ExitCode::SUCCESS
+}
+
+/// ### SELECT
+/// 
+/// Performs the Select Procedure, printing its result.
+fn select(client: &Arc<Client>, system: &mut u32) {
+  *system += 1;
+  match client.select(MessageID {session: 0xFFFF, system: *system}).join() {
+    Ok(Ok(())) => println!("select succeeded"),
+    Ok(Err(error)) => println!("select failed: {error}"),
+    Err(_) => println!("select failed"),
+  }
+}
+
+/// ### PROMPT
+/// 
+/// Prints the prompt, which differs while a message is incomplete.
+fn prompt(text: &str) {
+  print!("{}", if text.is_empty() {"> "} else {". "});
+  let _ = stdout().flush();
+}
+
+/// ### DEPTH OF
+/// 
+/// The number of items opened and not closed in a line of SML, excluding
+/// those within quoted strings.
+fn depth_of(line: &str) -> i64 {
+  let mut depth: i64 = 0;
+  let mut quoted: bool = false;
+  for c in line.chars() {
+    match c {
+      '"' => quoted = !quoted,
+      '<' if !quoted => depth += 1,
+      '>' if !quoted => depth -= 1,
+      _ => {},
+    }
+  }
+  depth
+}
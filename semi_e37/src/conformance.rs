@@ -0,0 +1,712 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # CONFORMANCE CHECKS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Exercises the behavior of a Remote Entity's implementation of the [HSMS]
+//! protocol, such as its responses to the control procedures, its use of the
+//! [Reject Procedure], and its handling of the expiry of its timers, so that
+//! third-party implementations may be qualified before being put into use.
+//! 
+//! Each [Check] is performed over its own TCP/IP connection, without the use
+//! of a [Client], so that messages may be sent or withheld in ways which a
+//! conforming implementation would not, and results in a [Verdict]. The
+//! [Check Procedure] performs any number of them, producing a report.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Conformance Settings] include the [Parameter Settings] with which the
+//! Remote Entity is expected to be configured. Its [Connect Mode] is that of
+//! the Local Entity, such that checks which depend on the Remote Entity
+//! initiating a procedure are skipped unless it is [PASSIVE].
+//! 
+//! [HSMS]:                 crate
+//! [Reject Procedure]:     crate::generic::Client::reject
+//! [Client]:               crate::generic::Client
+//! [Check]:                Check
+//! [Verdict]:              Verdict
+//! [Check Procedure]:      check
+//! [Conformance Settings]: ConformanceSettings
+//! [Parameter Settings]:   ParameterSettings
+//! [Connect Mode]:         ParameterSettings::connect_mode
+//! [PASSIVE]:              ConnectionMode::Passive
+
+use std::{
+  fmt::Display,
+  io::{Error, ErrorKind, Read, Write},
+  net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs},
+  thread,
+  time::{Duration, Instant},
+};
+use crate::{
+  generic::{ConnectionMode, Message, MessageContents, MessageID, ParameterSettings, RejectReason, SessionType},
+  primitive,
+  PresentationType,
+};
+
+/// ## CHECK
+/// 
+/// A behavior of the Remote Entity which is required by the [HSMS] protocol.
+/// 
+/// [HSMS]: crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Check {
+  /// ### SELECT
+  /// 
+  /// A Select.req is accepted with a Select.rsp of the same System Bytes
+  /// within T6.
+  Select,
+
+  /// ### ALREADY SELECTED
+  /// 
+  /// A Select.req received in the SELECTED state is rejected with a
+  /// Select.rsp with a nonzero status.
+  AlreadySelected,
+
+  /// ### SIMULTANEOUS SELECT
+  /// 
+  /// A Select.req received while the Remote Entity's own Select.req is
+  /// outstanding is accepted. Skipped unless the Local Entity is PASSIVE.
+  SimultaneousSelect,
+
+  /// ### LINKTEST
+  /// 
+  /// A Linktest.req is answered with a Linktest.rsp of the same System Bytes
+  /// within T6.
+  Linktest,
+
+  /// ### SEPARATE
+  /// 
+  /// A Separate.req returns the Remote Entity to the NOT SELECTED state, such
+  /// that a subsequent data message is rejected.
+  Separate,
+
+  /// ### DATA NOT SELECTED
+  /// 
+  /// A data message received in the NOT SELECTED state is rejected with a
+  /// Reject.req with reason 4, Entity Not Selected.
+  DataNotSelected,
+
+  /// ### UNSUPPORTED SESSION TYPE
+  /// 
+  /// A message with a reserved Session Type is rejected with a Reject.req
+  /// with reason 1, Session Type Not Supported.
+  UnsupportedSessionType,
+
+  /// ### UNSUPPORTED PRESENTATION TYPE
+  /// 
+  /// A message with a Presentation Type other than 0 is rejected with a
+  /// Reject.req with reason 2, Presentation Type Not Supported.
+  UnsupportedPresentationType,
+
+  /// ### TRANSACTION NOT OPEN
+  /// 
+  /// A Linktest.rsp which corresponds to no Linktest.req is rejected with a
+  /// Reject.req with reason 3, Transaction Not Open.
+  TransactionNotOpen,
+
+  /// ### T3 REPLY TIMEOUT
+  /// 
+  /// When a primary message requesting a reply is not replied to within T3,
+  /// S9F9 Transaction Timer Timeout is sent. Skipped if the Remote Entity
+  /// does not send such a message within T6 of being selected.
+  T3,
+
+  /// ### T5 CONNECTION SEPARATION TIMEOUT
+  /// 
+  /// After the connection is closed, no new connection is attempted before
+  /// T5 has elapsed. Skipped unless the Local Entity is PASSIVE.
+  T5,
+
+  /// ### T6 CONTROL TRANSACTION TIMEOUT
+  /// 
+  /// When a Select.req is not answered within T6, the connection is closed.
+  /// Skipped unless the Local Entity is PASSIVE.
+  T6,
+
+  /// ### T7 NOT SELECTED TIMEOUT
+  /// 
+  /// When no Select.req is received within T7 of connecting, the connection
+  /// is closed. Skipped if the Local Entity is PASSIVE.
+  T7,
+
+  /// ### T8 NETWORK INTERCHARACTER TIMEOUT
+  /// 
+  /// When a message is only partially received and no further bytes arrive
+  /// within T8, the connection is closed.
+  T8,
+}
+impl Check {
+  /// ### ALL CHECKS
+  pub const ALL: [Check; 14] = [
+    Check::Select,
+    Check::AlreadySelected,
+    Check::SimultaneousSelect,
+    Check::Linktest,
+    Check::Separate,
+    Check::DataNotSelected,
+    Check::UnsupportedSessionType,
+    Check::UnsupportedPresentationType,
+    Check::TransactionNotOpen,
+    Check::T3,
+    Check::T5,
+    Check::T6,
+    Check::T7,
+    Check::T8,
+  ];
+
+  /// ### NAME
+  /// 
+  /// A short name of the [Check], in lowercase with hyphens.
+  /// 
+  /// [Check]: Check
+  pub fn name(&self) -> &'static str {
+    match self {
+      Check::Select                      => "select",
+      Check::AlreadySelected             => "already-selected",
+      Check::SimultaneousSelect          => "simultaneous-select",
+      Check::Linktest                    => "linktest",
+      Check::Separate                    => "separate",
+      Check::DataNotSelected             => "data-not-selected",
+      Check::UnsupportedSessionType      => "unsupported-session-type",
+      Check::UnsupportedPresentationType => "unsupported-presentation-type",
+      Check::TransactionNotOpen          => "transaction-not-open",
+      Check::T3                          => "t3",
+      Check::T5                          => "t5",
+      Check::T6                          => "t6",
+      Check::T7                          => "t7",
+      Check::T8                          => "t8",
+    }
+  }
+
+  /// ### REQUIREMENT
+  /// 
+  /// The section of the standard on which the [Check] is based.
+  /// 
+  /// [Check]: Check
+  pub fn requirement(&self) -> &'static str {
+    match self {
+      Check::Select                      => "SEMI E37-1109§7.3-7.4",
+      Check::AlreadySelected             => "SEMI E37-1109§7.3-7.4",
+      Check::SimultaneousSelect          => "SEMI E37-1109§7.3-7.4",
+      Check::Linktest                    => "SEMI E37-1109§7.8",
+      Check::Separate                    => "SEMI E37-1109§7.9",
+      Check::DataNotSelected             => "SEMI E37-1109§7.10",
+      Check::UnsupportedSessionType      => "SEMI E37-1109§7.10",
+      Check::UnsupportedPresentationType => "SEMI E37-1109§7.10",
+      Check::TransactionNotOpen          => "SEMI E37-1109§7.10",
+      Check::T3                          => "SEMI E37-1109§10.2, SEMI E5§10.13",
+      Check::T5                          => "SEMI E37-1109§10.2",
+      Check::T6                          => "SEMI E37-1109§10.2",
+      Check::T7                          => "SEMI E37-1109§10.2",
+      Check::T8                          => "SEMI E37-1109§10.2",
+    }
+  }
+}
+impl std::str::FromStr for Check {
+  type Err = ();
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Check::ALL.into_iter().find(|check| check.name() == name).ok_or(())
+  }
+}
+
+/// ## VERDICT
+/// 
+/// The result of a [Check], with an explanation if it did not pass.
+/// 
+/// [Check]: Check
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+  /// ### PASS
+  Pass,
+
+  /// ### FAIL
+  Fail(String),
+
+  /// ### SKIP
+  /// 
+  /// The [Check] could not be performed, such as due to the [Connect Mode] or
+  /// the Remote Entity not initiating a transaction.
+  /// 
+  /// [Check]:        Check
+  /// [Connect Mode]: ParameterSettings::connect_mode
+  Skip(String),
+}
+impl Display for Verdict {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Verdict::Pass           => write!(f, "PASS"),
+      Verdict::Fail(reason)   => write!(f, "FAIL: {reason}"),
+      Verdict::Skip(reason)   => write!(f, "SKIP: {reason}"),
+    }
+  }
+}
+
+/// ## CONFORMANCE SETTINGS
+/// 
+/// The configurable behavior of the [Check Procedure].
+/// 
+/// [Check Procedure]: check
+#[derive(Clone, Copy, Debug)]
+pub struct ConformanceSettings {
+  /// ### PARAMETER SETTINGS
+  /// 
+  /// The timers with which the Remote Entity is expected to be configured,
+  /// and the [Connect Mode] of the Local Entity.
+  /// 
+  /// [Connect Mode]: ParameterSettings::connect_mode
+  pub parameters: ParameterSettings,
+
+  /// ### SESSION ID
+  /// 
+  /// The Session ID used in data messages.
+  pub session: u16,
+
+  /// ### MARGIN
+  /// 
+  /// The amount of time, in addition to a timer, allowed for the Remote
+  /// Entity to react to its expiry.
+  pub margin: Duration,
+}
+impl Default for ConformanceSettings {
+  fn default() -> Self {
+    Self {
+      parameters: ParameterSettings {
+        connect_mode: ConnectionMode::Active,
+        ..Default::default()
+      },
+      session: 0,
+      margin: Duration::from_secs(2),
+    }
+  }
+}
+
+/// ## CHECK PROCEDURE
+/// 
+/// Performs each [Check] against the Remote Entity, in order, resulting in
+/// the [Verdict] of each.
+/// 
+/// The socket address of the Remote Entity must be provided if the
+/// [Connect Mode] is [ACTIVE], and that of the Local Entity if it is
+/// [PASSIVE], in which case the Remote Entity must reconnect after each
+/// [Check].
+/// 
+/// [Check]:        Check
+/// [Verdict]:      Verdict
+/// [Connect Mode]: ParameterSettings::connect_mode
+/// [ACTIVE]:       ConnectionMode::Active
+/// [PASSIVE]:      ConnectionMode::Passive
+pub fn check(
+  address: &str,
+  settings: &ConformanceSettings,
+  checks: &[Check],
+) -> Result<Vec<(Check, Verdict)>, Error> {
+  let mut checker = Checker {
+    address: address.to_string(),
+    settings: *settings,
+    listener: match settings.parameters.connect_mode {
+      ConnectionMode::Passive => Some(TcpListener::bind(address)?),
+      ConnectionMode::Active  => None,
+    },
+    system: 0,
+  };
+  Ok(checks.iter().map(|check| {
+    let verdict: Verdict = match checker.perform(*check) {
+      Ok(verdict) => verdict,
+      Err(error) => Verdict::Fail(format!("connection failed: {error}")),
+    };
+    (*check, verdict)
+  }).collect())
+}
+
+/// ## CHECKER
+/// 
+/// The state shared between the [Check]s performed by the [Check Procedure].
+/// 
+/// [Check]:           Check
+/// [Check Procedure]: check
+struct Checker {
+  address: String,
+  settings: ConformanceSettings,
+  listener: Option<TcpListener>,
+  system: u32,
+}
+impl Checker {
+  /// ### PERFORM
+  /// 
+  /// Performs a [Check] over a new connection.
+  /// 
+  /// [Check]: Check
+  fn perform(&mut self, check: Check) -> Result<Verdict, Error> {
+    let passive: bool = self.listener.is_some();
+    let parameters: ParameterSettings = self.settings.parameters;
+    let margin: Duration = self.settings.margin;
+    // SKIP
+    match check {
+      Check::SimultaneousSelect | Check::T5 | Check::T6 if !passive => {
+        return Ok(Verdict::Skip("the remote entity does not initiate the connection".to_string()))
+      },
+      Check::T7 if passive => {
+        return Ok(Verdict::Skip("the remote entity initiates the select procedure".to_string()))
+      },
+      _ => {},
+    }
+    let mut stream: TcpStream = self.open()?;
+    let verdict: Verdict = match check {
+      Check::Select => self.select(&mut stream)?,
+      Check::AlreadySelected => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        let system = self.control(&mut stream, MessageContents::SelectRequest)?;
+        match self.wait(&mut stream, parameters.t6, |message| is_reply(message, SessionType::SelectResponse, system))? {
+          Some(message) if message.header.byte_3 != 0 => Verdict::Pass,
+          Some(_) => Verdict::Fail("select.req accepted in the selected state".to_string()),
+          None => Verdict::Fail("no select.rsp within t6".to_string()),
+        }
+      },
+      Check::SimultaneousSelect => 'check: {
+        let Some(request) = self.wait(&mut stream, parameters.t7, |message| message.header.session_type == SessionType::SelectRequest as u8)? else {
+          break 'check Verdict::Skip("no select.req within t7".to_string())
+        };
+        let system = self.control(&mut stream, MessageContents::SelectRequest)?;
+        send(&mut stream, Message {
+          id: MessageID {session: 0xFFFF, system: request.header.system},
+          contents: MessageContents::SelectResponse(0),
+        }.into())?;
+        match self.wait(&mut stream, parameters.t6, |message| is_reply(message, SessionType::SelectResponse, system))? {
+          Some(message) if message.header.byte_3 == 0 => Verdict::Pass,
+          Some(message) => Verdict::Fail(format!("select.req rejected with status {}", message.header.byte_3)),
+          None => Verdict::Fail("no select.rsp within t6".to_string()),
+        }
+      },
+      Check::Linktest => {
+        let system = self.control(&mut stream, MessageContents::LinktestRequest)?;
+        match self.wait(&mut stream, parameters.t6, |message| is_reply(message, SessionType::LinktestResponse, system))? {
+          Some(_) => Verdict::Pass,
+          None => Verdict::Fail("no linktest.rsp within t6".to_string()),
+        }
+      },
+      Check::Separate => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        self.control(&mut stream, MessageContents::SeparateRequest)?;
+        self.rejected(&mut stream, RejectReason::EntityNotSelected, |system, session| Message {
+          id: MessageID {session, system},
+          contents: MessageContents::DataMessage(semi_e5::Message {stream: 1, function: 1, w: true, text: None}),
+        }.into())?
+      },
+      Check::DataNotSelected => {
+        if passive {
+          self.wait(&mut stream, parameters.t7, |message| message.header.session_type == SessionType::SelectRequest as u8)?;
+        }
+        self.rejected(&mut stream, RejectReason::EntityNotSelected, |system, session| Message {
+          id: MessageID {session, system},
+          contents: MessageContents::DataMessage(semi_e5::Message {stream: 1, function: 1, w: true, text: None}),
+        }.into())?
+      },
+      Check::UnsupportedSessionType => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        self.rejected(&mut stream, RejectReason::UnsupportedSessionType, |system, _| primitive::Message {
+          header: primitive::MessageHeader {
+            session_id: 0xFFFF,
+            byte_2: 0,
+            byte_3: 0,
+            presentation_type: PresentationType::SecsII as u8,
+            session_type: 8,
+            system,
+          },
+          text: vec![],
+        })?
+      },
+      Check::UnsupportedPresentationType => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        self.rejected(&mut stream, RejectReason::UnsupportedPresentationType, |system, _| primitive::Message {
+          header: primitive::MessageHeader {
+            session_id: 0xFFFF,
+            byte_2: 0,
+            byte_3: 0,
+            presentation_type: 1,
+            session_type: SessionType::LinktestRequest as u8,
+            system,
+          },
+          text: vec![],
+        })?
+      },
+      Check::TransactionNotOpen => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        self.rejected(&mut stream, RejectReason::TransactionNotOpen, |system, _| Message {
+          id: MessageID {session: 0xFFFF, system},
+          contents: MessageContents::LinktestResponse,
+        }.into())?
+      },
+      Check::T3 => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        let Some(primary) = self.wait(&mut stream, parameters.t6, |message| {
+          message.header.session_type == SessionType::DataMessage as u8 && message.header.byte_2 & 0b1000_0000 != 0
+        })? else {
+          break 'check Verdict::Skip("no primary message requesting a reply within t6".to_string())
+        };
+        let start = Instant::now();
+        let header: [u8; 10] = primary.header.into();
+        match self.wait(&mut stream, parameters.t3 + margin, |message| {
+          message.header.session_type == SessionType::DataMessage as u8
+          && message.header.byte_2 & 0b0111_1111 == 9
+          && message.header.byte_3 == 9
+          && message.text.ends_with(&header)
+        })? {
+          Some(_) if start.elapsed() + margin < parameters.t3 => Verdict::Fail(format!("s9f9 sent after {:?}, before t3", start.elapsed())),
+          Some(_) => Verdict::Pass,
+          None => Verdict::Fail("no s9f9 within t3".to_string()),
+        }
+      },
+      Check::T5 => {
+        let start = Instant::now();
+        let _ = stream.shutdown(Shutdown::Both);
+        let listener = self.listener.as_ref().unwrap();
+        listener.set_nonblocking(true)?;
+        let accepted = loop {
+          match listener.accept() {
+            Ok((stream, _)) => break Some(stream),
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {
+              if start.elapsed() > parameters.t5 + margin {break None}
+              thread::sleep(Duration::from_millis(10));
+            },
+            Err(error) => return Err(error),
+          }
+        };
+        listener.set_nonblocking(false)?;
+        match accepted {
+          Some(accepted) => {
+            stream = accepted;
+            if start.elapsed() < parameters.t5 {
+              Verdict::Fail(format!("reconnected after {:?}, before t5", start.elapsed()))
+            } else {
+              Verdict::Pass
+            }
+          },
+          None => Verdict::Skip("did not reconnect within t5".to_string()),
+        }
+      },
+      Check::T6 => {
+        match self.wait(&mut stream, parameters.t7, |message| message.header.session_type == SessionType::SelectRequest as u8)? {
+          Some(_) => closed(&mut stream, parameters.t6, margin)?,
+          None => Verdict::Skip("no select.req within t7".to_string()),
+        }
+      },
+      Check::T7 => closed(&mut stream, parameters.t7, margin)?,
+      Check::T8 => 'check: {
+        if let verdict @ Verdict::Fail(_) = self.select(&mut stream)? {break 'check verdict}
+        self.system += 1;
+        let message: Vec<u8> = (&primitive::Message::from(Message {
+          id: MessageID {session: 0xFFFF, system: self.system},
+          contents: MessageContents::LinktestRequest,
+        })).into();
+        stream.write_all(&(message.len() as u32).to_be_bytes())?;
+        stream.write_all(&message[..5])?;
+        closed(&mut stream, parameters.t8, margin)?
+      },
+    };
+    let _ = stream.shutdown(Shutdown::Both);
+    Ok(verdict)
+  }
+
+  /// ### OPEN
+  /// 
+  /// Connects to the Remote Entity, retrying until T5 and the margin have
+  /// elapsed so that it may begin listening again, or waits for it to
+  /// connect.
+  fn open(&mut self) -> Result<TcpStream, Error> {
+    let stream: TcpStream = match &self.listener {
+      Some(listener) => listener.accept()?.0,
+      None => {
+        let socket = self.address.to_socket_addrs()?.next().ok_or(Error::from(ErrorKind::AddrNotAvailable))?;
+        let deadline: Instant = Instant::now() + self.settings.parameters.t5 + self.settings.margin;
+        loop {
+          match TcpStream::connect_timeout(&socket, self.settings.parameters.t5) {
+            Ok(stream) => break stream,
+            Err(error) if Instant::now() >= deadline => return Err(error),
+            Err(_) => thread::sleep(Duration::from_millis(100)),
+          }
+        }
+      },
+    };
+    stream.set_write_timeout(Some(self.settings.parameters.t8))?;
+    Ok(stream)
+  }
+
+  /// ### SELECT
+  /// 
+  /// Performs the Select Procedure, initiating it if the Local Entity is
+  /// ACTIVE, and otherwise waiting for the Remote Entity to initiate it.
+  fn select(&mut self, stream: &mut TcpStream) -> Result<Verdict, Error> {
+    let parameters: ParameterSettings = self.settings.parameters;
+    if self.listener.is_some() {
+      return match self.wait(stream, parameters.t7, |message| message.header.session_type == SessionType::SelectRequest as u8)? {
+        Some(request) => {
+          send(stream, Message {
+            id: MessageID {session: 0xFFFF, system: request.header.system},
+            contents: MessageContents::SelectResponse(0),
+          }.into())?;
+          Ok(Verdict::Pass)
+        },
+        None => Ok(Verdict::Fail("no select.req within t7".to_string())),
+      }
+    }
+    let system = self.control(stream, MessageContents::SelectRequest)?;
+    Ok(match self.wait(stream, parameters.t6, |message| is_reply(message, SessionType::SelectResponse, system))? {
+      Some(message) if message.header.byte_3 == 0 => Verdict::Pass,
+      Some(message) => Verdict::Fail(format!("select.req rejected with status {}", message.header.byte_3)),
+      None => Verdict::Fail("no select.rsp within t6".to_string()),
+    })
+  }
+
+  /// ### CONTROL
+  /// 
+  /// Sends a control message with new System Bytes, resulting in them.
+  fn control(&mut self, stream: &mut TcpStream, contents: MessageContents) -> Result<u32, Error> {
+    self.system += 1;
+    send(stream, Message {id: MessageID {session: 0xFFFF, system: self.system}, contents}.into())?;
+    Ok(self.system)
+  }
+
+  /// ### REJECTED
+  /// 
+  /// Sends a message with new System Bytes and the configured Session ID,
+  /// expecting it to be rejected for the given reason within T6.
+  fn rejected(
+    &mut self,
+    stream: &mut TcpStream,
+    reason: RejectReason,
+    message: impl FnOnce(u32, u16) -> primitive::Message,
+  ) -> Result<Verdict, Error> {
+    self.system += 1;
+    let system = self.system;
+    send(stream, message(system, self.settings.session))?;
+    Ok(match self.wait(stream, self.settings.parameters.t6, |message| {
+      message.header.system == system && message.header.session_type != SessionType::LinktestRequest as u8
+    })? {
+      Some(message) if message.header.session_type == SessionType::RejectRequest as u8 => {
+        if message.header.byte_3 == reason as u8 {
+          Verdict::Pass
+        } else {
+          Verdict::Fail(format!("reject.req with reason {}, not {}", message.header.byte_3, reason as u8))
+        }
+      },
+      Some(message) => Verdict::Fail(format!("answered with session type {} rather than reject.req", message.header.session_type)),
+      None => Verdict::Fail("no reject.req within t6".to_string()),
+    })
+  }
+
+  /// ### WAIT
+  /// 
+  /// Receives messages until one satisfies the predicate, resulting in
+  /// [None] if none does within the timeout or the connection is closed.
+  /// Linktest.req messages not satisfying it are answered.
+  fn wait(
+    &mut self,
+    stream: &mut TcpStream,
+    timeout: Duration,
+    predicate: impl Fn(&primitive::Message) -> bool,
+  ) -> Result<Option<primitive::Message>, Error> {
+    let deadline: Instant = Instant::now() + timeout;
+    loop {
+      let message: primitive::Message = match receive(stream, deadline, self.settings.parameters.t8) {
+        Ok(Some(message)) => message,
+        Ok(None) => return Ok(None),
+        Err(_) => return Ok(None),
+      };
+      if predicate(&message) {return Ok(Some(message))}
+      if message.header.session_type == SessionType::LinktestRequest as u8 {
+        send(stream, Message {
+          id: MessageID {session: 0xFFFF, system: message.header.system},
+          contents: MessageContents::LinktestResponse,
+        }.into())?;
+      }
+    }
+  }
+}
+
+/// ### IS REPLY
+/// 
+/// Determines whether a message is a control message of the given type with
+/// the given System Bytes.
+fn is_reply(message: &primitive::Message, session_type: SessionType, system: u32) -> bool {
+  message.header.session_type == session_type as u8 && message.header.system == system
+}
+
+/// ### SEND
+/// 
+/// Writes a message, preceded by its length.
+fn send(stream: &mut TcpStream, message: primitive::Message) -> Result<(), Error> {
+  let bytes: Vec<u8> = (&message).into();
+  stream.write_all(&(bytes.len() as u32).to_be_bytes())?;
+  stream.write_all(&bytes)
+}
+
+/// ### RECEIVE
+/// 
+/// Reads a message, resulting in [None] if none begins before the deadline
+/// and in an error if the connection is closed or the message is not
+/// completed within T8.
+fn receive(stream: &mut TcpStream, deadline: Instant, t8: Duration) -> Result<Option<primitive::Message>, Error> {
+  let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+  if remaining.is_zero() {return Ok(None)}
+  stream.set_read_timeout(Some(remaining))?;
+  let mut length: [u8; 4] = [0; 4];
+  match stream.read(&mut length[..1]) {
+    Ok(0) => return Err(Error::from(ErrorKind::UnexpectedEof)),
+    Ok(_) => {},
+    Err(error) if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => return Ok(None),
+    Err(error) => return Err(error),
+  }
+  stream.set_read_timeout(Some(t8))?;
+  stream.read_exact(&mut length[1..])?;
+  let mut message: Vec<u8> = vec![0; u32::from_be_bytes(length) as usize];
+  stream.read_exact(&mut message)?;
+  primitive::Message::try_from(message).map(Some).map_err(|_| Error::from(ErrorKind::InvalidData))
+}
+
+/// ### CLOSED
+/// 
+/// Expects the Remote Entity to close the connection once a timer expires,
+/// and not before, answering any Linktest.req in the meantime.
+fn closed(stream: &mut TcpStream, timer: Duration, margin: Duration) -> Result<Verdict, Error> {
+  let start: Instant = Instant::now();
+  let deadline: Instant = start + timer + margin;
+  loop {
+    match receive(stream, deadline, timer + margin) {
+      Ok(Some(message)) => if message.header.session_type == SessionType::LinktestRequest as u8 {
+        send(stream, Message {
+          id: MessageID {session: 0xFFFF, system: message.header.system},
+          contents: MessageContents::LinktestResponse,
+        }.into())?;
+      },
+      Ok(None) => return Ok(Verdict::Fail("connection not closed once the timer expired".to_string())),
+      Err(_) => break,
+    }
+  }
+  let elapsed: Duration = start.elapsed();
+  if elapsed + margin < timer {
+    Ok(Verdict::Fail(format!("connection closed after {elapsed:?}, before the timer expired")))
+  } else {
+    Ok(Verdict::Pass)
+  }
+}
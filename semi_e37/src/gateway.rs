@@ -0,0 +1,348 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # GATEWAY
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Gateway] exposes the connection of a [Client] over WebSocket, such
+//! that services not written in Rust may exchange data messages with the
+//! Remote Entity through a single managed connection.
+//! 
+//! - **tungstenite** feature: provides this module.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Gateway] performs no authentication or authorization: any WebSocket
+//! client able to reach its listener may send messages through the
+//! connection. It must therefore only be served on a loopback address, such
+//! as `127.0.0.1`, or behind a proxy which controls access to it.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each text frame sent by a WebSocket client is a command, in which messages
+//! are written in SML:
+//! 
+//! - `send S1F1 W.` - Sends a data message with the next System Bytes,
+//!   answered with `sent`, and then `reply` or `error` once the transaction
+//!   completes.
+//! - `reply SESSION SYSTEM S1F2 <L [0]>.` - Sends a data message which
+//!   expects no reply with the given Session ID and System Bytes, such as the
+//!   reply to a received message, answered with `replied` or `error`.
+//! - `session ID` - Sets the Session ID used by `send`, 0 by default.
+//! - `subscribe` - Begins receiving every primary data message received from
+//!   the Remote Entity as a `message`, along with changes of `state`.
+//! - `unsubscribe` - Stops receiving them.
+//! - `state` - Answered with `state`.
+//! 
+//! A WebSocket client which sends a command longer than 1 MiB is
+//! disconnected.
+//! 
+//! Every text frame sent by the [Gateway] is a JSON object whose `event`
+//! member is one of those named above, with the following other members:
+//! 
+//! - `sent` and `replied` - `system`.
+//! - `reply` - `system`, and `message`, being a [JSON Message] or `null` if
+//!   no reply was requested.
+//! - `error` - `error`, and `system` if it relates to a transaction.
+//! - `message` - `session`, `system`, and `message`, a [JSON Message].
+//! - `state` - `selection`, being `"SELECTED"` or `"NOT SELECTED"`.
+//! 
+//! [Client]:       Client
+//! [Gateway]:      Gateway
+//! [JSON Message]: semi_e5::json::JsonMessage
+
+use std::{
+  io::{Error, Read, Write},
+  net::{Shutdown, TcpListener, TcpStream},
+  sync::{
+    Arc, Mutex,
+    atomic::{AtomicU32, AtomicU64, Ordering::Relaxed},
+    mpsc::{channel, Receiver, Sender},
+  },
+  thread::{self, JoinHandle},
+};
+use semi_e5::json::{JsonMessage, JsonString};
+use tungstenite::{Message as Frame, WebSocket, protocol::{Role, WebSocketConfig}};
+use crate::generic::{Client, MessageID, SelectionState};
+
+/// ## GATEWAY
+/// 
+/// Serves WebSocket clients on behalf of a [Client], as described in the
+/// [module documentation].
+/// 
+/// [Client]:               Client
+/// [module documentation]: self
+pub struct Gateway {
+  client: Arc<Client>,
+  system: AtomicU32,
+  connections: AtomicU64,
+  subscribers: Mutex<Vec<(u64, Sender<Frame>)>>,
+}
+impl Gateway {
+  /// ### NEW GATEWAY
+  /// 
+  /// Creates a [Gateway] for the [Client], which may be connected before or
+  /// after, and reconnected any number of times.
+  /// 
+  /// [Gateway]: Gateway
+  /// [Client]:  Client
+  pub fn new(client: &Arc<Client>) -> Arc<Self> {
+    Arc::new(Self {
+      client: client.clone(),
+      system: AtomicU32::new(1),
+      connections: AtomicU64::new(0),
+      subscribers: Default::default(),
+    })
+  }
+
+  /// ### ATTACH
+  /// 
+  /// Forwards the primary data messages provided by the [Client]'s
+  /// [Connect Procedure] to subscribers, until the connection is lost.
+  /// 
+  /// Subscribers are told of the [Selection State] when attached and when
+  /// the connection is lost.
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [Selection State]:   SelectionState
+  pub fn attach(
+    self: &Arc<Self>,
+    receiver: Receiver<(MessageID, semi_e5::Message)>,
+  ) -> JoinHandle<()> {
+    let clone: Arc<Gateway> = self.clone();
+    thread::spawn(move || {
+      clone.broadcast(clone.state());
+      for (id, message) in receiver {
+        clone.broadcast(format!(
+          "{{\"event\":\"message\",\"session\":{},\"system\":{},\"message\":{}}}",
+          id.session, id.system, JsonMessage(&message),
+        ));
+      }
+      clone.broadcast(clone.state());
+    })
+  }
+
+  /// ### SERVE
+  /// 
+  /// Accepts WebSocket clients on the listener, serving each on its own
+  /// thread, until accepting fails.
+  pub fn serve(self: &Arc<Self>, listener: TcpListener) -> Result<(), Error> {
+    loop {
+      let (stream, _) = listener.accept()?;
+      let clone: Arc<Gateway> = self.clone();
+      thread::spawn(move || clone.connection(stream));
+    }
+  }
+
+  /// ### CONNECTION
+  /// 
+  /// Serves a WebSocket client until it disconnects, reading its commands on
+  /// the calling thread while the frames destined for it, including events
+  /// and replies to its pings, are written by another.
+  fn connection(self: Arc<Self>, stream: TcpStream) {
+    let Ok(socket) = tungstenite::accept_with_config(stream, Some(config())) else {return};
+    let Ok(read_stream) = socket.get_ref().try_clone() else {return};
+    let mut reader = WebSocket::from_raw_socket(ReadHalf(read_stream), Role::Server, Some(config()));
+    let number: u64 = self.connections.fetch_add(1, Relaxed);
+    let (sender, receiver) = channel::<Frame>();
+    let writer: JoinHandle<()> = thread::spawn(move || write_frames(socket, receiver));
+    let mut session: u16 = 0;
+    loop {
+      match reader.read() {
+        Ok(Frame::Text(text)) => self.command(text.trim(), number, &mut session, &sender),
+        Ok(Frame::Ping(payload)) => {let _ = sender.send(Frame::Pong(payload));},
+        Ok(Frame::Close(frame)) => {
+          let _ = sender.send(Frame::Close(frame));
+          break
+        },
+        Ok(_) => {},
+        Err(_) => {
+          let _ = sender.send(Frame::Close(None));
+          break
+        },
+      }
+    }
+    self.subscribers.lock().unwrap().retain(|(subscriber, _)| *subscriber != number);
+    let _ = writer.join();
+  }
+
+  /// ### COMMAND
+  /// 
+  /// Performs a command of a WebSocket client, sending the resulting events
+  /// to it.
+  fn command(self: &Arc<Self>, command: &str, number: u64, session: &mut u16, sender: &Sender<Frame>) {
+    let (name, rest) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+    let rest: &str = rest.trim_start();
+    let event: String = match name {
+      "send" => match rest.parse::<semi_e5::Message>() {
+        Ok(message) => {
+          let system: u32 = self.system.fetch_add(1, Relaxed);
+          self.transact(MessageID {session: *session, system}, message, sender.clone());
+          format!("{{\"event\":\"sent\",\"system\":{system}}}")
+        },
+        Err(error) => error_event(None, &format!("invalid message: {error:?}")),
+      },
+      "reply" => {
+        let mut words = rest.splitn(3, char::is_whitespace);
+        let session: Option<u16> = words.next().and_then(|word| word.parse().ok());
+        let system: Option<u32> = words.next().and_then(|word| word.parse().ok());
+        match (session, system, words.next().map(str::parse::<semi_e5::Message>)) {
+          (Some(session), Some(system), Some(Ok(message))) => {
+            match self.client.respond(MessageID {session, system}, message) {
+              Ok(()) => format!("{{\"event\":\"replied\",\"system\":{system}}}"),
+              Err(error) => error_event(Some(system), &error.to_string()),
+            }
+          },
+          (_, _, Some(Err(error))) => error_event(None, &format!("invalid message: {error:?}")),
+          _ => error_event(None, "usage: reply SESSION SYSTEM MESSAGE"),
+        }
+      },
+      "session" => match rest.parse() {
+        Ok(id) => {
+          *session = id;
+          return
+        },
+        Err(_) => error_event(None, "usage: session ID"),
+      },
+      "subscribe" => {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if !subscribers.iter().any(|(subscriber, _)| *subscriber == number) {
+          subscribers.push((number, sender.clone()));
+        }
+        self.state()
+      },
+      "unsubscribe" => {
+        self.subscribers.lock().unwrap().retain(|(subscriber, _)| *subscriber != number);
+        return
+      },
+      "state" => self.state(),
+      _ => error_event(None, &format!("unknown command: {name}")),
+    };
+    let _ = sender.send(Frame::Text(event));
+  }
+
+  /// ### TRANSACT
+  /// 
+  /// Performs the [Data Procedure], sending the resulting event once it
+  /// completes.
+  /// 
+  /// [Data Procedure]: Client::data
+  fn transact(self: &Arc<Self>, id: MessageID, message: semi_e5::Message, sender: Sender<Frame>) {
+    let transaction = self.client.data(id, message);
+    thread::spawn(move || {
+      let event: String = match transaction.join() {
+        Ok(Ok(Some(reply))) => format!("{{\"event\":\"reply\",\"system\":{},\"message\":{}}}", id.system, JsonMessage(&reply)),
+        Ok(Ok(None)) => format!("{{\"event\":\"reply\",\"system\":{},\"message\":null}}", id.system),
+        Ok(Err(error)) => error_event(Some(id.system), &error.to_string()),
+        Err(_) => error_event(Some(id.system), "transaction failed"),
+      };
+      let _ = sender.send(Frame::Text(event));
+    });
+  }
+
+  /// ### STATE
+  /// 
+  /// The `state` event for the [Client]'s current [Selection State].
+  /// 
+  /// [Client]:          Client
+  /// [Selection State]: SelectionState
+  fn state(&self) -> String {
+    let selection: &str = match self.client.selection_state() {
+      SelectionState::NotSelected => "NOT SELECTED",
+      SelectionState::Selected    => "SELECTED",
+    };
+    format!("{{\"event\":\"state\",\"selection\":\"{selection}\"}}")
+  }
+
+  /// ### BROADCAST
+  /// 
+  /// Sends an event to every subscriber, forgetting those which have
+  /// disconnected.
+  fn broadcast(&self, event: String) {
+    self.subscribers.lock().unwrap().retain(|(_, sender)| sender.send(Frame::Text(event.clone())).is_ok());
+  }
+}
+
+/// ### MAXIMUM COMMAND LENGTH
+/// 
+/// The greatest length of a command accepted from a WebSocket client, in
+/// bytes, beyond which the client is disconnected.
+const MAX_COMMAND_LENGTH: usize = 1 << 20;
+
+/// ### CONFIG
+/// 
+/// Limits the frames and messages read from a WebSocket client to the
+/// [Maximum Command Length], rather than the far larger defaults.
+/// 
+/// [Maximum Command Length]: MAX_COMMAND_LENGTH
+fn config() -> WebSocketConfig {
+  WebSocketConfig {
+    max_message_size: Some(MAX_COMMAND_LENGTH),
+    max_frame_size: Some(MAX_COMMAND_LENGTH),
+    ..Default::default()
+  }
+}
+
+/// ### WRITE FRAMES
+/// 
+/// Writes each frame destined for a WebSocket client as it is sent, until
+/// the connection is closed or lost, and then shuts the connection down so
+/// that its reader stops as well.
+fn write_frames(mut socket: WebSocket<TcpStream>, receiver: Receiver<Frame>) {
+  for frame in receiver {
+    let close: bool = frame.is_close();
+    if socket.send(frame).is_err() || close {break}
+  }
+  let _ = socket.get_ref().shutdown(Shutdown::Both);
+}
+
+/// ## READ HALF
+/// 
+/// The stream read by a WebSocket client's reader, whose writes are
+/// discarded, such that frames which would be written automatically in
+/// reply, such as pongs, are instead left to its writer.
+struct ReadHalf(TcpStream);
+impl Read for ReadHalf {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    self.0.read(buf)
+  }
+}
+impl Write for ReadHalf {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+/// ### ERROR EVENT
+/// 
+/// The `error` event, relating to the transaction of the System Bytes if
+/// provided.
+fn error_event(system: Option<u32>, error: &str) -> String {
+  match system {
+    Some(system) => format!("{{\"event\":\"error\",\"system\":{system},\"error\":{}}}", JsonString(error)),
+    None => format!("{{\"event\":\"error\",\"error\":{}}}", JsonString(error)),
+  }
+}
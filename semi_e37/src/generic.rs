@@ -28,6 +28,10 @@
 //!   [Connect Procedure].
 //! - Test connection integrity with the [Linktest Procedure].
 //! - Send [Data Message]s with the [Data Procedure].
+//! - Reply to received [Data Message]s on the calling thread with the
+//!   [Respond Procedure].
+//! - Send typed primary [Data Message]s and receive their typed replies with
+//!   the [Typed Data Procedure].
 //! - Send [Reject.req] messages [Reject Procedure].
 //! 
 //! [HSMS]:                 crate
@@ -41,6 +45,8 @@
 //! [Separate Procedure]:   Client::separate
 //! [Linktest Procedure]:   Client::linktest
 //! [Data Procedure]:       Client::data
+//! [Respond Procedure]:    Client::respond
+//! [Typed Data Procedure]: Client::request
 //! [Reject Procedure]:     Client::reject
 //! [Message]:              Message
 //! [Message ID]:           MessageID
@@ -65,10 +71,6 @@ use std::{
     ErrorKind,
   },
   net::SocketAddr,
-  ops::{
-    Deref,
-    DerefMut,
-  },
   sync::{
     atomic::Ordering::Relaxed,
     Arc,
@@ -92,8 +94,9 @@ use crate::{
   PresentationType,
   primitive,
 };
+use semi_e5::messages::{InquireMessage, MultiBlockMessage, PrimaryMessage};
 
-pub use crate::primitive::ConnectionMode;
+pub use crate::primitive::{ConnectionMode, Direction};
 
 /// ## CLIENT
 /// 
@@ -102,14 +105,122 @@ pub use crate::primitive::ConnectionMode;
 /// 
 /// [HSMS]:             crate
 /// [Generic Services]: crate::generic
-pub type Outbox = HashMap<u32, (MessageID, SendOnce<Option<Message>>)>;
 pub struct Client {
   parameter_settings: ParameterSettings,
   primitive_client: Arc<primitive::Client>,
   selection_state: Atomic<SelectionState>,
   selection_mutex: Mutex<()>,
-  outbox: Mutex<Outbox>,
-  system: Mutex<u32>,
+  outbox: Outbox,
+}
+
+/// ## OUTBOX
+/// 
+/// The open transactions of a [Client], each awaiting its response.
+/// 
+/// Transactions are divided among shards by their [System Bytes], each
+/// behind its own lock, such that the reception thread and senders contend
+/// only when their [System Bytes] fall in the same shard.
+/// 
+/// Each transaction is also given a token when opened, unique among all
+/// transactions, so that a sender abandoning its transaction cannot close
+/// another since opened with the same [Message ID].
+/// 
+/// [Client]:       Client
+/// [System Bytes]: MessageID::system
+/// [Message ID]:   MessageID
+struct Outbox {
+  shards: [Shard; Outbox::SHARDS],
+  tokens: Atomic<u64>,
+}
+
+/// ## SHARD
+/// 
+/// A portion of the [Outbox], mapping the [System Bytes] of each open
+/// transaction to its [Session ID], its token, and the sender of its
+/// response.
+/// 
+/// [Outbox]:       Outbox
+/// [System Bytes]: MessageID::system
+/// [Session ID]:   MessageID::session
+type Shard = Mutex<HashMap<u32, (u16, u64, SendOnce<Option<Message>>)>>;
+impl Outbox {
+  /// ### SHARDS
+  /// 
+  /// The number of shards.
+  const SHARDS: usize = 16;
+
+  /// ### SHARD
+  /// 
+  /// The shard holding transactions with the [System Bytes].
+  /// 
+  /// [System Bytes]: MessageID::system
+  fn shard(&self, system: u32) -> &Shard {
+    &self.shards[system as usize % Self::SHARDS]
+  }
+
+  /// ### OPEN
+  /// 
+  /// Opens a transaction, resulting in its token and the receiver of its
+  /// response, or [None] if a transaction with the same [System Bytes] is
+  /// already open.
+  /// 
+  /// [System Bytes]: MessageID::system
+  fn open(&self, id: MessageID) -> Option<(u64, oneshot::Receiver<Option<Message>>)> {
+    let mut shard = self.shard(id.system).lock().unwrap();
+    if shard.contains_key(&id.system) {return None}
+    let token: u64 = self.tokens.fetch_add(1, Relaxed);
+    let (sender, receiver) = oneshot::channel::<Option<Message>>();
+    shard.insert(id.system, (id.session, token, sender));
+    Some((token, receiver))
+  }
+
+  /// ### CLOSE
+  /// 
+  /// Closes the transaction with the [Message ID], if open, resulting in the
+  /// sender of its response.
+  /// 
+  /// [Message ID]: MessageID
+  fn close(&self, id: MessageID) -> Option<SendOnce<Option<Message>>> {
+    let mut shard = self.shard(id.system).lock().unwrap();
+    match shard.get(&id.system) {
+      Some((session, _, _)) if *session == id.session => shard.remove(&id.system).map(|(_, _, sender)| sender),
+      _ => None,
+    }
+  }
+
+  /// ### ABANDON
+  /// 
+  /// Closes the transaction with the [Message ID] on behalf of its sender,
+  /// only if it is still the transaction opened with the token.
+  /// 
+  /// [Message ID]: MessageID
+  fn abandon(&self, id: MessageID, token: u64) {
+    let mut shard = self.shard(id.system).lock().unwrap();
+    if let Some((_, open_token, _)) = shard.get(&id.system) {
+      if *open_token == token {
+        shard.remove(&id.system);
+      }
+    }
+  }
+
+  /// ### CLEAR
+  /// 
+  /// Closes every transaction, such that none receives a response.
+  fn clear(&self) {
+    for shard in &self.shards {
+      for (_, (_, _, sender)) in shard.lock().unwrap().drain() {
+        let _ = sender.send(None);
+      }
+    }
+  }
+}
+impl Default for Outbox {
+  fn default() -> Self {
+    Self {
+      shards: std::array::from_fn(|_| Default::default()),
+      tokens: Atomic::new(0),
+    }
+  }
 }
 
 /// ## CONNECTION PROCEDURES
@@ -145,7 +256,6 @@ impl Client {
       selection_state:  Default::default(),
       selection_mutex:  Default::default(),
       outbox:           Default::default(),
-      system:           Default::default(),
     })
   }
 
@@ -176,6 +286,11 @@ impl Client {
   /// the TCP stream's read and write timeout, and the [CONNECTED] state is
   /// entered.
   /// 
+  /// The greatest message length the [Primitive Client] accepts is set from
+  /// the [Decode Limits], as described there.
+  /// 
+  /// [Primitive Client]:  primitive::Client
+  /// [Decode Limits]:     ParameterSettings::decode_limits
   /// [Connection State]:  primitive::ConnectionState
   /// [NOT CONNECTED]:     primitive::ConnectionState::NotConnected
   /// [CONNECTED]:         primitive::ConnectionState::Connected
@@ -191,6 +306,11 @@ impl Client {
     entity: &str,
   ) -> Result<(SocketAddr, Receiver<(MessageID, semi_e5::Message)>), Error> {
     // Connect Primitive Client
+    // No Item within the decode limits occupies more than its data and a
+    // header of up to 4 bytes per element, after the 10 byte Message Header.
+    let limits: &semi_e5::DecodeLimits = &self.parameter_settings.decode_limits;
+    let max_length: usize = limits.max_elements.saturating_mul(4).saturating_add(limits.max_size).saturating_add(10);
+    self.primitive_client.set_max_length(max_length.try_into().unwrap_or(u32::MAX));
     let (socket, rx_receiver) = self.primitive_client.connect(entity, self.parameter_settings.connect_mode, self.parameter_settings.t5, self.parameter_settings.t8)?;
     // Create Channel
     let (data_sender, data_receiver) = channel::<(MessageID, semi_e5::Message)>();
@@ -234,6 +354,15 @@ impl Client {
     // Finish
     result
   }
+
+  /// ### SELECTION STATE
+  /// 
+  /// Provides the current [Selection State].
+  /// 
+  /// [Selection State]: SelectionState
+  pub fn selection_state(&self) -> SelectionState {
+    self.selection_state.load(Relaxed)
+  }
 }
 
 /// ## MESSAGE EXCHANGE PROCEDURES
@@ -243,16 +372,20 @@ impl Client {
 /// exchanging [Message]s.
 /// 
 /// - [Data Procedure] - [Data Message]s
+/// - [Respond Procedure] - [Data Message]s
 /// - [Select Procedure] - [Select.req] and [Select.rsp]
 /// - [Deselect Procedure] - [Deselect.req] and [Deselect.rsp]
 /// - [Linktest Procedure] - [Linktest.req] and [Linktest.rsp]
 /// - [Separate Procedure] - [Separate.req]
 /// - [Reject Procedure] - [Reject.req]
+/// - [Monitor Procedure] - Any [Message]
 /// 
 /// [Message]:            Message
+/// [Monitor Procedure]:  Client::set_monitor
 /// [Client]:             Client
 /// [Select Procedure]:   Client::select
 /// [Data Procedure]:     Client::data
+/// [Respond Procedure]:  Client::respond
 /// [Deselect Procedure]: Client::deselect
 /// [Linktest Procedure]: Client::linktest
 /// [Separate Procedure]: Client::separate
@@ -388,7 +521,10 @@ impl Client {
   ) {
     for primitive_message in rx_receiver {
       let primitive_header = primitive_message.header;
-      match Message::try_from(primitive_message) {
+      // The text is returned to the primitive client once it has been decoded.
+      let result = Message::decode(primitive_header, &primitive_message.text, &self.parameter_settings.decode_limits);
+      self.primitive_client.recycle(primitive_message.text);
+      match result {
         Ok(rx_message) => match rx_message.contents {
           // RX: Data Message
           MessageContents::DataMessage(data) => {
@@ -401,24 +537,15 @@ impl Client {
                   if rx_sender.send((rx_message.id, data)).is_err() {break}
                 }
                 // RX: Response Data Message
+                // An SxF0 is also a response, terminating the transaction.
                 else {
-                  // OUTBOX: Find Transaction
-                  let mut outbox = self.outbox.lock().unwrap();
-                  let mut optional_transaction: Option<u32> = None;
-                  for (outbox_id, (message_id, _)) in outbox.deref() {
-                    if *message_id == rx_message.id {
-                      optional_transaction = Some(*outbox_id);
-                      break;
-                    }
-                  }
                   // OUTBOX: Transaction Found
-                  if let Some(transaction) = optional_transaction {
+                  if let Some(sender) = self.outbox.close(rx_message.id) {
                     // OUTBOX: Complete Transaction
-                    let (_, sender) = outbox.deref_mut().remove(&transaction).unwrap();
-                    sender.send(Some(Message{
+                    let _ = sender.send(Some(Message{
                       id: rx_message.id,
                       contents: MessageContents::DataMessage(data),
-                    })).unwrap();
+                    }));
                   }
                   // OUTBOX: Transaction Not Found
                   else {
@@ -492,23 +619,13 @@ impl Client {
           },
           // RX: Select.rsp
           MessageContents::SelectResponse(select_status) => {
-            // OUTBOX: Find Transaction
-            let mut outbox = self.outbox.lock().unwrap();
-            let mut optional_transaction: Option<u32> = None;
-            for (outbox_id, (message_id, _)) in outbox.deref() {
-              if *message_id == rx_message.id {
-                optional_transaction = Some(*outbox_id);
-                break;
-              }
-            }
             // OUTBOX: Transaction Found
-            if let Some(transaction) = optional_transaction {
+            if let Some(sender) = self.outbox.close(rx_message.id) {
               // OUTBOX: Complete Transaction
-              let (_, sender) = outbox.deref_mut().remove(&transaction).unwrap();
-              sender.send(Some(Message{
+              let _ = sender.send(Some(Message{
                 id: rx_message.id,
                 contents: MessageContents::SelectResponse(select_status),
-              })).unwrap();
+              }));
             }
             // OUTBOX: Transaction Not Found
             else {
@@ -537,20 +654,10 @@ impl Client {
           },
           // RX: Linktest.rsp
           MessageContents::LinktestResponse => {
-            // OUTBOX: Find Transaction
-            let mut outbox = self.outbox.lock().unwrap();
-            let mut optional_transaction: Option<u32> = None;
-            for (outbox_id, (message_id, _)) in outbox.deref() {
-              if *message_id == rx_message.id {
-                optional_transaction = Some(*outbox_id);
-                break;
-              }
-            }
             // OUTBOX: Transaction Found
-            if let Some(transaction) = optional_transaction {
+            if let Some(sender) = self.outbox.close(rx_message.id) {
               // OUTBOX: Complete Transaction
-              let (_, sender) = outbox.deref_mut().remove(&transaction).unwrap();
-              sender.send(Some(rx_message)).unwrap();
+              let _ = sender.send(Some(rx_message));
             }
             // OUTBOX: Transaction Not Found
             else {
@@ -563,20 +670,10 @@ impl Client {
           },
           // RX: Reject.req
           MessageContents::RejectRequest(_message_type, _reason_code) => {
-            // OUTBOX: Find Transaction
-            let mut outbox = self.outbox.lock().unwrap();
-            let mut optional_transaction: Option<u32> = None;
-            for (outbox_id, (message_id, _)) in outbox.deref() {
-              if *message_id == rx_message.id {
-                optional_transaction = Some(*outbox_id);
-                break;
-              }
-            }
             // OUTBOX: Transaction Found
-            if let Some(transaction) = optional_transaction {
+            if let Some(sender) = self.outbox.close(rx_message.id) {
               // OUTBOX: Reject Transaction
-              let (_, sender) = outbox.deref_mut().remove(&transaction).unwrap();
-              sender.send(None).unwrap();
+              let _ = sender.send(None);
             }
           },
           // RX: Separate.req
@@ -603,9 +700,7 @@ impl Client {
       }
     }
     // OUTBOX: CLEAR
-    for (_, (_, sender)) in self.outbox.lock().unwrap().deref_mut().drain() {
-      let _ = sender.send(None);
-    }
+    self.outbox.clear();
   }
 
   /// ### TRANSMIT PROCEDURE
@@ -615,6 +710,10 @@ impl Client {
   /// If a reply is expected, this function will then wait up to the time
   /// specified for the requisite response [Message] to be recieved.
   /// 
+  /// A reply may only be expected if no other transaction with the same
+  /// System Bytes is open, such that responses are correlated by their
+  /// System Bytes alone.
+  /// 
   /// -------------------------------------------------------------------------
   /// 
   /// The [Connection State] must be in the [CONNECTED] state to use this
@@ -630,46 +729,31 @@ impl Client {
     reply_expected: bool,
     delay: Duration,
   ) -> Result<Option<Message>, Error> {
-    let (receiver, system) = {
-      // OUTBOX: LOCK
-      let outbox_lock = if reply_expected {Some(self.deref().outbox.lock().unwrap())} else {None};
-      // TX
-      let message_id = message.id;
-      match self.primitive_client.transmit(message.into()) {
-        // TX: Success
-        Ok(()) => {
-          match outbox_lock {
-            // REPLY NOT EXPECTED: Finish
-            None => return Ok(None),
-            // REPLY EXPECTED
-            Some(mut outbox) => {
-              // OUTBOX: Create Transaction
-              let (sender, receiver) = oneshot::channel::<Option<Message>>();
-              let system = {
-                let mut system_guard = self.deref().system.lock().unwrap();
-                let system_counter = system_guard.deref_mut();
-                let system = *system_counter;
-                *system_counter += 1;
-                system
-              };
-              outbox.deref_mut().insert(system, (message_id, sender));
-              (receiver, system)
-            }
-          }
-        },
-        // TX: Failure
-        Err(error) => {
-          // TO: NOT CONNECTED, NOT SELECTED
-          let _ = self.disconnect();
-          return Err(error)
-        },
-      }
+    let message_id = message.id;
+    // OUTBOX: Open Transaction
+    // The transaction is opened first, so that its response cannot arrive before it.
+    let transaction = match reply_expected {
+      true => match self.outbox.open(message_id) {
+        Some(transaction) => Some(transaction),
+        None => return Err(Error::from(ErrorKind::InvalidInput)),
+      },
+      false => None,
     };
+    // TX
+    if let Err(error) = self.primitive_client.transmit(message.into()) {
+      // TO: NOT CONNECTED, NOT SELECTED
+      if let Some((token, _)) = transaction {
+        self.outbox.abandon(message_id, token);
+      }
+      let _ = self.disconnect();
+      return Err(error)
+    }
+    // REPLY NOT EXPECTED: Finish
+    let Some((token, receiver)) = transaction else {return Ok(None)};
     // RX
     let rx_result = receiver.recv_timeout(delay);
-    // OUTBOX: Remove Transaction
-    let mut outbox = self.outbox.lock().unwrap();
-    outbox.deref_mut().remove(&system);
+    // OUTBOX: Abandon Transaction
+    self.outbox.abandon(message_id, token);
     match rx_result {
       // RX: Success
       Ok(rx_message) => Ok(rx_message),
@@ -695,6 +779,11 @@ impl Client {
   /// consider it a communications failure and initiate the
   /// [Disconnect Procedure].
   /// 
+  /// Results in an error of kind [InvalidInput], without transmitting, if the
+  /// [Reply Bit] of the [Data Message] is not permitted by the standard, or
+  /// if a reply is expected and another transaction with the same
+  /// System Bytes is still open.
+  /// 
   /// -------------------------------------------------------------------------
   /// 
   /// Although not done within this function, a [Client] in the [CONNECTED]
@@ -724,59 +813,208 @@ impl Client {
   /// [Reject Procedure]:     Client::reject
   /// [Data Message]:         MessageContents::DataMessage
   /// [Reject.req]:           MessageContents::RejectRequest
+  /// [InvalidInput]:         ErrorKind::InvalidInput
+  /// [Reply Bit]:            semi_e5::Message::w
   pub fn data(
     self: &Arc<Self>,
     id: MessageID,
     message: semi_e5::Message,
   ) -> JoinHandle<Result<Option<semi_e5::Message>, Error>> {
     let clone: Arc<Client> = self.clone();
+    thread::spawn(move || clone.data_procedure(id, message))
+  }
+
+  /// ### RESPOND PROCEDURE
+  /// **Based on SEMI E37-1109§7.5-7.6**
+  /// 
+  /// Asks the [Client] to complete the [Data Procedure] for a [Data Message]
+  /// to which no response is expected, such as the Response [Data Message]
+  /// to a Primary [Data Message] provided by the [Connect Procedure].
+  /// 
+  /// Unlike the [Data Procedure], the [Data Message] is transmitted on the
+  /// calling thread, such that a thread handling each received message in
+  /// turn may reply to it without spawning a thread per message.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// 
+  /// Results in an error of kind [InvalidInput], without transmitting, if a
+  /// response to the [Data Message] would be expected, or if its [Reply Bit]
+  /// is not permitted by the standard.
+  /// 
+  /// [Connection State]:  primitive::ConnectionState
+  /// [CONNECTED]:         primitive::ConnectionState::Connected
+  /// [Selection State]:   SelectionState
+  /// [SELECTED]:          SelectionState::Selected
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [Data Procedure]:    Client::data
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [InvalidInput]:      ErrorKind::InvalidInput
+  /// [Reply Bit]:         semi_e5::Message::w
+  pub fn respond(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: semi_e5::Message,
+  ) -> Result<(), Error> {
+    if message.function % 2 == 1 && message.w {
+      return Err(Error::from(ErrorKind::InvalidInput))
+    }
+    self.data_procedure(id, message).map(|_| ())
+  }
+
+  /// ### PERFORM DATA PROCEDURE
+  /// 
+  /// Performs the [Data Procedure] on the calling thread.
+  /// 
+  /// [Data Procedure]: Client::data
+  fn data_procedure(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: semi_e5::Message,
+  ) -> Result<Option<semi_e5::Message>, Error> {
     let reply_expected: bool = message.function % 2 == 1 && message.w;
+    // CHECK: Reply Bit
+    if message.check_reply().is_err() {
+      return Err(Error::from(ErrorKind::InvalidInput))
+    }
+    match self.selection_state.load(Relaxed) {
+      // IS: NOT SELECTED
+      SelectionState::NotSelected => Err(Error::from(ErrorKind::AlreadyExists)),
+      // IS: SELECTED
+      SelectionState::Selected => {
+        // TX: Data Message
+        match self.transmit(
+          Message {
+            id,
+            contents: MessageContents::DataMessage(message),
+          },
+          reply_expected,
+          self.parameter_settings.t3,
+        )?{
+          // RX: Response
+          Some(rx_message) => {
+            match rx_message.contents {
+              // RX: Data
+              MessageContents::DataMessage(data_message) => Ok(Some(data_message)),
+              // RX: Reject.req
+              MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+              // RX: Unknown
+              _ => Err(Error::from(ErrorKind::InvalidData)),
+            }
+          },
+          // RX: No Response
+          None => {
+            // REPLY EXPECTED
+            if reply_expected {
+              // TO: NOT CONNECTED
+              self.disconnect()?;
+              Err(Error::from(ErrorKind::ConnectionAborted))
+              // TODO: HSMS-SS does NOT disconnect when the Data Procedure fails, may require this behavior to be optional.
+            }
+            // REPLY NOT EXPECTED
+            else {
+              Ok(None)
+            }
+          },
+        }
+      },
+    }
+  }
+
+  /// ### TYPED DATA PROCEDURE
+  /// 
+  /// Performs the [Data Procedure] with a [Primary Message], converting the
+  /// Response [Data Message] into the corresponding [Reply] type.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// In addition to the errors produced by the [Data Procedure], results in
+  /// an error of kind [InvalidData] if no Response [Data Message] is received
+  /// or it cannot be converted into the [Reply] type, and in an error of kind
+  /// [Interrupted] if the transaction is aborted by an [Abort] message.
+  /// 
+  /// [Data Procedure]:  Client::data
+  /// [Data Message]:    MessageContents::DataMessage
+  /// [Primary Message]: semi_e5::messages::PrimaryMessage
+  /// [Reply]:           semi_e5::messages::PrimaryMessage::Reply
+  /// [InvalidData]:     ErrorKind::InvalidData
+  /// [Interrupted]:     ErrorKind::Interrupted
+  /// [Abort]:           semi_e5::messages::Abort
+  pub fn request<P>(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: P,
+  ) -> JoinHandle<Result<P::Reply, Error>>
+  where
+    P: PrimaryMessage,
+    P::Reply: Send + 'static,
+  {
+    let data = self.data(id, message.into());
     thread::spawn(move || {
-      match clone.selection_state.load(Relaxed) {
-        // IS: NOT SELECTED
-        SelectionState::NotSelected => Err(Error::from(ErrorKind::AlreadyExists)),
-        // IS: SELECTED
-        SelectionState::Selected => {
-          // TX: Data Message
-          match clone.transmit(
-            Message {
-              id,
-              contents: MessageContents::DataMessage(message),
-            },
-            reply_expected,
-            clone.parameter_settings.t3,
-          )?{
-            // RX: Response
-            Some(rx_message) => {
-              match rx_message.contents {
-                // RX: Data
-                MessageContents::DataMessage(data_message) => Ok(Some(data_message)),
-                // RX: Reject.req
-                MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
-                // RX: Unknown
-                _ => Err(Error::from(ErrorKind::InvalidData)),
-              }
-            },
-            // RX: No Response
-            None => {
-              // REPLY EXPECTED
-              if reply_expected {
-                // TO: NOT CONNECTED
-                clone.disconnect()?;
-                Err(Error::from(ErrorKind::ConnectionAborted))
-                // TODO: HSMS-SS does NOT disconnect when the Data Procedure fails, may require this behavior to be optional.
-              }
-              // REPLY NOT EXPECTED
-              else {
-                Ok(None)
-              }
-            },
-          }
-        },
+      match data.join().map_err(|_| Error::from(ErrorKind::Other))?? {
+        Some(reply) if reply.is_abort() => Err(Error::from(ErrorKind::Interrupted)),
+        Some(reply) => P::Reply::try_from(reply).map_err(|_| Error::from(ErrorKind::InvalidData)),
+        None => Err(Error::from(ErrorKind::InvalidData)),
       }
     })
   }
 
+  /// ### MULTI-BLOCK DATA PROCEDURE
+  /// 
+  /// Performs the [Typed Data Procedure] with the [Inquire Message] which
+  /// must precede a [Multi-Block Message], and once permission to send it has
+  /// been granted, performs the [Typed Data Procedure] with the
+  /// [Multi-Block Message] itself.
+  /// 
+  /// The [Message ID]s of the [Inquire Message] and of the
+  /// [Multi-Block Message] are provided separately, as each begins its own
+  /// transaction.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// In addition to the errors produced by the [Typed Data Procedure],
+  /// results in an error of kind [InvalidInput], without transmitting, if the
+  /// [Multi-Block Message] cannot be converted back from its serialized form
+  /// after measuring its length, and in an error of kind [PermissionDenied],
+  /// without transmitting the [Multi-Block Message], if permission to send
+  /// it is not granted.
+  /// 
+  /// [Typed Data Procedure]: Client::request
+  /// [Inquire Message]:      semi_e5::messages::InquireMessage
+  /// [Multi-Block Message]:  semi_e5::messages::MultiBlockMessage
+  /// [Message ID]:           MessageID
+  /// [InvalidInput]:         ErrorKind::InvalidInput
+  /// [PermissionDenied]:     ErrorKind::PermissionDenied
+  pub fn request_multi_block<P>(
+    self: &Arc<Self>,
+    inquire_id: MessageID,
+    id: MessageID,
+    message: P,
+  ) -> JoinHandle<Result<P::Reply, Error>>
+  where
+    P: MultiBlockMessage + Send + 'static,
+    P::Reply: Send + 'static,
+    <P::Inquire as PrimaryMessage>::Reply: Send + 'static,
+  {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      // MEASURE: Text Length
+      let message: semi_e5::Message = message.into();
+      let length: usize = message.text.as_ref().map_or(0, semi_e5::Item::encoded_size);
+      let message: P = P::try_from(message).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+      // TX: Inquire
+      let grant = clone.request(inquire_id, message.inquire(length)).join().map_err(|_| Error::from(ErrorKind::Other))??;
+      if !P::Inquire::is_granted(&grant) {
+        return Err(Error::from(ErrorKind::PermissionDenied))
+      }
+      // TX: Multi-Block Message
+      clone.request(id, message).join().map_err(|_| Error::from(ErrorKind::Other))?
+    })
+  }
+
   /// ### SELECT PROCEDURE
   /// **Based on SEMI E37-1109§7.3-7.4**
   /// 
@@ -1094,6 +1332,26 @@ impl Client {
   ) -> Result<(), Error> {
     todo!()
   }
+
+  /// ### MONITOR PROCEDURE
+  /// 
+  /// Provides a hook to which a copy of every [Primitive Message]
+  /// successfully received or transmitted is sent, along with its
+  /// [Direction], as with the [Primitive Client]'s [Monitor Procedure].
+  /// 
+  /// Each may be converted into a [Message] with [TryFrom].
+  /// 
+  /// [Message]:           Message
+  /// [Primitive Message]: primitive::Message
+  /// [Primitive Client]:  primitive::Client
+  /// [Monitor Procedure]: primitive::Client::set_monitor
+  /// [Direction]:         Direction
+  pub fn set_monitor(
+    &self,
+    monitor: Option<Sender<(Direction, primitive::Message)>>,
+  ) {
+    self.primitive_client.set_monitor(monitor);
+  }
 }
 
 /// ## SELECTION STATE
@@ -1236,6 +1494,34 @@ pub struct ParameterSettings {
   /// [Primitive Message]:    primitive::Message
   /// [Client]:               Client
   pub t8: Duration,
+
+  /// ### DECODE LIMITS
+  /// 
+  /// The [Decode Limits] that the [Client] will enforce when converting the
+  /// text of a received [Data Message] into an [Item], which is rejected as
+  /// malformed if they are exceeded.
+  /// 
+  /// The text of a received [Data Message] is read into memory in full
+  /// before it is converted, so the [Client] also refuses any message with
+  /// more text than an [Item] within the [Maximum Size] and
+  /// [Maximum Elements] could occupy, breaking the connection.
+  /// 
+  /// With the `spill` feature of [SECS-II], the [Maximum Resident] size
+  /// allows large [ASCII] and [Binary] [Item]s to be stored in temporary files
+  /// rather than in memory once converted, while the received text itself
+  /// is held in memory until the conversion is complete.
+  /// 
+  /// [Client]:           Client
+  /// [Data Message]:     MessageContents::DataMessage
+  /// [SECS-II]:          semi_e5
+  /// [Item]:             semi_e5::Item
+  /// [ASCII]:            semi_e5::Item::Ascii
+  /// [Binary]:           semi_e5::Item::Bin
+  /// [Decode Limits]:    semi_e5::DecodeLimits
+  /// [Maximum Size]:     semi_e5::DecodeLimits::max_size
+  /// [Maximum Elements]: semi_e5::DecodeLimits::max_elements
+  /// [Maximum Resident]: semi_e5::DecodeLimits::max_resident
+  pub decode_limits: semi_e5::DecodeLimits,
 }
 impl Default for ParameterSettings {
   /// ### DEFAULT PARAMETER SETTINGS
@@ -1250,6 +1536,7 @@ impl Default for ParameterSettings {
   /// - [T6] of 5 seconds
   /// - [T7] of 10 seconds
   /// - [T8] of 5 seconds
+  /// - The default [Decode Limits]
   /// 
   /// [Parameter Settings]: ParameterSettings
   /// [PASSIVE]:            ConnectionMode::Passive
@@ -1259,6 +1546,7 @@ impl Default for ParameterSettings {
   /// [T6]:                 ParameterSettings::t6
   /// [T7]:                 ParameterSettings::t7
   /// [T8]:                 ParameterSettings::t8
+  /// [Decode Limits]:      ParameterSettings::decode_limits
   fn default() -> Self {
     Self {
       connect_mode: ConnectionMode::default(),
@@ -1267,6 +1555,7 @@ impl Default for ParameterSettings {
       t6: Duration::from_secs(5),
       t7: Duration::from_secs(10),
       t8: Duration::from_secs(5),
+      decode_limits: semi_e5::DecodeLimits::default(),
     }
   }
 }
@@ -1432,19 +1721,38 @@ impl TryFrom<primitive::Message> for Message {
   /// [Generic Message]:   Message
   /// [Primitive Message]: primitive::Message
   fn try_from(message: primitive::Message) -> Result<Self, Self::Error> {
-    if message.header.presentation_type != 0 {return Err(RejectReason::UnsupportedPresentationType)}
+    Message::decode(message.header, &message.text, &semi_e5::DecodeLimits::default())
+  }
+}
+impl Message {
+  /// ### DECODE
+  /// 
+  /// Interprets a [Message Header] and borrowed [Message Text] as a
+  /// [Generic Message], leaving the [Message Text] to be reused, subject to
+  /// the [Decode Limits] provided.
+  /// 
+  /// [Generic Message]: Message
+  /// [Message Header]:  primitive::MessageHeader
+  /// [Message Text]:    primitive::Message::text
+  /// [Decode Limits]:   semi_e5::DecodeLimits
+  fn decode(
+    header: primitive::MessageHeader,
+    text: &[u8],
+    limits: &semi_e5::DecodeLimits,
+  ) -> Result<Self, RejectReason> {
+    if header.presentation_type != 0 {return Err(RejectReason::UnsupportedPresentationType)}
     Ok(Message {
       id: MessageID {
-        session: message.header.session_id,
-        system: message.header.system,
+        session: header.session_id,
+        system: header.system,
       },
-      contents: match message.header.session_type {
+      contents: match header.session_type {
         0 => {
           MessageContents::DataMessage(semi_e5::Message{
-            stream   : message.header.byte_2 & 0b0111_1111,
-            function : message.header.byte_3,
-            w        : message.header.byte_2 & 0b1000_0000 > 0,
-            text     : match semi_e5::Item::try_from(message.text) {
+            stream   : header.byte_2 & 0b0111_1111,
+            function : header.byte_3,
+            w        : header.byte_2 & 0b1000_0000 > 0,
+            text     : match semi_e5::Item::decode(text, limits) {
               // Valid Item
               Ok(text) => Some(text),
               // Invalid Item
@@ -1460,49 +1768,49 @@ impl TryFrom<primitive::Message> for Message {
           })
         },
         1 => {
-          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
-          if message.header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
+          if header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()   {return Err(RejectReason::MalformedData)}
           MessageContents::SelectRequest
         },
         2 => {
-          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
-          MessageContents::SelectResponse(message.header.byte_3)
+          if header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()   {return Err(RejectReason::MalformedData)}
+          MessageContents::SelectResponse(header.byte_3)
         },
         3 => {
-          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
-          if message.header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
+          if header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()   {return Err(RejectReason::MalformedData)}
           MessageContents::DeselectRequest
         },
         4 => {
-          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
-          MessageContents::DeselectResponse(message.header.byte_3)
+          if header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()   {return Err(RejectReason::MalformedData)}
+          MessageContents::DeselectResponse(header.byte_3)
         },
         5 => {
-          if message.header.session_id != 0xFFFF {return Err(RejectReason::MalformedData)}
-          if message.header.byte_2     != 0      {return Err(RejectReason::MalformedData)}
-          if message.header.byte_3     != 0      {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()            {return Err(RejectReason::MalformedData)}
+          if header.session_id != 0xFFFF {return Err(RejectReason::MalformedData)}
+          if header.byte_2     != 0      {return Err(RejectReason::MalformedData)}
+          if header.byte_3     != 0      {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()            {return Err(RejectReason::MalformedData)}
           MessageContents::LinktestRequest
         },
         6 => {
-          if message.header.session_id != 0xFFFF {return Err(RejectReason::MalformedData)}
-          if message.header.byte_2     != 0      {return Err(RejectReason::MalformedData)}
-          if message.header.byte_3     != 0      {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()            {return Err(RejectReason::MalformedData)}
+          if header.session_id != 0xFFFF {return Err(RejectReason::MalformedData)}
+          if header.byte_2     != 0      {return Err(RejectReason::MalformedData)}
+          if header.byte_3     != 0      {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()            {return Err(RejectReason::MalformedData)}
           MessageContents::LinktestResponse
         },
         7 => {
-          if !message.text.is_empty() {return Err(RejectReason::MalformedData)}
-          MessageContents::RejectRequest(message.header.byte_2, message.header.byte_3)
+          if !text.is_empty() {return Err(RejectReason::MalformedData)}
+          MessageContents::RejectRequest(header.byte_2, header.byte_3)
         },
         9 => {
-          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
-          if message.header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
-          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
+          if header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if header.byte_3 != 0 {return Err(RejectReason::MalformedData)}
+          if !text.is_empty()   {return Err(RejectReason::MalformedData)}
           MessageContents::SeparateRequest
         },
         _ => {return Err(RejectReason::UnsupportedSessionType)}
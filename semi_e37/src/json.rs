@@ -0,0 +1,173 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # JSON LOG
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [JSON Logger] writes every [Primitive Message] received or transmitted
+//! by a [Client] as one JSON object per line, such that the log may be
+//! ingested by tools such as Logstash or Grafana Loki without conversion.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each [Record] is written as an object with the following members:
+//! 
+//! - `time` - The time in UTC, as `YYYY-MM-DDTHH:MM:SS.ffffffZ`.
+//! - `direction` - `"received"` or `"transmitted"`.
+//! - `session` - The Session ID.
+//! - `system` - The System Bytes.
+//! - `type` - `"data"`, `"select.req"`, `"select.rsp"`, `"deselect.req"`,
+//!   `"deselect.rsp"`, `"linktest.req"`, `"linktest.rsp"`, `"reject.req"`,
+//!   `"separate.req"`, or `"invalid"` if the message could not be decoded.
+//! 
+//! A data message also has the following members:
+//! 
+//! - `sxfy` - The stream and function, as `"S1F13"`.
+//! - `stream` - The stream.
+//! - `function` - The function.
+//! - `w` - Whether a reply is requested.
+//! - `body` - The [Item] of the message as a [JSON Item], or `null`.
+//! 
+//! These are the members written by the [JSON Message] mapping of [SECS-II].
+//! 
+//! A Select or Deselect Response also has `status`, and a Reject Request
+//! also has `rejected`, the type being rejected, and `reason`.
+//! 
+//! [Primitive Message]: primitive::Message
+//! [Client]:            Client
+//! [JSON Logger]:       JsonLogger
+//! [Record]:            Record
+//! [Item]:              semi_e5::Item
+//! [JSON Item]:         JsonItem
+//! [JSON Message]:      semi_e5::json::JsonMessage
+//! [SECS-II]:           semi_e5
+
+use std::{
+  fmt::{Display, Formatter},
+  io::{Error, ErrorKind, Write},
+  sync::{Arc, mpsc::channel},
+  thread::{self, JoinHandle},
+  time::{SystemTime, UNIX_EPOCH},
+};
+pub use semi_e5::json::JsonItem;
+use semi_e5::json::JsonMessage;
+use crate::{
+  generic::{Client, Direction, Message, MessageContents},
+  primitive,
+  record::Record,
+  trace::civil_from_days,
+};
+
+/// ## JSON RECORD
+/// 
+/// Writes a [Record] as a JSON object on a single line, as described in the
+/// [module documentation].
+/// 
+/// [Record]:               Record
+/// [module documentation]: self
+pub struct JsonRecord<'a>(pub &'a Record);
+impl Display for JsonRecord<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let record: &Record = self.0;
+    let time = record.time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let (year, month, day) = civil_from_days((time.as_secs() / 86400) as i64);
+    let seconds: u64 = time.as_secs() % 86400;
+    write!(
+      f,
+      "{{\"time\":\"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z\",\"direction\":\"{}\",\"session\":{},\"system\":{},",
+      year, month, day,
+      seconds / 3600, seconds / 60 % 60, seconds % 60, time.subsec_micros(),
+      match record.direction {
+        Direction::Received    => "received",
+        Direction::Transmitted => "transmitted",
+      },
+      record.message.header.session_id, record.message.header.system,
+    )?;
+    match Message::try_from(record.message.clone()) {
+      Ok(message) => match message.contents {
+        MessageContents::DataMessage(data) => {
+          write!(f, "\"type\":\"data\",")?;
+          JsonMessage(&data).fmt_members(f)?;
+          write!(f, "}}")
+        },
+        MessageContents::SelectRequest            => write!(f, "\"type\":\"select.req\"}}"),
+        MessageContents::SelectResponse(status)   => write!(f, "\"type\":\"select.rsp\",\"status\":{status}}}"),
+        MessageContents::DeselectRequest          => write!(f, "\"type\":\"deselect.req\"}}"),
+        MessageContents::DeselectResponse(status) => write!(f, "\"type\":\"deselect.rsp\",\"status\":{status}}}"),
+        MessageContents::LinktestRequest          => write!(f, "\"type\":\"linktest.req\"}}"),
+        MessageContents::LinktestResponse         => write!(f, "\"type\":\"linktest.rsp\"}}"),
+        MessageContents::RejectRequest(rejected, reason) => write!(f, "\"type\":\"reject.req\",\"rejected\":{rejected},\"reason\":{reason}}}"),
+        MessageContents::SeparateRequest          => write!(f, "\"type\":\"separate.req\"}}"),
+      },
+      Err(_) => write!(f, "\"type\":\"invalid\"}}"),
+    }
+  }
+}
+
+/// ## JSON LOGGER
+/// 
+/// Writes every [Primitive Message] received or transmitted by a [Client]
+/// to a writer as a [JSON Record] per line, from when it is started until it
+/// is stopped, flushing after each line.
+/// 
+/// [Primitive Message]: primitive::Message
+/// [Client]:            Client
+/// [JSON Record]:       JsonRecord
+pub struct JsonLogger {
+  client: Arc<Client>,
+  thread: JoinHandle<Result<(), Error>>,
+}
+impl JsonLogger {
+  /// ### START
+  /// 
+  /// Begins logging with the [Client]'s [Monitor Procedure], replacing any
+  /// hook provided to it before.
+  /// 
+  /// [Client]:            Client
+  /// [Monitor Procedure]: Client::set_monitor
+  pub fn start(
+    client: &Arc<Client>,
+    mut writer: impl Write + Send + 'static,
+  ) -> Self {
+    let (sender, receiver) = channel::<(Direction, primitive::Message)>();
+    client.set_monitor(Some(sender));
+    let thread = thread::spawn(move || {
+      for (direction, message) in receiver {
+        let record = Record {time: SystemTime::now(), direction, message};
+        writeln!(writer, "{}", JsonRecord(&record))?;
+        writer.flush()?;
+      }
+      Ok(())
+    });
+    JsonLogger {client: client.clone(), thread}
+  }
+
+  /// ### STOP
+  /// 
+  /// Removes the hook from the [Client], and waits for every message
+  /// observed to be written.
+  /// 
+  /// [Client]: Client
+  pub fn stop(self) -> Result<(), Error> {
+    self.client.set_monitor(None);
+    self.thread.join().map_err(|_| Error::from(ErrorKind::Other))?
+  }
+}
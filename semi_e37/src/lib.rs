@@ -55,6 +55,20 @@
 //!   protocol to scenarios involving a single host/equipment pair in
 //!   communication.
 //!    - Not yet implemented.
+//! - [Record and Replay] - Persists the messages exchanged by a client to a
+//!   log, and resends a recorded session to a peer.
+//! - [Trace Log] - Reads and writes the textual trace logs of other SECS
+//!   drivers, in which data messages are written in SML.
+//! - [JSON Log] - Writes the messages exchanged by a client as one JSON object
+//!   per line, for ingestion by log aggregation tools.
+//! - [Conformance Checks] - Exercises a Remote Entity's implementation of the
+//!   protocol, producing a report of which requirements it meets.
+//! - [Load Generation] - Drives a mix of data messages against a Remote
+//!   Entity at configurable rates, measuring latency percentiles and error
+//!   rates.
+//! - Gateway - Exposes the connection of a client over WebSocket, so that
+//!   services not written in Rust may exchange messages through it.
+//!    - Requires the **tungstenite** feature.
 //! 
 //! ---------------------------------------------------------------------------
 //! 
@@ -74,9 +88,21 @@
 //! [SECS-II]:            semi_e5
 //! [Primitive Services]: primitive
 //! [Generic Services]:   generic
+//! [Record and Replay]:  record
+//! [Trace Log]:          trace
+//! [JSON Log]:           json
+//! [Conformance Checks]: conformance
+//! [Load Generation]:    load
 
 pub mod primitive;
 pub mod generic;
+pub mod record;
+pub mod trace;
+pub mod json;
+pub mod conformance;
+pub mod load;
+#[cfg(feature = "tungstenite")]
+pub mod gateway;
 
 /// ## PRESENTATION TYPE
 /// **Based on SEMI E37-1109§8.2.6.4**
@@ -0,0 +1,381 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # LOAD GENERATION
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Drives a configurable mix of data messages against a Remote Entity through
+//! a [Client], each [Traffic] being sent at its own rate, and measures the
+//! latency and outcome of every transaction, so that hosts may be sized and
+//! implementations validated under production load.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Generate Procedure] sends each message when it is due, rather than
+//! when the previous transaction completes, such that a slow Remote Entity is
+//! not able to lower the offered load. If the generator falls behind, due
+//! messages are sent immediately until it has caught up.
+//! 
+//! The latency of a transaction is measured from just before its primary
+//! message is sent until its reply is received, or if no reply is requested,
+//! until the message has been written to the TCP/IP connection.
+//! 
+//! [Client]:             Client
+//! [Traffic]:            Traffic
+//! [Generate Procedure]: generate
+
+use std::{
+  fmt::Display,
+  io::ErrorKind,
+  str::FromStr,
+  sync::{Arc, mpsc::channel},
+  thread,
+  time::{Duration, Instant},
+};
+use semi_e5::{Item, Payload};
+use crate::generic::{Client, MessageID, SelectionState};
+
+/// ## TRAFFIC
+/// 
+/// A data message to be sent repeatedly at a fixed rate.
+/// 
+/// It may be parsed from text of the form `S6F11W:500:1024`, being the
+/// stream and function, `W` if a reply is requested, the rate in messages per
+/// second, and optionally the size of the [Payload].
+/// 
+/// [Payload]: Traffic::payload
+#[derive(Clone, Debug)]
+pub struct Traffic {
+  /// ### MESSAGE
+  /// 
+  /// The message which is sent, whose System Bytes are assigned when it is
+  /// sent.
+  pub message: semi_e5::Message,
+
+  /// ### RATE
+  /// 
+  /// The number of messages sent per second.
+  pub rate: f64,
+}
+impl Traffic {
+  /// ### PAYLOAD
+  /// 
+  /// Constructs [Traffic] whose message has the given stream, function, and
+  /// reply bit, and whose text is a binary item with the given number of
+  /// bytes, or is absent if this is zero.
+  /// 
+  /// The bytes are shared between every message sent, such that large
+  /// payloads are not copied.
+  /// 
+  /// [Traffic]: Traffic
+  pub fn payload(stream: u8, function: u8, w: bool, size: usize, rate: f64) -> Self {
+    let text: Option<Item> = match size {
+      0 => None,
+      _ => Some(Item::Bin(Payload::Shared(vec![0; size].into()))),
+    };
+    Self {
+      message: semi_e5::Message {stream, function, w, text},
+      rate,
+    }
+  }
+
+  /// ### NAME
+  /// 
+  /// The stream, function, and reply bit of the message, such as `S6F11 W`.
+  pub fn name(&self) -> String {
+    let w: &str = if self.message.w {" W"} else {""};
+    format!("S{}F{}{w}", self.message.stream, self.message.function)
+  }
+}
+impl FromStr for Traffic {
+  type Err = ();
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    let mut fields = text.split(':');
+    let header: &str = fields.next().ok_or(())?;
+    let rate: f64 = fields.next().ok_or(())?.parse().map_err(|_| ())?;
+    let size: usize = match fields.next() {
+      Some(size) => size.parse().map_err(|_| ())?,
+      None => 0,
+    };
+    if fields.next().is_some() || !rate.is_finite() || rate <= 0.0 {
+      return Err(())
+    }
+    let header: String = header.to_ascii_uppercase();
+    let (header, w) = match header.strip_suffix('W') {
+      Some(header) => (header, true),
+      None => (header.as_str(), false),
+    };
+    let (stream, function) = header.strip_prefix('S').and_then(|header| header.split_once('F')).ok_or(())?;
+    let stream: u8 = stream.parse().map_err(|_| ())?;
+    let function: u8 = function.parse().map_err(|_| ())?;
+    if stream > 127 {
+      return Err(())
+    }
+    Ok(Self::payload(stream, function, w, size, rate))
+  }
+}
+
+/// ## LOAD SETTINGS
+/// 
+/// The configurable behavior of the [Generate Procedure].
+/// 
+/// [Generate Procedure]: generate
+#[derive(Clone, Debug)]
+pub struct LoadSettings {
+  /// ### TRAFFIC
+  /// 
+  /// The messages sent, each at its own rate.
+  pub traffic: Vec<Traffic>,
+
+  /// ### DURATION
+  /// 
+  /// The amount of time for which messages are sent, after which the
+  /// outstanding transactions are waited for.
+  pub duration: Duration,
+
+  /// ### SESSION ID
+  /// 
+  /// The Session ID used in data messages.
+  pub session: u16,
+}
+impl Default for LoadSettings {
+  fn default() -> Self {
+    Self {
+      traffic: vec![],
+      duration: Duration::from_secs(10),
+      session: 0,
+    }
+  }
+}
+
+/// ## STATISTICS
+/// 
+/// The outcomes of the transactions of one or more [Traffic].
+/// 
+/// [Traffic]: Traffic
+#[derive(Clone, Debug, Default)]
+pub struct Statistics {
+  /// ### LATENCIES
+  /// 
+  /// The latency of each successful transaction, in ascending order.
+  pub latencies: Vec<Duration>,
+
+  /// ### ERRORS
+  /// 
+  /// The number of failed transactions by the kind of error which resulted.
+  pub errors: Vec<(ErrorKind, u64)>,
+}
+impl Statistics {
+  /// ### SUCCEEDED
+  /// 
+  /// The number of successful transactions.
+  pub fn succeeded(&self) -> u64 {
+    self.latencies.len() as u64
+  }
+
+  /// ### FAILED
+  /// 
+  /// The number of failed transactions.
+  pub fn failed(&self) -> u64 {
+    self.errors.iter().map(|(_, count)| count).sum()
+  }
+
+  /// ### SENT
+  /// 
+  /// The number of transactions, successful or not.
+  pub fn sent(&self) -> u64 {
+    self.succeeded() + self.failed()
+  }
+
+  /// ### ERROR RATE
+  /// 
+  /// The fraction of transactions which failed, from 0 to 1.
+  pub fn error_rate(&self) -> f64 {
+    match self.sent() {
+      0 => 0.0,
+      sent => self.failed() as f64 / sent as f64,
+    }
+  }
+
+  /// ### PERCENTILE
+  /// 
+  /// The latency within which the given percentage of successful
+  /// transactions completed, by the nearest-rank method, if any succeeded.
+  pub fn percentile(&self, percent: f64) -> Option<Duration> {
+    let rank: f64 = (percent.clamp(0.0, 100.0) / 100.0 * self.latencies.len() as f64).ceil();
+    let index: usize = (rank as usize).max(1) - 1;
+    self.latencies.get(index).copied()
+  }
+
+  /// ### ADD
+  /// 
+  /// Records the outcome of a transaction.
+  fn add(&mut self, result: Result<Duration, ErrorKind>) {
+    match result {
+      Ok(latency) => self.latencies.push(latency),
+      Err(kind) => match self.errors.iter_mut().find(|(error, _)| *error == kind) {
+        Some((_, count)) => *count += 1,
+        None => self.errors.push((kind, 1)),
+      },
+    }
+  }
+}
+
+/// ## LOAD REPORT
+/// 
+/// The result of the [Generate Procedure].
+/// 
+/// [Generate Procedure]: generate
+#[derive(Clone, Debug)]
+pub struct LoadReport {
+  /// ### ELAPSED
+  /// 
+  /// The amount of time for which messages were sent, which is less than the
+  /// [Duration] if the [Client] was no longer SELECTED.
+  /// 
+  /// [Duration]: LoadSettings::duration
+  /// [Client]:   Client
+  pub elapsed: Duration,
+
+  /// ### TRAFFIC
+  /// 
+  /// The [Statistics] of each [Traffic], in the order of the [Load Settings].
+  /// 
+  /// [Statistics]:    Statistics
+  /// [Traffic]:       Traffic
+  /// [Load Settings]: LoadSettings::traffic
+  pub traffic: Vec<(Traffic, Statistics)>,
+}
+impl LoadReport {
+  /// ### TOTAL
+  /// 
+  /// The [Statistics] of every [Traffic] combined.
+  /// 
+  /// [Statistics]: Statistics
+  /// [Traffic]:    Traffic
+  pub fn total(&self) -> Statistics {
+    let mut total: Statistics = Statistics::default();
+    for (_, statistics) in &self.traffic {
+      total.latencies.extend_from_slice(&statistics.latencies);
+      for &(kind, count) in &statistics.errors {
+        match total.errors.iter_mut().find(|(error, _)| *error == kind) {
+          Some((_, total)) => *total += count,
+          None => total.errors.push((kind, count)),
+        }
+      }
+    }
+    total.latencies.sort_unstable();
+    total
+  }
+}
+impl Display for LoadReport {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let seconds: f64 = self.elapsed.as_secs_f64().max(f64::EPSILON);
+    let milliseconds = |latency: Option<Duration>| match latency {
+      Some(latency) => format!("{:.3}", latency.as_secs_f64() * 1000.0),
+      None => "-".to_string(),
+    };
+    writeln!(f, "{:<12} {:>10} {:>10} {:>8} {:>8} {:>10} {:>10} {:>10} {:>10}", "MESSAGE", "RATE/S", "SENT", "FAILED", "ERROR%", "P50 MS", "P90 MS", "P99 MS", "MAX MS")?;
+    let total: Statistics = self.total();
+    let rows = self.traffic.iter().map(|(traffic, statistics)| (traffic.name(), statistics)).chain([("TOTAL".to_string(), &total)]);
+    for (name, statistics) in rows {
+      writeln!(
+        f, "{:<12} {:>10.1} {:>10} {:>8} {:>8.3} {:>10} {:>10} {:>10} {:>10}",
+        name,
+        statistics.sent() as f64 / seconds,
+        statistics.sent(),
+        statistics.failed(),
+        statistics.error_rate() * 100.0,
+        milliseconds(statistics.percentile(50.0)),
+        milliseconds(statistics.percentile(90.0)),
+        milliseconds(statistics.percentile(99.0)),
+        milliseconds(statistics.latencies.last().copied()),
+      )?;
+    }
+    for (kind, count) in &total.errors {
+      writeln!(f, "{count} failed: {kind}")?;
+    }
+    Ok(())
+  }
+}
+
+/// ## GENERATE PROCEDURE
+/// 
+/// Sends the messages of each [Traffic] through the [Client] at its rate for
+/// the [Duration], then waits for the outstanding transactions to complete,
+/// resulting in the [Load Report].
+/// 
+/// The [Client] must be SELECTED, and messages stop being sent if it is no
+/// longer SELECTED. System Bytes are assigned counting up from 1.
+/// 
+/// [Traffic]:     Traffic
+/// [Client]:      Client
+/// [Duration]:    LoadSettings::duration
+/// [Load Report]: LoadReport
+pub fn generate(client: &Arc<Client>, settings: &LoadSettings) -> LoadReport {
+  let (sender, receiver) = channel::<(usize, Result<Duration, ErrorKind>)>();
+  let mut counts: Vec<u64> = vec![0; settings.traffic.len()];
+  let mut system: u32 = 1;
+  let start: Instant = Instant::now();
+  let end: Instant = start + settings.duration;
+  loop {
+    // NEXT: The traffic whose next message is due soonest.
+    let next: Option<(usize, Instant)> = settings.traffic.iter().enumerate()
+      .filter(|(_, traffic)| traffic.rate.is_finite() && traffic.rate > 0.0)
+      .map(|(index, traffic)| (index, start + Duration::from_secs_f64(counts[index] as f64 / traffic.rate)))
+      .min_by_key(|&(_, due)| due);
+    let Some((index, due)) = next else {break};
+    if due >= end {break}
+    let now: Instant = Instant::now();
+    if due > now {
+      thread::sleep(due - now);
+    }
+    if client.selection_state() != SelectionState::Selected {break}
+    counts[index] += 1;
+    // TX
+    let id: MessageID = MessageID {session: settings.session, system};
+    system = system.wrapping_add(1).max(1);
+    let message: semi_e5::Message = settings.traffic[index].message.clone();
+    let client: Arc<Client> = client.clone();
+    let sender = sender.clone();
+    thread::spawn(move || {
+      let sent: Instant = Instant::now();
+      let result: Result<Duration, ErrorKind> = match client.data(id, message).join() {
+        Ok(Ok(_)) => Ok(sent.elapsed()),
+        Ok(Err(error)) => Err(error.kind()),
+        Err(_) => Err(ErrorKind::Other),
+      };
+      let _ = sender.send((index, result));
+    });
+  }
+  let elapsed: Duration = start.elapsed().min(settings.duration);
+  drop(sender);
+  // RX
+  let mut traffic: Vec<(Traffic, Statistics)> = settings.traffic.iter().map(|traffic| (traffic.clone(), Statistics::default())).collect();
+  for (index, result) in receiver {
+    traffic[index].1.add(result);
+  }
+  for (_, statistics) in &mut traffic {
+    statistics.latencies.sort_unstable();
+  }
+  LoadReport {elapsed, traffic}
+}
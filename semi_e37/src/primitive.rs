@@ -33,6 +33,7 @@ use std::{
   io::{
     Error,
     ErrorKind,
+    IoSlice,
     Read,
     Write,
   },
@@ -49,11 +50,16 @@ use std::{
   },
   sync::{
     Arc,
+    atomic::{
+      AtomicU32,
+      Ordering::Relaxed,
+    },
     mpsc::{
       channel,
       Receiver,
       Sender,
     },
+    Mutex,
     RwLock,
   },
   thread,
@@ -70,6 +76,8 @@ use std::{
 ///   [Disconnect Procedure].
 /// - Receive [Message]s with the hook provided by the [Connect Procedure].
 /// - Transmit [Message]s with the [Transmit Procedure].
+/// - Observe every [Message] received and transmitted with the
+///   [Monitor Procedure].
 /// 
 /// [HSMS]:                 crate
 /// [Primitive Services]:   crate::primitive
@@ -78,9 +86,15 @@ use std::{
 /// [Connect Procedure]:    Client::connect
 /// [Disconnect Procedure]: Client::disconnect
 /// [Transmit Procedure]:   Client::transmit
+/// [Monitor Procedure]:    Client::set_monitor
 /// [Connection State]:     ConnectionState
 pub struct Client {
   connection_state: RwLock<ConnectionState>,
+  transmit_queue: Mutex<TransmitQueue>,
+  transmitter: Mutex<Transmitter>,
+  monitor: Mutex<Option<Sender<(Direction, Message)>>>,
+  pool: BufferPool,
+  max_length: AtomicU32,
 }
 
 /// ## CONNECTION PROCEDURES
@@ -109,6 +123,11 @@ impl Client {
   pub fn new() -> Arc<Self> {
     Arc::new(Self {
       connection_state: Default::default(),
+      transmit_queue:   Default::default(),
+      transmitter:      Default::default(),
+      monitor:          Default::default(),
+      pool:             Default::default(),
+      max_length:       AtomicU32::new(u32::MAX),
     })
   }
 
@@ -187,6 +206,7 @@ impl Client {
     stream.set_read_timeout(Some(t8))?;
     stream.set_write_timeout(Some(t8))?;
     // TO: CONNECTED
+    self.transmitter.lock().unwrap().failed = None;
     *self.connection_state.write().unwrap().deref_mut() = ConnectionState::Connected(stream);
     // Create Channels
     let (rx_sender, rx_receiver) = channel::<Message>();
@@ -242,10 +262,15 @@ impl Client {
 /// exchanging [Message]s.
 /// 
 /// - [Transmit Procedure] - Any [Message]
+/// - [Monitor Procedure] - Any [Message]
+/// - [Recycle Procedure] - Any [Message Text]
 /// 
 /// [Client]:             Client
 /// [Transmit Procedure]: Client::transmit
+/// [Monitor Procedure]:  Client::set_monitor
+/// [Recycle Procedure]:  Client::recycle
 /// [Message]:            Message
+/// [Message Text]:       Message::text
 impl Client {
   /// ### RECEIVE PROCEDURE
   /// 
@@ -269,7 +294,7 @@ impl Client {
         let length_bytes: usize = match stream.read(&mut length_buffer) {
           Ok(l) => l,
           Err(error) => match error.kind() {
-            ErrorKind::TimedOut => {
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => {
               break 'rx Ok(None)
             },
             _ => {
@@ -277,43 +302,51 @@ impl Client {
             },
           }
         };
-        if length_bytes != 4 {
-          break 'rx Err(Error::from(ErrorKind::TimedOut))
+        if length_bytes == 0 {
+          break 'rx Err(Error::from(ErrorKind::UnexpectedEof))
+        }
+        // The remainder of a message must arrive within T8 of each read.
+        if let Err(error) = stream.read_exact(&mut length_buffer[length_bytes..]) {
+          break 'rx Err(error)
         }
         let length: u32 = u32::from_be_bytes(length_buffer);
-        if length < 10 {
+        if length < 10 || length > self.max_length.load(Relaxed) {
           break 'rx Err(Error::from(ErrorKind::InvalidData))
         }
-        // Header + Data [Bytes 4+]
-        let mut message_buffer: Vec<u8> = vec![0; length as usize];
-        let message_bytes: usize = match stream.read(&mut message_buffer) {
-          Ok(message_bytes) => message_bytes,
-          Err(error) => break 'rx Err(error),
-        };
-        if message_bytes != length as usize {
-          break 'rx Err(Error::from(ErrorKind::TimedOut))
+        // Header [Bytes 4-13]
+        let mut header_buffer: [u8;10] = [0;10];
+        if let Err(error) = stream.read_exact(&mut header_buffer) {
+          break 'rx Err(error)
+        }
+        // Data [Bytes 14+]
+        let mut text: Vec<u8> = self.pool.take(length as usize - 10);
+        text.resize(length as usize - 10, 0);
+        if let Err(error) = stream.read_exact(&mut text) {
+          self.pool.put(text);
+          break 'rx Err(error)
         }
         // Diagnostic
         /*println!(
           "rx {: >4X} {: >3}{} {: >3} {: >2X} {: >2X} {: >8X} {:?}",
-          u16::from_be_bytes(message_buffer[0..2].try_into().unwrap()),
-          &message_buffer[2] & 0b0111_1111,
-          if (&message_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
-          &message_buffer[3],
-          &message_buffer[4],
-          &message_buffer[5],
-          u32::from_be_bytes(message_buffer[6..10].try_into().unwrap()),
-          &message_buffer[10..],
+          u16::from_be_bytes(header_buffer[0..2].try_into().unwrap()),
+          &header_buffer[2] & 0b0111_1111,
+          if (&header_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
+          &header_buffer[3],
+          &header_buffer[4],
+          &header_buffer[5],
+          u32::from_be_bytes(header_buffer[6..10].try_into().unwrap()),
+          &text,
         );// */
         // Finish
-        match Message::try_from(message_buffer) {
-          Ok(message) => Ok(Some(message)),
-          Err(_) => break 'rx Err(Error::from(ErrorKind::InvalidData)),
-        }
+        Ok(Some(Message {
+          header: MessageHeader::from_bytes(&header_buffer),
+          text,
+        }))
       };
       match res {
         // RX: SUCCESS
         Ok(optional_rx_message) => if let Some(rx_message) = optional_rx_message {
+          self.observe(Direction::Received, &rx_message);
           if rx_sender.send(rx_message).is_err() {break}
         },
         // RX: FAILURE
@@ -328,6 +361,12 @@ impl Client {
   /// 
   /// Serializes a [Message] and transmits it over the TCP/IP connection.
   /// 
+  /// Each [Message] is written in full before another may be, such that this
+  /// procedure may be used by several threads at once. The length, header and
+  /// text of a [Message] are written with a single vectored write, and
+  /// [Message]s transmitted by other threads while a write is in progress are
+  /// written together afterwards, in the order they were transmitted.
+  /// 
   /// -------------------------------------------------------------------------
   /// 
   /// The [Connection State] must be in the [CONNECTED] state to use this
@@ -341,29 +380,64 @@ impl Client {
     message: Message,
   ) -> Result<(), Error> {
     match self.connection_state.read().unwrap().deref() {
-      ConnectionState::Connected(stream_immutable) => 'disconnect: {
-        let mut stream: &TcpStream = stream_immutable;
-        // Header + Data [Bytes 4+]
-        let message_buffer: Vec<u8> = (&message).into();
+      ConnectionState::Connected(stream) => 'disconnect: {
         // Length [Bytes 0-3]
-        let length: u32 = message_buffer.len() as u32;
-        let length_buffer: [u8; 4] = length.to_be_bytes();
+        let length: u32 = (message.text.len() + 10) as u32;
+        // Header [Bytes 4-13]
+        let header_buffer: [u8;10] = message.header.to_bytes();
+        let mut prefix: [u8;14] = [0;14];
+        prefix[0..4].copy_from_slice(&length.to_be_bytes());
+        prefix[4..14].copy_from_slice(&header_buffer);
         // Diagnostic
         /*println!(
           "tx {: >4X} {: >3}{} {: >3} {: >2X} {: >2X} {: >8X} {:?}",
-          u16::from_be_bytes(message_buffer[0..2].try_into().unwrap()),
-          &message_buffer[2] & 0b0111_1111,
-          if (&message_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
-          &message_buffer[3],
-          &message_buffer[4],
-          &message_buffer[5],
-          u32::from_be_bytes(message_buffer[6..10].try_into().unwrap()),
-          &message_buffer[10..],
+          u16::from_be_bytes(header_buffer[0..2].try_into().unwrap()),
+          &header_buffer[2] & 0b0111_1111,
+          if (&header_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
+          &header_buffer[3],
+          &header_buffer[4],
+          &header_buffer[5],
+          u32::from_be_bytes(header_buffer[6..10].try_into().unwrap()),
+          &message.text,
         );// */
+        // Queue
+        let sequence: u64 = {
+          let mut queue = self.transmit_queue.lock().unwrap();
+          queue.frames.push((prefix, message));
+          queue.queued += 1;
+          queue.queued
+        };
         // Write
-        if stream.write_all(&length_buffer).is_err() {break 'disconnect};
-        if stream.write_all(&message_buffer).is_err() {break 'disconnect};
+        // Whichever thread holds the transmitter writes every queued message,
+        // such that messages queued while it was busy share its system calls.
+        let mut transmitter = self.transmitter.lock().unwrap();
+        if transmitter.written < sequence {
+          let transmitter = transmitter.deref_mut();
+          let last: u64 = {
+            let mut queue = self.transmit_queue.lock().unwrap();
+            std::mem::swap(&mut transmitter.batch, &mut queue.frames);
+            queue.queued
+          };
+          // A failed connection is not written to again.
+          let result: Result<(), Error> = match transmitter.failed {
+            Some(_) => Err(Error::from(ErrorKind::ConnectionAborted)),
+            None => transmitter.batch
+              .chunks(Transmitter::CHUNK_FRAMES)
+              .try_for_each(|chunk| Transmitter::write_chunk(stream, chunk)),
+          };
+          if result.is_err() && transmitter.failed.is_none() {
+            transmitter.failed = Some(transmitter.written + 1);
+          }
+          for (_, message) in transmitter.batch.drain(..) {
+            if result.is_ok() {
+              self.observe(Direction::Transmitted, &message);
+            }
+            self.pool.put(message.text);
+          }
+          transmitter.written = last;
+        }
         // Finish
+        if transmitter.failed.is_some_and(|failed| failed <= sequence) {break 'disconnect}
         return Ok(())
       },
       ConnectionState::NotConnected => return Err(Error::from(ErrorKind::NotConnected)),
@@ -371,6 +445,235 @@ impl Client {
     self.disconnect()?;
     Err(Error::from(ErrorKind::ConnectionAborted))
   }
+
+  /// ### MONITOR PROCEDURE
+  /// 
+  /// Provides a hook to which a copy of every [Message] successfully
+  /// received or transmitted is sent, along with its [Direction], replacing
+  /// any hook provided before.
+  /// 
+  /// The hook is removed once its receiver is dropped, or when [None] is
+  /// provided.
+  /// 
+  /// [Message]:   Message
+  /// [Direction]: Direction
+  pub fn set_monitor(
+    &self,
+    monitor: Option<Sender<(Direction, Message)>>,
+  ) {
+    *self.monitor.lock().unwrap() = monitor;
+  }
+
+  /// ### MAXIMUM LENGTH PROCEDURE
+  /// 
+  /// Sets the greatest message length, including the Message Header, which
+  /// the [Client] will accept, which is unlimited by default.
+  /// 
+  /// The text of a received [Message] is read into memory in full, so a
+  /// longer message is not read at all, and instead breaks the connection
+  /// as though it were malformed.
+  /// 
+  /// [Client]:  Client
+  /// [Message]: Message
+  pub fn set_max_length(&self, length: u32) {
+    self.max_length.store(length, Relaxed);
+  }
+
+  /// ### RECYCLE PROCEDURE
+  /// 
+  /// Returns the [Message Text] of a received [Message] which is no longer
+  /// needed, such that its allocation may be reused by the
+  /// [Receive Procedure] and [Transmit Procedure].
+  /// 
+  /// Doing so is optional, but avoids a heap allocation per [Message] when
+  /// they are received at a high rate.
+  /// 
+  /// [Message]:            Message
+  /// [Message Text]:       Message::text
+  /// [Receive Procedure]:  Client::connect
+  /// [Transmit Procedure]: Client::transmit
+  pub fn recycle(&self, text: Vec<u8>) {
+    self.pool.put(text);
+  }
+
+  /// ### OBSERVE
+  /// 
+  /// Sends a copy of the [Message] to the hook provided by the
+  /// [Monitor Procedure], if any.
+  /// 
+  /// [Message]:           Message
+  /// [Monitor Procedure]: Client::set_monitor
+  fn observe(&self, direction: Direction, message: &Message) {
+    let mut monitor = self.monitor.lock().unwrap();
+    if let Some(sender) = monitor.as_ref() {
+      if sender.send((direction, message.clone())).is_err() {
+        *monitor = None;
+      }
+    }
+  }
+}
+
+/// ## DIRECTION
+/// 
+/// Whether a [Message] provided by the [Monitor Procedure] was received from
+/// or transmitted to the Remote Entity.
+/// 
+/// [Message]:           Message
+/// [Monitor Procedure]: Client::set_monitor
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+  /// ### RECEIVED
+  Received,
+
+  /// ### TRANSMITTED
+  Transmitted,
+}
+
+/// ## TRANSMIT QUEUE
+/// 
+/// The [Message]s which are waiting to be written by the [Transmitter],
+/// along with their serialized length and header.
+/// 
+/// [Message]:     Message
+/// [Transmitter]: Transmitter
+#[derive(Debug, Default)]
+struct TransmitQueue {
+  /// ### QUEUED
+  /// 
+  /// The number of [Message]s ever queued, which is the sequence number of
+  /// the latest.
+  /// 
+  /// [Message]: Message
+  queued: u64,
+
+  /// ### FRAMES
+  frames: Vec<([u8;14], Message)>,
+}
+
+/// ## TRANSMITTER
+/// 
+/// The state held by the thread currently writing to the TCP/IP connection,
+/// which takes every [Message] in the [Transmit Queue] at once and writes
+/// them with as few vectored writes as possible.
+/// 
+/// [Message]:        Message
+/// [Transmit Queue]: TransmitQueue
+#[derive(Debug, Default)]
+struct Transmitter {
+  /// ### BATCH
+  /// 
+  /// The [Message]s being written, retained to reuse its allocation.
+  /// 
+  /// [Message]: Message
+  batch: Vec<([u8;14], Message)>,
+
+  /// ### WRITTEN
+  /// 
+  /// The sequence number of the latest [Message] which has been written, or
+  /// which failed to be written.
+  /// 
+  /// [Message]: Message
+  written: u64,
+
+  /// ### FAILED
+  /// 
+  /// The sequence number of the first [Message] which failed to be written
+  /// over the current TCP/IP connection, if any.
+  /// 
+  /// [Message]: Message
+  failed: Option<u64>,
+}
+impl Transmitter {
+  /// ### CHUNK FRAMES
+  /// 
+  /// The largest number of [Message]s given to a single vectored write.
+  /// 
+  /// [Message]: Message
+  const CHUNK_FRAMES: usize = 32;
+
+  /// ### WRITE CHUNK
+  /// 
+  /// Writes the length, header and text of each [Message] in full, with as
+  /// few vectored writes as the TCP/IP connection allows.
+  /// 
+  /// [Message]: Message
+  fn write_chunk(
+    mut stream: &TcpStream,
+    chunk: &[([u8;14], Message)],
+  ) -> Result<(), Error> {
+    let mut slices: [IoSlice; Self::CHUNK_FRAMES * 2] = [IoSlice::new(&[]); Self::CHUNK_FRAMES * 2];
+    let mut count: usize = 0;
+    for (prefix, message) in chunk {
+      slices[count] = IoSlice::new(prefix);
+      count += 1;
+      if !message.text.is_empty() {
+        slices[count] = IoSlice::new(&message.text);
+        count += 1;
+      }
+    }
+    let mut slices: &mut [IoSlice] = &mut slices[..count];
+    while !slices.is_empty() {
+      match stream.write_vectored(slices) {
+        Ok(0) => return Err(Error::from(ErrorKind::WriteZero)),
+        Ok(written) => IoSlice::advance_slices(&mut slices, written),
+        Err(error) if error.kind() == ErrorKind::Interrupted => {},
+        Err(error) => return Err(error),
+      }
+    }
+    Ok(())
+  }
+}
+
+/// ## BUFFER POOL
+/// 
+/// Retains the allocations of buffers used to frame [Message]s once they are
+/// no longer needed, such that the [Client] does not need to allocate a new
+/// buffer for each [Message] received or transmitted.
+/// 
+/// At most [MAX_BUFFERS] buffers are retained, and a buffer whose capacity
+/// exceeds [MAX_CAPACITY] is dropped rather than retained.
+/// 
+/// [Message]:      Message
+/// [Client]:       Client
+/// [MAX_BUFFERS]:  BufferPool::MAX_BUFFERS
+/// [MAX_CAPACITY]: BufferPool::MAX_CAPACITY
+#[derive(Debug, Default)]
+struct BufferPool {
+  buffers: Mutex<Vec<Vec<u8>>>,
+}
+impl BufferPool {
+  /// ### MAX BUFFERS
+  /// 
+  /// The largest number of buffers retained at once.
+  const MAX_BUFFERS: usize = 64;
+
+  /// ### MAX CAPACITY
+  /// 
+  /// The largest capacity, in bytes, of a buffer which will be retained.
+  const MAX_CAPACITY: usize = 1 << 20;
+
+  /// ### TAKE
+  /// 
+  /// Provides an empty buffer with at least the requested capacity, reusing a
+  /// retained buffer if one is available.
+  fn take(&self, capacity: usize) -> Vec<u8> {
+    let mut buffer: Vec<u8> = self.buffers.lock().unwrap().pop().unwrap_or_default();
+    buffer.reserve(capacity);
+    buffer
+  }
+
+  /// ### PUT
+  /// 
+  /// Retains a buffer which is no longer needed, unless the pool is full or
+  /// the buffer is too large.
+  fn put(&self, mut buffer: Vec<u8>) {
+    if buffer.capacity() == 0 || buffer.capacity() > Self::MAX_CAPACITY {return}
+    buffer.clear();
+    let mut buffers = self.buffers.lock().unwrap();
+    if buffers.len() < Self::MAX_BUFFERS {
+      buffers.push(buffer);
+    }
+  }
 }
 
 /// ## CONNECTION STATE
@@ -506,10 +809,9 @@ impl From<&Message> for Vec<u8> {
   /// 
   /// [Message]: Message
   fn from(val: &Message) -> Self {
-    let mut vec: Vec<u8> = vec![];
-    let header_bytes: [u8;10] = val.header.into();
-    vec.extend(header_bytes.iter());
-    vec.extend(&val.text);
+    let mut vec: Vec<u8> = Vec::with_capacity(MessageHeader::SIZE + val.text.len());
+    vec.extend_from_slice(&val.header.to_bytes());
+    vec.extend_from_slice(&val.text);
     vec
   }
 }
@@ -522,10 +824,10 @@ impl TryFrom<Vec<u8>> for Message {
   /// 
   /// [Message]: Message
   fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-    if bytes.len() < 10 {return Err(())}
+    let Some(header_bytes) = bytes.first_chunk::<{MessageHeader::SIZE}>() else {return Err(())};
     Ok(Self {
-      header: MessageHeader::from(<[u8;10]>::try_from(&bytes[0..10]).map_err(|_| ())?),
-      text: bytes[10..].to_vec(),
+      header: MessageHeader::from_bytes(header_bytes),
+      text: bytes[MessageHeader::SIZE..].to_vec(),
     })
   }
 }
@@ -594,6 +896,52 @@ pub struct MessageHeader {
   /// [Message]: Message
   pub system : u32,
 }
+impl MessageHeader {
+  /// ### SIZE
+  /// 
+  /// The number of bytes a [Message Header] occupies when serialized.
+  /// 
+  /// [Message Header]: MessageHeader
+  pub const SIZE: usize = 10;
+
+  /// ### SERIALIZE MESSAGE HEADER
+  /// 
+  /// Converts a [Message Header] into raw bytes, without allocating.
+  /// 
+  /// [Message Header]: MessageHeader
+  pub const fn to_bytes(&self) -> [u8; Self::SIZE] {
+    let session_id_bytes: [u8;2] = self.session_id.to_be_bytes();
+    let system_bytes: [u8;4] = self.system.to_be_bytes();
+    [
+      session_id_bytes[0],
+      session_id_bytes[1],
+      self.byte_2,
+      self.byte_3,
+      self.presentation_type,
+      self.session_type,
+      system_bytes[0],
+      system_bytes[1],
+      system_bytes[2],
+      system_bytes[3],
+    ]
+  }
+
+  /// ### DESERIALIZE MESSAGE HEADER
+  /// 
+  /// Converts raw bytes into a [Message Header], without allocating.
+  /// 
+  /// [Message Header]: MessageHeader
+  pub const fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+    Self {
+      session_id        : u16::from_be_bytes([bytes[0], bytes[1]]),
+      byte_2            : bytes[2],
+      byte_3            : bytes[3],
+      presentation_type : bytes[4],
+      session_type      : bytes[5],
+      system            : u32::from_be_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]),
+    }
+  }
+}
 impl From<MessageHeader> for [u8;10] {
   /// ### SERIALIZE MESSAGE HEADER
   /// 
@@ -601,20 +949,7 @@ impl From<MessageHeader> for [u8;10] {
   /// 
   /// [Message Header]: MessageHeader
   fn from(val: MessageHeader) -> Self {
-    let mut bytes: [u8;10] = [0;10];
-    let session_id_bytes: [u8;2] = val.session_id.to_be_bytes();
-    let system_bytes: [u8;4] = val.system.to_be_bytes();
-    bytes[0] = session_id_bytes[0];
-    bytes[1] = session_id_bytes[1];
-    bytes[2] = val.byte_2;
-    bytes[3] = val.byte_3;
-    bytes[4] = val.presentation_type;
-    bytes[5] = val.session_type;
-    bytes[6] = system_bytes[0];
-    bytes[7] = system_bytes[1];
-    bytes[8] = system_bytes[2];
-    bytes[9] = system_bytes[3];
-    bytes
+    val.to_bytes()
   }
 }
 impl From<[u8;10]> for MessageHeader {
@@ -624,13 +959,6 @@ impl From<[u8;10]> for MessageHeader {
   /// 
   /// [Message Header]: MessageHeader
   fn from(bytes: [u8;10]) -> Self {
-    Self {
-      session_id        : u16::from_be_bytes(bytes[0..2].try_into().unwrap()),
-      byte_2            : bytes[2],
-      byte_3            : bytes[3],
-      presentation_type : bytes[4],
-      session_type      : bytes[5],
-      system            : u32::from_be_bytes(bytes[6..10].try_into().unwrap()),
-    }
+    Self::from_bytes(&bytes)
   }
 }
@@ -0,0 +1,324 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # RECORD AND REPLAY
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Recorder] persists every [Primitive Message] received or transmitted
+//! by a [Client] to a compact log, and the [Replay Procedure] resends the
+//! data messages of a recorded session to a peer, with the original timing
+//! or scaled, for regression testing against captured traffic.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A log begins with the 8 bytes `HSMSLOG1`, followed by each [Record]:
+//! 
+//! - Timestamp - 8 bytes, microseconds since the UNIX epoch, big-endian.
+//! - Direction - 1 byte, 0 if received, 1 if transmitted.
+//! - Message Length - 4 bytes, big-endian, as in the HSMS protocol.
+//! - Message Header - 10 bytes.
+//! - Message Text - The remaining bytes of the message.
+//! 
+//! [Primitive Message]: primitive::Message
+//! [Client]:            Client
+//! [Recorder]:          Recorder
+//! [Record]:            Record
+//! [Replay Procedure]:  replay
+
+use std::{
+  io::{Error, ErrorKind, Read, Write},
+  sync::{Arc, mpsc::{channel, Receiver, RecvTimeoutError}},
+  thread::{self, JoinHandle},
+  time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use crate::{
+  generic::{Client, Direction, Message, MessageContents, MessageID},
+  primitive,
+};
+
+/// ## MAGIC
+/// 
+/// The bytes with which every log begins.
+pub const MAGIC: &[u8; 8] = b"HSMSLOG1";
+
+/// ## RECORD
+/// 
+/// A [Primitive Message] as observed at a particular time.
+/// 
+/// [Primitive Message]: primitive::Message
+#[derive(Clone, Debug)]
+pub struct Record {
+  /// ### TIME
+  pub time: SystemTime,
+
+  /// ### DIRECTION
+  pub direction: Direction,
+
+  /// ### MESSAGE
+  pub message: primitive::Message,
+}
+impl Record {
+  /// ### WRITE
+  /// 
+  /// Serializes the [Record] in the format of a log.
+  /// 
+  /// [Record]: Record
+  pub fn write(&self, writer: &mut impl Write) -> Result<(), Error> {
+    let micros: u64 = self.time.duration_since(UNIX_EPOCH).unwrap_or_default().as_micros() as u64;
+    let message: Vec<u8> = (&self.message).into();
+    writer.write_all(&micros.to_be_bytes())?;
+    writer.write_all(&[match self.direction {
+      Direction::Received    => 0,
+      Direction::Transmitted => 1,
+    }])?;
+    writer.write_all(&(message.len() as u32).to_be_bytes())?;
+    writer.write_all(&message)
+  }
+
+  /// ### READ
+  /// 
+  /// Deserializes a [Record] in the format of a log, resulting in [None] at
+  /// the end of the log.
+  /// 
+  /// [Record]: Record
+  pub fn read(reader: &mut impl Read) -> Result<Option<Self>, Error> {
+    let mut micros: [u8; 8] = [0; 8];
+    match reader.read_exact(&mut micros) {
+      Ok(()) => {},
+      Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+      Err(error) => return Err(error),
+    }
+    let mut direction: [u8; 1] = [0];
+    reader.read_exact(&mut direction)?;
+    let mut length: [u8; 4] = [0; 4];
+    reader.read_exact(&mut length)?;
+    let mut message: Vec<u8> = vec![0; u32::from_be_bytes(length) as usize];
+    reader.read_exact(&mut message)?;
+    Ok(Some(Record {
+      time: UNIX_EPOCH + Duration::from_micros(u64::from_be_bytes(micros)),
+      direction: match direction[0] {
+        0 => Direction::Received,
+        1 => Direction::Transmitted,
+        _ => return Err(Error::from(ErrorKind::InvalidData)),
+      },
+      message: primitive::Message::try_from(message).map_err(|_| Error::from(ErrorKind::InvalidData))?,
+    }))
+  }
+}
+
+/// ## READ LOG
+/// 
+/// Reads every [Record] of a log, checking that it begins with the [Magic].
+/// 
+/// [Record]: Record
+/// [Magic]:  MAGIC
+pub fn read_log(reader: &mut impl Read) -> Result<Vec<Record>, Error> {
+  let mut magic: [u8; 8] = [0; 8];
+  reader.read_exact(&mut magic)?;
+  if &magic != MAGIC {return Err(Error::from(ErrorKind::InvalidData))}
+  let mut records = vec![];
+  while let Some(record) = Record::read(reader)? {
+    records.push(record);
+  }
+  Ok(records)
+}
+
+/// ## RECORDER
+/// 
+/// Writes every [Primitive Message] received or transmitted by a [Client]
+/// to a log, from when it is started until it is stopped.
+/// 
+/// [Primitive Message]: primitive::Message
+/// [Client]:            Client
+pub struct Recorder {
+  client: Arc<Client>,
+  thread: JoinHandle<Result<(), Error>>,
+}
+impl Recorder {
+  /// ### START
+  /// 
+  /// Writes the [Magic] and begins recording with the [Client]'s
+  /// [Monitor Procedure], replacing any hook provided to it before.
+  /// 
+  /// [Magic]:             MAGIC
+  /// [Client]:            Client
+  /// [Monitor Procedure]: Client::set_monitor
+  pub fn start(
+    client: &Arc<Client>,
+    mut writer: impl Write + Send + 'static,
+  ) -> Result<Self, Error> {
+    writer.write_all(MAGIC)?;
+    let (sender, receiver) = channel::<(Direction, primitive::Message)>();
+    client.set_monitor(Some(sender));
+    let thread = thread::spawn(move || {
+      for (direction, message) in receiver {
+        Record {time: SystemTime::now(), direction, message}.write(&mut writer)?;
+      }
+      writer.flush()
+    });
+    Ok(Recorder {client: client.clone(), thread})
+  }
+
+  /// ### STOP
+  /// 
+  /// Removes the hook from the [Client], and waits for every [Record]
+  /// observed to be written.
+  /// 
+  /// [Client]: Client
+  /// [Record]: Record
+  pub fn stop(self) -> Result<(), Error> {
+    self.client.set_monitor(None);
+    self.thread.join().map_err(|_| Error::from(ErrorKind::Other))?
+  }
+}
+
+/// ## REPLAY SETTINGS
+/// 
+/// The configurable behavior of the [Replay Procedure].
+/// 
+/// [Replay Procedure]: replay
+#[derive(Clone, Copy, Debug)]
+pub struct ReplaySettings {
+  /// ### DIRECTION
+  /// 
+  /// The [Direction] of the data messages to be resent, such that
+  /// [Received] plays the role of the peer of the recorded [Client].
+  /// 
+  /// [Direction]: Direction
+  /// [Received]:  Direction::Received
+  /// [Client]:    Client
+  pub direction: Direction,
+
+  /// ### TIME SCALE
+  /// 
+  /// The factor by which the time between recorded messages is multiplied,
+  /// such that 1.0 is the original timing and 0.0 sends without delay.
+  pub time_scale: f64,
+
+  /// ### TIMEOUT
+  /// 
+  /// The amount of time waited for the peer to send a primary message to
+  /// which a recorded reply is to be resent.
+  pub timeout: Duration,
+}
+impl Default for ReplaySettings {
+  fn default() -> Self {
+    Self {
+      direction: Direction::Received,
+      time_scale: 1.0,
+      timeout: Duration::from_secs(45),
+    }
+  }
+}
+
+/// ## REPLAY EXCHANGE
+/// 
+/// A primary message resent by the [Replay Procedure], along with the reply
+/// which was recorded and the one which was received, which may be compared
+/// to find changes in the behavior of the peer.
+/// 
+/// [Replay Procedure]: replay
+#[derive(Clone, Debug)]
+pub struct ReplayExchange {
+  /// ### PRIMARY MESSAGE
+  pub primary: semi_e5::Message,
+
+  /// ### RECORDED REPLY
+  pub recorded: Option<semi_e5::Message>,
+
+  /// ### RECEIVED REPLY
+  pub received: Option<semi_e5::Message>,
+}
+
+/// ## REPLAY PROCEDURE
+/// 
+/// Resends the data messages of the given [Direction] from the recorded
+/// session over a connected and selected [Client], with primary messages
+/// received from its [Connect Procedure], waiting between them as recorded
+/// and scaled by the [Time Scale].
+/// 
+/// - A recorded primary message is sent with its original [Message ID],
+///   waiting for the reply if one is requested.
+/// - A recorded reply is sent once the peer sends a primary message of the
+///   matching stream and function, or fails with [TimedOut] if it does not
+///   within the [Timeout].
+/// 
+/// Primary messages from the peer which are not replied to in this way are
+/// ignored.
+/// 
+/// [Direction]:         Direction
+/// [Client]:            Client
+/// [Connect Procedure]: Client::connect
+/// [Message ID]:        MessageID
+/// [Time Scale]:        ReplaySettings::time_scale
+/// [Timeout]:           ReplaySettings::timeout
+/// [TimedOut]:          ErrorKind::TimedOut
+pub fn replay(
+  client: &Arc<Client>,
+  receiver: &Receiver<(MessageID, semi_e5::Message)>,
+  records: &[Record],
+  settings: &ReplaySettings,
+) -> Result<Vec<ReplayExchange>, Error> {
+  let data: Vec<(SystemTime, Direction, MessageID, semi_e5::Message)> = records.iter().filter_map(|record| {
+    let message = Message::try_from(record.message.clone()).ok()?;
+    match message.contents {
+      MessageContents::DataMessage(data) => Some((record.time, record.direction, message.id, data)),
+      _ => None,
+    }
+  }).collect();
+  let Some((first, ..)) = data.first() else {return Ok(vec![])};
+  let (first, start): (SystemTime, Instant) = (*first, Instant::now());
+  let mut pending: Vec<(MessageID, semi_e5::Message)> = vec![];
+  let mut exchanges: Vec<ReplayExchange> = vec![];
+  for (index, (time, direction, id, message)) in data.iter().enumerate() {
+    if *direction != settings.direction {continue}
+    // TIMING
+    let offset: Duration = time.duration_since(first).unwrap_or_default().mul_f64(settings.time_scale.max(0.0));
+    thread::sleep((start + offset).saturating_duration_since(Instant::now()));
+    // PRIMARY MESSAGE
+    if message.function % 2 == 1 {
+      let recorded = data[index..].iter()
+        .find(|(_, other, other_id, reply)| other != direction && other_id == id && reply.function % 2 == 0)
+        .map(|(.., reply)| reply.clone());
+      let received = client.data(*id, message.clone()).join().map_err(|_| Error::from(ErrorKind::Other))??;
+      exchanges.push(ReplayExchange {primary: message.clone(), recorded, received});
+    }
+    // REPLY
+    else {
+      let matches = |primary: &semi_e5::Message| {
+        primary.stream == message.stream && (primary.function == message.function - 1 || message.function == 0)
+      };
+      let deadline: Instant = Instant::now() + settings.timeout;
+      let primary_id: MessageID = loop {
+        if let Some(index) = pending.iter().position(|(_, primary)| matches(primary)) {
+          break pending.remove(index).0
+        }
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+          Ok(received) => pending.push(received),
+          Err(RecvTimeoutError::Timeout) => return Err(Error::from(ErrorKind::TimedOut)),
+          Err(RecvTimeoutError::Disconnected) => return Err(Error::from(ErrorKind::NotConnected)),
+        }
+      };
+      client.data(primary_id, message.clone()).join().map_err(|_| Error::from(ErrorKind::Other))??;
+    }
+  }
+  Ok(exchanges)
+}
@@ -0,0 +1,329 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # TRACE LOG
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Reads and writes the textual trace logs produced by many SECS drivers,
+//! in which each data message is written in SML along with the time at which
+//! it was observed and its direction, so that logs captured by other systems
+//! may be converted to [Record]s and resent by the [Replay Procedure].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each [Trace Entry] is written as follows, with the time in UTC:
+//! 
+//! ```text
+//! 2024-01-31 13:45:00.123456 SEND [0000 00000001] S1F13 W
+//! <L [2]
+//!   <A "MDLN">
+//!   <A "SOFTREV">
+//! >.
+//! ```
+//! 
+//! When read, the header of an entry is the line on which the stream and
+//! function of the message appear, and the message ends with the first line
+//! terminated by a period once all of its items are closed, or with a blank
+//! line. Within the header, words other than those below are ignored:
+//! 
+//! - Time - A date, as `YYYY-MM-DD` or `YYYY/MM/DD`, followed by a time, as
+//!   `HH:MM:SS` with an optional fraction, or combined with `T`. The date may
+//!   be omitted, in which case the time is taken to be on the UNIX epoch.
+//! - Direction - `RECV`, `RECEIVED`, `RX`, `IN`, `<-`, or `<<` for a
+//!   message [Received], and `SEND`, `SENT`, `TX`, `OUT`, `->`, or `>>` for
+//!   a message [Transmitted], in any case.
+//! - Session ID - `[SSSS SYSTEMBY]` in hexadecimal as written above, or
+//!   `DEVICE=`, `DEVICEID=`, or `SESSION=` followed by a number.
+//! - System Bytes - `[SSSS SYSTEMBY]` in hexadecimal as written above, or
+//!   `SYSTEM=`, `SYSTEMBYTES=`, or `TID=` followed by a number.
+//! 
+//! Numbers following `=` are decimal, or hexadecimal if prefixed by `0x`.
+//! 
+//! [Record]:           crate::record::Record
+//! [Replay Procedure]: crate::record::replay
+//! [Trace Entry]:      TraceEntry
+//! [Received]:         Direction::Received
+//! [Transmitted]:      Direction::Transmitted
+
+use std::{
+  fmt::Display,
+  io::{BufRead, Error, ErrorKind, Write},
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use crate::{
+  generic::{Direction, Message, MessageContents, MessageID},
+  record::Record,
+};
+
+/// ## TRACE ENTRY
+/// 
+/// A data message as written in a trace log.
+#[derive(Clone, Debug)]
+pub struct TraceEntry {
+  /// ### TIME
+  pub time: SystemTime,
+
+  /// ### DIRECTION
+  pub direction: Direction,
+
+  /// ### MESSAGE ID
+  pub id: MessageID,
+
+  /// ### MESSAGE
+  pub message: semi_e5::Message,
+}
+impl TraceEntry {
+  /// ### FROM RECORD
+  /// 
+  /// Converts a [Record] to a [Trace Entry], resulting in [None] if it is not
+  /// a valid data message.
+  /// 
+  /// [Record]:      Record
+  /// [Trace Entry]: TraceEntry
+  pub fn from_record(record: &Record) -> Option<Self> {
+    let message = Message::try_from(record.message.clone()).ok()?;
+    match message.contents {
+      MessageContents::DataMessage(data) => Some(TraceEntry {
+        time: record.time,
+        direction: record.direction,
+        id: message.id,
+        message: data,
+      }),
+      _ => None,
+    }
+  }
+}
+impl From<TraceEntry> for Record {
+  fn from(entry: TraceEntry) -> Self {
+    Record {
+      time: entry.time,
+      direction: entry.direction,
+      message: Message {
+        id: entry.id,
+        contents: MessageContents::DataMessage(entry.message),
+      }.into(),
+    }
+  }
+}
+impl Display for TraceEntry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let time: Duration = self.time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let (year, month, day) = civil_from_days((time.as_secs() / 86400) as i64);
+    let seconds: u64 = time.as_secs() % 86400;
+    write!(
+      f,
+      "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06} {} [{:04X} {:08X}] {}.",
+      year, month, day,
+      seconds / 3600, seconds / 60 % 60, seconds % 60, time.subsec_micros(),
+      match self.direction {
+        Direction::Received    => "RECV",
+        Direction::Transmitted => "SEND",
+      },
+      self.id.session, self.id.system,
+      self.message,
+    )
+  }
+}
+
+/// ## READ TRACE
+/// 
+/// Reads every [Trace Entry] of a trace log, failing with [InvalidData] if
+/// an entry has no direction or its message cannot be parsed.
+/// 
+/// [Trace Entry]: TraceEntry
+/// [InvalidData]: ErrorKind::InvalidData
+pub fn read_trace(reader: impl BufRead) -> Result<Vec<TraceEntry>, Error> {
+  let mut entries: Vec<TraceEntry> = vec![];
+  let mut lines = reader.lines().enumerate().peekable();
+  while let Some((number, line)) = lines.next() {
+    let line: String = line?;
+    let invalid = |reason: &str| Error::new(ErrorKind::InvalidData, format!("line {}: {reason}", number + 1));
+    // HEADER
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some(start) = words.iter().position(|word| is_stream_function(word)) else {continue};
+    let mut time: Option<SystemTime> = None;
+    let mut date: Option<i64> = None;
+    let mut direction: Option<Direction> = None;
+    let mut id = MessageID {session: 0, system: 0};
+    let offset: usize = words[start].as_ptr() as usize - line.as_ptr() as usize;
+    let (header, body) = line[offset..].split_at(line[offset..].find('<').unwrap_or(line.len() - offset));
+    let mut text: String = header.split_whitespace().filter(|word| !word.contains('=')).collect::<Vec<&str>>().join(" ");
+    text.push(' ');
+    text.push_str(body);
+    text.push('\n');
+    let prefix: Vec<&str> = words[..start].iter().copied().chain(header.split_whitespace().filter(|word| word.contains('='))).collect();
+    for (index, word) in prefix.iter().enumerate() {
+      let trimmed: &str = word.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')' | ',' | ';' | ':'));
+      if let Some((key, value)) = trimmed.split_once('=') {
+        let value: Option<u32> = match value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+          Some(hex) => u32::from_str_radix(hex, 16).ok(),
+          None => value.parse().ok(),
+        };
+        let value: u32 = value.ok_or_else(|| invalid("invalid number"))?;
+        match key.to_ascii_uppercase().as_str() {
+          "DEVICE" | "DEVICEID" | "SESSION" => id.session = value as u16,
+          "SYSTEM" | "SYSTEMBYTES" | "TID"  => id.system = value,
+          _ => {},
+        }
+      } else if word.starts_with('[') && word.len() == 5 && index + 1 < prefix.len() && prefix[index + 1].len() == 9 && prefix[index + 1].ends_with(']') {
+        if let (Ok(session), Ok(system)) = (u16::from_str_radix(&word[1..], 16), u32::from_str_radix(&prefix[index + 1][..8], 16)) {
+          id = MessageID {session, system};
+        }
+      } else if let Some(days) = parse_date(trimmed) {
+        date = Some(days);
+      } else if let Some((days, seconds)) = trimmed.split_once('T').and_then(|(day, second)| Some((parse_date(day)?, parse_time(second)?))) {
+        date = Some(days);
+        time = Some(UNIX_EPOCH + seconds);
+      } else if let Some(seconds) = parse_time(trimmed) {
+        time = Some(UNIX_EPOCH + seconds);
+      } else {
+        match trimmed.to_ascii_uppercase().as_str() {
+          "RECV" | "RECEIVED" | "RX" | "IN"  | "<-" | "<<" => direction = Some(Direction::Received),
+          "SEND" | "SENT"     | "TX" | "OUT" | "->" | ">>" => direction = Some(Direction::Transmitted),
+          _ => {},
+        }
+      }
+    }
+    // BODY
+    let mut depth: i64 = depth_of(&text);
+    while !(depth <= 0 && text.trim_end().ends_with('.')) {
+      if depth <= 0 {
+        match lines.peek() {
+          Some((_, Ok(line))) if line.trim().is_empty() => break,
+          Some((_, Ok(line))) if !line.trim_start().starts_with('<') && line.split_whitespace().any(is_stream_function) => break,
+          _ => {},
+        }
+      }
+      let Some((_, line)) = lines.next() else {break};
+      let line: String = line?;
+      depth += depth_of(&line);
+      text.push_str(&line);
+      text.push('\n');
+    }
+    let message: semi_e5::Message = text.parse().map_err(|_| invalid("invalid message"))?;
+    let time: SystemTime = time.unwrap_or(UNIX_EPOCH) + Duration::from_secs(date.unwrap_or(0).max(0) as u64 * 86400);
+    let direction: Direction = direction.ok_or_else(|| invalid("missing direction"))?;
+    entries.push(TraceEntry {time, direction, id, message});
+  }
+  Ok(entries)
+}
+
+/// ## WRITE TRACE
+/// 
+/// Writes each [Trace Entry] to a trace log, followed by a blank line.
+/// 
+/// [Trace Entry]: TraceEntry
+pub fn write_trace(writer: &mut impl Write, entries: &[TraceEntry]) -> Result<(), Error> {
+  for entry in entries {
+    writeln!(writer, "{entry}\n")?;
+  }
+  writer.flush()
+}
+
+/// ### IS STREAM FUNCTION
+/// 
+/// Determines whether a word of a header is of the form `SxFy`.
+fn is_stream_function(word: &str) -> bool {
+  word
+    .strip_prefix(['S', 's'])
+    .and_then(|word| word.split_once(['F', 'f']))
+    .is_some_and(|(stream, function)| {
+      !stream.is_empty() && stream.bytes().all(|b| b.is_ascii_digit())
+      && !function.is_empty() && function.trim_end_matches('.').bytes().all(|b| b.is_ascii_digit())
+    })
+}
+
+/// ### DEPTH OF
+/// 
+/// The number of items opened and not closed in a line of SML, excluding
+/// those within quoted strings.
+fn depth_of(line: &str) -> i64 {
+  let mut depth: i64 = 0;
+  let mut quoted: bool = false;
+  for c in line.chars() {
+    match c {
+      '"' => quoted = !quoted,
+      '<' if !quoted => depth += 1,
+      '>' if !quoted => depth -= 1,
+      _ => {},
+    }
+  }
+  depth
+}
+
+/// ### PARSE DATE
+/// 
+/// Parses a date as `YYYY-MM-DD` or `YYYY/MM/DD`, resulting in the number of
+/// days since the UNIX epoch.
+fn parse_date(word: &str) -> Option<i64> {
+  let mut parts = word.split(['-', '/']);
+  let year: i64 = parts.next().filter(|year| year.len() == 4)?.parse().ok()?;
+  let month: i64 = parts.next()?.parse().ok().filter(|month| (1..=12).contains(month))?;
+  let day: i64 = parts.next()?.parse().ok().filter(|day| (1..=31).contains(day))?;
+  if parts.next().is_some() {return None}
+  Some(days_from_civil(year, month, day))
+}
+
+/// ### PARSE TIME
+/// 
+/// Parses a time of day as `HH:MM:SS` with an optional fraction, separated
+/// by a period or comma, and an optional `Z`.
+fn parse_time(word: &str) -> Option<Duration> {
+  let word: &str = word.strip_suffix(['Z', 'z']).unwrap_or(word);
+  let (whole, fraction) = word.split_once(['.', ',']).unwrap_or((word, ""));
+  let mut parts = whole.split(':');
+  let hours: u64 = parts.next().filter(|hours| hours.len() == 2)?.parse().ok().filter(|hours| *hours < 24)?;
+  let minutes: u64 = parts.next().filter(|minutes| minutes.len() == 2)?.parse().ok().filter(|minutes| *minutes < 60)?;
+  let seconds: u64 = parts.next().filter(|seconds| seconds.len() == 2)?.parse().ok().filter(|seconds| *seconds < 61)?;
+  if parts.next().is_some() || !fraction.bytes().all(|b| b.is_ascii_digit()) {return None}
+  let nanos: u32 = format!("{:0<9}", &fraction[..fraction.len().min(9)]).parse().ok()?;
+  Some(Duration::new(hours * 3600 + minutes * 60 + seconds, nanos))
+}
+
+/// ### DAYS FROM CIVIL
+/// 
+/// The number of days since the UNIX epoch of a date in the proleptic
+/// Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+  let year: i64 = if month <= 2 {year - 1} else {year};
+  let era: i64 = year.div_euclid(400);
+  let year_of_era: i64 = year - era * 400;
+  let day_of_year: i64 = (153 * (month + if month > 2 {-3} else {9}) + 2) / 5 + day - 1;
+  let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146097 + day_of_era - 719468
+}
+
+/// ### CIVIL FROM DAYS
+/// 
+/// The date in the proleptic Gregorian calendar of a number of days since
+/// the UNIX epoch.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+  let days: i64 = days + 719468;
+  let era: i64 = days.div_euclid(146097);
+  let day_of_era: i64 = days - era * 146097;
+  let year_of_era: i64 = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index: i64 = (5 * day_of_year + 2) / 153;
+  let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month: i64 = if month_index < 10 {month_index + 3} else {month_index - 9};
+  let year: i64 = year_of_era + era * 400 + if month <= 2 {1} else {0};
+  (year, month, day)
+}
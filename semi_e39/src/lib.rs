@@ -0,0 +1,73 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # OBJECT SERVICES (OSS)
+//! 
+//! Copyright © 2024 Nathaniel Hardesty, Licensed under the MIT License
+//! 
+//! This software is created by a third-party and not endorsed or supported by
+//! SEMI.
+//! 
+//! The codebase will be updated to reflect more up-to-date SEMI standards
+//! if/when they can be acquired for this purpose.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! **Based on:**
+//! - **[SEMI E39]**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! OSS defines how the objects managed by equipment, such as carriers,
+//! process jobs, and substrates, are described by their attributes, and how
+//! the host may obtain and change those attributes with the Stream 14
+//! messages defined by [SECS-II] ([SEMI E5]).
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! For ease of programming and extension, the functionality of the standard
+//! has been divided into a few subsets:
+//! 
+//! - [Objects] - The types of object known to the equipment, the attributes
+//!   of each type along with whether the host may change them, and the
+//!   instances of each type.
+//! - [Object Services] - A registry of objects which answers the host's
+//!   GetAttr, SetAttr, GetType, and GetAttrName requests, so that each
+//!   subsystem only has to register its objects and keep their attributes
+//!   up to date.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TODO
+//! 
+//! - Object specifiers naming owning objects, such that objects may be
+//!   nested within other objects
+//! - Creation, deletion, attachment, and actions, [S14F9] through [S14F18]
+//! 
+//! [SEMI E5]:  https://store-us.semi.org/products/e00500-semi-e5-specification-for-semi-equipment-communications-standard-2-message-content-secs-ii
+//! [SEMI E39]: https://store-us.semi.org/products/e03900-semi-e39-specification-for-object-services-concepts-behavior-and-services
+//! 
+//! [SECS-II]:         semi_e5
+//! [S14F9]:           semi_e5::messages::s14::CreateObjectRequest
+//! [S14F18]:          semi_e5::messages::s14::SupervisedObjectActionAcknowledge
+//! [Objects]:         object
+//! [Object Services]: services
+
+pub mod object;
+pub mod services;
@@ -0,0 +1,160 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # OBJECTS
+//! **Based on SEMI E39**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Every object known to the equipment is an instance of an [Object Class],
+//! which names the type of the object and the attributes each of its
+//! instances has, along with the [Access] the host has to each attribute.
+//! 
+//! Every [Object] has the **ObjType** and **ObjID** attributes, which are
+//! read-only and provided implicitly.
+//! 
+//! [Object Class]: ObjectClass
+//! [Access]:       Access
+//! [Object]:       Object
+
+use std::collections::BTreeMap;
+use semi_e5::items::{AttributeValue, Char};
+
+/// ## OBJECT TYPE ATTRIBUTE
+/// 
+/// The name of the attribute holding the type of an object.
+pub const OBJTYPE: &str = "ObjType";
+
+/// ## OBJECT ID ATTRIBUTE
+/// 
+/// The name of the attribute holding the identifier of an object.
+pub const OBJID: &str = "ObjID";
+
+/// ## ACCESS
+/// 
+/// Whether the host may change the value of an attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Access {
+  ReadOnly,
+  ReadWrite,
+}
+
+/// ## OBJECT CLASS
+/// 
+/// A type of object, along with the attributes each of its instances has.
+#[derive(Clone, Debug)]
+pub struct ObjectClass {
+  pub(crate) name: String,
+  pub(crate) attributes: Vec<(String, Access)>,
+  pub(crate) objects: BTreeMap<String, Object>,
+}
+impl ObjectClass {
+  pub(crate) fn new(name: &str, attributes: &[(&str, Access)]) -> Self {
+    let mut list: Vec<(String, Access)> = vec![
+      (OBJTYPE.to_string(), Access::ReadOnly),
+      (OBJID.to_string(), Access::ReadOnly),
+    ];
+    for (attribute, access) in attributes {
+      if !list.iter().any(|(name, _)| name == attribute) {
+        list.push((attribute.to_string(), *access));
+      }
+    }
+    ObjectClass {
+      name: name.to_string(),
+      attributes: list,
+      objects: BTreeMap::new(),
+    }
+  }
+
+  /// ### NAME
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// ### ATTRIBUTES
+  /// 
+  /// The names of the attributes of the type, in the order they were
+  /// registered, starting with **ObjType** and **ObjID**.
+  pub fn attributes(&self) -> impl Iterator<Item = &str> {
+    self.attributes.iter().map(|(name, _)| name.as_str())
+  }
+
+  /// ### ACCESS
+  /// 
+  /// The access the host has to an attribute, if the type has it.
+  pub fn access(&self, attribute: &str) -> Option<Access> {
+    self.attributes.iter().find(|(name, _)| name == attribute).map(|(_, access)| *access)
+  }
+
+  /// ### OBJECT
+  pub fn object(&self, id: &str) -> Option<&Object> {
+    self.objects.get(id)
+  }
+
+  /// ### OBJECTS
+  pub fn objects(&self) -> impl Iterator<Item = &Object> {
+    self.objects.values()
+  }
+}
+
+/// ## OBJECT
+/// 
+/// An instance of an [Object Class], along with the values of its
+/// attributes.
+/// 
+/// [Object Class]: ObjectClass
+#[derive(Clone, Debug)]
+pub struct Object {
+  pub(crate) object_type: String,
+  pub(crate) id: String,
+  pub(crate) values: BTreeMap<String, AttributeValue>,
+}
+impl Object {
+  pub(crate) fn new(object_type: &str, id: &str) -> Self {
+    Object {
+      object_type: object_type.to_string(),
+      id: id.to_string(),
+      values: BTreeMap::new(),
+    }
+  }
+
+  /// ### OBJECT TYPE
+  pub fn object_type(&self) -> &str {
+    &self.object_type
+  }
+
+  /// ### ID
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// ### VALUE
+  /// 
+  /// The value of an attribute, including **ObjType** and **ObjID**.
+  /// 
+  /// An attribute of the type which has yet to be given a value results in
+  /// an empty list.
+  pub fn value(&self, attribute: &str) -> AttributeValue {
+    match attribute {
+      OBJTYPE => AttributeValue::Ascii(Char::safe_str_to_chars(&self.object_type)),
+      OBJID => AttributeValue::Ascii(Char::safe_str_to_chars(&self.id)),
+      _ => self.values.get(attribute).cloned().unwrap_or(AttributeValue::List(vec![])),
+    }
+  }
+}
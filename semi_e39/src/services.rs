@@ -0,0 +1,508 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # OBJECT SERVICES
+//! **Based on SEMI E39**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Object Services] keep every [Object Class] registered by the
+//! subsystems of the equipment, along with their instances, and answer the
+//! host's requests concerning them:
+//! 
+//! - [S14F1] - **GetAttr** - Obtains the values of attributes of the objects
+//!   of a type, optionally only those whose attributes satisfy qualifiers.
+//! - [S14F3] - **SetAttr** - Changes the values of attributes of the objects
+//!   of a type, where the host has [Read Write] access to them.
+//! - [S14F5] - **GetType** - Obtains the types of object known.
+//! - [S14F7] - **GetAttrName** - Obtains the attributes of types of object.
+//! 
+//! Object IDs, types, and attribute names are kept as text. Those sent by
+//! the host in a numeric format are interpreted as their decimal
+//! representation.
+//! 
+//! Only the empty object specifier, referring to the equipment itself, is
+//! currently understood.
+//! 
+//! [Object Services]: ObjectServices
+//! [Object Class]:    ObjectClass
+//! [Read Write]:      Access::ReadWrite
+//! [S14F1]:           s14::GetAttributeRequest
+//! [S14F3]:           s14::SetAttributeRequest
+//! [S14F5]:           s14::GetTypeRequest
+//! [S14F7]:           s14::GetAttributeNameRequest
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    AttributeID,
+    AttributeRelation,
+    AttributeValue,
+    Char,
+    CodedValue,
+    ErrorCode,
+    ErrorText,
+    KnownErrorCode,
+    ObjectAcknowledge,
+    ObjectID,
+    ObjectSpecifier,
+    ObjectType,
+    VecList,
+  },
+  messages::{Abort, s14},
+};
+use crate::object::{Access, Object, ObjectClass, OBJID, OBJTYPE};
+
+/// ## OBJECT ERROR
+/// 
+/// The reason an operation on an object could not be performed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectError {
+  /// ### UNKNOWN OBJECT SPECIFIER
+  UnknownSpecifier(String),
+
+  /// ### UNKNOWN TYPE
+  UnknownType(String),
+
+  /// ### DUPLICATE TYPE
+  DuplicateType(String),
+
+  /// ### UNKNOWN OBJECT
+  UnknownObject(String),
+
+  /// ### DUPLICATE OBJECT
+  DuplicateObject(String),
+
+  /// ### UNKNOWN ATTRIBUTE
+  UnknownAttribute(String),
+
+  /// ### READ ONLY
+  /// 
+  /// The host may not change the value of the attribute.
+  ReadOnly(String),
+}
+impl ObjectError {
+  /// ### ERROR CODE
+  pub fn code(&self) -> KnownErrorCode {
+    match self {
+      ObjectError::UnknownSpecifier(_) => KnownErrorCode::UnknownObjectInObjectSpecifier,
+      ObjectError::UnknownType(_)      => KnownErrorCode::UnknownObjectType,
+      ObjectError::DuplicateType(_)    => KnownErrorCode::ObjectIdentifierInUse,
+      ObjectError::UnknownObject(_)    => KnownErrorCode::UnknownObjectInstance,
+      ObjectError::DuplicateObject(_)  => KnownErrorCode::ObjectIdentifierInUse,
+      ObjectError::UnknownAttribute(_) => KnownErrorCode::UnknownAttributeName,
+      ObjectError::ReadOnly(_)         => KnownErrorCode::ReadonlyAttributeAccessDenied,
+    }
+  }
+}
+impl std::fmt::Display for ObjectError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ObjectError::UnknownSpecifier(spec) => write!(f, "unknown object specifier {spec}"),
+      ObjectError::UnknownType(name)      => write!(f, "unknown object type {name}"),
+      ObjectError::DuplicateType(name)    => write!(f, "object type {name} already exists"),
+      ObjectError::UnknownObject(id)      => write!(f, "unknown object {id}"),
+      ObjectError::DuplicateObject(id)    => write!(f, "object {id} already exists"),
+      ObjectError::UnknownAttribute(name) => write!(f, "unknown attribute {name}"),
+      ObjectError::ReadOnly(name)         => write!(f, "attribute {name} is read-only"),
+    }
+  }
+}
+impl std::error::Error for ObjectError {}
+
+/// ## OBJECT SERVICES
+/// 
+/// The types of object known to the equipment, and their instances.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectServices {
+  classes: BTreeMap<String, ObjectClass>,
+}
+impl ObjectServices {
+  /// ### NEW OBJECT SERVICES
+  /// 
+  /// Creates [Object Services] with no types of object.
+  /// 
+  /// [Object Services]: ObjectServices
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### REGISTER TYPE
+  /// 
+  /// Registers a type of object with the attributes provided, in addition
+  /// to **ObjType** and **ObjID**.
+  pub fn register_type(&mut self, name: &str, attributes: &[(&str, Access)]) -> Result<(), ObjectError> {
+    if self.classes.contains_key(name) {
+      return Err(ObjectError::DuplicateType(name.to_string()))
+    }
+    self.classes.insert(name.to_string(), ObjectClass::new(name, attributes));
+    Ok(())
+  }
+
+  /// ### CLASS
+  pub fn class(&self, object_type: &str) -> Option<&ObjectClass> {
+    self.classes.get(object_type)
+  }
+
+  /// ### CLASSES
+  pub fn classes(&self) -> impl Iterator<Item = &ObjectClass> {
+    self.classes.values()
+  }
+
+  /// ### OBJECT
+  pub fn object(&self, object_type: &str, id: &str) -> Option<&Object> {
+    self.classes.get(object_type)?.objects.get(id)
+  }
+
+  /// ### CREATE
+  /// 
+  /// Creates an instance of a type of object, whose attributes have yet to
+  /// be given values.
+  pub fn create(&mut self, object_type: &str, id: &str) -> Result<(), ObjectError> {
+    let class = self.class_mut(object_type)?;
+    if class.objects.contains_key(id) {
+      return Err(ObjectError::DuplicateObject(id.to_string()))
+    }
+    class.objects.insert(id.to_string(), Object::new(object_type, id));
+    Ok(())
+  }
+
+  /// ### DELETE
+  pub fn delete(&mut self, object_type: &str, id: &str) -> Result<Object, ObjectError> {
+    self.class_mut(object_type)?.objects.remove(id).ok_or_else(|| ObjectError::UnknownObject(id.to_string()))
+  }
+
+  /// ### VALUE
+  pub fn value(&self, object_type: &str, id: &str, attribute: &str) -> Result<AttributeValue, ObjectError> {
+    let class = self.classes.get(object_type).ok_or_else(|| ObjectError::UnknownType(object_type.to_string()))?;
+    let object = class.objects.get(id).ok_or_else(|| ObjectError::UnknownObject(id.to_string()))?;
+    class.access(attribute).ok_or_else(|| ObjectError::UnknownAttribute(attribute.to_string()))?;
+    Ok(object.value(attribute))
+  }
+
+  /// ### SET VALUE
+  /// 
+  /// Sets the value of an attribute of an object on behalf of the
+  /// equipment, regardless of the access the host has to it.
+  /// 
+  /// The **ObjType** and **ObjID** attributes may not be set.
+  pub fn set_value(&mut self, object_type: &str, id: &str, attribute: &str, value: AttributeValue) -> Result<(), ObjectError> {
+    self.set(object_type, id, attribute, value, false)
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to an [S14F1], [S14F3], [S14F5], or [S14F7], or an
+  /// Abort Transaction if it cannot be understood.
+  /// 
+  /// Results in [None] if the message is not one the object services
+  /// answer.
+  /// 
+  /// [S14F1]: s14::GetAttributeRequest
+  /// [S14F3]: s14::SetAttributeRequest
+  /// [S14F5]: s14::GetTypeRequest
+  /// [S14F7]: s14::GetAttributeNameRequest
+  pub fn service(&mut self, message: &Message) -> Option<Message> {
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (14, 1) => s14::GetAttributeRequest::try_from(message.clone()).ok().map(|request| {
+        let (spec, object_type, VecList(ids), VecList(qualifiers), VecList(attributes)) = request.0;
+        let mut errors: Vec<ObjectError> = vec![];
+        let data = self.get_attributes(&spec, &object_type, &ids, &qualifiers, attributes, &mut errors);
+        s14::GetAttributeData((VecList(data), acknowledge(errors))).into()
+      }),
+      (14, 3) => s14::SetAttributeRequest::try_from(message.clone()).ok().map(|request| {
+        let (spec, object_type, VecList(ids), VecList(attributes)) = request.0;
+        let mut errors: Vec<ObjectError> = vec![];
+        let data = self.set_attributes(&spec, &object_type, &ids, attributes, &mut errors);
+        s14::SetAttributeData((VecList(data), acknowledge(errors))).into()
+      }),
+      (14, 5) => s14::GetTypeRequest::try_from(message.clone()).ok().map(|request| {
+        let (types, errors) = match specifier(&request.0) {
+          Ok(()) => (self.classes.keys().map(|name| ObjectType::Ascii(Char::safe_str_to_chars(name))).collect(), vec![]),
+          Err(error) => (vec![], vec![error]),
+        };
+        s14::GetTypeData((VecList(types), acknowledge(errors))).into()
+      }),
+      (14, 7) => s14::GetAttributeNameRequest::try_from(message.clone()).ok().map(|request| {
+        let (spec, VecList(types)) = request.0;
+        let mut errors: Vec<ObjectError> = vec![];
+        let data = self.get_attribute_names(&spec, types, &mut errors);
+        s14::GetAttributeNameData((VecList(data), acknowledge(errors))).into()
+      }),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(14).into()))
+  }
+
+  fn class_mut(&mut self, object_type: &str) -> Result<&mut ObjectClass, ObjectError> {
+    self.classes.get_mut(object_type).ok_or_else(|| ObjectError::UnknownType(object_type.to_string()))
+  }
+
+  fn set(&mut self, object_type: &str, id: &str, attribute: &str, value: AttributeValue, host: bool) -> Result<(), ObjectError> {
+    let class = self.class_mut(object_type)?;
+    let access = class.access(attribute).ok_or_else(|| ObjectError::UnknownAttribute(attribute.to_string()))?;
+    if (host && access == Access::ReadOnly) || attribute == OBJTYPE || attribute == OBJID {
+      return Err(ObjectError::ReadOnly(attribute.to_string()))
+    }
+    let object = class.objects.get_mut(id).ok_or_else(|| ObjectError::UnknownObject(id.to_string()))?;
+    object.values.insert(attribute.to_string(), value);
+    Ok(())
+  }
+
+  /// Selects the objects of a type by ID, or all of them if none are
+  /// specified.
+  fn select(&self, class: &ObjectClass, ids: &[ObjectID], errors: &mut Vec<ObjectError>) -> Vec<String> {
+    if ids.is_empty() {
+      return class.objects.keys().cloned().collect()
+    }
+    let mut selected: Vec<String> = vec![];
+    for id in ids {
+      let id = object_id(id);
+      match class.objects.contains_key(&id) {
+        true => selected.push(id),
+        false => errors.push(ObjectError::UnknownObject(id)),
+      }
+    }
+    selected
+  }
+
+  fn get_attributes(
+    &self,
+    spec: &ObjectSpecifier,
+    object_type: &ObjectType,
+    ids: &[ObjectID],
+    qualifiers: &[(AttributeID, AttributeValue, AttributeRelation)],
+    attributes: Vec<AttributeID>,
+    errors: &mut Vec<ObjectError>,
+  ) -> Vec<(ObjectID, VecList<(AttributeID, AttributeValue)>)> {
+    if let Err(error) = specifier(spec) {
+      errors.push(error);
+      return vec![]
+    }
+    let name = type_name(object_type);
+    let Some(class) = self.classes.get(&name) else {
+      errors.push(ObjectError::UnknownType(name));
+      return vec![]
+    };
+    for (attribute, _, _) in qualifiers {
+      let attribute = attribute_name(attribute);
+      if class.access(&attribute).is_none() {
+        errors.push(ObjectError::UnknownAttribute(attribute));
+        return vec![]
+      }
+    }
+    let attributes: Vec<(AttributeID, String)> = match attributes.is_empty() {
+      true => class.attributes().map(|name| (AttributeID::Ascii(Char::safe_str_to_chars(name)), name.to_string())).collect(),
+      false => attributes.into_iter().filter_map(|id| {
+        let name = attribute_name(&id);
+        match class.access(&name) {
+          Some(_) => Some((id, name)),
+          None => {errors.push(ObjectError::UnknownAttribute(name)); None},
+        }
+      }).collect(),
+    };
+    self.select(class, ids, errors).into_iter()
+      .filter_map(|id| class.objects.get(&id))
+      .filter(|object| qualifiers.iter().all(|(attribute, value, relation)| {
+        qualifies(&value.clone().into(), *relation, &object.value(&attribute_name(attribute)).into())
+      }))
+      .map(|object| (
+        ObjectID::Ascii(Char::safe_str_to_chars(&object.id)),
+        VecList(attributes.iter().map(|(id, name)| (id.clone(), object.value(name))).collect()),
+      ))
+      .collect()
+  }
+
+  fn set_attributes(
+    &mut self,
+    spec: &ObjectSpecifier,
+    object_type: &ObjectType,
+    ids: &[ObjectID],
+    attributes: Vec<(AttributeID, AttributeValue)>,
+    errors: &mut Vec<ObjectError>,
+  ) -> Vec<(ObjectID, VecList<(AttributeID, AttributeValue)>)> {
+    if let Err(error) = specifier(spec) {
+      errors.push(error);
+      return vec![]
+    }
+    let name = type_name(object_type);
+    let Some(class) = self.classes.get(&name) else {
+      errors.push(ObjectError::UnknownType(name));
+      return vec![]
+    };
+    let selected: Vec<String> = self.select(class, ids, errors);
+    let mut data = vec![];
+    for id in selected {
+      let mut values = vec![];
+      for (attribute, value) in &attributes {
+        let attribute_name = attribute_name(attribute);
+        if let Err(error) = self.set(&name, &id, &attribute_name, value.clone(), true) {
+          if !errors.contains(&error) {
+            errors.push(error);
+          }
+          continue
+        }
+        values.push((attribute.clone(), value.clone()));
+      }
+      data.push((ObjectID::Ascii(Char::safe_str_to_chars(&id)), VecList(values)));
+    }
+    data
+  }
+
+  fn get_attribute_names(
+    &self,
+    spec: &ObjectSpecifier,
+    types: Vec<ObjectType>,
+    errors: &mut Vec<ObjectError>,
+  ) -> Vec<(ObjectType, VecList<AttributeID>)> {
+    if let Err(error) = specifier(spec) {
+      errors.push(error);
+      return vec![]
+    }
+    let types: Vec<ObjectType> = match types.is_empty() {
+      true => self.classes.keys().map(|name| ObjectType::Ascii(Char::safe_str_to_chars(name))).collect(),
+      false => types,
+    };
+    types.into_iter().filter_map(|object_type| {
+      let name = type_name(&object_type);
+      match self.classes.get(&name) {
+        Some(class) => Some((
+          object_type,
+          VecList(class.attributes().map(|name| AttributeID::Ascii(Char::safe_str_to_chars(name))).collect()),
+        )),
+        None => {errors.push(ObjectError::UnknownType(name)); None},
+      }
+    }).collect()
+  }
+}
+
+/// Only the empty object specifier, referring to the equipment itself, is
+/// understood.
+fn specifier(spec: &ObjectSpecifier) -> Result<(), ObjectError> {
+  match spec.0.is_empty() {
+    true => Ok(()),
+    false => Err(ObjectError::UnknownSpecifier(Char::chars_to_str(&spec.0))),
+  }
+}
+
+fn object_id(id: &ObjectID) -> String {
+  match id {
+    ObjectID::Ascii(chars) => Char::chars_to_str(chars),
+    ObjectID::U1(value) => value.to_string(),
+    ObjectID::U2(value) => value.to_string(),
+    ObjectID::U4(value) => value.to_string(),
+    ObjectID::U8(value) => value.to_string(),
+  }
+}
+
+fn type_name(object_type: &ObjectType) -> String {
+  match object_type {
+    ObjectType::Ascii(chars) => Char::chars_to_str(chars),
+    ObjectType::U1(value) => value.to_string(),
+    ObjectType::U2(value) => value.to_string(),
+    ObjectType::U4(value) => value.to_string(),
+    ObjectType::U8(value) => value.to_string(),
+  }
+}
+
+fn attribute_name(attribute: &AttributeID) -> String {
+  match attribute {
+    AttributeID::Ascii(chars) => Char::chars_to_str(chars),
+    AttributeID::U1(value) => value.to_string(),
+    AttributeID::U2(value) => value.to_string(),
+    AttributeID::U4(value) => value.to_string(),
+    AttributeID::U8(value) => value.to_string(),
+  }
+}
+
+/// ## QUALIFIES
+/// 
+/// Whether the qualifying value has the relation to the value of interest.
+/// 
+/// Ordering relations are only satisfied by single numbers, or by text
+/// compared lexically, while presence is tested against the elements of a
+/// list, or otherwise equality.
+fn qualifies(qualifier: &Item, relation: AttributeRelation, value: &Item) -> bool {
+  let ordering = || compare(qualifier, value);
+  match relation {
+    AttributeRelation::EqualTo => qualifier == value,
+    AttributeRelation::NotEqualTo => qualifier != value,
+    AttributeRelation::LessThan => ordering() == Some(Ordering::Less),
+    AttributeRelation::LessThanOrEqualTo => matches!(ordering(), Some(Ordering::Less | Ordering::Equal)),
+    AttributeRelation::GreaterThan => ordering() == Some(Ordering::Greater),
+    AttributeRelation::GreaterThanOrEqualTo => matches!(ordering(), Some(Ordering::Greater | Ordering::Equal)),
+    AttributeRelation::Present => present(qualifier, value),
+    AttributeRelation::Absent => !present(qualifier, value),
+  }
+}
+
+fn present(qualifier: &Item, value: &Item) -> bool {
+  match value {
+    Item::List(items) => items.contains(qualifier),
+    _ => qualifier == value,
+  }
+}
+
+fn compare(a: &Item, b: &Item) -> Option<Ordering> {
+  match (a, b) {
+    (Item::Ascii(a), Item::Ascii(b)) => Some(a[..].cmp(&b[..])),
+    _ => number(a)?.partial_cmp(&number(b)?),
+  }
+}
+
+/// ## NUMBER
+/// 
+/// The value of an [Item] containing a single number.
+fn number(item: &Item) -> Option<f64> {
+  fn single<T: Copy + Into<f64>>(values: &[T]) -> Option<f64> {
+    match values {
+      [value] => Some((*value).into()),
+      _ => None,
+    }
+  }
+  match item {
+    Item::I1(values) => single(values),
+    Item::I2(values) => single(values),
+    Item::I4(values) => single(values),
+    Item::I8(values) => match &values[..] {[value] => Some(*value as f64), _ => None},
+    Item::U1(values) => single(values),
+    Item::U2(values) => single(values),
+    Item::U4(values) => single(values),
+    Item::U8(values) => match &values[..] {[value] => Some(*value as f64), _ => None},
+    Item::F4(values) => single(values),
+    Item::F8(values) => single(values),
+    _ => None,
+  }
+}
+
+fn acknowledge(errors: Vec<ObjectError>) -> (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>) {
+  let ack = match errors.is_empty() {
+    true => ObjectAcknowledge::Success,
+    false => ObjectAcknowledge::Error,
+  };
+  let errors = errors.iter().map(|error| {
+    let mut chars: Vec<Char> = Char::safe_str_to_chars(&error.to_string());
+    chars.truncate(120);
+    (ErrorCode::Known(error.code()), ErrorText::new(chars).expect("ERRTEXT within length"))
+  }).collect();
+  (CodedValue::Known(ack), VecList(errors))
+}
@@ -0,0 +1,193 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SECS BRIDGE
+//! 
+//! Presents equipment which only supports [SECS-I] to a host as though it
+//! supported [HSMS], taking the role of the host on the SECS-I line and of
+//! the equipment on the HSMS connection, and forwarding data messages in both
+//! directions along with their replies.
+//! 
+//! ```text
+//! secs-bridge <line> <address> [--active] [--map=SESSION:DEVICE...]
+//! ```
+//! 
+//! The line is either `tcp:HOST:PORT`, for a terminal server, or the path of
+//! a serial device, whose baud rate and framing must already be configured,
+//! such as with `stty`. The HSMS connection is passive unless `--active` is
+//! given, and is accepted again whenever it is lost.
+//! 
+//! Each `--map` pairs an HSMS Session ID with a SECS-I Device ID, and any
+//! other ID is forwarded unchanged.
+//! 
+//! [SECS-I]: semi_e4
+//! [HSMS]:   semi_e37
+
+use std::{
+  env,
+  fs::OpenOptions,
+  io::{Error, Read, Write},
+  net::TcpStream,
+  process::ExitCode,
+  sync::{Arc, Mutex, atomic::{AtomicU32, Ordering::Relaxed}},
+  thread,
+  time::{Duration, Instant},
+};
+use semi_e4::message as secs_i;
+use semi_e37::generic as hsms;
+
+const USAGE: &str = "usage: secs-bridge <line> <address> [--active] [--map=SESSION:DEVICE...]";
+
+fn main() -> ExitCode {
+  // ARGUMENTS
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let mut active: bool = false;
+  let mut map: Vec<(u16, u16)> = vec![];
+  let mut positional: Vec<&str> = vec![];
+  for argument in &arguments {
+    if argument == "--active" {
+      active = true;
+    } else if let Some(pair) = argument.strip_prefix("--map=") {
+      match pair.split_once(':').and_then(|(session, device)| Some((session.parse().ok()?, device.parse().ok()?))) {
+        Some(pair) => map.push(pair),
+        None => {
+          eprintln!("{USAGE}");
+          return ExitCode::FAILURE
+        },
+      }
+    } else {
+      positional.push(argument);
+    }
+  }
+  let [line, address] = positional.as_slice() else {
+    eprintln!("{USAGE}");
+    return ExitCode::FAILURE
+  };
+  let (line, address): (String, String) = (line.to_string(), address.to_string());
+  let map: Arc<Vec<(u16, u16)>> = Arc::new(map);
+  // SECS-I
+  let (reader, writer) = match open(&line) {
+    Ok(halves) => halves,
+    Err(error) => {
+      eprintln!("secs-bridge: unable to open {line}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  let secs: Arc<secs_i::Client> = secs_i::Client::new(secs_i::ParameterSettings::default());
+  let secs_receiver = match secs.connect(reader, writer) {
+    Ok(receiver) => receiver,
+    Err(error) => {
+      eprintln!("secs-bridge: unable to open {line}: {error}");
+      return ExitCode::FAILURE
+    },
+  };
+  eprintln!("secs-bridge: opened {line}");
+  // HSMS
+  let hsms_client: Arc<Mutex<Option<Arc<hsms::Client>>>> = Default::default();
+  let hsms_clone = hsms_client.clone();
+  let secs_clone = secs.clone();
+  let map_clone = map.clone();
+  thread::spawn(move || {
+    let connect_mode = if active {hsms::ConnectionMode::Active} else {hsms::ConnectionMode::Passive};
+    let parameter_settings = hsms::ParameterSettings {connect_mode, ..Default::default()};
+    loop {
+      // CONNECT
+      let client: Arc<hsms::Client> = hsms::Client::new(parameter_settings);
+      let (socket, hsms_receiver) = match client.connect(&address) {
+        Ok(connection) => connection,
+        Err(error) => {
+          eprintln!("secs-bridge: unable to connect to {address}: {error}");
+          thread::sleep(parameter_settings.t5);
+          continue
+        },
+      };
+      eprintln!("secs-bridge: connected to {socket}");
+      // SELECT
+      if active {
+        let _ = client.select(hsms::MessageID {session: 0xFFFF, system: 0}).join();
+      }
+      let deadline: Instant = Instant::now() + parameter_settings.t7;
+      while client.selection_state() != hsms::SelectionState::Selected && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+      }
+      if client.selection_state() != hsms::SelectionState::Selected {
+        eprintln!("secs-bridge: not selected within T7");
+        let _ = client.disconnect();
+        continue
+      }
+      *hsms_clone.lock().unwrap() = Some(client.clone());
+      // HSMS -> SECS-I
+      for (id, message) in hsms_receiver {
+        let (client, secs, map) = (client.clone(), secs_clone.clone(), map_clone.clone());
+        thread::spawn(move || {
+          let device: u16 = map.iter().find(|(session, _)| *session == id.session).map_or(id.session, |(_, device)| *device);
+          match secs.data(secs_i::MessageID {device, system: id.system}, message).join() {
+            Ok(Ok(Some(reply))) => {let _ = client.data(id, reply).join();},
+            Ok(Ok(None)) => {},
+            Ok(Err(error)) => eprintln!("secs-bridge: secs-i transaction failed: {error}"),
+            Err(_) => eprintln!("secs-bridge: secs-i transaction failed"),
+          }
+        });
+      }
+      *hsms_clone.lock().unwrap() = None;
+      let _ = client.disconnect();
+      eprintln!("secs-bridge: disconnected from {socket}");
+    }
+  });
+  // SECS-I -> HSMS
+  let system: Arc<AtomicU32> = Arc::new(AtomicU32::new(1));
+  for (id, message) in secs_receiver {
+    let Some(client) = hsms_client.lock().unwrap().clone() else {
+      eprintln!("secs-bridge: not selected, discarding S{}F{}", message.stream, message.function);
+      continue
+    };
+    let (secs, map, system) = (secs.clone(), map.clone(), system.clone());
+    thread::spawn(move || {
+      let session: u16 = map.iter().find(|(_, device)| *device == id.device).map_or(id.device, |(session, _)| *session);
+      let hsms_id = hsms::MessageID {session, system: system.fetch_add(1, Relaxed)};
+      match client.data(hsms_id, message).join() {
+        Ok(Ok(Some(reply))) => {let _ = secs.data(id, reply).join();},
+        Ok(Ok(None)) => {},
+        Ok(Err(error)) => eprintln!("secs-bridge: hsms transaction failed: {error}"),
+        Err(_) => eprintln!("secs-bridge: hsms transaction failed"),
+      }
+    });
+  }
+  eprintln!("secs-bridge: {line} closed");
+  ExitCode::FAILURE
+}
+
+/// ### OPEN
+/// 
+/// Opens the SECS-I line, resulting in its reader and writer.
+#[allow(clippy::type_complexity)]
+fn open(line: &str) -> Result<(Box<dyn Read + Send>, Box<dyn Write + Send>), Error> {
+  match line.strip_prefix("tcp:") {
+    Some(address) => {
+      let stream = TcpStream::connect(address)?;
+      stream.set_nodelay(true)?;
+      Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+    },
+    None => {
+      let file = OpenOptions::new().read(true).write(true).open(line)?;
+      Ok((Box::new(file.try_clone()?), Box::new(file)))
+    },
+  }
+}
@@ -0,0 +1,208 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # BLOCKS
+//! **Based on SEMI E4**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A message is transferred as one or more [Block]s, each of which is sent
+//! as its length, followed by a 10 byte [Block Header], up to 244 bytes of
+//! data, and a 2 byte checksum.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The handshake with which each [Block] is sent uses these characters:
+//! 
+//! - [ENQ] - Sent by an entity wishing to send a [Block].
+//! - [EOT] - Sent in reply to [ENQ] by an entity ready to receive a [Block].
+//! - [ACK] - Sent once a [Block] has been received with a correct checksum.
+//! - [NAK] - Sent once a [Block] has been received incorrectly.
+//! 
+//! [Block]:        Block
+//! [Block Header]: BlockHeader
+//! [ENQ]:          ENQ
+//! [EOT]:          EOT
+//! [ACK]:          ACK
+//! [NAK]:          NAK
+
+/// ## ENQUIRE
+pub const ENQ: u8 = 0x05;
+
+/// ## END OF TRANSMISSION
+pub const EOT: u8 = 0x04;
+
+/// ## ACKNOWLEDGE
+pub const ACK: u8 = 0x06;
+
+/// ## NEGATIVE ACKNOWLEDGE
+pub const NAK: u8 = 0x15;
+
+/// ## MAXIMUM DATA LENGTH
+/// 
+/// The number of bytes of a message's text which may be sent in one [Block].
+/// 
+/// [Block]: Block
+pub const MAX_DATA: usize = 244;
+
+/// ## BLOCK
+/// 
+/// A [Block Header] and up to [244] bytes of data.
+/// 
+/// [Block Header]: BlockHeader
+/// [244]:          MAX_DATA
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+  pub header: BlockHeader,
+
+  pub data: Vec<u8>,
+}
+impl Block {
+  /// ### CHECKSUM
+  /// 
+  /// The arithmetic sum of the bytes of the header and data, truncated to
+  /// 16 bits.
+  pub fn checksum(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |sum, byte| sum.wrapping_add(*byte as u16))
+  }
+}
+impl From<&Block> for Vec<u8> {
+  /// ### BYTES FROM BLOCK
+  /// 
+  /// Serializes the [Block] as sent on the line, with its length and
+  /// checksum.
+  /// 
+  /// [Block]: Block
+  fn from(block: &Block) -> Self {
+    let header: [u8; 10] = block.header.into();
+    let mut bytes: Vec<u8> = Vec::with_capacity(block.data.len() + 13);
+    bytes.push((block.data.len() + 10) as u8);
+    bytes.extend(header);
+    bytes.extend(&block.data);
+    let checksum: u16 = Block::checksum(&bytes[1..]);
+    bytes.extend(checksum.to_be_bytes());
+    bytes
+  }
+}
+impl TryFrom<&[u8]> for Block {
+  type Error = ();
+
+  /// ### BLOCK FROM BYTES
+  /// 
+  /// Deserializes a [Block] from the bytes following its length, including
+  /// the checksum, failing if the checksum is incorrect.
+  /// 
+  /// [Block]: Block
+  fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+    if bytes.len() < 12 || bytes.len() > MAX_DATA + 12 {return Err(())}
+    let (content, checksum) = bytes.split_at(bytes.len() - 2);
+    if Block::checksum(content) != u16::from_be_bytes([checksum[0], checksum[1]]) {return Err(())}
+    Ok(Block {
+      header: BlockHeader::from(<[u8; 10]>::try_from(&content[..10]).map_err(|_| ())?),
+      data: content[10..].to_vec(),
+    })
+  }
+}
+
+/// ## BLOCK HEADER
+/// **Based on SEMI E4**
+/// 
+/// The 10 bytes at the beginning of each [Block].
+/// 
+/// [Block]: Block
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockHeader {
+  /// ### REVERSE BIT
+  /// 
+  /// Whether the message is sent from the equipment to the host.
+  pub reverse: bool,
+
+  /// ### DEVICE ID
+  /// 
+  /// Identifies the equipment to or from which the message is sent, being
+  /// 15 bits in length.
+  pub device_id: u16,
+
+  /// ### WAIT BIT
+  /// 
+  /// Whether the message is a primary message for which a reply is
+  /// requested.
+  pub w: bool,
+
+  /// ### STREAM
+  pub stream: u8,
+
+  /// ### FUNCTION
+  pub function: u8,
+
+  /// ### END BIT
+  /// 
+  /// Whether the [Block] is the last of its message.
+  /// 
+  /// [Block]: Block
+  pub end: bool,
+
+  /// ### BLOCK NUMBER
+  /// 
+  /// The position of the [Block] within its message, being 15 bits in
+  /// length and starting from 1.
+  /// 
+  /// [Block]: Block
+  pub block: u16,
+
+  /// ### SYSTEM BYTES
+  /// 
+  /// Identifies the transaction to which the message belongs, shared by a
+  /// primary message and its reply.
+  pub system: u32,
+}
+impl From<BlockHeader> for [u8; 10] {
+  fn from(header: BlockHeader) -> Self {
+    let device_id: [u8; 2] = (header.device_id & 0x7FFF).to_be_bytes();
+    let block: [u8; 2] = (header.block & 0x7FFF).to_be_bytes();
+    let system: [u8; 4] = header.system.to_be_bytes();
+    [
+      ((header.reverse as u8) << 7) | device_id[0],
+      device_id[1],
+      ((header.w as u8) << 7) | (header.stream & 0x7F),
+      header.function,
+      ((header.end as u8) << 7) | block[0],
+      block[1],
+      system[0],
+      system[1],
+      system[2],
+      system[3],
+    ]
+  }
+}
+impl From<[u8; 10]> for BlockHeader {
+  fn from(bytes: [u8; 10]) -> Self {
+    Self {
+      reverse   : bytes[0] & 0b1000_0000 != 0,
+      device_id : u16::from_be_bytes([bytes[0] & 0b0111_1111, bytes[1]]),
+      w         : bytes[2] & 0b1000_0000 != 0,
+      stream    : bytes[2] & 0b0111_1111,
+      function  : bytes[3],
+      end       : bytes[4] & 0b1000_0000 != 0,
+      block     : u16::from_be_bytes([bytes[4] & 0b0111_1111, bytes[5]]),
+      system    : u32::from_be_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]),
+    }
+  }
+}
@@ -0,0 +1,75 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SEMI EQUIPMENT COMMUNICATIONS STANDARD 1 MESSAGE TRANSFER (SECS-I)
+//! 
+//! Copyright © 2024 Nathaniel Hardesty, Licensed under the MIT License
+//! 
+//! This software is created by a third-party and not endorsed or supported by
+//! SEMI.
+//! 
+//! The codebase will be updated to reflect more up-to-date SEMI standards
+//! if/when they can be acquired for this purpose.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! **Based on:**
+//! - **[SEMI E4]**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! SECS-I is a protocol designed to facilitate the reliable transmission of
+//! messages between semiconductor equipment over a point-to-point serial
+//! line, which is still found on older equipment.
+//! 
+//! Most commonly, exchanged messages are encoded with the [SECS-II]
+//! ([SEMI E5]) protocol.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! For ease of programming and extension, the functionality of the protocol
+//! has been divided into a few subsets:
+//! 
+//! - [Blocks] - The blocks of up to 254 bytes into which messages are divided,
+//!   their headers, and their checksums.
+//! - [Message Services] - Manages the line, sending and receiving blocks with
+//!   the handshake defined by the Block Transfer Protocol, and assembles them
+//!   into messages, correlating replies with their primary messages.
+//! 
+//! The line is any pair of reader and writer, such as a serial device opened
+//! as a file once its baud rate has been configured, or a TCP/IP connection
+//! to a terminal server.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TODO
+//! 
+//! - Interleaving of blocks of multiple messages
+//! - Checking of Device IDs by the equipment, and the S9F1 message
+//! 
+//! [SEMI E4]: https://store-us.semi.org/products/e00400-semi-e4-specification-for-semi-equipment-communications-standard-1-message-transfer-secs-i
+//! [SEMI E5]: https://store-us.semi.org/products/e00500-semi-e5-specification-for-semi-equipment-communications-standard-2-message-content-secs-ii
+//! 
+//! [SECS-II]:          semi_e5
+//! [Blocks]:           block
+//! [Message Services]: message
+
+pub mod block;
+pub mod message;
@@ -0,0 +1,629 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # MESSAGE SERVICES
+//! **Based on SEMI E4**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Client] manages a line on which [Block]s are exchanged with the
+//! Remote Entity, and the messages which they make up.
+//! 
+//! This [Client] can be used to:
+//! - Manage the line with the [Connect Procedure] and
+//!   [Disconnect Procedure].
+//! - Receive primary messages with the hook provided by the
+//!   [Connect Procedure].
+//! - Send messages, and wait for their replies, with the [Data Procedure].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each [Block] is sent with the handshake of the Block Transfer Protocol:
+//! the sender sends [ENQ] and waits up to [T2] for [EOT], sends the [Block],
+//! and waits up to [T2] for [ACK], retrying up to [RTY] times. The receiver
+//! waits up to [T2] for the length of the [Block] and up to [T1] for each
+//! following byte. When both entities send [ENQ] at once, the host yields to
+//! the equipment, receiving its [Block] before retrying.
+//! 
+//! The [Block]s of a message must be received with no more than [T4]
+//! between them, and a duplicate [Block], having the same header as the one
+//! before it, is ignored.
+//! 
+//! [Block]:                crate::block::Block
+//! [ENQ]:                  crate::block::ENQ
+//! [EOT]:                  crate::block::EOT
+//! [ACK]:                  crate::block::ACK
+//! [Client]:               Client
+//! [Connect Procedure]:    Client::connect
+//! [Disconnect Procedure]: Client::disconnect
+//! [Data Procedure]:       Client::data
+//! [T1]:                   ParameterSettings::t1
+//! [T2]:                   ParameterSettings::t2
+//! [T4]:                   ParameterSettings::t4
+//! [RTY]:                  ParameterSettings::retry
+
+use std::{
+  collections::{HashMap, VecDeque},
+  io::{Error, ErrorKind, Read, Write},
+  sync::{
+    Arc,
+    mpsc::{channel, Receiver, RecvTimeoutError, Sender},
+    Mutex,
+  },
+  thread::{self, JoinHandle},
+  time::{Duration, Instant},
+};
+use oneshot::Sender as SendOnce;
+use crate::block::{Block, BlockHeader, ACK, ENQ, EOT, MAX_DATA, NAK};
+
+/// ## CLIENT
+/// 
+/// Encapsulates the functionality of the [SECS-I] protocol.
+/// 
+/// [SECS-I]: crate
+pub struct Client {
+  parameter_settings: ParameterSettings,
+  events: Mutex<Option<Sender<Event>>>,
+  outbox: Mutex<HashMap<u32, SendOnce<semi_e5::Message>>>,
+}
+
+/// ## CONNECTION PROCEDURES
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// taking and releasing control of the line.
+/// 
+/// [Client]: Client
+impl Client {
+  /// ### NEW CLIENT
+  /// 
+  /// Creates a [Client] which is not connected, ready to initiate the
+  /// [Connect Procedure].
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  pub fn new(
+    parameter_settings: ParameterSettings,
+  ) -> Arc<Self> {
+    Arc::new(Client {
+      parameter_settings,
+      events: Default::default(),
+      outbox: Default::default(),
+    })
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// 
+  /// Begins managing the line with the given reader and writer, such as the
+  /// two halves of a serial device or TCP/IP connection, resulting in the
+  /// hook to which received primary messages are sent.
+  /// 
+  /// The line is released once the reader reaches its end or fails, the
+  /// writer fails, or the [Disconnect Procedure] is used.
+  /// 
+  /// Results in an error of kind [AlreadyExists] if the [Client] is already
+  /// connected.
+  /// 
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [AlreadyExists]:        ErrorKind::AlreadyExists
+  pub fn connect(
+    self: &Arc<Self>,
+    mut reader: impl Read + Send + 'static,
+    writer: impl Write + Send + 'static,
+  ) -> Result<Receiver<(MessageID, semi_e5::Message)>, Error> {
+    let mut events_lock = self.events.lock().unwrap();
+    if events_lock.is_some() {return Err(Error::from(ErrorKind::AlreadyExists))}
+    let (event_sender, event_receiver) = channel::<Event>();
+    let (rx_sender, rx_receiver) = channel::<(MessageID, semi_e5::Message)>();
+    *events_lock = Some(event_sender.clone());
+    // Start Reader Thread
+    thread::spawn(move || {
+      let mut buffer: [u8; 256] = [0; 256];
+      loop {
+        match reader.read(&mut buffer) {
+          Ok(0) => break,
+          Ok(length) => for byte in &buffer[..length] {
+            if event_sender.send(Event::Byte(*byte)).is_err() {return}
+          },
+          Err(error) if error.kind() == ErrorKind::Interrupted => {},
+          Err(_) => break,
+        }
+      }
+      let _ = event_sender.send(Event::Closed);
+    });
+    // Start Line Thread
+    let mut line = Line {
+      client: self.clone(),
+      writer: Box::new(writer),
+      events: event_receiver,
+      queue: VecDeque::new(),
+      previous: None,
+      partial: None,
+      rx_sender,
+    };
+    thread::spawn(move || {
+      line.run();
+      *line.client.events.lock().unwrap() = None;
+      line.client.outbox.lock().unwrap().clear();
+    });
+    Ok(rx_receiver)
+  }
+
+  /// ### DISCONNECT PROCEDURE
+  /// 
+  /// Releases the line, failing any transaction which is still open.
+  /// 
+  /// Results in an error of kind [NotConnected] if the [Client] is not
+  /// connected.
+  /// 
+  /// [Client]:       Client
+  /// [NotConnected]: ErrorKind::NotConnected
+  pub fn disconnect(
+    self: &Arc<Self>,
+  ) -> Result<(), Error> {
+    match self.events.lock().unwrap().take() {
+      Some(events) => {
+        let _ = events.send(Event::Closed);
+        Ok(())
+      },
+      None => Err(Error::from(ErrorKind::NotConnected)),
+    }
+  }
+}
+
+/// ## MESSAGE EXCHANGE PROCEDURES
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// exchanging messages.
+/// 
+/// [Client]: Client
+impl Client {
+  /// ### DATA PROCEDURE
+  /// 
+  /// Sends a message in as many [Block]s as necessary, with the given
+  /// [Message ID].
+  /// 
+  /// If it is a primary message requesting a reply, the reply is then waited
+  /// for up to the time specified by [T3], resulting in an error of kind
+  /// [TimedOut] if it does not arrive. A reply is sent with the
+  /// [Message ID] of the primary message to which it replies.
+  /// 
+  /// [Block]:      Block
+  /// [Message ID]: MessageID
+  /// [T3]:         ParameterSettings::t3
+  /// [TimedOut]:   ErrorKind::TimedOut
+  pub fn data(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: semi_e5::Message,
+  ) -> JoinHandle<Result<Option<semi_e5::Message>, Error>> {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      let events: Sender<Event> = clone.events.lock().unwrap().clone().ok_or(Error::from(ErrorKind::NotConnected))?;
+      let reply_expected: bool = message.function % 2 == 1 && message.w;
+      let blocks: Vec<Block> = clone.blocks(id, message);
+      // OUTBOX: Open Transaction
+      let reply = if reply_expected {
+        let (sender, receiver) = oneshot::channel::<semi_e5::Message>();
+        clone.outbox.lock().unwrap().insert(id.system, sender);
+        Some(receiver)
+      } else {None};
+      // TX
+      let (done_sender, done_receiver) = oneshot::channel::<Result<(), Error>>();
+      let sent: Result<(), Error> = match events.send(Event::Send(blocks, done_sender)) {
+        Ok(()) => done_receiver.recv().unwrap_or(Err(Error::from(ErrorKind::ConnectionAborted))),
+        Err(_) => Err(Error::from(ErrorKind::NotConnected)),
+      };
+      if let Err(error) = sent {
+        clone.outbox.lock().unwrap().remove(&id.system);
+        return Err(error)
+      }
+      // RX
+      let Some(reply) = reply else {return Ok(None)};
+      match reply.recv_timeout(clone.parameter_settings.t3) {
+        Ok(message) => Ok(Some(message)),
+        Err(oneshot::RecvTimeoutError::Timeout) => {
+          clone.outbox.lock().unwrap().remove(&id.system);
+          Err(Error::from(ErrorKind::TimedOut))
+        },
+        Err(oneshot::RecvTimeoutError::Disconnected) => Err(Error::from(ErrorKind::ConnectionAborted)),
+      }
+    })
+  }
+
+  /// ### BLOCKS
+  /// 
+  /// Divides a message into [Block]s.
+  /// 
+  /// [Block]: Block
+  fn blocks(&self, id: MessageID, message: semi_e5::Message) -> Vec<Block> {
+    let text: Vec<u8> = match message.text {
+      Some(item) => item.into(),
+      None => vec![],
+    };
+    let chunks: Vec<&[u8]> = if text.is_empty() {vec![&[]]} else {text.chunks(MAX_DATA).collect()};
+    let count: usize = chunks.len();
+    chunks.into_iter().enumerate().map(|(index, chunk)| Block {
+      header: BlockHeader {
+        reverse: self.parameter_settings.role == Role::Equipment,
+        device_id: id.device,
+        w: message.w,
+        stream: message.stream,
+        function: message.function,
+        end: index + 1 == count,
+        block: index as u16 + 1,
+        system: id.system,
+      },
+      data: chunk.to_vec(),
+    }).collect()
+  }
+}
+
+/// ## EVENT
+/// 
+/// An input to the thread managing the line.
+enum Event {
+  Byte(u8),
+  Send(Vec<Block>, SendOnce<Result<(), Error>>),
+  Closed,
+}
+
+/// ## LINE
+/// 
+/// The state of the thread managing the line.
+#[allow(clippy::type_complexity)]
+struct Line {
+  client: Arc<Client>,
+  writer: Box<dyn Write + Send>,
+  events: Receiver<Event>,
+  queue: VecDeque<(Vec<Block>, SendOnce<Result<(), Error>>)>,
+  previous: Option<BlockHeader>,
+  partial: Option<(BlockHeader, Vec<u8>, Instant)>,
+  rx_sender: Sender<(MessageID, semi_e5::Message)>,
+}
+impl Line {
+  /// ### RUN
+  /// 
+  /// Sends queued messages and receives [Block]s until the line is
+  /// released.
+  /// 
+  /// [Block]: Block
+  fn run(&mut self) {
+    loop {
+      // TX: Queued Message
+      if let Some((blocks, done)) = self.queue.pop_front() {
+        let result: Result<(), Error> = blocks.iter().try_for_each(|block| self.send(block));
+        let closed: bool = matches!(&result, Err(error) if error.kind() == ErrorKind::NotConnected);
+        let _ = done.send(result);
+        if closed {break}
+        continue
+      }
+      // IDLE
+      match self.events.recv_timeout(Duration::from_millis(100)) {
+        Ok(Event::Byte(ENQ)) => if self.receive().is_err() {break},
+        Ok(Event::Byte(_)) => {},
+        Ok(Event::Send(blocks, done)) => self.queue.push_back((blocks, done)),
+        Ok(Event::Closed) | Err(RecvTimeoutError::Disconnected) => break,
+        Err(RecvTimeoutError::Timeout) => {},
+      }
+      // T4: Inter-Block Timeout
+      if let Some((_, _, time)) = self.partial {
+        if time.elapsed() > self.client.parameter_settings.t4 {
+          self.partial = None;
+        }
+      }
+    }
+  }
+
+  /// ### NEXT
+  /// 
+  /// Waits for the next byte until the deadline, queueing any message to be
+  /// sent in the meantime, resulting in an error of kind [NotConnected] once
+  /// the line is released.
+  /// 
+  /// [NotConnected]: ErrorKind::NotConnected
+  fn next(&mut self, deadline: Instant) -> Result<Option<u8>, Error> {
+    loop {
+      match self.events.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        Ok(Event::Byte(byte)) => return Ok(Some(byte)),
+        Ok(Event::Send(blocks, done)) => self.queue.push_back((blocks, done)),
+        Ok(Event::Closed) | Err(RecvTimeoutError::Disconnected) => return Err(Error::from(ErrorKind::NotConnected)),
+        Err(RecvTimeoutError::Timeout) => return Ok(None),
+      }
+    }
+  }
+
+  /// ### WRITE
+  /// 
+  /// Writes bytes to the line, the line being released if this fails.
+  fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+    self.writer.write_all(bytes).and_then(|_| self.writer.flush()).map_err(|_| Error::from(ErrorKind::NotConnected))
+  }
+
+  /// ### SEND
+  /// 
+  /// Sends a [Block] as the sender of the Block Transfer Protocol, resulting
+  /// in an error of kind [TimedOut] once [RTY] retries have failed.
+  /// 
+  /// [Block]:    Block
+  /// [TimedOut]: ErrorKind::TimedOut
+  /// [RTY]:      ParameterSettings::retry
+  fn send(&mut self, block: &Block) -> Result<(), Error> {
+    let settings: ParameterSettings = self.client.parameter_settings;
+    let bytes: Vec<u8> = block.into();
+    let mut retries: u8 = 0;
+    while retries <= settings.retry {
+      // LINE CONTROL
+      self.write(&[ENQ])?;
+      let deadline: Instant = Instant::now() + settings.t2;
+      let mut contention: bool = false;
+      let ready: bool = loop {
+        match self.next(deadline)? {
+          Some(EOT) => break true,
+          // CONTENTION: The host yields to the equipment.
+          Some(ENQ) if settings.role == Role::Host => {
+            self.receive()?;
+            contention = true;
+            break false
+          },
+          Some(_) => {},
+          None => break false,
+        }
+      };
+      if !ready {
+        if !contention {retries += 1}
+        continue
+      }
+      // TX: Block
+      self.write(&bytes)?;
+      match self.next(Instant::now() + settings.t2)? {
+        Some(ACK) => return Ok(()),
+        _ => retries += 1,
+      }
+    }
+    Err(Error::from(ErrorKind::TimedOut))
+  }
+
+  /// ### RECEIVE
+  /// 
+  /// Receives a [Block] as the receiver of the Block Transfer Protocol, once
+  /// [ENQ] has been received.
+  /// 
+  /// [Block]: Block
+  /// [ENQ]:   ENQ
+  fn receive(&mut self) -> Result<(), Error> {
+    let settings: ParameterSettings = self.client.parameter_settings;
+    self.write(&[EOT])?;
+    // RX: Length
+    let length: usize = match self.next(Instant::now() + settings.t2)? {
+      Some(length) if (10..=(MAX_DATA as u8 + 10)).contains(&length) => length as usize,
+      Some(_) => {
+        self.flush()?;
+        return self.write(&[NAK])
+      },
+      None => return self.write(&[NAK]),
+    };
+    // RX: Block
+    let mut bytes: Vec<u8> = Vec::with_capacity(length + 2);
+    while bytes.len() < length + 2 {
+      match self.next(Instant::now() + settings.t1)? {
+        Some(byte) => bytes.push(byte),
+        None => return self.write(&[NAK]),
+      }
+    }
+    match Block::try_from(&bytes[..]) {
+      Ok(block) => {
+        self.write(&[ACK])?;
+        self.accept(block);
+        Ok(())
+      },
+      Err(()) => {
+        self.flush()?;
+        self.write(&[NAK])
+      },
+    }
+  }
+
+  /// ### FLUSH
+  /// 
+  /// Discards bytes until none is received for [T1].
+  /// 
+  /// [T1]: ParameterSettings::t1
+  fn flush(&mut self) -> Result<(), Error> {
+    while self.next(Instant::now() + self.client.parameter_settings.t1)?.is_some() {}
+    Ok(())
+  }
+
+  /// ### ACCEPT
+  /// 
+  /// Adds a received [Block] to the message it belongs to, and once complete,
+  /// sends the message to the hook if it is a primary message, or completes
+  /// its transaction if it is a reply.
+  /// 
+  /// [Block]: Block
+  fn accept(&mut self, block: Block) {
+    let header: BlockHeader = block.header;
+    // DUPLICATE BLOCK
+    if self.previous == Some(header) {return}
+    self.previous = Some(header);
+    // ASSEMBLY
+    let data: Vec<u8> = match self.partial.take() {
+      Some((last, mut data, _))
+        if last.system == header.system
+        && last.device_id == header.device_id
+        && last.block.wrapping_add(1) == header.block
+      => {
+        data.extend(block.data);
+        data
+      },
+      _ if header.block <= 1 => block.data,
+      _ => return,
+    };
+    if !header.end {
+      self.partial = Some((header, data, Instant::now()));
+      return
+    }
+    // MESSAGE
+    let text: Option<semi_e5::Item> = match semi_e5::Item::try_from(data) {
+      Ok(item) => Some(item),
+      Err(semi_e5::Error::EmptyText) => None,
+      Err(_) => return,
+    };
+    let message = semi_e5::Message {
+      stream: header.stream,
+      function: header.function,
+      w: header.w,
+      text,
+    };
+    // RX: Primary Message
+    if message.function % 2 == 1 {
+      let _ = self.rx_sender.send((MessageID {device: header.device_id, system: header.system}, message));
+    }
+    // RX: Reply
+    // An SxF0 is also a reply, terminating the transaction.
+    else if let Some(sender) = self.client.outbox.lock().unwrap().remove(&header.system) {
+      let _ = sender.send(message);
+    }
+  }
+}
+
+/// ## ROLE
+/// 
+/// Whether the Local Entity is the host or the equipment, which determines
+/// the [Reverse Bit] of the [Block]s it sends, and which entity yields when
+/// both wish to send at once.
+/// 
+/// [Block]:       Block
+/// [Reverse Bit]: BlockHeader::reverse
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Role {
+  /// ### HOST
+  /// 
+  /// The slave, which yields to the equipment.
+  #[default]
+  Host,
+
+  /// ### EQUIPMENT
+  /// 
+  /// The master, to which the host yields.
+  Equipment,
+}
+
+/// ## PARAMETER SETTINGS
+/// **Based on SEMI E4**
+/// 
+/// The required set of parameters which the [SECS-I] protocol specifies,
+/// which may be configured.
+/// 
+/// [SECS-I]: crate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParameterSettings {
+  /// ### ROLE
+  pub role: Role,
+
+  /// ### T1: INTER-CHARACTER TIMEOUT
+  /// 
+  /// The time allowed between the bytes of a [Block].
+  /// 
+  /// [Block]: Block
+  pub t1: Duration,
+
+  /// ### T2: PROTOCOL TIMEOUT
+  /// 
+  /// The time allowed for [EOT] after sending [ENQ], for [ACK] after
+  /// sending a [Block], and for the length of a [Block] after sending [EOT].
+  /// 
+  /// [Block]: Block
+  /// [ENQ]:   ENQ
+  /// [EOT]:   EOT
+  /// [ACK]:   ACK
+  pub t2: Duration,
+
+  /// ### T3: REPLY TIMEOUT
+  /// 
+  /// The time allowed for a reply to a primary message to be received.
+  pub t3: Duration,
+
+  /// ### T4: INTER-BLOCK TIMEOUT
+  /// 
+  /// The time allowed between the [Block]s of a message.
+  /// 
+  /// [Block]: Block
+  pub t4: Duration,
+
+  /// ### RTY: RETRY LIMIT
+  /// 
+  /// The number of times sending a [Block] is retried before the message is
+  /// considered to have failed.
+  /// 
+  /// [Block]: Block
+  pub retry: u8,
+}
+impl Default for ParameterSettings {
+  /// ### DEFAULT PARAMETER SETTINGS
+  /// 
+  /// Provides [Parameter Settings] with these values:
+  /// 
+  /// - [Role] of [Host]
+  /// - [T1] of 0.5 seconds
+  /// - [T2] of 10 seconds
+  /// - [T3] of 45 seconds
+  /// - [T4] of 45 seconds
+  /// - [RTY] of 3
+  /// 
+  /// [Parameter Settings]: ParameterSettings
+  /// [Role]:               ParameterSettings::role
+  /// [Host]:               Role::Host
+  /// [T1]:                 ParameterSettings::t1
+  /// [T2]:                 ParameterSettings::t2
+  /// [T3]:                 ParameterSettings::t3
+  /// [T4]:                 ParameterSettings::t4
+  /// [RTY]:                ParameterSettings::retry
+  fn default() -> Self {
+    Self {
+      role: Role::Host,
+      t1: Duration::from_millis(500),
+      t2: Duration::from_secs(10),
+      t3: Duration::from_secs(45),
+      t4: Duration::from_secs(45),
+      retry: 3,
+    }
+  }
+}
+
+/// ## MESSAGE ID
+/// 
+/// The identity of a transaction, as carried in the header of each [Block].
+/// 
+/// [Block]: Block
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MessageID {
+  /// ### DEVICE ID
+  /// 
+  /// Identifies the equipment to or from which the message is sent, being
+  /// 15 bits in length.
+  pub device: u16,
+
+  /// ### SYSTEM BYTES
+  /// 
+  /// Identifies the transaction uniquely among the set of open
+  /// transactions.
+  pub system: u32,
+}
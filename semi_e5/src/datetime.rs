@@ -0,0 +1,182 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # DATE AND TIME CONVERSIONS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Conversions between the [TIME] item and the date and time types of
+//! commonly used crates, covering each of its [Time Format]s.
+//! 
+//! - **chrono** feature: [NaiveDateTime] and [DateTime].
+//! - **time** feature: [PrimitiveDateTime] and [OffsetDateTime].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Converting from a [TIME] into a type without a time zone accepts every
+//! [Time Format], with any time zone of the [Extended] format discarded so
+//! that the local time is kept. Converting into a type with a time zone
+//! accepts only the [Extended] format, as it is the only one to carry a
+//! time zone.
+//! 
+//! Converting into a [TIME] from a type without a time zone produces the
+//! [16-byte] format, and from a type with a time zone produces the
+//! [Extended] format. Other [Time Format]s can then be chosen with
+//! [to_format].
+//! 
+//! [TIME]:              crate::items::Time
+//! [Time Format]:       crate::items::TimeFormat
+//! [16-byte]:           crate::items::TimeFormat::Long
+//! [Extended]:          crate::items::TimeFormat::Extended
+//! [to_format]:         crate::items::Time::to_format
+//! [NaiveDateTime]:     https://docs.rs/chrono/latest/chrono/struct.NaiveDateTime.html
+//! [DateTime]:          https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+//! [PrimitiveDateTime]: https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+//! [OffsetDateTime]:    https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+  use crate::Error::{self, WrongFormat};
+  use crate::items::{Time, TimeComponents, TimeFormat};
+  use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+  fn naive(components: &TimeComponents) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(components.year, components.month, components.day)?
+      .and_hms_nano_opt(components.hour, components.minute, components.second, components.nanosecond)
+  }
+
+  fn components(datetime: &NaiveDateTime, format: TimeFormat, offset: Option<i32>) -> TimeComponents {
+    TimeComponents {
+      format,
+      year: datetime.year(),
+      month: datetime.month(),
+      day: datetime.day(),
+      hour: datetime.hour(),
+      minute: datetime.minute(),
+      second: datetime.second().min(59),
+      nanosecond: datetime.nanosecond().min(999_999_999),
+      offset,
+    }
+  }
+
+  impl TryFrom<&Time> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: &Time) -> Result<Self, Self::Error> {
+      naive(&time.components().map_err(|_| WrongFormat)?).ok_or(WrongFormat)
+    }
+  }
+
+  impl TryFrom<&Time> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(time: &Time) -> Result<Self, Self::Error> {
+      let components = time.components().map_err(|_| WrongFormat)?;
+      let offset = FixedOffset::east_opt(components.offset.ok_or(WrongFormat)?).ok_or(WrongFormat)?;
+      offset.from_local_datetime(&naive(&components).ok_or(WrongFormat)?).single().ok_or(WrongFormat)
+    }
+  }
+
+  impl TryFrom<NaiveDateTime> for Time {
+    type Error = Error;
+
+    fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+      components(&datetime, TimeFormat::Long, None).to_time().map_err(|_| WrongFormat)
+    }
+  }
+
+  impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for Time {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime<Tz>) -> Result<Self, Self::Error> {
+      let offset = datetime.fixed_offset().offset().local_minus_utc();
+      components(&datetime.naive_local(), TimeFormat::Extended, Some(offset)).to_time().map_err(|_| WrongFormat)
+    }
+  }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+  use crate::Error::{self, WrongFormat};
+  use crate::items::{Time, TimeComponents, TimeFormat};
+  use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+  fn primitive(components: &TimeComponents) -> Option<PrimitiveDateTime> {
+    let month = Month::try_from(components.month as u8).ok()?;
+    let date = Date::from_calendar_date(components.year, month, components.day as u8).ok()?;
+    let time = time::Time::from_hms_nano(
+      components.hour as u8,
+      components.minute as u8,
+      components.second as u8,
+      components.nanosecond,
+    ).ok()?;
+    Some(PrimitiveDateTime::new(date, time))
+  }
+
+  fn components(datetime: &PrimitiveDateTime, format: TimeFormat, offset: Option<i32>) -> TimeComponents {
+    TimeComponents {
+      format,
+      year: datetime.year(),
+      month: datetime.month() as u32,
+      day: datetime.day() as u32,
+      hour: datetime.hour() as u32,
+      minute: datetime.minute() as u32,
+      second: datetime.second() as u32,
+      nanosecond: datetime.nanosecond(),
+      offset,
+    }
+  }
+
+  impl TryFrom<&Time> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: &Time) -> Result<Self, Self::Error> {
+      primitive(&time.components().map_err(|_| WrongFormat)?).ok_or(WrongFormat)
+    }
+  }
+
+  impl TryFrom<&Time> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: &Time) -> Result<Self, Self::Error> {
+      let components = time.components().map_err(|_| WrongFormat)?;
+      let offset = UtcOffset::from_whole_seconds(components.offset.ok_or(WrongFormat)?).map_err(|_| WrongFormat)?;
+      Ok(primitive(&components).ok_or(WrongFormat)?.assume_offset(offset))
+    }
+  }
+
+  impl TryFrom<PrimitiveDateTime> for Time {
+    type Error = Error;
+
+    fn try_from(datetime: PrimitiveDateTime) -> Result<Self, Self::Error> {
+      components(&datetime, TimeFormat::Long, None).to_time().map_err(|_| WrongFormat)
+    }
+  }
+
+  impl TryFrom<OffsetDateTime> for Time {
+    type Error = Error;
+
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+      let offset = datetime.offset().whole_seconds();
+      let local = PrimitiveDateTime::new(datetime.date(), datetime.time());
+      components(&local, TimeFormat::Extended, Some(offset)).to_time().map_err(|_| WrongFormat)
+    }
+  }
+}
@@ -125,3 +125,15 @@ pub const U2: u8 = 0b101010_00;
 /// 
 /// - **Format Code 0o54**
 pub const U4: u8 = 0b101100_00;
+
+/// ### IS DEFINED
+/// **Based on SEMI E5§9.2.2**
+/// 
+/// Whether the upper six bits of a Format Byte are one of the Format Codes
+/// defined above.
+pub const fn is_defined(format: u8) -> bool {
+  matches!(
+    format & 0b111111_00,
+    LIST | BIN | BOOL | ASCII | JIS8 | LOCAL | I8 | I1 | I2 | I4 | F8 | F4 | U8 | U1 | U2 | U4
+  )
+}
@@ -0,0 +1,406 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # FUZZING GENERATORS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Generators for [Item]s, provided so that users are able to fuzz their own
+//! message handlers as well as the serialization and deserialization of
+//! [Item]s.
+//! 
+//! - **arbitrary** feature: implements [Arbitrary] for [Item], [Char], and
+//!   [Payload], as well as for [Adversarial Text].
+//! - **proptest** feature: provides the [item] and [adversarial_text]
+//!   strategies, and implements proptest's Arbitrary for [Item].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Generated [Item]s are well-formed, such that serializing and then
+//! deserializing them results in an equal [Item]:
+//! 
+//! - [Localized String]s are never generated, as they are not yet supported.
+//! - [JIS-8] strings are limited to printable ASCII characters.
+//! - Floating point numbers are never NaN.
+//! - [Raw] Items are only generated with format codes which are undefined.
+//! 
+//! [Adversarial Text] is generated by applying a [Mutation] to a serialized
+//! well-formed [Item], producing binary data which is likely to be invalid
+//! in some manner, such as being truncated, nested to extreme depth, or
+//! having lengths far larger than the data provided.
+//! 
+//! [Item]:             crate::Item
+//! [Char]:             crate::items::Char
+//! [Payload]:          crate::Payload
+//! [Localized String]: crate::Item::Local
+//! [JIS-8]:            crate::Item::Jis8
+//! [Raw]:              crate::Item::Raw
+//! [Adversarial Text]: AdversarialText
+//! [Mutation]:         Mutation
+//! [Arbitrary]:        https://docs.rs/arbitrary
+//! [item]:             item
+//! [adversarial_text]: adversarial_text
+
+#[cfg(feature = "arbitrary")]
+use crate::{Item, Payload};
+#[cfg(feature = "arbitrary")]
+use crate::items::Char;
+use crate::format;
+
+/// ## MAXIMUM DEPTH
+/// 
+/// The maximum depth of nested [List]s in a generated [Item].
+/// 
+/// [Item]: crate::Item
+/// [List]: crate::Item::List
+pub const MAX_DEPTH: u32 = 4;
+
+/// ## MAXIMUM LENGTH
+/// 
+/// The maximum number of elements in a generated [Item], or in each of the
+/// [List]s it contains.
+/// 
+/// [Item]: crate::Item
+/// [List]: crate::Item::List
+pub const MAX_LENGTH: usize = 16;
+
+/// ## MUTATION
+/// 
+/// A modification applied to binary data in order to produce
+/// [Adversarial Text].
+/// 
+/// [Adversarial Text]: AdversarialText
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mutation {
+  /// ### NONE
+  /// 
+  /// The binary data is left unmodified.
+  None,
+
+  /// ### TRUNCATE
+  /// 
+  /// The binary data is cut short at the provided index, modulo its length.
+  Truncate(usize),
+
+  /// ### FLIP
+  /// 
+  /// The byte at the provided index, modulo the length of the binary data, is
+  /// XORed with the provided mask.
+  Flip(usize, u8),
+
+  /// ### ZERO LENGTH BYTES
+  /// 
+  /// The number of length bytes of the outermost Item Header is set to zero,
+  /// which is not allowed.
+  ZeroLengthBytes,
+
+  /// ### UNKNOWN FORMAT
+  /// 
+  /// The format code of the outermost Item Header is replaced with an
+  /// undefined format code, chosen by the provided index, such that it is
+  /// decoded as a [Raw] Item if otherwise valid.
+  /// 
+  /// [Raw]: crate::Item::Raw
+  UnknownFormat(u8),
+
+  /// ### DEEP NESTING
+  /// 
+  /// The binary data is wrapped in the provided number of single element
+  /// [List]s.
+  /// 
+  /// [List]: crate::Item::List
+  DeepNesting(u16),
+
+  /// ### HUGE LENGTH
+  /// 
+  /// The length of the outermost Item Header is set to the maximum possible
+  /// value, which will exceed the length of the binary data provided.
+  HugeLength,
+
+  /// ### TRAILING BYTES
+  /// 
+  /// The provided bytes are appended after the end of the binary data.
+  TrailingBytes(Vec<u8>),
+}
+impl Mutation {
+  /// ### APPLY MUTATION
+  /// 
+  /// Modifies serialized binary data according to the [Mutation].
+  /// 
+  /// [Mutation]: Mutation
+  pub fn apply(&self, mut text: Vec<u8>) -> Vec<u8> {
+    match self {
+      Mutation::None => {},
+      Mutation::Truncate(index) => {
+        if !text.is_empty() {
+          text.truncate(index % text.len());
+        }
+      },
+      Mutation::Flip(index, mask) => {
+        if !text.is_empty() {
+          let len = text.len();
+          text[index % len] ^= mask;
+        }
+      },
+      Mutation::ZeroLengthBytes => {
+        if let Some(byte) = text.first_mut() {
+          *byte &= 0b111111_00;
+        }
+      },
+      Mutation::UnknownFormat(index) => {
+        if let Some(byte) = text.first_mut() {
+          *byte = unknown_format(*index) | (*byte & 0b000000_11);
+        }
+      },
+      Mutation::DeepNesting(depth) => {
+        let mut nested = Vec::with_capacity(*depth as usize * 2 + text.len());
+        for _ in 0..*depth {
+          nested.push(format::LIST | 1);
+          nested.push(1);
+        }
+        nested.append(&mut text);
+        text = nested;
+      },
+      Mutation::HugeLength => {
+        if let Some(&byte) = text.first() {
+          let length_length = (byte & 0b000000_11) as usize;
+          let header_end = (1 + length_length).min(text.len());
+          text.splice(0..header_end, [(byte & 0b111111_00) | 3, 0xFF, 0xFF, 0xFF]);
+        }
+      },
+      Mutation::TrailingBytes(bytes) => {
+        text.extend_from_slice(bytes);
+      },
+    }
+    text
+  }
+}
+
+/// ## ADVERSARIAL TEXT
+/// 
+/// Binary data produced by applying a [Mutation] to a serialized well-formed
+/// [Item], intended to exercise the error paths of deserialization.
+/// 
+/// [Item]:     crate::Item
+/// [Mutation]: Mutation
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdversarialText(pub Vec<u8>);
+
+/// ## UNKNOWN FORMAT
+/// 
+/// A format code not defined by SEMI E5, chosen by the provided index.
+fn unknown_format(index: u8) -> u8 {
+  let unknown: Vec<u8> = (0..64u8)
+    .map(|code| code << 2)
+    .filter(|code| !format::is_defined(*code))
+    .collect();
+  unknown[index as usize % unknown.len()]
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl {
+  use super::*;
+  use arbitrary::{Arbitrary, Result, Unstructured};
+
+  impl<'a> Arbitrary<'a> for Char {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+      Char::try_from(u.int_in_range(0..=0x7Fu8)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+  }
+
+  impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Payload<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+      let len = u.int_in_range(0..=MAX_LENGTH)?;
+      (0..len).map(|_| T::arbitrary(u)).collect()
+    }
+  }
+
+  impl<'a> Arbitrary<'a> for Item {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+      arbitrary_item(u, MAX_DEPTH)
+    }
+  }
+
+  impl<'a> Arbitrary<'a> for Mutation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+      Ok(match u.int_in_range(0..=7u8)? {
+        0 => Mutation::None,
+        1 => Mutation::Truncate(usize::arbitrary(u)?),
+        2 => Mutation::Flip(usize::arbitrary(u)?, u8::arbitrary(u)?),
+        3 => Mutation::ZeroLengthBytes,
+        4 => Mutation::UnknownFormat(u8::arbitrary(u)?),
+        5 => Mutation::DeepNesting(u16::arbitrary(u)?),
+        6 => Mutation::HugeLength,
+        _ => Mutation::TrailingBytes(Vec::arbitrary(u)?),
+      })
+    }
+  }
+
+  impl<'a> Arbitrary<'a> for AdversarialText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+      let item = Item::arbitrary(u)?;
+      let mutation = Mutation::arbitrary(u)?;
+      Ok(AdversarialText(mutation.apply(item.into())))
+    }
+  }
+
+  /// ### ARBITRARY ITEM
+  /// 
+  /// Generates an [Item] containing [List]s nested no deeper than the
+  /// provided depth.
+  /// 
+  /// [Item]: crate::Item
+  /// [List]: crate::Item::List
+  fn arbitrary_item(u: &mut Unstructured<'_>, depth: u32) -> Result<Item> {
+    let format = if depth == 0 {u.int_in_range(1..=15u8)?} else {u.int_in_range(0..=15u8)?};
+    Ok(match format {
+      0 => {
+        let len = u.int_in_range(0..=MAX_LENGTH)?;
+        let mut vec = Vec::with_capacity(len);
+        for _ in 0..len {
+          vec.push(arbitrary_item(u, depth - 1)?);
+        }
+        Item::List(vec)
+      },
+      1 => Item::Ascii(Payload::arbitrary(u)?),
+      2 => {
+        let len = u.int_in_range(0..=MAX_LENGTH)?;
+        let mut string = String::with_capacity(len);
+        for _ in 0..len {
+          string.push(u.int_in_range(0x20..=0x7Eu8)? as char);
+        }
+        Item::Jis8(string)
+      },
+      3 => Item::Bin(Payload::arbitrary(u)?),
+      4 => Item::Bool(Payload::arbitrary(u)?),
+      5 => Item::I1(Payload::arbitrary(u)?),
+      6 => Item::I2(Payload::arbitrary(u)?),
+      7 => Item::I4(Payload::arbitrary(u)?),
+      8 => Item::I8(Payload::arbitrary(u)?),
+      9 => Item::U1(Payload::arbitrary(u)?),
+      10 => Item::U2(Payload::arbitrary(u)?),
+      11 => Item::U4(Payload::arbitrary(u)?),
+      12 => Item::U8(Payload::arbitrary(u)?),
+      13 => {
+        let payload: Payload<f32> = Payload::arbitrary(u)?;
+        Item::F4(payload.into_iter().map(|f| if f.is_nan() {0.0} else {f}).collect())
+      },
+      14 => {
+        let payload: Payload<f64> = Payload::arbitrary(u)?;
+        Item::F8(payload.into_iter().map(|f| if f.is_nan() {0.0} else {f}).collect())
+      },
+      _ => {
+        let len = u.int_in_range(0..=MAX_LENGTH)?;
+        let mut vec = Vec::with_capacity(len);
+        for _ in 0..len {
+          vec.push(u8::arbitrary(u)?);
+        }
+        Item::Raw(unknown_format(u8::arbitrary(u)?), vec)
+      },
+    })
+  }
+}
+
+#[cfg(feature = "proptest")]
+pub use proptest_impl::{adversarial_text, item, mutation};
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+  use super::*;
+  use crate::{Item, Payload};
+  use crate::items::Char;
+  use proptest::prelude::*;
+  use proptest::collection::vec;
+
+  /// ### PAYLOAD STRATEGY
+  fn payload<T: std::fmt::Debug + Clone>(element: impl Strategy<Value = T>) -> impl Strategy<Value = Payload<T>> {
+    vec(element, 0..=MAX_LENGTH).prop_map(Payload::from)
+  }
+
+  /// ## ITEM STRATEGY
+  /// 
+  /// Generates well-formed [Item]s, with [List]s nested no deeper than
+  /// [MAX_DEPTH].
+  /// 
+  /// [Item]:      crate::Item
+  /// [List]:      crate::Item::List
+  /// [MAX_DEPTH]: super::MAX_DEPTH
+  pub fn item() -> impl Strategy<Value = Item> {
+    use proptest::num::{f32, f64};
+    let leaf = prop_oneof![
+      payload((0..=0x7Fu8).prop_map(|byte| Char::try_from(byte).unwrap())).prop_map(Item::Ascii),
+      "[ -~]{0,16}".prop_map(Item::Jis8),
+      payload(any::<u8>()).prop_map(Item::Bin),
+      payload(any::<bool>()).prop_map(Item::Bool),
+      payload(any::<i8>()).prop_map(Item::I1),
+      payload(any::<i16>()).prop_map(Item::I2),
+      payload(any::<i32>()).prop_map(Item::I4),
+      payload(any::<i64>()).prop_map(Item::I8),
+      payload(any::<u8>()).prop_map(Item::U1),
+      payload(any::<u16>()).prop_map(Item::U2),
+      payload(any::<u32>()).prop_map(Item::U4),
+      payload(any::<u64>()).prop_map(Item::U8),
+      payload(f32::POSITIVE | f32::NEGATIVE | f32::NORMAL | f32::SUBNORMAL | f32::ZERO | f32::INFINITE).prop_map(Item::F4),
+      payload(f64::POSITIVE | f64::NEGATIVE | f64::NORMAL | f64::SUBNORMAL | f64::ZERO | f64::INFINITE).prop_map(Item::F8),
+      (any::<u8>(), vec(any::<u8>(), 0..=MAX_LENGTH)).prop_map(|(index, vec)| Item::Raw(unknown_format(index), vec)),
+    ];
+    leaf.prop_recursive(MAX_DEPTH, 256, MAX_LENGTH as u32, |inner| {
+      vec(inner, 0..=MAX_LENGTH).prop_map(Item::List)
+    })
+  }
+
+  /// ## MUTATION STRATEGY
+  /// 
+  /// Generates any [Mutation].
+  /// 
+  /// [Mutation]: super::Mutation
+  pub fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+      Just(Mutation::None),
+      any::<usize>().prop_map(Mutation::Truncate),
+      (any::<usize>(), any::<u8>()).prop_map(|(index, mask)| Mutation::Flip(index, mask)),
+      Just(Mutation::ZeroLengthBytes),
+      any::<u8>().prop_map(Mutation::UnknownFormat),
+      any::<u16>().prop_map(Mutation::DeepNesting),
+      Just(Mutation::HugeLength),
+      vec(any::<u8>(), 1..=MAX_LENGTH).prop_map(Mutation::TrailingBytes),
+    ]
+  }
+
+  /// ## ADVERSARIAL TEXT STRATEGY
+  /// 
+  /// Generates binary data by applying a [Mutation] to a serialized
+  /// well-formed [Item].
+  /// 
+  /// [Item]:     crate::Item
+  /// [Mutation]: super::Mutation
+  pub fn adversarial_text() -> impl Strategy<Value = AdversarialText> {
+    (item(), mutation()).prop_map(|(item, mutation)| AdversarialText(mutation.apply(item.into())))
+  }
+
+  impl Arbitrary for Item {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Item>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+      item().boxed()
+    }
+  }
+}
@@ -51,11 +51,12 @@
 //! [Format]:          crate::format
 //! [List]:            crate::Item::List
 
-use crate::Item;
+use crate::{format, Item, Payload};
 use crate::Error::{self, *};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct Char(u8);
 
 impl std::fmt::Debug for Char {
@@ -218,6 +219,83 @@ impl<A: Into<Item>> From<VecList<A>> for Vec<Item> {
   }
 }
 
+/// ## CODED VALUE
+///
+/// Represents an enumerated code whose standard values are known, but whose
+/// remaining values are split between a reserved range and a user defined
+/// range, such that any byte received can be represented and re-encoded
+/// without loss.
+///
+/// The intent is that type E will be an enum of the standard values of a
+/// specific item, implementing [CodedEnum] by use of the [coded_value]
+/// macro.
+///
+/// [coded_value]: crate::coded_value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CodedValue<E> {
+  Known(E),
+  Reserved(u8),
+  UserDefined(u8),
+}
+impl<E: CodedEnum> CodedValue<E> {
+  /// ### KNOWN VALUE
+  ///
+  /// Returns the standard value, if the code is one.
+  pub fn known(&self) -> Option<E> {
+    match self {
+      CodedValue::Known(value) => Some(*value),
+      _ => None,
+    }
+  }
+
+  /// ### IS KNOWN
+  ///
+  /// Returns whether the code is the given standard value.
+  pub fn is(&self, value: E) -> bool
+  where E: PartialEq
+  {
+    self.known() == Some(value)
+  }
+}
+
+/// ## CODED ENUM
+///
+/// Implemented by enums of the standard values of a [Coded Value].
+///
+/// [Coded Value]: CodedValue
+pub trait CodedEnum: Copy + Into<u8> + TryFrom<u8> {
+  /// ### USER DEFINED
+  ///
+  /// The first code of the user defined range, all codes which are not
+  /// standard values and which are below this being reserved.
+  const USER_DEFINED: u8;
+}
+
+/// ## U8 -> CODED VALUE
+impl<E: CodedEnum> From<u8> for CodedValue<E> {
+  fn from(value: u8) -> Self {
+    match E::try_from(value) {
+      Ok(known) => CodedValue::Known(known),
+      Err(_) => if value < E::USER_DEFINED {
+        CodedValue::Reserved(value)
+      } else {
+        CodedValue::UserDefined(value)
+      },
+    }
+  }
+}
+
+/// ## CODED VALUE -> U8
+impl<E: CodedEnum> From<CodedValue<E>> for u8 {
+  fn from(value: CodedValue<E>) -> Self {
+    match value {
+      CodedValue::Known(known) => known.into(),
+      CodedValue::Reserved(code) => code,
+      CodedValue::UserDefined(code) => code,
+    }
+  }
+}
+
 // EMPTY LIST IS IMPLEMENTED BY THE USE OF THE UNIT TYPE ()
 
 /// ## ITEM -> EMPTY LIST
@@ -247,6 +325,39 @@ impl From<()> for Item {
 
 // HETEROGENEOUS LISTS ARE IMPLEMENTED BY USE OF TUPLE TYPES (...)
 
+/// ## ITEM -> HETEROGENEOUS LIST (1 ELEMENT)
+impl <
+  A: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A,) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 1 {
+          Ok((
+            list[0].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
+        }
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
+
+/// ## HETEROGENEOUS LIST (1 ELEMENT) -> ITEM
+impl <
+  A: Into<Item>,
+> From<(A,)> for Item {
+  fn from(value: (A,)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+    ])
+  }
+}
+
 /// ## ITEM -> HETEROGENEOUS LIST (2 ELEMENTS)
 impl <
   A: TryFrom<Item, Error = Error>,
@@ -472,1234 +583,5030 @@ impl <
   }
 }
 
-// TODO: ITEM -> HETEROGENEOUS LIST, UP TO 15 ELEMENTS
-// TODO: HETEROGENEOUS LIST -> ITEM, UP TO 15 ELEMENTS
-// NOTE: To implement Stream 1, only lengths of 2 and 3 are required.
-
-// IMPLEMENTATION MACROS
-
-/// ## DATA ITEM MACRO: SINGLE FORMAT
-/// 
-/// #### Arguments:
-/// 
-/// - **$name**: Name of struct.
-/// - **$format**: Item format.
-/// 
-/// -------------------------------------------------------------------------
-/// 
-/// #### Expansion:
-/// 
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-#[macro_export]
-macro_rules! singleformat {
-  (
-    $name:ident,
-    $format:ident
-  ) => {
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        Item::$format(vec![value.0])
-      }
-    }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
+/// ## ITEM -> HETEROGENEOUS LIST (7 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G) {
+  type Error = Error;
 
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            if vec.len() == 1 {
-              Ok(Self(vec[0]))
-            } else {
-              Err(WrongFormat)
-            }
-          },
-          _ => Err(WrongFormat),
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 7 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
         }
-      }
+      },
+      _ => Err(Error::WrongFormat),
     }
   }
 }
 
-/// ## DATA ITEM MACRO: SINGLE FORMAT, VEC
-/// 
-/// #### Arguments:
-/// - **$name**: Name of struct.
-/// - **$format**: Item format.
-/// - Optional:
-///    - **$range**: Range expression limiting vector length.
-///    - **$type**: Type contained in vector.
-/// 
-/// -------------------------------------------------------------------------
-/// 
-/// #### Expansion:
-/// 
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-/// - Optional:
-///    - new(Vec\<$type\>) -> Option\<Self\>
-///    - read(&self) -> &Vec\<$type\>
-#[macro_export]
-macro_rules! singleformat_vec {
-  // Special case for Char type (with optional range) - includes Display trait
-  (
-    $name:ident,
-    $format:ident
-    $(, $range:expr)?,
-    Char
-  ) => {
-    $(impl $name {
-      pub fn new(vec: Vec<Char>) -> Option<Self> {
-        if $range.contains(&vec.len()) {
-          Some(Self(vec))
+/// ## HETEROGENEOUS LIST (7 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+> From<(A, B, C, D, E, F, G)> for Item {
+  fn from(value: (A, B, C, D, E, F, G)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+    ])
+  }
+}
+
+/// ## ITEM -> HETEROGENEOUS LIST (8 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 8 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+          ))
         } else {
-          None
-        }
-      }
-      /// Creates a new instance from a string.
-      ///
-      /// NOTE: Invalid ASCII characters are replaced with '?'.
-      /// If the string exceeds the maximum length, it will be automatically truncated.
-      /// Returns None only if the string is shorter than the minimum length.
-      pub fn new_from_str(vec: &str) -> Option<Self> {
-        let mut vec = Char::safe_str_to_chars(vec);
-        // Truncate if the string exceeds the maximum length
-        if vec.len() > *$range.end() {
-          vec.truncate(*$range.end());
+          Err(Error::WrongFormat)
         }
-        if $range.contains(&vec.len()) {
-          Some(Self(vec))
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
+
+/// ## HETEROGENEOUS LIST (8 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+> From<(A, B, C, D, E, F, G, H)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+    ])
+  }
+}
+
+/// ## ITEM -> HETEROGENEOUS LIST (9 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 9 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+          ))
         } else {
-          None
+          Err(Error::WrongFormat)
         }
-      }
-      pub fn read(&self) -> &Vec<Char> {
-        &self.0
-      }
-    })?
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        Item::$format(value.0)
-      }
+      },
+      _ => Err(Error::WrongFormat),
     }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
+  }
+}
 
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            $(if !$range.contains(&vec.len()) {
-              return Err(WrongFormat)
-            })?
-            Ok(Self(vec))
-          },
-          _ => Err(WrongFormat),
-        }
-      }
-    }
-    impl std::fmt::Display for $name {
-      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", Char::chars_to_str(&self.0))
-      }
-    }
-  };
-  // Special case for Ascii format without range - includes Display trait
-  (
-    $name:ident,
-    Ascii
-  ) => {
-    impl $name {
-      pub fn new(vec: Vec<Char>) -> Option<Self> {
-        Some(Self(vec))
-      }
-      /// Creates a new instance from a string.
-      ///
-      /// NOTE: Invalid ASCII characters are replaced with '?'.
-      pub fn new_from_str(vec: &str) -> Option<Self> {
-        let vec = Char::safe_str_to_chars(vec);
-        Some(Self(vec))
-      }
-      pub fn read(&self) -> &Vec<Char> {
-        &self.0
-      }
-    }
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        Item::Ascii(value.0)
-      }
-    }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
+/// ## HETEROGENEOUS LIST (9 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+    ])
+  }
+}
 
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::Ascii(vec) => Ok(Self(vec)),
-          _ => Err(WrongFormat),
-        }
-      }
-    }
-    impl std::fmt::Display for $name {
-      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", Char::chars_to_str(&self.0))
-      }
-    }
-  };
-  // General case (with optional range and type)
-  (
-    $name:ident,
-    $format:ident
-    $(,$range:expr, $type:ty)?
-  ) => {
-    $(impl $name {
-      pub fn new(vec: Vec<$type>) -> Option<Self> {
-        if $range.contains(&vec.len()) {
-          Some(Self(vec))
+/// ## ITEM -> HETEROGENEOUS LIST (10 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 10 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+          ))
         } else {
-          None
+          Err(Error::WrongFormat)
         }
-      }
-      pub fn read(&self) -> &Vec<$type> {
-        &self.0
-      }
-    })?
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        Item::$format(value.0)
-      }
+      },
+      _ => Err(Error::WrongFormat),
     }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
+  }
+}
 
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            $(if !$range.contains(&vec.len()) {
-              return Err(WrongFormat)
-            })?
-            Ok(Self(vec))
-          },
-          _ => Err(WrongFormat),
-        }
-      }
-    }
+/// ## HETEROGENEOUS LIST (10 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+    ])
   }
 }
 
-/// ## DATA ITEM MACRO: SINGLE FORMAT, ENUM
-///
-/// #### Arguments
-///
-/// - **$name**: Name of enum.
-/// - **$format**: Item format.
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Expansion
-///
-/// - pub struct {$name}List(pub Vec\<$name\>) - Local wrapper type
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-/// - From\<{$name}List\> for Item
-/// - TryFrom\<Item\> for {$name}List
-/// - From\<Vec\<$name\>\> for {$name}List
-/// - From\<{$name}List\> for Vec\<$name\>
-#[macro_export]
-macro_rules! singleformat_enum {
-  (
-    $name:ident,
-    $format:ident
-  ) => {
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        Item::$format(vec![value.into()])
-      }
-    }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
+/// ## ITEM -> HETEROGENEOUS LIST (11 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+  K: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J, K) {
+  type Error = Error;
 
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            if vec.len() == 1 {
-              $name::try_from(vec[0]).map_err(|_| -> Self::Error {WrongFormat})
-            } else {
-              Err(WrongFormat)
-            }
-          },
-          _ => Err(WrongFormat),
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 11 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+            list[10].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
         }
-      }
+      },
+      _ => Err(Error::WrongFormat),
     }
+  }
+}
 
-    paste::paste! {
-      /// Local wrapper type for Vec<$name> to work around orphan rules.
-      /// Generated by the singleformat_enum! macro.
-      #[derive(Clone, Debug)]
-      pub struct [<$name List>](pub Vec<$name>);
+/// ## HETEROGENEOUS LIST (11 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+  K: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J, K)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J, K)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+      value.10.into(),
+    ])
+  }
+}
 
-      // Conversions between the List wrapper and Vec
-      impl From<Vec<$name>> for [<$name List>] {
-        fn from(vec: Vec<$name>) -> Self {
-          [<$name List>](vec)
-        }
-      }
+/// ## ITEM -> HETEROGENEOUS LIST (12 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+  K: TryFrom<Item, Error = Error>,
+  L: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J, K, L) {
+  type Error = Error;
 
-      impl From<[<$name List>]> for Vec<$name> {
-        fn from(list: [<$name List>]) -> Self {
-          list.0
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 12 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+            list[10].clone().try_into()?,
+            list[11].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
         }
-      }
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
 
-      // List wrapper conversions for Item
-      impl From<[<$name List>]> for Item {
-        fn from(list: [<$name List>]) -> Item {
-          let mut newvec = vec![];
-          for value in list.0 {
-            newvec.push(value.into());
-          }
-          Item::$format(newvec)
+/// ## HETEROGENEOUS LIST (12 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+  K: Into<Item>,
+  L: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J, K, L)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J, K, L)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+      value.10.into(),
+      value.11.into(),
+    ])
+  }
+}
+
+/// ## ITEM -> HETEROGENEOUS LIST (13 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+  K: TryFrom<Item, Error = Error>,
+  L: TryFrom<Item, Error = Error>,
+  M: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J, K, L, M) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 13 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+            list[10].clone().try_into()?,
+            list[11].clone().try_into()?,
+            list[12].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
         }
-      }
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
 
-      impl TryFrom<Item> for [<$name List>] {
-        type Error = Error;
+/// ## HETEROGENEOUS LIST (13 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+  K: Into<Item>,
+  L: Into<Item>,
+  M: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J, K, L, M)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J, K, L, M)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+      value.10.into(),
+      value.11.into(),
+      value.12.into(),
+    ])
+  }
+}
 
-        fn try_from(item: Item) -> Result<Self, Self::Error> {
-          match item {
-            Item::$format(vec) => {
-              let mut newvec: Vec<$name> = vec![];
-              for value in vec {
-                newvec.push($name::try_from(value).map_err(|_| -> Self::Error {WrongFormat})?);
-              }
-              Ok([<$name List>](newvec))
-            },
-            _ => Err(WrongFormat),
-          }
+/// ## ITEM -> HETEROGENEOUS LIST (14 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+  K: TryFrom<Item, Error = Error>,
+  L: TryFrom<Item, Error = Error>,
+  M: TryFrom<Item, Error = Error>,
+  N: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J, K, L, M, N) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 14 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+            list[10].clone().try_into()?,
+            list[11].clone().try_into()?,
+            list[12].clone().try_into()?,
+            list[13].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
+        }
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
+
+/// ## HETEROGENEOUS LIST (14 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+  K: Into<Item>,
+  L: Into<Item>,
+  M: Into<Item>,
+  N: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J, K, L, M, N)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J, K, L, M, N)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+      value.10.into(),
+      value.11.into(),
+      value.12.into(),
+      value.13.into(),
+    ])
+  }
+}
+
+/// ## ITEM -> HETEROGENEOUS LIST (15 ELEMENTS)
+impl <
+  A: TryFrom<Item, Error = Error>,
+  B: TryFrom<Item, Error = Error>,
+  C: TryFrom<Item, Error = Error>,
+  D: TryFrom<Item, Error = Error>,
+  E: TryFrom<Item, Error = Error>,
+  F: TryFrom<Item, Error = Error>,
+  G: TryFrom<Item, Error = Error>,
+  H: TryFrom<Item, Error = Error>,
+  I: TryFrom<Item, Error = Error>,
+  J: TryFrom<Item, Error = Error>,
+  K: TryFrom<Item, Error = Error>,
+  L: TryFrom<Item, Error = Error>,
+  M: TryFrom<Item, Error = Error>,
+  N: TryFrom<Item, Error = Error>,
+  O: TryFrom<Item, Error = Error>,
+> TryFrom<Item> for (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O) {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    match item {
+      Item::List(list) => {
+        if list.len() == 15 {
+          Ok((
+            list[0].clone().try_into()?,
+            list[1].clone().try_into()?,
+            list[2].clone().try_into()?,
+            list[3].clone().try_into()?,
+            list[4].clone().try_into()?,
+            list[5].clone().try_into()?,
+            list[6].clone().try_into()?,
+            list[7].clone().try_into()?,
+            list[8].clone().try_into()?,
+            list[9].clone().try_into()?,
+            list[10].clone().try_into()?,
+            list[11].clone().try_into()?,
+            list[12].clone().try_into()?,
+            list[13].clone().try_into()?,
+            list[14].clone().try_into()?,
+          ))
+        } else {
+          Err(Error::WrongFormat)
+        }
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+}
+
+/// ## HETEROGENEOUS LIST (15 ELEMENTS) -> ITEM
+impl <
+  A: Into<Item>,
+  B: Into<Item>,
+  C: Into<Item>,
+  D: Into<Item>,
+  E: Into<Item>,
+  F: Into<Item>,
+  G: Into<Item>,
+  H: Into<Item>,
+  I: Into<Item>,
+  J: Into<Item>,
+  K: Into<Item>,
+  L: Into<Item>,
+  M: Into<Item>,
+  N: Into<Item>,
+  O: Into<Item>,
+> From<(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O)> for Item {
+  fn from(value: (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O)) -> Self {
+    Item::List(vec![
+      value.0.into(),
+      value.1.into(),
+      value.2.into(),
+      value.3.into(),
+      value.4.into(),
+      value.5.into(),
+      value.6.into(),
+      value.7.into(),
+      value.8.into(),
+      value.9.into(),
+      value.10.into(),
+      value.11.into(),
+      value.12.into(),
+      value.13.into(),
+      value.14.into(),
+    ])
+  }
+}
+
+// IMPLEMENTATION MACROS
+
+/// ## DATA ITEM MACRO: FORMAT TYPE
+/// 
+/// #### Arguments:
+/// 
+/// - **$format**: Item format.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion:
+/// 
+/// - The type of a single value of $format.
+#[macro_export]
+macro_rules! format_type {
+  (Bin)   => {u8};
+  (Bool)  => {bool};
+  (Ascii) => {$crate::items::Char};
+  (I1)    => {i8};
+  (I2)    => {i16};
+  (I4)    => {i32};
+  (I8)    => {i64};
+  (U1)    => {u8};
+  (U2)    => {u16};
+  (U4)    => {u32};
+  (U8)    => {u64};
+  (F4)    => {f32};
+  (F8)    => {f64};
+}
+
+/// ## DATA ITEM MACRO: SINGLE FORMAT
+/// 
+/// #### Arguments:
+/// 
+/// - **$name**: Name of struct.
+/// - **$format**: Item format.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion:
+/// 
+/// - new(T) -> Result\<Self, Error\>
+/// - read(&self) -> &T
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+#[macro_export]
+macro_rules! singleformat {
+  (
+    $name:ident,
+    $format:ident
+  ) => {
+    impl $name {
+      pub fn new(value: $crate::format_type!($format)) -> Result<Self, Error> {
+        Ok(Self(value))
+      }
+      pub fn read(&self) -> &$crate::format_type!($format) {
+        &self.0
+      }
+    }
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::$format(Payload::Single(value.0))
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            if vec.len() == 1 {
+              Ok(Self(vec[0]))
+            } else {
+              Err(WrongFormat)
+            }
+          },
+          _ => Err(WrongFormat),
         }
       }
     }
   }
 }
 
-/// ## DATA ITEM MACRO: MULTIFORMAT
+/// ## DATA ITEM MACRO: SINGLE FORMAT, VEC
+/// 
+/// #### Arguments:
+/// - **$name**: Name of struct.
+/// - **$format**: Item format.
+/// - Optional:
+///    - **$range**: Range expression limiting vector length.
+///    - **$type**: Type contained in vector.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion:
+/// 
+/// - new(Vec\<T\>) -> Result\<Self, Error\>, checking $range if provided
+/// - read(&self) -> &Vec\<T\>
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+#[macro_export]
+macro_rules! singleformat_vec {
+  // Special case for Char type (with optional range) - includes Display trait
+  (
+    $name:ident,
+    $format:ident
+    $(, $range:expr)?,
+    Char
+  ) => {
+    $(impl $name {
+      pub fn new(vec: Vec<Char>) -> Result<Self, Error> {
+        if $range.contains(&vec.len()) {
+          Ok(Self(vec))
+        } else {
+          Err(Error::InvalidLength {
+            length: vec.len(),
+            min: *$range.start(),
+            max: *$range.end(),
+          })
+        }
+      }
+      /// Creates a new instance from a string.
+      ///
+      /// NOTE: Invalid ASCII characters are replaced with '?'.
+      /// If the string exceeds the maximum length, it will be automatically truncated.
+      /// Returns an error only if the string is shorter than the minimum length.
+      pub fn new_from_str(vec: &str) -> Result<Self, Error> {
+        let mut vec = Char::safe_str_to_chars(vec);
+        // Truncate if the string exceeds the maximum length
+        if vec.len() > *$range.end() {
+          vec.truncate(*$range.end());
+        }
+        Self::new(vec)
+      }
+      pub fn read(&self) -> &Vec<Char> {
+        &self.0
+      }
+    })?
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::$format(value.0.into())
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            $(if !$range.contains(&vec.len()) {
+              return Err(WrongFormat)
+            })?
+            Ok(Self(vec.into()))
+          },
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+    impl std::fmt::Display for $name {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Char::chars_to_str(&self.0))
+      }
+    }
+  };
+  // Special case for Ascii format without range - includes Display trait
+  (
+    $name:ident,
+    Ascii
+  ) => {
+    impl $name {
+      pub fn new(vec: Vec<Char>) -> Result<Self, Error> {
+        Ok(Self(vec))
+      }
+      /// Creates a new instance from a string.
+      ///
+      /// NOTE: Invalid ASCII characters are replaced with '?'.
+      pub fn new_from_str(vec: &str) -> Result<Self, Error> {
+        let vec = Char::safe_str_to_chars(vec);
+        Ok(Self(vec))
+      }
+      pub fn read(&self) -> &Vec<Char> {
+        &self.0
+      }
+    }
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::Ascii(value.0.into())
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::Ascii(vec) => Ok(Self(vec.into())),
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+    impl std::fmt::Display for $name {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Char::chars_to_str(&self.0))
+      }
+    }
+  };
+  // General case (with optional range and type)
+  (
+    $name:ident,
+    $format:ident
+    $(,$range:expr, $type:ty)?
+  ) => {
+    impl $name {
+      pub fn new(vec: Vec<$crate::format_type!($format)>) -> Result<Self, Error> {
+        $(if !$range.contains(&vec.len()) {
+          return Err(Error::InvalidLength {
+            length: vec.len(),
+            min: *$range.start(),
+            max: *$range.end(),
+          })
+        })?
+        Ok(Self(vec))
+      }
+      pub fn read(&self) -> &Vec<$crate::format_type!($format)> {
+        &self.0
+      }
+    }
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::$format(value.0.into())
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            $(if !$range.contains(&vec.len()) {
+              return Err(WrongFormat)
+            })?
+            Ok(Self(vec.into()))
+          },
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
+/// ## DATA ITEM MACRO: SINGLE FORMAT, ENUM
+///
+/// #### Arguments
+///
+/// - **$name**: Name of enum.
+/// - **$format**: Item format.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Expansion
+///
+/// - pub struct {$name}List(pub Vec\<$name\>) - Local wrapper type
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+/// - From\<{$name}List\> for Item
+/// - TryFrom\<Item\> for {$name}List
+/// - From\<Vec\<$name\>\> for {$name}List
+/// - From\<{$name}List\> for Vec\<$name\>
+#[macro_export]
+macro_rules! singleformat_enum {
+  (
+    $name:ident,
+    $format:ident
+  ) => {
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::$format(Payload::Single(value.into()))
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            if vec.len() == 1 {
+              $name::try_from(vec[0]).map_err(|_| -> Self::Error {WrongFormat})
+            } else {
+              Err(WrongFormat)
+            }
+          },
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+
+    paste::paste! {
+      /// Local wrapper type for Vec<$name> to work around orphan rules.
+      /// Generated by the singleformat_enum! macro.
+      #[derive(Clone, Debug)]
+      pub struct [<$name List>](pub Vec<$name>);
+
+      // Conversions between the List wrapper and Vec
+      impl From<Vec<$name>> for [<$name List>] {
+        fn from(vec: Vec<$name>) -> Self {
+          [<$name List>](vec)
+        }
+      }
+
+      impl From<[<$name List>]> for Vec<$name> {
+        fn from(list: [<$name List>]) -> Self {
+          list.0
+        }
+      }
+
+      // List wrapper conversions for Item
+      impl From<[<$name List>]> for Item {
+        fn from(list: [<$name List>]) -> Item {
+          let mut newvec = Payload::new();
+          for value in list.0 {
+            newvec.push(value.into());
+          }
+          Item::$format(newvec)
+        }
+      }
+
+      impl TryFrom<Item> for [<$name List>] {
+        type Error = Error;
+
+        fn try_from(item: Item) -> Result<Self, Self::Error> {
+          match item {
+            Item::$format(vec) => {
+              let mut newvec: Vec<$name> = vec![];
+              for value in vec {
+                newvec.push($name::try_from(value).map_err(|_| -> Self::Error {WrongFormat})?);
+              }
+              Ok([<$name List>](newvec))
+            },
+            _ => Err(WrongFormat),
+          }
+        }
+      }
+    }
+  }
+}
+
+/// ## DATA ITEM MACRO: CODED VALUE
+///
+/// #### Arguments
+///
+/// - **$name**: Name of enum of standard values.
+/// - **$format**: Item format.
+/// - **$user**: First code of the user defined range.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Expansion
+///
+/// - CodedEnum for $name
+/// - From\<CodedValue\<$name\>\> for Item
+/// - TryFrom\<Item\> for CodedValue\<$name\>
+#[macro_export]
+macro_rules! coded_value {
+  (
+    $name:ident,
+    $format:ident,
+    $user:expr
+  ) => {
+    impl CodedEnum for $name {
+      const USER_DEFINED: u8 = $user;
+    }
+    impl From<CodedValue<$name>> for Item {
+      fn from(value: CodedValue<$name>) -> Item {
+        Item::$format(Payload::Single(u8::from(value)))
+      }
+    }
+    impl TryFrom<Item> for CodedValue<$name> {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            if vec.len() == 1 {
+              Ok(CodedValue::from(vec[0]))
+            } else {
+              Err(WrongFormat)
+            }
+          },
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
+/// ## DATA ITEM MACRO: NONNEGATIVE
+/// 
+/// #### Arguments
+/// 
+/// - **$name**: Name of struct.
+/// - **$type**: Unsigned integer type contained in struct.
+/// - Optional:
+///    - **signed**: The data item is sent in a signed integer format.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion
+/// 
+/// - read(&self) -> &$type
+/// - From\<$name\> for Item, in the smallest unsigned integer format
+/// - TryFrom\<Item\> for $name, from any single integer which is neither
+///   negative nor too large for $type
+/// 
+/// Where **signed** is provided, instead:
+/// 
+/// - new($type) -> Result\<Self, Error\>, refusing values too large for a
+///   signed integer format
+/// - From\<$name\> for Item, in the smallest signed integer format, with
+///   values too large for it sent as the largest value it can hold
+#[macro_export]
+macro_rules! nonnegative {
+  (
+    $name:ident,
+    $type:ty
+  ) => {
+    impl $name {
+      pub fn read(&self) -> &$type {
+        &self.0
+      }
+    }
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::u8(value.0 as u64).into_smallest_format()
+      }
+    }
+    $crate::nonnegative!{@try_from $name, $type}
+  };
+  (
+    $name:ident,
+    $type:ty,
+    signed
+  ) => {
+    impl $name {
+      pub fn new(value: $type) -> Result<Self, Error> {
+        match i64::try_from(value) {
+          Ok(_) => Ok(Self(value)),
+          Err(_) => Err(WrongFormat),
+        }
+      }
+      pub fn read(&self) -> &$type {
+        &self.0
+      }
+    }
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        Item::i8(i64::try_from(value.0).unwrap_or(i64::MAX)).into_smallest_format()
+      }
+    }
+    $crate::nonnegative!{@try_from $name, $type}
+  };
+  (
+    @try_from $name:ident,
+    $type:ty
+  ) => {
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        let number: i128 = match value {
+          Item::I1(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::I2(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::I4(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::I8(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::U1(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::U2(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::U4(vec) if vec.len() == 1 => vec[0] as i128,
+          Item::U8(vec) if vec.len() == 1 => vec[0] as i128,
+          _ => return Err(WrongFormat),
+        };
+        <$type>::try_from(number).map(Self).map_err(|_| WrongFormat)
+      }
+    }
+  };
+}
+
+/// ## DATA ITEM MACRO: MULTIFORMAT
+/// 
+/// #### Arguments
+/// 
+/// - **$name**: Name of enum.
+/// - **$format**: Item format.
+/// - Optional:
+///    - **$formats**: Further item formats.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion
+/// 
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+#[macro_export]
+macro_rules! multiformat {
+  (
+    $name:ident
+    ,$format:ident
+    $(,$formats:ident)*
+    $(,)?
+  ) => {
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        match value {
+          $name::$format(val) => Item::$format(Payload::Single(val)),
+          $(
+            $name::$formats(val) => Item::$formats(Payload::Single(val)),
+          )*
+        }
+        
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            if vec.len() == 1 {
+              Ok(Self::$format(vec[0]))
+            } else {
+              Err(WrongFormat)
+            }
+          },
+          $(
+            Item::$formats(vec) => {
+              if vec.len() == 1 {
+                Ok(Self::$formats(vec[0]))
+              } else {
+                Err(WrongFormat)
+              }
+            },
+          )*
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
+/// ## DATA ITEM MACRO: MULTIFORMAT + ASCII
+/// 
+/// #### Arguments
+/// 
+/// - **$name**: Name of enum.
+/// - **$format**: Item format.
+/// - Optional:
+///    - **$formats**: Further item formats.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion
+/// 
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+#[macro_export]
+macro_rules! multiformat_ascii {
+  (
+    $name:ident
+    ,$format:ident
+    $(,$formats:ident)*
+    $(,)?
+  ) => {
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        match value {
+          $name::Ascii(vec) => Item::Ascii(vec.into()),
+          $name::$format(val) => Item::$format(Payload::Single(val)),
+          $($name::$formats(val) => Item::$formats(Payload::Single(val)),)*
+        }
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(item: Item) -> Result<Self, Self::Error> {
+        match item {
+          Item::Ascii(vec) => Ok($name::Ascii(vec.into())),
+          Item::$format(vec) => {
+            if vec.len() == 1 {
+              Ok(Self::$format(vec[0]))
+            } else {
+              Err(WrongFormat)
+            }
+          },
+          $(Item::$formats(vec) => {
+            if vec.len() == 1 {
+              Ok(Self::$formats(vec[0]))
+            } else {
+              Err(WrongFormat)
+            }
+          },)*
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
+/// ## DATA ITEM MACRO: MULTIFORMAT, VEC
+/// 
+/// #### Arguments
+/// 
+/// - **$name**: Name of enum.
+/// - **$format**: Item format.
+/// - Optional:
+///    - **$formats**: Further item formats.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion
+/// 
+/// - From\<$name\> for Item
+/// - TryFrom\<Item\> for $name
+#[macro_export]
+macro_rules! multiformat_vec {
+  (
+    $name:ident
+    ,$format:ident
+    $(,$formats:ident)*
+    $(,)?
+  ) => {
+    impl From<$name> for Item {
+      fn from(value: $name) -> Item {
+        match value {
+          $name::$format(vec) => Item::$format(vec.into()),
+          $(
+            $name::$formats(vec) => Item::$formats(vec.into()),
+          )*
+        }
+        
+      }
+    }
+    impl TryFrom<Item> for $name {
+      type Error = Error;
+
+      fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+          Item::$format(vec) => {
+            Ok(Self::$format(vec.into()))
+          },
+          $(
+            Item::$formats(vec) => {
+              Ok(Self::$formats(vec.into()))
+            },
+          )*
+          _ => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
+// ITEMS
+
+/// ## ABS
+/// 
+/// Any binary string.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F25], [S2F26]
+/// 
+/// [S2F25]: crate::messages::s2::LoopbackDiagnosticRequest
+/// [S2F26]: crate::messages::s2::LoopbackDiagnosticData
+#[derive(Clone, Debug)]
+pub struct AnyBinaryString(pub Vec<u8>);
+singleformat_vec!{AnyBinaryString, Bin}
+
+/// ## ACCESSMODE
+/// 
+/// Load Port Access Mode
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S3F21, [S3F27]
+/// 
+/// [S3F27]: crate::messages::s3::ChangeAccess
+#[derive(Clone, Copy, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AccessMode {
+  Manual = 0,
+  Auto = 1,
+}
+singleformat_enum!{AccessMode, U1}
+
+/// ## ACDS
+/// 
+/// After Command Codes
+/// 
+/// Vector of all command codes which the defined command must succeed
+/// within the same block.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum AfterCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
+}
+multiformat_vec!{AfterCommandCodes, I2, U2}
+
+/// ## ACKA
+/// 
+/// Request success, true is successful, false is unsuccessful.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S5F14], [S5F15], [S5F18]
+/// - S16F4, [S16F6], [S16F7], [S16F12], [S16F16], [S16F18], S16F24, [S16F26],
+///   [S16F28], S16F30
+/// - [S17F4], [S17F8], [S17F14]
+/// 
+/// [S5F14]:  crate::messages::s5::ExceptionRecoverAcknowledge
+/// [S5F15]:  crate::messages::s5::ExceptionRecoveryCompleteNotify
+/// [S5F18]:  crate::messages::s5::ExceptionRecoveryAbortAcknowledge
+/// [S16F6]:  crate::messages::s16::ProcessJobCommandAcknowledge
+/// [S16F7]:  crate::messages::s16::ProcessJobAlertNotify
+/// [S16F12]: crate::messages::s16::ProcessJobCreateEnhancedAcknowledge
+/// [S16F16]: crate::messages::s16::ProcessJobMultiCreateAcknowledge
+/// [S16F18]: crate::messages::s16::ProcessJobDequeueAcknowledge
+/// [S16F26]: crate::messages::s16::ProcessJobSetStartMethodAcknowledge
+/// [S16F28]: crate::messages::s16::ControlJobCommandAcknowledge
+/// [S17F4]:  crate::messages::s17::DataReportDeleteAcknowledge
+/// [S17F8]:  crate::messages::s17::TraceDeleteAcknowledge
+/// [S17F14]: crate::messages::s17::TraceResetAcknowledge
+#[derive(Clone, Copy, Debug)]
+pub struct AcknowledgeAny(pub bool);
+singleformat!{AcknowledgeAny, Bool}
+
+// TODO: ACKC3
+// Use CodedValue, with 1-63 being reserved and the rest open for user values.
+
+/// ## ACKC5
+///
+/// Acknowledge code for Stream 5, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = Accepted
+/// - 1 = Error, Not Accepted
+/// - 2-63 = Reserved, Error
+/// - 64-255 = User Defined, Error
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S5F2], [S5F4]
+///
+/// [S5F2]: crate::messages::s5::AlarmReportAcknowledge
+/// [S5F4]: crate::messages::s5::EnableDisableAlarmAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode5 {
+  Accepted    = 0,
+  NotAccepted = 1,
+}
+coded_value!{AcknowledgeCode5, Bin, 64}
+impl CodedValue<AcknowledgeCode5> {
+  /// ### IS ACCEPTED
+  pub fn is_accepted(&self) -> bool {
+    self.is(AcknowledgeCode5::Accepted)
+  }
+}
+
+/// ## ACKC6
+///
+/// Acknowledge code for Stream 6, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = Accepted
+/// - 1 = Error, Not Accepted
+/// - 2-63 = Reserved, Error
+/// - 64-255 = User Defined, Error
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S6F2], [S6F4], [S6F10], [S6F12], [S6F14]
+///
+/// [S6F2]:  crate::messages::s6::TraceDataAcknowledge
+/// [S6F4]:  crate::messages::s6::DiscreteVariableDataAcknowledge
+/// [S6F10]: crate::messages::s6::FormattedVariableAcknowledge
+/// [S6F12]: crate::messages::s6::EventReportAcknowledge
+/// [S6F14]: crate::messages::s6::AnnotatedEventReportAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode6 {
+  Accepted    = 0,
+  NotAccepted = 1,
+}
+coded_value!{AcknowledgeCode6, Bin, 64}
+impl CodedValue<AcknowledgeCode6> {
+  /// ### IS ACCEPTED
+  pub fn is_accepted(&self) -> bool {
+    self.is(AcknowledgeCode6::Accepted)
+  }
+}
+
+/// ## ACKC7
+///
+/// Acknowledge code for Stream 7, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = Accepted
+/// - 1 = Permission not granted
+/// - 2 = Length error
+/// - 3 = Matrix overflow
+/// - 4 = PPID not found
+/// - 5 = Mode unsupported
+/// - 6 = Command will be performed with completion signaled later
+/// - 7-63 = Reserved
+/// - 64-255 = User Defined
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S7F4], S7F12, S7F14, S7F16, [S7F18], [S7F24], [S7F32], [S7F38], [S7F40],
+///   [S7F42], [S7F44]
+/// 
+/// [S7F4]:  crate::messages::s7::ProcessProgramAcknowledge
+/// [S7F18]: crate::messages::s7::DeleteProcessProgramAcknowledge
+/// [S7F24]: crate::messages::s7::FormattedProcessProgramAcknowledge
+/// [S7F32]: crate::messages::s7::VerificationRequestAcknowledge
+/// [S7F38]: crate::messages::s7::LargeProcessProgramAcknowledge
+/// [S7F40]: crate::messages::s7::LargeFormattedProcessProgramAcknowledge
+/// [S7F42]: crate::messages::s7::LargeProcessProgramRequestAcknowledge
+/// [S7F44]: crate::messages::s7::LargeFormattedProcessProgramRequestAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode7 {
+  Accepted             = 0,
+  PermissionNotGranted = 1,
+  LengthError          = 2,
+  MatrixOverflow       = 3,
+  PPIDNotFound         = 4,
+  ModeUnsupported      = 5,
+  PerformedLater       = 6,
+}
+coded_value!{AcknowledgeCode7, Bin, 64}
+impl CodedValue<AcknowledgeCode7> {
+  /// ### IS ACCEPTED
+  pub fn is_accepted(&self) -> bool {
+    self.is(AcknowledgeCode7::Accepted)
+  }
+}
+
+/// ## ACKC7A
+///
+/// Acknowledge code for Stream 7, used to indicate errors in the
+/// enhanced process program format, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = Accepted
+/// - 1 = MDLN is inconsistent
+/// - 2 = SOFTREV is inconsistent
+/// - 3 = Invalid CCODE
+/// - 4 = Invalid PPARM value
+/// - 5 = Other error, described by ERRW7
+/// - 6-63 = Reserved
+/// - 64-255 = User Defined
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S7F27]
+/// 
+/// [S7F27]: crate::messages::s7::ProcessProgramVerificationSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode7A {
+  Accepted                     = 0,
+  ModelNameInconsistent        = 1,
+  SoftwareRevisionInconsistent = 2,
+  InvalidCommandCode           = 3,
+  InvalidParameterValue        = 4,
+  OtherError                   = 5,
+}
+coded_value!{AcknowledgeCode7A, Bin, 64}
+impl CodedValue<AcknowledgeCode7A> {
+  /// ### IS ACCEPTED
+  pub fn is_accepted(&self) -> bool {
+    self.is(AcknowledgeCode7A::Accepted)
+  }
+}
+
+/// ## ACKC10
+///
+/// **Acknowledge Code for Stream 10**
+///
+/// Terminal display acknowledge code, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = Accepted
+/// - 1 = Will not be displayed
+/// - 2 = Terminal not available
+/// - 3-63 = Reserved
+/// - 64-255 = User Defined
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S10F2], [S10F4], [S10F6], [S10F10]
+///
+/// [S10F2]:  crate::messages::s10::TerminalAcknowledge
+/// [S10F4]:  crate::messages::s10::TerminalDisplaySingleAcknowledge
+/// [S10F6]:  crate::messages::s10::TerminalDisplayMultiBlockAcknowledge
+/// [S10F10]: crate::messages::s10::BroadcastAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode10 {
+  Accepted             = 0,
+  NotDisplayed         = 1,
+  TerminalNotAvailable = 2,
+}
+coded_value!{AcknowledgeCode10, Bin, 64}
+impl CodedValue<AcknowledgeCode10> {
+  /// ### IS ACCEPTED
+  pub fn is_accepted(&self) -> bool {
+    self.is(AcknowledgeCode10::Accepted)
+  }
+}
+
+/// ## ACKC13
+///
+/// **Acknowledge Code for Stream 13**
+///
+/// Data set transfer acknowledge code, 1 byte.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Values
+///
+/// - 0 = OK
+/// - 1 = Error, Try Later
+/// - 2 = Unknown Data Set Name
+/// - 3 = Illegal Checkpoint Value
+/// - 4 = Too Many Open Data Sets
+/// - 5-10 = Other Error
+/// - 11-127 = Reserved
+/// - 128-255 = User Defined
+///
+/// TODO: Enumerate values 5-10.
+///
+/// -------------------------------------------------------------------------
+///
+/// #### Used By
+///
+/// - [S13F2], [S13F4], [S13F6], [S13F8]
+/// 
+/// [S13F2]: crate::messages::s13::SendDataSetAcknowledge
+/// [S13F4]: crate::messages::s13::OpenDataSetData
+/// [S13F6]: crate::messages::s13::ReadDataSetData
+/// [S13F8]: crate::messages::s13::CloseDataSetAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode13 {
+  Ok                = 0,
+  TryLater          = 1,
+  UnknownDataSet    = 2,
+  IllegalCheckpoint = 3,
+  TooManyOpen       = 4,
+}
+coded_value!{AcknowledgeCode13, Bin, 128}
+impl CodedValue<AcknowledgeCode13> {
+  /// ### IS OK
+  pub fn is_ok(&self) -> bool {
+    self.is(AcknowledgeCode13::Ok)
+  }
+}
+
+// TODO: ACKC15
+// Use CodedValue, with 5-63 being reserved and the rest open for user values.
+
+/// ## AGENT
+/// 
+/// TODO: Document variable based on appearances in streams.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S15F11, S15F12, S15F21, S15F22, S15F25
+#[derive(Clone, Debug)]
+pub struct Agent(pub Vec<Char>);
+singleformat_vec!{Agent, Ascii}
+
+/// ## ALCD
+/// 
+/// Alarm code byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - bit 8 = 1 - Alarm Set
+/// - bit 8 = 0 - Alarm Cleared
+/// - bit 7-1 - Alarm Category
+///   - 0 - Not Used
+///   - 1 - Personal Safety
+///   - 2 - Equipment Safety
+///   - 3 - Parameter Control Warning
+///   - 4 - Parameter Control Error
+///   - 5 - Irrecoverable Error
+///   - 6 - Equipment Status Warning
+///   - 7 - Attention Flags
+///   - 8 - Data Integrity
+///   - \>8 - Other Categories
+///   - 9-63 - Reserved
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S5F1], [S5F6], [S5F8]
+/// 
+/// [S5F1]: crate::messages::s5::AlarmReportSend
+/// [S5F6]: crate::messages::s5::ListAlarmsData
+/// [S5F8]: crate::messages::s5::ListEnabledAlarmsData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AlarmCode(pub u8);
+impl AlarmCode {
+  /// ### FROM PARTS
+  /// 
+  /// Construct an alarm code from its set/cleared bit and category. Only
+  /// the lower 7 bits of the category are used.
+  pub fn from_parts(set: bool, category: CodedValue<AlarmCategory>) -> Self {
+    Self(((set as u8) << 7) | (u8::from(category) & 0b0111_1111))
+  }
+
+  /// ### IS SET
+  /// 
+  /// Returns true if the alarm is set, false if it is cleared.
+  pub fn is_set(&self) -> bool {
+    self.0 & 0b1000_0000 != 0
+  }
+
+  /// ### CATEGORY
+  pub fn category(&self) -> CodedValue<AlarmCategory> {
+    CodedValue::from(self.0 & 0b0111_1111)
+  }
+}
+singleformat!{AlarmCode, Bin}
+
+/// ## ALARM CATEGORY
+/// 
+/// Alarm category, held in bits 7-1 of [ALCD].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 - Not Used
+/// - 1 - Personal Safety
+/// - 2 - Equipment Safety
+/// - 3 - Parameter Control Warning
+/// - 4 - Parameter Control Error
+/// - 5 - Irrecoverable Error
+/// - 6 - Equipment Status Warning
+/// - 7 - Attention Flags
+/// - 8 - Data Integrity
+/// - 9-63 - Reserved
+/// - 64-127 - Other Categories
+/// 
+/// [ALCD]: AlarmCode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AlarmCategory {
+  NotUsed                 = 0,
+  PersonalSafety          = 1,
+  EquipmentSafety         = 2,
+  ParameterControlWarning = 3,
+  ParameterControlError   = 4,
+  IrrecoverableError      = 5,
+  EquipmentStatusWarning  = 6,
+  AttentionFlags          = 7,
+  DataIntegrity           = 8,
+}
+impl CodedEnum for AlarmCategory {
+  const USER_DEFINED: u8 = 64;
+}
+
+/// ## ALED
+/// 
+/// Alarm Enable/Disable Code, 1 Byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - Bit 8
+///   - 0 = Disable Alarm
+///   - 1 = Enable Alarm
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S5F3
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AlarmEnableDisable {
+  Disable = 0,
+  Enable = 128,
+}
+singleformat_enum!{AlarmEnableDisable, Bin}
+
+/// ## ALID
+/// 
+/// Alarm identification.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S5F1, S5F3, S5F5, S5F6, S5F8
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum AlarmID {
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{AlarmID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## ALID (ALL ALARMS)
+/// 
+/// Alarm identification of zero length, which stands for all alarms.
+/// 
+/// Only a zero-length integer format is accepted from an [Item], and the
+/// format it was received in is kept so that it is reproduced exactly.
+/// [ALL] is what should be sent.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S5F3]
+/// 
+/// [ALL]:  AllAlarmID::ALL
+/// [S5F3]: crate::messages::s5::EnableDisableAllAlarmSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllAlarmID {
+  format: u8,
+}
+impl AllAlarmID {
+  /// ### ALL ALARMS
+  /// 
+  /// A zero-length [U4] alarm identification.
+  /// 
+  /// [U4]: Item::U4
+  pub const ALL: Self = Self {format: format::U4};
+
+  /// ### FORMAT
+  /// 
+  /// The Format Code of the zero-length integer [Item].
+  pub const fn format(&self) -> u8 {
+    self.format
+  }
+}
+impl From<AllAlarmID> for Item {
+  fn from(value: AllAlarmID) -> Item {
+    match value.format {
+      format::I1 => Item::I1(vec![].into()),
+      format::I2 => Item::I2(vec![].into()),
+      format::I4 => Item::I4(vec![].into()),
+      format::I8 => Item::I8(vec![].into()),
+      format::U1 => Item::U1(vec![].into()),
+      format::U2 => Item::U2(vec![].into()),
+      format::U8 => Item::U8(vec![].into()),
+      _          => Item::U4(vec![].into()),
+    }
+  }
+}
+impl TryFrom<Item> for AllAlarmID {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    let format: u8 = match item {
+      Item::I1(vec) if vec.is_empty() => format::I1,
+      Item::I2(vec) if vec.is_empty() => format::I2,
+      Item::I4(vec) if vec.is_empty() => format::I4,
+      Item::I8(vec) if vec.is_empty() => format::I8,
+      Item::U1(vec) if vec.is_empty() => format::U1,
+      Item::U2(vec) if vec.is_empty() => format::U2,
+      Item::U4(vec) if vec.is_empty() => format::U4,
+      Item::U8(vec) if vec.is_empty() => format::U8,
+      _ => return Err(WrongFormat),
+    };
+    Ok(Self {format})
+  }
+}
+
+/// ## ALTX
+/// 
+/// Alarm text, maximum 120 characters.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S5F1, S5F6, S5F8
+#[derive(Clone, Debug)]
+pub struct AlarmText(Vec<Char>);
+singleformat_vec!{AlarmText, Ascii, 0..=120, Char}
+
+/// ## ATTRDATA
+/// 
+/// Specific attribute value for a specific object.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F20]
+/// - [S3F17], S3F35
+/// - [S13F13], [S13F16]
+/// - [S14F1], [S14F2], [S14F3], [S14F4], [S14F9], [S14F10], [S14F11], [S14F12],
+///   [S14F13], [S14F14], [S14F15], [S14F16], [S14F17], [S14F18], S14F19
+/// - [S18F1], [S18F3]
+/// 
+/// [S1F20]:  crate::messages::s1::AttributeData
+/// [S3F17]:  crate::messages::s3::CarrierActionRequest
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F16]: crate::messages::s13::TableData
+/// [S14F1]:  crate::messages::s14::GetAttributeRequest
+/// [S14F2]:  crate::messages::s14::GetAttributeData
+/// [S14F3]:  crate::messages::s14::SetAttributeRequest
+/// [S14F4]:  crate::messages::s14::SetAttributeData
+/// [S14F9]:  crate::messages::s14::CreateObjectRequest
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S14F11]: crate::messages::s14::DeleteObjectRequest
+/// [S14F12]: crate::messages::s14::DeleteObjectAcknowledge
+/// [S14F13]: crate::messages::s14::ObjectAttachRequest
+/// [S14F14]: crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F15]: crate::messages::s14::AttachedObjectActionRequest
+/// [S14F16]: crate::messages::s14::AttachedObjectActionAcknowledge
+/// [S14F17]: crate::messages::s14::SupervisedObjectActionRequest
+/// [S14F18]: crate::messages::s14::SupervisedObjectActionAcknowledge
+/// [S18F1]:  crate::messages::s18::ReadAttributeRequest
+/// [S18F3]:  crate::messages::s18::WriteAttributeRequest
+#[derive(Clone, Debug)]
+pub enum AttributeValue {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{AttributeValue, List, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## ATTRID
+/// 
+/// Identifier for an attribute for a type of object.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F19]
+/// - [S3F17], S3F35
+/// - [S13F13], [S13F16]
+/// - [S14F1], [S14F2], [S14F3], [S14F4], [S14F8], [S14F9], [S14F10], [S14F11],
+///   [S14F12], [S14F13], [S14F14], [S14F15], [S14F16], [S14F17], [S14F18],
+///   S14F19
+/// - [S18F1], [S18F3]
+/// 
+/// [S1F19]:  crate::messages::s1::GetAttribute
+/// [S3F17]:  crate::messages::s3::CarrierActionRequest
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F16]: crate::messages::s13::TableData
+/// [S14F1]:  crate::messages::s14::GetAttributeRequest
+/// [S14F2]:  crate::messages::s14::GetAttributeData
+/// [S14F3]:  crate::messages::s14::SetAttributeRequest
+/// [S14F4]:  crate::messages::s14::SetAttributeData
+/// [S14F8]:  crate::messages::s14::GetAttributeNameData
+/// [S14F9]:  crate::messages::s14::CreateObjectRequest
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S14F11]: crate::messages::s14::DeleteObjectRequest
+/// [S14F12]: crate::messages::s14::DeleteObjectAcknowledge
+/// [S14F13]: crate::messages::s14::ObjectAttachRequest
+/// [S14F14]: crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F15]: crate::messages::s14::AttachedObjectActionRequest
+/// [S14F16]: crate::messages::s14::AttachedObjectActionAcknowledge
+/// [S14F17]: crate::messages::s14::SupervisedObjectActionRequest
+/// [S14F18]: crate::messages::s14::SupervisedObjectActionAcknowledge
+/// [S18F1]:  crate::messages::s18::ReadAttributeRequest
+/// [S18F3]:  crate::messages::s18::WriteAttributeRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AttributeID {
+  Ascii(Vec<Char>),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{AttributeID, U1, U2, U4, U8}
+
+/// ## ATTRRELN
+/// 
+/// The relationship between a qualyfing value and the value of an attribute
+/// of an object instance (i.e. value of interest).
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F1]
+/// 
+/// [S14F1]: crate::messages::s14::GetAttributeRequest
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum AttributeRelation {
+  /// ### EQUAL TO
+  /// 
+  /// The qualifying value is equal to the value of interest.
+  EqualTo = 0,
+
+  /// ### NOT EQUAL TO
+  /// 
+  /// The qualifying value is not equal to the value of interest.
+  NotEqualTo = 1,
+
+  /// ### LESS THAN
+  /// 
+  /// The qualifying value is less than the value of interest.
+  LessThan = 2,
+
+  /// ### LESS THAN OR EQUAL TO
+  /// 
+  /// The qualifying value is less than or equal to the value of interest.
+  LessThanOrEqualTo = 3,
+
+  /// ### GREATER THAN
+  /// 
+  /// The qualifying value is greater than the value of interest.
+  GreaterThan = 4,
+
+  /// ### GREATER THAN OR EQUAL TO
+  /// 
+  /// The qualifying value is greater than or equal to the value of interest.
+  GreaterThanOrEqualTo = 5,
+
+  /// ### PRESENT
+  /// 
+  /// The qualifying value is present in the set of the value of interest.
+  Present = 6,
+
+  /// ### ABSENT
+  /// 
+  /// The qualifying value is absent from the set of the value of interest.
+  Absent = 7,
+}
+singleformat_enum!{AttributeRelation, U1}
+
+/// ## BCDS
+/// 
+/// Before Command Codes
+/// 
+/// Vector of all command codes which the defined command must preceed within
+/// the same block.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum BeforeCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
+}
+multiformat_vec!{BeforeCommandCodes, I2, U2}
+
+/// ## BCEQU
+/// 
+/// Bin code equivalents.
+/// 
+/// Array of all codes that are to be processed.
+/// 
+/// Must be same format as [BINLT] and [NULBC].
+/// 
+/// Zero length indicates that all codes be sent.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F3], [S12F4]
+/// 
+/// [BINLT]: BinList
+/// [NULBC]: NullBinCode
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Debug)]
+pub enum BinCodeEquivalents {
+  Ascii(Vec<Char>),
+  U1(Vec<u8>),
+}
+multiformat_vec!{BinCodeEquivalents, Ascii, U1}
+
+/// ## BINLT
+/// 
+/// The bin list.
+/// 
+/// Array of bin values.
+/// 
+/// Must be same format as [BCEQU] and [NULBC].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F7], [S12F9], [S12F11], [S12F14], [S12F16], [S12F18]
+/// 
+/// [BCEQU]:  BinCodeEquivalents
+/// [NULBC]:  NullBinCode
+/// [S12F7]:  crate::messages::s12::MapDataSendType1
+/// [S12F9]:  crate::messages::s12::MapDataSendType2
+/// [S12F11]: crate::messages::s12::MapDataSendType3
+/// [S12F14]: crate::messages::s12::MapDataType1
+/// [S12F16]: crate::messages::s12::MapDataType2
+/// [S12F18]: crate::messages::s12::MapDataType3
+#[derive(Clone, Debug)]
+pub enum BinList {
+  Ascii(Vec<Char>),
+  U1(Vec<u8>),
+}
+multiformat_vec!{BinList, Ascii, U1}
+
+/// ## BLKDEF
+/// 
+/// Block Definition
+/// 
+/// Specifies whether a command being defined starts, terminates, or is
+/// within the body of a block.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(i8)]
+pub enum BlockDefinition {
+  /// ### TERMINATE
+  /// 
+  /// Command terminates a block body.
+  Terminate = -1,
+
+  /// ### WITHIN
+  /// 
+  /// Command neither starts or terminates a block body.
+  Within = 0,
+
+  /// ### START
+  /// 
+  /// Command starts a block body.
+  Start = 1,
+}
+singleformat_enum!{BlockDefinition, I1}
+
+/// ## BPD
+/// 
+/// Boot program data.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S8F2
+#[derive(Clone, Debug)]
+pub struct BootProgramData(pub Vec<u8>);
+singleformat_vec!{BootProgramData, Bin}
+
+/// ## BYTMAX
+/// 
+/// **Byte Maximum**
+/// 
+/// Maximum length of a process program in bytes.
+/// 
+/// Sent in the smallest signed integer format able to hold it, but negative
+/// values are invalid and are rejected on conversion.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ByteMaximum(pub u64);
+nonnegative!{ByteMaximum, u64, signed}
+
+/// ## CAACK
+/// 
+/// **Carrier Action Acknowledge Code**
+/// 
+/// Acknowledge code for carrier actions, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Acknowledge, Command Has Been Performed
+/// - 1 = Invalid Command
+/// - 2 = Cannot Perform Now
+/// - 3 = Invalid Data or Argument
+/// - 4 = Acknowledge, Request Will Be Performed with Completion Signaled
+///   Later by an Event
+/// - 5 = Rejected, Invalid State
+/// - 6 = Command Performed with Errors
+/// - 7-63 = Reserved
+/// - 64-255 = User Defined
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F18], S3F20, S3F22, S3F24, S3F26, [S3F30], [S3F32]
+/// 
+/// [S3F18]: crate::messages::s3::CarrierActionRequestAcknowledge
+/// [S3F30]: crate::messages::s3::CarrierTagReadData
+/// [S3F32]: crate::messages::s3::CarrierTagWriteDataAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CarrierActionAcknowledge {
+  Ok              = 0,
+  InvalidCommand  = 1,
+  CannotPerform   = 2,
+  InvalidData     = 3,
+  Later           = 4,
+  InvalidState    = 5,
+  PerformedErrors = 6,
+}
+coded_value!{CarrierActionAcknowledge, U1, 64}
+impl CodedValue<CarrierActionAcknowledge> {
+  /// ### IS OK
+  pub fn is_ok(&self) -> bool {
+    self.is(CarrierActionAcknowledge::Ok)
+  }
+}
+
+/// ## CARRIERACTION
+/// 
+/// Specifies the action requested for a carrier.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F17]
+/// 
+/// [S3F17]: crate::messages::s3::CarrierActionRequest
+#[derive(Clone, Debug)]
+pub struct CarrierAction(pub Vec<Char>);
+singleformat_vec!{CarrierAction, Ascii}
+
+/// ## CARRIERID
+/// 
+/// The identifier of a carrier.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F17], [S16F11], [S16F15]
+/// 
+/// [S3F17]:  crate::messages::s3::CarrierActionRequest
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CarrierID(pub Vec<Char>);
+singleformat_vec!{CarrierID, Ascii}
+
+/// ## CARRIERSPEC
+/// 
+/// The object specifier for a carrier.
+/// 
+/// TODO: Make this conform to OBJSPEC requirements, seems related to E39.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F29], [S3F31]
+/// 
+/// [S3F29]: crate::messages::s3::CarrierTagReadRequest
+/// [S3F31]: crate::messages::s3::CarrierTagWriteDataRequest
+#[derive(Clone, Debug)]
+pub struct CarrierSpecifier(pub Vec<Char>);
+singleformat_vec!{CarrierSpecifier, Ascii}
+
+/// ## CATTRDATA
+/// 
+/// The value of a carrier attribute, mirroring [ATTRDATA].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F17]
+/// 
+/// [ATTRDATA]: AttributeValue
+/// [S3F17]:    crate::messages::s3::CarrierActionRequest
+#[derive(Clone, Debug)]
+pub enum CarrierAttributeData {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{CarrierAttributeData, List, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## CATTRID
+/// 
+/// The name of a carrier attribute.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F17]
+/// 
+/// [S3F17]: crate::messages::s3::CarrierActionRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CarrierAttributeID(pub Vec<Char>);
+singleformat_vec!{CarrierAttributeID, Ascii}
+
+/// ## CCODE
+/// 
+/// Command code.
+/// 
+/// Each command code corresponds to a unique process operation the machine
+/// is capable of performing.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22], [S7F23], [S7F26], [S7F31], S7F39, S7F43
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+/// [S7F23]: crate::messages::s7::FormattedProcessProgramSend
+/// [S7F26]: crate::messages::s7::FormattedProcessProgramData
+/// [S7F31]: crate::messages::s7::VerificationRequestSend
+#[derive(Clone, Debug)]
+pub enum CommandCode {
+  Ascii(Vec<Char>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+}
+multiformat_vec!{CommandCode, Ascii, I2, I4, U2, U4}
+
+/// ## CEED
+/// 
+/// Collection event or trace enable/disable code, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - False = Disable
+/// - True = Enable
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F37]
+/// - [S17F5]
+/// 
+/// [S2F37]: crate::messages::s2::EnableDisableEventReport
+/// [S17F5]: crate::messages::s17::TraceCreateRequest
+#[derive(Clone, Debug)]
+pub struct CollectionEventEnableDisable(pub bool);
+singleformat!{CollectionEventEnableDisable, Bool}
+
+/// ## CEID
+/// 
+/// Collection event ID.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F23], [S1F24]
+/// - [S2F35], [S2F37]
+/// - [S6F3], [S6F8], [S6F9], [S6F11], [S6F13], [S6F15], [S6F16], [S6F17],
+///   [S6F18]
+/// - [S17F5], [S17F9], [S17F10], [S17F11], [S17F12]
+/// 
+/// [S1F23]:  crate::messages::s1::CollectionEventNamelistRequest
+/// [S1F24]:  crate::messages::s1::CollectionEventNamelist
+/// [S2F35]:  crate::messages::s2::LinkEventReport
+/// [S2F37]:  crate::messages::s2::EnableDisableEventReport
+/// [S6F3]:   crate::messages::s6::DiscreteVariableDataSend
+/// [S6F8]:   crate::messages::s6::DataTransferData
+/// [S6F9]:   crate::messages::s6::FormattedVariableSend
+/// [S6F11]:  crate::messages::s6::EventReport
+/// [S6F13]:  crate::messages::s6::AnnotatedEventReport
+/// [S6F15]:  crate::messages::s6::EventReportRequest
+/// [S6F16]:  crate::messages::s6::EventReportData
+/// [S6F17]:  crate::messages::s6::AnnotatedEventReportRequest
+/// [S6F18]:  crate::messages::s6::AnnotatedEventReportData
+/// [S17F5]:  crate::messages::s17::TraceCreateRequest
+/// [S17F9]:  crate::messages::s17::CollectionEventLinkRequest
+/// [S17F10]: crate::messages::s17::CollectionEventLinkAcknowledge
+/// [S17F11]: crate::messages::s17::CollectionEventUnlinkRequest
+/// [S17F12]: crate::messages::s17::CollectionEventUnlinkAcknowledge
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CollectionEventID {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{CollectionEventID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## CENAME
+/// 
+/// Collection event name.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F24]
+/// 
+/// [S1F24]: crate::messages::s1::CollectionEventNamelist
+#[derive(Clone, Debug)]
+pub struct CollectionEventName(pub Vec<Char>);
+singleformat_vec!{CollectionEventName, Ascii}
+
+/// ## CEPACK
+/// 
+/// **Command Enhanced Paramater Acknowledge Code**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Mirrors the form of the [CEPVAL] of concern:
+/// 
+/// 1. A single [CEPACK Code], if the [CEPVAL] is a single non-list value.
+/// 2. A list of [CEPACK Code]s, if the [CEPVAL] is a list of single items,
+///    one for each item.
+/// 3. A list of items of the form of a list of two items containing a
+///    [CPNAME] and its [CEPACK], if the [CEPVAL] is a list of name-value
+///    pairs.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F50]
+/// 
+/// [CEPVAL]:      CommandEnhancedParameterValue
+/// [CEPACK]:      CommandEnhancedParameterAcknowledge
+/// [CEPACK Code]: CommandEnhancedParameterAcknowledgeCode
+/// [CPNAME]:      CommandParameterName
+/// [S2F50]:       crate::messages::s2::EnhancedRemoteCommandAcknowledge
+#[derive(Clone, Debug)]
+pub enum CommandEnhancedParameterAcknowledge {
+  Single(CommandEnhancedParameterAcknowledgeCode),
+  List(Vec<CommandEnhancedParameterAcknowledgeCode>),
+  Pairs(Vec<(CommandParameterName, CommandEnhancedParameterAcknowledge)>),
+}
+impl CommandEnhancedParameterAcknowledge {
+  /// ### PAIRS
+  /// 
+  /// Constructs a list of acknowledgements of nested name-value pairs,
+  /// mirroring a [CEPVAL] constructed with [pairs].
+  /// 
+  /// [CEPVAL]: CommandEnhancedParameterValue
+  /// [pairs]:  CommandEnhancedParameterValue::pairs
+  pub fn pairs(pairs: impl IntoIterator<Item = (CommandParameterName, Self)>) -> Self {
+    Self::Pairs(pairs.into_iter().collect())
+  }
+}
+impl From<CommandEnhancedParameterAcknowledge> for Item {
+  fn from(value: CommandEnhancedParameterAcknowledge) -> Self {
+    match value {
+      CommandEnhancedParameterAcknowledge::Single(code) => code.into(),
+      CommandEnhancedParameterAcknowledge::List(codes) => {
+        Item::List(codes.into_iter().map(Item::from).collect())
+      },
+      CommandEnhancedParameterAcknowledge::Pairs(pairs) => {
+        Item::List(pairs.into_iter().map(Item::from).collect())
+      },
+    }
+  }
+}
+impl TryFrom<Item> for CommandEnhancedParameterAcknowledge {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::List(list) => {
+        if !list.is_empty() && list.iter().all(|item| matches!(item, Item::List(_))) {
+          let mut pairs = vec![];
+          for item in list {
+            pairs.push(item.try_into()?);
+          }
+          Ok(Self::Pairs(pairs))
+        } else {
+          let mut codes = vec![];
+          for item in list {
+            codes.push(item.try_into()?);
+          }
+          Ok(Self::List(codes))
+        }
+      },
+      item => Ok(Self::Single(item.try_into()?)),
+    }
+  }
+}
+
+/// ## CEPACK CODE
+/// 
+/// Single-byte enumerated value of a [CEPACK].
+/// 
+/// [CEPACK]: CommandEnhancedParameterAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CommandEnhancedParameterAcknowledgeCode {
+  Ok = 0,
+  ParameterNameDoesNotExist = 1,
+  IllegalValue = 2,
+  IllegalFormat = 3,
+  ParameterNameNotValidAsUsed = 4,
+}
+singleformat_enum!{CommandEnhancedParameterAcknowledgeCode, U1}
+
+/// ## CEPVAL
+/// 
+/// **Command Enhanced Parameter Value**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Allowed forms:
+/// 
+/// 1. A single non-list value.
+/// 2. A list of single items of identical format.
+/// 3. A list of items of the form of a list of two items containing another
+///    name-value pair.
+/// 
+/// Conversion from an [Item] of any other form results in an
+/// [Invalid Form] error, and the [List] variant should be constructed with
+/// [list] in order to enforce the same.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F49]
+/// 
+/// [Item]:         crate::Item
+/// [Invalid Form]: crate::Error::InvalidForm
+/// [List]:         CommandEnhancedParameterValue::List
+/// [list]:         CommandEnhancedParameterValue::list
+/// [S2F49]:        crate::messages::s2::EnhancedRemoteCommand
+#[derive(Clone, Debug)]
+pub enum CommandEnhancedParameterValue {
+  List(Vec<CommandEnhancedParameterValue>),
+  Pairs(Vec<(CommandParameterName, CommandEnhancedParameterValue)>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+impl CommandEnhancedParameterValue {
+  /// ### LIST
+  /// 
+  /// Constructs a list of single values, which must be of identical format.
+  pub fn list(values: Vec<Self>) -> Result<Self, Error> {
+    let mut format = None;
+    for value in &values {
+      let discriminant = match value {
+        Self::List(_) | Self::Pairs(_) => return Err(InvalidForm),
+        value => std::mem::discriminant(value),
+      };
+      if *format.get_or_insert(discriminant) != discriminant {
+        return Err(InvalidForm)
+      }
+    }
+    Ok(Self::List(values))
+  }
+
+  /// ### PAIRS
+  /// 
+  /// Constructs a list of nested name-value pairs, each value of which may
+  /// itself be a list of name-value pairs.
+  pub fn pairs(pairs: impl IntoIterator<Item = (CommandParameterName, Self)>) -> Self {
+    Self::Pairs(pairs.into_iter().collect())
+  }
+
+  /// ### WITH PAIR
+  /// 
+  /// Appends a name-value pair to a list of nested name-value pairs, as
+  /// constructed with [pairs].
+  /// 
+  /// Fails with [Invalid Form] if this value is not such a list.
+  /// 
+  /// [pairs]:        Self::pairs
+  /// [Invalid Form]: crate::Error::InvalidForm
+  pub fn with_pair(mut self, name: CommandParameterName, value: Self) -> Result<Self, Error> {
+    match &mut self {
+      Self::Pairs(pairs) => pairs.push((name, value)),
+      _ => return Err(InvalidForm),
+    }
+    Ok(self)
+  }
+}
+impl From<CommandEnhancedParameterValue> for Item {
+  fn from(value: CommandEnhancedParameterValue) -> Self {
+    match value {
+      CommandEnhancedParameterValue::List(list) => {
+        Item::List(list.into_iter().map(Item::from).collect())
+      },
+      CommandEnhancedParameterValue::Pairs(pairs) => {
+        Item::List(pairs.into_iter().map(Item::from).collect())
+      },
+      CommandEnhancedParameterValue::Bin(vec)   => Item::Bin(vec.into()),
+      CommandEnhancedParameterValue::Bool(vec)  => Item::Bool(vec.into()),
+      CommandEnhancedParameterValue::Ascii(vec) => Item::Ascii(vec.into()),
+      CommandEnhancedParameterValue::Jis8(text) => Item::Jis8(text),
+      CommandEnhancedParameterValue::I1(vec)    => Item::I1(vec.into()),
+      CommandEnhancedParameterValue::I2(vec)    => Item::I2(vec.into()),
+      CommandEnhancedParameterValue::I4(vec)    => Item::I4(vec.into()),
+      CommandEnhancedParameterValue::I8(vec)    => Item::I8(vec.into()),
+      CommandEnhancedParameterValue::U1(vec)    => Item::U1(vec.into()),
+      CommandEnhancedParameterValue::U2(vec)    => Item::U2(vec.into()),
+      CommandEnhancedParameterValue::U4(vec)    => Item::U4(vec.into()),
+      CommandEnhancedParameterValue::U8(vec)    => Item::U8(vec.into()),
+      CommandEnhancedParameterValue::F4(vec)    => Item::F4(vec.into()),
+      CommandEnhancedParameterValue::F8(vec)    => Item::F8(vec.into()),
+    }
+  }
+}
+impl TryFrom<Item> for CommandEnhancedParameterValue {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::List(list) => {
+        if !list.is_empty() && list.iter().all(|item| matches!(item, Item::List(_))) {
+          let mut pairs = vec![];
+          for item in list {
+            match item {
+              Item::List(pair) if pair.len() == 2 => pairs.push(Item::List(pair).try_into()?),
+              _ => return Err(InvalidForm),
+            }
+          }
+          Ok(Self::Pairs(pairs))
+        } else {
+          let mut values = vec![];
+          for item in list {
+            values.push(item.try_into()?);
+          }
+          Self::list(values)
+        }
+      },
+      Item::Bin(vec)   => Ok(Self::Bin(vec.into())),
+      Item::Bool(vec)  => Ok(Self::Bool(vec.into())),
+      Item::Ascii(vec) => Ok(Self::Ascii(vec.into())),
+      Item::Jis8(text) => Ok(Self::Jis8(text)),
+      Item::I1(vec)    => Ok(Self::I1(vec.into())),
+      Item::I2(vec)    => Ok(Self::I2(vec.into())),
+      Item::I4(vec)    => Ok(Self::I4(vec.into())),
+      Item::I8(vec)    => Ok(Self::I8(vec.into())),
+      Item::U1(vec)    => Ok(Self::U1(vec.into())),
+      Item::U2(vec)    => Ok(Self::U2(vec.into())),
+      Item::U4(vec)    => Ok(Self::U4(vec.into())),
+      Item::U8(vec)    => Ok(Self::U8(vec.into())),
+      Item::F4(vec)    => Ok(Self::F4(vec.into())),
+      Item::F8(vec)    => Ok(Self::F8(vec.into())),
+      _ => Err(WrongFormat),
+    }
+  }
+}
+
+/// ## CKPNT
+/// 
+/// Checkpoint as defined by the sending system.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F3], [S13F6]
+/// 
+/// [S13F3]: crate::messages::s13::OpenDataSetRequest
+/// [S13F6]: crate::messages::s13::ReadDataSetData
+#[derive(Clone, Copy, Debug)]
+pub struct Checkpoint(pub u32);
+singleformat!{Checkpoint, U4}
+
+/// ## CMDA
+/// 
+/// Command acknowledge code.
+/// 
+/// TODO: Implement Format 31.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F22], [S2F28]
+/// 
+/// [S2F22]: crate::messages::s2::RemoteCommandAcknowledge
+/// [S2F28]: crate::messages::s2::InitiateProcessingAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CommandAcknowledge {
+  Ok = 0,
+  CommandDoesNotExist = 1,
+  CannotPerformNow = 2,
+}
+singleformat_enum!{CommandAcknowledge, U1}
+
+/// ## CMDMAX
+/// 
+/// **Command Maximum**
+/// 
+/// Maximum number of commands allowed in a process program.
+/// 
+/// Sent in the smallest signed integer format able to hold it, but negative
+/// values are invalid and are rejected on conversion.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommandMaximum(pub u64);
+nonnegative!{CommandMaximum, u64, signed}
+
+/// ## CNAME
+/// 
+/// Command name, maximum 16 characters.
+/// 
+/// A text string which is unique among other command names in a PCD, which
+/// describes the processing done by the equipment for the corresponding
+/// [CCODE].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [CCODE]: CommandCode
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub struct CommandName(Vec<Char>);
+singleformat_vec!{CommandName, Ascii, 0..=16, Char}
+
+/// ## COLCT
+/// 
+/// Column count, in die increments.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F4]
+/// 
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug)]
+pub enum ColumnCount {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{ColumnCount, U1, U2, U4, U8}
+
+/// ## COLHDR
+/// 
+/// Text description of contents of [TBLELT], 1-20 characters.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F13], [S13F15], [S13F16]
+/// 
+/// [TBLELT]: TableElement
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+/// [S13F16]: crate::messages::s13::TableData
+#[derive(Clone, Debug)]
+pub struct ColumnHeader(Vec<Char>);
+singleformat_vec!{ColumnHeader, Ascii, 1..=20, Char}
+
+/// ## COMMACK
+/// 
+/// Establish Communications Acknowledge Code, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F14]
+/// 
+/// [S1F14]: crate::messages::s1::EquipmentCRA
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CommAck {
+  /// ### ACCEPTED
+  Accepted = 0,
+
+  /// ### DENIED
+  Denied = 1,
+}
+singleformat_enum!{CommAck, Bin}
+
+/// ## COMPARISONOPERATOR
+/// 
+/// Choice of available operators that compare the supplied value to the
+/// current attribute value.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S19F1
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ComparisonOperator {
+  /// ### EQ
+  /// 
+  /// Equals, numeric or string.
+  EqualTo = 0,
+
+  /// ### NOTEQ
+  /// 
+  /// Not Equal, numeric or string.
+  NotEqualTo = 1,
+
+  /// ### LT
+  /// 
+  /// Less Than, numeric.
+  LessThan = 2,
+
+  /// ### LE
+  /// 
+  /// Less than or equal to, numeric.
+  LessThanOrEqualTo = 3,
+
+  /// ### GT
+  /// 
+  /// Greater than, numeric.
+  GreaterThan = 4,
+
+  /// ### GE
+  /// 
+  /// Greater than or equal to, numeric.
+  GreaterThanOrEqualTo = 5,
+
+  /// ### LIKE
+  /// 
+  /// Contains the substring, string.
+  Like = 6,
+
+  /// ### NOTLIKE
+  /// 
+  /// Does not contain the substring, string.
+  NotLike = 7,
+}
+singleformat_enum!{ComparisonOperator, U1}
+
+/// ## CONDITION
+/// 
+/// Provides condition information for a subsystem component.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [CONDITIONLIST]
+/// 
+/// [CONDITIONLIST]: ConditionList
+#[derive(Clone, Debug)]
+pub struct Condition(pub Vec<Char>);
+singleformat_vec!{Condition, Ascii}
+
+/// ## CONDITIONLIST
+/// 
+/// A list of [CONDITION] data sent in a fixed order.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F16]
+/// 
+/// [CONDITION]: Condition
+/// [S18F16]:    crate::messages::s18::Read2DCodeConditionData
+pub type ConditionList = VecList<Condition>;
+
+/// ## CPACK
+/// 
+/// Command parameter acknowledge code, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F42]
+/// 
+/// [S2F42]: crate::messages::s2::HostCommandAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CommandParameterAcknowledgeCode {
+  /// CPNAME does not exist.
+  ParameterNameDoesNotExist = 1,
+
+  /// Illegal value specified for CPVAL.
+  IllegalValue = 2,
+
+  /// Illegal format specified for CPVAL.
+  IllegalFormat = 3,
+}
+singleformat_enum!{CommandParameterAcknowledgeCode, Bin}
+
+/// ## CPNAME
+/// 
+/// **Command Parameter Name**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F41], [S2F42], [S2F49], [S2F50]
+/// - S4F21, S4F29
+/// - [S16F5], [S16F27]
+/// 
+/// [S2F41]:  crate::messages::s2::HostCommandSend
+/// [S2F42]:  crate::messages::s2::HostCommandAcknowledge
+/// [S2F49]:  crate::messages::s2::EnhancedRemoteCommand
+/// [S2F50]:  crate::messages::s2::EnhancedRemoteCommandAcknowledge
+/// [S16F5]:  crate::messages::s16::ProcessJobCommand
+/// [S16F27]: crate::messages::s16::ControlJobCommandRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CommandParameterName {
+  Ascii(Vec<Char>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+}
+multiformat_vec!{CommandParameterName, Ascii, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## CPVAL
+/// 
+/// **Command Parameter Value**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F41], [S2F49]
+/// - S4F21, S4F29
+/// - [S16F5], [S16F27]
+/// - [S18F13]
+/// 
+/// [S2F41]:  crate::messages::s2::HostCommandSend
+/// [S2F49]:  crate::messages::s2::EnhancedRemoteCommand
+/// [S16F5]:  crate::messages::s16::ProcessJobCommand
+/// [S16F27]: crate::messages::s16::ControlJobCommandRequest
+/// [S18F13]: crate::messages::s18::SubsystemCommandRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CommandParameterValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+}
+multiformat_vec!{CommandParameterValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8}
+
+impl From<CommandParameterValue> for CommandEnhancedParameterValue {
+  fn from(value: CommandParameterValue) -> Self {
+    match value {
+      CommandParameterValue::Bin(v) => CommandEnhancedParameterValue::Bin(v),
+      CommandParameterValue::Bool(v) => CommandEnhancedParameterValue::Bool(v),
+      CommandParameterValue::Ascii(v) => CommandEnhancedParameterValue::Ascii(v),
+      CommandParameterValue::Jis8(v) => CommandEnhancedParameterValue::Jis8(v),
+      CommandParameterValue::I1(v) => CommandEnhancedParameterValue::I1(v),
+      CommandParameterValue::I2(v) => CommandEnhancedParameterValue::I2(v),
+      CommandParameterValue::I4(v) => CommandEnhancedParameterValue::I4(v),
+      CommandParameterValue::I8(v) => CommandEnhancedParameterValue::I8(v),
+      CommandParameterValue::U1(v) => CommandEnhancedParameterValue::U1(v),
+      CommandParameterValue::U2(v) => CommandEnhancedParameterValue::U2(v),
+      CommandParameterValue::U4(v) => CommandEnhancedParameterValue::U4(v),
+      CommandParameterValue::U8(v) => CommandEnhancedParameterValue::U8(v),
+    }
+  }
+}
+
+/// ## CSAACK
+/// 
+/// Equipment acknowledge code, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F8]
+/// 
+/// [S2F8]: crate::messages::s2::ServiceProgramRunAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ServiceAcknowledgeCode {
+  Ok = 0,
+  Busy = 1,
+  InvalidSPID = 2,
+  InvalidData = 3,
+}
+singleformat_enum!{ServiceAcknowledgeCode, Bin}
+
+/// ## CTLJOBCMD
+/// 
+/// Control Job command code.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S16F27]
+/// 
+/// [S16F27]: crate::messages::s16::ControlJobCommandRequest
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ControlJobCommand {
+  /// ### CJStart
+  Start = 1,
+
+  /// ### CJPause
+  Pause = 2,
+
+  /// ### CJResume
+  Resume = 3,
+
+  /// ### CJCancel
+  Cancel = 4,
+
+  /// ### CJDeselect
+  Deselect = 5,
+
+  /// ### CJStop
+  Stop = 6,
+
+  /// ### CJAbort
+  Abort = 7,
+
+  /// ### CJHOQ
+  HeadOfQueue = 8,
+}
+singleformat_enum!{ControlJobCommand, U1}
+
+/// ## CTLJOBID
+/// 
+/// **Control Job ID**
+/// 
+/// Identifier for a control job, conforming to [OBJID].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F9], [S14F10]
+/// - [S16F27], S16F29
+/// 
+/// [OBJID]:  ObjectID
+/// [S14F9]:  crate::messages::s14::CreateObjectRequest
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S16F27]: crate::messages::s16::ControlJobCommandRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ControlJobID {
+  Ascii(Vec<Char>),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{ControlJobID, U1, U2, U4, U8}
+impl From<ControlJobID> for ObjectID {
+  fn from(value: ControlJobID) -> Self {
+    match value {
+      ControlJobID::Ascii(vec) => ObjectID::Ascii(vec),
+      ControlJobID::U1(val) => ObjectID::U1(val),
+      ControlJobID::U2(val) => ObjectID::U2(val),
+      ControlJobID::U4(val) => ObjectID::U4(val),
+      ControlJobID::U8(val) => ObjectID::U8(val),
+    }
+  }
+}
+impl From<ObjectID> for ControlJobID {
+  fn from(value: ObjectID) -> Self {
+    match value {
+      ObjectID::Ascii(vec) => ControlJobID::Ascii(vec),
+      ObjectID::U1(val) => ControlJobID::U1(val),
+      ObjectID::U2(val) => ControlJobID::U2(val),
+      ObjectID::U4(val) => ControlJobID::U4(val),
+      ObjectID::U8(val) => ControlJobID::U8(val),
+    }
+  }
+}
+
+/// ## DATA
+/// 
+/// A string of unformatted data.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S3F30], [S3F31]
+/// - [S18F6], [S18F7]
+/// 
+/// [S3F30]: crate::messages::s3::CarrierTagReadData
+/// [S3F31]: crate::messages::s3::CarrierTagWriteDataRequest
+/// [S18F6]: crate::messages::s18::ReadData
+/// [S18F7]: crate::messages::s18::WriteDataRequest
+#[derive(Clone, Debug)]
+pub struct Data(pub Vec<Char>);
+singleformat_vec!{Data, Ascii}
+
+/// ## DATAACK
+/// 
+/// Data acknowledge code.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F22]
+/// 
+/// [S14F22]: crate::messages::s14::GenericServiceCompletionAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DataAcknowledge {
+  Ok = 0,
+  UnknownDataID = 1,
+  InvalidParameter = 2,
+}
+singleformat_enum!{DataAcknowledge, Bin}
+
+/// ## DATAID
+/// 
+/// **Data ID**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F33], [S2F35], [S2F39], [S2F45], [S2F49]
+/// - S3F15, [S3F17]
+/// - S4F19, S4F25
+/// - [S6F3], [S6F5], [S6F7], [S6F8], [S6F9], [S6F11], [S6F13], [S6F16],
+///   [S6F18], [S6F25], [S6F27]
+/// - [S13F11], [S13F13], [S13F15]
+/// - [S14F19], [S14F21], [S14F23]
+/// - [S15F1], S15F13, S15F15, S15F21, S15F23, S15F25, [S15F27], S15F29, S15F33,
+///   S15F35, S15F39, S15F41, S15F43, S15F45, S15F47, S15F49
+/// - [S16F1], S16F3, [S16F5], [S16F11], [S16F15]
+/// - [S17F1], [S17F5], [S17F9]
+/// 
+/// [S2F33]:  crate::messages::s2::DefineReport
+/// [S2F35]:  crate::messages::s2::LinkEventReport
+/// [S2F39]:  crate::messages::s2::MultiBlockInquire
+/// [S2F45]:  crate::messages::s2::DefineVariableLimitAttributes
+/// [S2F49]:  crate::messages::s2::EnhancedRemoteCommand
+/// [S3F17]:  crate::messages::s3::CarrierActionRequest
+/// [S6F3]:   crate::messages::s6::DiscreteVariableDataSend
+/// [S6F5]:   crate::messages::s6::MultiBlockDataSendInquire
+/// [S6F7]:   crate::messages::s6::DataTransferRequest
+/// [S6F8]:   crate::messages::s6::DataTransferData
+/// [S6F9]:   crate::messages::s6::FormattedVariableSend
+/// [S6F11]:  crate::messages::s6::EventReport
+/// [S6F13]:  crate::messages::s6::AnnotatedEventReport
+/// [S6F16]:  crate::messages::s6::EventReportData
+/// [S6F18]:  crate::messages::s6::AnnotatedEventReportData
+/// [S6F25]:  crate::messages::s6::NotificationReportSend
+/// [S6F27]:  crate::messages::s6::TraceReportSend
+/// [S13F11]: crate::messages::s13::DataSetObjectMultiBlockInquire
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+/// [S14F23]: crate::messages::s14::GenericServiceMultiBlockInquire
+/// [S15F1]:  crate::messages::s15::RecipeManagementMultiBlockInquire
+/// [S15F27]: crate::messages::s15::RecipeDownloadRequest
+/// [S16F1]:  crate::messages::s16::ProcessJobMultiBlockInquire
+/// [S16F5]:  crate::messages::s16::ProcessJobCommand
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+/// [S17F1]:  crate::messages::s17::DataReportCreateRequest
+/// [S17F5]:  crate::messages::s17::TraceCreateRequest
+/// [S17F9]:  crate::messages::s17::CollectionEventLinkRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DataID {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{DataID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## DATALENGTH
+/// 
+/// Total bytes to be sent.
+/// 
+/// TODO: Do negative numbers need to be restricted?
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F39]
+/// - S3F15, [S3F29], [S3F31]
+/// - S4F25
+/// - [S6F5]
+/// - [S13F11]
+/// - [S14F23]
+/// - [S16F1]
+/// - [S18F5], [S18F7]
+/// - S19F19
+/// 
+/// [S2F39]:  crate::messages::s2::MultiBlockInquire
+/// [S3F29]:  crate::messages::s3::CarrierTagReadRequest
+/// [S3F31]:  crate::messages::s3::CarrierTagWriteDataRequest
+/// [S6F5]:   crate::messages::s6::MultiBlockDataSendInquire
+/// [S13F11]: crate::messages::s13::DataSetObjectMultiBlockInquire
+/// [S14F23]: crate::messages::s14::GenericServiceMultiBlockInquire
+/// [S16F1]:  crate::messages::s16::ProcessJobMultiBlockInquire
+/// [S18F5]:  crate::messages::s18::ReadRequest
+/// [S18F7]:  crate::messages::s18::WriteDataRequest
+#[derive(Clone, Debug)]
+pub enum DataLength {
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{DataLength, I1, I2, I4, I8, U1, U2, U4, U8}
+impl From<usize> for DataLength {
+  /// Uses the smallest unsigned format able to hold the length.
+  fn from(length: usize) -> Self {
+    let length = length as u64;
+    if let Ok(length) = u8::try_from(length) {
+      DataLength::U1(length)
+    } else if let Ok(length) = u16::try_from(length) {
+      DataLength::U2(length)
+    } else if let Ok(length) = u32::try_from(length) {
+      DataLength::U4(length)
+    } else {
+      DataLength::U8(length)
+    }
+  }
+}
+
+/// ## DATASEG
+/// 
+/// **Data Segment**
+/// 
+/// Identifies a particular segment of data in a subsystem component.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F5], [S18F7]
+/// 
+/// [S18F5]: crate::messages::s18::ReadRequest
+/// [S18F7]: crate::messages::s18::WriteDataRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DataSegment(pub Vec<Char>);
+singleformat_vec!{DataSegment, Ascii}
+
+/// ## DATASRC
+/// 
+/// **Data Source**
+/// 
+/// Identifier of a source of data, such as a particular chamber of an
+/// equipment, where a zero-length value indicates the default source.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S17F1]
+/// 
+/// [S17F1]: crate::messages::s17::DataReportCreateRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DataSource(pub Vec<Char>);
+singleformat_vec!{DataSource, Ascii}
+
+/// ## DATLC
+/// 
+/// **Data Location**
+/// 
+/// Location of invalid data within the message in which an error was
+/// detected.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F19]
+/// 
+/// [S12F19]: crate::messages::s12::MapErrorReportSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DataLocation(pub u8);
+singleformat!{DataLocation, U1}
+
+/// ## DRACK
+/// 
+/// **Define Report Acknowledge Code**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single byte enumerated value.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F34]
+/// 
+/// [S2F34]: crate::messages::s2::DefineReportAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DefineReportAcknowledgeCode {
+  Ok = 0,
+  InsufficientSpace = 1,
+  InvalidFormat = 2,
+  ReportAlreadyDefined = 3,
+  VariableDoesNotExist = 4,
+}
+singleformat_enum!{DefineReportAcknowledgeCode, Bin}
+
+/// ## DSID
+/// 
+/// **Data Set ID**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F3], [S6F8], [S6F9]
+/// 
+/// [S6F3]: crate::messages::s6::DiscreteVariableDataSend
+/// [S6F8]: crate::messages::s6::DataTransferData
+/// [S6F9]: crate::messages::s6::FormattedVariableSend
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DataSetID {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{DataSetID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## DSNAME
+/// 
+/// **Data Set Name**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F37], [S7F39], [S7F41], [S7F43]
+/// - [S13F1], [S13F3], [S13F4], S13F11
+/// 
+/// [S7F37]: crate::messages::s7::LargeProcessProgramSend
+/// [S7F39]: crate::messages::s7::LargeFormattedProcessProgramSend
+/// [S7F41]: crate::messages::s7::LargeProcessProgramRequest
+/// [S7F43]: crate::messages::s7::LargeFormattedProcessProgramRequest
+/// [S13F1]: crate::messages::s13::SendDataSetSend
+/// [S13F3]: crate::messages::s13::OpenDataSetRequest
+/// [S13F4]: crate::messages::s13::OpenDataSetData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DataSetName(pub Vec<Char>);
+singleformat_vec!{DataSetName, Ascii}
+
+/// ## DSPER
+/// 
+/// Data sample period.
+/// 
+/// TODO: Implement format restrictions on conversion from an [Item]. Until
+/// then, use [to_duration] to validate the format.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// Format 1:
+/// - hhmmss
+///    - hh = Hours
+///    - mm = Minutes
+///    - ss = Seconds
+/// 
+/// Format 2:
+/// - hhmmsscc
+///    - hh = Hours
+///    - mm = Minutes
+///    - ss = Seconds
+///    - cc = CentiSeconds
+/// 
+/// Equipment must implement Format 1, and may optionally implement Format 2.
+/// 
+/// Support for Format 2 does not necessitate a trace resolution of 0.01sec.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F23]
+/// - [S17F5]
+/// 
+/// [Item]:        crate::Item
+/// [to_duration]: DataSamplePeriod::to_duration
+/// [S2F23]:       crate::messages::s2::TraceInitializeSend
+/// [S17F5]:       crate::messages::s17::TraceCreateRequest
+#[derive(Clone, Debug)]
+pub struct DataSamplePeriod(pub Vec<Char>);
+singleformat_vec!{DataSamplePeriod, Ascii}
+impl DataSamplePeriod {
+  /// ### FROM DURATION
+  /// 
+  /// Constructs a [DSPER] representing the provided [Duration], using
+  /// Format 1 when the [Duration] is a whole number of seconds, and
+  /// Format 2 otherwise.
+  /// 
+  /// Any precision finer than a centisecond is discarded. Results in [None]
+  /// if the [Duration] is 100 hours or longer.
+  /// 
+  /// [DSPER]:    DataSamplePeriod
+  /// [Duration]: std::time::Duration
+  pub fn from_duration(duration: std::time::Duration) -> Option<Self> {
+    let seconds = duration.as_secs();
+    let centiseconds = duration.subsec_millis() / 10;
+    let hours = seconds / 3600;
+    if hours > 99 {return None}
+    let text = if centiseconds == 0 {
+      format!("{:02}{:02}{:02}", hours, seconds / 60 % 60, seconds % 60)
+    } else {
+      format!("{:02}{:02}{:02}{:02}", hours, seconds / 60 % 60, seconds % 60, centiseconds)
+    };
+    Some(Self(Char::safe_str_to_chars(&text)))
+  }
+
+  /// ### TO DURATION
+  /// 
+  /// Converts the [DSPER] into a [Duration], validating that it is written
+  /// in either Format 1 or Format 2, with minutes and seconds below 60.
+  /// 
+  /// Results in [None] if the [DSPER] is not valid.
+  /// 
+  /// [DSPER]:    DataSamplePeriod
+  /// [Duration]: std::time::Duration
+  pub fn to_duration(&self) -> Option<std::time::Duration> {
+    if !matches!(self.0.len(), 6 | 8) {return None}
+    let mut fields = self.0.chunks(2).map(|pair| -> Option<u64> {
+      let tens = char::from(pair[0]).to_digit(10)?;
+      let ones = char::from(pair[1]).to_digit(10)?;
+      Some((tens * 10 + ones) as u64)
+    });
+    let hours = fields.next()??;
+    let minutes = fields.next()??;
+    let seconds = fields.next()??;
+    let centiseconds = fields.next().unwrap_or(Some(0))?;
+    if minutes > 59 || seconds > 59 {return None}
+    Some(
+      std::time::Duration::from_secs(hours * 3600 + minutes * 60 + seconds)
+      + std::time::Duration::from_millis(centiseconds * 10)
+    )
+  }
+}
+
+/// ## DUTMS
+/// 
+/// **Die Units of Measure**
+/// 
+/// [Units Expression] in which [XDIES] and [YDIES] are given.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F4]
+/// 
+/// [Units Expression]: crate::units::Expression
+/// [XDIES]:            DieSizeX
+/// [YDIES]:            DieSizeY
+/// [S12F1]:            crate::messages::s12::MapSetupDataSend
+/// [S12F4]:            crate::messages::s12::MapSetupData
+#[derive(Clone, Debug)]
+pub struct DieUnitsOfMeasure(pub Vec<Char>);
+singleformat_vec!{DieUnitsOfMeasure, Ascii}
+
+/// ## DVNAME
+/// 
+/// **Data Value Name**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F3], [S6F8]
+/// 
+/// [S6F3]: crate::messages::s6::DiscreteVariableDataSend
+/// [S6F8]: crate::messages::s6::DataTransferData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DataValueName {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{DataValueName, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## DVVAL
+/// 
+/// **Data Value**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F3], [S6F8], [S6F9]
+/// 
+/// [S6F3]: crate::messages::s6::DiscreteVariableDataSend
+/// [S6F8]: crate::messages::s6::DataTransferData
+/// [S6F9]: crate::messages::s6::FormattedVariableSend
+#[derive(Clone, Debug)]
+pub enum DataValue {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{DataValue, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## DVVALNAME
+/// 
+/// Descriptive name for a data variable.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F22]
+/// 
+/// [S1F22]: crate::messages::s1::DataVariableNamelist
+#[derive(Clone, Debug)]
+pub struct DataVariableValueName(pub Vec<Char>);
+singleformat_vec!{DataVariableValueName, Ascii}
+
+/// ## EAC
+/// 
+/// EquipmentAcknowledgeCode, 1 byte.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F16]
+/// 
+/// [S2F16]: crate::messages::s2::NewEquipmentConstantAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EquipmentAcknowledgeCode {
+  Acknowledge = 0,
+  DoesNotExist = 1,
+  Busy = 2,
+  OutOfRange = 3,
+}
+singleformat_enum!{EquipmentAcknowledgeCode, Bin}
+
+/// ## ECDEF
+/// 
+/// Equipment constant default value.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F30]
+/// 
+/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+#[derive(Clone, Debug)]
+pub enum EquipmentConstantDefaultValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{EquipmentConstantDefaultValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## ECID
+/// 
+/// Equipment constant ID.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F13], [S2F15], [S2F29], [S2F30]
+/// 
+/// [S2F13]: crate::messages::s2::EquipmentConstantRequest
+/// [S2F15]: crate::messages::s2::NewEquipmentConstantSend
+/// [S2F29]: crate::messages::s2::EquipmentConstantNamelistRequest
+/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EquipmentConstantID {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{EquipmentConstantID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+impl From<EquipmentConstantID> for VariableID {
+  fn from(ec_id: EquipmentConstantID) -> Self {
+    match ec_id {
+      EquipmentConstantID::Ascii(v) => VariableID::Ascii(v),
+      EquipmentConstantID::I1(v) => VariableID::I1(v),
+      EquipmentConstantID::I2(v) => VariableID::I2(v),
+      EquipmentConstantID::I4(v) => VariableID::I4(v),
+      EquipmentConstantID::I8(v) => VariableID::I8(v),
+      EquipmentConstantID::U1(v) => VariableID::U1(v),
+      EquipmentConstantID::U2(v) => VariableID::U2(v),
+      EquipmentConstantID::U4(v) => VariableID::U4(v),
+      EquipmentConstantID::U8(v) => VariableID::U8(v),
+    }
+  }
+}
+
+impl From<VariableID> for EquipmentConstantID {
+  fn from(var_id: VariableID) -> Self {
+    match var_id {
+      VariableID::Ascii(v) => EquipmentConstantID::Ascii(v),
+      VariableID::I1(v) => EquipmentConstantID::I1(v),
+      VariableID::I2(v) => EquipmentConstantID::I2(v),
+      VariableID::I4(v) => EquipmentConstantID::I4(v),
+      VariableID::I8(v) => EquipmentConstantID::I8(v),
+      VariableID::U1(v) => EquipmentConstantID::U1(v),
+      VariableID::U2(v) => EquipmentConstantID::U2(v),
+      VariableID::U4(v) => EquipmentConstantID::U4(v),
+      VariableID::U8(v) => EquipmentConstantID::U8(v),
+    }
+  }
+}
+
+/// ## ECMAX
+/// 
+/// Equipment constant maximum value.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F30]
+/// 
+/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+#[derive(Clone, Debug)]
+pub enum EquipmentConstantMaximumValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{EquipmentConstantMaximumValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## ECMIN
+/// 
+/// Equipment constant minimum value.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F30]
+/// 
+/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+#[derive(Clone, Debug)]
+pub enum EquipmentConstantMinimumValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{EquipmentConstantMinimumValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## ECNAME
+/// 
+/// Equipment constant name.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F30]
+/// 
+/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+#[derive(Clone, Debug)]
+pub struct EquipmentConstantName(pub Vec<Char>);
+singleformat_vec!{EquipmentConstantName, Ascii}
+
+/// ## ECV
+/// 
+/// Equipment constant value.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F14], [S2F15]
+/// 
+/// [S2F14]: crate::messages::s2::EquipmentConstantData
+/// [S2F15]: crate::messages::s2::NewEquipmentConstantSend
+#[derive(Clone, Debug)]
+pub enum EquipmentConstantValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{EquipmentConstantValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## EDID
+/// 
+/// Expected data identification.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S9F13]
+/// 
+/// [S9F13]: crate::messages::s9::ConversationTimeout
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ExpectedDataID {
+  Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat_ascii!{ExpectedDataID, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## EMID
+/// 
+/// Equivalent Material ID.
+/// 
+/// Maximum 16 characters or bytes, either in ASCII or binary format.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S3F9
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EquivalentMaterialID {
+  Ascii(Vec<Char>),
+  Bin(Vec<u8>),
+}
+impl EquivalentMaterialID {
+  const MAX_LENGTH: usize = 16;
+
+  pub fn new(vec: Vec<Char>) -> Result<Self, Error> {
+    Self::check_length(vec.len())?;
+    Ok(Self::Ascii(vec))
+  }
+  /// Creates a new instance from a string.
+  ///
+  /// NOTE: Invalid ASCII characters are replaced with '?'.
+  /// If the string exceeds the maximum length, it will be automatically truncated.
+  pub fn new_from_str(vec: &str) -> Result<Self, Error> {
+    let mut vec = Char::safe_str_to_chars(vec);
+    vec.truncate(Self::MAX_LENGTH);
+    Ok(Self::Ascii(vec))
+  }
+  pub fn new_bin(vec: Vec<u8>) -> Result<Self, Error> {
+    Self::check_length(vec.len())?;
+    Ok(Self::Bin(vec))
+  }
+  fn check_length(length: usize) -> Result<(), Error> {
+    if length <= Self::MAX_LENGTH {
+      Ok(())
+    } else {
+      Err(Error::InvalidLength {length, min: 0, max: Self::MAX_LENGTH})
+    }
+  }
+}
+impl From<EquivalentMaterialID> for Item {
+  fn from(value: EquivalentMaterialID) -> Item {
+    match value {
+      EquivalentMaterialID::Ascii(vec) => Item::Ascii(vec.into()),
+      EquivalentMaterialID::Bin(vec)   => Item::Bin(vec.into()),
+    }
+  }
+}
+impl TryFrom<Item> for EquivalentMaterialID {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::Ascii(vec) => Self::new(vec.into()).map_err(|_| WrongFormat),
+      Item::Bin(vec)   => Self::new_bin(vec.into()).map_err(|_| WrongFormat),
+      _ => Err(WrongFormat),
+    }
+  }
+}
+impl std::fmt::Display for EquivalentMaterialID {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      EquivalentMaterialID::Ascii(vec) => write!(f, "{}", Char::chars_to_str(vec)),
+      EquivalentMaterialID::Bin(vec) => {
+        for byte in vec {
+          write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+      },
+    }
+  }
+}
+
+/// ## ERRCODE
 /// 
-/// #### Arguments
+/// Code identifying an error.
 /// 
-/// - **$name**: Name of enum.
-/// - **$format**: Item format.
-/// - Optional:
-///    - **$formats**: Further item formats.
+/// Standard codes are represented by [KnownErrorCode], while codes in the
+/// reserved and user defined ranges are kept as is, such that they can be
+/// re-encoded faithfully.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
-/// #### Expansion
+/// #### Values
 /// 
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-#[macro_export]
-macro_rules! multiformat {
-  (
-    $name:ident
-    ,$format:ident
-    $(,$formats:ident)*
-    $(,)?
-  ) => {
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        match value {
-          $name::$format(val) => Item::$format(vec![val]),
-          $(
-            $name::$formats(val) => Item::$formats(vec![val]),
-          )*
-        }
-        
-      }
+/// - 0-50 = Standard
+/// - 51-63 = Reserved
+/// - 64-32767 = User Defined
+/// - 32768-32784 = Standard
+/// - 32785-65535 = Reserved
+/// - 65536+ = User Defined
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F20]
+/// - [S3F18], S3F20, S3F22, S3F24, S3F26, [S3F28], [S3F30], [S3F32], S3F34,
+///   S3F36
+/// - S4F20, S4F22, S4F23, S4F31, S4F33
+/// - [S5F14], [S5F15], [S5F18]
+/// - [S6F25], [S6F30]
+/// - [S13F14], [S13F16]
+/// - [S14F2], [S14F4], [S14F5], [S14F6], [S14F8], [S14F10], [S14F12], [S14F14],
+///   [S14F16], [S14F18], [S14F20], [S14F21], [S14F26], [S14F28]
+/// - S15F4, S15F6, S15F8, S15F10, S15F12, S15F14, S15F16, S15F18, S15F20,
+///   S15F22, S15F24, S15F26, [S15F28], S15F30, [S15F32], S15F34, S15F36,
+///   S15F38, S15F40, S15F42, S15F44, S15F48, S15F53
+/// - S16F4, [S16F6], [S16F7], [S16F12], [S16F16], [S16F18], S16F24, [S16F26],
+///   [S16F28]
+/// - [S17F2], [S17F4], [S17F6], [S17F8], [S17F10], [S17F12], [S17F14]
+/// 
+/// [S1F20]:  crate::messages::s1::AttributeData
+/// [S3F18]:  crate::messages::s3::CarrierActionRequestAcknowledge
+/// [S3F28]:  crate::messages::s3::ChangeAccessAcknowledge
+/// [S3F30]:  crate::messages::s3::CarrierTagReadData
+/// [S3F32]:  crate::messages::s3::CarrierTagWriteDataAcknowledge
+/// [S5F14]:  crate::messages::s5::ExceptionRecoverAcknowledge
+/// [S5F15]:  crate::messages::s5::ExceptionRecoveryCompleteNotify
+/// [S5F18]:  crate::messages::s5::ExceptionRecoveryAbortAcknowledge
+/// [S6F25]:  crate::messages::s6::NotificationReportSend
+/// [S6F30]:  crate::messages::s6::TraceReportData
+/// [S13F14]: crate::messages::s13::TableDataAcknowledge
+/// [S13F16]: crate::messages::s13::TableData
+/// [S14F2]:  crate::messages::s14::GetAttributeData
+/// [S14F4]:  crate::messages::s14::SetAttributeData
+/// [S14F5]:  crate::messages::s14::GetTypeRequest
+/// [S14F6]:  crate::messages::s14::GetTypeData
+/// [S14F8]:  crate::messages::s14::GetAttributeNameData
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S14F12]: crate::messages::s14::DeleteObjectAcknowledge
+/// [S14F14]: crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F16]: crate::messages::s14::AttachedObjectActionAcknowledge
+/// [S14F18]: crate::messages::s14::SupervisedObjectActionAcknowledge
+/// [S14F20]: crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+/// [S14F26]: crate::messages::s14::ServiceNameData
+/// [S14F28]: crate::messages::s14::ServiceParameterData
+/// [S15F28]: crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F32]: crate::messages::s15::RecipeUploadData
+/// [S16F6]:  crate::messages::s16::ProcessJobCommandAcknowledge
+/// [S16F7]:  crate::messages::s16::ProcessJobAlertNotify
+/// [S16F12]: crate::messages::s16::ProcessJobCreateEnhancedAcknowledge
+/// [S16F16]: crate::messages::s16::ProcessJobMultiCreateAcknowledge
+/// [S16F18]: crate::messages::s16::ProcessJobDequeueAcknowledge
+/// [S16F26]: crate::messages::s16::ProcessJobSetStartMethodAcknowledge
+/// [S16F28]: crate::messages::s16::ControlJobCommandAcknowledge
+/// [S17F2]:  crate::messages::s17::DataReportCreateAcknowledge
+/// [S17F4]:  crate::messages::s17::DataReportDeleteAcknowledge
+/// [S17F6]:  crate::messages::s17::TraceCreateAcknowledge
+/// [S17F8]:  crate::messages::s17::TraceDeleteAcknowledge
+/// [S17F10]: crate::messages::s17::CollectionEventLinkAcknowledge
+/// [S17F12]: crate::messages::s17::CollectionEventUnlinkAcknowledge
+/// [S17F14]: crate::messages::s17::TraceResetAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+  Known(KnownErrorCode),
+  Reserved(u64),
+  UserDefined(u64),
+}
+impl ErrorCode {
+  /// ### KNOWN VALUE
+  /// 
+  /// Returns the standard value, if the code is one.
+  pub fn known(&self) -> Option<KnownErrorCode> {
+    match self {
+      ErrorCode::Known(code) => Some(*code),
+      _ => None,
     }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
-
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            if vec.len() == 1 {
-              Ok(Self::$format(vec[0]))
-            } else {
-              Err(WrongFormat)
-            }
-          },
-          $(
-            Item::$formats(vec) => {
-              if vec.len() == 1 {
-                Ok(Self::$formats(vec[0]))
-              } else {
-                Err(WrongFormat)
-              }
-            },
-          )*
-          _ => Err(WrongFormat),
-        }
-      }
+  }
+}
+impl From<KnownErrorCode> for ErrorCode {
+  fn from(value: KnownErrorCode) -> Self {
+    ErrorCode::Known(value)
+  }
+}
+impl From<u64> for ErrorCode {
+  fn from(value: u64) -> Self {
+    match KnownErrorCode::try_from(value) {
+      Ok(code) => ErrorCode::Known(code),
+      Err(_) => match value {
+        64..=32767 | 65536.. => ErrorCode::UserDefined(value),
+        _ => ErrorCode::Reserved(value),
+      },
+    }
+  }
+}
+impl From<ErrorCode> for u64 {
+  fn from(value: ErrorCode) -> Self {
+    match value {
+      ErrorCode::Known(code) => code.into(),
+      ErrorCode::Reserved(code) => code,
+      ErrorCode::UserDefined(code) => code,
     }
   }
 }
 
-/// ## DATA ITEM MACRO: MULTIFORMAT + ASCII
+/// ## IACDS
 /// 
-/// #### Arguments
+/// **Independent After Command Codes**
 /// 
-/// - **$name**: Name of enum.
-/// - **$format**: Item format.
-/// - Optional:
-///    - **$formats**: Further item formats.
+/// Vector of all command codes which the defined command must succeed,
+/// regardless of block.
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Expansion
+/// #### Used By
 /// 
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-#[macro_export]
-macro_rules! multiformat_ascii {
-  (
-    $name:ident
-    ,$format:ident
-    $(,$formats:ident)*
-    $(,)?
-  ) => {
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        match value {
-          $name::Ascii(vec) => Item::Ascii(vec),
-          $name::$format(val) => Item::$format(vec![val]),
-          $($name::$formats(val) => Item::$formats(vec![val]),)*
-        }
-      }
-    }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
-
-      fn try_from(item: Item) -> Result<Self, Self::Error> {
-        match item {
-          Item::Ascii(vec) => Ok($name::Ascii(vec)),
-          Item::$format(vec) => {
-            if vec.len() == 1 {
-              Ok(Self::$format(vec[0]))
-            } else {
-              Err(WrongFormat)
-            }
-          },
-          $(Item::$formats(vec) => {
-            if vec.len() == 1 {
-              Ok(Self::$formats(vec[0]))
-            } else {
-              Err(WrongFormat)
-            }
-          },)*
-          _ => Err(WrongFormat),
-        }
-      }
-    }
-  }
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum IndependentAfterCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
 }
+multiformat_vec!{IndependentAfterCommandCodes, I2, U2}
 
-/// ## DATA ITEM MACRO: MULTIFORMAT, VEC
+/// ## IBCDS
 /// 
-/// #### Arguments
+/// **Independent Before Command Codes**
 /// 
-/// - **$name**: Name of enum.
-/// - **$format**: Item format.
-/// - Optional:
-///    - **$formats**: Further item formats.
+/// Vector of all command codes which the defined command must precede,
+/// regardless of block.
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Expansion
+/// #### Used By
 /// 
-/// - From\<$name\> for Item
-/// - TryFrom\<Item\> for $name
-#[macro_export]
-macro_rules! multiformat_vec {
-  (
-    $name:ident
-    ,$format:ident
-    $(,$formats:ident)*
-    $(,)?
-  ) => {
-    impl From<$name> for Item {
-      fn from(value: $name) -> Item {
-        match value {
-          $name::$format(vec) => Item::$format(vec),
-          $(
-            $name::$formats(vec) => Item::$formats(vec),
-          )*
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum IndependentBeforeCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
+}
+multiformat_vec!{IndependentBeforeCommandCodes, I2, U2}
+
+/// ## KNOWN ERROR CODE
+/// 
+/// Standard values of [ERRCODE].
+/// 
+/// [ERRCODE]: ErrorCode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u64)]
+pub enum KnownErrorCode {
+  NoError                         = 0,
+  UnknownObjectInObjectSpecifier  = 1,
+  UnknownTargetObjectType         = 2,
+  UnknownObjectInstance           = 3,
+  UnknownAttributeName            = 4,
+  ReadonlyAttributeAccessDenied   = 5,
+  UnknownObjectType               = 6,
+  InvalidAttributeValue           = 7,
+  SyntaxError                     = 8,
+  VerificationError               = 9,
+  ValidationError                 = 10,
+  ObjectIdentifierInUse           = 11,
+  ParametersImproperlySpecified   = 12,
+  InsufficientParametersSpecified = 13,
+  UnsupportedOptionRequested      = 14,
+  Busy                            = 15,
+  NotAvailableForProcessing       = 16,
+  CommandNotValidForCurrentState  = 17,
+  NoMaterialAltered               = 18,
+  MaterialPartiallyProcessed      = 19,
+  AllMaterialProcessed            = 20,
+  RecipeSpecificationError        = 21,
+  FailedDuringProcessing          = 22,
+  FailedWhileNotProcessing        = 23,
+  FailedDueToLackOfMaterial       = 24,
+  JobAborted                      = 25,
+  JobStopped                      = 26,
+  JobCancelled                    = 27,
+  CannotChangeSelectedRecipe      = 28,
+  UnknownEvent                    = 29,
+  DuplicateReportID               = 30,
+  UnknownDataReport               = 31,
+  DataReportNotLinked             = 32,
+  UnknownTraceReport              = 33,
+  DuplicateTraceID                = 34,
+  TooManyDataReports              = 35,
+  SamplePeriodOutOfRange          = 36,
+  GroupSizeTooLarge               = 37,
+  RecoveryActionCurrentlyInvalid  = 38,
+  BusyWithAnotherRecovery         = 39,
+  NoActiveRecoveryAction          = 40,
+  ExceptionRecoveryFailed         = 41,
+  ExceptionRecoveryAborted        = 42,
+  InvalidTableElement             = 43,
+  UnknownTableElement             = 44,
+  CannotDeletePredefined          = 45,
+  InvalidToken                    = 46,
+  InvalidParameter                = 47,
+  LoadPortDoesNotExist            = 48,
+  LoadPortAlreadyInUse            = 49,
+  MissingCarrier                  = 50,
+  //51-63: Reserved
+  //64-32767: User Defined
+  ActionWillBePerformed           = 32768,
+  ActionCannotBePerformedNow      = 32769,
+  ActionFailedDueToErrors         = 32770,
+  InvalidCommand                  = 32771,
+  ClientAlr                       = 32772,
+  DuplicateClientID               = 32773,
+  InvalidClientType               = 32774,
+  IncompatibleVersions            = 32775,
+  UnrecognizedClientID            = 32776,
+  FailedCompletedUnsuccessfully   = 32777,
+  FailedUnsafe                    = 32778,
+  SensorDetectedObstacle          = 32779,
+  MaterialNotSent                 = 32780,
+  MaterialNotReceived             = 32781,
+  MaterialLost                    = 32782,
+  HardwareFailure                 = 32783,
+  TransferCancelled               = 32784,
+  //32785-32789: Reserved for SEMI E127
+  //32793-65335: Reserved
+  //65536+: User Defined
+}
+impl From<ErrorCode> for Item {
+  fn from(value: ErrorCode) -> Self {
+    let number: u64 = value.into();
+    Item::u8(number).into_smallest_format()
+  }
+}
+impl TryFrom<Item> for ErrorCode {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::U1(vec) => {
+        if vec.len() == 1 {
+          Ok(ErrorCode::from(vec[0] as u64))
+        } else {
+          Err(WrongFormat)
+        }
+      },
+      Item::U2(vec) => {
+        if vec.len() == 1 {
+          Ok(ErrorCode::from(vec[0] as u64))
+        } else {
+          Err(WrongFormat)
+        }
+      },
+      Item::U4(vec) => {
+        if vec.len() == 1 {
+          Ok(ErrorCode::from(vec[0] as u64))
+        } else {
+          Err(WrongFormat)
         }
-        
-      }
-    }
-    impl TryFrom<Item> for $name {
-      type Error = Error;
-
-      fn try_from(value: Item) -> Result<Self, Self::Error> {
-        match value {
-          Item::$format(vec) => {
-            Ok(Self::$format(vec))
-          },
-          $(
-            Item::$formats(vec) => {
-              Ok(Self::$formats(vec))
-            },
-          )*
-          _ => Err(WrongFormat),
+      },
+      Item::U8(vec) => {
+        if vec.len() == 1 {
+          Ok(ErrorCode::from(vec[0]))
+        } else {
+          Err(WrongFormat)
         }
-      }
+      },
+      _ => Err(WrongFormat),
     }
   }
 }
 
-// ITEMS
-
-/// ## ABS
-/// 
-/// Any binary string.
-/// 
-/// -------------------------------------------------------------------------
+/// ## ERACK
 /// 
-/// #### Used By
+/// **Enable/Disable Event Report Acknowledge Code**
 /// 
-/// - [S2F25], [S2F26]
+/// ---------------------------------------------------------------------------
 /// 
-/// [S2F25]: crate::messages::s2::LoopbackDiagnosticRequest
-/// [S2F26]: crate::messages::s2::LoopbackDiagnosticData
-#[derive(Clone, Debug)]
-pub struct AnyBinaryString(pub Vec<u8>);
-singleformat_vec!{AnyBinaryString, Bin}
-
-/// ## ACCESSMODE
+/// #### Format
 /// 
-/// Load Port Access Mode
+/// Single-byte enumerated value.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S3F21, S3F27
+/// - [S2F38]
+/// 
+/// [S2F38]: crate::messages::s2::EnableDisableEventReportAcknowledge
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum AccessMode {
-  Manual = 0,
-  Auto = 1,
+pub enum EnableDisableEventReportAcknowledgeCode {
+  Ok = 0,
+  CollectionEventDoesNotExist = 1,
 }
-singleformat_enum!{AccessMode, U1}
+singleformat_enum!{EnableDisableEventReportAcknowledgeCode, Bin}
 
-/// ## ACDS
+/// ## ERRTEXT
 /// 
-/// After Command Codes
+/// Text string describing the error noted in the corresponding [ERRCODE].
 /// 
-/// Vector of all command codes which the defined command must succeed
-/// within the same block.
+/// Maximum 120 characters.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S7F22
+/// - [S1F20]
+/// - [S3F18], S3F20, S3F22, S3F24, S3F26, [S3F28], [S3F30], [S3F32], S3F34,
+///   S3F36
+/// - S4F20, S4F22, S4F23, S4F31, S4F33
+/// - [S5F14], [S5F15], [S5F18]
+/// - [S6F25]
+/// - [S13F14], [S13F16]
+/// - [S14F2], [S14F4], [S14F6], [S14F8], [S14F10], [S14F12], [S14F14],
+///   [S14F16], [S14F18], [S14F20], [S14F21], [S14F26], [S14F28]
+/// - S15F4, S15F6, S15F8, S15F10, S15F12, S15F14, S15F16, S15F18, S15F20,
+///   S15F22, S15F24, S15F26, [S15F28], S15F30, [S15F32], S15F34, S15F36,
+///   S15F38, S15F40, S15F42, S15F44, S15F48, S15F53
+/// - S16F4, [S16F6], [S16F7], [S16F12], [S16F16], [S16F18], S16F24, [S16F26],
+///   [S16F28]
+/// - [S17F4], [S17F8], S17F18
+/// 
+/// [ERRCODE]: ErrorCode
+/// [S1F20]:   crate::messages::s1::AttributeData
+/// [S3F18]:   crate::messages::s3::CarrierActionRequestAcknowledge
+/// [S3F28]:   crate::messages::s3::ChangeAccessAcknowledge
+/// [S3F30]:   crate::messages::s3::CarrierTagReadData
+/// [S3F32]:   crate::messages::s3::CarrierTagWriteDataAcknowledge
+/// [S5F14]:   crate::messages::s5::ExceptionRecoverAcknowledge
+/// [S5F15]:   crate::messages::s5::ExceptionRecoveryCompleteNotify
+/// [S5F18]:   crate::messages::s5::ExceptionRecoveryAbortAcknowledge
+/// [S6F25]:   crate::messages::s6::NotificationReportSend
+/// [S13F14]:  crate::messages::s13::TableDataAcknowledge
+/// [S13F16]:  crate::messages::s13::TableData
+/// [S14F2]:   crate::messages::s14::GetAttributeData
+/// [S14F4]:   crate::messages::s14::SetAttributeData
+/// [S14F6]:   crate::messages::s14::GetTypeData
+/// [S14F8]:   crate::messages::s14::GetAttributeNameData
+/// [S14F10]:  crate::messages::s14::CreateObjectAcknowledge
+/// [S14F12]:  crate::messages::s14::DeleteObjectAcknowledge
+/// [S14F14]:  crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F16]:  crate::messages::s14::AttachedObjectActionAcknowledge
+/// [S14F18]:  crate::messages::s14::SupervisedObjectActionAcknowledge
+/// [S14F20]:  crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]:  crate::messages::s14::GenericServiceCompletion
+/// [S14F26]:  crate::messages::s14::ServiceNameData
+/// [S14F28]:  crate::messages::s14::ServiceParameterData
+/// [S15F28]:  crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F32]:  crate::messages::s15::RecipeUploadData
+/// [S16F6]:   crate::messages::s16::ProcessJobCommandAcknowledge
+/// [S16F7]:   crate::messages::s16::ProcessJobAlertNotify
+/// [S16F12]:  crate::messages::s16::ProcessJobCreateEnhancedAcknowledge
+/// [S16F16]:  crate::messages::s16::ProcessJobMultiCreateAcknowledge
+/// [S16F18]:  crate::messages::s16::ProcessJobDequeueAcknowledge
+/// [S16F26]:  crate::messages::s16::ProcessJobSetStartMethodAcknowledge
+/// [S16F28]:  crate::messages::s16::ControlJobCommandAcknowledge
+/// [S17F4]:   crate::messages::s17::DataReportDeleteAcknowledge
+/// [S17F8]:   crate::messages::s17::TraceDeleteAcknowledge
 #[derive(Clone, Debug)]
-pub enum AfterCommandCodes {
-  I2(Vec<i16>),
-  U2(Vec<u16>),
-}
-multiformat_vec!{AfterCommandCodes, I2, U2}
+pub struct ErrorText(Vec<Char>);
+singleformat_vec!{ErrorText, Ascii, 0..=120, Char}
 
-/// ## ACKA
+/// ## ERRW7
 /// 
-/// Request success, true is successful, false is unsuccessful.
+/// Text string describing an error found in a process program.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F14, S5F15, S5F18
-/// - S16F4, S16F6, S16F7, S16F12, S16F16, S16F18, S16F24, S16F26, S16F28,
-///   S16F30
-/// - S17F4, S17F8, S17F14
-#[derive(Clone, Copy, Debug)]
-pub struct AcknowledgeAny(pub bool);
-singleformat!{AcknowledgeAny, Bool}
-
-// TODO: ACKC3
-// How to deal with 1-63 being reserved but the rest being open for user values?
-
-/// ## ACKC5
-///
-/// Acknowledge code for Stream 5.
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Values
-///
-/// - 0 = Accepted
-/// - 1 = Error, Not Accepted
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Used By
-///
-/// - S5F2, S5F4
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum AcknowledgeCode5 {
-  Accepted = 0,
-  NotAccepted = 1,
-}
-singleformat_enum!{AcknowledgeCode5, Bin}
-
-/// ## ACKC6
-///
-/// Acknowledge code for Stream 6.
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Values
-///
-/// - 0 = Accepted
-/// - 1 = Error, Not Accepted
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Used By
-///
-/// - S6F12
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum AcknowledgeCode6 {
-  Accepted = 0,
-  NotAccepted = 1,
-}
-singleformat_enum!{AcknowledgeCode6, Bin}
-
-// TODO: ACKC7
-// How to deal with 7-63 being reserved but the rest being open for user values?
-
-// TODO: ACKC7A
-// How to deal with 6-63 being reserved but the rest being open for user values?
+/// - [S7F26]
+/// 
+/// [S7F26]: crate::messages::s7::FormattedProcessProgramData
+#[derive(Clone, Debug)]
+pub struct ProcessProgramErrorText(pub Vec<Char>);
+singleformat_vec!{ProcessProgramErrorText, Ascii}
 
-/// ## ACKC10
-///
-/// **Acknowledge Code for Stream 10**
-///
-/// Terminal display acknowledge code, 1 byte.
-///
-/// -------------------------------------------------------------------------
-///
-/// #### Values
-///
-/// - 0 = Accepted
-/// - 1 = Will not be displayed
-/// - 2 = Terminal not available
-/// - 3-63 = Reserved
-///
+/// ## EVNTSRC
+/// 
+/// **Event Source**
+/// 
+/// Identifier of a source of collection events, such as a particular module
+/// of an equipment, where a zero-length value indicates the default source.
+/// 
 /// -------------------------------------------------------------------------
-///
+/// 
 /// #### Used By
-///
-/// - S10F2, S10F4, S10F6, S10F10
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum AcknowledgeCode10 {
-  Accepted = 0,
-  NotDisplayed = 1,
-  TerminalNotAvailable = 2,
-}
-singleformat_enum!{AcknowledgeCode10, Bin}
-
-// TODO: ACKC13
-// How to deal with 11-127 being reserved but the rest being open for user values?
-
-// TODO: ACKC15
-// How to deal with 5-63 being reserved but the rest being open for user values?
+/// 
+/// - [S17F9], [S17F10], [S17F11], [S17F12]
+/// 
+/// [S17F9]:  crate::messages::s17::CollectionEventLinkRequest
+/// [S17F10]: crate::messages::s17::CollectionEventLinkAcknowledge
+/// [S17F11]: crate::messages::s17::CollectionEventUnlinkRequest
+/// [S17F12]: crate::messages::s17::CollectionEventUnlinkAcknowledge
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EventSource(pub Vec<Char>);
+singleformat_vec!{EventSource, Ascii}
 
-/// ## AGENT
+/// ## EXID
 /// 
-/// TODO: Document variable based on appearances in streams.
+/// **Exception ID**
+/// 
+/// Identifier of an exception, unique within the equipment.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S15F11, S15F12, S15F21, S15F22, S15F25
-#[derive(Clone, Debug)]
-pub struct Agent(pub Vec<Char>);
-singleformat_vec!{Agent, Ascii}
+/// - [S5F9], [S5F11], [S5F13], [S5F14], [S5F15], [S5F17], [S5F18]
+/// 
+/// [S5F9]:  crate::messages::s5::ExceptionPostNotify
+/// [S5F11]: crate::messages::s5::ExceptionClearNotify
+/// [S5F13]: crate::messages::s5::ExceptionRecoverRequest
+/// [S5F14]: crate::messages::s5::ExceptionRecoverAcknowledge
+/// [S5F15]: crate::messages::s5::ExceptionRecoveryCompleteNotify
+/// [S5F17]: crate::messages::s5::ExceptionRecoveryAbortRequest
+/// [S5F18]: crate::messages::s5::ExceptionRecoveryAbortAcknowledge
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ExceptionID(pub Vec<Char>);
+singleformat_vec!{ExceptionID, Ascii}
 
-/// ## ALCD
+/// ## EXMESSAGE
 /// 
-/// Alarm code byte.
+/// **Exception Message**
+/// 
+/// Text describing an exception.
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Values
+/// #### Used By
 /// 
-/// - bit 8 = 1 - Alarm Set
-/// - bit 8 = 0 - Alarm Cleared
-/// - bit 7-1 - Alarm Category
-///   - 0 - Not Used
-///   - 1 - Personal Safety
-///   - 2 - Equipment Safety
-///   - 3 - Parameter Control Warning
-///   - 4 - Parameter Control Error
-///   - 5 - Irrecoverable Error
-///   - 6 - Equipment Status Warning
-///   - 7 - Attention Flags
-///   - 8 - Data Integrity
-///   - \>8 - Other Categories
-///   - 9-63 - Reserved
+/// - [S5F9], [S5F11]
+/// 
+/// [S5F9]:  crate::messages::s5::ExceptionPostNotify
+/// [S5F11]: crate::messages::s5::ExceptionClearNotify
+#[derive(Clone, Debug)]
+pub struct ExceptionMessage(pub Vec<Char>);
+singleformat_vec!{ExceptionMessage, Ascii}
+
+/// ## EXRECVRA
+/// 
+/// **Exception Recovery Action**
 /// 
-/// TODO: Implement Set/Cleared and Category Manually?
+/// Text describing a recovery action which may be taken for an exception.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F6, S5F8
-#[derive(Clone, Copy, Debug)]
-pub struct AlarmCode(pub u8);
-singleformat!{AlarmCode, Bin}
+/// - [S5F9], [S5F13]
+/// 
+/// [S5F9]:  crate::messages::s5::ExceptionPostNotify
+/// [S5F13]: crate::messages::s5::ExceptionRecoverRequest
+#[derive(Clone, Debug)]
+pub struct ExceptionRecoveryAction(pub Vec<Char>);
+singleformat_vec!{ExceptionRecoveryAction, Ascii}
 
-/// ## ALED
+/// ## EXTYPE
 /// 
-/// Alarm Enable/Disable Code, 1 Byte.
+/// **Exception Type**
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Values
 /// 
-/// - Bit 8
-///   - 0 = Disable Alarm
-///   - 1 = Enable Alarm
+/// - "ALARM"
+/// - "ERROR"
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F3
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum AlarmEnableDisable {
-  Disable = 0,
-  Enable = 128,
+/// - [S5F9], [S5F11]
+/// 
+/// [S5F9]:  crate::messages::s5::ExceptionPostNotify
+/// [S5F11]: crate::messages::s5::ExceptionClearNotify
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExceptionType {
+  Alarm,
+  Error,
 }
-singleformat_enum!{AlarmEnableDisable, Bin}
+impl From<ExceptionType> for Item {
+  fn from(value: ExceptionType) -> Item {
+    match value {
+      ExceptionType::Alarm => Item::from("ALARM"),
+      ExceptionType::Error => Item::from("ERROR"),
+    }
+  }
+}
+impl TryFrom<Item> for ExceptionType {
+  type Error = Error;
 
-/// ## ALID
+  fn try_from(value: Item) -> Result<Self, Error> {
+    match value {
+      Item::Ascii(vec) => match Char::chars_to_str(&vec).as_str() {
+        "ALARM" => Ok(ExceptionType::Alarm),
+        "ERROR" => Ok(ExceptionType::Error),
+        _ => Err(WrongFormat),
+      },
+      _ => Err(WrongFormat),
+    }
+  }
+}
+
+/// ## FCNID
 /// 
-/// Alarm identification.
+/// **Function ID**
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F3, S5F5, S5F6, S5F8
+/// - [S2F43], [S2F44]
+/// 
+/// [S2F43]: crate::messages::s2::ResetSpoolingStreamsAndFunctions
+/// [S2F44]: crate::messages::s2::ResetSpoolingAcknowledge
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub enum AlarmID {
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
-  U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
-}
-multiformat!{AlarmID, I1, I2, I4, I8, U1, U2, U4, U8}
+pub struct FunctionID(pub u8);
+singleformat!{FunctionID, U1}
+
+/// ## FFROT
+/// 
+/// **Film Frame Rotation**
+/// 
+/// Rotation of the film frame, in whole degrees clockwise from the top of
+/// the frame.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F3]
+/// 
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FilmFrameRotation(pub u16);
+singleformat!{FilmFrameRotation, U2}
 
-/// ## ALID
+/// ## FILDAT
 /// 
-/// Alarm identification.
+/// **File Data**
+/// 
+/// Data read from a data set.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F3, S5F5, S5F6, S5F8
+/// - [S13F6]
+/// 
+/// [S13F6]: crate::messages::s13::ReadDataSetData
 #[derive(Clone, Debug)]
-pub enum AllAlarmID {
-  List(Vec<Item>),
+pub enum FileData {
   Bin(Vec<u8>),
-  Bool(Vec<bool>),
   Ascii(Vec<Char>),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
 }
-multiformat_vec!{AllAlarmID, List, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{FileData, Bin, Ascii}
 
-/// ## ALTX
+/// ## FNLOC
 /// 
-/// Alarm text, maximum 120 characters.
+/// **Flat/Notch Location**
+/// 
+/// Location of the flat or notch of the wafer, in whole degrees clockwise
+/// from the top of the wafer.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F6, S5F8
-#[derive(Clone, Debug)]
-pub struct AlarmText(Vec<Char>);
-singleformat_vec!{AlarmText, Ascii, 0..=120, Char}
+/// - [S12F1], [S12F3], [S12F4]
+/// 
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlatNotchLocation(pub u16);
+singleformat!{FlatNotchLocation, U2}
 
-/// ## ATTRDATA
+/// ## FRMLEN
 /// 
-/// Specific attribute value for a specific object.
+/// **Formatted Length**
+/// 
+/// Length of a formatted process program in bytes.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F20]
-/// - S3F17, S3F35
-/// - S13F13, S13F16
-/// - S14F1, S14F2, S14F3, S14F4, S14F9, S14F10, S14F11, S14F12, S14F13,
-///   S14F14, S14F15, S14F16, S14F17, S14F18, S14F19
-/// - S18F1, S18F3
+/// - [S7F34]
 /// 
-/// [S1F20]: crate::messages::s1::AttributeData
-#[derive(Clone, Debug)]
-pub enum AttributeValue {
-  List(Vec<Item>),
-  Bin(Vec<u8>),
-  Bool(Vec<bool>),
-  Ascii(Vec<Char>),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
-}
-multiformat_vec!{AttributeValue, List, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+/// [S7F34]: crate::messages::s7::ProcessProgramAvailabilityData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormattedLength(pub u64);
+nonnegative!{FormattedLength, u64}
 
-/// ## ATTRID
+/// ## GRANT
 /// 
-/// Identifier for an attribute for a type of object.
+/// Grant code, 1 byte.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F19]
-/// - S3F17, S3F35
-/// - S13F13, S13F16
-/// - S14F1, S14F2, S14F3, S14F4, S14F8, S14F9, S14F10, S14F11, S14F12,
-///   S14F13, S14F14, S14F15, S14F16, S14F17, S14F18, S14F19
-/// - S18F1, S18F3
+/// - [S2F2], [S2F40]
+/// - S3F16
+/// - S4F26
+/// - [S13F12]
+/// - [S14F24]
+/// - [S16F2]
+/// - S19F20
 /// 
-/// [S1F19]: crate::messages::s1::GetAttribute
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum AttributeID {
-  Ascii(Vec<Char>),
-  U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
+/// [S2F2]:   crate::messages::s2::ServiceProgramLoadGrant
+/// [S2F40]:  crate::messages::s2::MultiBlockGrant
+/// [S13F12]: crate::messages::s13::DataSetObjectMultiBlockGrant
+/// [S14F24]: crate::messages::s14::GenericServiceMultiBlockGrant
+/// [S16F2]:  crate::messages::s16::ProcessJobMultiBlockGrant
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Grant {
+  Granted = 0,
+  Busy = 1,
+  NoSpaceAvailable = 2,
+  DuplicateDataID = 3,
 }
-multiformat_ascii!{AttributeID, U1, U2, U4, U8}
+singleformat_enum!{Grant, Bin}
 
-/// ## ATTRRELN
+/// ## GRANT6
 /// 
-/// The relationship between a qualyfing value and the value of an attribute
-/// of an object instance (i.e. value of interest).
+/// Permission to send, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Permission Granted
+/// - 1 = Busy, Try Again
+/// - 2 = Not Interested
+/// - 3-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S14F1
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// - [S6F6]
+/// 
+/// [S6F6]: crate::messages::s6::MultiBlockGrant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum AttributeRelation {
-  /// ### EQUAL TO
-  /// 
-  /// The qualifying value is equal to the value of interest.
-  EqualTo = 0,
-
-  /// ### NOT EQUAL TO
-  /// 
-  /// The qualifying value is not equal to the value of interest.
-  NotEqualTo = 1,
-
-  /// ### LESS THAN
-  /// 
-  /// The qualifying value is less than the value of interest.
-  LessThan = 2,
-
-  /// ### LESS THAN OR EQUAL TO
-  /// 
-  /// The qualifying value is less than or equal to the value of interest.
-  LessThanOrEqualTo = 3,
-
-  /// ### GREATER THAN
-  /// 
-  /// The qualifying value is greater than the value of interest.
-  GreaterThan = 4,
-
-  /// ### GREATER THAN OR EQUAL TO
-  /// 
-  /// The qualifying value is greater than or equal to the value of interest.
-  GreaterThanOrEqualTo = 5,
-
-  /// ### PRESENT
-  /// 
-  /// The qualifying value is present in the set of the value of interest.
-  Present = 6,
-
-  /// ### ABSENT
-  /// 
-  /// The qualifying value is absent from the set of the value of interest.
-  Absent = 7,
+pub enum Grant6 {
+  Granted       = 0,
+  Busy          = 1,
+  NotInterested = 2,
 }
-singleformat_enum!{AttributeRelation, U1}
+coded_value!{Grant6, Bin, 64}
 
-/// ## BCDS
+/// ## GRNT1
 /// 
-/// Before Command Codes
+/// **Grant Code for Stream 12**
 /// 
-/// Vector of all command codes which the defined command must preceed within
-/// the same block.
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Permission Granted
+/// - 1 = Busy, Try Again
+/// - 2 = No Space Available
+/// - 3 = Map Too Large
+/// - 4 = Duplicate ID
+/// - 5 = Material ID Not Found
+/// - 6 = Unknown Map Format
+/// - 7-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S7F22
-#[derive(Clone, Debug)]
-pub enum BeforeCommandCodes {
-  I2(Vec<i16>),
-  U2(Vec<u16>),
+/// - [S12F6]
+/// 
+/// [S12F6]: crate::messages::s12::MapTransmitGrant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Grant12 {
+  Granted          = 0,
+  Busy             = 1,
+  NoSpace          = 2,
+  MapTooLarge      = 3,
+  DuplicateID      = 4,
+  MaterialNotFound = 5,
+  UnknownFormat    = 6,
 }
-multiformat_vec!{BeforeCommandCodes, I2, U2}
+coded_value!{Grant12, Bin, 64}
 
-/// ## BCEQU
-/// 
-/// Bin code equivalents.
-/// 
-/// Array of all codes that are to be processed.
-/// 
-/// Must be same format as [BINLT] and [NULBC].
+/// ## HANDLE
 /// 
-/// Zero length indicates that all codes be sent.
+/// Logical handle of an open data set.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S12F3, S12F4
+/// - [S13F3], [S13F4], [S13F5], [S13F6], [S13F7], [S13F8]
 /// 
-/// [BINLT]: BinList
-/// [NULBC]: NullBinCode
-#[derive(Clone, Debug)]
-pub enum BinCodeEquivalents {
-  Ascii(Vec<Char>),
-  U1(Vec<u8>),
+/// [S13F3]: crate::messages::s13::OpenDataSetRequest
+/// [S13F4]: crate::messages::s13::OpenDataSetData
+/// [S13F5]: crate::messages::s13::ReadDataSetRequest
+/// [S13F6]: crate::messages::s13::ReadDataSetData
+/// [S13F7]: crate::messages::s13::CloseDataSetSend
+/// [S13F8]: crate::messages::s13::CloseDataSetAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handle {
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
 }
-multiformat_vec!{BinCodeEquivalents, Ascii, U1}
+multiformat!{Handle, I1, I2, I4, I8, U1, U2, U4, U8}
 
-/// ## BINLT
+/// ## HCACK
 /// 
-/// The bin list.
+/// **Host Command Parameter Acknowledge Code**
 /// 
-/// Array of bin values.
+/// ---------------------------------------------------------------------------
 /// 
-/// Must be same format as [BCEQU] and [NULBC].
+/// #### Format
 /// 
-/// -------------------------------------------------------------------------
+/// Single-byte enumerated value.
+/// 
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S12F7, S12F9, S12F11, S12F14, S12F16, S12F18
+/// - [S2F42], [S2F50]
 /// 
-/// [BCEQU]: BinCodeEquivalents
-/// [NULBC]: NullBinCode
-#[derive(Clone, Debug)]
-pub enum BinList {
-  Ascii(Vec<Char>),
-  U1(Vec<u8>),
+/// [S2F42]: crate::messages::s2::HostCommandAcknowledge
+/// [S2F50]: crate::messages::s2::EnhancedRemoteCommandAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum HostCommandAcknowledgeCode {
+  Ok = 0,
+  CommandDoesNotExist = 1,
+  CannotPerformNow = 2,
+  ParameterInvalid = 3,
+  ToBeCompleted = 4,
+  AlreadyInDesiredCondition = 5,
+  ObjectDoesNotExist = 6,
 }
-multiformat_vec!{BinList, Ascii, U1}
+singleformat_enum!{HostCommandAcknowledgeCode, Bin}
 
-/// ## BLKDEF
+/// ## IDTYP
 /// 
-/// Block Definition
+/// **ID Type**
 /// 
-/// Specifies whether a command being defined starts, terminates, or is
-/// within the body of a block.
+/// Type of material identified by the accompanying [MID].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Wafer ID
+/// - 1 = Wafer Cassette ID
+/// - 2 = Film Frame ID
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S7F22
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(i8)]
-pub enum BlockDefinition {
-  /// ### TERMINATE
-  /// 
-  /// Command terminates a block body.
-  Terminate = -1,
-
-  /// ### WITHIN
-  /// 
-  /// Command neither starts or terminates a block body.
-  Within = 0,
-
-  /// ### START
-  /// 
-  /// Command starts a block body.
-  Start = 1,
+/// - [S12F1], [S12F3], [S12F4], [S12F5], [S12F7], [S12F9], [S12F11], [S12F13],
+///   [S12F14], [S12F15], [S12F16], [S12F17], [S12F18]
+/// 
+/// [MID]:    MaterialID
+/// [S12F1]:  crate::messages::s12::MapSetupDataSend
+/// [S12F3]:  crate::messages::s12::MapSetupDataRequest
+/// [S12F4]:  crate::messages::s12::MapSetupData
+/// [S12F5]:  crate::messages::s12::MapTransmitInquire
+/// [S12F7]:  crate::messages::s12::MapDataSendType1
+/// [S12F9]:  crate::messages::s12::MapDataSendType2
+/// [S12F11]: crate::messages::s12::MapDataSendType3
+/// [S12F13]: crate::messages::s12::MapDataRequestType1
+/// [S12F14]: crate::messages::s12::MapDataType1
+/// [S12F15]: crate::messages::s12::MapDataRequestType2
+/// [S12F16]: crate::messages::s12::MapDataType2
+/// [S12F17]: crate::messages::s12::MapDataRequestType3
+/// [S12F18]: crate::messages::s12::MapDataType3
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum IDType {
+  Wafer         = 0,
+  WaferCassette = 1,
+  FilmFrame     = 2,
 }
-singleformat_enum!{BlockDefinition, I1}
+singleformat_enum!{IDType, Bin}
 
-/// ## BPD
+/// ## LENGTH
 /// 
-/// Boot program data.
+/// Length of the service program or process program in bytes.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S8F2
-#[derive(Clone, Debug)]
-pub struct BootProgramData(pub Vec<u8>);
-singleformat_vec!{BootProgramData, Bin}
-
-// TODO: BYTMAX
-// How to deal with negative values being invalid even though you can use signed int?
-
-// TODO: CAACK
-// Usual about reserved/user enum values.
+/// - [S2F1]
+/// - [S7F1], [S7F29]
+/// 
+/// [S2F1]:  crate::messages::s2::ServiceProgramLoadInquire
+/// [S7F1]:  crate::messages::s7::ProcessProgramLoadInquire
+/// [S7F29]: crate::messages::s7::ProcessProgramVerificationInquire
+#[derive(Clone, Copy, Debug)]
+pub enum Length {
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{Length, I1, I2, I4, I8, U1, U2, U4, U8}
 
-/// ## CARRIERACTION
-/// 
-/// Specifies the action requested for a carrier.
+/// ## LIMITACK
 /// 
-/// -------------------------------------------------------------------------
+/// **Variable Limit Attribute Set Acknowledge Code**
 /// 
-/// #### Used By
+/// ---------------------------------------------------------------------------
 /// 
-/// - S3F17
-#[derive(Clone, Debug)]
-pub struct CarrierAction(pub Vec<Char>);
-singleformat_vec!{CarrierAction, Ascii}
-
-/// ## CARRIERID
+/// #### Format
 /// 
-/// The identifier of a carrier.
+/// Single-byte enumerated value.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S3F17, S16F11, S16F15
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct CarrierID(pub Vec<Char>);
-singleformat_vec!{CarrierID, Ascii}
-
-/// ## CARRIERSPEC
+/// - [S2F46]
 /// 
-/// The object specifier for a carrier.
+/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum VariableLimitAttributeSetAcknowledgeCode {
+  LimitIDDoesNotExist = 1,
+  UpperDeadbandGreaterThanLimitMax = 2,
+  LowerDeadbandLessThanLimitMin = 3,
+  UpperDeadbandLessThanLowerDeadband = 4,
+  IllegalFormat = 5,
+  AsciiValueNonNumeric = 6,
+  DuplicateLimitDefinition = 7,
+}
+singleformat_enum!{VariableLimitAttributeSetAcknowledgeCode, Bin}
+
+/// ## LIMITID
 /// 
-/// TODO: Make this conform to OBJSPEC requirements, seems related to E39.
+/// **Limit Identifier**
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
-/// #### Used By
+/// #### Format
 /// 
-/// - S3F29, S3F31
-#[derive(Clone, Debug)]
-pub struct CarrierSpecifier(pub Vec<Char>);
-singleformat_vec!{CarrierSpecifier, Ascii}
-
-// TODO: CATTRDATA
-// Seems like it should mirror ATTRDATA.
-
-/// ## CATTRID
+/// Identifier for a specific set of limits for a variable to which the
+/// corresponding limit attributes refer.
 /// 
-/// The name of a carrier attribute.
+/// Single-byte.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S3F17
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct CarrierAttributeID(pub Vec<Char>);
-singleformat_vec!{CarrierAttributeID, Ascii}
+/// - [S2F45], [S2F46], [S2F48]
+/// 
+/// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
+/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
+/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct LimitID(pub u8);
+singleformat!{LimitID, Bin}
 
-/// ## CCODE
+/// ## LIMITMAX
 /// 
-/// Command code.
+/// **Limit Maximum**
 /// 
-/// Each command code corresponds to a unique process operation the machine
-/// is capable of performing.
+/// ---------------------------------------------------------------------------
 /// 
-/// -------------------------------------------------------------------------
+/// The maximum allowed value for the limit values of a variable.
+/// 
+/// The format must match that of the specified variable.
+/// 
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S7F22, S7F23, S7F26, S7F31, S7F39, S7F43
+/// - [S2F48]
+/// 
+/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
 #[derive(Clone, Debug)]
-pub enum CommandCode {
+pub enum LimitMaximum {
+  Bool(Vec<bool>),
   Ascii(Vec<Char>),
+  I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
   U2(Vec<u16>),
   U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
 }
-multiformat_vec!{CommandCode, Ascii, I2, I4, U2, U4}
+multiformat_vec!{LimitMaximum, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## CEED
+/// ## LIMITMIN
 /// 
-/// Collection event or trace enable/disable code, 1 byte.
+/// **Limit Minimum**
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
-/// #### Values
+/// The minimum allowed value for the limit values of a variable.
 /// 
-/// - False = Disable
-/// - True = Enable
+/// The format must match that of the specified variable.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F37]
-/// - S17F5
+/// - [S2F48]
 /// 
-/// [S2F37]: crate::messages::s2::EnableDisableEventReport
+/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
 #[derive(Clone, Debug)]
-pub struct CollectionEventEnableDisable(pub bool);
-singleformat!{CollectionEventEnableDisable, Bool}
+pub enum LimitMinimum {
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{LimitMinimum, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## CEID
+/// ## LINKID
 /// 
-/// Collection event ID.
+/// **Link ID**
+/// 
+/// Identifier used to link a service request with its later completion.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F23], [S1F24]
-/// - [S2F35], [S2F37]
-/// - S6F3, S6F8, S6F9, S6F11, S6F13, S6F15, S6F16, S6F17, S6F18
-/// - S17F5, S17F9, S17F10, S17F11, S17F12
+/// - [S6F25]
+/// - [S14F20], [S14F21]
 /// 
-/// [S1F23]: crate::messages::s1::CollectionEventNamelistRequest
-/// [S1F24]: crate::messages::s1::CollectionEventNamelist
-/// [S2F35]: crate::messages::s2::LinkEventReport
-/// [S2F37]: crate::messages::s2::EnableDisableEventReport
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum CollectionEventID {
-  Ascii(Vec<Char>),
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
+/// [S6F25]:  crate::messages::s6::NotificationReportSend
+/// [S14F20]: crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LinkID {
   U1(u8),
   U2(u16),
   U4(u32),
   U8(u64),
 }
-multiformat_ascii!{CollectionEventID, I1, I2, I4, I8, U1, U2, U4, U8}
-
-/// ## CENAME
-/// 
-/// Collection event name.
-/// 
-/// -------------------------------------------------------------------------
-/// 
-/// #### Used By
-/// 
-/// - [S1F24]
-/// 
-/// [S1F24]: crate::messages::s1::CollectionEventNamelist
-#[derive(Clone, Debug)]
-pub struct CollectionEventName(pub Vec<Char>);
-singleformat_vec!{CollectionEventName, Ascii}
+multiformat!{LinkID, U1, U2, U4, U8}
 
-/// ## CEPACK
+/// ## LOC
 /// 
-/// **Command Enhanced Paramater Acknowledge Code**
+/// Machine material location code.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
 /// #### Format
 /// 
-/// Single-byte enumerated value.
-/// 
-/// Alternatively, if the [CEPVAL] of concern is of a list format, this item
-/// will also be of a list format.
-/// 
-/// TODO: Implement list format.
+/// 1 byte.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F50]
+/// - [S2F27]
+/// - S3F2
 /// 
-/// [CEPVAL]: CommandEnhancedParameterValue
-/// [S2F50]:  crate::messages::s2::EnhancedRemoteCommandAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum CommandEnhancedParameterAcknowledgeCode {
-  Ok = 0,
-  ParameterNameDoesNotExist = 1,
-  IllegalValue = 2,
-  IllegalFormat = 3,
-  ParameterNameNotValidAsUsed = 4,
-}
-singleformat_enum!{CommandEnhancedParameterAcknowledgeCode, U1}
+/// [S2F27]: crate::messages::s2::InitiateProcessingRequest
+#[derive(Clone, Copy, Debug)]
+pub struct LocationCode(pub u8);
+singleformat!{LocationCode, Bin}
 
-/// ## CEPVAL
+/// ## LOCID
 /// 
-/// **Command Enhanced Parameter Value**
+/// **Location ID**
 /// 
-/// ---------------------------------------------------------------------------
+/// The identifier of a material location.
 /// 
-/// #### Format
+/// -------------------------------------------------------------------------
 /// 
-/// Allowed forms:
+/// #### Used By
 /// 
-/// 1. A single non-list value.
-/// 2. A list of single items of identical format.
-/// 3. A list of items of the form of a list of two items containing another
-///    name-value pair.
+/// - [S3F29], [S3F31]
+/// 
+/// [S3F29]: crate::messages::s3::CarrierTagReadRequest
+/// [S3F31]: crate::messages::s3::CarrierTagWriteDataRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LocationID(pub Vec<Char>);
+singleformat_vec!{LocationID, Ascii}
+
+/// ## LOWERDB
+/// 
+/// **Lower Deadband**
 /// 
-/// TODO: Enforce format.
+/// Variable limit attribute which defines the lower boundary of the deadband
+/// of a limit. The value applies to a single limit for a specified variable.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F49]
+/// - [S2F45], [S2F48]
 /// 
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+/// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
+/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
 #[derive(Clone, Debug)]
-pub enum CommandEnhancedParameterValue {
-  List(Vec<Item>),
-  Bin(Vec<u8>),
+pub enum LowerDeadband {
   Bool(Vec<bool>),
   Ascii(Vec<Char>),
-  Jis8(String),
   I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
@@ -1711,595 +5618,912 @@ pub enum CommandEnhancedParameterValue {
   F4(Vec<f32>),
   F8(Vec<f64>),
 }
-multiformat_vec!{CommandEnhancedParameterValue, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{LowerDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## CKPNT
+/// ## LRACK
 /// 
-/// Checkpoint as defined by the sending system.
+/// **Link Report Acknowledge Code**
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte enumerated value.
+/// 
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S13F3, S13F6
-#[derive(Clone, Copy, Debug)]
-pub struct Checkpoint(pub u32);
-singleformat!{Checkpoint, U4}
+/// - [S2F36]
+/// 
+/// [S2F36]: crate::messages::s2::LinkEventReportAcknowledge
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum LinkReportAcknowledgeCode {
+  Ok = 0,
+  InsufficientSpace = 1,
+  InvalidFormat = 2,
+  CollectionEventLinkAlreadyDefined = 3,
+  CollectionEventDoesNotExist = 4,
+  ReportDoesNotExist = 5,
+}
+singleformat_enum!{LinkReportAcknowledgeCode, Bin}
 
-/// ## CMDA
+/// ## LVACK
 /// 
-/// Command acknowledge code.
+/// **Variable Limit Definition Acknowledge Code**
 /// 
-/// TODO: Implement Format 31.
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte enumerated value.
 /// 
-/// -------------------------------------------------------------------------
+/// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F22], [S2F28]
+/// - [S2F46]
 /// 
-/// [S2F22]: crate::messages::s2::RemoteCommandAcknowledge
-/// [S2F28]: crate::messages::s2::InitiateProcessingAcknowledge
+/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum CommandAcknowledge {
-  Ok = 0,
-  CommandDoesNotExist = 1,
-  CannotPerformNow = 2,
+pub enum VariableLimitDefinitonAcknowledgeCode {
+  VariableDoesNotExist = 1,
+  VariableHasNoLimitsCapability = 2,
+  VariableRepeatedInMessage = 3,
+  LimitValueError = 4,
 }
-singleformat_enum!{CommandAcknowledge, U1}
-
-// TODO: CMDMAX
-// How to deal with negative values being invalid even though you can use signed int?
+singleformat_enum!{VariableLimitDefinitonAcknowledgeCode, Bin}
 
-/// ## CNAME
+/// ## MAPER
 /// 
-/// Command name, maximum 16 characters.
-/// 
-/// A text string which is unique among other command names in a PCD, which
-/// describes the processing done by the equipment for the corresponding
-/// [CCODE].
+/// **Map Error**
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Used By
-/// 
-/// - S7F22
-/// 
-/// [CCODE]: CommandCode
-#[derive(Clone, Debug)]
-pub struct CommandName(Vec<Char>);
-singleformat_vec!{CommandName, Ascii, 0..=16, Char}
-
-/// ## COLCT
+/// #### Values
 /// 
-/// Column count, in die increments.
+/// - 0 = ID Not Found
+/// - 1 = Invalid Data
+/// - 2 = Format Error
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S12F1, S12F4
-#[derive(Clone, Copy, Debug)]
-pub enum ColumnCount {
-  U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
+/// - [S12F19]
+/// 
+/// [S12F19]: crate::messages::s12::MapErrorReportSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum MapError {
+  IDNotFound  = 0,
+  InvalidData = 1,
+  FormatError = 2,
 }
-multiformat!{ColumnCount, U1, U2, U4, U8}
+singleformat_enum!{MapError, Bin}
 
-/// ## COLHDR
+/// ## MAPFT
 /// 
-/// Text description of contents of [TBLELT], 1-20 characters.
+/// **Map Data Format Type**
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Used By
-/// 
-/// - S13F13, S13F15, S13F16
-/// 
-/// [TBLELT]: TableElement
-#[derive(Clone, Debug)]
-pub struct ColumnHeader(Vec<Char>);
-singleformat_vec!{ColumnHeader, Ascii, 1..=20, Char}
-
-/// ## COMMACK
+/// #### Values
 /// 
-/// Establish Communications Acknowledge Code, 1 byte.
+/// - 0 = Row Format
+/// - 1 = Array Format
+/// - 2 = Coordinate Format
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F14]
+/// - [S12F3], [S12F5]
 /// 
-/// [S1F14]: crate::messages::s1::EquipmentCRA
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+/// [S12F5]: crate::messages::s12::MapTransmitInquire
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum CommAck {
-  /// ### ACCEPTED
-  Accepted = 0,
-
-  /// ### DENIED
-  Denied = 1,
+pub enum MapFormat {
+  Row        = 0,
+  Array      = 1,
+  Coordinate = 2,
 }
-singleformat_enum!{CommAck, Bin}
+singleformat_enum!{MapFormat, Bin}
 
-/// ## COMPARISONOPERATOR
+/// ## MDACK
 /// 
-/// Choice of available operators that compare the supplied value to the
-/// current attribute value.
+/// **Map Data Acknowledge**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Map Received
+/// - 1 = Format Error
+/// - 2 = No ID Match
+/// - 3 = Abort/Discard Map
+/// - 4-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S19F1
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// - [S12F8], [S12F10], [S12F12]
+/// 
+/// [S12F8]:  crate::messages::s12::MapDataAcknowledgeType1
+/// [S12F10]: crate::messages::s12::MapDataAcknowledgeType2
+/// [S12F12]: crate::messages::s12::MapDataAcknowledgeType3
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum ComparisonOperator {
-  /// ### EQ
-  /// 
-  /// Equals, numeric or string.
-  EqualTo = 0,
-
-  /// ### NOTEQ
-  /// 
-  /// Not Equal, numeric or string.
-  NotEqualTo = 1,
-
-  /// ### LT
-  /// 
-  /// Less Than, numeric.
-  LessThan = 2,
-
-  /// ### LE
-  /// 
-  /// Less than or equal to, numeric.
-  LessThanOrEqualTo = 3,
-
-  /// ### GT
-  /// 
-  /// Greater than, numeric.
-  GreaterThan = 4,
-
-  /// ### GE
-  /// 
-  /// Greater than or equal to, numeric.
-  GreaterThanOrEqualTo = 5,
-
-  /// ### LIKE
-  /// 
-  /// Contains the substring, string.
-  Like = 6,
-
-  /// ### NOTLIKE
-  /// 
-  /// Does not contain the substring, string.
-  NotLike = 7,
+pub enum MapDataAcknowledge {
+  Received    = 0,
+  FormatError = 1,
+  NoIDMatch   = 2,
+  Abort       = 3,
 }
-singleformat_enum!{ComparisonOperator, U1}
+coded_value!{MapDataAcknowledge, Bin, 64}
 
-/// ## CONDITION
+/// ## MDLN
 /// 
-/// Provides condition information for a subsystem component.
+/// Equipment Model Type, 20 bytes max.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [CONDITIONLIST]
+/// - [S1F2], [S1F13H], [S1F13E], [S1F14H], [S1F14E]
+/// - [S7F22], [S7F23], [S7F26], [S7F31], S7F39, S7F43
 /// 
-/// [CONDITIONLIST]: ConditionList
+/// [S1F13H]: crate::messages::s1::HostCR
+/// [S1F13E]: crate::messages::s1::EquipmentCR
+/// [S1F14H]: crate::messages::s1::HostCRA
+/// [S1F14E]: crate::messages::s1::EquipmentCRA
+/// [S1F2]:   crate::messages::s1::OnLineDataEquipment
+/// [S7F22]:  crate::messages::s7::ProcessCapabilitiesData
+/// [S7F23]:  crate::messages::s7::FormattedProcessProgramSend
+/// [S7F26]:  crate::messages::s7::FormattedProcessProgramData
+/// [S7F31]:  crate::messages::s7::VerificationRequestSend
 #[derive(Clone, Debug)]
-pub struct Condition(pub Vec<Char>);
-singleformat_vec!{Condition, Ascii}
+pub struct ModelName(Vec<Char>);
+singleformat_vec!{ModelName, Ascii, 0..=20, Char}
 
-/// ## CONDITIONLIST
+/// ## MEXP
 /// 
-/// A list of [CONDITION] data sent in a fixed order.
+/// Message expected, in the form SxxFyy, 6 characters.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S18F16
+/// - [S9F13]
 /// 
-/// [CONDITION]: Condition
-pub type ConditionList = VecList<Condition>;
+/// [S9F13]: crate::messages::s9::ConversationTimeout
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MessageExpected(Vec<Char>);
+singleformat_vec!{MessageExpected, Ascii, 6..=6, Char}
 
-/// ## CPACK
+/// ## MF
 /// 
-/// Command parameter acknowledge code, 1 byte.
+/// Material format code.
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Used By
+/// #### Values
 /// 
-/// - [S2F42]
+/// - ASCII: [Units Expression] identifying the material.
+/// - Binary, 1 byte:
+///    - 1 = Wafer
+///    - 2 = Cassette
+///    - 3 = Die or Chip
+///    - 4 = Boat
+///    - 5 = Ingot
+///    - 6 = Leadframe
+///    - 7 = Lot
+///    - 8 = Magazine
+///    - 9 = Package
+///    - 10 = Plate
+///    - 11 = Tube
+///    - 12 = Waferframe
+///    - 13 = Carrier
+///    - 14 = Substrate
 /// 
-/// [S2F42]: crate::messages::s2::HostCommandAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum CommandParameterAcknowledgeCode {
-  /// CPNAME does not exist.
-  ParameterNameDoesNotExist = 1,
-
-  /// Illegal value specified for CPVAL.
-  IllegalValue = 2,
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S3F2, S3F4, S3F7, S3F9, S3F12, S3F13
+/// - S7F7, S7F11, S7F13, S7F35, S7F36
+/// - [S12F1], [S12F3], [S12F4], [S12F5], [S12F7], [S12F9], [S12F11], [S12F13],
+///   [S12F15], [S12F18], [S12F19]
+/// - [S16F11], [S16F15]
+/// 
+/// [Units Expression]: crate::units::Expression
+/// [S12F1]:            crate::messages::s12::MapSetupDataSend
+/// [S12F3]:            crate::messages::s12::MapSetupDataRequest
+/// [S12F4]:            crate::messages::s12::MapSetupData
+/// [S12F5]:            crate::messages::s12::MapTransmitInquire
+/// [S12F7]:            crate::messages::s12::MapDataSendType1
+/// [S12F9]:            crate::messages::s12::MapDataSendType2
+/// [S12F11]:           crate::messages::s12::MapDataSendType3
+/// [S12F13]:           crate::messages::s12::MapDataRequestType1
+/// [S12F15]:           crate::messages::s12::MapDataRequestType2
+/// [S12F18]:           crate::messages::s12::MapDataType3
+/// [S12F19]:           crate::messages::s12::MapErrorReportSend
+/// [S16F11]:           crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]:           crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaterialFormat {
+  Unit(crate::units::Expression),
+  Code(u8),
+}
+impl From<MaterialFormat> for Item {
+  fn from(value: MaterialFormat) -> Self {
+    match value {
+      MaterialFormat::Unit(expression) => Item::Ascii(Char::safe_str_to_chars(&expression.to_string()).into()),
+      MaterialFormat::Code(code) => Item::bin(code),
+    }
+  }
+}
+impl TryFrom<Item> for MaterialFormat {
+  type Error = Error;
 
-  /// Illegal format specified for CPVAL.
-  IllegalFormat = 3,
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::Ascii(vec) => Ok(MaterialFormat::Unit(Char::chars_to_str(&vec).parse()?)),
+      Item::Bin(vec) if vec.len() == 1 => Ok(MaterialFormat::Code(vec[0])),
+      _ => Err(WrongFormat),
+    }
+  }
 }
-singleformat_enum!{CommandParameterAcknowledgeCode, Bin}
 
-/// ## CPNAME
+/// ## MHEAD
 /// 
-/// **Command Parameter Name**
+/// SECS message block header associated with the message block in error,
+/// 10 bytes.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F41], [S2F42], [S2F49], [S2F50]
-/// - S4F21, S4F29
-/// - S16F5, S16F27
+/// - [S9F1], [S9F3], [S9F5], [S9F7], [S9F11]
 /// 
-/// [S2F41]: crate::messages::s2::HostCommandSend
-/// [S2F42]: crate::messages::s2::HostCommandAcknowledge
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
-/// [S2F50]: crate::messages::s2::EnhancedRemoteCommandAcknowledge
+/// [S9F1]:  crate::messages::s9::UnrecognizedDeviceID
+/// [S9F3]:  crate::messages::s9::UnrecognizedStreamType
+/// [S9F5]:  crate::messages::s9::UnrecognizedFunctionType
+/// [S9F7]:  crate::messages::s9::IllegalData
+/// [S9F11]: crate::messages::s9::DataTooLong
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum CommandParameterName {
-  Ascii(Vec<Char>),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
+pub struct MessageBlockHeader(Vec<u8>);
+singleformat_vec!{MessageBlockHeader, Bin, 10..=10, u8}
+impl From<[u8; 10]> for MessageBlockHeader {
+  fn from(value: [u8; 10]) -> Self {
+    Self(value.to_vec())
+  }
 }
-multiformat_vec!{CommandParameterName, Ascii, I1, I2, I4, I8, U1, U2, U4, U8}
 
-/// ## CPVAL
+/// ## MID
 /// 
-/// **Command Parameter Value**
+/// Material ID.
+/// 
+/// Maximum 80 characters or bytes, either in ASCII or binary format.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F41], [S2F49]
-/// - S4F21, S4F29
-/// - S16F5, S16F27
-/// - S18F13
-/// 
-/// [S2F41]: crate::messages::s2::HostCommandSend
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+/// - [S2F27]
+/// - S3F2, S3F4, S3F7, S3F9, [S3F12], S3F13
+/// - S4F1, S4F3, S4F5, S4F7, S4F9, S4F11, S4F13, S4F15, S4F17
+/// - S7F7, S7F8, S7F10, S7F11, S7F13, [S7F35], [S7F36]
+/// - [S12F1], [S12F3], [S12F4], [S12F5], [S12F7], [S12F9], [S12F11], [S12F13],
+///   [S12F14], [S12F15], [S12F16], [S12F17], [S12F18]
+/// - S16F3, [S16F11], [S16F15]
+/// - [S18F10], [S18F11], [S18F16]
+/// 
+/// [S2F27]:  crate::messages::s2::InitiateProcessingRequest
+/// [S3F12]:  crate::messages::s3::MaterialIDRequestAcknowledge
+/// [S7F35]:  crate::messages::s7::ProcessProgramForMaterialRequest
+/// [S7F36]:  crate::messages::s7::ProcessProgramForMaterialData
+/// [S12F1]:  crate::messages::s12::MapSetupDataSend
+/// [S12F3]:  crate::messages::s12::MapSetupDataRequest
+/// [S12F4]:  crate::messages::s12::MapSetupData
+/// [S12F5]:  crate::messages::s12::MapTransmitInquire
+/// [S12F7]:  crate::messages::s12::MapDataSendType1
+/// [S12F9]:  crate::messages::s12::MapDataSendType2
+/// [S12F11]: crate::messages::s12::MapDataSendType3
+/// [S12F13]: crate::messages::s12::MapDataRequestType1
+/// [S12F14]: crate::messages::s12::MapDataType1
+/// [S12F15]: crate::messages::s12::MapDataRequestType2
+/// [S12F16]: crate::messages::s12::MapDataType2
+/// [S12F17]: crate::messages::s12::MapDataRequestType3
+/// [S12F18]: crate::messages::s12::MapDataType3
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+/// [S18F10]: crate::messages::s18::ReadIDData
+/// [S18F11]: crate::messages::s18::WriteIDRequest
+/// [S18F16]: crate::messages::s18::Read2DCodeConditionData
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum CommandParameterValue {
-  Bin(Vec<u8>),
-  Bool(Vec<bool>),
+pub enum MaterialID {
   Ascii(Vec<Char>),
-  Jis8(String),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
+  Bin(Vec<u8>),
 }
-multiformat_vec!{CommandParameterValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8}
+impl MaterialID {
+  const MAX_LENGTH: usize = 80;
 
-impl From<CommandParameterValue> for CommandEnhancedParameterValue {
-  fn from(value: CommandParameterValue) -> Self {
+  pub fn new(vec: Vec<Char>) -> Result<Self, Error> {
+    Self::check_length(vec.len())?;
+    Ok(Self::Ascii(vec))
+  }
+  /// Creates a new instance from a string.
+  ///
+  /// NOTE: Invalid ASCII characters are replaced with '?'.
+  /// If the string exceeds the maximum length, it will be automatically truncated.
+  pub fn new_from_str(vec: &str) -> Result<Self, Error> {
+    let mut vec = Char::safe_str_to_chars(vec);
+    vec.truncate(Self::MAX_LENGTH);
+    Ok(Self::Ascii(vec))
+  }
+  pub fn new_bin(vec: Vec<u8>) -> Result<Self, Error> {
+    Self::check_length(vec.len())?;
+    Ok(Self::Bin(vec))
+  }
+  fn check_length(length: usize) -> Result<(), Error> {
+    if length <= Self::MAX_LENGTH {
+      Ok(())
+    } else {
+      Err(Error::InvalidLength {length, min: 0, max: Self::MAX_LENGTH})
+    }
+  }
+}
+impl From<MaterialID> for Item {
+  fn from(value: MaterialID) -> Item {
     match value {
-      CommandParameterValue::Bin(v) => CommandEnhancedParameterValue::Bin(v),
-      CommandParameterValue::Bool(v) => CommandEnhancedParameterValue::Bool(v),
-      CommandParameterValue::Ascii(v) => CommandEnhancedParameterValue::Ascii(v),
-      CommandParameterValue::Jis8(v) => CommandEnhancedParameterValue::Jis8(v),
-      CommandParameterValue::I1(v) => CommandEnhancedParameterValue::I1(v),
-      CommandParameterValue::I2(v) => CommandEnhancedParameterValue::I2(v),
-      CommandParameterValue::I4(v) => CommandEnhancedParameterValue::I4(v),
-      CommandParameterValue::I8(v) => CommandEnhancedParameterValue::I8(v),
-      CommandParameterValue::U1(v) => CommandEnhancedParameterValue::U1(v),
-      CommandParameterValue::U2(v) => CommandEnhancedParameterValue::U2(v),
-      CommandParameterValue::U4(v) => CommandEnhancedParameterValue::U4(v),
-      CommandParameterValue::U8(v) => CommandEnhancedParameterValue::U8(v),
+      MaterialID::Ascii(vec) => Item::Ascii(vec.into()),
+      MaterialID::Bin(vec)   => Item::Bin(vec.into()),
+    }
+  }
+}
+impl TryFrom<Item> for MaterialID {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::Ascii(vec) => Self::new(vec.into()).map_err(|_| WrongFormat),
+      Item::Bin(vec)   => Self::new_bin(vec.into()).map_err(|_| WrongFormat),
+      _ => Err(WrongFormat),
+    }
+  }
+}
+impl std::fmt::Display for MaterialID {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      MaterialID::Ascii(vec) => write!(f, "{}", Char::chars_to_str(vec)),
+      MaterialID::Bin(vec) => {
+        for byte in vec {
+          write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+      },
     }
   }
 }
 
-/// ## CSAACK
+/// ## MIDRA
 /// 
-/// Equipment acknowledge code, 1 byte.
+/// **Material ID Request Acknowledge**
+/// 
+/// Acknowledge code for a material ID request, 1 byte.
+/// 
+/// The [MID] accompanying this code is only present when the request is
+/// accepted, being zero-length otherwise.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Accepted
+/// - 1 = Invalid port number
+/// - 2 = Material ID not available
+/// - 3-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F8]
+/// - [S3F12]
 /// 
-/// [S2F8]: crate::messages::s2::ServiceProgramRunAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// [MID]:   MaterialID
+/// [S3F12]: crate::messages::s3::MaterialIDRequestAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum ServiceAcknowledgeCode {
-  Ok = 0,
-  Busy = 1,
-  InvalidSPID = 2,
-  InvalidData = 3,
+pub enum MaterialIDRequestAcknowledgeCode {
+  Accepted               = 0,
+  InvalidPortNumber      = 1,
+  MaterialIDNotAvailable = 2,
 }
-singleformat_enum!{ServiceAcknowledgeCode, Bin}
+coded_value!{MaterialIDRequestAcknowledgeCode, Bin, 64}
 
-/// ## CTLJOBCMD
+/// ## MLCL
 /// 
-/// Control Job command code.
+/// **Message Length**
+/// 
+/// Total length in bytes of the map data which will follow.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S16F27
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum ControlJobCommand {
-  /// ### CJStart
-  Start = 1,
-
-  /// ### CJPause
-  Pause = 2,
-
-  /// ### CJResume
-  Resume = 3,
-
-  /// ### CJCancel
-  Cancel = 4,
-
-  /// ### CJDeselect
-  Deselect = 5,
-
-  /// ### CJStop
-  Stop = 6,
-
-  /// ### CJAbort
-  Abort = 7,
+/// - [S12F4], [S12F5]
+/// 
+/// [S12F4]: crate::messages::s12::MapSetupData
+/// [S12F5]: crate::messages::s12::MapTransmitInquire
+#[derive(Clone, Copy, Debug)]
+pub enum MessageLength {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{MessageLength, U1, U2, U4, U8}
+impl From<usize> for MessageLength {
+  /// Uses the smallest unsigned format able to hold the length.
+  fn from(length: usize) -> Self {
+    let length = length as u64;
+    if let Ok(length) = u8::try_from(length) {
+      MessageLength::U1(length)
+    } else if let Ok(length) = u16::try_from(length) {
+      MessageLength::U2(length)
+    } else if let Ok(length) = u32::try_from(length) {
+      MessageLength::U4(length)
+    } else {
+      MessageLength::U8(length)
+    }
+  }
+}
 
-  /// ### CJHOQ
-  HeadOfQueue = 8,
+/// ## NACDS
+/// 
+/// **Not After Command Codes**
+/// 
+/// Vector of all command codes which the defined command may not succeed
+/// within the same block.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum NotAfterCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
 }
-singleformat_enum!{ControlJobCommand, U1}
+multiformat_vec!{NotAfterCommandCodes, I2, U2}
 
-// TODO: CTLJOBID
-// Something about OBJID.
+/// ## NBCDS
+/// 
+/// **Not Before Command Codes**
+/// 
+/// Vector of all command codes which the defined command may not precede
+/// within the same block.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub enum NotBeforeCommandCodes {
+  I2(Vec<i16>),
+  U2(Vec<u16>),
+}
+multiformat_vec!{NotBeforeCommandCodes, I2, U2}
 
-/// ## DATA
+/// ## NULBC
 /// 
-/// A string of unformatted data.
+/// Null bin code value.
+/// 
+/// Used to indicate no die at a location.
+/// 
+/// Must be the same format as [BCEQU] and [BINLT].
+/// 
+/// Zero length indicates not used.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S3F30, S3F31
-/// - S18F6, S18F7
+/// - [S12F1], [S12F3], [S12F4]
+/// 
+/// [BCEQU]: BinCodeEquivalents
+/// [BINLT]: BinList
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+/// [S12F4]: crate::messages::s12::MapSetupData
 #[derive(Clone, Debug)]
-pub struct Data(pub Vec<Char>);
-singleformat_vec!{Data, Ascii}
+pub enum NullBinCode {
+  Ascii(Vec<Char>),
+  U1(Vec<u8>),
+}
+multiformat_vec!{NullBinCode, Ascii, U1}
 
-/// ## DATAACK
+/// ## OBJACK
 /// 
-/// Data acknowledge code.
+/// **Object Acknowledge**
+/// 
+/// Indicates success or failure of an object service, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Successful Completion
+/// - 1 = Error
+/// - 2-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S14F22
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// - [S14F2], [S14F4], [S14F6], [S14F8], [S14F10], [S14F12], [S14F14],
+///   [S14F16], [S14F18], [S14F20], [S14F21], [S14F26], [S14F28]
+/// 
+/// [S14F2]:  crate::messages::s14::GetAttributeData
+/// [S14F4]:  crate::messages::s14::SetAttributeData
+/// [S14F6]:  crate::messages::s14::GetTypeData
+/// [S14F8]:  crate::messages::s14::GetAttributeNameData
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S14F12]: crate::messages::s14::DeleteObjectAcknowledge
+/// [S14F14]: crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F16]: crate::messages::s14::AttachedObjectActionAcknowledge
+/// [S14F18]: crate::messages::s14::SupervisedObjectActionAcknowledge
+/// [S14F20]: crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+/// [S14F26]: crate::messages::s14::ServiceNameData
+/// [S14F28]: crate::messages::s14::ServiceParameterData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum DataAcknowledge {
-  Ok = 0,
-  UnknownDataID = 1,
-  InvalidParameter = 2,
+pub enum ObjectAcknowledge {
+  Success = 0,
+  Error   = 1,
+}
+coded_value!{ObjectAcknowledge, U1, 64}
+impl CodedValue<ObjectAcknowledge> {
+  /// ### IS SUCCESS
+  pub fn is_success(&self) -> bool {
+    self.is(ObjectAcknowledge::Success)
+  }
 }
-singleformat_enum!{DataAcknowledge, Bin}
 
-/// ## DATAID
+/// ## OBJCMD
 /// 
-/// **Data ID**
+/// **Object Command**
 /// 
-/// ---------------------------------------------------------------------------
+/// Specifies an action to be performed by an object, 1 byte.
+/// 
+/// The meaning of each value is defined by the type of the object.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F33], [S2F35], [S2F39], [S2F45], [S2F49]
-/// - S3F15, S3F17
-/// - S4F19, S4F25
-/// - S6F3, S6F5, S6F7, S6F8, S6F9, S6F11, S6F13, S6F16, S6F18, S6F25, S6F27
-/// - S13F11, S13F13, S13F15
-/// - S14F19, S14F21, S14F23
-/// - S15F1, S15F13, S15F15, S15F21, S15F23, S15F25, S15F27, S15F29, S15F33,
-///   S15F35, S15F39, S15F41, S15F43, S15F45, S15F47, S15F49
-/// - S16F1, S16F3, S16F5, S16F11, S16F15
-/// - S17F1, S17F5, S17F9
+/// - [S14F15], [S14F17]
 /// 
-/// [S2F33]: crate::messages::s2::DefineReport
-/// [S2F35]: crate::messages::s2::LinkEventReport
-/// [S2F39]: crate::messages::s2::MultiBlockInquire
-/// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+/// [S14F15]: crate::messages::s14::AttachedObjectActionRequest
+/// [S14F17]: crate::messages::s14::SupervisedObjectActionRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectCommand(pub u8);
+singleformat!{ObjectCommand, U1}
+
+/// ## OBJID
+/// 
+/// Identifier for an object.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F19]
+/// - [S14F1], [S14F2], [S14F3], [S14F4]
+/// 
+/// [S1F19]: crate::messages::s1::GetAttribute
+/// [S14F1]: crate::messages::s14::GetAttributeRequest
+/// [S14F2]: crate::messages::s14::GetAttributeData
+/// [S14F3]: crate::messages::s14::SetAttributeRequest
+/// [S14F4]: crate::messages::s14::SetAttributeData
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum DataID {
+pub enum ObjectID {
   Ascii(Vec<Char>),
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
   U1(u8),
   U2(u16),
   U4(u32),
   U8(u64),
 }
-multiformat_ascii!{DataID, I1, I2, I4, I8, U1, U2, U4, U8}
+multiformat_ascii!{ObjectID, U1, U2, U4, U8}
 
-/// ## DATALENGTH
+/// ## OBJSPEC
 /// 
-/// Total bytes to be sent.
+/// **Object Specifier**
 /// 
-/// TODO: Do negative numbers need to be restricted?
+/// Text string that has an internal format and that is used to point to a
+/// specific object instance.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// The string is formed out of a sequence of formatted substrings, each
+/// specifying an object's type, and name. The substring format has the
+/// following four parts:
+/// 
+/// 1. Object Type
+/// 2. Colon Character ':'
+/// 3. Object Name
+/// 4. Greater-Than Symbol '>'
+/// 
+/// The Object Type may be ommitted (along with the Colon Character), if it is
+/// not necessary to uniquely identify an object. The final Greater-Than Symbol
+/// is optional.
+/// 
+/// TODO: Implement format restrictions.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F49]
+/// - [S13F11], [S13F13], [S13F15]
+/// - [S14F1], [S14F3], [S14F5], [S14F7], [S14F9], [S14F10], [S14F11], [S14F13],
+///   [S14F15], [S14F17], [S14F19], [S14F25], [S14F27]
+/// - S15F7, S15F23, S15F43, S15F47
+/// 
+/// [S2F49]:  crate::messages::s2::EnhancedRemoteCommand
+/// [S13F11]: crate::messages::s13::DataSetObjectMultiBlockInquire
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+/// [S14F1]:  crate::messages::s14::GetAttributeRequest
+/// [S14F3]:  crate::messages::s14::SetAttributeRequest
+/// [S14F5]:  crate::messages::s14::GetTypeRequest
+/// [S14F7]:  crate::messages::s14::GetAttributeNameRequest
+/// [S14F9]:  crate::messages::s14::CreateObjectRequest
+/// [S14F10]: crate::messages::s14::CreateObjectAcknowledge
+/// [S14F11]: crate::messages::s14::DeleteObjectRequest
+/// [S14F13]: crate::messages::s14::ObjectAttachRequest
+/// [S14F15]: crate::messages::s14::AttachedObjectActionRequest
+/// [S14F17]: crate::messages::s14::SupervisedObjectActionRequest
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F25]: crate::messages::s14::ServiceNameRequest
+/// [S14F27]: crate::messages::s14::ServiceParameterRequest
+#[derive(Clone, Debug)]
+pub struct ObjectSpecifier(pub Vec<Char>);
+singleformat_vec!{ObjectSpecifier, Ascii}
+
+/// ## OBJTOKEN
+/// 
+/// **Object Token**
+/// 
+/// Token used for authorization when acting upon an attached object.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F39]
-/// - S3F15, S3F29, S3F31
-/// - S4F25
-/// - S6F5
-/// - S13F11
-/// - S14F23
-/// - S16F1
-/// - S18F5, S18F7
-/// - S19F19
+/// - [S14F14], [S14F15]
+/// 
+/// [S14F14]: crate::messages::s14::ObjectAttachAcknowledge
+/// [S14F15]: crate::messages::s14::AttachedObjectActionRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectToken(pub u32);
+singleformat!{ObjectToken, U4}
+
+/// ## OBJTYPE
+/// 
+/// An identifier for a class of objects.
+/// 
+/// All objects of the same type must have the same set of attributes.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
 /// 
-/// [S2F39]: crate::messages::s2::MultiBlockInquire
+/// - [S1F19]
+/// - [S14F1], [S14F3], [S14F6], [S14F7], [S14F8], [S14F9], [S14F25], [S14F26],
+///   [S14F27]
+/// 
+/// [S1F19]:  crate::messages::s1::GetAttribute
+/// [S14F1]:  crate::messages::s14::GetAttributeRequest
+/// [S14F3]:  crate::messages::s14::SetAttributeRequest
+/// [S14F6]:  crate::messages::s14::GetTypeData
+/// [S14F7]:  crate::messages::s14::GetAttributeNameRequest
+/// [S14F8]:  crate::messages::s14::GetAttributeNameData
+/// [S14F9]:  crate::messages::s14::CreateObjectRequest
+/// [S14F25]: crate::messages::s14::ServiceNameRequest
+/// [S14F26]: crate::messages::s14::ServiceNameData
+/// [S14F27]: crate::messages::s14::ServiceParameterRequest
 #[derive(Clone, Debug)]
-pub enum DataLength {
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
+pub enum ObjectType {
+  Ascii(Vec<Char>),
   U1(u8),
   U2(u16),
   U4(u32),
   U8(u64),
 }
-multiformat!{DataLength, I1, I2, I4, I8, U1, U2, U4, U8}
+multiformat_ascii!{ObjectType, U1, U2, U4, U8}
 
-/// ## DRACK
+/// ## OFLACK
 /// 
-/// **Define Report Acknowledge Code**
+/// Acknowledge code for OFF-LINE request.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
-/// #### Format
+/// #### Used By
 /// 
-/// Single byte enumerated value.
+/// - [S1F16]
 /// 
-/// ---------------------------------------------------------------------------
+/// [S1F16]: crate::messages::s1::OffLineAck
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum OffLineAcknowledge {
+  Acknowledge = 0,
+}
+singleformat_enum!{OffLineAcknowledge, Bin}
+
+/// ## ONLACK
+/// 
+/// Acknowledge code for ON-LINE request.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F34]
+/// - [S1F18]
 /// 
-/// [S2F34]: crate::messages::s2::DefineReportAcknowledge
+/// [S1F18]: crate::messages::s1::OnLineAck
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum DefineReportAcknowledgeCode {
-  Ok = 0,
-  InsufficientSpace = 1,
-  InvalidFormat = 2,
-  ReportAlreadyDefined = 3,
-  VariableDoesNotExist = 4,
+pub enum OnLineAcknowledge {
+  Accepted      = 0,
+  NotAllowed    = 1,
+  AlreadyOnLine = 2,
 }
-singleformat_enum!{DefineReportAcknowledgeCode, Bin}
+singleformat_enum!{OnLineAcknowledge, Bin}
 
-/// ## DSPER
+/// ## OPID
 /// 
-/// Data sample period.
+/// **Operation ID**
 /// 
-/// TODO: Implement format restrictions.
+/// Identifier of a requested operation, unique to the requester.
 /// 
 /// -------------------------------------------------------------------------
 /// 
-/// #### Values
+/// #### Used By
 /// 
-/// Format 1:
-/// - hhmmss
-///    - hh = Hours
-///    - mm = Minutes
-///    - ss = Seconds
+/// - [S6F25]
+/// - [S14F19], [S14F21]
 /// 
-/// Format 2:
-/// - hhmmsscc
-///    - hh = Hours
-///    - mm = Minutes
-///    - ss = Seconds
-///    - cc = CentiSeconds
+/// [S6F25]:  crate::messages::s6::NotificationReportSend
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperationID {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{OperationID, U1, U2, U4, U8}
+
+/// ## ORLOC
 /// 
-/// Equipment must implement Format 1, and may optionally implement Format 2.
+/// **Origin Location**
 /// 
-/// Support for Format 2 does not necessitate a trace resolution of 0.01sec.
+/// Location of the origin of the map's coordinate system.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Center Die of Wafer
+/// - 1 = Upper Right
+/// - 2 = Upper Left
+/// - 3 = Lower Left
+/// - 4 = Lower Right
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F23]
+/// - [S12F1], [S12F3], [S12F4]
 /// 
-/// [S2F23]: crate::messages::s2::TraceInitializeSend
-#[derive(Clone, Debug)]
-pub struct DataSamplePeriod(pub Vec<Char>);
-singleformat_vec!{DataSamplePeriod, Ascii}
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum OriginLocation {
+  Center     = 0,
+  UpperRight = 1,
+  UpperLeft  = 2,
+  LowerLeft  = 3,
+  LowerRight = 4,
+}
+singleformat_enum!{OriginLocation, Bin}
 
-/// ## DVVALNAME
+/// ## PDFLT
 /// 
-/// Descriptive name for a data variable.
+/// **Parameter Default**
+/// 
+/// Default value of a parameter of a command, used where the parameter is
+/// not required and is omitted from a formatted process program.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F22]
+/// - [S7F22]
 /// 
-/// [S1F22]: crate::messages::s1::DataVariableNamelist
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
 #[derive(Clone, Debug)]
-pub struct DataVariableValueName(pub Vec<Char>);
-singleformat_vec!{DataVariableValueName, Ascii}
+pub enum ParameterDefault {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{ParameterDefault, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## EAC
+/// ## PFCD
 /// 
-/// EquipmentAcknowledgeCode, 1 byte.
+/// **Predefined Form Code**
 /// 
-/// ---------------------------------------------------------------------------
+/// Identifies a form of data set agreed upon in advance, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F16]
+/// - [S6F9]
 /// 
-/// [S2F16]: crate::messages::s2::NewEquipmentConstantAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum EquipmentAcknowledgeCode {
-  Acknowledge = 0,
-  DoesNotExist = 1,
-  Busy = 2,
-  OutOfRange = 3,
-}
-singleformat_enum!{EquipmentAcknowledgeCode, Bin}
+/// [S6F9]: crate::messages::s6::FormattedVariableSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PredefinedFormCode(pub u8);
+singleformat!{PredefinedFormCode, Bin}
 
-/// ## ECDEF
+/// ## PMAX
 /// 
-/// Equipment constant default value.
+/// **Parameter Maximum**
 /// 
-/// ---------------------------------------------------------------------------
+/// Maximum value of a numeric parameter of a command, or the maximum length
+/// of a parameter of any other format.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F30]
+/// - [S7F22]
 /// 
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
 #[derive(Clone, Debug)]
-pub enum EquipmentConstantDefaultValue {
-  Bin(Vec<u8>),
-  Bool(Vec<bool>),
-  Ascii(Vec<Char>),
-  Jis8(String),
+pub enum ParameterMaximum {
   I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
@@ -2311,85 +6535,62 @@ pub enum EquipmentConstantDefaultValue {
   F4(Vec<f32>),
   F8(Vec<f64>),
 }
-multiformat_vec!{EquipmentConstantDefaultValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{ParameterMaximum, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## ECID
+/// ## PNAME
 /// 
-/// Equipment constant ID.
+/// **Parameter Name**
 /// 
-/// ---------------------------------------------------------------------------
+/// Name of a parameter of a command, unique among the parameters of that
+/// command.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F13], [S2F15], [S2F29], [S2F30]
+/// - [S7F22]
 /// 
-/// [S2F13]: crate::messages::s2::EquipmentConstantRequest
-/// [S2F15]: crate::messages::s2::NewEquipmentConstantSend
-/// [S2F29]: crate::messages::s2::EquipmentConstantNamelistRequest
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum EquipmentConstantID {
-  Ascii(Vec<Char>),
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
-  U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
-}
-multiformat_ascii!{EquipmentConstantID, I1, I2, I4, I8, U1, U2, U4, U8}
-
-impl From<EquipmentConstantID> for VariableID {
-  fn from(ec_id: EquipmentConstantID) -> Self {
-    match ec_id {
-      EquipmentConstantID::Ascii(v) => VariableID::Ascii(v),
-      EquipmentConstantID::I1(v) => VariableID::I1(v),
-      EquipmentConstantID::I2(v) => VariableID::I2(v),
-      EquipmentConstantID::I4(v) => VariableID::I4(v),
-      EquipmentConstantID::I8(v) => VariableID::I8(v),
-      EquipmentConstantID::U1(v) => VariableID::U1(v),
-      EquipmentConstantID::U2(v) => VariableID::U2(v),
-      EquipmentConstantID::U4(v) => VariableID::U4(v),
-      EquipmentConstantID::U8(v) => VariableID::U8(v),
-    }
-  }
-}
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub struct ParameterName(pub Vec<Char>);
+singleformat_vec!{ParameterName, Ascii}
 
-impl From<VariableID> for EquipmentConstantID {
-  fn from(var_id: VariableID) -> Self {
-    match var_id {
-      VariableID::Ascii(v) => EquipmentConstantID::Ascii(v),
-      VariableID::I1(v) => EquipmentConstantID::I1(v),
-      VariableID::I2(v) => EquipmentConstantID::I2(v),
-      VariableID::I4(v) => EquipmentConstantID::I4(v),
-      VariableID::I8(v) => EquipmentConstantID::I8(v),
-      VariableID::U1(v) => EquipmentConstantID::U1(v),
-      VariableID::U2(v) => EquipmentConstantID::U2(v),
-      VariableID::U4(v) => EquipmentConstantID::U4(v),
-      VariableID::U8(v) => EquipmentConstantID::U8(v),
-    }
-  }
-}
+/// ## PNDESC
+/// 
+/// **Parameter Description**
+/// 
+/// Description of a parameter of a command.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Debug)]
+pub struct ParameterDescription(pub Vec<Char>);
+singleformat_vec!{ParameterDescription, Ascii}
 
-/// ## ECMAX
+/// ## PPARM
 /// 
-/// Equipment constant maximum value.
+/// **Process Parameter**
 /// 
-/// ---------------------------------------------------------------------------
+/// Parameter of a command within a formatted process program.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F30]
+/// - [S7F23], [S7F26]
 /// 
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+/// [S7F23]: crate::messages::s7::FormattedProcessProgramSend
+/// [S7F26]: crate::messages::s7::FormattedProcessProgramData
 #[derive(Clone, Debug)]
-pub enum EquipmentConstantMaximumValue {
+pub enum ProcessParameter {
   Bin(Vec<u8>),
   Bool(Vec<bool>),
   Ascii(Vec<Char>),
-  Jis8(String),
   I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
@@ -2401,25 +6602,28 @@ pub enum EquipmentConstantMaximumValue {
   F4(Vec<f32>),
   F8(Vec<f64>),
 }
-multiformat_vec!{EquipmentConstantMaximumValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{ProcessParameter, Bin, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## ECMIN
+/// ## PPBODY
 /// 
-/// Equipment constant minimum value.
+/// **Process Program Body**
 /// 
-/// ---------------------------------------------------------------------------
+/// Contents of an unformatted process program.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F30]
+/// - [S7F3], [S7F6], [S7F31], [S7F36]
 /// 
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
+/// [S7F3]:  crate::messages::s7::ProcessProgramSend
+/// [S7F6]:  crate::messages::s7::ProcessProgramData
+/// [S7F31]: crate::messages::s7::VerificationRequestSend
+/// [S7F36]: crate::messages::s7::ProcessProgramForMaterialData
 #[derive(Clone, Debug)]
-pub enum EquipmentConstantMinimumValue {
+pub enum ProcessProgramBody {
   Bin(Vec<u8>),
-  Bool(Vec<bool>),
   Ascii(Vec<Char>),
-  Jis8(String),
   I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
@@ -2428,444 +6632,499 @@ pub enum EquipmentConstantMinimumValue {
   U2(Vec<u16>),
   U4(Vec<u32>),
   U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
 }
-multiformat_vec!{EquipmentConstantMinimumValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{ProcessProgramBody, Bin, Ascii, I1, I2, I4, I8, U1, U2, U4, U8}
+
+/// ## PPID
+/// 
+/// Process Program ID
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Maximum 120 bytes.
+/// 
+/// Format is host dependent. For the internal use of the equipment, it can be
+/// treated as a unique binary pattern. If the equipment is not prepared to
+/// display the transmitted code, it should be displayed in hexadecimal.
+/// 
+/// TODO: Implement format 10.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S2F27]
+/// - [S7F1], [S7F3], [S7F5], [S7F6], S7F8, S7F10, S7F11, S7F13, [S7F17],
+///   [S7F20], [S7F23], [S7F25], [S7F26], [S7F27], [S7F31], [S7F33], [S7F34],
+///   [S7F36], S7F39, S7F43
+/// 
+/// [S2F27]: crate::messages::s2::InitiateProcessingRequest
+/// [S7F1]:  crate::messages::s7::ProcessProgramLoadInquire
+/// [S7F3]:  crate::messages::s7::ProcessProgramSend
+/// [S7F5]:  crate::messages::s7::ProcessProgramRequest
+/// [S7F6]:  crate::messages::s7::ProcessProgramData
+/// [S7F17]: crate::messages::s7::DeleteProcessProgramSend
+/// [S7F20]: crate::messages::s7::CurrentProcessProgramDirectoryData
+/// [S7F23]: crate::messages::s7::FormattedProcessProgramSend
+/// [S7F25]: crate::messages::s7::FormattedProcessProgramRequest
+/// [S7F26]: crate::messages::s7::FormattedProcessProgramData
+/// [S7F27]: crate::messages::s7::ProcessProgramVerificationSend
+/// [S7F31]: crate::messages::s7::VerificationRequestSend
+/// [S7F33]: crate::messages::s7::ProcessProgramAvailableRequest
+/// [S7F34]: crate::messages::s7::ProcessProgramAvailabilityData
+/// [S7F36]: crate::messages::s7::ProcessProgramForMaterialData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ProcessProgramID(Vec<Char>);
+singleformat_vec!{ProcessProgramID, Ascii, 0..=120, Char}
+
+/// ## PPGNT
+/// 
+/// **Process Program Grant**
+/// 
+/// Permission to send a process program, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = OK
+/// - 1 = Already Have
+/// - 2 = No Space
+/// - 3 = Invalid PPID
+/// - 4 = Busy, Try Later
+/// - 5 = Will Not Accept
+/// - 6-63 = Reserved, Other Error
+/// - 64-255 = User Defined, Other Error
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F2], [S7F30]
+/// 
+/// [S7F2]:  crate::messages::s7::ProcessProgramLoadGrant
+/// [S7F30]: crate::messages::s7::ProcessProgramVerificationGrant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ProcessProgramGrant {
+  Ok            = 0,
+  AlreadyHave   = 1,
+  NoSpace       = 2,
+  InvalidPPID   = 3,
+  Busy          = 4,
+  WillNotAccept = 5,
+}
+coded_value!{ProcessProgramGrant, Bin, 64}
+
+/// ## PRAXI
+/// 
+/// **Process Axis**
+/// 
+/// Order in which the die are processed.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Rows, Top, Increasing
+/// - 1 = Rows, Top, Decreasing
+/// - 2 = Rows, Bottom, Increasing
+/// - 3 = Rows, Bottom, Decreasing
+/// - 4 = Columns, Left, Increasing
+/// - 5 = Columns, Left, Decreasing
+/// - 6 = Columns, Right, Increasing
+/// - 7 = Columns, Right, Decreasing
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F3]
+/// 
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F3]: crate::messages::s12::MapSetupDataRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ProcessAxis {
+  RowsTopIncreasing      = 0,
+  RowsTopDecreasing      = 1,
+  RowsBottomIncreasing   = 2,
+  RowsBottomDecreasing   = 3,
+  ColumnsLeftIncreasing  = 4,
+  ColumnsLeftDecreasing  = 5,
+  ColumnsRightIncreasing = 6,
+  ColumnsRightDecreasing = 7,
+}
+singleformat_enum!{ProcessAxis, Bin}
+
+/// ## PRCMDNAME
+/// 
+/// **Process Job Command Name**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - "START"
+/// - "STOP"
+/// - "PAUSE"
+/// - "RESUME"
+/// - "ABORT"
+/// - "CANCEL"
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S16F5]
+/// 
+/// [S16F5]: crate::messages::s16::ProcessJobCommand
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ProcessJobCommandName(pub Vec<Char>);
+singleformat_vec!{ProcessJobCommandName, Ascii}
+
+/// ## PRDCT
+/// 
+/// **Process Die Count**
+/// 
+/// Number of die to be processed.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F4]
+/// 
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug)]
+pub enum ProcessDieCount {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{ProcessDieCount, U1, U2, U4, U8}
 
-/// ## ECNAME
+/// ## PRJOBID
 /// 
-/// Equipment constant name.
+/// **Process Job ID**
 /// 
-/// ---------------------------------------------------------------------------
+/// Identifier of a process job, conforming to [OBJID].
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F30]
+/// - [S16F5], [S16F6], [S16F7], S16F9, [S16F11], [S16F12], S16F13, [S16F15],
+///   [S16F16], [S16F17], [S16F18], [S16F20], [S16F21], [S16F25], [S16F26]
+/// 
+/// [OBJID]:  ObjectID
+/// [S16F5]:  crate::messages::s16::ProcessJobCommand
+/// [S16F6]:  crate::messages::s16::ProcessJobCommandAcknowledge
+/// [S16F7]:  crate::messages::s16::ProcessJobAlertNotify
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F12]: crate::messages::s16::ProcessJobCreateEnhancedAcknowledge
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+/// [S16F16]: crate::messages::s16::ProcessJobMultiCreateAcknowledge
+/// [S16F17]: crate::messages::s16::ProcessJobDequeue
+/// [S16F18]: crate::messages::s16::ProcessJobDequeueAcknowledge
+/// [S16F20]: crate::messages::s16::ProcessJobGetAllJobsSend
+/// [S16F21]: crate::messages::s16::ProcessJobGetSpace
+/// [S16F25]: crate::messages::s16::ProcessJobSetStartMethod
+/// [S16F26]: crate::messages::s16::ProcessJobSetStartMethodAcknowledge
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ProcessJobID(pub Vec<Char>);
+singleformat_vec!{ProcessJobID, Ascii}
+
+/// ## PRJOBMILESTONE
 /// 
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
-#[derive(Clone, Debug)]
-pub struct EquipmentConstantName(pub Vec<Char>);
-singleformat_vec!{EquipmentConstantName, Ascii}
+/// **Process Job Milestone**
+/// 
+/// Milestone reached by a process job, reported in a process job alert.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S16F7]
+/// 
+/// [S16F7]: crate::messages::s16::ProcessJobAlertNotify
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessJobMilestone(pub u8);
+singleformat!{ProcessJobMilestone, U1}
 
-/// ## ECV
+/// ## PRJOBSPACE
 /// 
-/// Equipment constant value.
+/// **Process Job Space**
 /// 
-/// ---------------------------------------------------------------------------
+/// Number of process jobs which may yet be created.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F14], [S2F15]
+/// - [S16F22]
 /// 
-/// [S2F14]: crate::messages::s2::EquipmentConstantData
-/// [S2F15]: crate::messages::s2::NewEquipmentConstantSend
-#[derive(Clone, Debug)]
-pub enum EquipmentConstantValue {
-  Bin(Vec<u8>),
-  Bool(Vec<bool>),
-  Ascii(Vec<Char>),
-  Jis8(String),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
-}
-multiformat_vec!{EquipmentConstantValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+/// [S16F22]: crate::messages::s16::ProcessJobGetSpaceSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessJobSpace(pub u16);
+singleformat!{ProcessJobSpace, U2}
 
-/// ## ERRCODE
+/// ## ProcessJobMaterial
 /// 
-/// Code identifying an error.
+/// Material to be processed by a process job.
 /// 
-/// TODO: Implement user defined errors.
+/// The form used depends on the accompanying [MF]: a list of carriers with
+/// the slots of each carrier to process if material is specified by
+/// carrier, otherwise a list of [MID]s.
 /// 
-/// ---------------------------------------------------------------------------
+/// An empty list is always read as an empty list of carriers.
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F20]
-/// - S3F18, S3F20, S3F22, S3F24, S3F26, S3F28, S3F30, S3F32,
-///   S3F34, S3F36
-/// - S4F20, S4F22, S4F23, S4F31, S4F33
-/// - S5F14, S5F15, S5F18
-/// - S6F25, S6F30
-/// - S13F14, S13F16
-/// - S14F2, S14F4, S14F5, S14F6, S14F8, S14F10, S14F12,
-///   S14F14, S14F16, S14F18, S14F20, S14F21, S14F26, S14F28
-/// - S15F4, S15F6, S15F8, S15F10, S15F12, S15F14, S15F16,
-///   S15F18, S15F20, S15F22, S15F24, S15F26, S15F28, S15F30,
-///   S15F32, S15F34, S15F36, S15F38, S15F40, S15F42, S15F44,
-///   S15F48, S15F53
-/// - S16F4, S16F6, S16F7, S16F12, S16F16, S16F18, S16F24,
-///   S16F26, S16F28
-/// - S17F2, S17F4, S17F6, S17F8, S17F10, S17F12, S17F14
-/// 
-/// [S1F20]: crate::messages::s1::AttributeData
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u64)]
-pub enum ErrorCode {
-  NoError                         = 0,
-  UnknownObjectInObjectSpecifier  = 1,
-  UnknownTargetObjectType         = 2,
-  UnknownObjectInstance           = 3,
-  UnknownAttributeName            = 4,
-  ReadonlyAttributeAccessDenied   = 5,
-  UnknownObjectType               = 6,
-  InvalidAttributeValue           = 7,
-  SyntaxError                     = 8,
-  VerificationError               = 9,
-  ValidationError                 = 10,
-  ObjectIdentifierInUse           = 11,
-  ParametersImproperlySpecified   = 12,
-  InsufficientParametersSpecified = 13,
-  UnsupportedOptionRequested      = 14,
-  Busy                            = 15,
-  NotAvailableForProcessing       = 16,
-  CommandNotValidForCurrentState  = 17,
-  NoMaterialAltered               = 18,
-  MaterialPartiallyProcessed      = 19,
-  AllMaterialProcessed            = 20,
-  RecipeSpecificationError        = 21,
-  FailedDuringProcessing          = 22,
-  FailedWhileNotProcessing        = 23,
-  FailedDueToLackOfMaterial       = 24,
-  JobAborted                      = 25,
-  JobStopped                      = 26,
-  JobCancelled                    = 27,
-  CannotChangeSelectedRecipe      = 28,
-  UnknownEvent                    = 29,
-  DuplicateReportID               = 30,
-  UnknownDataReport               = 31,
-  DataReportNotLinked             = 32,
-  UnknownTraceReport              = 33,
-  DuplicateTraceID                = 34,
-  TooManyDataReports              = 35,
-  SamplePeriodOutOfRange          = 36,
-  GroupSizeTooLarge               = 37,
-  RecoveryActionCurrentlyInvalid  = 38,
-  BusyWithAnotherRecovery         = 39,
-  NoActiveRecoveryAction          = 40,
-  ExceptionRecoveryFailed         = 41,
-  ExceptionRecoveryAborted        = 42,
-  InvalidTableElement             = 43,
-  UnknownTableElement             = 44,
-  CannotDeletePredefined          = 45,
-  InvalidToken                    = 46,
-  InvalidParameter                = 47,
-  LoadPortDoesNotExist            = 48,
-  LoadPortAlreadyInUse            = 49,
-  MissingCarrier                  = 50,
-  //51-63: Reserved
-  //64-32767: User Defined
-  ActionWillBePerformed           = 32768,
-  ActionCannotBePerformedNow      = 32769,
-  ActionFailedDueToErrors         = 32770,
-  InvalidCommand                  = 32771,
-  ClientAlr                       = 32772,
-  DuplicateClientID               = 32773,
-  InvalidClientType               = 32774,
-  IncompatibleVersions            = 32775,
-  UnrecognizedClientID            = 32776,
-  FailedCompletedUnsuccessfully   = 32777,
-  FailedUnsafe                    = 32778,
-  SensorDetectedObstacle          = 32779,
-  MaterialNotSent                 = 32780,
-  MaterialNotReceived             = 32781,
-  MaterialLost                    = 32782,
-  HardwareFailure                 = 32783,
-  TransferCancelled               = 32784,
-  //32785-32789: Reserved for SEMI E127
-  //32793-65335: Reserved
-  //65536+: User Defined
+/// - [S16F11], [S16F15]
+/// 
+/// [MF]:     MaterialFormat
+/// [MID]:    MaterialID
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Debug)]
+pub enum ProcessJobMaterial {
+  Carriers(VecList<(CarrierID, VecList<SlotID>)>),
+  Materials(VecList<MaterialID>),
 }
-impl From<ErrorCode> for Item {
-  fn from(value: ErrorCode) -> Self {
-    let number: u64 = value.into();
-    if number < 256 {
-      Item::U1(vec![number as u8])
-    } else if number < 65536 {
-      Item::U2(vec![number as u16])
-    } else {
-      Item::U8(vec![number])
+
+impl From<ProcessJobMaterial> for Item {
+  fn from(value: ProcessJobMaterial) -> Self {
+    match value {
+      ProcessJobMaterial::Carriers(carriers) => carriers.into(),
+      ProcessJobMaterial::Materials(materials) => materials.into(),
     }
   }
 }
-impl TryFrom<Item> for ErrorCode {
+
+impl TryFrom<Item> for ProcessJobMaterial {
   type Error = Error;
 
-  fn try_from(value: Item) -> Result<Self, Self::Error> {
-    match value {
-      Item::U1(vec) => {
-        if vec.len() == 1 {
-          ErrorCode::try_from(vec[0] as u64).map_err(|_| -> Self::Error {WrongFormat})
-        } else {
-          Err(WrongFormat)
-        }
-      },
-      Item::U2(vec) => {
-        if vec.len() == 1 {
-          ErrorCode::try_from(vec[0] as u64).map_err(|_| -> Self::Error {WrongFormat})
-        } else {
-          Err(WrongFormat)
-        }
-      },
-      Item::U4(vec) => {
-        if vec.len() == 1 {
-          ErrorCode::try_from(vec[0] as u64).map_err(|_| -> Self::Error {WrongFormat})
-        } else {
-          Err(WrongFormat)
-        }
-      },
-      Item::U8(vec) => {
-        if vec.len() == 1 {
-          ErrorCode::try_from(vec[0]).map_err(|_| -> Self::Error {WrongFormat})
-        } else {
-          Err(WrongFormat)
-        }
-      },
-      _ => Err(WrongFormat),
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    if let Ok(carriers) = item.clone().try_into() {
+      return Ok(ProcessJobMaterial::Carriers(carriers));
     }
+    Ok(ProcessJobMaterial::Materials(item.try_into()?))
   }
 }
 
-/// ## ERACK
-/// 
-/// **Enable/Disable Event Report Acknowledge Code**
-/// 
-/// ---------------------------------------------------------------------------
+/// ## PRPAUSEEVENT
 /// 
-/// #### Format
+/// **Process Job Pause Event**
 /// 
-/// Single-byte enumerated value.
+/// A [CEID] upon which the process job is to be paused.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F38]
+/// - [S16F11], [S16F15]
 /// 
-/// [S2F38]: crate::messages::s2::EnableDisableEventReportAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum EnableDisableEventReportAcknowledgeCode {
-  Ok = 0,
-  CollectionEventDoesNotExist = 1,
-}
-singleformat_enum!{EnableDisableEventReportAcknowledgeCode, Bin}
+/// [CEID]:   CollectionEventID
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+pub type ProcessJobPauseEvent = CollectionEventID;
 
-/// ## ERRTEXT
+/// ## PRPROCESSSTART
 /// 
-/// Text string describing the error noted in the corresponding [ERRCODE].
+/// **Process Job Process Start**
 /// 
-/// Maximum 120 characters.
+/// Whether the process job is started automatically once its material
+/// arrives, or otherwise must wait for a start command.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F20]
-/// - S3F18, S3F20, S3F22, S3F24, S3F26, S3F28, S3F30, S3F32, S3F34, S3F36
-/// - S4F20, S4F22, S4F23, S4F31, S4F33
-/// - S5F14, S5F15, S5F18
-/// - S6F25
-/// - S13F14, S13F16
-/// - S14F2, S14F4, S14F6, S14F8, S14F10, S14F12, S14F14, S14F16, S14F18,
-///   S14F20, S14F21, S14F26, S14F28
-/// - S15F4, S15F6, S15F8, S15F10, S15F12, S15F14, S15F16, S15F18, S15F20,
-///   S15F22, S15F24, S15F26, S15F28, S15F30, S15F32, S15F34, S15F36, S15F38,
-///   S15F40, S15F42, S15F44, S15F48, S15F53
-/// - S16F4, S16F6, S16F7, S16F12, S16F16, S16F18, S16F24, S16F26, S16F28
-/// - S17F4, S17F8, S17F18
-/// 
-/// [ERRCODE]: ErrorCode
-/// [S1F20]:   crate::messages::s1::AttributeData
-#[derive(Clone, Debug)]
-pub struct ErrorText(Vec<Char>);
-singleformat_vec!{ErrorText, Ascii, 0..=120, Char}
-
-/// ## FCNID
-/// 
-/// **Function ID**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// #### Used By
-/// 
-/// - [S2F43], [S2F44]
+/// - [S16F11], [S16F15]
 /// 
-/// [S2F43]: crate::messages::s2::ResetSpoolingStreamsAndFunctions
-/// [S2F44]: crate::messages::s2::ResetSpoolingAcknowledge
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub struct FunctionID(pub u8);
-singleformat!{FunctionID, U1}
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessJobProcessStart(pub bool);
+singleformat!{ProcessJobProcessStart, Bool}
 
-/// ## GRANT
+/// ## PRRECIPEMETHOD
 /// 
-/// Grant code, 1 byte.
+/// **Process Job Recipe Method**
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F2], [S2F40]
-/// - S3F16
-/// - S4F26
-/// - S13F12
-/// - S14F24
-/// - S16F2
-/// - S19F20
+/// - [S16F11], [S16F15]
 /// 
-/// [S2F2]:  crate::messages::s2::ServiceProgramLoadGrant
-/// [S2F40]: crate::messages::s2::MultiBlockGrant
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum Grant {
-  Granted = 0,
-  Busy = 1,
-  NoSpaceAvailable = 2,
-  DuplicateDataID = 3,
+pub enum ProcessJobRecipeMethod {
+  RecipeOnly               = 1,
+  RecipeWithVariableTuning = 2,
 }
-singleformat_enum!{Grant, Bin}
+singleformat_enum!{ProcessJobRecipeMethod, U1}
 
-/// ## HCACK
-/// 
-/// **Host Command Parameter Acknowledge Code**
+/// ## PRSTATE
 /// 
-/// ---------------------------------------------------------------------------
+/// **Process Job State**
 /// 
-/// #### Format
+/// State of a process job as defined by SEMI E40, 1 byte.
 /// 
-/// Single-byte enumerated value.
+/// -------------------------------------------------------------------------
 /// 
-/// ---------------------------------------------------------------------------
+/// #### Values
 /// 
-/// #### Used By
+/// - 0 = Queued/Pooled
+/// - 1 = Setting Up
+/// - 2 = Waiting for Start
+/// - 3 = Processing
+/// - 4 = Process Complete
+/// - 5 = Reserved
+/// - 6 = Pausing
+/// - 7 = Paused
+/// - 8 = Stopping
+/// - 9 = Aborting
+/// - 10 = Stopped
+/// - 11 = Aborted
+/// - 12-63 = Reserved
+/// - 64-255 = User Defined
 /// 
-/// - [S2F42], [S2F50]
+/// -------------------------------------------------------------------------
 /// 
-/// [S2F42]: crate::messages::s2::HostCommandAcknowledge
-/// [S2F50]: crate::messages::s2::EnhancedRemoteCommandAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum HostCommandAcknowledgeCode {
-  Ok = 0,
-  CommandDoesNotExist = 1,
-  CannotPerformNow = 2,
-  ParameterInvalid = 3,
-  ToBeCompleted = 4,
-  AlreadyInDesiredCondition = 5,
-  ObjectDoesNotExist = 6,
+/// #### Used By
+/// 
+/// - [S16F20]
+/// 
+/// [S16F20]: crate::messages::s16::ProcessJobGetAllJobsSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ProcessJobState {
+  Queued          = 0,
+  SettingUp       = 1,
+  WaitingForStart = 2,
+  Processing      = 3,
+  ProcessComplete = 4,
+  Pausing         = 6,
+  Paused          = 7,
+  Stopping        = 8,
+  Aborting        = 9,
+  Stopped         = 10,
+  Aborted         = 11,
 }
-singleformat_enum!{HostCommandAcknowledgeCode, Bin}
+coded_value!{ProcessJobState, U1, 64}
 
-/// ## LENGTH
+/// ## PTN
 /// 
-/// Length of the service program or process program in bytes.
+/// **Port Number**
+/// 
+/// Material I/O port number, 1 byte.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F1]
-/// - S7F1, S7F29
+/// - [S3F11], [S3F12], S3F13, [S3F17], S3F19, S3F21, S3F23, S3F25, [S3F27], [S3F28]
+/// - S4F1, S4F3, S4F5, S4F7, S4F9, S4F11, S4F13, S4F15, S4F17
 /// 
-/// [S2F1]: crate::messages::s2::ServiceProgramLoadInquire
-#[derive(Clone, Copy, Debug)]
-pub enum Length {
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
+/// [S3F11]: crate::messages::s3::MaterialIDRequest
+/// [S3F12]: crate::messages::s3::MaterialIDRequestAcknowledge
+/// [S3F17]: crate::messages::s3::CarrierActionRequest
+/// [S3F27]: crate::messages::s3::ChangeAccess
+/// [S3F28]: crate::messages::s3::ChangeAccessAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PortNumber {
+  Bin(u8),
   U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
 }
-multiformat!{Length, I1, I2, I4, I8, U1, U2, U4, U8}
+multiformat!{PortNumber, Bin, U1}
+impl PortNumber {
+  /// ### NUMBER
+  /// 
+  /// The port number, regardless of format.
+  pub fn number(&self) -> u8 {
+    match self {
+      PortNumber::Bin(number) => *number,
+      PortNumber::U1(number) => *number,
+    }
+  }
+}
 
-/// ## LIMITACK
-/// 
-/// **Variable Limit Attribute Set Acknowledge Code**
-/// 
-/// ---------------------------------------------------------------------------
+/// ## RAC
 /// 
-/// #### Format
+/// Reset acknowledge code, 1 byte.
 /// 
-/// Single-byte enumerated value.
+/// TODO: Implement Format 31.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F46]
+/// - [S2F20]
 /// 
-/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
+/// [S2F20]: crate::messages::s2::ResetAcknowledge
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum VariableLimitAttributeSetAcknowledgeCode {
-  LimitIDDoesNotExist = 1,
-  UpperDeadbandGreaterThanLimitMax = 2,
-  LowerDeadbandLessThanLimitMin = 3,
-  UpperDeadbandLessThanLowerDeadband = 4,
-  IllegalFormat = 5,
-  AsciiValueNonNumeric = 6,
-  DuplicateLimitDefinition = 7,
+pub enum ResetAcknowledgeCode {
+  Ok = 0,
+  Denied = 1,
 }
-singleformat_enum!{VariableLimitAttributeSetAcknowledgeCode, Bin}
+singleformat_enum!{ResetAcknowledgeCode, U1}
 
-/// ## LIMITID
-/// 
-/// **Limit Identifier**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// #### Format
-/// 
-/// Identifier for a specific set of limits for a variable to which the
-/// corresponding limit attributes refer.
+/// ## RCMD
 /// 
-/// Single-byte.
+/// Remote command.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F45], [S2F46], [S2F48]
+/// - [S2F21], [S2F41], [S2F49]
 /// 
-/// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
-/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
-/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub struct LimitID(pub u8);
-singleformat!{LimitID, Bin}
+/// [S2F21]: crate::messages::s2::RemoteCommandSend
+/// [S2F41]: crate::messages::s2::HostCommandSend
+/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+#[derive(Clone, Debug)]
+pub enum RemoteCommand {
+  Ascii(Vec<Char>),
+  I1(i8),
+  U1(u8),
+}
+multiformat_ascii!{RemoteCommand, I1, U1}
 
-/// ## LIMITMAX
-/// 
-/// **Limit Maximum**
-/// 
-/// ---------------------------------------------------------------------------
+/// ## RCPATTRDATA
 /// 
-/// The maximum allowed value for the limit values of a variable.
+/// **Recipe Attribute Data**
 /// 
-/// The format must match that of the specified variable.
+/// Value of a recipe attribute.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F48]
+/// - S15F13, S15F17, S15F18, S15F19, S15F21, [S15F27], [S15F28], S15F31,
+///   [S15F32], S15F34
 /// 
-/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
+/// [S15F27]: crate::messages::s15::RecipeDownloadRequest
+/// [S15F28]: crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F32]: crate::messages::s15::RecipeUploadData
 #[derive(Clone, Debug)]
-pub enum LimitMaximum {
+pub enum RecipeAttributeData {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
   Bool(Vec<bool>),
   Ascii(Vec<Char>),
+  Jis8(String),
   I1(Vec<i8>),
   I2(Vec<i16>),
   I4(Vec<i32>),
@@ -2877,463 +7136,475 @@ pub enum LimitMaximum {
   F4(Vec<f32>),
   F8(Vec<f64>),
 }
-multiformat_vec!{LimitMaximum, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{RecipeAttributeData, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## LIMITMIN
+/// ## RCPATTRID
 /// 
-/// **Limit Minimum**
+/// **Recipe Attribute ID**
 /// 
-/// ---------------------------------------------------------------------------
+/// Name of a recipe attribute.
 /// 
-/// The minimum allowed value for the limit values of a variable.
+/// -------------------------------------------------------------------------
 /// 
-/// The format must match that of the specified variable.
+/// #### Used By
 /// 
-/// ---------------------------------------------------------------------------
+/// - S15F13, S15F17, S15F18, S15F19, S15F21, [S15F27], [S15F28], S15F31,
+///   [S15F32], S15F34
+/// 
+/// [S15F27]: crate::messages::s15::RecipeDownloadRequest
+/// [S15F28]: crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F32]: crate::messages::s15::RecipeUploadData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecipeAttributeID(pub Vec<Char>);
+singleformat_vec!{RecipeAttributeID, Ascii}
+
+/// ## RCPBODY
+/// 
+/// **Recipe Body**
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F48]
+/// - S15F13, S15F16, S15F17, S15F18, [S15F27], S15F28, S15F31, [S15F32]
 /// 
-/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
+/// [S15F27]: crate::messages::s15::RecipeDownloadRequest
+/// [S15F32]: crate::messages::s15::RecipeUploadData
 #[derive(Clone, Debug)]
-pub enum LimitMinimum {
-  Bool(Vec<bool>),
+pub enum RecipeBody {
+  Bin(Vec<u8>),
   Ascii(Vec<Char>),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
 }
-multiformat_vec!{LimitMinimum, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+multiformat_vec!{RecipeBody, Bin, Ascii}
 
-/// ## LOC
+/// ## RCPCMD
 /// 
-/// Machine material location code.
+/// **Recipe Command**
 /// 
-/// ---------------------------------------------------------------------------
+/// Action to be performed on a recipe, 1 byte.
 /// 
-/// #### Format
+/// -------------------------------------------------------------------------
 /// 
-/// 1 byte.
+/// #### Used By
 /// 
-/// ---------------------------------------------------------------------------
+/// - S15F21
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum RecipeCommand {
+  Create = 1,
+  Store  = 2,
+  Update = 3,
+  Delete = 4,
+}
+singleformat_enum!{RecipeCommand, U1}
+
+/// ## RCPID
+/// 
+/// **Recipe ID**
+/// 
+/// Identifier of a recipe, forming the last part of a [RCPSPEC].
+/// 
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F27]
-/// - S3F2
+/// - S15F21, S15F22
 /// 
-/// [S2F27]: crate::messages::s2::InitiateProcessingRequest
-#[derive(Clone, Copy, Debug)]
-pub struct LocationCode(pub u8);
-singleformat!{LocationCode, Bin}
+/// [RCPSPEC]: RecipeSpecifier
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecipeID(pub Vec<Char>);
+singleformat_vec!{RecipeID, Ascii}
 
-/// ## LOWERDB
+/// ## RCPNEWID
 /// 
-/// **Lower Deadband**
+/// **New Recipe ID**
 /// 
-/// Variable limit attribute which defines the lower boundary of the deadband
-/// of a limit. The value applies to a single limit for a specified variable.
+/// The new [RCPSPEC] of a recipe being renamed or copied, conforming to
+/// [OBJSPEC].
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F45], [S2F48]
+/// - S15F11, S15F15
 /// 
-/// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
-/// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
-#[derive(Clone, Debug)]
-pub enum LowerDeadband {
-  Bool(Vec<bool>),
-  Ascii(Vec<Char>),
-  I1(Vec<i8>),
-  I2(Vec<i16>),
-  I4(Vec<i32>),
-  I8(Vec<i64>),
-  U1(Vec<u8>),
-  U2(Vec<u16>),
-  U4(Vec<u32>),
-  U8(Vec<u64>),
-  F4(Vec<f32>),
-  F8(Vec<f64>),
-}
-multiformat_vec!{LowerDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+/// [RCPSPEC]: RecipeSpecifier
+/// [OBJSPEC]: ObjectSpecifier
+pub type NewRecipeID = ObjectSpecifier;
 
-/// ## LRACK
-/// 
-/// **Link Report Acknowledge Code**
-/// 
-/// ---------------------------------------------------------------------------
+/// ## RCPOWCODE
 /// 
-/// #### Format
+/// **Recipe Overwrite Code**
 /// 
-/// Single-byte enumerated value.
+/// Whether an existing recipe of the same [RCPSPEC] may be overwritten.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F36]
+/// - S15F11, S15F13, S15F15, [S15F27], S15F29
 /// 
-/// [S2F36]: crate::messages::s2::LinkEventReportAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum LinkReportAcknowledgeCode {
-  Ok = 0,
-  InsufficientSpace = 1,
-  InvalidFormat = 2,
-  CollectionEventLinkAlreadyDefined = 3,
-  CollectionEventDoesNotExist = 4,
-  ReportDoesNotExist = 5,
-}
-singleformat_enum!{LinkReportAcknowledgeCode, Bin}
+/// [RCPSPEC]: RecipeSpecifier
+/// [S15F27]:  crate::messages::s15::RecipeDownloadRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RecipeOverwriteCode(pub bool);
+singleformat!{RecipeOverwriteCode, Bool}
 
-/// ## LVACK
+/// ## RCPPARNM
 /// 
-/// **Variable Limit Definition Acknowledge Code**
+/// **Recipe Parameter Name**
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
-/// #### Format
+/// #### Used By
 /// 
-/// Single-byte enumerated value.
+/// - S15F19, S15F21, S15F25, S15F26, S15F30, S15F31
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecipeParameterName(pub Vec<Char>);
+singleformat_vec!{RecipeParameterName, Ascii}
+
+/// ## RCPPARRULE
 /// 
-/// ---------------------------------------------------------------------------
+/// **Recipe Parameter Rule**
 /// 
-/// #### Used By
+/// Restriction imposed on a recipe parameter.
 /// 
-/// - [S2F46]
+/// -------------------------------------------------------------------------
 /// 
-/// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum VariableLimitDefinitonAcknowledgeCode {
-  VariableDoesNotExist = 1,
-  VariableHasNoLimitsCapability = 2,
-  VariableRepeatedInMessage = 3,
-  LimitValueError = 4,
-}
-singleformat_enum!{VariableLimitDefinitonAcknowledgeCode, Bin}
+/// #### Used By
+/// 
+/// - S15F26
+#[derive(Clone, Debug)]
+pub struct RecipeParameterRule(pub Vec<Char>);
+singleformat_vec!{RecipeParameterRule, Ascii}
 
-/// ## MDLN
+/// ## RCPPARVAL
 /// 
-/// Equipment Model Type, 20 bytes max.
+/// **Recipe Parameter Value**
+/// 
+/// Any format other than a list.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F2], [S1F13H], [S1F13E], [S1F14H], [S1F14E]
-/// - S7F22, S7F23, S7F26, S7F31, S7F39, S7F43
-/// 
-/// [S1F2]:   crate::messages::s1::OnLineDataEquipment
-/// [S1F13H]: crate::messages::s1::HostCR
-/// [S1F13E]: crate::messages::s1::EquipmentCR
-/// [S1F14H]: crate::messages::s1::HostCRA
-/// [S1F14E]: crate::messages::s1::EquipmentCRA
+/// - S15F19, S15F21, S15F26, S15F30, S15F31
 #[derive(Clone, Debug)]
-pub struct ModelName(Vec<Char>);
-singleformat_vec!{ModelName, Ascii, 0..=20, Char}
+pub enum RecipeParameterValue {
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{RecipeParameterValue, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
-/// ## MID
+/// ## RCPSPEC
 /// 
-/// Material ID.
+/// **Recipe Specifier**
 /// 
-/// Maximum 80 characters.
+/// Specifier of a recipe, conforming to [OBJSPEC], formed of the
+/// [RMNSPEC] of its namespace, its [RCPID], and optionally its [RCPVERS].
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F27]
-/// - S3F2, S3F4, S3F7, S3F9, S3F12, S3F13
-/// - S4F1, S4F3, S4F5, S4F7, S4F9, S4F11, S4F13, S4F15, S4F17
-/// - S7F7, S7F8, S7F10, S7F11, S7F13, S7F35, S7F36
-/// - S12F1, S12F3, S12F4, S12F5, S12F7, S12F9, S12F11, S12F13, S12F14, S12F15
-///   S12F16, S12F17, S12F18
-/// - S16F3, S16F11, S16F15
-/// - S18F10, S18F11, S18F16
+/// - [S15F1], S15F3, S15F5, S15F7, S15F9, S15F11, S15F13, S15F15, S15F17,
+///   S15F18, S15F19, S15F21, [S15F27], [S15F28], S15F29, S15F30, [S15F31],
+///   [S15F32], S15F33, S15F34, S15F35, S15F39, S15F41
 /// 
-/// [S2F27]: crate::messages::s2::InitiateProcessingRequest
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct MaterialID(Vec<Char>);
-singleformat_vec!{MaterialID, Ascii, 0..=80, Char}
+/// [OBJSPEC]: ObjectSpecifier
+/// [RMNSPEC]: RecipeNamespaceSpecifier
+/// [RCPID]:   RecipeID
+/// [RCPVERS]: RecipeVersion
+/// [S15F1]:   crate::messages::s15::RecipeManagementMultiBlockInquire
+/// [S15F27]:  crate::messages::s15::RecipeDownloadRequest
+/// [S15F28]:  crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F31]:  crate::messages::s15::RecipeUploadRequest
+/// [S15F32]:  crate::messages::s15::RecipeUploadData
+pub type RecipeSpecifier = ObjectSpecifier;
 
-/// ## NULBC
+/// ## RCPSTAT
 /// 
-/// Null bin code value.
+/// **Recipe Status**
 /// 
-/// Used to indicate no die at a location.
+/// Status code of a recipe, 1 byte, as defined by SEMI E42.
 /// 
-/// Must be the same format as [BCEQU] and [BINLT].
+/// -------------------------------------------------------------------------
 /// 
-/// Zero length indicates not used.
+/// #### Used By
+/// 
+/// - S15F36, S15F40
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RecipeStatus(pub u8);
+singleformat!{RecipeStatus, U1}
+
+/// ## RCPVERS
+/// 
+/// **Recipe Version**
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - S12F1, S12F3, S12F4
-/// 
-/// [BCEQU]: BinCodeEquivalents
-/// [BINLT]: BinList
-#[derive(Clone, Debug)]
-pub enum NullBinCode {
-  Ascii(Vec<Char>),
-  U1(Vec<u8>),
-}
-multiformat_vec!{NullBinCode, Ascii, U1}
+/// - S15F21, S15F22
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecipeVersion(pub Vec<Char>);
+singleformat_vec!{RecipeVersion, Ascii}
 
-/// ## OBJID
+/// ## READLN
 /// 
-/// Identifier for an object.
+/// **Read Length**
+/// 
+/// Maximum number of bytes to be read from a data set.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F19]
-/// - S14F1, S14F2, S14F3, S14F4
+/// - [S13F5]
 /// 
-/// [S1F19]: crate::messages::s1::GetAttribute
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum ObjectID {
-  Ascii(Vec<Char>),
-  U1(u8),
-  U2(u16),
-  U4(u32),
-  U8(u64),
-}
-multiformat_ascii!{ObjectID, U1, U2, U4, U8}
+/// [S13F5]: crate::messages::s13::ReadDataSetRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReadLength(pub u64);
+nonnegative!{ReadLength, u64}
 
-/// ## OBJSPEC
+/// ## RECLEN
 /// 
-/// **Object Specifier**
+/// **Record Length**
 /// 
-/// Text string that has an internal format and that is used to point to a
-/// specific object instance.
+/// Maximum length of a record of a data set in bytes.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
-/// #### Format
+/// #### Used By
 /// 
-/// The string is formed out of a sequence of formatted substrings, each
-/// specifying an object's type, and name. The substring format has the
-/// following four parts:
+/// - [S13F4]
 /// 
-/// 1. Object Type
-/// 2. Colon Character ':'
-/// 3. Object Name
-/// 4. Greater-Than Symbol '>'
+/// [S13F4]: crate::messages::s13::OpenDataSetData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RecordLength(pub u64);
+nonnegative!{RecordLength, u64}
+
+/// ## REFP
 /// 
-/// The Object Type may be ommitted (along with the Colon Character), if it is
-/// not necessary to uniquely identify an object. The final Greater-Than Symbol
-/// is optional.
+/// **Reference Point**
 /// 
-/// TODO: Implement format restrictions.
+/// Location of a reference die, as its X and Y coordinates.
 /// 
-/// ---------------------------------------------------------------------------
+/// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F49]
-/// - S13F11, S13F13, S13F15
-/// - S14F1, S14F3, S14F5, S14F7, S14F9, S14F10, S14F11, S14F13, S14F15,
-///   S14F17, S14F19, S14F25, S14F27
-/// - S15F7, S15F23, S15F43, S15F47
+/// - [S12F1], [S12F4]
 /// 
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
 #[derive(Clone, Debug)]
-pub struct ObjectSpecifier(pub Vec<Char>);
-singleformat_vec!{ObjectSpecifier, Ascii}
+pub enum ReferencePoint {
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+}
+multiformat_vec!{ReferencePoint, I1, I2, I4, I8}
 
-/// ## OBJTYPE
-/// 
-/// An identifier for a class of objects.
+/// ## REPGSZ
 /// 
-/// All objects of the same type must have the same set of attributes.
+/// Reporting group size.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F19]
-/// - S14F1, S14F3, S14F6, S14F7, S14F8, S14F9, S14F25, S14F26, S14F27
+/// - [S2F23]
+/// - [S17F5]
 /// 
-/// [S1F19]: crate::messages::s1::GetAttribute
+/// [S2F23]: crate::messages::s2::TraceInitializeSend
+/// [S17F5]: crate::messages::s17::TraceCreateRequest
 #[derive(Clone, Debug)]
-pub enum ObjectType {
+pub enum ReportingGroupSize {
   Ascii(Vec<Char>),
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
   U1(u8),
   U2(u16),
   U4(u32),
   U8(u64),
 }
-multiformat_ascii!{ObjectType, U1, U2, U4, U8}
+multiformat_ascii!{ReportingGroupSize, I1, I2, I4, I8, U1, U2, U4, U8}
 
-/// ## OFLACK
+/// ## RIC
 /// 
-/// Acknowledge code for OFF-LINE request.
+/// Reset code, 1 byte.
+/// 
+/// TODO: Implement Format 31.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F16]
+/// - [S2F19]
 /// 
-/// [S1F16]: crate::messages::s1::OffLineAck
+/// [S2F19]: crate::messages::s2::ResetInitializeSend
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum OffLineAcknowledge {
-  Acknowledge = 0,
+pub enum ResetCode {
+  NotUsed = 0,
+  PowerUpReset = 1,
 }
-singleformat_enum!{OffLineAcknowledge, Bin}
+singleformat_enum!{ResetCode, U1}
 
-/// ## ONLACK
+/// ## RMACK
 /// 
-/// Acknowledge code for ON-LINE request.
+/// **Recipe Management Acknowledge**
+/// 
+/// Whether a requested recipe management action was completed, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Completed Successfully
+/// - 1 = Action Will Be Performed, Completion Signaled Later
+/// - 2 = Action Denied or Incomplete, See Errors
+/// - 3-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F18]
+/// - S15F4, S15F6, S15F8, S15F10, S15F12, S15F14, S15F16, S15F18, S15F20,
+///   S15F22, S15F24, S15F26, [S15F28], S15F30, [S15F32], S15F34, S15F36,
+///   S15F38, S15F40, S15F42, S15F44, S15F48, S15F53
 /// 
-/// [S1F18]: crate::messages::s1::OnLineAck
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// [S15F28]: crate::messages::s15::RecipeDownloadAcknowledge
+/// [S15F32]: crate::messages::s15::RecipeUploadData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum OnLineAcknowledge {
-  Accepted      = 0,
-  NotAllowed    = 1,
-  AlreadyOnLine = 2,
+pub enum RecipeManagementAcknowledge {
+  Completed    = 0,
+  WillComplete = 1,
+  Denied       = 2,
 }
-singleformat_enum!{OnLineAcknowledge, Bin}
+coded_value!{RecipeManagementAcknowledge, Bin, 64}
 
-/// ## PPID
-/// 
-/// Process Program ID
+/// ## RMDATASIZE
 /// 
-/// ---------------------------------------------------------------------------
+/// **Recipe Management Data Size**
 /// 
-/// #### Format
+/// Total bytes of the recipe management message to be sent, conforming to
+/// [DATALENGTH].
 /// 
-/// Maximum 120 bytes.
+/// -------------------------------------------------------------------------
 /// 
-/// Format is host dependent. For the internal use of the equipment, it can be
-/// treated as a unique binary pattern. If the equipment is not prepared to
-/// display the transmitted code, it should be displayed in hexadecimal.
+/// #### Used By
 /// 
-/// TODO: Implement format 10.
+/// - [S15F1]
 /// 
-/// ---------------------------------------------------------------------------
+/// [DATALENGTH]: DataLength
+/// [S15F1]:      crate::messages::s15::RecipeManagementMultiBlockInquire
+pub type RecipeDataSize = DataLength;
+
+/// ## RMGRNT
 /// 
-/// #### Used By
+/// **Recipe Management Grant**
 /// 
-/// - [S2F27]
-/// - S7F1, S7F3, S7F5, S7F6, S7F8, S7F10, S7F11, S7F13, S7F17, S7F20, S7F23,
-///   S7F25, S7F26, S7F27, S7F31, S7F33, S7F34, S7F36, S7F39, S7F43
+/// Permission to send a recipe management message, 1 byte.
 /// 
-/// [S2F27]: crate::messages::s2::InitiateProcessingRequest
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct ProcessProgramID(Vec<Char>);
-singleformat_vec!{ProcessProgramID, Ascii, 0..=120, Char}
-
-/// ## RAC
+/// -------------------------------------------------------------------------
 /// 
-/// Reset acknowledge code, 1 byte.
+/// #### Values
 /// 
-/// TODO: Implement Format 31.
+/// - 0 = Granted
+/// - 1 = Busy, Try Later
+/// - 2 = No Space Available
+/// - 3 = Duplicate DATAID
+/// - 4-63 = Reserved
+/// - 64-255 = User Defined
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F20]
+/// - [S15F2]
 /// 
-/// [S2F20]: crate::messages::s2::ResetAcknowledge
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+/// [S15F2]: crate::messages::s15::RecipeManagementMultiBlockGrant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum ResetAcknowledgeCode {
-  Ok = 0,
-  Denied = 1,
+pub enum RecipeManagementGrant {
+  Granted          = 0,
+  Busy             = 1,
+  NoSpaceAvailable = 2,
+  DuplicateDataID  = 3,
 }
-singleformat_enum!{ResetAcknowledgeCode, U1}
+coded_value!{RecipeManagementGrant, Bin, 64}
 
-/// ## RCMD
+/// ## RMNSPEC
 /// 
-/// Remote command.
+/// **Recipe Namespace Specifier**
+/// 
+/// Specifier of a recipe namespace, conforming to [OBJSPEC].
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F21], [S2F41], [S2F49]
+/// - S15F1, S15F3, S15F5, S15F7, S15F9, S15F11, S15F13, S15F15, S15F21,
+///   S15F23, S15F35, S15F37, S15F39, S15F41, S15F43, S15F45
 /// 
-/// [S2F21]: crate::messages::s2::RemoteCommandSend
-/// [S2F41]: crate::messages::s2::HostCommandSend
-/// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
-#[derive(Clone, Debug)]
-pub enum RemoteCommand {
-  Ascii(Vec<Char>),
-  I1(i8),
-  U1(u8),
-}
-multiformat_ascii!{RemoteCommand, I1, U1}
+/// [OBJSPEC]: ObjectSpecifier
+pub type RecipeNamespaceSpecifier = ObjectSpecifier;
 
-/// ## REPGSZ
+/// ## ROWCT
 /// 
-/// Reporting group size.
+/// Row count, in die increments.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F23]
-/// - S17F5
+/// - [S12F1], [S12F4]
 /// 
-/// [S2F23]: crate::messages::s2::TraceInitializeSend
-#[derive(Clone, Debug)]
-pub enum ReportingGroupSize {
-  Ascii(Vec<Char>),
-  I1(i8),
-  I2(i16),
-  I4(i32),
-  I8(i64),
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug)]
+pub enum RowCount {
   U1(u8),
   U2(u16),
   U4(u32),
   U8(u64),
 }
-multiformat_ascii!{ReportingGroupSize, I1, I2, I4, I8, U1, U2, U4, U8}
+multiformat!{RowCount, U1, U2, U4, U8}
 
-/// ## RIC
+/// ## RPSEL
 /// 
-/// Reset code, 1 byte.
-/// 
-/// TODO: Implement Format 31.
+/// **Reference Point Select**
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S2F19]
+/// - [S12F1], [S12F4]
 /// 
-/// [S2F19]: crate::messages::s2::ResetInitializeSend
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
-#[repr(u8)]
-pub enum ResetCode {
-  NotUsed = 0,
-  PowerUpReset = 1,
-}
-singleformat_enum!{ResetCode, U1}
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferencePointSelect(pub u8);
+singleformat!{ReferencePointSelect, U1}
 
 /// ## RPTID
 /// 
@@ -3344,11 +7615,27 @@ singleformat_enum!{ResetCode, U1}
 /// #### Used By
 /// 
 /// - [S2F33], [S2F35]
-/// - S6F11, S6F13, S6F16, S6F18, S6F19, S6F21, S6F27, S6F30
-/// - S17F1, S17F2, S17F3, S17F4, S17F5, S17F9, S17F11, S17F12
-/// 
-/// [S2F33]: crate::messages::s2::DefineReport
-/// [S2F35]: crate::messages::s2::LinkEventReport
+/// - [S6F11], [S6F13], [S6F16], [S6F18], [S6F19], [S6F21], [S6F27], [S6F30]
+/// - [S17F1], [S17F2], [S17F3], [S17F4], [S17F5], [S17F9], [S17F11], [S17F12]
+/// 
+/// [S2F33]:  crate::messages::s2::DefineReport
+/// [S2F35]:  crate::messages::s2::LinkEventReport
+/// [S6F11]:  crate::messages::s6::EventReport
+/// [S6F13]:  crate::messages::s6::AnnotatedEventReport
+/// [S6F16]:  crate::messages::s6::EventReportData
+/// [S6F18]:  crate::messages::s6::AnnotatedEventReportData
+/// [S6F19]:  crate::messages::s6::IndividualReportRequest
+/// [S6F21]:  crate::messages::s6::AnnotatedIndividualReportRequest
+/// [S6F27]:  crate::messages::s6::TraceReportSend
+/// [S6F30]:  crate::messages::s6::TraceReportData
+/// [S17F1]:  crate::messages::s17::DataReportCreateRequest
+/// [S17F2]:  crate::messages::s17::DataReportCreateAcknowledge
+/// [S17F3]:  crate::messages::s17::DataReportDeleteRequest
+/// [S17F4]:  crate::messages::s17::DataReportDeleteAcknowledge
+/// [S17F5]:  crate::messages::s17::TraceCreateRequest
+/// [S17F9]:  crate::messages::s17::CollectionEventLinkRequest
+/// [S17F11]: crate::messages::s17::CollectionEventUnlinkRequest
+/// [S17F12]: crate::messages::s17::CollectionEventUnlinkAcknowledge
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum ReportID {
   Ascii(Vec<Char>),
@@ -3363,6 +7650,40 @@ pub enum ReportID {
 }
 multiformat_ascii!{ReportID, I1, I2, I4, I8, U1, U2, U4, U8}
 
+/// ## RQCMD
+/// 
+/// **Required Command**
+/// 
+/// Whether a command must appear in every formatted process program.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequiredCommand(pub bool);
+singleformat!{RequiredCommand, Bool}
+
+/// ## RQPAR
+/// 
+/// **Required Parameter**
+/// 
+/// Whether a parameter must be provided wherever its command appears.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F22]
+/// 
+/// [S7F22]: crate::messages::s7::ProcessCapabilitiesData
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequiredParameter(pub bool);
+singleformat!{RequiredParameter, Bool}
+
 /// ## Report
 ///
 /// A single report containing a report ID and a list of variable values.
@@ -3371,7 +7692,10 @@ multiformat_ascii!{ReportID, I1, I2, I4, I8, U1, U2, U4, U8}
 ///
 /// #### Used By
 ///
-/// - S6F11, S6F16
+/// - [S6F11], [S6F16]
+/// 
+/// [S6F11]: crate::messages::s6::EventReport
+/// [S6F16]: crate::messages::s6::EventReportData
 #[derive(Clone, Debug)]
 pub struct Report(pub ReportID, pub VecList<Item>);
 
@@ -3402,6 +7726,100 @@ impl TryFrom<Item> for Report {
   }
 }
 
+/// ## RPTOC
+/// 
+/// **Report on Change**
+/// 
+/// Whether a trace report is only to be sent when the sampled data has
+/// changed since the previous report.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S17F5]
+/// 
+/// [S17F5]: crate::messages::s17::TraceCreateRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportOnChange(pub bool);
+singleformat!{ReportOnChange, Bool}
+
+/// ## RSDA
+/// 
+/// **Request Spool Data Acknowledge**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = OK
+/// - 1 = Denied, Busy, Try Later
+/// - 2 = Denied, Spooled Data Does Not Exist
+/// - 3-63 = Reserved
+/// - 64-255 = User Defined
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F24]
+/// 
+/// [S6F24]: crate::messages::s6::RequestSpooledDataAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum RequestSpoolDataAcknowledge {
+  Ok            = 0,
+  Busy          = 1,
+  NoSpooledData = 2,
+}
+coded_value!{RequestSpoolDataAcknowledge, Bin, 64}
+
+/// ## RSDC
+/// 
+/// **Request Spool Data Code**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F23]
+/// 
+/// [S6F23]: crate::messages::s6::RequestSpooledData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum RequestSpoolDataCode {
+  Transmit = 0,
+  Purge    = 1,
+}
+singleformat_enum!{RequestSpoolDataCode, U1}
+
+/// ## RSINF
+/// 
+/// **Starting Location**
+/// 
+/// Starting location of a row or column, given as the X coordinate, Y
+/// coordinate, and die count.
+/// 
+/// A negative die count indicates that the row or column is traversed in the
+/// decreasing direction.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F7], [S12F14]
+/// 
+/// [S12F7]:  crate::messages::s12::MapDataSendType1
+/// [S12F14]: crate::messages::s12::MapDataType1
+#[derive(Clone, Debug)]
+pub enum StartingLocation {
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+}
+multiformat_vec!{StartingLocation, I1, I2, I4, I8}
+
 /// ## RSPACK
 /// 
 /// **Reset Spooling Acknowledge Code**
@@ -3427,6 +7845,92 @@ pub enum ResetSpoolingAcknowledgeCode {
 }
 singleformat_enum!{ResetSpoolingAcknowledgeCode, Bin}
 
+/// ## RTYPE
+/// 
+/// **Record Type**
+/// 
+/// Type of the records contained in an opened data set.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F4]
+/// 
+/// [S13F4]: crate::messages::s13::OpenDataSetData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RecordType(pub u8);
+singleformat!{RecordType, U1}
+
+/// ## SDACK
+/// 
+/// **Map Setup Acknowledge**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Received Data
+/// - 1-63 = Error
+/// - 64-255 = User Defined
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F2]
+/// 
+/// [S12F2]: crate::messages::s12::MapSetupDataAcknowledge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum MapSetupAcknowledge {
+  Received = 0,
+}
+coded_value!{MapSetupAcknowledge, Bin, 64}
+
+/// ## SDBIN
+/// 
+/// **Send Bin Information**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Send Bin Information
+/// - 1 = Do Not Send Bin Information
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F17]
+/// 
+/// [S12F17]: crate::messages::s12::MapDataRequestType3
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SendBinInformation {
+  Send     = 0,
+  DontSend = 1,
+}
+singleformat_enum!{SendBinInformation, Bin}
+
+/// ## SEQNUM
+/// 
+/// **Sequence Number**
+/// 
+/// Number of a command within a formatted process program.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F26]
+/// 
+/// [S7F26]: crate::messages::s7::FormattedProcessProgramData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SequenceNumber(pub u16);
+singleformat!{SequenceNumber, U2}
+
 /// ## SFCD
 /// 
 /// Status form code, 1 byte.
@@ -3443,6 +7947,69 @@ singleformat_enum!{ResetSpoolingAcknowledgeCode, Bin}
 pub struct StatusFormCode(pub u8);
 singleformat!{StatusFormCode, Bin}
 
+/// ## SHEAD
+/// 
+/// Stored SECS message block header related to the transaction timer,
+/// 10 bytes.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S9F9]
+/// 
+/// [S9F9]: crate::messages::s9::TransactionTimerTimeout
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StoredHeader(Vec<u8>);
+singleformat_vec!{StoredHeader, Bin, 10..=10, u8}
+impl From<[u8; 10]> for StoredHeader {
+  fn from(value: [u8; 10]) -> Self {
+    Self(value.to_vec())
+  }
+}
+
+/// ## SLOTID
+/// 
+/// **Slot ID**
+/// 
+/// Identifier of a slot within a carrier.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S16F11], [S16F15]
+/// 
+/// [S16F11]: crate::messages::s16::ProcessJobCreateEnhanced
+/// [S16F15]: crate::messages::s16::ProcessJobMultiCreate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotID(pub u8);
+singleformat!{SlotID, U1}
+
+/// ## SMPLN
+/// 
+/// **Sample Number**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F1]
+/// 
+/// [S6F1]: crate::messages::s6::TraceDataSend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleNumber {
+  I1(i8),
+  I2(i16),
+  I4(i32),
+  I8(i64),
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+}
+multiformat!{SampleNumber, I1, I2, I4, I8, U1, U2, U4, U8}
+
 /// ## SOFTREV
 /// 
 /// Software Revision Code, 20 bytes max.
@@ -3452,13 +8019,17 @@ singleformat!{StatusFormCode, Bin}
 /// #### Used By
 /// 
 /// - [S1F2E], [S1F13H], [S1F13E], [S1F14H], [S1F14E]
-/// - S7F22, S7F23, S7F26, S7F31, S7F39, S7F43
+/// - [S7F22], [S7F23], [S7F26], [S7F31], S7F39, S7F43
 /// 
 /// [S1F2E]:  crate::messages::s1::OnLineDataEquipment
 /// [S1F13H]: crate::messages::s1::HostCR
 /// [S1F13E]: crate::messages::s1::EquipmentCR
 /// [S1F14H]: crate::messages::s1::HostCRA
 /// [S1F14E]: crate::messages::s1::EquipmentCRA
+/// [S7F22]:  crate::messages::s7::ProcessCapabilitiesData
+/// [S7F23]:  crate::messages::s7::FormattedProcessProgramSend
+/// [S7F26]:  crate::messages::s7::FormattedProcessProgramData
+/// [S7F31]:  crate::messages::s7::VerificationRequestSend
 #[derive(Clone, Debug)]
 pub struct SoftwareRevision(Vec<Char>);
 singleformat_vec!{SoftwareRevision, Ascii, 0..=20, Char}
@@ -3519,7 +8090,7 @@ impl From<ServiceProgramID> for Item {
   fn from(value: ServiceProgramID) -> Self {
     let mut vec = vec![];
     vec.extend_from_slice(&value.0);
-    Item::Ascii(vec)
+    Item::Ascii(vec.into())
   }
 }
 impl TryFrom<Item> for ServiceProgramID {
@@ -3539,6 +8110,24 @@ impl TryFrom<Item> for ServiceProgramID {
   }
 }
 
+/// ## SPNAME
+/// 
+/// **Service Parameter Name**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F19], [S14F20], [S14F21], [S14F28]
+/// 
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F20]: crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+/// [S14F28]: crate::messages::s14::ServiceParameterData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ServiceParameterName(pub Vec<Char>);
+singleformat_vec!{ServiceParameterName, Ascii}
+
 /// ## SPR
 /// 
 /// Service program results.
@@ -3552,6 +8141,141 @@ impl TryFrom<Item> for ServiceProgramID {
 /// [S2F10]: crate::messages::s2::ServiceProgramResultsData
 pub type ServiceProgramResults = Item;
 
+/// ## SPVAL
+/// 
+/// **Service Parameter Value**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F19], [S14F20], [S14F21]
+/// 
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F20]: crate::messages::s14::GenericServiceAcknowledge
+/// [S14F21]: crate::messages::s14::GenericServiceCompletion
+#[derive(Clone, Debug)]
+pub enum ServiceParameterValue {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{ServiceParameterValue, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
+/// ## SSACK
+/// 
+/// **Subsystem Acknowledge**
+/// 
+/// Two character acknowledge code of a subsystem component.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - "NO" = Normal Operation
+/// - "EE" = Execution Error
+/// - "CE" = Communication Error
+/// - "HE" = Hardware Error
+/// - "TE" = Tag Error
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F2], [S18F4], [S18F6], [S18F8], [S18F10], [S18F12], [S18F14], [S18F16]
+/// 
+/// [S18F2]:  crate::messages::s18::ReadAttributeData
+/// [S18F4]:  crate::messages::s18::WriteAttributeAcknowledge
+/// [S18F6]:  crate::messages::s18::ReadData
+/// [S18F8]:  crate::messages::s18::WriteDataAcknowledge
+/// [S18F10]: crate::messages::s18::ReadIDData
+/// [S18F12]: crate::messages::s18::WriteIDAcknowledge
+/// [S18F14]: crate::messages::s18::SubsystemCommandAcknowledge
+/// [S18F16]: crate::messages::s18::Read2DCodeConditionData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SubsystemAcknowledge(Vec<Char>);
+singleformat_vec!{SubsystemAcknowledge, Ascii, 2..=2, Char}
+
+/// ## SSCMD
+/// 
+/// **Subsystem Command**
+/// 
+/// Command to be performed by a subsystem component.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F13]
+/// 
+/// [S18F13]: crate::messages::s18::SubsystemCommandRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SubsystemCommand(pub Vec<Char>);
+singleformat_vec!{SubsystemCommand, Ascii}
+
+/// ## STATUS
+/// 
+/// Status information of a subsystem component.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F2], [S18F4], [S18F6], [S18F8], [S18F10], [S18F12], [S18F14], [S18F16]
+/// 
+/// [S18F2]:  crate::messages::s18::ReadAttributeData
+/// [S18F4]:  crate::messages::s18::WriteAttributeAcknowledge
+/// [S18F6]:  crate::messages::s18::ReadData
+/// [S18F8]:  crate::messages::s18::WriteDataAcknowledge
+/// [S18F10]: crate::messages::s18::ReadIDData
+/// [S18F12]: crate::messages::s18::WriteIDAcknowledge
+/// [S18F14]: crate::messages::s18::SubsystemCommandAcknowledge
+/// [S18F16]: crate::messages::s18::Read2DCodeConditionData
+#[derive(Clone, Debug)]
+pub struct Status(pub Vec<Char>);
+singleformat_vec!{Status, Ascii}
+
+/// ## STIME
+/// 
+/// **Sample Time**
+/// 
+/// Time at which a sample was taken, in any of the formats of [TIME].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F1]
+/// 
+/// [TIME]: Time
+/// [S6F1]: crate::messages::s6::TraceDataSend
+#[derive(Clone, Debug)]
+pub struct SampleTime(pub Time);
+impl From<SampleTime> for Item {
+  fn from(value: SampleTime) -> Item {
+    value.0.into()
+  }
+}
+impl TryFrom<Item> for SampleTime {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    Ok(Self(value.try_into()?))
+  }
+}
+
 /// ## STRACK
 /// 
 /// **Spool Stream Acknowledge Code**
@@ -3595,6 +8319,30 @@ singleformat_enum!{SpoolStreamAcknowledgeCode, Bin}
 pub struct StreamID(pub u8);
 singleformat!{StreamID, U1}
 
+/// ## STRP
+/// 
+/// **Starting Position**
+/// 
+/// Starting position of an array map in die coordinates, given as the X
+/// and Y coordinates.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F9], [S12F16]
+/// 
+/// [S12F9]:  crate::messages::s12::MapDataSendType2
+/// [S12F16]: crate::messages::s12::MapDataType2
+#[derive(Clone, Debug)]
+pub enum StartingPosition {
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+}
+multiformat_vec!{StartingPosition, I1, I2, I4, I8}
+
 /// ## SV
 /// 
 /// Status variable value.
@@ -3604,9 +8352,10 @@ singleformat!{StreamID, U1}
 /// #### Used By
 /// 
 /// - [S1F4]
-/// - S6F1
+/// - [S6F1]
 /// 
 /// [S1F4]: crate::messages::s1::SelectedEquipmentStatusData
+/// [S6F1]: crate::messages::s6::TraceDataSend
 #[derive(Clone, Debug)]
 pub enum StatusVariableValue {
   List(Vec<Item>),
@@ -3627,6 +8376,24 @@ pub enum StatusVariableValue {
 }
 multiformat_vec!{StatusVariableValue, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
+/// ## SVCNAME
+/// 
+/// **Service Name**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F19], [S14F26], [S14F27], [S14F28]
+/// 
+/// [S14F19]: crate::messages::s14::GenericServiceRequest
+/// [S14F26]: crate::messages::s14::ServiceNameData
+/// [S14F27]: crate::messages::s14::ServiceParameterRequest
+/// [S14F28]: crate::messages::s14::ServiceParameterData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ServiceName(pub Vec<Char>);
+singleformat_vec!{ServiceName, Ascii}
+
 /// ## SVID
 ///
 /// Status variable ID.
@@ -3688,20 +8455,112 @@ impl From<VariableID> for StatusVariableID {
   }
 }
 
-/// ## SVNAME
+/// ## SVNAME
+/// 
+/// Status variable name.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S1F12]
+/// 
+/// [S1F12]: crate::messages::s1::StatusVariableNamelistReply
+#[derive(Clone, Debug)]
+pub struct StatusVariableName(pub Vec<Char>);
+singleformat_vec!{StatusVariableName, Ascii}
+
+/// ## TARGETID
+/// 
+/// **Target ID**
+/// 
+/// Identifier of the subsystem component to which a message is addressed.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S18F1] through [S18F16]
+/// 
+/// [S18F1]:  crate::messages::s18::ReadAttributeRequest
+/// [S18F16]: crate::messages::s18::Read2DCodeConditionData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TargetID(pub Vec<Char>);
+singleformat_vec!{TargetID, Ascii}
+
+/// ## TARGETSPEC
+/// 
+/// **Target Specifier**
+/// 
+/// Specifier of a target object, conforming to [OBJSPEC].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S14F17]
+/// 
+/// [OBJSPEC]: ObjectSpecifier
+/// [S14F17]:  crate::messages::s14::SupervisedObjectActionRequest
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TargetSpecifier(pub Vec<Char>);
+singleformat_vec!{TargetSpecifier, Ascii}
+
+/// ## TBLACK
+/// 
+/// **Table Acknowledge**
+/// 
+/// Indicates success or failure of a table operation, 1 byte.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Values
+/// 
+/// - 0 = Success
+/// - 1 = Failure
+/// - 2-63 = Reserved
+/// - 64-255 = User Defined
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F14], [S13F16]
+/// 
+/// [S13F14]: crate::messages::s13::TableDataAcknowledge
+/// [S13F16]: crate::messages::s13::TableData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TableAcknowledge {
+  Success = 0,
+  Failure = 1,
+}
+coded_value!{TableAcknowledge, U1, 64}
+impl CodedValue<TableAcknowledge> {
+  /// ### IS SUCCESS
+  pub fn is_success(&self) -> bool {
+    self.is(TableAcknowledge::Success)
+  }
+}
+
+/// ## TBLCMD
 /// 
-/// Status variable name.
+/// **Table Command**
+/// 
+/// Operation to be performed on a table, or on the part of a table which is
+/// being transferred or requested.
 /// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
-/// - [S1F12]
+/// - [S13F13], [S13F15]
 /// 
-/// [S1F12]: crate::messages::s1::StatusVariableNamelistReply
-#[derive(Clone, Debug)]
-pub struct StatusVariableName(pub Vec<Char>);
-singleformat_vec!{StatusVariableName, Ascii}
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TableCommand(pub u8);
+singleformat!{TableCommand, U1}
 
 /// ## TBLELT
 /// 
@@ -3713,7 +8572,11 @@ singleformat_vec!{StatusVariableName, Ascii}
 /// 
 /// #### Used By
 /// 
-/// - S13F13, S13F15, S13F16
+/// - [S13F13], [S13F15], [S13F16]
+/// 
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+/// [S13F16]: crate::messages::s13::TableData
 #[derive(Clone, Debug)]
 pub enum TableElement {
   List(Vec<Item>),
@@ -3734,6 +8597,46 @@ pub enum TableElement {
 }
 multiformat_vec!{TableElement, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
+/// ## TBLID
+/// 
+/// **Table ID**
+/// 
+/// Identifier of a table, unique within its table type.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F13], [S13F15], [S13F16]
+/// 
+/// [S13F13]: crate::messages::s13::TableDataSend
+/// [S13F15]: crate::messages::s13::TableDataRequest
+/// [S13F16]: crate::messages::s13::TableData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TableID(pub Vec<Char>);
+singleformat_vec!{TableID, Ascii}
+
+/// ## TBLTYP
+/// 
+/// **Table Type**
+/// 
+/// Identifier of a class of tables sharing the same format, conforming to
+/// [OBJTYPE].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S13F13], [S13F15], [S13F16]
+/// 
+/// [OBJTYPE]: ObjectType
+/// [S13F13]:  crate::messages::s13::TableDataSend
+/// [S13F15]:  crate::messages::s13::TableDataRequest
+/// [S13F16]:  crate::messages::s13::TableData
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TableType(pub Vec<Char>);
+singleformat_vec!{TableType, Ascii}
+
 /// ## TEXT
 ///
 /// **Text String**
@@ -3744,7 +8647,12 @@ multiformat_vec!{TableElement, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1,
 ///
 /// #### Used By
 ///
-/// - S10F1, S10F3, S10F5, S10F9
+/// - [S10F1], [S10F3], [S10F5], [S10F9]
+/// 
+/// [S10F1]: crate::messages::s10::TerminalRequest
+/// [S10F3]: crate::messages::s10::TerminalDisplaySingle
+/// [S10F5]: crate::messages::s10::TerminalDisplayMultiBlock
+/// [S10F9]: crate::messages::s10::Broadcast
 #[derive(Clone, Debug)]
 pub enum Text {
   Bin(Vec<u8>),
@@ -3812,7 +8720,9 @@ singleformat_enum!{TimeAcknowledgeCode, Bin}
 /// 
 /// Time of day.
 /// 
-/// TODO: Implement specific format restrictions.
+/// Conversion from an [Item] which is not valid in any of the formats below
+/// is rejected, and the [validate] function describes which rule of the
+/// format was violated.
 /// 
 /// -------------------------------------------------------------------------
 /// 
@@ -3852,17 +8762,417 @@ singleformat_enum!{TimeAcknowledgeCode, Bin}
 ///       - UTC: Z 
 /// - See SEMI E148 for more information.
 /// 
+/// Conversions to and from the date and time types of the chrono and time
+/// crates are provided by the datetime module when the features of the same
+/// name are enabled.
+/// 
 /// -------------------------------------------------------------------------
 /// 
 /// #### Used By
 /// 
 /// - [S2F18], [S2F31]
 /// 
-/// [S2F18]: crate::messages::s2::DateTimeData
-/// [S2F31]: crate::messages::s2::DateTimeSetRequest
+/// [Item]:     crate::Item
+/// [validate]: Time::validate
+/// [S2F18]:    crate::messages::s2::DateTimeData
+/// [S2F31]:    crate::messages::s2::DateTimeSetRequest
 #[derive(Clone, Debug)]
 pub struct Time(pub Vec<Char>);
-singleformat_vec!{Time, Ascii}
+impl Time {
+  pub fn new(vec: Vec<Char>) -> Result<Self, TimeError> {
+    let time = Self(vec);
+    time.validate()?;
+    Ok(time)
+  }
+  /// Creates a new instance from a string.
+  ///
+  /// NOTE: Invalid ASCII characters are replaced with '?'.
+  pub fn new_from_str(vec: &str) -> Result<Self, TimeError> {
+    Self::new(Char::safe_str_to_chars(vec))
+  }
+  pub fn read(&self) -> &Vec<Char> {
+    &self.0
+  }
+
+  /// ### 12-BYTE FORMAT
+  /// 
+  /// Constructs a [TIME] in the [Short] format, with a year between 1969
+  /// and 2068.
+  /// 
+  /// [TIME]:  Time
+  /// [Short]: TimeFormat::Short
+  pub fn short(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Result<Self, TimeError> {
+    TimeComponents {
+      format: TimeFormat::Short,
+      year, month, day, hour, minute, second,
+      nanosecond: 0,
+      offset: None,
+    }.to_time()
+  }
+
+  /// ### 16-BYTE FORMAT
+  /// 
+  /// Constructs a [TIME] in the [Long] format.
+  /// 
+  /// [TIME]: Time
+  /// [Long]: TimeFormat::Long
+  pub fn long(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, centisecond: u32) -> Result<Self, TimeError> {
+    if centisecond > 99 {return Err(TimeError::Fraction)}
+    TimeComponents {
+      format: TimeFormat::Long,
+      year, month, day, hour, minute, second,
+      nanosecond: centisecond * 10_000_000,
+      offset: None,
+    }.to_time()
+  }
+
+  /// ### EXTENDED FORMAT
+  /// 
+  /// Constructs a [TIME] in the [Extended] format, with an offset from UTC
+  /// in whole minutes, given in seconds.
+  /// 
+  /// [TIME]:     Time
+  /// [Extended]: TimeFormat::Extended
+  #[allow(clippy::too_many_arguments)]
+  pub fn extended(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, microsecond: u32, offset: i32) -> Result<Self, TimeError> {
+    if microsecond > 999_999 {return Err(TimeError::Fraction)}
+    if offset % 60 != 0 || offset.abs() >= 24 * 3600 {return Err(TimeError::TimeZone)}
+    TimeComponents {
+      format: TimeFormat::Extended,
+      year, month, day, hour, minute, second,
+      nanosecond: microsecond * 1_000,
+      offset: Some(offset),
+    }.to_time()
+  }
+
+  /// ### VALIDATE
+  /// 
+  /// Results in the [Time Format] the [TIME] is written in, or otherwise in
+  /// a [Time Error] describing the rule which it violates.
+  /// 
+  /// [TIME]:        Time
+  /// [Time Format]: TimeFormat
+  /// [Time Error]:  TimeError
+  pub fn validate(&self) -> Result<TimeFormat, TimeError> {
+    self.components().map(|components| components.format)
+  }
+
+  /// ### TIME FORMAT
+  /// 
+  /// The [Time Format] the [TIME] is written in, if it is valid.
+  /// 
+  /// [TIME]:        Time
+  /// [Time Format]: TimeFormat
+  pub fn format(&self) -> Option<TimeFormat> {
+    self.validate().ok()
+  }
+
+  /// ### CONVERT TIME FORMAT
+  /// 
+  /// Rewrites the [TIME] in the provided [Time Format].
+  /// 
+  /// Information not representable by the new [Time Format] is discarded.
+  /// Results in a [Time Error] if the [TIME] is not valid, if the year
+  /// cannot be represented by the new [Time Format], or if converting to
+  /// the [Extended] format without a known time zone.
+  /// 
+  /// [TIME]:        Time
+  /// [Time Format]: TimeFormat
+  /// [Time Error]:  TimeError
+  /// [Extended]:    TimeFormat::Extended
+  pub fn to_format(&self, format: TimeFormat) -> Result<Self, TimeError> {
+    let components = self.components()?;
+    TimeComponents {format, ..components}.to_time()
+  }
+
+  /// ### TIME COMPONENTS
+  /// 
+  /// Parses the [TIME] into its components, according to whichever
+  /// [Time Format] it is written in.
+  /// 
+  /// [TIME]:        Time
+  /// [Time Format]: TimeFormat
+  pub(crate) fn components(&self) -> Result<TimeComponents, TimeError> {
+    let text = Char::chars_to_str(&self.0);
+    let bytes = text.as_bytes();
+    let number = |range: std::ops::Range<usize>| -> Result<u32, TimeError> {
+      let mut value = 0;
+      for index in range {
+        match bytes.get(index) {
+          Some(digit) if digit.is_ascii_digit() => value = value * 10 + (digit - b'0') as u32,
+          _ => return Err(TimeError::Digit(index)),
+        }
+      }
+      Ok(value)
+    };
+    let literal = |index: usize, byte: u8| -> Result<(), TimeError> {
+      if bytes.get(index) == Some(&byte) {Ok(())} else {Err(TimeError::Separator(index))}
+    };
+    let components = match bytes.len() {
+      12 => {
+        let year = number(0..2)? as i32;
+        TimeComponents {
+          format: TimeFormat::Short,
+          year: if year < 69 {2000 + year} else {1900 + year},
+          month: number(2..4)?,
+          day: number(4..6)?,
+          hour: number(6..8)?,
+          minute: number(8..10)?,
+          second: number(10..12)?,
+          nanosecond: 0,
+          offset: None,
+        }
+      },
+      16 => TimeComponents {
+        format: TimeFormat::Long,
+        year: number(0..4)? as i32,
+        month: number(4..6)?,
+        day: number(6..8)?,
+        hour: number(8..10)?,
+        minute: number(10..12)?,
+        second: number(12..14)?,
+        nanosecond: number(14..16)? * 10_000_000,
+        offset: None,
+      },
+      20..=32 => {
+        let year = number(0..4)? as i32;
+        literal(4, b'-')?;
+        let month = number(5..7)?;
+        literal(7, b'-')?;
+        let day = number(8..10)?;
+        literal(10, b'T')?;
+        let hour = number(11..13)?;
+        literal(13, b':')?;
+        let minute = number(14..16)?;
+        literal(16, b':')?;
+        let second = number(17..19)?;
+        let mut index = 19;
+        let mut nanosecond = 0;
+        if bytes[index] == b'.' {
+          let digits = bytes[index + 1..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+          if !(1..=6).contains(&digits) {return Err(TimeError::Fraction)}
+          nanosecond = number(index + 1..index + 1 + digits)? * 10u32.pow(9 - digits as u32);
+          index += 1 + digits;
+        }
+        let offset = match &bytes[index..] {
+          b"Z" => 0,
+          [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let hours = number(index + 1..index + 3).map_err(|_| TimeError::TimeZone)?;
+            let minutes = number(index + 4..index + 6).map_err(|_| TimeError::TimeZone)?;
+            if hours > 23 || minutes > 59 {return Err(TimeError::TimeZone)}
+            let offset = (hours * 3600 + minutes * 60) as i32;
+            if *sign == b'-' {-offset} else {offset}
+          },
+          _ => return Err(TimeError::TimeZone),
+        };
+        TimeComponents {
+          format: TimeFormat::Extended,
+          year, month, day, hour, minute, second,
+          nanosecond,
+          offset: Some(offset),
+        }
+      },
+      _ => return Err(TimeError::Length),
+    };
+    components.validate()?;
+    Ok(components)
+  }
+}
+impl From<Time> for Item {
+  fn from(value: Time) -> Item {
+    Item::Ascii(value.0.into())
+  }
+}
+impl TryFrom<Item> for Time {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    match value {
+      Item::Ascii(vec) => Self::new(vec.into()).map_err(|_| WrongFormat),
+      _ => Err(WrongFormat),
+    }
+  }
+}
+impl std::fmt::Display for Time {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", Char::chars_to_str(&self.0))
+  }
+}
+
+/// ## TIME FORMAT
+/// 
+/// The forms in which a [TIME] may be written.
+/// 
+/// [TIME]: Time
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeFormat {
+  /// ### 12-BYTE FORMAT
+  /// 
+  /// YYMMDDhhmmss
+  /// 
+  /// The two digit year is interpreted as 1969 to 2068.
+  Short,
+
+  /// ### 16-BYTE FORMAT
+  /// 
+  /// YYYYMMDDhhmmsscc
+  Long,
+
+  /// ### EXTENDED FORMAT
+  /// 
+  /// YYYY-MM-DDThh:mm:ss.sTZD, as defined by SEMI E148.
+  Extended,
+}
+
+/// ## TIME ERROR
+/// 
+/// The rule of a [Time Format] which a [TIME] violates.
+/// 
+/// [TIME]:        Time
+/// [Time Format]: TimeFormat
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeError {
+  /// ### LENGTH
+  /// 
+  /// The length matches none of the [Time Format]s.
+  /// 
+  /// [Time Format]: TimeFormat
+  Length,
+
+  /// ### DIGIT
+  /// 
+  /// A digit was expected at the given byte index.
+  Digit(usize),
+
+  /// ### SEPARATOR
+  /// 
+  /// A separator was expected at the given byte index.
+  Separator(usize),
+
+  /// ### FRACTION
+  /// 
+  /// The fraction of a second has too few or too many digits.
+  Fraction,
+
+  /// ### TIME ZONE
+  /// 
+  /// The time zone designator is missing or invalid.
+  TimeZone,
+
+  /// ### YEAR
+  /// 
+  /// The year is out of range of the [Time Format].
+  /// 
+  /// [Time Format]: TimeFormat
+  Year,
+
+  /// ### MONTH
+  Month,
+
+  /// ### DAY
+  /// 
+  /// The day is out of range of the particular month.
+  Day,
+
+  /// ### HOUR
+  Hour,
+
+  /// ### MINUTE
+  Minute,
+
+  /// ### SECOND
+  Second,
+}
+
+/// ## TIME COMPONENTS
+/// 
+/// The parsed contents of a [TIME], shared by its conversions.
+/// 
+/// [TIME]: Time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct TimeComponents {
+  pub format: TimeFormat,
+  pub year: i32,
+  pub month: u32,
+  pub day: u32,
+  pub hour: u32,
+  pub minute: u32,
+  pub second: u32,
+  pub nanosecond: u32,
+  /// Offset from UTC in seconds, known only in the [Extended] format.
+  /// 
+  /// [Extended]: TimeFormat::Extended
+  pub offset: Option<i32>,
+}
+impl TimeComponents {
+  /// ### VALIDATE
+  /// 
+  /// Checks that each component lies within its allowed range, including
+  /// the number of days in the particular month.
+  pub fn validate(&self) -> Result<(), TimeError> {
+    let leap = (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0;
+    let days = match self.month {
+      1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+      4 | 6 | 9 | 11 => 30,
+      2 if leap => 29,
+      2 => 28,
+      _ => return Err(TimeError::Month),
+    };
+    if !(0..=9999).contains(&self.year)    {return Err(TimeError::Year)}
+    if !(1..=days).contains(&self.day)     {return Err(TimeError::Day)}
+    if self.hour >= 24                     {return Err(TimeError::Hour)}
+    if self.minute >= 60                   {return Err(TimeError::Minute)}
+    if self.second >= 60                   {return Err(TimeError::Second)}
+    if self.nanosecond >= 1_000_000_000    {return Err(TimeError::Fraction)}
+    Ok(())
+  }
+
+  /// ### COMPONENTS -> TIME
+  /// 
+  /// Writes the components in their [Time Format], or results in a
+  /// [Time Error] if they cannot be represented by it.
+  /// 
+  /// [Time Format]: TimeFormat
+  /// [Time Error]:  TimeError
+  pub fn to_time(self) -> Result<Time, TimeError> {
+    self.validate()?;
+    let text = match self.format {
+      TimeFormat::Short => {
+        if !(1969..=2068).contains(&self.year) {return Err(TimeError::Year)}
+        format!(
+          "{:02}{:02}{:02}{:02}{:02}{:02}",
+          self.year % 100, self.month, self.day, self.hour, self.minute, self.second,
+        )
+      },
+      TimeFormat::Long => format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}{:02}",
+        self.year, self.month, self.day, self.hour, self.minute, self.second,
+        self.nanosecond / 10_000_000,
+      ),
+      TimeFormat::Extended => {
+        let offset = self.offset.ok_or(TimeError::TimeZone)?;
+        let fraction = format!("{:06}", self.nanosecond / 1_000);
+        let fraction = match fraction.trim_end_matches('0') {
+          "" => "0",
+          trimmed => trimmed,
+        };
+        let zone = match offset {
+          0 => "Z".to_string(),
+          _ => format!(
+            "{}{:02}:{:02}",
+            if offset < 0 {'-'} else {'+'}, offset.abs() / 3600, offset.abs() % 3600 / 60,
+          ),
+        };
+        format!(
+          "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{}{}",
+          self.year, self.month, self.day, self.hour, self.minute, self.second,
+          fraction, zone,
+        )
+      },
+    };
+    Ok(Time(Char::safe_str_to_chars(&text)))
+  }
+}
 
 /// ## TID
 ///
@@ -3874,11 +9184,46 @@ singleformat_vec!{Time, Ascii}
 ///
 /// #### Used By
 ///
-/// - S10F1, S10F3, S10F5, S10F7
+/// - [S10F1], [S10F3], [S10F5], [S10F7]
+/// 
+/// [S10F1]: crate::messages::s10::TerminalRequest
+/// [S10F3]: crate::messages::s10::TerminalDisplaySingle
+/// [S10F5]: crate::messages::s10::TerminalDisplayMultiBlock
+/// [S10F7]: crate::messages::s10::MultiBlockNotAllowed
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct TerminalID(pub u8);
 singleformat!{TerminalID, Bin}
 
+/// ## TIMESTAMP
+/// 
+/// Time at which an exception occurred or was cleared, in any of the
+/// formats of [TIME].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S5F9], [S5F11], [S5F15]
+/// 
+/// [TIME]:  Time
+/// [S5F9]:  crate::messages::s5::ExceptionPostNotify
+/// [S5F11]: crate::messages::s5::ExceptionClearNotify
+/// [S5F15]: crate::messages::s5::ExceptionRecoveryCompleteNotify
+#[derive(Clone, Debug)]
+pub struct TimeStamp(pub Time);
+impl From<TimeStamp> for Item {
+  fn from(value: TimeStamp) -> Item {
+    value.0.into()
+  }
+}
+impl TryFrom<Item> for TimeStamp {
+  type Error = Error;
+
+  fn try_from(value: Item) -> Result<Self, Self::Error> {
+    Ok(Self(value.try_into()?))
+  }
+}
+
 /// ## TOTSMP
 /// 
 /// Total samples to be made.
@@ -3888,9 +9233,10 @@ singleformat!{TerminalID, Bin}
 /// #### Used By
 /// 
 /// - [S2F23]
-/// - S17F5
+/// - [S17F5]
 /// 
 /// [S2F23]: crate::messages::s2::TraceInitializeSend
+/// [S17F5]: crate::messages::s17::TraceCreateRequest
 #[derive(Clone, Debug)]
 pub enum TotalSamples {
   Ascii(Vec<Char>),
@@ -3905,6 +9251,25 @@ pub enum TotalSamples {
 }
 multiformat_ascii!{TotalSamples, I1, I2, I4, I8, U1, U2, U4, U8}
 
+/// ## TRAUTOD
+/// 
+/// **Trace Auto Delete**
+/// 
+/// Whether a trace is to be deleted automatically once it has completed
+/// its [TOTSMP].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S17F5]
+/// 
+/// [TOTSMP]: TotalSamples
+/// [S17F5]:  crate::messages::s17::TraceCreateRequest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TraceAutoDelete(pub bool);
+singleformat!{TraceAutoDelete, Bool}
+
 /// ## TRID
 /// 
 /// Trace request ID.
@@ -3914,10 +9279,21 @@ multiformat_ascii!{TotalSamples, I1, I2, I4, I8, U1, U2, U4, U8}
 /// #### Used By
 /// 
 /// - [S2F23]
-/// - S6F1, S6F27, S6F28, S6F29, S6F30
-/// - S17F5, S17F6, S17F7, S17F8, S17F13, S17F14
-/// 
-/// [S2F23]: crate::messages::s2::TraceInitializeSend
+/// - [S6F1], [S6F27], [S6F28], [S6F29], [S6F30]
+/// - [S17F5], [S17F6], [S17F7], [S17F8], [S17F13], [S17F14]
+/// 
+/// [S2F23]:  crate::messages::s2::TraceInitializeSend
+/// [S6F1]:   crate::messages::s6::TraceDataSend
+/// [S6F27]:  crate::messages::s6::TraceReportSend
+/// [S6F28]:  crate::messages::s6::TraceReportSendAcknowledge
+/// [S6F29]:  crate::messages::s6::TraceReportRequest
+/// [S6F30]:  crate::messages::s6::TraceReportData
+/// [S17F5]:  crate::messages::s17::TraceCreateRequest
+/// [S17F6]:  crate::messages::s17::TraceCreateAcknowledge
+/// [S17F7]:  crate::messages::s17::TraceDeleteRequest
+/// [S17F8]:  crate::messages::s17::TraceDeleteAcknowledge
+/// [S17F13]: crate::messages::s17::TraceResetRequest
+/// [S17F14]: crate::messages::s17::TraceResetAcknowledge
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TraceRequestID {
   Ascii(Vec<Char>),
@@ -3975,11 +9351,29 @@ pub enum TransferStatusOutputPort {
 }
 singleformat_enum!{TransferStatusOutputPort, Bin}
 
+/// ## UNFLEN
+/// 
+/// **Unformatted Length**
+/// 
+/// Length of an unformatted process program in bytes.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F34]
+/// 
+/// [S7F34]: crate::messages::s7::ProcessProgramAvailabilityData
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnformattedLength(pub u64);
+nonnegative!{UnformattedLength, u64}
+
 /// ## UNITS
 /// 
 /// Units identifier.
 /// 
-/// TODO: Implement this variable using the units module.
+/// The contents may be parsed into a typed [Units Expression] with
+/// [expression].
 /// 
 /// -------------------------------------------------------------------------
 /// 
@@ -3987,15 +9381,35 @@ singleformat_enum!{TransferStatusOutputPort, Bin}
 /// 
 /// - [S1F12], [S1F22]
 /// - [S2F30], [S2F38]
-/// - S7F22
-/// 
-/// [S1F12]: crate::messages::s1::StatusVariableNamelistReply
-/// [S1F22]: crate::messages::s1::DataVariableNamelist
-/// [S2F30]: crate::messages::s2::EquipmentConstantNamelist
-/// [S2F38]: crate::messages::s2::EnableDisableEventReportAcknowledge
+/// - [S7F22]
+/// 
+/// [Units Expression]: crate::units::Expression
+/// [expression]:       Units::expression
+/// [S1F12]:            crate::messages::s1::StatusVariableNamelistReply
+/// [S1F22]:            crate::messages::s1::DataVariableNamelist
+/// [S2F30]:            crate::messages::s2::EquipmentConstantNamelist
+/// [S2F38]:            crate::messages::s2::EnableDisableEventReportAcknowledge
+/// [S7F22]:            crate::messages::s7::ProcessCapabilitiesData
 #[derive(Clone, Debug)]
 pub struct Units(pub Vec<Char>);
 singleformat_vec!{Units, Ascii}
+impl Units {
+  /// ### UNITS EXPRESSION
+  /// 
+  /// Parses the [UNITS] into a [Units Expression], or results in [None] if
+  /// it is not a valid units identifier.
+  /// 
+  /// [UNITS]:            Units
+  /// [Units Expression]: crate::units::Expression
+  pub fn expression(&self) -> Option<crate::units::Expression> {
+    Char::chars_to_str(&self.0).parse().ok()
+  }
+}
+impl From<crate::units::Expression> for Units {
+  fn from(expression: crate::units::Expression) -> Self {
+    Self(Char::safe_str_to_chars(&expression.to_string()))
+  }
+}
 
 /// ## UPPERDB
 /// 
@@ -4029,6 +9443,42 @@ pub enum UpperDeadband {
 }
 multiformat_vec!{UpperDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
+/// ## V
+/// 
+/// **Variable Data**
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F11], [S6F16]
+/// - [S6F13], [S6F20], [S6F22]
+/// 
+/// [S6F11]: crate::messages::s6::EventReport
+/// [S6F13]: crate::messages::s6::AnnotatedEventReport
+/// [S6F16]: crate::messages::s6::EventReportData
+/// [S6F20]: crate::messages::s6::IndividualReportData
+/// [S6F22]: crate::messages::s6::AnnotatedIndividualReportData
+#[derive(Clone, Debug)]
+pub enum VariableValue {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{VariableValue, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+
 /// ## VID
 /// 
 /// **Variable ID**
@@ -4039,9 +9489,9 @@ multiformat_vec!{UpperDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4,
 /// 
 /// - [S1F21], [S1F22], [S1F24]
 /// - [S2F33], [S2F45], [S2F46], [S2F47], [S2F48]
-/// - S6F13, S6F18, S6F22
+/// - [S6F13], [S6F18], [S6F22]
 /// - S16F9
-/// - S17F1
+/// - [S17F1]
 /// 
 /// [S1F21]: crate::messages::s1::DataVariableNamelistRequest
 /// [S1F22]: crate::messages::s1::DataVariableNamelist
@@ -4051,6 +9501,10 @@ multiformat_vec!{UpperDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4,
 /// [S2F46]: crate::messages::s2::VariableLimitAttributeAcknowledge
 /// [S2F47]: crate::messages::s2::VariableLimitAttributeRequest
 /// [S2F48]: crate::messages::s2::VariableLimitAttributeSend
+/// [S6F13]: crate::messages::s6::AnnotatedEventReport
+/// [S6F18]: crate::messages::s6::AnnotatedEventReportData
+/// [S6F22]: crate::messages::s6::AnnotatedIndividualReportData
+/// [S17F1]: crate::messages::s17::DataReportCreateRequest
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum VariableID {
   Ascii(Vec<Char>),
@@ -4090,3 +9544,78 @@ pub enum VariableLimitAttributeAcknowledgeCode {
   CannotPerformNow = 2,
 }
 singleformat_enum!{VariableLimitAttributeAcknowledgeCode, Bin}
+
+/// ## XDIES
+/// 
+/// **Die Size, X**
+/// 
+/// Die size or index along the X axis, in [DUTMS].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F4]
+/// 
+/// [DUTMS]: DieUnitsOfMeasure
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug)]
+pub enum DieSizeX {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+  F4(f32),
+  F8(f64),
+}
+multiformat!{DieSizeX, U1, U2, U4, U8, F4, F8}
+
+/// ## XYPOS
+/// 
+/// **X/Y Position**
+/// 
+/// Position of a die in die coordinates, given as the X and Y coordinates.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F11], [S12F18]
+/// 
+/// [S12F11]: crate::messages::s12::MapDataSendType3
+/// [S12F18]: crate::messages::s12::MapDataType3
+#[derive(Clone, Debug)]
+pub enum XYPosition {
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+}
+multiformat_vec!{XYPosition, I1, I2, I4, I8}
+
+/// ## YDIES
+/// 
+/// **Die Size, Y**
+/// 
+/// Die size or index along the Y axis, in [DUTMS].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S12F1], [S12F4]
+/// 
+/// [DUTMS]: DieUnitsOfMeasure
+/// [S12F1]: crate::messages::s12::MapSetupDataSend
+/// [S12F4]: crate::messages::s12::MapSetupData
+#[derive(Clone, Copy, Debug)]
+pub enum DieSizeY {
+  U1(u8),
+  U2(u16),
+  U4(u32),
+  U8(u64),
+  F4(f32),
+  F8(f64),
+}
+multiformat!{DieSizeY, U1, U2, U4, U8, F4, F8}
@@ -0,0 +1,169 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # JSON MAPPING
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Writes [Item]s and [Message]s as JSON, such that captured traffic may be
+//! handed to tools which do not understand SECS-II, such as log aggregators
+//! or web-based log viewers.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A [Message] is written as an object with the following members:
+//! 
+//! - `sxfy` - The stream and function, as `"S1F13"`.
+//! - `stream` - The stream.
+//! - `function` - The function.
+//! - `w` - Whether a reply is requested.
+//! - `body` - The [Item] of the message as a [JSON Item], or `null`.
+//! 
+//! [Item]:      Item
+//! [Message]:   Message
+//! [JSON Item]: JsonItem
+
+use std::fmt::{Display, Formatter};
+use crate::{Item, Message};
+
+/// ## JSON ITEM
+/// 
+/// Writes an [Item] as a JSON object with the members `format`, the name of
+/// its format as in SML, and `value`:
+/// 
+/// - An array of items, for a list.
+/// - A string, for ASCII and JIS-8 items.
+/// - An array of numbers, for binary, integer, and floating point items,
+///   with `null` in place of values which are not finite.
+/// - An array of booleans, for boolean items.
+/// - An array of bytes, for localized string items, with the member
+///   `encoding`, and for items of an unrecognized format, with the member
+///   `code`.
+/// 
+/// [Item]: Item
+pub struct JsonItem<'a>(pub &'a Item);
+impl Display for JsonItem<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn numbers<T: Display>(f: &mut Formatter<'_>, format: &str, values: impl IntoIterator<Item = T>) -> std::fmt::Result {
+      write!(f, "\"format\":\"{format}\",\"value\":[")?;
+      for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {write!(f, ",")?}
+        write!(f, "{value}")?;
+      }
+      write!(f, "]")
+    }
+    fn finite(is_finite: bool, value: impl std::fmt::Debug) -> String {
+      if is_finite {format!("{value:?}")} else {"null".to_string()}
+    }
+    write!(f, "{{")?;
+    match self.0 {
+      Item::List(items) => {
+        write!(f, "\"format\":\"L\",\"value\":[")?;
+        for (index, item) in items.iter().enumerate() {
+          if index > 0 {write!(f, ",")?}
+          write!(f, "{}", JsonItem(item))?;
+        }
+        write!(f, "]")?;
+      },
+      Item::Ascii(chars) => {
+        let text: String = chars.iter().map(|c| u8::from(*c) as char).collect();
+        write!(f, "\"format\":\"A\",\"value\":{}", JsonString(&text))?;
+      },
+      Item::Jis8(text) => write!(f, "\"format\":\"J\",\"value\":{}", JsonString(text))?,
+      Item::Local(header, bytes) => {
+        write!(f, "\"encoding\":{},", *header as u16)?;
+        numbers(f, "LOCAL", bytes)?;
+      },
+      Item::Bin(values)  => numbers(f, "B", values)?,
+      Item::Bool(values) => numbers(f, "BOOL", values)?,
+      Item::I1(values)   => numbers(f, "I1", values)?,
+      Item::I2(values)   => numbers(f, "I2", values)?,
+      Item::I4(values)   => numbers(f, "I4", values)?,
+      Item::I8(values)   => numbers(f, "I8", values)?,
+      Item::U1(values)   => numbers(f, "U1", values)?,
+      Item::U2(values)   => numbers(f, "U2", values)?,
+      Item::U4(values)   => numbers(f, "U4", values)?,
+      Item::U8(values)   => numbers(f, "U8", values)?,
+      Item::F4(values)   => numbers(f, "F4", values.iter().map(|value| finite(value.is_finite(), value)))?,
+      Item::F8(values)   => numbers(f, "F8", values.iter().map(|value| finite(value.is_finite(), value)))?,
+      Item::Raw(code, bytes) => {
+        write!(f, "\"code\":{code},")?;
+        numbers(f, "RAW", bytes)?;
+      },
+    }
+    write!(f, "}}")
+  }
+}
+
+/// ## JSON MESSAGE
+/// 
+/// Writes a [Message] as a JSON object, as described in the
+/// [module documentation].
+/// 
+/// [Message]:              Message
+/// [module documentation]: self
+pub struct JsonMessage<'a>(pub &'a Message);
+impl Display for JsonMessage<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{{")?;
+    self.fmt_members(f)?;
+    write!(f, "}}")
+  }
+}
+impl JsonMessage<'_> {
+  /// ### FORMAT MEMBERS
+  /// 
+  /// Writes the members of the object without the enclosing braces, such
+  /// that they may be embedded in a larger object.
+  pub fn fmt_members(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let message: &Message = self.0;
+    write!(
+      f,
+      "\"sxfy\":\"S{}F{}\",\"stream\":{},\"function\":{},\"w\":{},\"body\":",
+      message.stream, message.function, message.stream, message.function, message.w,
+    )?;
+    match message.text {
+      Some(ref item) => write!(f, "{}", JsonItem(item)),
+      None => write!(f, "null"),
+    }
+  }
+}
+
+/// ## JSON STRING
+/// 
+/// Writes a string as a quoted JSON string, escaping characters as needed.
+pub struct JsonString<'a>(pub &'a str);
+impl Display for JsonString<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in self.0.chars() {
+      match c {
+        '"'  => write!(f, "\\\"")?,
+        '\\' => write!(f, "\\\\")?,
+        '\n' => write!(f, "\\n")?,
+        '\r' => write!(f, "\\r")?,
+        '\t' => write!(f, "\\t")?,
+        c if (c as u32) < 0x20 || c == '\u{7F}' => write!(f, "\\u{:04x}", c as u32)?,
+        c => write!(f, "{c}")?,
+      }
+    }
+    write!(f, "\"")
+  }
+}
@@ -78,10 +78,19 @@
 #![allow(clippy::collapsible_match)]
 #![allow(clippy::type_complexity)]
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod datetime;
 pub mod format;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub mod fuzz;
 pub mod items;
+pub mod json;
 pub mod messages;
+#[cfg(feature = "spill")]
+pub mod spill;
 pub mod units;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
 
 use encoding::{all::ISO_2022_JP, Encoding};
 use items::{Char};
@@ -139,6 +148,32 @@ pub struct Message {
   /// - [Some] - Indicates a message with contents after the header.
   pub text: Option<Item>,
 }
+impl Message {
+  /// ### IS ABORT
+  /// 
+  /// Whether the [Message] is an Abort Transaction message, SxF0, of any
+  /// [Stream], sent in lieu of an expected reply.
+  /// 
+  /// [Message]: Message
+  /// [Stream]:  Message::stream
+  pub fn is_abort(&self) -> bool {
+    self.function == 0 && !self.w && self.text.is_none()
+  }
+
+  /// ### CHECK REPLY
+  /// 
+  /// Validates the [Reply Bit] of the [Message] against the rule of the
+  /// standard for its [Stream] and [Function], as listed in the [Registry].
+  /// 
+  /// [Message]:   Message
+  /// [Stream]:    Message::stream
+  /// [Function]:  Message::function
+  /// [Reply Bit]: Message::w
+  /// [Registry]:  messages::registry
+  pub fn check_reply(&self) -> Result<(), Error> {
+    messages::registry::check_reply(self)
+  }
+}
 impl std::fmt::Display for Message {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "S{}F{}", self.stream, self.function)?;
@@ -151,6 +186,37 @@ impl std::fmt::Display for Message {
     Ok(())
   }
 }
+/// ## TEXT -> MESSAGE
+/// 
+/// Parses a [Message] from the SML-like text written by its [Display]
+/// implementation, such as `S1F13 W <L [0]>`, optionally terminated by a
+/// period.
+/// 
+/// [Message]: Message
+/// [Display]: std::fmt::Display
+impl std::str::FromStr for Message {
+  type Err = Error;
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    let text = text.trim();
+    let text = text.strip_suffix('.').unwrap_or(text);
+    let end = text.find(|c: char| c.is_whitespace() || c == '<').unwrap_or(text.len());
+    let (header, rest) = text.split_at(end);
+    let (stream, function) = header
+      .strip_prefix(['S', 's'])
+      .and_then(|header| header.split_once(['F', 'f']))
+      .ok_or(Error::InvalidText)?;
+    let stream: u8 = stream.parse().ok().filter(|stream| *stream < 128).ok_or(Error::InvalidText)?;
+    let function: u8 = function.parse().map_err(|_| Error::InvalidText)?;
+    let rest = rest.trim_start();
+    let (w, rest) = match rest.strip_prefix(['W', 'w']) {
+      Some(rest) => (true, rest.trim_start()),
+      None => (false, rest),
+    };
+    let text = if rest.is_empty() {None} else {Some(rest.parse()?)};
+    Ok(Message {stream, function, w, text})
+  }
+}
 
 /// ## DATA CONVERSION ERROR
 /// 
@@ -173,6 +239,14 @@ pub enum Error {
   /// invalid format.
   InvalidText,
 
+  /// ### LIMIT EXCEEDED
+  /// 
+  /// Binary data was attempted to be converted into an [Item] despite
+  /// exceeding the [Decode Limits] in effect.
+  /// 
+  /// [Decode Limits]: DecodeLimits
+  LimitExceeded,
+
   /// ### WRONG STREAM
   /// 
   /// A [Generic Message] was attempted to be converted into a specifc [Message]
@@ -212,6 +286,46 @@ pub enum Error {
   /// [Generic Message]: Message
   /// [Message Body]:    Message::text
   WrongFormat,
+
+  /// ### INVALID FORM
+  /// 
+  /// An [Item] was attempted to be converted into a specific data item
+  /// despite being of a form which the data item does not allow, such as a
+  /// list of items of mixed formats where identical formats are required.
+  /// 
+  /// [Item]: Item
+  InvalidForm,
+
+  /// ### INVALID LENGTH
+  /// 
+  /// A data item was attempted to be constructed from a number of elements
+  /// outside of the range allowed by its definition.
+  InvalidLength {
+    /// Number of elements provided.
+    length: usize,
+
+    /// Minimum number of elements allowed.
+    min: usize,
+
+    /// Maximum number of elements allowed.
+    max: usize,
+  },
+
+  /// ### MISSING FIELD
+  ///
+  /// A [Message] was attempted to be constructed without any of a group of
+  /// conditional fields, of which at least one must be provided.
+  ///
+  /// [Message]: messages
+  MissingField,
+
+  /// ### DUPLICATE ID
+  ///
+  /// A [Message] was attempted to be constructed with the same identifier
+  /// appearing more than once where identifiers are required to be unique.
+  ///
+  /// [Message]: messages
+  DuplicateID,
 }
 
 /// ## GENERIC ITEM
@@ -219,13 +333,14 @@ pub enum Error {
 /// 
 /// A packet of information of a particular [Format], which
 /// through the [List] format, is able to represent a tree-like structure
-/// of information. Each item comprises a [Vector] of a particular type,
-/// or sometimes a [String] of characters.
+/// of information. Each item comprises a [Vector] or [Payload] of a
+/// particular type, or sometimes a [String] of characters.
 /// 
-/// [Format]: crate::format
-/// [List]:   Item::List
-/// [Vector]: Vec
-/// [String]: String
+/// [Format]:  crate::format
+/// [List]:    Item::List
+/// [Vector]:  Vec
+/// [Payload]: Payload
+/// [String]:  String
 #[repr(u8)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Item {
@@ -245,7 +360,7 @@ pub enum Item {
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// ASCII character string.
-  Ascii(Vec<Char>) = format::ASCII,
+  Ascii(Payload<Char>) = format::ASCII,
 
   /// ### JIS-8
   /// **Based on SEMI E5§9.2.2**
@@ -266,74 +381,89 @@ pub enum Item {
   /// 
   /// Single-byte quanitity where the value can be anything and does not
   /// otherwise have a strictly defined meaning.
-  Bin(Vec<u8>) = format::BIN,
+  Bin(Payload<u8>) = format::BIN,
 
   /// ### BOOLEAN
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// Single-byte quantity where a value of 0 is equivalent to 'false' and any
   /// non-zero value is equivalent to 'true'.
-  Bool(Vec<bool>) = format::BOOL,
+  Bool(Payload<bool>) = format::BOOL,
 
   /// ### 1-BYTE SIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 1-byte two's compliment integer.
-  I1(Vec<i8>) = format::I1,
+  I1(Payload<i8>) = format::I1,
 
   /// ### 2-BYTE SIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 2-byte two's compliment integer.
-  I2(Vec<i16>) = format::I2,
+  I2(Payload<i16>) = format::I2,
 
   /// ### 4-BYTE SIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 4-byte two's compliment integer.
-  I4(Vec<i32>) = format::I4,
+  I4(Payload<i32>) = format::I4,
 
   /// ### 8-BYTE SIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 8-byte two's compliment integer.
-  I8(Vec<i64>) = format::I8,
+  I8(Payload<i64>) = format::I8,
 
   /// ### 1-BYTE UNSIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 1-byte integer.
-  U1(Vec<u8>) = format::U1,
+  U1(Payload<u8>) = format::U1,
 
   /// ### 2-BYTE UNSIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 2-byte integer.
-  U2(Vec<u16>) = format::U2,
+  U2(Payload<u16>) = format::U2,
 
   /// ### 4-BYTE UNSIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 4-byte integer.
-  U4(Vec<u32>) = format::U4,
+  U4(Payload<u32>) = format::U4,
 
   /// ### 8-BYTE UNSIGNED INTEGER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 8-byte integer.
-  U8(Vec<u64>) = format::U8,
+  U8(Payload<u64>) = format::U8,
 
   /// ### 4-BYTE FLOATING POINT NUMBER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 4-byte IEEE-754 floating point number.
-  F4(Vec<f32>) = format::F4,
+  F4(Payload<f32>) = format::F4,
 
   /// ### 8-BYTE FLOATING POINT NUMBER
   /// **Based on SEMI E5§9.2.2**
   /// 
   /// 8-byte IEEE-754 floating point number.
-  F8(Vec<f64>) = format::F8,
+  F8(Payload<f64>) = format::F8,
+
+  /// ### RAW
+  /// 
+  /// An [Item] of a [Format] which is not recognized, such as one which is
+  /// undefined or vendor-specific, retained as its Format Code and its
+  /// undecoded bytes so that it is reproduced exactly when serialized.
+  /// 
+  /// The Format Code is stored as the upper six bits of the Format Byte, in
+  /// the same manner as the constants of the [Format] module, and must not
+  /// be one which is defined, as checked by the [Raw Item] function.
+  /// 
+  /// [Item]:     Item
+  /// [Format]:   crate::format
+  /// [Raw Item]: Item::raw
+  Raw(u8, Vec<u8>) = 0b111111_11,
 }
 impl Item {
   /// ### SINGLE BINARY ITEM
@@ -345,7 +475,7 @@ impl Item {
   /// [Item]:   Item
   /// [Binary]: Item::Bin
   pub fn bin(value: u8) -> Self {
-    Self::Bin(vec![value])
+    Self::Bin(Payload::Single(value))
   }
 
   /// ### SINGLE BOOLEAN ITEM
@@ -357,7 +487,7 @@ impl Item {
   /// [Item]:    Item
   /// [Boolean]: Item::Bool
   pub fn bool(value: bool) -> Self {
-    Self::Bool(vec![value])
+    Self::Bool(Payload::Single(value))
   }
 
   /// ### SINGLE 1-BYTE SIGNED INTEGER ITEM
@@ -369,7 +499,7 @@ impl Item {
   /// [Item]:                  Item
   /// [1-byte Signed Integer]: Item::I1
   pub fn i1(value: i8) -> Self {
-    Self::I1(vec![value])
+    Self::I1(Payload::Single(value))
   }
 
   /// ### SINGLE 2-BYTE SIGNED INTEGER ITEM
@@ -381,7 +511,7 @@ impl Item {
   /// [Item]:                  Item
   /// [2-byte Signed Integer]: Item::I2
   pub fn i2(value: i16) -> Self {
-    Self::I2(vec![value])
+    Self::I2(Payload::Single(value))
   }
 
   /// ### SINGLE 4-BYTE SIGNED INTEGER ITEM
@@ -393,7 +523,7 @@ impl Item {
   /// [Item]:                  Item
   /// [4-byte Signed Integer]: Item::I4
   pub fn i4(value: i32) -> Self {
-    Self::I4(vec![value])
+    Self::I4(Payload::Single(value))
   }
 
   /// ### SINGLE 8-BYTE SIGNED INTEGER ITEM
@@ -405,7 +535,7 @@ impl Item {
   /// [Item]:                  Item
   /// [8-byte Signed Integer]: Item::I8
   pub fn i8(value: i64) -> Self {
-    Self::I8(vec![value])
+    Self::I8(Payload::Single(value))
   }
 
   /// ### SINGLE 1-BYTE UNSIGNED INTEGER ITEM
@@ -417,7 +547,7 @@ impl Item {
   /// [Item]:                    Item
   /// [1-byte Unsigned Integer]: Item::U1
   pub fn u1(value: u8) -> Self {
-    Self::U1(vec![value])
+    Self::U1(Payload::Single(value))
   }
 
   /// ### SINGLE 2-BYTE UNSIGNED INTEGER ITEM
@@ -429,7 +559,7 @@ impl Item {
   /// [Item]:                    Item
   /// [2-byte Unsigned Integer]: Item::U2
   pub fn u2(value: u16) -> Self {
-    Self::U2(vec![value])
+    Self::U2(Payload::Single(value))
   }
 
   /// ### SINGLE 4-BYTE UNSIGNED INTEGER ITEM
@@ -441,7 +571,7 @@ impl Item {
   /// [Item]:                    Item
   /// [4-byte Unsigned Integer]: Item::U4
   pub fn u4(value: u32) -> Self {
-    Self::U4(vec![value])
+    Self::U4(Payload::Single(value))
   }
 
   /// ### SINGLE 8-BYTE UNSIGNED INTEGER ITEM
@@ -453,7 +583,7 @@ impl Item {
   /// [Item]:                    Item
   /// [8-byte Unsigned Integer]: Item::U8
   pub fn u8(value: u64) -> Self {
-    Self::U8(vec![value])
+    Self::U8(Payload::Single(value))
   }
 
   /// ### SINGLE 4-BYTE FLOATING POINT NUMBER ITEM
@@ -465,7 +595,7 @@ impl Item {
   /// [Item]:                         Item
   /// [4-byte Floating Point Number]: Item::F4
   pub fn f4(value: f32) -> Self {
-    Self::F4(vec![value])
+    Self::F4(Payload::Single(value))
   }
 
   /// ### SINGLE 8-BYTE FLOATING POINT NUMBER ITEM
@@ -477,7 +607,224 @@ impl Item {
   /// [Item]:                         Item
   /// [8-byte Floating Point Number]: Item::F8
   pub fn f8(value: f64) -> Self {
-    Self::F8(vec![value])
+    Self::F8(Payload::Single(value))
+  }
+
+  /// ### RAW ITEM
+  /// 
+  /// Constructs a [Raw] [Item], resulting in an error if the Format Code is
+  /// one which is defined, as its bytes would then be read as that format.
+  /// 
+  /// [Item]: Item
+  /// [Raw]:  Item::Raw
+  pub fn raw(format: u8, vec: Vec<u8>) -> Result<Self, Error> {
+    match format::is_defined(format) {
+      true => Err(Error::InvalidForm),
+      false => Ok(Self::Raw(format & 0b111111_00, vec)),
+    }
+  }
+
+  /// ### SHARED ITEM
+  /// 
+  /// Moves the contents of all [ASCII] and [Binary] [Item]s in the tree into
+  /// [Shared] storage, so that identifiers which are cached and repeated
+  /// across many [Message]s are cloned by reference counting.
+  /// 
+  /// [Item]:    Item
+  /// [ASCII]:   Item::Ascii
+  /// [Binary]:  Item::Bin
+  /// [Message]: Message
+  /// [Shared]:  Payload::Shared
+  pub fn into_shared(self) -> Self {
+    match self {
+      Self::List(vec) => Self::List(vec.into_iter().map(Item::into_shared).collect()),
+      Self::Ascii(payload) => Self::Ascii(payload.into_shared()),
+      Self::Bin(payload) => Self::Bin(payload.into_shared()),
+      item => item,
+    }
+  }
+
+  /// ### LIST FROM ITERATOR
+  /// 
+  /// Constructs a [List] from anything which can be converted into [Item]s,
+  /// such as data items defined in the [Items] module.
+  /// 
+  /// [Item]:  Item
+  /// [List]:  Item::List
+  /// [Items]: crate::items
+  pub fn list_from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator,
+    I::Item: Into<Item>,
+  {
+    Self::List(iter.into_iter().map(Into::into).collect())
+  }
+
+  /// ### ITERATE LIST
+  /// 
+  /// Iterates over the [Item]s contained in a [List].
+  /// 
+  /// Any other [Item] contains no [Item]s, and yields nothing.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub fn iter(&self) -> std::slice::Iter<'_, Item> {
+    match self {
+      Self::List(vec) => vec.iter(),
+      _ => [].iter(),
+    }
+  }
+
+  /// ### ITERATE LIST MUTABLY
+  /// 
+  /// Mutably iterates over the [Item]s contained in a [List].
+  /// 
+  /// Any other [Item] contains no [Item]s, and yields nothing.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Item> {
+    match self {
+      Self::List(vec) => vec.iter_mut(),
+      _ => [].iter_mut(),
+    }
+  }
+
+  /// ### SMALLEST FORMAT
+  /// 
+  /// Rewrites every integer [Item] in the tree into the smallest format of
+  /// the same signedness which is able to hold all of its values, so that
+  /// the [Item] is encoded as compactly as possible.
+  /// 
+  /// [Unsigned Integer] [Item]s remain unsigned and [Signed Integer] [Item]s
+  /// remain signed. All other [Item]s are unchanged.
+  /// 
+  /// [Item]:             Item
+  /// [Unsigned Integer]: Item::U1
+  /// [Signed Integer]:   Item::I1
+  pub fn into_smallest_format(self) -> Self {
+    fn unsigned<I: Iterator<Item = u64> + Clone>(values: I) -> Item {
+      let max = values.clone().max().unwrap_or(0);
+      if max <= u8::MAX as u64 {
+        Item::U1(values.map(|value| value as u8).collect())
+      } else if max <= u16::MAX as u64 {
+        Item::U2(values.map(|value| value as u16).collect())
+      } else if max <= u32::MAX as u64 {
+        Item::U4(values.map(|value| value as u32).collect())
+      } else {
+        Item::U8(values.collect())
+      }
+    }
+    fn signed<I: Iterator<Item = i64> + Clone>(values: I) -> Item {
+      let min = values.clone().min().unwrap_or(0);
+      let max = values.clone().max().unwrap_or(0);
+      if min >= i8::MIN as i64 && max <= i8::MAX as i64 {
+        Item::I1(values.map(|value| value as i8).collect())
+      } else if min >= i16::MIN as i64 && max <= i16::MAX as i64 {
+        Item::I2(values.map(|value| value as i16).collect())
+      } else if min >= i32::MIN as i64 && max <= i32::MAX as i64 {
+        Item::I4(values.map(|value| value as i32).collect())
+      } else {
+        Item::I8(values.collect())
+      }
+    }
+    match self {
+      Self::List(vec) => Self::List(vec.into_iter().map(Item::into_smallest_format).collect()),
+      Self::I2(vec) => signed(vec.iter().map(|&value| value as i64)),
+      Self::I4(vec) => signed(vec.iter().map(|&value| value as i64)),
+      Self::I8(vec) => signed(vec.iter().copied()),
+      Self::U2(vec) => unsigned(vec.iter().map(|&value| value as u64)),
+      Self::U4(vec) => unsigned(vec.iter().map(|&value| value as u64)),
+      Self::U8(vec) => unsigned(vec.iter().copied()),
+      item => item,
+    }
+  }
+
+  /// ### DEPTH
+  /// 
+  /// The number of levels in the tree of [Item]s, counting this [Item] as
+  /// depth 1, in the same manner as the [Decode Limits].
+  /// 
+  /// [Item]:          Item
+  /// [Decode Limits]: DecodeLimits::max_depth
+  pub fn depth(&self) -> usize {
+    match self {
+      Self::List(vec) => 1 + vec.iter().map(Item::depth).max().unwrap_or(0),
+      _ => 1,
+    }
+  }
+
+  /// ### NODE COUNT
+  /// 
+  /// The total number of [Item]s in the tree, including this [Item] and
+  /// every [List].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub fn node_count(&self) -> usize {
+    match self {
+      Self::List(vec) => 1 + vec.iter().map(Item::node_count).sum::<usize>(),
+      _ => 1,
+    }
+  }
+
+  /// ### ENCODED SIZE
+  /// 
+  /// The number of bytes the tree of [Item]s occupies when serialized into
+  /// binary data, computed without serializing it.
+  /// 
+  /// [Item]: Item
+  pub fn encoded_size(&self) -> usize {
+    let mut size: usize = 0;
+    // The tree is walked with an explicit stack, in the same manner as when
+    // it is serialized.
+    let mut stack: Vec<&Item> = vec![self];
+    while let Some(item) = stack.pop() {
+      size += item.shallow_size();
+      if let Self::List(vec) = item {stack.extend(vec);}
+    }
+    size
+  }
+
+  /// ### SHALLOW SIZE
+  /// 
+  /// The number of bytes an [Item] occupies when serialized into binary data,
+  /// excluding the [Item]s contained within it if it is a [List].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn shallow_size(&self) -> usize {
+    // Counts the bytes written by an encoder rather than keeping them.
+    struct Counter(usize);
+    impl encoding::ByteWriter for Counter {
+      fn write_byte(&mut self, _: u8) {self.0 += 1}
+      fn write_bytes(&mut self, v: &[u8]) {self.0 += v.len()}
+    }
+    let (len, data) = match self {
+      Self::List(vec)        => (vec.len(), 0),
+      Self::Ascii(vec)       => (vec.len(), vec.len()),
+      Self::Jis8(string)     => {
+        let mut counter = Counter(0);
+        let _ = ISO_2022_JP.encode_to(string, encoding::EncoderTrap::Ignore, &mut counter);
+        (counter.0, counter.0)
+      },
+      Self::Local(_, vec)    => (vec.len() + 2, vec.len() + 2),
+      Self::Bin(vec)         => (vec.len(), vec.len()),
+      Self::Bool(vec)        => (vec.len(), vec.len()),
+      Self::I1(vec)          => (vec.len(), vec.len()),
+      Self::I2(vec)          => (vec.len() * 2, vec.len() * 2),
+      Self::I4(vec)          => (vec.len() * 4, vec.len() * 4),
+      Self::I8(vec)          => (vec.len() * 8, vec.len() * 8),
+      Self::U1(vec)          => (vec.len(), vec.len()),
+      Self::U2(vec)          => (vec.len() * 2, vec.len() * 2),
+      Self::U4(vec)          => (vec.len() * 4, vec.len() * 4),
+      Self::U8(vec)          => (vec.len() * 8, vec.len() * 8),
+      Self::F4(vec)          => (vec.len() * 4, vec.len() * 4),
+      Self::F8(vec)          => (vec.len() * 8, vec.len() * 8),
+      Self::Raw(_, vec)      => (vec.len(), vec.len()),
+    };
+    let length_bytes = if len < 256 {1} else if len < 65536 {2} else {3};
+    1 + length_bytes + data
   }
 }
 impl std::fmt::Display for Item {
@@ -504,10 +851,10 @@ impl std::fmt::Display for Item {
             let byte: u8 = (*c).into();
             byte as char
           }).collect();
-          write!(f, "<A \"{}\">", s)
+          fmt_text(f, "A", &s)
         },
         Item::Jis8(s) => {
-          write!(f, "<J \"{}\">", s)
+          fmt_text(f, "J", s)
         },
         Item::Local(header, data) => {
           write!(f, "<LOCAL {:?} {:?}>", header, data)
@@ -596,79 +943,365 @@ impl std::fmt::Display for Item {
           }
           write!(f, " >")
         },
+        Item::Raw(format, vec) => {
+          write!(f, "<0o{:02o}", format >> 2)?;
+          for b in vec {
+            write!(f, " 0x{:02X}", b)?;
+          }
+          write!(f, " >")
+        },
       }
     }
 
+    /// Writes text as quoted runs, with any quotation mark written as a
+    /// number between them, such that it can be parsed back.
+    fn fmt_text(f: &mut std::fmt::Formatter<'_>, format: &str, text: &str) -> std::fmt::Result {
+      write!(f, "<{}", format)?;
+      if text.is_empty() {
+        write!(f, " \"\"")?;
+      }
+      for (index, run) in text.split('"').enumerate() {
+        if index > 0 {
+          write!(f, " 0x22")?;
+        }
+        if !run.is_empty() {
+          write!(f, " \"{}\"", run)?;
+        }
+      }
+      write!(f, ">")
+    }
+
     writeln!(f)?;
     fmt_item(self, f, 1)
   }
 }
+/// ## TEXT -> ITEM
+/// 
+/// Parses an [Item] from the SML-like text written by its [Display]
+/// implementation, such as `<L [2] <A "MDLN"> <U4 1 2>>`.
+/// 
+/// The element count of a [List] is optional, integers may be written in
+/// hexadecimal with a `0x` prefix, and adjacent quoted strings are joined,
+/// along with any character codes written as numbers between them, such as
+/// the `0x22` written in place of a quotation mark.
+/// [Localized Strings] are not supported, and a [Raw] Item written with an
+/// octal Format Code such as `<0o77 1 2>` is refused if the code is defined.
+/// 
+/// [Item]:              Item
+/// [List]:              Item::List
+/// [Raw]:               Item::Raw
+/// [Localized Strings]: Item::Local
+/// [Display]:           std::fmt::Display
+impl std::str::FromStr for Item {
+  type Err = Error;
+
+  /// ### TEXT -> ITEM
+  /// 
+  /// The text is read with an explicit stack rather than with recursion, and
+  /// results in an error of kind [Limit Exceeded] if it nests deeper than the
+  /// [Maximum Depth] of the default [Decode Limits].
+  /// 
+  /// [Limit Exceeded]: Error::LimitExceeded
+  /// [Maximum Depth]:  DecodeLimits::max_depth
+  /// [Decode Limits]:  DecodeLimits
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    let max_depth: usize = DecodeLimits::default().max_depth;
+    // Each entry holds the items of a List whose closing bracket is yet to
+    // be read.
+    let mut stack: Vec<Vec<Item>> = vec![];
+    let mut rest: &str = text;
+    loop {
+      rest = rest.trim_start();
+      let item: Item = match rest.strip_prefix('>') {
+        // Close List
+        Some(next) if !stack.is_empty() => {
+          rest = next;
+          Item::List(stack.pop().unwrap_or_default())
+        },
+        // Open List, or Other Item
+        _ => {
+          if stack.len() + 1 > max_depth {return Err(Error::LimitExceeded)}
+          let (item, next) = parse_item(rest)?;
+          rest = next;
+          match item {
+            Some(item) => item,
+            None => {
+              stack.push(vec![]);
+              continue
+            },
+          }
+        },
+      };
+      match stack.last_mut() {
+        Some(items) => items.push(item),
+        None => {
+          if !rest.trim().is_empty() {return Err(Error::InvalidText)}
+          return Ok(item)
+        },
+      }
+    }
+  }
+}
+/// ## PARSE ITEM
+/// 
+/// Parses the [Item] at the start of the text, other than a [List], of
+/// which only the opening is parsed and [None] is returned, its contents
+/// being left to the caller.
+/// 
+/// [Item]: Item
+/// [List]: Item::List
+fn parse_item(text: &str) -> Result<(Option<Item>, &str), Error> {
+  fn values<T>(values: &[&str], parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, Error> {
+    values.iter().map(|value| parse(value).ok_or(Error::InvalidText)).collect()
+  }
+  fn unsigned(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+      Some(hex) => u64::from_str_radix(hex, 16).ok(),
+      None => value.parse().ok(),
+    }
+  }
+  fn signed(value: &str) -> Option<i64> {
+    match unsigned(value) {
+      Some(value) => i64::try_from(value).ok(),
+      None => value.parse().ok(),
+    }
+  }
+  let text = text.trim_start().strip_prefix('<').ok_or(Error::InvalidText)?.trim_start();
+  let end = text.find(|c: char| c.is_whitespace() || c == '>' || c == '[' || c == '"').unwrap_or(text.len());
+  let (format, mut rest) = text.split_at(end);
+  let format = format.to_ascii_uppercase();
+  match format.as_str() {
+    "L" => {
+      rest = rest.trim_start();
+      if let Some(count) = rest.strip_prefix('[') {
+        let close = count.find(']').ok_or(Error::InvalidText)?;
+        rest = &count[close + 1..];
+      }
+      Ok((None, rest))
+    },
+    "A" | "J" => {
+      let mut string = String::new();
+      loop {
+        rest = rest.trim_start();
+        if let Some(rest) = rest.strip_prefix('>') {
+          let item = if format == "A" {
+            Item::Ascii(Char::str_to_chars(&string)?.into())
+          } else {
+            Item::Jis8(string)
+          };
+          return Ok((Some(item), rest))
+        }
+        match rest.strip_prefix('"') {
+          // Quoted Run
+          Some(quoted) => {
+            let close = quoted.find('"').ok_or(Error::InvalidText)?;
+            string.push_str(&quoted[..close]);
+            rest = &quoted[close + 1..];
+          },
+          // Character Code
+          None => {
+            let end = rest.find(|c: char| c.is_whitespace() || c == '>' || c == '"').unwrap_or(rest.len());
+            let code = unsigned(&rest[..end]).filter(|code| *code < 128).ok_or(Error::InvalidText)?;
+            string.push(code as u8 as char);
+            rest = &rest[end..];
+          },
+        }
+      }
+    },
+    _ => {
+      let close = rest.find('>').ok_or(Error::InvalidText)?;
+      let list: Vec<&str> = rest[..close].split_whitespace().collect();
+      let rest = &rest[close + 1..];
+      let item = match format.as_str() {
+        "B"    => Item::Bin(values(&list, |v| unsigned(v)?.try_into().ok())?.into()),
+        "BOOL" => Item::Bool(values(&list, |v| match v.to_ascii_uppercase().as_str() {
+          "T" | "TRUE"  => Some(true),
+          "F" | "FALSE" => Some(false),
+          _ => unsigned(v).map(|v| v != 0),
+        })?.into()),
+        "I1"   => Item::I1(values(&list, |v| signed(v)?.try_into().ok())?.into()),
+        "I2"   => Item::I2(values(&list, |v| signed(v)?.try_into().ok())?.into()),
+        "I4"   => Item::I4(values(&list, |v| signed(v)?.try_into().ok())?.into()),
+        "I8"   => Item::I8(values(&list, signed)?.into()),
+        "U1"   => Item::U1(values(&list, |v| unsigned(v)?.try_into().ok())?.into()),
+        "U2"   => Item::U2(values(&list, |v| unsigned(v)?.try_into().ok())?.into()),
+        "U4"   => Item::U4(values(&list, |v| unsigned(v)?.try_into().ok())?.into()),
+        "U8"   => Item::U8(values(&list, unsigned)?.into()),
+        "F4"   => Item::F4(values(&list, |v| v.parse().ok())?.into()),
+        "F8"   => Item::F8(values(&list, |v| v.parse().ok())?.into()),
+        _ => {
+          let octal = format.strip_prefix("0O").ok_or(Error::InvalidText)?;
+          let code = u8::from_str_radix(octal, 8).ok().filter(|code| *code < 64).ok_or(Error::InvalidText)?;
+          let vec = values(&list, |v| unsigned(v)?.try_into().ok())?;
+          Item::raw(code << 2, vec).map_err(|_| Error::InvalidText)?
+        },
+      };
+      Ok((Some(item), rest))
+    },
+  }
+}
+/// ## SCALAR -> ITEM
+/// 
+/// Implements [From] a primitive value for [Item], constructing a single
+/// member [Item] of the format which naturally represents it.
+/// 
+/// [Item]: Item
+macro_rules! item_from_scalar {
+  ($($type:ty => $constructor:ident),* $(,)?) => {$(
+    impl From<$type> for Item {
+      fn from(value: $type) -> Self {
+        Item::$constructor(value)
+      }
+    }
+  )*}
+}
+item_from_scalar!{
+  bool => bool,
+  i8   => i1,
+  i16  => i2,
+  i32  => i4,
+  i64  => i8,
+  u8   => u1,
+  u16  => u2,
+  u32  => u4,
+  u64  => u8,
+  f32  => f4,
+  f64  => f8,
+}
+impl From<&str> for Item {
+  /// ### STRING -> ASCII
+  /// 
+  /// Constructs an [ASCII] [Item] from a string.
+  /// 
+  /// NOTE: Characters which are not valid ASCII are replaced with '?'.
+  /// 
+  /// [Item]:  Item
+  /// [ASCII]: Item::Ascii
+  fn from(value: &str) -> Self {
+    Item::Ascii(items::Char::safe_str_to_chars(value).into())
+  }
+}
+impl FromIterator<Item> for Item {
+  /// ### ITEMS -> LIST
+  /// 
+  /// Collects [Item]s into a [List].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn from_iter<I: IntoIterator<Item = Item>>(iter: I) -> Self {
+    Self::List(iter.into_iter().collect())
+  }
+}
+impl IntoIterator for Item {
+  type Item = Item;
+  type IntoIter = std::vec::IntoIter<Item>;
+
+  /// ### LIST -> ITEMS
+  /// 
+  /// Consumes a [List], iterating over the [Item]s it contains.
+  /// 
+  /// Any other [Item] contains no [Item]s, and yields nothing.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      Self::List(vec) => vec.into_iter(),
+      _ => Vec::new().into_iter(),
+    }
+  }
+}
+impl<'a> IntoIterator for &'a Item {
+  type Item = &'a Item;
+  type IntoIter = std::slice::Iter<'a, Item>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+impl<'a> IntoIterator for &'a mut Item {
+  type Item = &'a mut Item;
+  type IntoIter = std::slice::IterMut<'a, Item>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
 impl From<Item> for Vec<u8> {
   /// ### ITEM -> BINARY DATA
   /// 
   /// Infallable serialization of an [Item], which can represent an entire tree
   /// of [Item]s due to [List]s, into binary data.
   /// 
+  /// The tree is walked with an explicit stack rather than with recursion, so
+  /// that its depth is limited only by memory.
+  /// 
   /// [Item]: Item
   /// [List]: Item::List
   fn from(item: Item) -> Self {
-    let mut vec = vec![];
-    match item {
+    let mut vec: Vec<u8> = Vec::with_capacity(item.encoded_size());
+    // Each entry holds the items of a List which are yet to be serialized.
+    // They are consumed as they go, so the tree is also dropped piece by piece
+    // rather than with recursion.
+    let mut stack: Vec<std::vec::IntoIter<Item>> = vec![vec![item].into_iter()];
+    while let Some(items) = stack.last_mut() {
+      let Some(item) = items.next() else {
+        stack.pop();
+        continue
+      };
+      item.encode_shallow(&mut vec);
+      if let Item::List(item_vec) = item {
+        stack.push(item_vec.into_iter());
+      }
+    }
+    vec
+  }
+}
+impl Item {
+  /// ### ENCODE INTO
+  /// 
+  /// Appends the binary data of the [Item] to the provided buffer, such that
+  /// a [Message]'s text can be serialized piece by piece.
+  /// 
+  /// [Item]:    Item
+  /// [Message]: messages
+  pub(crate) fn encode_into(&self, vec: &mut Vec<u8>) {
+    // Each entry holds the items of a List which are yet to be serialized.
+    let mut stack: Vec<std::slice::Iter<Item>> = vec![std::slice::from_ref(self).iter()];
+    while let Some(items) = stack.last_mut() {
+      let Some(item) = items.next() else {
+        stack.pop();
+        continue
+      };
+      item.encode_shallow(vec);
+      if let Item::List(item_vec) = item {
+        stack.push(item_vec.iter());
+      }
+    }
+  }
+
+  /// ### ENCODE SHALLOW
+  /// 
+  /// Appends the binary data of the [Item], not including the items of a
+  /// [List], which are left to the caller.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn encode_shallow(&self, vec: &mut Vec<u8>) {
+    match self {
       // List
       Item::List(item_vec) => {
-        //Length
-        let len = item_vec.len();
-        if len < 256 {
-          vec.push(format::LIST | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::LIST | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::LIST | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Items
-        for item in item_vec {
-          vec.append(&mut item.into());
-        }
+        encode_header(vec, format::LIST, item_vec.len());
       },
       // ASCII
       Item::Ascii(ascii_vec) => {
-        //Length
-        let len = ascii_vec.len();
-        if len < 256 {
-          vec.push(format::ASCII | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::ASCII | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::ASCII | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for ascii in ascii_vec {
-          vec.push(ascii.into());
-        }
+        encode_header(vec, format::ASCII, ascii_vec.len());
+        vec.extend(ascii_vec.iter().map(|ascii| u8::from(*ascii)));
       },
       // JIS-8
       Item::Jis8(jis8_string) => {
-        // Encode
-        let encoded = ISO_2022_JP.encode(&jis8_string, encoding::EncoderTrap::Ignore).unwrap();
-        // Item Code + Length
-        let len = encoded.len();
-        if len < 256 {
-          vec.push(format::JIS8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::JIS8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::JIS8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        // Vector
+        let encoded = ISO_2022_JP.encode(jis8_string, encoding::EncoderTrap::Ignore).unwrap();
+        encode_header(vec, format::JIS8, encoded.len());
         vec.extend_from_slice(&encoded);
       },
       // Localized String (TODO)
@@ -678,236 +1311,90 @@ impl From<Item> for Vec<u8> {
       },
       // Binary
       Item::Bin(bin_vec) => {
-        //Length
-        let len = bin_vec.len();
-        if len < 256 {
-          vec.push(format::BIN | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::BIN | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::BIN | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for bin in bin_vec {
-          vec.push(bin);
-        }
+        encode_header(vec, format::BIN, bin_vec.len());
+        vec.extend_from_slice(bin_vec);
       },
       // Boolean
       Item::Bool(bool_vec) => {
-        //Length
-        let len = bool_vec.len();
-        if len < 256 {
-          vec.push(format::BOOL | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::BOOL | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::BOOL | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for bool in bool_vec {
-          vec.push(bool as u8);
-        }
+        encode_header(vec, format::BOOL, bool_vec.len());
+        vec.extend(bool_vec.iter().map(|bool| *bool as u8));
       },
       // 1-Byte Signed Integer
       Item::I1(i1_vec) => {
-        //Length
-        let len = i1_vec.len();
-        if len < 256 {
-          vec.push(format::I1 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I1 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I1 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for i1 in i1_vec {
-          vec.extend_from_slice(&i1.to_be_bytes());
-        }
+        encode_header(vec, format::I1, i1_vec.len());
+        vec.extend(i1_vec.iter().map(|i1| *i1 as u8));
       },
       // 2-Byte Signed Integer
       Item::I2(i2_vec) => {
-        //Length
-        let len = i2_vec.len() * 2;
-        if len < 256 {
-          vec.push(format::I2 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I2 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I2 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for i2 in i2_vec {
-          vec.extend_from_slice(&i2.to_be_bytes());
-        }
+        encode_header(vec, format::I2, i2_vec.len() * 2);
+        for i2 in i2_vec.iter() {vec.extend_from_slice(&i2.to_be_bytes());}
       },
       // 4-Byte Signed Integer
       Item::I4(i4_vec) => {
-        //Length
-        let len = i4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::I4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for i4 in i4_vec {
-          vec.extend_from_slice(&i4.to_be_bytes());
-        }
+        encode_header(vec, format::I4, i4_vec.len() * 4);
+        for i4 in i4_vec.iter() {vec.extend_from_slice(&i4.to_be_bytes());}
       },
       // 8-Byte Signed Integer
       Item::I8(i8_vec) => {
-        //Length
-        let len = i8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::I8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for i8 in i8_vec {
-          vec.extend_from_slice(&i8.to_be_bytes());
-        }
+        encode_header(vec, format::I8, i8_vec.len() * 8);
+        for i8 in i8_vec.iter() {vec.extend_from_slice(&i8.to_be_bytes());}
       },
       // 1-Byte Unsigned Integer
       Item::U1(u1_vec) => {
-        //Length
-        let len = u1_vec.len();
-        if len < 256 {
-          vec.push(format::U1 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U1 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U1 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for u1 in u1_vec {
-          vec.push(u1);
-        }
+        encode_header(vec, format::U1, u1_vec.len());
+        vec.extend_from_slice(u1_vec);
       },
       // 2-Byte Unsigned Integer
       Item::U2(u2_vec) => {
-        //Length
-        let len = u2_vec.len() * 2;
-        if len < 256 {
-          vec.push(format::U2 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U2 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U2 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for u2 in u2_vec {
-          vec.extend_from_slice(&u2.to_be_bytes());
-        }
+        encode_header(vec, format::U2, u2_vec.len() * 2);
+        for u2 in u2_vec.iter() {vec.extend_from_slice(&u2.to_be_bytes());}
       },
       // 4-Byte Unsigned Integer
       Item::U4(u4_vec) => {
-        //Length
-        let len = u4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::U4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for u4 in u4_vec {
-          vec.extend_from_slice(&u4.to_be_bytes());
-        }
+        encode_header(vec, format::U4, u4_vec.len() * 4);
+        for u4 in u4_vec.iter() {vec.extend_from_slice(&u4.to_be_bytes());}
       },
       // 8-Byte Unsigned Integer
       Item::U8(u8_vec) => {
-        //Length
-        let len = u8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::U8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for u8 in u8_vec {
-          vec.extend_from_slice(&u8.to_be_bytes());
-        }
+        encode_header(vec, format::U8, u8_vec.len() * 8);
+        for u8 in u8_vec.iter() {vec.extend_from_slice(&u8.to_be_bytes());}
       },
       // 4-Byte Floating Point Number
       Item::F4(f4_vec) => {
-        //Length
-        let len = f4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::F4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::F4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::F4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for f4 in f4_vec {
-          vec.extend_from_slice(&f4.to_be_bytes());
-        }
+        encode_header(vec, format::F4, f4_vec.len() * 4);
+        for f4 in f4_vec.iter() {vec.extend_from_slice(&f4.to_be_bytes());}
       },
       // 8-Byte Floating Point Number
       Item::F8(f8_vec) => {
-        //Length
-        let len = f8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::F8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::F8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::F8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for f8 in f8_vec {
-          vec.extend_from_slice(&f8.to_be_bytes());
-        }
+        encode_header(vec, format::F8, f8_vec.len() * 8);
+        for f8 in f8_vec.iter() {vec.extend_from_slice(&f8.to_be_bytes());}
+      },
+      // Unrecognized
+      Item::Raw(format, raw_vec) => {
+        encode_header(vec, format & 0b111111_00, raw_vec.len());
+        vec.extend_from_slice(raw_vec);
       },
     }
-    vec
   }
 }
+
+/// ## ENCODE HEADER
+/// 
+/// Appends the Format Byte and Length Bytes of an item, using the fewest
+/// Length Bytes able to hold its length.
+pub(crate) fn encode_header(vec: &mut Vec<u8>, format: u8, len: usize) {
+  if len < 256 {
+    vec.push(format | 1);
+    vec.push(len as u8);
+  } else if len < 65536 {
+    vec.push(format | 2);
+    vec.extend_from_slice(&(len as u16).to_be_bytes());
+  } else {
+    vec.push(format | 3);
+    vec.extend_from_slice(&(len as u32).to_be_bytes()[1..4]);
+  };
+}
+
 impl TryFrom<Vec<u8>> for Item {
   type Error = Error;
 
@@ -916,14 +1403,83 @@ impl TryFrom<Vec<u8>> for Item {
   /// Fallable deserialization of binary data into an [Item], which can
   /// represent an entire tree of [Item]s due to [List]s.
   /// 
-  /// [Item]: Item
-  /// [List]: Item::List
+  /// The default [Decode Limits] are enforced.
+  /// 
+  /// [Item]:          Item
+  /// [List]:          Item::List
+  /// [Decode Limits]: DecodeLimits
   fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+    Item::decode(&text, &DecodeLimits::default())
+  }
+}
+impl Item {
+  /// ### BINARY DATA -> ITEM, WITH LIMITS
+  /// 
+  /// Fallable deserialization of binary data into an [Item], which can
+  /// represent an entire tree of [Item]s due to [List]s.
+  /// 
+  /// The provided [Decode Limits] are enforced, resulting in a
+  /// [Limit Exceeded] error if any of them are exceeded.
+  /// 
+  /// [Item]:           Item
+  /// [List]:           Item::List
+  /// [Decode Limits]:  DecodeLimits
+  /// [Limit Exceeded]: Error::LimitExceeded
+  pub fn decode(text: &[u8], limits: &DecodeLimits) -> Result<Self, Error> {
+    /// ## INTERNAL DECODE BUDGET
+    /// 
+    /// Tracks the resources consumed so far against the limits provided.
+    struct Budget<'a> {
+      limits: &'a DecodeLimits,
+      elements: usize,
+      size: usize,
+      exceeded: bool,
+    }
+
+    /// ## INTERNAL ELEMENT
+    /// 
+    /// The result of converting a single item header and its data, where a
+    /// List item is only described by its length, its items following it.
+    enum Element {
+      List(u32),
+      Item(Item),
+    }
+
+    /// ## INTERNAL SPILL FUNCTION
+    /// 
+    /// Takes the data of an item exceeding the maximum resident size from the
+    /// iterator, storing it in a temporary file.
+    #[cfg(feature = "spill")]
+    fn spill<T: spill::Byte>(data: &mut std::slice::Iter<u8>, length: usize, budget: &mut Budget) -> Option<Payload<T>> {
+      let bytes: &[u8] = data.as_slice().get(..length)?;
+      *data = data.as_slice()[length..].iter();
+      match spill::Spilled::new(bytes) {
+        Ok(spilled) => Some(Payload::Spilled(spilled)),
+        // Invalid Element
+        Err(error) if error.kind() == std::io::ErrorKind::InvalidData => None,
+        // Temporary File Unavailable
+        Err(_) => {
+          budget.exceeded = true;
+          None
+        },
+      }
+    }
+
+    /// ## INTERNAL SPILL FUNCTION
+    /// 
+    /// Without the spill feature, an item exceeding the maximum resident size
+    /// exceeds the limits.
+    #[cfg(not(feature = "spill"))]
+    fn spill<T>(_data: &mut std::slice::Iter<u8>, _length: usize, budget: &mut Budget) -> Option<Payload<T>> {
+      budget.exceeded = true;
+      None
+    }
+
     /// ## INTERNAL CONVERSION FUNCTION
     /// 
-    /// Converts data from an iterator into an item without final checks and
-    /// using recursion in the case of List items.
-    fn convert(data: &mut std::slice::Iter<u8>) -> Option<Item> {
+    /// Converts data from an iterator into an element without final checks,
+    /// leaving the items of a List item to be converted by the caller.
+    fn convert(data: &mut std::slice::Iter<u8>, budget: &mut Budget, depth: usize) -> Option<Element> {
       let format_byte = *data.next()?;
       let item = format_byte & 0b111111_00;
       let length_length = format_byte & 0b000000_11;
@@ -935,149 +1491,191 @@ impl TryFrom<Vec<u8>> for Item {
         }
         u32::from_be_bytes(bytes)
       };
+      // Limits
+      budget.elements += 1;
+      if item != format::LIST {budget.size += length as usize}
+      if depth > budget.limits.max_depth
+      || budget.elements > budget.limits.max_elements
+      || budget.size > budget.limits.max_size {
+        budget.exceeded = true;
+        return None
+      }
       match item {
         // List
-        format::LIST => {
-          let mut vec: Vec<Item> = vec![];
-          // Perform Recursion
-          for _ in 0..length {vec.push(convert(data)?);}
-          Some(Item::List(vec))
-        },
+        format::LIST => Some(Element::List(length)),
         // ASCII
         format::ASCII => {
-          let mut vec: Vec<Char> = vec![];
+          if length as usize > budget.limits.max_resident {
+            return Some(Element::Item(Item::Ascii(spill(data, length as usize, budget)?)))
+          }
+          let mut vec: Payload<Char> = Payload::new();
           for _ in 0..length {vec.push((*data.next()?).try_into().ok()?);}
-          Some(Item::Ascii(vec))
+          Some(Element::Item(Item::Ascii(vec)))
         },
         // JIS-8
         format::JIS8 => {
           let mut vec: Vec<u8> = vec![];
           for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::Jis8(ISO_2022_JP.decode(&vec, encoding::types::DecoderTrap::Strict).ok()?))
+          Some(Element::Item(Item::Jis8(ISO_2022_JP.decode(&vec, encoding::types::DecoderTrap::Strict).ok()?)))
         },
         // Localized String (TODO)
         format::LOCAL => None,
         // Binary
         format::BIN => {
-          let mut vec: Vec<u8> = vec![];
+          if length as usize > budget.limits.max_resident {
+            return Some(Element::Item(Item::Bin(spill(data, length as usize, budget)?)))
+          }
+          let mut vec: Payload<u8> = Payload::new();
           for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::Bin(vec))
+          Some(Element::Item(Item::Bin(vec)))
         },
         // Boolean
         format::BOOL => {
-          let mut vec: Vec<bool> = vec![];
+          let mut vec: Payload<bool> = Payload::new();
           for _ in 0..length {vec.push(*data.next()? > 0);}
-          Some(Item::Bool(vec))
+          Some(Element::Item(Item::Bool(vec)))
         },
         // 1-Byte Signed Integer
         format::I1 => {
-          let mut vec: Vec<i8> = vec![];
+          let mut vec: Payload<i8> = Payload::new();
           for _ in 0..length {vec.push(*data.next()? as i8);}
-          Some(Item::I1(vec))
+          Some(Element::Item(Item::I1(vec)))
         },
         // 2-Byte Signed Integer
         format::I2 => {
           if length % 2 != 0 {return None}
-          let mut vec: Vec<i16> = vec![];
+          let mut vec: Payload<i16> = Payload::new();
           for _ in 0..length/2 {
             let mut bytes = [0u8;2];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(i16::from_be_bytes(bytes));
           }
-          Some(Item::I2(vec))
+          Some(Element::Item(Item::I2(vec)))
         },
         // 4-Byte Signed Integer
         format::I4 => {
           if length % 4 != 0 {return None}
-          let mut vec: Vec<i32> = vec![];
+          let mut vec: Payload<i32> = Payload::new();
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(i32::from_be_bytes(bytes));
           }
-          Some(Item::I4(vec))
+          Some(Element::Item(Item::I4(vec)))
         },
         // 8-Byte Signed Integer
         format::I8 => {
           if length % 8 != 0 {return None}
-          let mut vec: Vec<i64> = vec![];
+          let mut vec: Payload<i64> = Payload::new();
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(i64::from_be_bytes(bytes));
           }
-          Some(Item::I8(vec))
+          Some(Element::Item(Item::I8(vec)))
         },
         // 1-Byte Unsigned Integer
         format::U1 => {
-          let mut vec: Vec<u8> = vec![];
+          let mut vec: Payload<u8> = Payload::new();
           for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::U1(vec))
+          Some(Element::Item(Item::U1(vec)))
         },
         // 2-Byte Unsigned Integer
         format::U2 => {
           if length % 2 != 0 {return None}
-          let mut vec: Vec<u16> = vec![];
+          let mut vec: Payload<u16> = Payload::new();
           for _ in 0..length/2 {
             let mut bytes = [0u8;2];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(u16::from_be_bytes(bytes));
           }
-          Some(Item::U2(vec))
+          Some(Element::Item(Item::U2(vec)))
         },
         // 4-Byte Unsigned Integer
         format::U4 => {
           if length % 4 != 0 {return None}
-          let mut vec: Vec<u32> = vec![];
+          let mut vec: Payload<u32> = Payload::new();
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(u32::from_be_bytes(bytes));
           }
-          Some(Item::U4(vec))
+          Some(Element::Item(Item::U4(vec)))
         },
         // 8-Byte Unsigned Integer
         format::U8 => {
           if length % 8 != 0 {return None}
-          let mut vec: Vec<u64> = vec![];
+          let mut vec: Payload<u64> = Payload::new();
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(u64::from_be_bytes(bytes));
           }
-          Some(Item::U8(vec))
+          Some(Element::Item(Item::U8(vec)))
         },
         // 4-Byte Floating Point Number
         format::F4 => {
           if length % 4 != 0 {return None}
-          let mut vec: Vec<f32> = vec![];
+          let mut vec: Payload<f32> = Payload::new();
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(f32::from_be_bytes(bytes));
           }
-          Some(Item::F4(vec))
+          Some(Element::Item(Item::F4(vec)))
         },
         // 8-Byte Floating Point Number
         format::F8 => {
           if length % 8 != 0 {return None}
-          let mut vec: Vec<f64> = vec![];
+          let mut vec: Payload<f64> = Payload::new();
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
             for byte in &mut bytes {*byte = *data.next()?}
             vec.push(f64::from_be_bytes(bytes));
           }
-          Some(Item::F8(vec))
+          Some(Element::Item(Item::F8(vec)))
         },
         // Unrecognized
-        _ => None
+        _ => {
+          let mut vec: Vec<u8> = vec![];
+          for _ in 0..length {vec.push(*data.next()?);}
+          Some(Element::Item(Item::Raw(item, vec)))
+        },
       }
     }
     // Empty items are their own category of error which may be acceptable elsewhere.
     if text.is_empty() {return Err(Error::EmptyText)};
     // Convert data into an item.
+    // Each entry holds a List item's items converted so far, and how many
+    // remain, such that nesting is limited only by memory and the limits.
     let mut data: std::slice::Iter<u8> = text.iter();
-    let result = convert(&mut data).ok_or(Error::InvalidText)?;
+    let mut budget = Budget {limits, elements: 0, size: 0, exceeded: false};
+    let mut stack: Vec<(Vec<Item>, u32)> = vec![];
+    let result = 'convert: loop {
+      let mut item: Item = match convert(&mut data, &mut budget, stack.len() + 1) {
+        // Non-Empty List: Convert Its Items Next
+        Some(Element::List(length)) if length > 0 => {
+          // Every item occupies at least two bytes.
+          let capacity: usize = (length as usize).min(data.len() / 2);
+          stack.push((Vec::with_capacity(capacity), length));
+          continue
+        },
+        // Empty List
+        Some(Element::List(_)) => Item::List(vec![]),
+        Some(Element::Item(item)) => item,
+        None if budget.exceeded => return Err(Error::LimitExceeded),
+        None => return Err(Error::InvalidText),
+      };
+      // Add the item to its List item, finishing each List item filled.
+      loop {
+        let Some((vec, remaining)) = stack.last_mut() else {break 'convert item};
+        vec.push(item);
+        *remaining -= 1;
+        if *remaining > 0 {break}
+        let (vec, _) = stack.pop().unwrap();
+        item = Item::List(vec);
+      }
+    };
     // Check that all text has been handled.
     if data.next().is_some() {return Err(Error::InvalidText)}
     // Finish.
@@ -1085,6 +1683,376 @@ impl TryFrom<Vec<u8>> for Item {
   }
 }
 
+/// ## DECODE LIMITS
+/// 
+/// Restrictions on the resources which may be consumed in converting binary
+/// data into an [Item], protecting against binary data crafted to cause deep
+/// nesting or enormous allocations.
+/// 
+/// [Item]: Item
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeLimits {
+  /// ### MAXIMUM DEPTH
+  /// 
+  /// The maximum depth of the tree of [Item]s, where an [Item] which is not
+  /// contained within a [List] has a depth of 1.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub max_depth: usize,
+
+  /// ### MAXIMUM ELEMENTS
+  /// 
+  /// The maximum total number of [Item]s in the tree, including [List]s.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub max_elements: usize,
+
+  /// ### MAXIMUM SIZE
+  /// 
+  /// The maximum total number of bytes of data contained in [Item]s other
+  /// than [List]s, not including Item Headers.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub max_size: usize,
+
+  /// ### MAXIMUM RESIDENT
+  /// 
+  /// The maximum number of bytes of data contained in a single [ASCII] or
+  /// [Binary] [Item] which will be kept in memory.
+  /// 
+  /// With the `spill` feature, the data of a larger [Item] is spilled to a
+  /// temporary file instead, otherwise it exceeds the limits.
+  /// 
+  /// [Item]:   Item
+  /// [ASCII]:  Item::Ascii
+  /// [Binary]: Item::Bin
+  pub max_resident: usize,
+}
+impl Default for DecodeLimits {
+  /// ### DEFAULT DECODE LIMITS
+  /// 
+  /// - [Maximum Depth] - 64
+  /// - [Maximum Elements] - 1,048,576
+  /// - [Maximum Size] - 64 MiB
+  /// - [Maximum Resident] - Unlimited
+  /// 
+  /// [Maximum Depth]:    DecodeLimits::max_depth
+  /// [Maximum Elements]: DecodeLimits::max_elements
+  /// [Maximum Size]:     DecodeLimits::max_size
+  /// [Maximum Resident]: DecodeLimits::max_resident
+  fn default() -> Self {
+    Self {
+      max_depth: 64,
+      max_elements: 1 << 20,
+      max_size: 64 << 20,
+      max_resident: usize::MAX,
+    }
+  }
+}
+impl DecodeLimits {
+  /// ### UNLIMITED
+  /// 
+  /// [Decode Limits] which are never exceeded.
+  /// 
+  /// [Decode Limits]: DecodeLimits
+  pub const UNLIMITED: Self = Self {
+    max_depth: usize::MAX,
+    max_elements: usize::MAX,
+    max_size: usize::MAX,
+    max_resident: usize::MAX,
+  };
+}
+
+/// ## ITEM PAYLOAD
+/// 
+/// The storage used by the [ASCII], [Binary], [Boolean], integer, and
+/// floating point [Item] formats.
+/// 
+/// The overwhelming majority of such [Item]s contain exactly one element, so
+/// in this case the element is stored inline without a heap allocation. Any
+/// other number of elements is stored in a [Vec], where an empty [Vec] also
+/// does not allocate.
+/// 
+/// Contents may also be [Shared], such that identifiers repeated across many
+/// [Message]s can be cloned by reference counting rather than by copying.
+/// Mutating [Shared] contents will first copy them.
+/// 
+/// With the `spill` feature, the contents of [ASCII] and [Binary] [Item]s may
+/// also be spilled to a temporary file rather than kept in memory. As the
+/// variants present depend on the features enabled, matches on a [Payload]
+/// outside this crate must include a wildcard arm.
+/// 
+/// Dereferences to a slice, and converts to and from a [Vec], so it may be
+/// treated as such in most circumstances.
+/// 
+/// [Item]:    Item
+/// [ASCII]:   Item::Ascii
+/// [Binary]:  Item::Bin
+/// [Boolean]: Item::Bool
+/// [Message]: Message
+/// [Shared]:  Payload::Shared
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum Payload<T> {
+  /// ### SINGLE
+  /// 
+  /// Exactly one element, stored inline.
+  Single(T),
+
+  /// ### MULTIPLE
+  /// 
+  /// Any number of elements, stored on the heap.
+  Multiple(Vec<T>),
+
+  /// ### SHARED
+  /// 
+  /// Any number of elements, stored on the heap and shared by reference
+  /// counting.
+  Shared(std::sync::Arc<[T]>),
+
+  /// ### SPILLED
+  /// 
+  /// Any number of single byte elements, stored in a memory-mapped temporary
+  /// file and shared by reference counting.
+  #[cfg(feature = "spill")]
+  Spilled(spill::Spilled<T>),
+}
+impl<T> Payload<T> {
+  /// ### NEW PAYLOAD
+  /// 
+  /// Constructs an empty [Payload], which does not allocate.
+  /// 
+  /// [Payload]: Payload
+  pub const fn new() -> Self {
+    Self::Multiple(Vec::new())
+  }
+
+  /// ### IS SHARED
+  /// 
+  /// Whether the contents are stored by reference counting.
+  pub fn is_shared(&self) -> bool {
+    matches!(self, Self::Shared(_))
+  }
+
+  /// ### IS SPILLED
+  /// 
+  /// Whether the contents are stored in a temporary file.
+  pub fn is_spilled(&self) -> bool {
+    #[cfg(feature = "spill")]
+    if let Self::Spilled(_) = self {return true}
+    false
+  }
+}
+impl<T: Clone> Payload<T> {
+  /// ### PUSH
+  /// 
+  /// Appends an element, moving the contents to the heap only once a second
+  /// element is added.
+  pub fn push(&mut self, value: T) {
+    match self {
+      Self::Multiple(vec) if vec.is_empty() => *self = Self::Single(value),
+      Self::Multiple(vec) => vec.push(value),
+      _ => {
+        let mut vec = std::mem::take(self).into_vec();
+        vec.push(value);
+        *self = Self::Multiple(vec);
+      },
+    }
+  }
+
+  /// ### INTO VEC
+  /// 
+  /// Converts the contents into a [Vec], allocating if they are stored inline
+  /// or shared.
+  pub fn into_vec(self) -> Vec<T> {
+    match self {
+      Self::Single(value) => vec![value],
+      Self::Multiple(vec) => vec,
+      Self::Shared(arc) => arc.to_vec(),
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled) => spilled.to_vec(),
+    }
+  }
+
+  /// ### INTO SHARED
+  /// 
+  /// Moves the contents into [Shared] storage, after which clones of the
+  /// [Payload] no longer copy its elements. Spilled contents are left in
+  /// place, as their clones already do not.
+  /// 
+  /// [Payload]: Payload
+  /// [Shared]:  Payload::Shared
+  pub fn into_shared(self) -> Self {
+    match self {
+      Self::Single(value) => Self::Shared(std::sync::Arc::new([value])),
+      Self::Multiple(vec) => Self::Shared(vec.into()),
+      Self::Shared(arc) => Self::Shared(arc),
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled) => Self::Spilled(spilled),
+    }
+  }
+}
+impl<T> Default for Payload<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+impl<T> std::ops::Deref for Payload<T> {
+  type Target = [T];
+
+  fn deref(&self) -> &[T] {
+    match self {
+      Self::Single(value) => std::slice::from_ref(value),
+      Self::Multiple(vec) => vec,
+      Self::Shared(arc) => arc,
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled) => spilled,
+    }
+  }
+}
+impl<T: Clone> std::ops::DerefMut for Payload<T> {
+  fn deref_mut(&mut self) -> &mut [T] {
+    if let Self::Shared(arc) = self {
+      *self = Self::Multiple(arc.to_vec());
+    }
+    #[cfg(feature = "spill")]
+    if let Self::Spilled(spilled) = self {
+      *self = Self::Multiple(spilled.to_vec());
+    }
+    match self {
+      Self::Single(value) => std::slice::from_mut(value),
+      Self::Multiple(vec) => vec,
+      _ => unreachable!(),
+    }
+  }
+}
+impl<T: std::fmt::Debug> std::fmt::Debug for Payload<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
+impl<T: PartialEq> PartialEq for Payload<T> {
+  fn eq(&self, other: &Self) -> bool {
+    **self == **other
+  }
+}
+impl<T> From<Vec<T>> for Payload<T> {
+  fn from(mut vec: Vec<T>) -> Self {
+    if vec.len() == 1 {
+      if let Some(value) = vec.pop() {
+        return Self::Single(value)
+      }
+    }
+    Self::Multiple(vec)
+  }
+}
+impl<T> From<std::sync::Arc<[T]>> for Payload<T> {
+  fn from(arc: std::sync::Arc<[T]>) -> Self {
+    Self::Shared(arc)
+  }
+}
+impl<T: Clone> From<Payload<T>> for Vec<T> {
+  fn from(payload: Payload<T>) -> Self {
+    payload.into_vec()
+  }
+}
+impl<T> FromIterator<T> for Payload<T> {
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut iter = iter.into_iter();
+    match (iter.next(), iter.next()) {
+      (None, _) => Self::new(),
+      (Some(first), None) => Self::Single(first),
+      (Some(first), Some(second)) => {
+        let mut vec = Vec::with_capacity(iter.size_hint().0 + 2);
+        vec.push(first);
+        vec.push(second);
+        vec.extend(iter);
+        Self::Multiple(vec)
+      },
+    }
+  }
+}
+impl<T: Clone> IntoIterator for Payload<T> {
+  type Item = T;
+  type IntoIter = PayloadIntoIter<T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      Self::Single(value) => PayloadIntoIter::Single(Some(value)),
+      Self::Multiple(vec) => PayloadIntoIter::Multiple(vec.into_iter()),
+      Self::Shared(arc) => PayloadIntoIter::Shared(arc, 0),
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled) => PayloadIntoIter::Spilled(spilled, 0),
+    }
+  }
+}
+impl<'a, T> IntoIterator for &'a Payload<T> {
+  type Item = &'a T;
+  type IntoIter = std::slice::Iter<'a, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+impl<'a, T: Clone> IntoIterator for &'a mut Payload<T> {
+  type Item = &'a mut T;
+  type IntoIter = std::slice::IterMut<'a, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+/// ## ITEM PAYLOAD ITERATOR
+/// 
+/// Owning iterator over the elements of a [Payload].
+/// 
+/// [Payload]: Payload
+#[non_exhaustive]
+pub enum PayloadIntoIter<T> {
+  Single(Option<T>),
+  Multiple(std::vec::IntoIter<T>),
+  Shared(std::sync::Arc<[T]>, usize),
+  #[cfg(feature = "spill")]
+  Spilled(spill::Spilled<T>, usize),
+}
+impl<T: Clone> Iterator for PayloadIntoIter<T> {
+  type Item = T;
+
+  fn next(&mut self) -> Option<T> {
+    match self {
+      Self::Single(value) => value.take(),
+      Self::Multiple(iter) => iter.next(),
+      Self::Shared(arc, index) => {
+        let value = arc.get(*index)?.clone();
+        *index += 1;
+        Some(value)
+      },
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled, index) => {
+        let value = spilled.get(*index)?.clone();
+        *index += 1;
+        Some(value)
+      },
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = match self {
+      Self::Single(value) => value.is_some() as usize,
+      Self::Multiple(iter) => iter.len(),
+      Self::Shared(arc, index) => arc.len() - *index,
+      #[cfg(feature = "spill")]
+      Self::Spilled(spilled, index) => spilled.len() - *index,
+    };
+    (len, Some(len))
+  }
+}
+impl<T: Clone> ExactSizeIterator for PayloadIntoIter<T> {}
+
 /// ## LOCALIZED STRING HEADER
 /// **Based on SEMI E5§9.4**
 #[repr(u16)]
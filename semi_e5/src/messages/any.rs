@@ -0,0 +1,476 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # ANY MESSAGE
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A single enum holding any [Message] defined herein, so that inbound
+//! traffic can be converted once and matched exhaustively, rather than
+//! attempting conversion into each specific type in turn.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each variant is named after the [Stream] and type of the [Message] it
+//! holds, such as [S1AreYouThere], and variants are ordered as in the
+//! [Registry].
+//! 
+//! [Message]:       crate::messages
+//! [Stream]:        crate::Message::stream
+//! [S1AreYouThere]: AnyMessage::S1AreYouThere
+//! [Registry]:      super::registry::REGISTRY
+
+use crate::{Error, Message};
+use super::MessageHeader;
+use super::{s1, s2, s3, s5, s6, s7, s9, s10, s12, s13, s14, s15, s16, s17, s18};
+
+/// ## INTERNAL ANY MESSAGE MACRO
+/// 
+/// Constructs the [Any Message] enum and its conversions, given the variant
+/// name, [Stream], [Function], and type of each [Message].
+/// 
+/// [Any Message]: AnyMessage
+/// [Message]:     crate::messages
+/// [Stream]:      crate::Message::stream
+/// [Function]:    crate::Message::function
+macro_rules! any_message {
+  (
+    $(
+      $variant:ident:
+      $stream:literal,
+      $function:literal,
+      $type:ty
+    ),* $(,)?
+  ) => {
+    /// ## ANY MESSAGE
+    /// 
+    /// Any [Message] defined herein.
+    /// 
+    /// ------------------------------------------------------------------------
+    /// 
+    /// Converting from a [Generic Message] attempts each [Message] defined
+    /// with its [Stream] and [Function] in turn, and the first successful
+    /// conversion is returned, in the same manner as [Decode].
+    /// 
+    /// ------------------------------------------------------------------------
+    /// 
+    /// #### Errors
+    /// 
+    /// - [Wrong Stream] - No [Message] is defined with the [Stream].
+    /// - [Wrong Function] - No [Message] is defined with the [Function].
+    /// - Otherwise, the error from the last attempted conversion.
+    /// 
+    /// [Message]:         crate::messages
+    /// [Generic Message]: crate::Message
+    /// [Stream]:          crate::Message::stream
+    /// [Function]:        crate::Message::function
+    /// [Decode]:          super::registry::decode
+    /// [Wrong Stream]:    Error::WrongStream
+    /// [Wrong Function]:  Error::WrongFunction
+    pub enum AnyMessage {
+      $($variant($type),)*
+    }
+    impl AnyMessage {
+      /// ### STREAM
+      /// 
+      /// The [Stream] of the held [Message].
+      /// 
+      /// [Message]: crate::messages
+      /// [Stream]:  crate::Message::stream
+      pub fn stream(&self) -> u8 {
+        match self {
+          $(Self::$variant(message) => message.stream(),)*
+        }
+      }
+
+      /// ### FUNCTION
+      /// 
+      /// The [Function] of the held [Message].
+      /// 
+      /// [Message]:  crate::messages
+      /// [Function]: crate::Message::function
+      pub fn function(&self) -> u8 {
+        match self {
+          $(Self::$variant(message) => message.function(),)*
+        }
+      }
+
+      /// ### REPLY REQUESTED
+      /// 
+      /// The [Reply Bit] of the held [Message].
+      /// 
+      /// [Message]:   crate::messages
+      /// [Reply Bit]: crate::Message::w
+      pub fn w(&self) -> bool {
+        match self {
+          $(Self::$variant(message) => message.w(),)*
+        }
+      }
+    }
+    impl From<AnyMessage> for Message {
+      fn from(value: AnyMessage) -> Self {
+        match value {
+          $(AnyMessage::$variant(message) => message.into(),)*
+        }
+      }
+    }
+    impl TryFrom<Message> for AnyMessage {
+      type Error = Error;
+
+      fn try_from(message: Message) -> Result<Self, Self::Error> {
+        if ![$($stream),*].contains(&message.stream) {
+          return Err(Error::WrongStream)
+        }
+        let mut error = Error::WrongFunction;
+        $(
+          if message.stream == $stream && message.function == $function {
+            match <$type>::try_from(message.clone()) {
+              Ok(message) => return Ok(Self::$variant(message)),
+              Err(e) => error = e,
+            }
+          }
+        )*
+        Err(error)
+      }
+    }
+  }
+}
+
+any_message!{
+  S1Abort:                                 1,  0,  s1::Abort,
+  S1AreYouThere:                           1,  1,  s1::AreYouThere,
+  S1OnLineDataHost:                        1,  2,  s1::OnLineDataHost,
+  S1OnLineDataEquipment:                   1,  2,  s1::OnLineDataEquipment,
+  S1SelectedEquipmentStatusRequest:        1,  3,  s1::SelectedEquipmentStatusRequest,
+  S1SelectedEquipmentStatusData:           1,  4,  s1::SelectedEquipmentStatusData,
+  S1FormattedStatusRequest:                1,  5,  s1::FormattedStatusRequest,
+  S1FormattedStatusData:                   1,  6,  s1::FormattedStatusData,
+  S1FixedFormRequest:                      1,  7,  s1::FixedFormRequest,
+  S1FixedFormData:                         1,  8,  s1::FixedFormData,
+  S1MaterialTransferStatusRequest:         1,  9,  s1::MaterialTransferStatusRequest,
+  S1MaterialTransferStatusData:            1,  10, s1::MaterialTransferStatusData,
+  S1StatusVariableNamelistRequest:         1,  11, s1::StatusVariableNamelistRequest,
+  S1StatusVariableNamelistReply:           1,  12, s1::StatusVariableNamelistReply,
+  S1HostCR:                                1,  13, s1::HostCR,
+  S1EquipmentCR:                           1,  13, s1::EquipmentCR,
+  S1HostCRA:                               1,  14, s1::HostCRA,
+  S1EquipmentCRA:                          1,  14, s1::EquipmentCRA,
+  S1RequestOffLine:                        1,  15, s1::RequestOffLine,
+  S1OffLineAck:                            1,  16, s1::OffLineAck,
+  S1RequestOnLine:                         1,  17, s1::RequestOnLine,
+  S1OnLineAck:                             1,  18, s1::OnLineAck,
+  S1GetAttribute:                          1,  19, s1::GetAttribute,
+  S1AttributeData:                         1,  20, s1::AttributeData,
+  S1DataVariableNamelistRequest:           1,  21, s1::DataVariableNamelistRequest,
+  S1DataVariableNamelist:                  1,  22, s1::DataVariableNamelist,
+  S1CollectionEventNamelistRequest:        1,  23, s1::CollectionEventNamelistRequest,
+  S1CollectionEventNamelist:               1,  24, s1::CollectionEventNamelist,
+  S2Abort:                                 2,  0,  s2::Abort,
+  S2ServiceProgramLoadInquire:             2,  1,  s2::ServiceProgramLoadInquire,
+  S2ServiceProgramLoadGrant:               2,  2,  s2::ServiceProgramLoadGrant,
+  S2ServiceProgramSend:                    2,  3,  s2::ServiceProgramSend,
+  S2ServiceProgramSendAcknowledge:         2,  4,  s2::ServiceProgramSendAcknowledge,
+  S2ServiceProgramLoadRequest:             2,  5,  s2::ServiceProgramLoadRequest,
+  S2ServiceProgramLoadData:                2,  6,  s2::ServiceProgramLoadData,
+  S2ServiceProgramRunSend:                 2,  7,  s2::ServiceProgramRunSend,
+  S2ServiceProgramRunAcknowledge:          2,  8,  s2::ServiceProgramRunAcknowledge,
+  S2ServiceProgramResultsRequest:          2,  9,  s2::ServiceProgramResultsRequest,
+  S2ServiceProgramResultsData:             2,  10, s2::ServiceProgramResultsData,
+  S2ServiceProgramDirectoryRequest:        2,  11, s2::ServiceProgramDirectoryRequest,
+  S2ServiceProgramDirectoryData:           2,  12, s2::ServiceProgramDirectoryData,
+  S2EquipmentConstantRequest:              2,  13, s2::EquipmentConstantRequest,
+  S2EquipmentConstantData:                 2,  14, s2::EquipmentConstantData,
+  S2NewEquipmentConstantSend:              2,  15, s2::NewEquipmentConstantSend,
+  S2NewEquipmentConstantAcknowledge:       2,  16, s2::NewEquipmentConstantAcknowledge,
+  S2DateTimeRequest:                       2,  17, s2::DateTimeRequest,
+  S2DateTimeData:                          2,  18, s2::DateTimeData,
+  S2ResetInitializeSend:                   2,  19, s2::ResetInitializeSend,
+  S2ResetAcknowledge:                      2,  20, s2::ResetAcknowledge,
+  S2RemoteCommandSend:                     2,  21, s2::RemoteCommandSend,
+  S2RemoteCommandAcknowledge:              2,  22, s2::RemoteCommandAcknowledge,
+  S2TraceInitializeSend:                   2,  23, s2::TraceInitializeSend,
+  S2TraceInitializeAcknowledge:            2,  24, s2::TraceInitializeAcknowledge,
+  S2LoopbackDiagnosticRequest:             2,  25, s2::LoopbackDiagnosticRequest,
+  S2LoopbackDiagnosticData:                2,  26, s2::LoopbackDiagnosticData,
+  S2InitiateProcessingRequest:             2,  27, s2::InitiateProcessingRequest,
+  S2InitiateProcessingAcknowledge:         2,  28, s2::InitiateProcessingAcknowledge,
+  S2EquipmentConstantNamelistRequest:      2,  29, s2::EquipmentConstantNamelistRequest,
+  S2EquipmentConstantNamelist:             2,  30, s2::EquipmentConstantNamelist,
+  S2DateTimeSetRequest:                    2,  31, s2::DateTimeSetRequest,
+  S2DateTimeSetAcknowledge:                2,  32, s2::DateTimeSetAcknowledge,
+  S2DefineReport:                          2,  33, s2::DefineReport,
+  S2DefineReportAcknowledge:               2,  34, s2::DefineReportAcknowledge,
+  S2LinkEventReport:                       2,  35, s2::LinkEventReport,
+  S2LinkEventReportAcknowledge:            2,  36, s2::LinkEventReportAcknowledge,
+  S2EnableDisableEventReport:              2,  37, s2::EnableDisableEventReport,
+  S2EnableDisableEventReportAcknowledge:   2,  38, s2::EnableDisableEventReportAcknowledge,
+  S2MultiBlockInquire:                     2,  39, s2::MultiBlockInquire,
+  S2MultiBlockGrant:                       2,  40, s2::MultiBlockGrant,
+  S2HostCommandSend:                       2,  41, s2::HostCommandSend,
+  S2HostCommandAcknowledge:                2,  42, s2::HostCommandAcknowledge,
+  S2ResetSpoolingStreamsAndFunctions:      2,  43, s2::ResetSpoolingStreamsAndFunctions,
+  S2ResetSpoolingAcknowledge:              2,  44, s2::ResetSpoolingAcknowledge,
+  S2DefineVariableLimitAttributes:         2,  45, s2::DefineVariableLimitAttributes,
+  S2VariableLimitAttributeAcknowledge:     2,  46, s2::VariableLimitAttributeAcknowledge,
+  S2VariableLimitAttributeRequest:         2,  47, s2::VariableLimitAttributeRequest,
+  S2VariableLimitAttributeSend:            2,  48, s2::VariableLimitAttributeSend,
+  S2EnhancedRemoteCommand:                 2,  49, s2::EnhancedRemoteCommand,
+  S2EnhancedRemoteCommandAcknowledge:      2,  50, s2::EnhancedRemoteCommandAcknowledge,
+  S3Abort:                                 3,  0,  s3::Abort,
+  S3MaterialIDRequest:                     3,  11, s3::MaterialIDRequest,
+  S3MaterialIDRequestAcknowledge:          3,  12, s3::MaterialIDRequestAcknowledge,
+  S3CarrierActionRequest:                  3,  17, s3::CarrierActionRequest,
+  S3CarrierActionRequestAcknowledge:       3,  18, s3::CarrierActionRequestAcknowledge,
+  S3ChangeAccess:                          3,  27, s3::ChangeAccess,
+  S3ChangeAccessAcknowledge:               3,  28, s3::ChangeAccessAcknowledge,
+  S3CarrierTagReadRequest:                 3,  29, s3::CarrierTagReadRequest,
+  S3CarrierTagReadData:                    3,  30, s3::CarrierTagReadData,
+  S3CarrierTagWriteDataRequest:            3,  31, s3::CarrierTagWriteDataRequest,
+  S3CarrierTagWriteDataAcknowledge:        3,  32, s3::CarrierTagWriteDataAcknowledge,
+  S5Abort:                                 5,  0,  s5::Abort,
+  S5AlarmReportSend:                       5,  1,  s5::AlarmReportSend,
+  S5AlarmReportAcknowledge:                5,  2,  s5::AlarmReportAcknowledge,
+  S5EnableDisableAlarmSend:                5,  3,  s5::EnableDisableAlarmSend,
+  S5EnableDisableAllAlarmSend:             5,  3,  s5::EnableDisableAllAlarmSend,
+  S5EnableDisableAlarmAcknowledge:         5,  4,  s5::EnableDisableAlarmAcknowledge,
+  S5ListAlarmsRequest:                     5,  5,  s5::ListAlarmsRequest,
+  S5ListAlarmsData:                        5,  6,  s5::ListAlarmsData,
+  S5ListEnabledAlarmsRequest:              5,  7,  s5::ListEnabledAlarmsRequest,
+  S5ListEnabledAlarmsData:                 5,  8,  s5::ListEnabledAlarmsData,
+  S5ExceptionPostNotify:                   5,  9,  s5::ExceptionPostNotify,
+  S5ExceptionPostConfirm:                  5,  10, s5::ExceptionPostConfirm,
+  S5ExceptionClearNotify:                  5,  11, s5::ExceptionClearNotify,
+  S5ExceptionClearConfirm:                 5,  12, s5::ExceptionClearConfirm,
+  S5ExceptionRecoverRequest:               5,  13, s5::ExceptionRecoverRequest,
+  S5ExceptionRecoverAcknowledge:           5,  14, s5::ExceptionRecoverAcknowledge,
+  S5ExceptionRecoveryCompleteNotify:       5,  15, s5::ExceptionRecoveryCompleteNotify,
+  S5ExceptionRecoveryCompleteConfirm:      5,  16, s5::ExceptionRecoveryCompleteConfirm,
+  S5ExceptionRecoveryAbortRequest:         5,  17, s5::ExceptionRecoveryAbortRequest,
+  S5ExceptionRecoveryAbortAcknowledge:     5,  18, s5::ExceptionRecoveryAbortAcknowledge,
+  S6Abort:                                 6,  0,  s6::Abort,
+  S6TraceDataSend:                         6,  1,  s6::TraceDataSend,
+  S6TraceDataAcknowledge:                  6,  2,  s6::TraceDataAcknowledge,
+  S6DiscreteVariableDataSend:              6,  3,  s6::DiscreteVariableDataSend,
+  S6DiscreteVariableDataAcknowledge:       6,  4,  s6::DiscreteVariableDataAcknowledge,
+  S6MultiBlockDataSendInquire:             6,  5,  s6::MultiBlockDataSendInquire,
+  S6MultiBlockGrant:                       6,  6,  s6::MultiBlockGrant,
+  S6DataTransferRequest:                   6,  7,  s6::DataTransferRequest,
+  S6DataTransferData:                      6,  8,  s6::DataTransferData,
+  S6FormattedVariableSend:                 6,  9,  s6::FormattedVariableSend,
+  S6FormattedVariableAcknowledge:          6,  10, s6::FormattedVariableAcknowledge,
+  S6EventReport:                           6,  11, s6::EventReport,
+  S6EventReportAcknowledge:                6,  12, s6::EventReportAcknowledge,
+  S6AnnotatedEventReport:                  6,  13, s6::AnnotatedEventReport,
+  S6AnnotatedEventReportAcknowledge:       6,  14, s6::AnnotatedEventReportAcknowledge,
+  S6EventReportRequest:                    6,  15, s6::EventReportRequest,
+  S6EventReportData:                       6,  16, s6::EventReportData,
+  S6AnnotatedEventReportRequest:           6,  17, s6::AnnotatedEventReportRequest,
+  S6AnnotatedEventReportData:              6,  18, s6::AnnotatedEventReportData,
+  S6IndividualReportRequest:               6,  19, s6::IndividualReportRequest,
+  S6IndividualReportData:                  6,  20, s6::IndividualReportData,
+  S6AnnotatedIndividualReportRequest:      6,  21, s6::AnnotatedIndividualReportRequest,
+  S6AnnotatedIndividualReportData:         6,  22, s6::AnnotatedIndividualReportData,
+  S6RequestSpooledData:                    6,  23, s6::RequestSpooledData,
+  S6RequestSpooledDataAcknowledge:         6,  24, s6::RequestSpooledDataAcknowledge,
+  S6NotificationReportSend:                6,  25, s6::NotificationReportSend,
+  S6NotificationReportSendAcknowledge:     6,  26, s6::NotificationReportSendAcknowledge,
+  S6TraceReportSend:                       6,  27, s6::TraceReportSend,
+  S6TraceReportSendAcknowledge:            6,  28, s6::TraceReportSendAcknowledge,
+  S6TraceReportRequest:                    6,  29, s6::TraceReportRequest,
+  S6TraceReportData:                       6,  30, s6::TraceReportData,
+  S7Abort:                                 7,  0,  s7::Abort,
+  S7ProcessProgramLoadInquire:             7,  1,  s7::ProcessProgramLoadInquire,
+  S7ProcessProgramLoadGrant:               7,  2,  s7::ProcessProgramLoadGrant,
+  S7ProcessProgramSend:                    7,  3,  s7::ProcessProgramSend,
+  S7ProcessProgramAcknowledge:             7,  4,  s7::ProcessProgramAcknowledge,
+  S7ProcessProgramRequest:                 7,  5,  s7::ProcessProgramRequest,
+  S7ProcessProgramData:                    7,  6,  s7::ProcessProgramData,
+  S7DeleteProcessProgramSend:              7,  17, s7::DeleteProcessProgramSend,
+  S7DeleteProcessProgramAcknowledge:       7,  18, s7::DeleteProcessProgramAcknowledge,
+  S7CurrentProcessProgramDirectoryRequest: 7,  19, s7::CurrentProcessProgramDirectoryRequest,
+  S7CurrentProcessProgramDirectoryData:    7,  20, s7::CurrentProcessProgramDirectoryData,
+  S7ProcessCapabilitiesRequest:            7,  21, s7::ProcessCapabilitiesRequest,
+  S7ProcessCapabilitiesData:               7,  22, s7::ProcessCapabilitiesData,
+  S7FormattedProcessProgramSend:           7,  23, s7::FormattedProcessProgramSend,
+  S7FormattedProcessProgramAcknowledge:    7,  24, s7::FormattedProcessProgramAcknowledge,
+  S7FormattedProcessProgramRequest:        7,  25, s7::FormattedProcessProgramRequest,
+  S7FormattedProcessProgramData:           7,  26, s7::FormattedProcessProgramData,
+  S7ProcessProgramVerificationSend:        7,  27, s7::ProcessProgramVerificationSend,
+  S7ProcessProgramVerificationAcknowledge: 7,  28, s7::ProcessProgramVerificationAcknowledge,
+  S7ProcessProgramVerificationInquire:     7,  29, s7::ProcessProgramVerificationInquire,
+  S7ProcessProgramVerificationGrant:       7,  30, s7::ProcessProgramVerificationGrant,
+  S7VerificationRequestSend:               7,  31, s7::VerificationRequestSend,
+  S7VerificationRequestAcknowledge:        7,  32, s7::VerificationRequestAcknowledge,
+  S7ProcessProgramAvailableRequest:        7,  33, s7::ProcessProgramAvailableRequest,
+  S7ProcessProgramAvailabilityData:        7,  34, s7::ProcessProgramAvailabilityData,
+  S7ProcessProgramForMaterialRequest:      7,  35, s7::ProcessProgramForMaterialRequest,
+  S7ProcessProgramForMaterialData:         7,  36, s7::ProcessProgramForMaterialData,
+  S7LargeProcessProgramSend:               7,  37, s7::LargeProcessProgramSend,
+  S7LargeProcessProgramAcknowledge:        7,  38, s7::LargeProcessProgramAcknowledge,
+  S7LargeFormattedProcessProgramSend:      7,  39, s7::LargeFormattedProcessProgramSend,
+  S7LargeFormattedProcessProgramAcknowledge: 7,  40, s7::LargeFormattedProcessProgramAcknowledge,
+  S7LargeProcessProgramRequest:            7,  41, s7::LargeProcessProgramRequest,
+  S7LargeProcessProgramRequestAcknowledge: 7,  42, s7::LargeProcessProgramRequestAcknowledge,
+  S7LargeFormattedProcessProgramRequest:   7,  43, s7::LargeFormattedProcessProgramRequest,
+  S7LargeFormattedProcessProgramRequestAcknowledge: 7,  44, s7::LargeFormattedProcessProgramRequestAcknowledge,
+  S9UnrecognizedDeviceID:                  9,  1,  s9::UnrecognizedDeviceID,
+  S9UnrecognizedStreamType:                9,  3,  s9::UnrecognizedStreamType,
+  S9UnrecognizedFunctionType:              9,  5,  s9::UnrecognizedFunctionType,
+  S9IllegalData:                           9,  7,  s9::IllegalData,
+  S9TransactionTimerTimeout:               9,  9,  s9::TransactionTimerTimeout,
+  S9DataTooLong:                           9,  11, s9::DataTooLong,
+  S9ConversationTimeout:                   9,  13, s9::ConversationTimeout,
+  S10Abort:                                10, 0,  s10::Abort,
+  S10TerminalRequest:                      10, 1,  s10::TerminalRequest,
+  S10TerminalAcknowledge:                  10, 2,  s10::TerminalAcknowledge,
+  S10TerminalDisplaySingle:                10, 3,  s10::TerminalDisplaySingle,
+  S10TerminalDisplaySingleAcknowledge:     10, 4,  s10::TerminalDisplaySingleAcknowledge,
+  S10TerminalDisplayMultiBlock:            10, 5,  s10::TerminalDisplayMultiBlock,
+  S10TerminalDisplayMultiBlockAcknowledge: 10, 6,  s10::TerminalDisplayMultiBlockAcknowledge,
+  S10MultiBlockNotAllowed:                 10, 7,  s10::MultiBlockNotAllowed,
+  S10Broadcast:                            10, 9,  s10::Broadcast,
+  S10BroadcastAcknowledge:                 10, 10, s10::BroadcastAcknowledge,
+  S12Abort:                                12, 0,  s12::Abort,
+  S12MapSetupDataSend:                     12, 1,  s12::MapSetupDataSend,
+  S12MapSetupDataAcknowledge:              12, 2,  s12::MapSetupDataAcknowledge,
+  S12MapSetupDataRequest:                  12, 3,  s12::MapSetupDataRequest,
+  S12MapSetupData:                         12, 4,  s12::MapSetupData,
+  S12MapTransmitInquire:                   12, 5,  s12::MapTransmitInquire,
+  S12MapTransmitGrant:                     12, 6,  s12::MapTransmitGrant,
+  S12MapDataSendType1:                     12, 7,  s12::MapDataSendType1,
+  S12MapDataAcknowledgeType1:              12, 8,  s12::MapDataAcknowledgeType1,
+  S12MapDataSendType2:                     12, 9,  s12::MapDataSendType2,
+  S12MapDataAcknowledgeType2:              12, 10, s12::MapDataAcknowledgeType2,
+  S12MapDataSendType3:                     12, 11, s12::MapDataSendType3,
+  S12MapDataAcknowledgeType3:              12, 12, s12::MapDataAcknowledgeType3,
+  S12MapDataRequestType1:                  12, 13, s12::MapDataRequestType1,
+  S12MapDataType1:                         12, 14, s12::MapDataType1,
+  S12MapDataRequestType2:                  12, 15, s12::MapDataRequestType2,
+  S12MapDataType2:                         12, 16, s12::MapDataType2,
+  S12MapDataRequestType3:                  12, 17, s12::MapDataRequestType3,
+  S12MapDataType3:                         12, 18, s12::MapDataType3,
+  S12MapErrorReportSend:                   12, 19, s12::MapErrorReportSend,
+  S13Abort:                                13, 0,  s13::Abort,
+  S13SendDataSetSend:                      13, 1,  s13::SendDataSetSend,
+  S13SendDataSetAcknowledge:               13, 2,  s13::SendDataSetAcknowledge,
+  S13OpenDataSetRequest:                   13, 3,  s13::OpenDataSetRequest,
+  S13OpenDataSetData:                      13, 4,  s13::OpenDataSetData,
+  S13ReadDataSetRequest:                   13, 5,  s13::ReadDataSetRequest,
+  S13ReadDataSetData:                      13, 6,  s13::ReadDataSetData,
+  S13CloseDataSetSend:                     13, 7,  s13::CloseDataSetSend,
+  S13CloseDataSetAcknowledge:              13, 8,  s13::CloseDataSetAcknowledge,
+  S13ResetDataSetSend:                     13, 9,  s13::ResetDataSetSend,
+  S13ResetDataSetAcknowledge:              13, 10, s13::ResetDataSetAcknowledge,
+  S13DataSetObjectMultiBlockInquire:       13, 11, s13::DataSetObjectMultiBlockInquire,
+  S13DataSetObjectMultiBlockGrant:         13, 12, s13::DataSetObjectMultiBlockGrant,
+  S13TableDataSend:                        13, 13, s13::TableDataSend,
+  S13TableDataAcknowledge:                 13, 14, s13::TableDataAcknowledge,
+  S13TableDataRequest:                     13, 15, s13::TableDataRequest,
+  S13TableData:                            13, 16, s13::TableData,
+  S14Abort:                                14, 0,  s14::Abort,
+  S14GetAttributeRequest:                  14, 1,  s14::GetAttributeRequest,
+  S14GetAttributeData:                     14, 2,  s14::GetAttributeData,
+  S14SetAttributeRequest:                  14, 3,  s14::SetAttributeRequest,
+  S14SetAttributeData:                     14, 4,  s14::SetAttributeData,
+  S14GetTypeRequest:                       14, 5,  s14::GetTypeRequest,
+  S14GetTypeData:                          14, 6,  s14::GetTypeData,
+  S14GetAttributeNameRequest:              14, 7,  s14::GetAttributeNameRequest,
+  S14GetAttributeNameData:                 14, 8,  s14::GetAttributeNameData,
+  S14CreateObjectRequest:                  14, 9,  s14::CreateObjectRequest,
+  S14CreateObjectAcknowledge:              14, 10, s14::CreateObjectAcknowledge,
+  S14DeleteObjectRequest:                  14, 11, s14::DeleteObjectRequest,
+  S14DeleteObjectAcknowledge:              14, 12, s14::DeleteObjectAcknowledge,
+  S14ObjectAttachRequest:                  14, 13, s14::ObjectAttachRequest,
+  S14ObjectAttachAcknowledge:              14, 14, s14::ObjectAttachAcknowledge,
+  S14AttachedObjectActionRequest:          14, 15, s14::AttachedObjectActionRequest,
+  S14AttachedObjectActionAcknowledge:      14, 16, s14::AttachedObjectActionAcknowledge,
+  S14SupervisedObjectActionRequest:        14, 17, s14::SupervisedObjectActionRequest,
+  S14SupervisedObjectActionAcknowledge:    14, 18, s14::SupervisedObjectActionAcknowledge,
+  S14GenericServiceRequest:                14, 19, s14::GenericServiceRequest,
+  S14GenericServiceAcknowledge:            14, 20, s14::GenericServiceAcknowledge,
+  S14GenericServiceCompletion:             14, 21, s14::GenericServiceCompletion,
+  S14GenericServiceCompletionAcknowledge:  14, 22, s14::GenericServiceCompletionAcknowledge,
+  S14GenericServiceMultiBlockInquire:      14, 23, s14::GenericServiceMultiBlockInquire,
+  S14GenericServiceMultiBlockGrant:        14, 24, s14::GenericServiceMultiBlockGrant,
+  S14ServiceNameRequest:                   14, 25, s14::ServiceNameRequest,
+  S14ServiceNameData:                      14, 26, s14::ServiceNameData,
+  S14ServiceParameterRequest:              14, 27, s14::ServiceParameterRequest,
+  S14ServiceParameterData:                 14, 28, s14::ServiceParameterData,
+  S15Abort:                                15, 0,  s15::Abort,
+  S15RecipeManagementMultiBlockInquire:    15, 1,  s15::RecipeManagementMultiBlockInquire,
+  S15RecipeManagementMultiBlockGrant:      15, 2,  s15::RecipeManagementMultiBlockGrant,
+  S15RecipeDownloadRequest:                15, 27, s15::RecipeDownloadRequest,
+  S15RecipeDownloadAcknowledge:            15, 28, s15::RecipeDownloadAcknowledge,
+  S15RecipeUploadRequest:                  15, 31, s15::RecipeUploadRequest,
+  S15RecipeUploadData:                     15, 32, s15::RecipeUploadData,
+  S16Abort:                                16, 0,  s16::Abort,
+  S16ProcessJobMultiBlockInquire:          16, 1,  s16::ProcessJobMultiBlockInquire,
+  S16ProcessJobMultiBlockGrant:            16, 2,  s16::ProcessJobMultiBlockGrant,
+  S16ProcessJobCommand:                    16, 5,  s16::ProcessJobCommand,
+  S16ProcessJobCommandAcknowledge:         16, 6,  s16::ProcessJobCommandAcknowledge,
+  S16ProcessJobAlertNotify:                16, 7,  s16::ProcessJobAlertNotify,
+  S16ProcessJobAlertConfirm:               16, 8,  s16::ProcessJobAlertConfirm,
+  S16ProcessJobCreateEnhanced:             16, 11, s16::ProcessJobCreateEnhanced,
+  S16ProcessJobCreateEnhancedAcknowledge:  16, 12, s16::ProcessJobCreateEnhancedAcknowledge,
+  S16ProcessJobMultiCreate:                16, 15, s16::ProcessJobMultiCreate,
+  S16ProcessJobMultiCreateAcknowledge:     16, 16, s16::ProcessJobMultiCreateAcknowledge,
+  S16ProcessJobDequeue:                    16, 17, s16::ProcessJobDequeue,
+  S16ProcessJobDequeueAcknowledge:         16, 18, s16::ProcessJobDequeueAcknowledge,
+  S16ProcessJobGetAllJobs:                 16, 19, s16::ProcessJobGetAllJobs,
+  S16ProcessJobGetAllJobsSend:             16, 20, s16::ProcessJobGetAllJobsSend,
+  S16ProcessJobGetSpace:                   16, 21, s16::ProcessJobGetSpace,
+  S16ProcessJobGetSpaceSend:               16, 22, s16::ProcessJobGetSpaceSend,
+  S16ProcessJobSetStartMethod:             16, 25, s16::ProcessJobSetStartMethod,
+  S16ProcessJobSetStartMethodAcknowledge:  16, 26, s16::ProcessJobSetStartMethodAcknowledge,
+  S16ControlJobCommandRequest:             16, 27, s16::ControlJobCommandRequest,
+  S16ControlJobCommandAcknowledge:         16, 28, s16::ControlJobCommandAcknowledge,
+  S17Abort:                                17, 0,  s17::Abort,
+  S17DataReportCreateRequest:              17, 1,  s17::DataReportCreateRequest,
+  S17DataReportCreateAcknowledge:          17, 2,  s17::DataReportCreateAcknowledge,
+  S17DataReportDeleteRequest:              17, 3,  s17::DataReportDeleteRequest,
+  S17DataReportDeleteAcknowledge:          17, 4,  s17::DataReportDeleteAcknowledge,
+  S17TraceCreateRequest:                   17, 5,  s17::TraceCreateRequest,
+  S17TraceCreateAcknowledge:               17, 6,  s17::TraceCreateAcknowledge,
+  S17TraceDeleteRequest:                   17, 7,  s17::TraceDeleteRequest,
+  S17TraceDeleteAcknowledge:               17, 8,  s17::TraceDeleteAcknowledge,
+  S17CollectionEventLinkRequest:           17, 9,  s17::CollectionEventLinkRequest,
+  S17CollectionEventLinkAcknowledge:       17, 10, s17::CollectionEventLinkAcknowledge,
+  S17CollectionEventUnlinkRequest:         17, 11, s17::CollectionEventUnlinkRequest,
+  S17CollectionEventUnlinkAcknowledge:     17, 12, s17::CollectionEventUnlinkAcknowledge,
+  S17TraceResetRequest:                    17, 13, s17::TraceResetRequest,
+  S17TraceResetAcknowledge:                17, 14, s17::TraceResetAcknowledge,
+  S18Abort:                                18, 0,  s18::Abort,
+  S18ReadAttributeRequest:                 18, 1,  s18::ReadAttributeRequest,
+  S18ReadAttributeData:                    18, 2,  s18::ReadAttributeData,
+  S18WriteAttributeRequest:                18, 3,  s18::WriteAttributeRequest,
+  S18WriteAttributeAcknowledge:            18, 4,  s18::WriteAttributeAcknowledge,
+  S18ReadRequest:                          18, 5,  s18::ReadRequest,
+  S18ReadData:                             18, 6,  s18::ReadData,
+  S18WriteDataRequest:                     18, 7,  s18::WriteDataRequest,
+  S18WriteDataAcknowledge:                 18, 8,  s18::WriteDataAcknowledge,
+  S18ReadIDRequest:                        18, 9,  s18::ReadIDRequest,
+  S18ReadIDData:                           18, 10, s18::ReadIDData,
+  S18WriteIDRequest:                       18, 11, s18::WriteIDRequest,
+  S18WriteIDAcknowledge:                   18, 12, s18::WriteIDAcknowledge,
+  S18SubsystemCommandRequest:              18, 13, s18::SubsystemCommandRequest,
+  S18SubsystemCommandAcknowledge:          18, 14, s18::SubsystemCommandAcknowledge,
+  S18Read2DCodeConditionRequest:           18, 15, s18::Read2DCodeConditionRequest,
+  S18Read2DCodeConditionData:              18, 16, s18::Read2DCodeConditionData,
+}
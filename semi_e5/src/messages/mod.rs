@@ -29,6 +29,14 @@
 //! Each such message defined herein implements:
 //! - [From]\<T\> for [Message]
 //! - [TryFrom]\<[Message]\> for T
+//! - [MessageHeader] for T
+//! 
+//! Some [Message]s, such as [S6F11] and [S2F17], also implement
+//! [TryFrom]\<&\[u8\]\> and `to_bytes`, converting directly between the
+//! binary data of their text and the structure without constructing a tree
+//! of [Item]s along the way. These are for use where the binary data of the
+//! text is handled directly, the HSMS clients of semi_e37 still exchanging
+//! [Message]s whose text is an [Item].
 //! 
 //! ---------------------------------------------------------------------------
 //! 
@@ -38,6 +46,382 @@
 //! [Message]: crate::Message
 //! [Stream]:  crate::Message::stream
 //! [Item]:    crate::Item
+//! [S6F11]:   s6::EventReport
+//! [S2F17]:   s2::DateTimeRequest
+
+use crate::{Error, Message};
+
+/// ## MESSAGE HEADER
+/// 
+/// The header information which is fixed for each particular [Message],
+/// implemented by every [Message] defined herein so that generic code can be
+/// written over all of them, such as for routing.
+/// 
+/// [Message]: crate::messages
+pub trait MessageHeader: Into<Message> + TryFrom<Message, Error = Error> {
+  /// ### STREAM
+  /// 
+  /// The [Stream] of the [Message].
+  /// 
+  /// [Message]: crate::messages
+  /// [Stream]:  crate::Message::stream
+  const STREAM: u8;
+
+  /// ### FUNCTION
+  /// 
+  /// The [Function] of the [Message].
+  /// 
+  /// [Message]:  crate::messages
+  /// [Function]: crate::Message::function
+  const FUNCTION: u8;
+
+  /// ### REPLY REQUESTED
+  /// 
+  /// The [Reply Bit] of the [Message].
+  /// 
+  /// Where a reply is optional, this is the [Reply Bit] used by default, and
+  /// each instance carries its own.
+  /// 
+  /// [Message]:   crate::messages
+  /// [Reply Bit]: crate::Message::w
+  const W: bool;
+
+  /// ### REPLY RULE
+  /// 
+  /// Whether the [Message] requires, forbids, or optionally allows a reply
+  /// to be requested, which the [Reply Bit] must satisfy.
+  /// 
+  /// Secondary [Message]s, which have an even [Function], always forbid a
+  /// reply from being requested.
+  /// 
+  /// [Message]:   crate::messages
+  /// [Function]:  crate::Message::function
+  /// [Reply Bit]: crate::Message::w
+  const REPLY: Reply;
+
+  /// ### STREAM
+  /// 
+  /// The [Stream] of the [Message].
+  /// 
+  /// [Message]: crate::messages
+  /// [Stream]:  crate::Message::stream
+  fn stream(&self) -> u8 {
+    Self::STREAM
+  }
+
+  /// ### FUNCTION
+  /// 
+  /// The [Function] of the [Message].
+  /// 
+  /// [Message]:  crate::messages
+  /// [Function]: crate::Message::function
+  fn function(&self) -> u8 {
+    Self::FUNCTION
+  }
+
+  /// ### REPLY REQUESTED
+  /// 
+  /// The [Reply Bit] of the [Message].
+  /// 
+  /// [Message]:   crate::messages
+  /// [Reply Bit]: crate::Message::w
+  fn w(&self) -> bool {
+    Self::W
+  }
+
+  /// ### HEADER BYTES
+  /// 
+  /// The first four bytes of the message header used by both SECS-I and
+  /// HSMS, given the Device ID or Session ID to attach:
+  /// 
+  /// - Bytes 0-1: Device ID or Session ID, big-endian. Under SECS-I, the
+  ///   most significant bit is the R-bit, which is left to the caller.
+  /// - Byte 2: [Reply Bit] and [Stream].
+  /// - Byte 3: [Function].
+  /// 
+  /// [Stream]:    crate::Message::stream
+  /// [Function]:  crate::Message::function
+  /// [Reply Bit]: crate::Message::w
+  fn header(&self, device: u16) -> [u8; 4] {
+    let [upper, lower] = device.to_be_bytes();
+    [upper, lower, ((self.w() as u8) << 7) | (Self::STREAM & 0b0111_1111), Self::FUNCTION]
+  }
+}
+
+/// ## REPLY
+/// 
+/// Whether a particular [Message] requires, forbids, or optionally allows a
+/// reply to be requested.
+/// 
+/// [Message]: crate::messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reply {
+  /// ### REPLY REQUIRED
+  Required,
+
+  /// ### REPLY FORBIDDEN
+  Forbidden,
+
+  /// ### REPLY OPTIONAL
+  Optional,
+}
+impl Reply {
+  /// ### PERMITS
+  /// 
+  /// Whether the provided [Reply Bit] is allowed by this rule.
+  /// 
+  /// [Reply Bit]: crate::Message::w
+  pub const fn permits(self, w: bool) -> bool {
+    match self {
+      Reply::Required  => w,
+      Reply::Forbidden => !w,
+      Reply::Optional  => true,
+    }
+  }
+}
+
+/// ## ROLE
+/// 
+/// Which entity sends a particular [Message], used to select the structure
+/// of those [Message]s which differ depending on the sender.
+/// 
+/// [Message]: crate::messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+  /// ### HOST
+  Host,
+
+  /// ### EQUIPMENT
+  Equipment,
+}
+
+/// ## PRIMARY MESSAGE
+/// 
+/// Implemented by each primary [Message] defined herein which requires a
+/// reply, linking it to the type of the corresponding reply [Message].
+/// 
+/// Where the structure of the reply depends on which entity sends it, such
+/// as with [S1F1], the reply is a directional [Message] which is able to
+/// hold either structure.
+/// 
+/// [Message]: crate::messages
+/// [S1F1]:    s1::AreYouThere
+pub trait PrimaryMessage: MessageHeader {
+  /// ### REPLY
+  /// 
+  /// The type of the reply [Message].
+  /// 
+  /// [Message]: crate::messages
+  type Reply: MessageHeader;
+}
+
+/// ## INQUIRE MESSAGE
+/// 
+/// Implemented by each primary [Message] defined herein which asks
+/// permission to send a multi-block [Message], allowing the reply to be
+/// checked for whether permission was granted.
+/// 
+/// [Message]: crate::messages
+pub trait InquireMessage: PrimaryMessage {
+  /// ### IS GRANTED
+  /// 
+  /// Whether the reply grants permission to send the multi-block [Message].
+  /// 
+  /// [Message]: crate::messages
+  fn is_granted(reply: &Self::Reply) -> bool;
+}
+
+/// ## MULTI-BLOCK MESSAGE
+/// 
+/// Implemented by each primary [Message] defined herein which, being
+/// multi-block, must be preceded by an [Inquire Message] whose reply grants
+/// permission to send it.
+/// 
+/// [Message]:         crate::messages
+/// [Inquire Message]: InquireMessage
+pub trait MultiBlockMessage: PrimaryMessage {
+  /// ### INQUIRE
+  /// 
+  /// The type of the [Inquire Message] preceding this [Message].
+  /// 
+  /// [Message]:         crate::messages
+  /// [Inquire Message]: InquireMessage
+  type Inquire: InquireMessage;
+
+  /// ### INQUIRE
+  /// 
+  /// Constructs the [Inquire Message] preceding this [Message], given the
+  /// number of bytes its text occupies when serialized.
+  /// 
+  /// [Message]:         crate::messages
+  /// [Inquire Message]: InquireMessage
+  fn inquire(&self, length: usize) -> Self::Inquire;
+}
+
+/// ## SxF0
+/// 
+/// **Abort Transaction**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Used in lieu of an expected reply to abort a transaction, for any
+/// [Stream].
+/// 
+/// Converting from a [Generic Message] accepts the Abort Transaction message
+/// of every [Stream], which is then retained.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// Header only.
+/// 
+/// [Stream]:          crate::Message::stream
+/// [Generic Message]: crate::Message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Abort {
+  pub stream: u8,
+}
+impl Abort {
+  /// ### FOR STREAM
+  /// 
+  /// The Abort Transaction message of the provided [Stream].
+  /// 
+  /// [Stream]: crate::Message::stream
+  pub const fn for_stream(stream: u8) -> Self {
+    Self {stream}
+  }
+}
+impl From<Abort> for Message {
+  fn from(value: Abort) -> Self {
+    Message {
+      stream:   value.stream,
+      function: 0,
+      w:        false,
+      text:     None,
+    }
+  }
+}
+impl TryFrom<Message> for Abort {
+  type Error = Error;
+
+  fn try_from(message: Message) -> Result<Self, Self::Error> {
+    if message.function != 0 {return Err(Error::WrongFunction)}
+    if message.w             {return Err(Error::WrongReply)}
+    match message.text {
+      None => Ok(Abort::for_stream(message.stream)),
+      Some(_item) => Err(Error::WrongFormat),
+    }
+  }
+}
+
+/// ## TYPED MESSAGE
+/// 
+/// An object-safe view of any [Message] defined herein, allowing [Message]s
+/// of differing types to be handled together, such as when returned by the
+/// [Decode] function.
+/// 
+/// The specific type can be recovered with `downcast` or `downcast_ref`.
+/// 
+/// [Message]: crate::messages
+/// [Decode]:  registry::decode
+pub trait TypedMessage: std::any::Any + Send {
+  /// ### STREAM
+  fn stream(&self) -> u8;
+
+  /// ### FUNCTION
+  fn function(&self) -> u8;
+
+  /// ### REPLY REQUESTED
+  fn w(&self) -> bool;
+
+  /// ### TYPE NAME
+  /// 
+  /// The full path of the [Message]'s type.
+  /// 
+  /// [Message]: crate::messages
+  fn type_name(&self) -> &'static str;
+
+  /// ### INTO GENERIC MESSAGE
+  /// 
+  /// Converts the boxed [Message] back into a [Generic Message].
+  /// 
+  /// [Message]:         crate::messages
+  /// [Generic Message]: crate::Message
+  fn into_message(self: Box<Self>) -> Message;
+
+  /// ### AS ANY
+  fn as_any(&self) -> &dyn std::any::Any;
+
+  /// ### INTO ANY
+  fn into_any(self: Box<Self>) -> Box<dyn std::any::Any>;
+}
+impl<T: MessageHeader + Send + 'static> TypedMessage for T {
+  fn stream(&self) -> u8 {
+    T::STREAM
+  }
+
+  fn function(&self) -> u8 {
+    T::FUNCTION
+  }
+
+  fn w(&self) -> bool {
+    MessageHeader::w(self)
+  }
+
+  fn type_name(&self) -> &'static str {
+    std::any::type_name::<T>()
+  }
+
+  fn into_message(self: Box<Self>) -> Message {
+    (*self).into()
+  }
+
+  fn as_any(&self) -> &dyn std::any::Any {
+    self
+  }
+
+  fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+    self
+  }
+}
+impl dyn TypedMessage {
+  /// ### IS
+  /// 
+  /// Whether the [Message] is of the provided type.
+  /// 
+  /// [Message]: crate::messages
+  pub fn is<T: TypedMessage>(&self) -> bool {
+    self.as_any().is::<T>()
+  }
+
+  /// ### DOWNCAST REFERENCE
+  /// 
+  /// Borrows the [Message] as the provided type, if it is of that type.
+  /// 
+  /// [Message]: crate::messages
+  pub fn downcast_ref<T: TypedMessage>(&self) -> Option<&T> {
+    self.as_any().downcast_ref::<T>()
+  }
+
+  /// ### DOWNCAST
+  /// 
+  /// Converts the [Message] into the provided type, if it is of that type,
+  /// or returns it unchanged otherwise.
+  /// 
+  /// [Message]: crate::messages
+  pub fn downcast<T: TypedMessage>(self: Box<Self>) -> Result<Box<T>, Box<Self>> {
+    if self.is::<T>() {
+      Ok(self.into_any().downcast::<T>().unwrap_or_else(|_| unreachable!()))
+    } else {
+      Err(self)
+    }
+  }
+}
 
 /// ## MESSAGE MACRO: HEADER ONLY
 /// 
@@ -48,7 +432,8 @@
 /// #### Arguments
 /// 
 /// - **$name**: Name of struct.
-/// - **$w**: W-bit of message.
+/// - **$w**: W-bit of message, or `optional` if a reply may optionally be
+///   requested, in which case the struct holds the W-bit as its only field.
 /// - **$stream**: Stream of message.
 /// - **$function**: Function of message.
 /// 
@@ -58,13 +443,71 @@
 /// 
 /// - From\<$name\> for Message
 /// - TryFrom\<Message\> for $name
+/// - MessageHeader for $name
 macro_rules! message_headeronly {
+  (
+    $name:ident,
+    optional,
+    $stream:expr,
+    $function:expr
+  ) => {
+    impl crate::messages::MessageHeader for $name {
+      const STREAM:   u8   = $stream;
+      const FUNCTION: u8   = $function;
+      const W:        bool = true;
+      const REPLY:    crate::messages::Reply = crate::messages::Reply::Optional;
+
+      fn w(&self) -> bool {
+        self.0
+      }
+    }
+    const _: () = assert!(
+      $function % 2 == 1,
+      "a secondary message cannot request a reply",
+    );
+    impl From<$name> for Message {
+      fn from(value: $name) -> Self {
+        Message {
+          stream:   $stream,
+          function: $function,
+          w:        value.0,
+          text:     None,
+        }
+      }
+    }
+    impl TryFrom<Message> for $name {
+      type Error = Error;
+
+      fn try_from(message: Message) -> Result<Self, Self::Error> {
+        if message.stream   != $stream   {return Err(WrongStream)}
+        if message.function != $function {return Err(WrongFunction)}
+        match message.text {
+          None => Ok($name(message.w)),
+          Some(_item) => Err(WrongFormat),
+        }
+      }
+    }
+  };
   (
     $name:ident,
     $w:expr,
     $stream:expr,
     $function:expr
   ) => {
+    impl crate::messages::MessageHeader for $name {
+      const STREAM:   u8   = $stream;
+      const FUNCTION: u8   = $function;
+      const W:        bool = $w;
+      const REPLY:    crate::messages::Reply = if $w {
+        crate::messages::Reply::Required
+      } else {
+        crate::messages::Reply::Forbidden
+      };
+    }
+    const _: () = assert!(
+      !($w && $function % 2 == 0),
+      "a secondary message cannot request a reply",
+    );
     impl From<$name> for Message {
       fn from(_value: $name) -> Self {
         Message {
@@ -100,7 +543,9 @@ macro_rules! message_headeronly {
 /// #### Arguments
 /// 
 /// - **$name**: Name of struct.
-/// - **$w**: W-bit of message.
+/// - **$w**: W-bit of message, or `optional` if a reply may optionally be
+///   requested, in which case the struct holds the W-bit as its second
+///   field.
 /// - **$stream**: Stream of message.
 /// - **$function**: Function of message.
 /// 
@@ -110,13 +555,71 @@ macro_rules! message_headeronly {
 /// 
 /// - From\<$name\> for Message
 /// - TryFrom\<Message\> for $name
+/// - MessageHeader for $name
 macro_rules! message_data {
+  (
+    $name:ident,
+    optional,
+    $stream:expr,
+    $function:expr
+  ) => {
+    impl crate::messages::MessageHeader for $name {
+      const STREAM:   u8   = $stream;
+      const FUNCTION: u8   = $function;
+      const W:        bool = true;
+      const REPLY:    crate::messages::Reply = crate::messages::Reply::Optional;
+
+      fn w(&self) -> bool {
+        self.1
+      }
+    }
+    const _: () = assert!(
+      $function % 2 == 1,
+      "a secondary message cannot request a reply",
+    );
+    impl From<$name> for Message {
+      fn from(value: $name) -> Self {
+        Message {
+          stream:   $stream,
+          function: $function,
+          w:        value.1,
+          text:     Some(value.0.into()),
+        }
+      }
+    }
+    impl TryFrom<Message> for $name {
+      type Error = Error;
+
+      fn try_from(message: Message) -> Result<Self, Self::Error> {
+        if message.stream   != $stream   {return Err(WrongStream)}
+        if message.function != $function {return Err(WrongFunction)}
+        match message.text {
+          Some(item) => {Ok(Self(item.try_into()?, message.w))},
+          None => Err(WrongFormat),
+        }
+      }
+    }
+  };
   (
     $name:ident,
     $w:expr,
     $stream:expr,
     $function:expr
   ) => {
+    impl crate::messages::MessageHeader for $name {
+      const STREAM:   u8   = $stream;
+      const FUNCTION: u8   = $function;
+      const W:        bool = $w;
+      const REPLY:    crate::messages::Reply = if $w {
+        crate::messages::Reply::Required
+      } else {
+        crate::messages::Reply::Forbidden
+      };
+    }
+    const _: () = assert!(
+      !($w && $function % 2 == 0),
+      "a secondary message cannot request a reply",
+    );
     impl From<$name> for Message {
       fn from(value: $name) -> Self {
         Message {
@@ -162,6 +665,7 @@ macro_rules! message_data {
 /// 
 /// - From\<$name\> for Message
 /// - TryFrom\<Message\> for $name
+/// - MessageHeader for $name
 macro_rules! message_item {
   (
     $name:ident,
@@ -169,6 +673,20 @@ macro_rules! message_item {
     $stream:expr,
     $function:expr
   ) => {
+    impl crate::messages::MessageHeader for $name {
+      const STREAM:   u8   = $stream;
+      const FUNCTION: u8   = $function;
+      const W:        bool = $w;
+      const REPLY:    crate::messages::Reply = if $w {
+        crate::messages::Reply::Required
+      } else {
+        crate::messages::Reply::Forbidden
+      };
+    }
+    const _: () = assert!(
+      !($w && $function % 2 == 0),
+      "a secondary message cannot request a reply",
+    );
     impl From<$name> for Message {
       fn from(value: $name) -> Self {
         Message {
@@ -195,61 +713,150 @@ macro_rules! message_item {
   }
 }
 
-pub mod s1;
-pub mod s2;
-
-/// # STREAM 3: MATERIAL STATUS
-/// **Based on SEMI E5§10.7**
+/// ## MESSAGE MACRO: DIRECTIONAL
+/// 
+/// To be used with particular messages whose structure differs depending on
+/// whether the host or equipment sends them, each structure having been
+/// defined as its own message struct.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
-/// [Message]s which deal with communicating information and actions related
-/// to material, including carriers and material-in-process,
-/// time-to-completion information, and extraordinary material circumstances.
+/// #### Arguments
 /// 
-/// ---------------------------------------------------------------------------
+/// - **$meta**: Attributes of directional message enum, such as its docs.
+/// - **$name**: Name of directional message enum.
+/// - **$host**: Name of message struct sent by the host.
+/// - **$equipment**: Name of message struct sent by the equipment.
 /// 
-/// ## TO BE DONE
+/// ---------------------------------------------------------------------------
 /// 
-/// - Fill out stream contents
+/// #### Expansion
 /// 
-/// [Message]: crate::Message
-pub mod s3 {}
+/// - Enum $name, with variants Host($host) and Equipment($equipment)
+/// - $name::decode_from(Message, Role)
+/// - $name::sender()
+/// - MessageHeader for $name
+/// - From<$host> for $name
+/// - From<$equipment> for $name
+/// - Into<Message> for $name
+/// - TryFrom<Message> for $name, trying $host first, then $equipment
+macro_rules! message_directional {
+  (
+    $(#[$meta:meta])*
+    $name:ident,
+    $host:ident,
+    $equipment:ident
+  ) => {
+    $(#[$meta])*
+    pub enum $name {
+      Host($host),
+      Equipment($equipment),
+    }
+    impl $name {
+      /// ### DECODE FROM
+      /// 
+      /// Converts a [Generic Message] into the structure used by the
+      /// given sender.
+      /// 
+      /// [Generic Message]: crate::Message
+      pub fn decode_from(message: Message, sender: crate::messages::Role) -> Result<Self, Error> {
+        match sender {
+          crate::messages::Role::Host      => Ok(Self::Host($host::try_from(message)?)),
+          crate::messages::Role::Equipment => Ok(Self::Equipment($equipment::try_from(message)?)),
+        }
+      }
 
-/// # STREAM 4: MATERIAL CONTROL
-/// **Based on SEMI E5§10.8**
+      /// ### SENDER
+      /// 
+      /// The entity whose structure is held.
+      pub fn sender(&self) -> crate::messages::Role {
+        match self {
+          Self::Host(_)      => crate::messages::Role::Host,
+          Self::Equipment(_) => crate::messages::Role::Equipment,
+        }
+      }
+    }
+    impl crate::messages::MessageHeader for $name {
+      const STREAM: u8 = <$host as crate::messages::MessageHeader>::STREAM;
+      const FUNCTION: u8 = <$host as crate::messages::MessageHeader>::FUNCTION;
+      const W: bool = <$host as crate::messages::MessageHeader>::W;
+      const REPLY: crate::messages::Reply = <$host as crate::messages::MessageHeader>::REPLY;
+    }
+    impl From<$host> for $name {
+      fn from(value: $host) -> Self {
+        Self::Host(value)
+      }
+    }
+    impl From<$equipment> for $name {
+      fn from(value: $equipment) -> Self {
+        Self::Equipment(value)
+      }
+    }
+    impl From<$name> for Message {
+      fn from(value: $name) -> Self {
+        match value {
+          $name::Host(message)      => message.into(),
+          $name::Equipment(message) => message.into(),
+        }
+      }
+    }
+    impl TryFrom<Message> for $name {
+      type Error = Error;
+
+      fn try_from(message: Message) -> Result<Self, Self::Error> {
+        match $host::try_from(message.clone()) {
+          Ok(host) => Ok(Self::Host(host)),
+          Err(_) => Ok(Self::Equipment($equipment::try_from(message)?)),
+        }
+      }
+    }
+  }
+}
+
+/// ## MESSAGE MACRO: REPLY
+/// 
+/// To be used with particular messages which are the reply to a particular
+/// primary message.
 /// 
 /// ---------------------------------------------------------------------------
 /// 
-/// [Message]s which deal with the original material control protocol and the
-/// newer protocol which supports [SEMI E32].
+/// #### Arguments
 /// 
-/// ---------------------------------------------------------------------------
+/// - **$primary**: Name of primary message struct.
+/// - **$reply**: Name of reply message struct.
 /// 
-/// ## TO BE DONE
+/// ---------------------------------------------------------------------------
 /// 
-/// - Fill out stream contents
+/// #### Expansion
 /// 
-/// [Message]: crate::Message
-pub mod s4 {}
+/// - PrimaryMessage for $primary
+macro_rules! message_reply {
+  (
+    $primary:ident,
+    $reply:ident
+  ) => {
+    impl crate::messages::PrimaryMessage for $primary {
+      type Reply = $reply;
+    }
+  }
+}
 
-pub mod s5;
-pub mod s6;
+pub mod any;
+pub mod registry;
+mod wire;
 
-/// # STREAM 7: PROCESS PROGRAM MANAGEMENT
-/// **Based on SEMI E5§10.11**
+pub mod s1;
+pub mod s2;
+
+pub mod s3;
+
+/// # STREAM 4: MATERIAL CONTROL
+/// **Based on SEMI E5§10.8**
 /// 
 /// ---------------------------------------------------------------------------
 /// 
-/// [Message]s which deal with the management and transfer of Process Programs.
-/// 
-/// Process Programs are the equipment-specific descriptions that determine
-/// the procedure to be conducted on the material by a single piece of
-/// equipment.
-/// 
-/// Methods are provided to transfer programs as well as establish the link
-/// between the process program and the material to be processed with that
-/// program.
+/// [Message]s which deal with the original material control protocol and the
+/// newer protocol which supports [SEMI E32].
 /// 
 /// ---------------------------------------------------------------------------
 /// 
@@ -258,7 +865,11 @@ pub mod s6;
 /// - Fill out stream contents
 /// 
 /// [Message]: crate::Message
-pub mod s7 {}
+pub mod s4 {}
+
+pub mod s5;
+pub mod s6;
+pub mod s7;
 
 /// # STREAM 8: CONTROL PROGRAM TRANSFER
 /// **Based on SEMI E5§10.12**
@@ -278,26 +889,7 @@ pub mod s7 {}
 /// [Message]: crate::Message
 pub mod s8 {}
 
-/// # STREAM 9: SYSTEM ERRORS
-/// **Based on SEMI E5§10.13**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with informing the host of communication errors,
-/// particularly that a message block has been received which cannot be
-/// handled or that a timeout on a transaction reception timer has occurred.
-/// 
-/// The messages indicate either a Message Fault or a Communications Fault
-/// has occurred but do not indicate a Communications Failure has occurred.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s9 {}
+pub mod s9;
 
 pub mod s10;
 
@@ -318,192 +910,19 @@ pub mod s10;
 /// [Message]: crate::Message
 pub mod s11 {}
 
-/// # STREAM 12: WAFER MAPPING
-/// **Based on SEMI E5§10.16**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with coordinate positions and data associated with
-/// those positions.
-/// 
-/// This includes functions such as wafer mapping with the coordinates of die
-/// on wafer maps to and from the process equipment.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// S12F1 through S12F20 address the variations required by semiconductor
-/// equipment manufactureers in transmitting wafer maps to and from the
-/// process equipment.
-/// 
-/// The functions include three basic formats:
-/// 
-/// - Row/Column - A coordinate row starting position is given with die count
-///   in the row and starting direction. The respective binning information
-///   follows each die.
-/// - Array - A matrix array captures all or part of a wafer with the
-///   associated binning information.
-/// - Coordinate - An X/Y location and bin code for die on the wafer.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Complete this documentation
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s12 {}
+pub mod s12;
 
-/// # STREAM 13: DATA SET TRANSFER
-/// **Based on SEMI E5§10.17**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with the transfer of data sets between systems.
-/// 
-/// It is not intended to provide a general file access mechanism.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Complete this documentation
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s13 {}
+pub mod s13;
 
-/// # STREAM 14: OBJECT SERVICES
-/// **Based on SEMI E5§10.18**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with generic functions concerning objects,
-/// including obtaining information about objects and setting values for an
-/// object.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s14 {}
+pub mod s14;
 
-/// # STREAM 15: RECIPE MANAGEMENT
-/// **Based on SEMI E5§10.19**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with requestion information and operations
-/// concerning recipes, recipe namespaces, and recipe executors.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// A recipe is an object that is transferred in sections, where a section
-/// consists of either recipe attributes, agent-specific dataset attributes,
-/// or the body of the recipe.
-/// 
-/// An attribute is information concerning the recipe body, the recipe as a
-/// whole, or the application of the recipe, and consists of a name/value
-/// pair.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s15 {}
+pub mod s15;
 
-/// # STREAM 16: PROCESSING MANAGEMENT
-/// **Based on SEMI E5§10.20**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with control of material processing at equipment
-/// and equipment resources.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// Control is implemented by supporting two job types; the control job and
-/// the process job.
-/// 
-/// A process job is a single unit of work that ensures that the appropriate
-/// processing is applied to a particular material by a processing resource.
-/// It provides a widely applicable supervisory control capability for
-/// automated processing of material in equipment, irrespective of the
-/// particular process being used. It also creates a transient link between
-/// the three elements of the manufacturing process (material, equipment,
-/// and recipe). When a process job has been completed, it ceases to exist;
-/// its Job ID is no longer valid.
-/// 
-/// A control job is used to group a set of related process jobs. The group
-/// is logically related from the host's viewpoint. It also provides
-/// mechanisms for specifying the destination for processed material.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s16 {}
+pub mod s16;
 
-/// # STREAM 17: EQUIPMENT CONTROL AND DIAGNOSTICS
-/// **Based on SEMI E5§10.21**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with control of the equipment from the host.
-/// 
-/// This includes all remote operations and equipment self-diagnostics and
-/// calibration but specifically excluses:
-/// 
-/// - Control operations associated with material transfer ([Stream 4]).
-/// - Loading of executive and boot programs ([Stream 8]).
-/// - File and operating system calls ([Stream 10], [Stream 13]).
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// This is a continuation of [Stream 2].
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-/// [Stream 2]: crate::messages::s2
-/// [Stream 4]: crate::messages::s4
-/// [Stream 8]: crate::messages::s8
-/// [Stream 10]: crate::messages::s10
-/// [Stream 13]: crate::messages::s13
-pub mod s17 {}
+pub mod s17;
 
-/// # STREAM 18: SUBSYSTEM CONTROL AND DATA
-/// **Based on SEMI E5§10.22**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with interfacing between component subsystems and
-/// higher level controllers.
-/// 
-/// Compared to similar mesages exchanged between equipment and host,
-/// subsystem messages are less complex.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s18 {}
+pub mod s18;
 
 /// # STREAM 19: RECIPE AND PARAMETER MANAGEMENT
 /// **Based on SEMI E5§10.23**
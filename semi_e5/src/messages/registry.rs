@@ -0,0 +1,630 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # MESSAGE REGISTRY
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message Metadata] for every [Message] defined herein, searchable by
+//! [Stream] and [Function], so that tools such as log viewers and error
+//! reporting are able to describe a [Message] without first converting it
+//! into a specific type.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Where more than one [Message] has been defined for the same [Stream] and
+//! [Function], such as where their structure differs based on the sender,
+//! each is listed separately, in the order they are defined.
+//! 
+//! [Message Metadata]: MessageMetadata
+//! [Message]:          crate::messages
+//! [Stream]:           crate::Message::stream
+//! [Function]:         crate::Message::function
+
+use crate::{Error, Message};
+use super::{MessageHeader, TypedMessage};
+pub use super::Reply;
+
+/// ## MESSAGE METADATA
+/// 
+/// Information about a [Message] as defined by the standard.
+/// 
+/// [Message]: crate::messages
+#[derive(Clone, Copy, Debug)]
+pub struct MessageMetadata {
+  /// ### STREAM
+  pub stream: u8,
+
+  /// ### FUNCTION
+  pub function: u8,
+
+  /// ### TYPE NAME
+  /// 
+  /// The path of the type representing the [Message], relative to the
+  /// [messages] module.
+  /// 
+  /// [Message]:  crate::messages
+  /// [messages]: crate::messages
+  pub type_name: &'static str,
+
+  /// ### NAME
+  /// 
+  /// The name of the [Message] as given by the standard.
+  /// 
+  /// [Message]: crate::messages
+  pub name: &'static str,
+
+  /// ### MNEMONIC
+  /// 
+  /// The abbreviation of the [Message]'s name as given by the standard, if
+  /// any.
+  /// 
+  /// [Message]: crate::messages
+  pub mnemonic: Option<&'static str>,
+
+  /// ### DIRECTION
+  pub direction: Direction,
+
+  /// ### BLOCKS
+  pub blocks: Blocks,
+
+  /// ### REPLY
+  pub reply: Reply,
+
+  /// ### DECODE
+  /// 
+  /// Converts a [Generic Message] into the [Message] this metadata describes.
+  /// 
+  /// [Generic Message]: crate::Message
+  /// [Message]:         crate::messages
+  pub decode: fn(Message) -> Result<Box<dyn TypedMessage>, Error>,
+}
+impl std::fmt::Display for MessageMetadata {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let type_name = self.type_name.rsplit("::").next().unwrap_or(self.type_name);
+    write!(f, "S{}F{} {}", self.stream, self.function, type_name)
+  }
+}
+
+/// ## DIRECTION
+/// 
+/// Which entity may send a particular [Message].
+/// 
+/// [Message]: crate::messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+  /// ### HOST -> EQUIPMENT
+  HostToEquipment,
+
+  /// ### HOST <- EQUIPMENT
+  EquipmentToHost,
+
+  /// ### HOST <-> EQUIPMENT
+  Both,
+}
+
+/// ## BLOCKS
+/// 
+/// Whether a particular [Message] may span multiple blocks under SECS-I.
+/// 
+/// [Message]: crate::messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Blocks {
+  /// ### SINGLE-BLOCK
+  Single,
+
+  /// ### MULTI-BLOCK
+  Multi,
+}
+
+/// ## LOOKUP
+/// 
+/// Finds the [Message Metadata] for the first [Message] defined with the
+/// provided [Stream] and [Function].
+/// 
+/// [Message Metadata]: MessageMetadata
+/// [Message]:          crate::messages
+/// [Stream]:           crate::Message::stream
+/// [Function]:         crate::Message::function
+pub fn lookup(stream: u8, function: u8) -> Option<&'static MessageMetadata> {
+  lookup_all(stream, function).next()
+}
+
+/// ## LOOKUP ALL
+/// 
+/// Finds the [Message Metadata] for every [Message] defined with the
+/// provided [Stream] and [Function].
+/// 
+/// [Message Metadata]: MessageMetadata
+/// [Message]:          crate::messages
+/// [Stream]:           crate::Message::stream
+/// [Function]:         crate::Message::function
+pub fn lookup_all(stream: u8, function: u8) -> impl Iterator<Item = &'static MessageMetadata> {
+  REGISTRY.iter().filter(move |metadata| metadata.stream == stream && metadata.function == function)
+}
+
+/// ## DESCRIBE
+/// 
+/// Describes a [Message] by its [Stream], [Function], and type name, such as
+/// "S1F1 AreYouThere", or by its [Stream] and [Function] alone if it is not
+/// registered.
+/// 
+/// [Message]:  crate::Message
+/// [Stream]:   crate::Message::stream
+/// [Function]: crate::Message::function
+pub fn describe(stream: u8, function: u8) -> String {
+  match lookup(stream, function) {
+    Some(metadata) => metadata.to_string(),
+    None => format!("S{}F{}", stream, function),
+  }
+}
+
+/// ## CHECK REPLY
+/// 
+/// Validates the [Reply Bit] of a [Generic Message] against the [Reply] rule
+/// of each [Message] registered with its [Stream] and [Function].
+/// 
+/// A reply may never be requested by a secondary [Message], which has an
+/// even [Function], whether or not it is registered.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Errors
+/// 
+/// - [Wrong Reply] - The [Reply Bit] is not permitted.
+/// 
+/// [Generic Message]: Message
+/// [Message]:         crate::messages
+/// [Stream]:          crate::Message::stream
+/// [Function]:        crate::Message::function
+/// [Reply Bit]:       crate::Message::w
+/// [Wrong Reply]:     Error::WrongReply
+pub fn check_reply(message: &Message) -> Result<(), Error> {
+  if message.function % 2 == 0 && message.w {
+    return Err(Error::WrongReply)
+  }
+  let mut candidates = lookup_all(message.stream, message.function).peekable();
+  if candidates.peek().is_none() || candidates.any(|metadata| metadata.reply.permits(message.w)) {
+    Ok(())
+  } else {
+    Err(Error::WrongReply)
+  }
+}
+
+/// ## DECODE
+/// 
+/// Converts a [Generic Message] into whichever specific [Message] is
+/// registered with its [Stream] and [Function], boxed as a [Typed Message].
+/// 
+/// Where more than one [Message] is registered, each is attempted in turn and
+/// the first successful conversion is returned.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Errors
+/// 
+/// - [Wrong Stream] - No [Message] is registered with the [Stream].
+/// - [Wrong Function] - No [Message] is registered with the [Function].
+/// - Otherwise, the error from the last attempted conversion.
+/// 
+/// [Generic Message]: Message
+/// [Message]:         crate::messages
+/// [Typed Message]:   TypedMessage
+/// [Stream]:          crate::Message::stream
+/// [Function]:        crate::Message::function
+/// [Wrong Stream]:    Error::WrongStream
+/// [Wrong Function]:  Error::WrongFunction
+pub fn decode(message: Message) -> Result<Box<dyn TypedMessage>, Error> {
+  if !REGISTRY.iter().any(|metadata| metadata.stream == message.stream) {
+    return Err(Error::WrongStream)
+  }
+  let candidates: Vec<&MessageMetadata> = lookup_all(message.stream, message.function).collect();
+  let mut message = Some(message);
+  let mut error = Error::WrongFunction;
+  for (index, metadata) in candidates.iter().enumerate() {
+    let attempt = if index + 1 == candidates.len() {message.take()} else {message.clone()};
+    if let Some(attempt) = attempt {
+      match (metadata.decode)(attempt) {
+        Ok(typed) => return Ok(typed),
+        Err(e) => error = e,
+      }
+    }
+  }
+  Err(error)
+}
+
+/// ## INTERNAL ENTRY MACRO
+/// 
+/// Constructs the [Message Metadata] of a [Message], given its type.
+/// 
+/// [Message Metadata]: MessageMetadata
+/// [Message]:          crate::messages
+macro_rules! entry {
+  (
+    $stream:expr,
+    $function:expr,
+    $type:ty,
+    $name:expr,
+    $mnemonic:expr,
+    $direction:expr,
+    $blocks:expr,
+    $reply:expr
+  ) => {
+    MessageMetadata {
+      stream:    $stream,
+      function:  $function,
+      type_name: stringify!($type),
+      name:      $name,
+      mnemonic:  $mnemonic,
+      direction: $direction,
+      blocks:    $blocks,
+      reply:     $reply,
+      decode:    decode_as::<$type>,
+    }
+  }
+}
+
+/// ## INTERNAL DECODER
+/// 
+/// Converts a [Generic Message] into a specific [Message], boxed as a
+/// [Typed Message].
+/// 
+/// [Generic Message]: Message
+/// [Message]:         crate::messages
+/// [Typed Message]:   TypedMessage
+fn decode_as<T: MessageHeader + Send + 'static>(message: Message) -> Result<Box<dyn TypedMessage>, Error> {
+  Ok(Box::new(T::try_from(message)?))
+}
+
+use Direction::*;
+use Blocks::*;
+use Reply::*;
+use super::{s1, s2, s3, s5, s6, s7, s9, s10, s12, s13, s14, s15, s16, s17, s18};
+
+/// ## REGISTRY
+/// 
+/// The [Message Metadata] of every [Message] defined herein, ordered by
+/// [Stream] and [Function].
+/// 
+/// [Message Metadata]: MessageMetadata
+/// [Message]:          crate::messages
+/// [Stream]:           crate::Message::stream
+/// [Function]:         crate::Message::function
+pub static REGISTRY: &[MessageMetadata] = &[
+  entry!(1,  0,  s1::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(1,  1,  s1::AreYouThere,                         "Are You There Request",                        Some("R"),     Both,            Single, Required),
+  entry!(1,  2,  s1::OnLineDataHost,                      "On Line Data",                                 Some("D"),     HostToEquipment, Single, Forbidden),
+  entry!(1,  2,  s1::OnLineDataEquipment,                 "On Line Data",                                 Some("D"),     EquipmentToHost, Single, Forbidden),
+  entry!(1,  3,  s1::SelectedEquipmentStatusRequest,      "Selected Equipment Status Request",            Some("SSR"),   HostToEquipment, Single, Required),
+  entry!(1,  4,  s1::SelectedEquipmentStatusData,         "Selected Equipment Status Data",               Some("SSD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  5,  s1::FormattedStatusRequest,              "Formatted Status Request",                     Some("FSR"),   HostToEquipment, Single, Required),
+  entry!(1,  6,  s1::FormattedStatusData,                 "Formatted Status Data",                        Some("FSD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  7,  s1::FixedFormRequest,                    "Fixed Form Request",                           Some("FFR"),   HostToEquipment, Single, Required),
+  entry!(1,  8,  s1::FixedFormData,                       "Fixed Form Data",                              Some("FFD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  9,  s1::MaterialTransferStatusRequest,       "Material Transfer Status Request",             Some("TSR"),   HostToEquipment, Single, Required),
+  entry!(1,  10, s1::MaterialTransferStatusData,          "Material Transfer Status data",                Some("TSD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  11, s1::StatusVariableNamelistRequest,       "Status Variable Namelist Request",             Some("SVNR"),  HostToEquipment, Single, Required),
+  entry!(1,  12, s1::StatusVariableNamelistReply,         "Status Variable Namelist Reply",               Some("SVNRR"), EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  13, s1::HostCR,                              "Establish Communications Request",             Some("CR"),    HostToEquipment, Single, Required),
+  entry!(1,  13, s1::EquipmentCR,                         "Establish Communications Request",             Some("CR"),    EquipmentToHost, Single, Required),
+  entry!(1,  14, s1::HostCRA,                             "Establish Communications Request Acknowledge", Some("CRA"),   HostToEquipment, Single, Forbidden),
+  entry!(1,  14, s1::EquipmentCRA,                        "Establish Communications Request Acknowledge", Some("CRA"),   EquipmentToHost, Single, Forbidden),
+  entry!(1,  15, s1::RequestOffLine,                      "Request OFF-LINE",                             Some("ROFL"),  HostToEquipment, Single, Required),
+  entry!(1,  16, s1::OffLineAck,                          "OFF-LINE Acknowledge",                         Some("OFLA"),  EquipmentToHost, Single, Forbidden),
+  entry!(1,  17, s1::RequestOnLine,                       "Request ON-LINE",                              Some("RONL"),  HostToEquipment, Single, Required),
+  entry!(1,  18, s1::OnLineAck,                           "ON-LINE Acknowledge",                          Some("ONLA"),  EquipmentToHost, Single, Forbidden),
+  entry!(1,  19, s1::GetAttribute,                        "Get Attribute",                                Some("GA"),    Both,            Single, Required),
+  entry!(1,  20, s1::AttributeData,                       "Attribute Data",                               Some("AD"),    Both,            Multi,  Forbidden),
+  entry!(1,  21, s1::DataVariableNamelistRequest,         "Data Variable Namelist Request",               Some("DVNR"),  HostToEquipment, Single, Required),
+  entry!(1,  22, s1::DataVariableNamelist,                "Data Variable Namelist",                       Some("DVN"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(1,  23, s1::CollectionEventNamelistRequest,      "Collection Event Namelist Request",            Some("CENR"),  HostToEquipment, Single, Required),
+  entry!(1,  24, s1::CollectionEventNamelist,             "Collection Event Namelist",                    Some("CEN"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  0,  s2::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(2,  1,  s2::ServiceProgramLoadInquire,           "Service Program Load Inquire",                 Some("SPI"),   Both,            Single, Required),
+  entry!(2,  2,  s2::ServiceProgramLoadGrant,             "Service Program Load Grant",                   Some("SPG"),   Both,            Single, Forbidden),
+  entry!(2,  3,  s2::ServiceProgramSend,                  "Service Program Send",                         Some("SPS"),   Both,            Multi,  Required),
+  entry!(2,  4,  s2::ServiceProgramSendAcknowledge,       "Service Program Send Acknowledge",             Some("SPA"),   Both,            Single, Forbidden),
+  entry!(2,  5,  s2::ServiceProgramLoadRequest,           "Service Program Load Request",                 Some("SPR"),   Both,            Single, Required),
+  entry!(2,  6,  s2::ServiceProgramLoadData,              "Service Program Load Data",                    Some("SPD"),   Both,            Multi,  Forbidden),
+  entry!(2,  7,  s2::ServiceProgramRunSend,               "Service Program Run Send",                     Some("CSS"),   HostToEquipment, Single, Required),
+  entry!(2,  8,  s2::ServiceProgramRunAcknowledge,        "Service Program Run Acknowledge",              Some("CSA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  9,  s2::ServiceProgramResultsRequest,        "Service Program Results Request",              Some("SRR"),   HostToEquipment, Single, Required),
+  entry!(2,  10, s2::ServiceProgramResultsData,           "Service Program Results Data",                 Some("SRD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  11, s2::ServiceProgramDirectoryRequest,      "Service Program Directory Request",            Some("SDR"),   Both,            Single, Required),
+  entry!(2,  12, s2::ServiceProgramDirectoryData,         "Service Program Directory Data",               Some("SDD"),   Both,            Single, Forbidden),
+  entry!(2,  13, s2::EquipmentConstantRequest,            "Equipment Constant Request",                   Some("ECR"),   HostToEquipment, Single, Required),
+  entry!(2,  14, s2::EquipmentConstantData,               "Equipment Constant Data",                      Some("ECD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  15, s2::NewEquipmentConstantSend,            "New Equipment Constant Send",                  Some("ECS"),   HostToEquipment, Single, Required),
+  entry!(2,  16, s2::NewEquipmentConstantAcknowledge,     "New Equipment Constant Acknowledge",           Some("ECA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  17, s2::DateTimeRequest,                     "Date and Time Request",                        Some("DTR"),   Both,            Single, Required),
+  entry!(2,  18, s2::DateTimeData,                        "Date and Time Data",                           Some("DTD"),   Both,            Single, Forbidden),
+  entry!(2,  19, s2::ResetInitializeSend,                 "Reset/Initialize Send",                        Some("RIS"),   HostToEquipment, Single, Required),
+  entry!(2,  20, s2::ResetAcknowledge,                    "Reset Acknowledge",                            Some("RIA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  21, s2::RemoteCommandSend,                   "Remote Command Send",                          Some("RCS"),   HostToEquipment, Single, Optional),
+  entry!(2,  22, s2::RemoteCommandAcknowledge,            "Remote Command Acknowledge",                   Some("RCA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  23, s2::TraceInitializeSend,                 "Trace Initialize Send",                        Some("TIS"),   HostToEquipment, Multi,  Required),
+  entry!(2,  24, s2::TraceInitializeAcknowledge,          "Trace Initialize Acknowledge",                 Some("TIA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  25, s2::LoopbackDiagnosticRequest,           "Loopback Diagnostic Request",                  Some("LDR"),   Both,            Single, Required),
+  entry!(2,  26, s2::LoopbackDiagnosticData,              "Loopback Diagnostic Data",                     Some("LDD"),   Both,            Single, Forbidden),
+  entry!(2,  27, s2::InitiateProcessingRequest,           "Initiate Processing Request",                  Some("IPR"),   HostToEquipment, Single, Required),
+  entry!(2,  28, s2::InitiateProcessingAcknowledge,       "Initiate Processing Acknowledge",              Some("IPA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  29, s2::EquipmentConstantNamelistRequest,    "Equipment Constant Namelist Request",          Some("ECNR"),  HostToEquipment, Single, Required),
+  entry!(2,  30, s2::EquipmentConstantNamelist,           "Equipment Constant Namelist",                  Some("ECN"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  31, s2::DateTimeSetRequest,                  "Date and Time Set Request",                    Some("DTS"),   HostToEquipment, Single, Required),
+  entry!(2,  32, s2::DateTimeSetAcknowledge,              "Date and Time Set Acknowledge",                Some("DTA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  33, s2::DefineReport,                        "Define Report",                                Some("DR"),    HostToEquipment, Multi,  Required),
+  entry!(2,  34, s2::DefineReportAcknowledge,             "Define Report Acknowledge",                    Some("DRA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  35, s2::LinkEventReport,                     "Link Event Report",                            Some("LER"),   HostToEquipment, Multi,  Required),
+  entry!(2,  36, s2::LinkEventReportAcknowledge,          "Link Event Report Acknowledge",                Some("LERA"),  EquipmentToHost, Single, Forbidden),
+  entry!(2,  37, s2::EnableDisableEventReport,            "Enable/Disable Event Report",                  Some("EDER"),  HostToEquipment, Single, Required),
+  entry!(2,  38, s2::EnableDisableEventReportAcknowledge, "Enable/Disable Event Report Acknowledge",      Some("EERA"),  EquipmentToHost, Single, Forbidden),
+  entry!(2,  39, s2::MultiBlockInquire,                   "Multi-Block Inquire",                          Some("DMBI"),  HostToEquipment, Single, Required),
+  entry!(2,  40, s2::MultiBlockGrant,                     "Multi-Block Grant",                            Some("DMBG"),  EquipmentToHost, Single, Forbidden),
+  entry!(2,  41, s2::HostCommandSend,                     "Host Command Send",                            Some("HCS"),   HostToEquipment, Single, Required),
+  entry!(2,  42, s2::HostCommandAcknowledge,              "Host Command Acknowledge",                     Some("HCA"),   EquipmentToHost, Single, Forbidden),
+  entry!(2,  43, s2::ResetSpoolingStreamsAndFunctions,    "Reset Spooling Streams and Functions",         Some("RSSF"),  HostToEquipment, Single, Required),
+  entry!(2,  44, s2::ResetSpoolingAcknowledge,            "Reset Spooling Acknowledge",                   Some("RSA"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  45, s2::DefineVariableLimitAttributes,       "Define Variable Limit Attributes",             Some("DVLA"),  HostToEquipment, Multi,  Required),
+  entry!(2,  46, s2::VariableLimitAttributeAcknowledge,   "Variable Limit Attribute Acknowledge",         Some("VLAA"),  EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  47, s2::VariableLimitAttributeRequest,       "Variable Limit Attribute Request",             Some("VLAR"),  HostToEquipment, Single, Required),
+  entry!(2,  48, s2::VariableLimitAttributeSend,          "Variable Limit Attribute Send",                Some("VLAS"),  EquipmentToHost, Multi,  Forbidden),
+  entry!(2,  49, s2::EnhancedRemoteCommand,               "Enhanced Remote Command",                      None,          HostToEquipment, Multi,  Required),
+  entry!(2,  50, s2::EnhancedRemoteCommandAcknowledge,    "Enhanced Remote Command Acknowledge",          None,          EquipmentToHost, Multi,  Forbidden),
+  entry!(3,  0,  s3::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(3,  11, s3::MaterialIDRequest,                   "Material ID Request",                          Some("MIDR"),  EquipmentToHost, Single, Required),
+  entry!(3,  12, s3::MaterialIDRequestAcknowledge,        "Material ID Request Acknowledge",              Some("MIDRA"), HostToEquipment, Single, Forbidden),
+  entry!(3,  17, s3::CarrierActionRequest,                "Carrier Action Request",                       Some("CAR"),   HostToEquipment, Single, Required),
+  entry!(3,  18, s3::CarrierActionRequestAcknowledge,     "Carrier Action Acknowledge",                   Some("CAA"),   EquipmentToHost, Single, Forbidden),
+  entry!(3,  27, s3::ChangeAccess,                        "Change Access",                                Some("CA"),    HostToEquipment, Single, Required),
+  entry!(3,  28, s3::ChangeAccessAcknowledge,             "Change Access Acknowledge",                    Some("CAA"),   EquipmentToHost, Single, Forbidden),
+  entry!(3,  29, s3::CarrierTagReadRequest,               "Carrier Tag Read Request",                     Some("CTRR"),  HostToEquipment, Single, Required),
+  entry!(3,  30, s3::CarrierTagReadData,                  "Carrier Tag Read Data",                        Some("CTRD"),  EquipmentToHost, Single, Forbidden),
+  entry!(3,  31, s3::CarrierTagWriteDataRequest,          "Carrier Tag Write Data Request",               Some("CTWR"),  HostToEquipment, Single, Required),
+  entry!(3,  32, s3::CarrierTagWriteDataAcknowledge,      "Carrier Tag Write Data Acknowledge",           Some("CTWA"),  EquipmentToHost, Single, Forbidden),
+  entry!(5,  0,  s5::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(5,  1,  s5::AlarmReportSend,                     "Alarm Report Send",                            Some("ARS"),   EquipmentToHost, Single, Required),
+  entry!(5,  2,  s5::AlarmReportAcknowledge,              "Alarm Report Acknowledge",                     Some("ARA"),   HostToEquipment, Single, Forbidden),
+  entry!(5,  3,  s5::EnableDisableAlarmSend,              "Enable/Disable Alarm Send",                    Some("EAS"),   HostToEquipment, Single, Required),
+  entry!(5,  3,  s5::EnableDisableAllAlarmSend,           "Enable/Disable All Alarm Send",                Some("EAS"),   HostToEquipment, Single, Required),
+  entry!(5,  4,  s5::EnableDisableAlarmAcknowledge,       "Enable/Disable Alarm Acknowledge",             Some("EAA"),   EquipmentToHost, Single, Forbidden),
+  entry!(5,  5,  s5::ListAlarmsRequest,                   "List Alarms Request",                          Some("LAR"),   HostToEquipment, Single, Required),
+  entry!(5,  6,  s5::ListAlarmsData,                      "List Alarms Data",                             Some("LAD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(5,  7,  s5::ListEnabledAlarmsRequest,            "List Enabled Alarms Request",                  Some("LEAR"),  HostToEquipment, Single, Required),
+  entry!(5,  8,  s5::ListEnabledAlarmsData,               "List Enabled Alarms Data",                     Some("LEAD"),  EquipmentToHost, Multi,  Forbidden),
+  entry!(5,  9,  s5::ExceptionPostNotify,                 "Exception Post Notify",                        Some("EXPN"),  EquipmentToHost, Single, Required),
+  entry!(5,  10, s5::ExceptionPostConfirm,                "Exception Post Confirm",                       Some("EXPC"),  HostToEquipment, Single, Forbidden),
+  entry!(5,  11, s5::ExceptionClearNotify,                "Exception Clear Notify",                       Some("EXCN"),  EquipmentToHost, Single, Required),
+  entry!(5,  12, s5::ExceptionClearConfirm,               "Exception Clear Confirm",                      Some("EXCC"),  HostToEquipment, Single, Forbidden),
+  entry!(5,  13, s5::ExceptionRecoverRequest,             "Exception Recover Request",                    Some("EXRR"),  HostToEquipment, Single, Required),
+  entry!(5,  14, s5::ExceptionRecoverAcknowledge,         "Exception Recover Acknowledge",                Some("EXRA"),  EquipmentToHost, Single, Forbidden),
+  entry!(5,  15, s5::ExceptionRecoveryCompleteNotify,     "Exception Recovery Complete Notify",           Some("EXRCN"), EquipmentToHost, Single, Required),
+  entry!(5,  16, s5::ExceptionRecoveryCompleteConfirm,    "Exception Recovery Complete Confirm",          Some("EXRCC"), HostToEquipment, Single, Forbidden),
+  entry!(5,  17, s5::ExceptionRecoveryAbortRequest,       "Exception Recovery Abort Request",             Some("EXRAR"), HostToEquipment, Single, Required),
+  entry!(5,  18, s5::ExceptionRecoveryAbortAcknowledge,   "Exception Recovery Abort Acknowledge",         Some("EXRAA"), EquipmentToHost, Single, Forbidden),
+  entry!(6,  0,  s6::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(6,  1,  s6::TraceDataSend,                       "Trace Data Send",                              Some("TDS"),   EquipmentToHost, Single, Optional),
+  entry!(6,  2,  s6::TraceDataAcknowledge,                "Trace Data Acknowledge",                       Some("TDA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  3,  s6::DiscreteVariableDataSend,            "Discrete Variable Data Send",                  Some("DVS"),   EquipmentToHost, Multi,  Required),
+  entry!(6,  4,  s6::DiscreteVariableDataAcknowledge,     "Discrete Variable Data Acknowledge",           Some("DVA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  5,  s6::MultiBlockDataSendInquire,           "Multi-Block Data Send Inquire",                Some("MBI"),   EquipmentToHost, Single, Required),
+  entry!(6,  6,  s6::MultiBlockGrant,                     "Multi-Block Grant",                            Some("MBG"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  7,  s6::DataTransferRequest,                 "Data Transfer Request",                        Some("DDR"),   HostToEquipment, Single, Required),
+  entry!(6,  8,  s6::DataTransferData,                    "Data Transfer Data",                           Some("DDD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(6,  9,  s6::FormattedVariableSend,               "Formatted Variable Send",                      Some("FVS"),   EquipmentToHost, Multi,  Required),
+  entry!(6,  10, s6::FormattedVariableAcknowledge,        "Formatted Variable Acknowledge",               Some("FVA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  11, s6::EventReport,                         "Event Report",                                 Some("ERS"),   EquipmentToHost, Multi,  Required),
+  entry!(6,  12, s6::EventReportAcknowledge,              "Event Report Acknowledge",                     Some("ERA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  13, s6::AnnotatedEventReport,                "Annotated Event Report",                       Some("AERS"),  EquipmentToHost, Multi,  Required),
+  entry!(6,  14, s6::AnnotatedEventReportAcknowledge,     "Annotated Event Report Acknowledge",           Some("AERA"),  HostToEquipment, Single, Forbidden),
+  entry!(6,  15, s6::EventReportRequest,                  "Event Report Request",                         Some("ERR"),   HostToEquipment, Single, Required),
+  entry!(6,  16, s6::EventReportData,                     "Event Report Data",                            Some("ERD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(6,  17, s6::AnnotatedEventReportRequest,         "Annotated Event Report Request",               Some("AERR"),  HostToEquipment, Single, Required),
+  entry!(6,  18, s6::AnnotatedEventReportData,            "Annotated Event Report Data",                  Some("AERD"),  EquipmentToHost, Multi,  Forbidden),
+  entry!(6,  19, s6::IndividualReportRequest,             "Individual Report Request",                    Some("IRR"),   HostToEquipment, Single, Required),
+  entry!(6,  20, s6::IndividualReportData,                "Individual Report Data",                       Some("IRD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(6,  21, s6::AnnotatedIndividualReportRequest,    "Annotated Individual Report Request",          Some("AIRR"),  HostToEquipment, Single, Required),
+  entry!(6,  22, s6::AnnotatedIndividualReportData,       "Annotated Individual Report Data",             Some("AIRD"),  EquipmentToHost, Multi,  Forbidden),
+  entry!(6,  23, s6::RequestSpooledData,                  "Request Spooled Data",                         Some("RSD"),   HostToEquipment, Single, Required),
+  entry!(6,  24, s6::RequestSpooledDataAcknowledge,       "Request Spooled Data Acknowledgement Send",    Some("RSDAS"), EquipmentToHost, Single, Forbidden),
+  entry!(6,  25, s6::NotificationReportSend,              "Notification Report Send",                     Some("NRS"),   EquipmentToHost, Single, Required),
+  entry!(6,  26, s6::NotificationReportSendAcknowledge,   "Notification Report Send Acknowledge",         Some("NRA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  27, s6::TraceReportSend,                     "Trace Report Send",                            Some("TRS"),   EquipmentToHost, Multi,  Required),
+  entry!(6,  28, s6::TraceReportSendAcknowledge,          "Trace Report Send Acknowledge",                Some("TRA"),   HostToEquipment, Single, Forbidden),
+  entry!(6,  29, s6::TraceReportRequest,                  "Trace Report Request",                         Some("TRR"),   HostToEquipment, Single, Required),
+  entry!(6,  30, s6::TraceReportData,                     "Trace Report Data",                            Some("TRD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(7,  0,  s7::Abort,                               "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(7,  1,  s7::ProcessProgramLoadInquire,           "Process Program Load Inquire",                 Some("PPI"),   Both,            Single, Required),
+  entry!(7,  2,  s7::ProcessProgramLoadGrant,             "Process Program Load Grant",                   Some("PPG"),   Both,            Single, Forbidden),
+  entry!(7,  3,  s7::ProcessProgramSend,                  "Process Program Send",                         Some("PPS"),   Both,            Multi,  Required),
+  entry!(7,  4,  s7::ProcessProgramAcknowledge,           "Process Program Acknowledge",                  Some("PPA"),   Both,            Single, Forbidden),
+  entry!(7,  5,  s7::ProcessProgramRequest,               "Process Program Request",                      Some("PPR"),   Both,            Single, Required),
+  entry!(7,  6,  s7::ProcessProgramData,                  "Process Program Data",                         Some("PPD"),   Both,            Multi,  Forbidden),
+  entry!(7,  17, s7::DeleteProcessProgramSend,            "Delete Process Program Send",                  Some("DPS"),   HostToEquipment, Single, Required),
+  entry!(7,  18, s7::DeleteProcessProgramAcknowledge,     "Delete Process Program Acknowledge",           Some("DPA"),   EquipmentToHost, Single, Forbidden),
+  entry!(7,  19, s7::CurrentProcessProgramDirectoryRequest, "Current EPPD Request",                         Some("RER"),   HostToEquipment, Single, Required),
+  entry!(7,  20, s7::CurrentProcessProgramDirectoryData,  "Current EPPD Data",                            Some("RED"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(7,  21, s7::ProcessCapabilitiesRequest,          "Equipment Process Capabilities Request",       Some("PCR"),   HostToEquipment, Single, Required),
+  entry!(7,  22, s7::ProcessCapabilitiesData,             "Equipment Process Capabilities Data",          Some("PCD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(7,  23, s7::FormattedProcessProgramSend,         "Formatted Process Program Send",               Some("FPS"),   Both,            Multi,  Required),
+  entry!(7,  24, s7::FormattedProcessProgramAcknowledge,  "Formatted Process Program Acknowledge",        Some("FPA"),   Both,            Single, Forbidden),
+  entry!(7,  25, s7::FormattedProcessProgramRequest,      "Formatted Process Program Request",            Some("FPR"),   Both,            Single, Required),
+  entry!(7,  26, s7::FormattedProcessProgramData,         "Formatted Process Program Data",               Some("FPD"),   Both,            Multi,  Forbidden),
+  entry!(7,  27, s7::ProcessProgramVerificationSend,      "Process Program Verification Send",            Some("PVS"),   EquipmentToHost, Multi,  Required),
+  entry!(7,  28, s7::ProcessProgramVerificationAcknowledge, "Process Program Verification Acknowledge",     Some("PVA"),   HostToEquipment, Single, Forbidden),
+  entry!(7,  29, s7::ProcessProgramVerificationInquire,   "Process Program Verification Inquire",         Some("PVI"),   EquipmentToHost, Single, Required),
+  entry!(7,  30, s7::ProcessProgramVerificationGrant,     "Process Program Verification Grant",           Some("PVG"),   HostToEquipment, Single, Forbidden),
+  entry!(7,  31, s7::VerificationRequestSend,             "Verification Request Send",                    Some("VRS"),   HostToEquipment, Multi,  Required),
+  entry!(7,  32, s7::VerificationRequestAcknowledge,      "Verification Request Acknowledge",             Some("VRA"),   EquipmentToHost, Single, Forbidden),
+  entry!(7,  33, s7::ProcessProgramAvailableRequest,      "Process Program Available Request",            Some("PAR"),   Both,            Single, Required),
+  entry!(7,  34, s7::ProcessProgramAvailabilityData,      "Process Program Availability Data",            Some("PAD"),   Both,            Single, Forbidden),
+  entry!(7,  35, s7::ProcessProgramForMaterialRequest,    "Process Program for MID Request",              Some("PPMR"),  EquipmentToHost, Single, Required),
+  entry!(7,  36, s7::ProcessProgramForMaterialData,       "Process Program for MID Data",                 Some("PPMD"),  HostToEquipment, Multi,  Forbidden),
+  entry!(7,  37, s7::LargeProcessProgramSend,             "Large Process Program Send",                   Some("LPPS"),  Both,            Single, Required),
+  entry!(7,  38, s7::LargeProcessProgramAcknowledge,      "Large Process Program Acknowledge",            Some("LPPA"),  Both,            Single, Forbidden),
+  entry!(7,  39, s7::LargeFormattedProcessProgramSend,    "Large Formatted Process Program Send",         Some("LFPS"),  Both,            Single, Required),
+  entry!(7,  40, s7::LargeFormattedProcessProgramAcknowledge, "Large Formatted Process Program Acknowledge",  Some("LFPA"),  Both,            Single, Forbidden),
+  entry!(7,  41, s7::LargeProcessProgramRequest,          "Large Process Program Request",                Some("LPPR"),  Both,            Single, Required),
+  entry!(7,  42, s7::LargeProcessProgramRequestAcknowledge, "Large Process Program Request Acknowledge",    Some("LPRA"),  Both,            Single, Forbidden),
+  entry!(7,  43, s7::LargeFormattedProcessProgramRequest, "Large Formatted Process Program Request",      Some("LFPR"),  Both,            Single, Required),
+  entry!(7,  44, s7::LargeFormattedProcessProgramRequestAcknowledge, "Large Formatted Process Program Request Acknowledge", Some("LFRA"),  Both,            Single, Forbidden),
+  entry!(9,  1,  s9::UnrecognizedDeviceID,                "Unrecognized Device ID",                       Some("UDN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  3,  s9::UnrecognizedStreamType,              "Unrecognized Stream Type",                     Some("USN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  5,  s9::UnrecognizedFunctionType,            "Unrecognized Function Type",                   Some("UFN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  7,  s9::IllegalData,                         "Illegal Data",                                 Some("IDN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  9,  s9::TransactionTimerTimeout,             "Transaction Timer Timeout",                    Some("TTN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  11, s9::DataTooLong,                         "Data Too Long",                                Some("DLN"),   EquipmentToHost, Single, Forbidden),
+  entry!(9,  13, s9::ConversationTimeout,                 "Conversation Timeout",                         Some("CTN"),   EquipmentToHost, Single, Forbidden),
+  entry!(10, 0,  s10::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(10, 1,  s10::TerminalRequest,                    "Terminal Request",                             Some("TRN"),   EquipmentToHost, Single, Required),
+  entry!(10, 2,  s10::TerminalAcknowledge,                "Terminal Acknowledge",                         Some("TRA"),   HostToEquipment, Single, Forbidden),
+  entry!(10, 3,  s10::TerminalDisplaySingle,              "Terminal Display, Single",                     Some("VTN"),   HostToEquipment, Single, Required),
+  entry!(10, 4,  s10::TerminalDisplaySingleAcknowledge,   "Terminal Display, Single - Acknowledge",       Some("VTA"),   EquipmentToHost, Single, Forbidden),
+  entry!(10, 5,  s10::TerminalDisplayMultiBlock,          "Terminal Display, Multi-Block",                Some("VMN"),   HostToEquipment, Multi,  Required),
+  entry!(10, 6,  s10::TerminalDisplayMultiBlockAcknowledge, "Terminal Display, Multi-Block - Acknowledge",  Some("VMA"),   EquipmentToHost, Single, Forbidden),
+  entry!(10, 7,  s10::MultiBlockNotAllowed,               "Multi-Block Not Allowed",                      Some("MNN"),   EquipmentToHost, Single, Forbidden),
+  entry!(10, 9,  s10::Broadcast,                          "Broadcast",                                    Some("BCN"),   HostToEquipment, Single, Required),
+  entry!(10, 10, s10::BroadcastAcknowledge,               "Broadcast Acknowledge",                        Some("BCA"),   EquipmentToHost, Single, Forbidden),
+  entry!(12, 0,  s12::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(12, 1,  s12::MapSetupDataSend,                   "Map Set-up Data Send",                         Some("MSDS"),  EquipmentToHost, Single, Required),
+  entry!(12, 2,  s12::MapSetupDataAcknowledge,            "Map Set-up Data Acknowledge",                  Some("MSDA"),  HostToEquipment, Single, Forbidden),
+  entry!(12, 3,  s12::MapSetupDataRequest,                "Map Set-up Data Request",                      Some("MSDR"),  EquipmentToHost, Single, Required),
+  entry!(12, 4,  s12::MapSetupData,                       "Map Set-up Data",                              Some("MSD"),   HostToEquipment, Multi,  Forbidden),
+  entry!(12, 5,  s12::MapTransmitInquire,                 "Map Transmit Inquire",                         Some("MAPTI"), EquipmentToHost, Single, Required),
+  entry!(12, 6,  s12::MapTransmitGrant,                   "Map Transmit Grant",                           Some("MAPTG"), HostToEquipment, Single, Forbidden),
+  entry!(12, 7,  s12::MapDataSendType1,                   "Map Data Send Type 1",                         Some("MDS1"),  EquipmentToHost, Multi,  Required),
+  entry!(12, 8,  s12::MapDataAcknowledgeType1,            "Map Data Acknowledge Type 1",                  Some("MDA1"),  HostToEquipment, Single, Forbidden),
+  entry!(12, 9,  s12::MapDataSendType2,                   "Map Data Send Type 2",                         Some("MDS2"),  EquipmentToHost, Multi,  Required),
+  entry!(12, 10, s12::MapDataAcknowledgeType2,            "Map Data Acknowledge Type 2",                  Some("MDA2"),  HostToEquipment, Single, Forbidden),
+  entry!(12, 11, s12::MapDataSendType3,                   "Map Data Send Type 3",                         Some("MDS3"),  EquipmentToHost, Multi,  Required),
+  entry!(12, 12, s12::MapDataAcknowledgeType3,            "Map Data Acknowledge Type 3",                  Some("MDA3"),  HostToEquipment, Single, Forbidden),
+  entry!(12, 13, s12::MapDataRequestType1,                "Map Data Request Type 1",                      Some("MDR1"),  EquipmentToHost, Single, Required),
+  entry!(12, 14, s12::MapDataType1,                       "Map Data Type 1",                              Some("MD1"),   HostToEquipment, Multi,  Forbidden),
+  entry!(12, 15, s12::MapDataRequestType2,                "Map Data Request Type 2",                      Some("MDR2"),  EquipmentToHost, Single, Required),
+  entry!(12, 16, s12::MapDataType2,                       "Map Data Type 2",                              Some("MD2"),   HostToEquipment, Multi,  Forbidden),
+  entry!(12, 17, s12::MapDataRequestType3,                "Map Data Request Type 3",                      Some("MDR3"),  EquipmentToHost, Single, Required),
+  entry!(12, 18, s12::MapDataType3,                       "Map Data Type 3",                              Some("MD3"),   HostToEquipment, Multi,  Forbidden),
+  entry!(12, 19, s12::MapErrorReportSend,                 "Map Error Report Send",                        Some("MERS"),  Both,            Single, Forbidden),
+  entry!(13, 0,  s13::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(13, 1,  s13::SendDataSetSend,                    "Send Data Set Send",                           Some("DSSS"),  Both,            Single, Required),
+  entry!(13, 2,  s13::SendDataSetAcknowledge,             "Send Data Set Acknowledge",                    Some("DSSA"),  Both,            Single, Forbidden),
+  entry!(13, 3,  s13::OpenDataSetRequest,                 "Open Data Set Request",                        Some("DSOR"),  Both,            Single, Required),
+  entry!(13, 4,  s13::OpenDataSetData,                    "Open Data Set Data",                           Some("DSOD"),  Both,            Single, Forbidden),
+  entry!(13, 5,  s13::ReadDataSetRequest,                 "Read Data Set Request",                        Some("DSRR"),  Both,            Single, Required),
+  entry!(13, 6,  s13::ReadDataSetData,                    "Read Data Set Data",                           Some("DSRD"),  Both,            Multi,  Forbidden),
+  entry!(13, 7,  s13::CloseDataSetSend,                   "Close Data Set Send",                          Some("DSCS"),  Both,            Single, Required),
+  entry!(13, 8,  s13::CloseDataSetAcknowledge,            "Close Data Set Acknowledge",                   Some("DSCA"),  Both,            Single, Forbidden),
+  entry!(13, 9,  s13::ResetDataSetSend,                   "Reset Data Set Send",                          Some("DSRS"),  Both,            Single, Required),
+  entry!(13, 10, s13::ResetDataSetAcknowledge,            "Reset Data Set Acknowledge",                   Some("DSRA"),  Both,            Single, Forbidden),
+  entry!(13, 11, s13::DataSetObjectMultiBlockInquire,     "Data Set Object Multi-Block Inquire",          Some("DSOI"),  Both,            Single, Required),
+  entry!(13, 12, s13::DataSetObjectMultiBlockGrant,       "Data Set Object Multi-Block Grant",            Some("DSOG"),  Both,            Single, Forbidden),
+  entry!(13, 13, s13::TableDataSend,                      "Table Data Send",                              Some("TDS"),   Both,            Multi,  Required),
+  entry!(13, 14, s13::TableDataAcknowledge,               "Table Data Acknowledge",                       Some("TDA"),   Both,            Single, Forbidden),
+  entry!(13, 15, s13::TableDataRequest,                   "Table Data Request",                           Some("TDR"),   Both,            Single, Required),
+  entry!(13, 16, s13::TableData,                          "Table Data",                                   Some("TD"),    Both,            Multi,  Forbidden),
+  entry!(14, 0,  s14::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(14, 1,  s14::GetAttributeRequest,                "GetAttr Request",                              Some("GAR"),   Both,            Single, Required),
+  entry!(14, 2,  s14::GetAttributeData,                   "GetAttr Data",                                 Some("GAD"),   Both,            Multi,  Forbidden),
+  entry!(14, 3,  s14::SetAttributeRequest,                "SetAttr Request",                              Some("SAR"),   Both,            Multi,  Required),
+  entry!(14, 4,  s14::SetAttributeData,                   "SetAttr Data",                                 Some("SAD"),   Both,            Multi,  Forbidden),
+  entry!(14, 5,  s14::GetTypeRequest,                     "GetType Request",                              Some("GTR"),   Both,            Single, Required),
+  entry!(14, 6,  s14::GetTypeData,                        "GetType Data",                                 Some("GTD"),   Both,            Multi,  Forbidden),
+  entry!(14, 7,  s14::GetAttributeNameRequest,            "GetAttrName Request",                          Some("GANR"),  Both,            Single, Required),
+  entry!(14, 8,  s14::GetAttributeNameData,               "GetAttrName Data",                             Some("GAND"),  Both,            Multi,  Forbidden),
+  entry!(14, 9,  s14::CreateObjectRequest,                "Create Object Request",                        Some("CR"),    Both,            Multi,  Required),
+  entry!(14, 10, s14::CreateObjectAcknowledge,            "Create Object Acknowledge",                    Some("CRA"),   Both,            Multi,  Forbidden),
+  entry!(14, 11, s14::DeleteObjectRequest,                "Delete Object Request",                        Some("DR"),    Both,            Single, Required),
+  entry!(14, 12, s14::DeleteObjectAcknowledge,            "Delete Object Acknowledge",                    Some("DRA"),   Both,            Multi,  Forbidden),
+  entry!(14, 13, s14::ObjectAttachRequest,                "Object Attach Request",                        Some("OAR"),   Both,            Single, Required),
+  entry!(14, 14, s14::ObjectAttachAcknowledge,            "Object Attach Acknowledge",                    Some("OAA"),   Both,            Multi,  Forbidden),
+  entry!(14, 15, s14::AttachedObjectActionRequest,        "Attached Object Action Request",               Some("AOAR"),  Both,            Single, Required),
+  entry!(14, 16, s14::AttachedObjectActionAcknowledge,    "Attached Object Action Acknowledge",           Some("AOAA"),  Both,            Multi,  Forbidden),
+  entry!(14, 17, s14::SupervisedObjectActionRequest,      "Supervised Object Action Request",             Some("SOAR"),  Both,            Single, Required),
+  entry!(14, 18, s14::SupervisedObjectActionAcknowledge,  "Supervised Object Action Acknowledge",         Some("SOAA"),  Both,            Multi,  Forbidden),
+  entry!(14, 19, s14::GenericServiceRequest,              "Generic Service Request",                      Some("GSR"),   Both,            Multi,  Required),
+  entry!(14, 20, s14::GenericServiceAcknowledge,          "Generic Service Acknowledge",                  Some("GSA"),   Both,            Multi,  Forbidden),
+  entry!(14, 21, s14::GenericServiceCompletion,           "Generic Service Completion",                   Some("GSC"),   Both,            Multi,  Required),
+  entry!(14, 22, s14::GenericServiceCompletionAcknowledge, "Generic Service Completion Acknowledge",       Some("GSCA"),  Both,            Single, Forbidden),
+  entry!(14, 23, s14::GenericServiceMultiBlockInquire,    "Generic Service Multi-Block Inquire",          Some("GSMI"),  Both,            Single, Required),
+  entry!(14, 24, s14::GenericServiceMultiBlockGrant,      "Generic Service Multi-Block Grant",            Some("GSMG"),  Both,            Single, Forbidden),
+  entry!(14, 25, s14::ServiceNameRequest,                 "Service Name Request",                         Some("SNR"),   Both,            Single, Required),
+  entry!(14, 26, s14::ServiceNameData,                    "Service Name Data",                            Some("SND"),   Both,            Multi,  Forbidden),
+  entry!(14, 27, s14::ServiceParameterRequest,            "Service Parameter Request",                    Some("SPR"),   Both,            Single, Required),
+  entry!(14, 28, s14::ServiceParameterData,               "Service Parameter Data",                       Some("SPD"),   Both,            Multi,  Forbidden),
+  entry!(15, 0,  s15::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(15, 1,  s15::RecipeManagementMultiBlockInquire,  "Recipe Management Multi-Block Inquire",        Some("RMMBI"), Both,            Single, Required),
+  entry!(15, 2,  s15::RecipeManagementMultiBlockGrant,    "Recipe Management Multi-Block Grant",          Some("RMMBG"), Both,            Single, Forbidden),
+  entry!(15, 27, s15::RecipeDownloadRequest,              "Recipe Download Request",                      Some("RDR"),   HostToEquipment, Multi,  Required),
+  entry!(15, 28, s15::RecipeDownloadAcknowledge,          "Recipe Download Acknowledge",                  Some("RDA"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(15, 31, s15::RecipeUploadRequest,                "Recipe Upload Request",                        Some("RUR"),   HostToEquipment, Single, Required),
+  entry!(15, 32, s15::RecipeUploadData,                   "Recipe Upload Data",                           Some("RUD"),   EquipmentToHost, Multi,  Forbidden),
+  entry!(16, 0,  s16::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(16, 1,  s16::ProcessJobMultiBlockInquire,        "PRJob Multi-Block Inquire",                    Some("PRJI"),  HostToEquipment, Single, Required),
+  entry!(16, 2,  s16::ProcessJobMultiBlockGrant,          "PRJob Multi-Block Grant",                      Some("PRJG"),  EquipmentToHost, Single, Forbidden),
+  entry!(16, 5,  s16::ProcessJobCommand,                  "PRJob Command",                                Some("PRJCMD"), HostToEquipment, Single, Required),
+  entry!(16, 6,  s16::ProcessJobCommandAcknowledge,       "PRJob Command Acknowledge",                    Some("PRJCMDA"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 7,  s16::ProcessJobAlertNotify,              "PRJob Alert Notify",                           Some("PRJA"),  EquipmentToHost, Single, Required),
+  entry!(16, 8,  s16::ProcessJobAlertConfirm,             "PRJob Alert Confirm",                          Some("PRJAC"), HostToEquipment, Single, Forbidden),
+  entry!(16, 11, s16::ProcessJobCreateEnhanced,           "PRJob Create Enhanced",                        Some("PRJCE"), HostToEquipment, Multi,  Required),
+  entry!(16, 12, s16::ProcessJobCreateEnhancedAcknowledge, "PRJob Create Enhanced Acknowledge",            Some("PRJCEA"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 15, s16::ProcessJobMultiCreate,              "PRJob Multi Create",                           Some("PRJMC"), HostToEquipment, Multi,  Required),
+  entry!(16, 16, s16::ProcessJobMultiCreateAcknowledge,   "PRJob Multi Create Acknowledge",               Some("PRJMCA"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 17, s16::ProcessJobDequeue,                  "PRJob Dequeue",                                Some("PRJD"),  HostToEquipment, Single, Required),
+  entry!(16, 18, s16::ProcessJobDequeueAcknowledge,       "PRJob Dequeue Acknowledge",                    Some("PRJDA"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 19, s16::ProcessJobGetAllJobs,               "PRJob Get All Jobs",                           Some("PRGAJ"), HostToEquipment, Single, Required),
+  entry!(16, 20, s16::ProcessJobGetAllJobsSend,           "PRJob Get All Jobs Send",                      Some("PRGAJS"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 21, s16::ProcessJobGetSpace,                 "PRJob Get Space",                              Some("PRGS"),  HostToEquipment, Single, Required),
+  entry!(16, 22, s16::ProcessJobGetSpaceSend,             "PRJob Get Space Send",                         Some("PRGSS"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 25, s16::ProcessJobSetStartMethod,           "PRJob Set Start Method",                       Some("PRJSSM"), HostToEquipment, Single, Required),
+  entry!(16, 26, s16::ProcessJobSetStartMethodAcknowledge, "PRJob Set Start Method Acknowledge",           Some("PRJSSMA"), EquipmentToHost, Single, Forbidden),
+  entry!(16, 27, s16::ControlJobCommandRequest,           "Control Job Command Request",                  Some("CJCR"),  HostToEquipment, Single, Required),
+  entry!(16, 28, s16::ControlJobCommandAcknowledge,       "Control Job Command Acknowledge",              Some("CJCA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 0,  s17::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(17, 1,  s17::DataReportCreateRequest,            "Data Report Create Request",                   Some("DRC"),   HostToEquipment, Single, Required),
+  entry!(17, 2,  s17::DataReportCreateAcknowledge,        "Data Report Create Acknowledge",               Some("DRCA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 3,  s17::DataReportDeleteRequest,            "Data Report Delete Request",                   Some("DRD"),   HostToEquipment, Single, Required),
+  entry!(17, 4,  s17::DataReportDeleteAcknowledge,        "Data Report Delete Acknowledge",               Some("DRDA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 5,  s17::TraceCreateRequest,                 "Trace Create Request",                         Some("TRC"),   HostToEquipment, Single, Required),
+  entry!(17, 6,  s17::TraceCreateAcknowledge,             "Trace Create Acknowledge",                     Some("TRCA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 7,  s17::TraceDeleteRequest,                 "Trace Delete Request",                         Some("TRD"),   HostToEquipment, Single, Required),
+  entry!(17, 8,  s17::TraceDeleteAcknowledge,             "Trace Delete Acknowledge",                     Some("TRDA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 9,  s17::CollectionEventLinkRequest,         "Collection Event Link Request",                Some("CELR"),  HostToEquipment, Single, Required),
+  entry!(17, 10, s17::CollectionEventLinkAcknowledge,     "Collection Event Link Acknowledge",            Some("CELA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 11, s17::CollectionEventUnlinkRequest,       "Collection Event Unlink Request",              Some("CEUR"),  HostToEquipment, Single, Required),
+  entry!(17, 12, s17::CollectionEventUnlinkAcknowledge,   "Collection Event Unlink Acknowledge",          Some("CEUA"),  EquipmentToHost, Single, Forbidden),
+  entry!(17, 13, s17::TraceResetRequest,                  "Trace Reset Request",                          Some("TRR"),   HostToEquipment, Single, Required),
+  entry!(17, 14, s17::TraceResetAcknowledge,              "Trace Reset Acknowledge",                      Some("TRRA"),  EquipmentToHost, Single, Forbidden),
+  entry!(18, 0,  s18::Abort,                              "Abort Transaction",                            None,          Both,            Single, Forbidden),
+  entry!(18, 1,  s18::ReadAttributeRequest,               "Read Attribute Request",                       Some("RAR"),   HostToEquipment, Single, Required),
+  entry!(18, 2,  s18::ReadAttributeData,                  "Read Attribute Data",                          Some("RAD"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 3,  s18::WriteAttributeRequest,              "Write Attribute Request",                      Some("WAR"),   HostToEquipment, Single, Required),
+  entry!(18, 4,  s18::WriteAttributeAcknowledge,          "Write Attribute Acknowledge",                  Some("WAA"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 5,  s18::ReadRequest,                        "Read Request",                                 Some("RR"),    HostToEquipment, Single, Required),
+  entry!(18, 6,  s18::ReadData,                           "Read Data",                                    Some("RD"),    EquipmentToHost, Single, Forbidden),
+  entry!(18, 7,  s18::WriteDataRequest,                   "Write Data Request",                           Some("WDR"),   HostToEquipment, Single, Required),
+  entry!(18, 8,  s18::WriteDataAcknowledge,               "Write Data Acknowledge",                       Some("WDA"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 9,  s18::ReadIDRequest,                      "Read ID Request",                              Some("RIR"),   HostToEquipment, Single, Required),
+  entry!(18, 10, s18::ReadIDData,                         "Read ID Data",                                 Some("RID"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 11, s18::WriteIDRequest,                     "Write ID Request",                             Some("WIR"),   HostToEquipment, Single, Required),
+  entry!(18, 12, s18::WriteIDAcknowledge,                 "Write ID Acknowledge",                         Some("WIA"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 13, s18::SubsystemCommandRequest,            "Subsystem Command Request",                    Some("SCR"),   HostToEquipment, Single, Required),
+  entry!(18, 14, s18::SubsystemCommandAcknowledge,        "Subsystem Command Acknowledge",                Some("SCA"),   EquipmentToHost, Single, Forbidden),
+  entry!(18, 15, s18::Read2DCodeConditionRequest,         "Read 2D Code Condition Request",               Some("R2CR"),  HostToEquipment, Single, Required),
+  entry!(18, 16, s18::Read2DCodeConditionData,            "Read 2D Code Condition Data",                  Some("R2CD"),  EquipmentToHost, Single, Forbidden),
+];
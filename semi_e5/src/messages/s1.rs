@@ -119,6 +119,28 @@ message_data!{OnLineDataHost, false, 1, 2}
 pub struct OnLineDataEquipment(pub (ModelName, SoftwareRevision));
 message_data!{OnLineDataEquipment, false, 1, 2}
 
+message_directional!{
+  /// ## S1F2
+  /// 
+  /// **On Line Data (D)**
+  /// 
+  /// - **SINGLE-BLOCK**
+  /// - **HOST <-> EQUIPMENT**
+  /// - **REPLY FORBIDDEN**
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Data signifying the sender is alive, in the structure used by either
+  /// the [host] or the [equipment].
+  /// 
+  /// [host]:      OnLineDataHost
+  /// [equipment]: OnLineDataEquipment
+  OnLineData,
+  OnLineDataHost,
+  OnLineDataEquipment
+}
+message_reply!{AreYouThere, OnLineData}
+
 /// ## S1F3
 /// 
 /// **Selected Equipment Status Request (SSR)**
@@ -173,6 +195,7 @@ message_data!{SelectedEquipmentStatusRequest, true, 1, 3}
 /// [SVID]: StatusVariableID
 pub struct SelectedEquipmentStatusData(pub VecList<StatusVariableValue>);
 message_data!{SelectedEquipmentStatusData, false, 1, 4}
+message_reply!{SelectedEquipmentStatusRequest, SelectedEquipmentStatusData}
 
 /// ## S1F5
 /// 
@@ -220,6 +243,7 @@ message_data!{FormattedStatusRequest, true, 1, 5}
 /// [SFCD]: StatusFormCode
 pub struct FormattedStatusData(pub Item);
 message_item!{FormattedStatusData, false, 1, 6}
+message_reply!{FormattedStatusRequest, FormattedStatusData}
 
 /// ## S1F7
 /// 
@@ -269,6 +293,7 @@ message_data!{FixedFormRequest, true, 1, 7}
 /// [S1F6]: FormattedStatusData
 pub struct FixedFormData(pub Item);
 message_item!{FixedFormData, false, 1, 8}
+message_reply!{FixedFormRequest, FixedFormData}
 
 /// ## S1F9
 /// 
@@ -317,6 +342,7 @@ message_headeronly!{MaterialTransferStatusRequest, true, 1, 9}
 /// [TSOP]: TransferStatusOutputPort
 pub struct MaterialTransferStatusData(pub OptionItem<(TransferStatusInputPortList, TransferStatusOutputPortList)>);
 message_data!{MaterialTransferStatusData, false, 1, 10}
+message_reply!{MaterialTransferStatusRequest, MaterialTransferStatusData}
 
 /// ## S1F11
 /// 
@@ -375,6 +401,7 @@ message_data!{StatusVariableNamelistRequest, true, 1, 11}
 /// [UNITS]:  Units
 pub struct StatusVariableNamelistReply(pub VecList<(StatusVariableID, StatusVariableName, Units)>);
 message_data!{StatusVariableNamelistReply, false, 1, 12}
+message_reply!{StatusVariableNamelistRequest, StatusVariableNamelistReply}
 
 /// ## S1F13H
 /// 
@@ -472,6 +499,7 @@ message_data!{EquipmentCR, true, 1, 13}
 /// [COMMACK]: CommAck
 pub struct HostCRA(pub (CommAck, ()));
 message_data!{HostCRA, false, 1, 14}
+message_reply!{EquipmentCR, HostCRA}
 
 /// ## S1F14E
 /// 
@@ -504,6 +532,50 @@ message_data!{HostCRA, false, 1, 14}
 /// [SOFTREV]: SoftwareRevision
 pub struct EquipmentCRA(pub (CommAck, (ModelName, SoftwareRevision)));
 message_data!{EquipmentCRA, false, 1, 14}
+message_reply!{HostCR, EquipmentCRA}
+
+message_directional!{
+  /// ## S1F13
+  /// 
+  /// **Establish Communications Request (CR)**
+  /// 
+  /// - **SINGLE-BLOCK**
+  /// - **HOST <-> EQUIPMENT**
+  /// - **REPLY REQUIRED**
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Establish Communications Request in the structure used by either the
+  /// [host] or the [equipment].
+  /// 
+  /// [host]:      HostCR
+  /// [equipment]: EquipmentCR
+  EstablishCommunicationsRequest,
+  HostCR,
+  EquipmentCR
+}
+
+message_directional!{
+  /// ## S1F14
+  /// 
+  /// **Establish Communications Request Acknowledge (CRA)**
+  /// 
+  /// - **SINGLE-BLOCK**
+  /// - **HOST <-> EQUIPMENT**
+  /// - **REPLY FORBIDDEN**
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Establish Communications Request Acknowledge in the structure used by
+  /// either the [host] or the [equipment].
+  /// 
+  /// [host]:      HostCRA
+  /// [equipment]: EquipmentCRA
+  EstablishCommunicationsRequestAcknowledge,
+  HostCRA,
+  EquipmentCRA
+}
+message_reply!{EstablishCommunicationsRequest, EstablishCommunicationsRequestAcknowledge}
 
 /// ## S1F15
 /// 
@@ -547,6 +619,7 @@ message_headeronly!{RequestOffLine, true, 1, 15}
 /// [OFLACK]: OffLineAcknowledge
 pub struct OffLineAck(pub OffLineAcknowledge);
 message_data!{OffLineAck, false, 1, 16}
+message_reply!{RequestOffLine, OffLineAck}
 
 /// ## S1F17
 /// 
@@ -589,6 +662,7 @@ message_headeronly!{RequestOnLine, true, 1, 17}
 /// [ONLACK]: OnLineAcknowledge
 pub struct OnLineAck(pub OnLineAcknowledge);
 message_data!{OnLineAck, false, 1, 18}
+message_reply!{RequestOnLine, OnLineAck}
 
 /// ## S1F19
 /// 
@@ -670,6 +744,7 @@ message_data!{GetAttribute, true, 1, 19}
 /// [ATTRID]:   AttributeID
 pub struct AttributeData(pub (VecList<VecList<AttributeValue>>, VecList<(ErrorCode, ErrorText)>));
 message_data!{AttributeData, false, 1, 20}
+message_reply!{GetAttribute, AttributeData}
 
 /// ## S1F21
 /// 
@@ -734,6 +809,7 @@ message_data!{DataVariableNamelistRequest, true, 1, 21}
 /// [UNITS]:     Units
 pub struct DataVariableNamelist(pub VecList<(VariableID, DataVariableValueName, Units)>);
 message_data!{DataVariableNamelist, false, 1, 22}
+message_reply!{DataVariableNamelistRequest, DataVariableNamelist}
 
 /// ## S1F23
 /// 
@@ -800,3 +876,4 @@ message_data!{CollectionEventNamelistRequest, true, 1, 23}
 /// [VID]:    VariableID
 pub struct CollectionEventNamelist(pub VecList<(CollectionEventID, CollectionEventName, VecList<VariableID>)>);
 message_data!{CollectionEventNamelist, false, 1, 24}
+message_reply!{CollectionEventNamelistRequest, CollectionEventNamelist}
@@ -98,8 +98,9 @@ message_data!{TerminalRequest, true, 10, 1}
 /// - [ACKC10]
 ///
 /// [ACKC10]: AcknowledgeCode10
-pub struct TerminalAcknowledge(pub AcknowledgeCode10);
+pub struct TerminalAcknowledge(pub CodedValue<AcknowledgeCode10>);
 message_data!{TerminalAcknowledge, false, 10, 2}
+message_reply!{TerminalRequest, TerminalAcknowledge}
 
 /// ## S10F3
 ///
@@ -145,5 +146,126 @@ message_data!{TerminalDisplaySingle, true, 10, 3}
 /// - [ACKC10]
 ///
 /// [ACKC10]: AcknowledgeCode10
-pub struct TerminalDisplaySingleAcknowledge(pub AcknowledgeCode10);
+pub struct TerminalDisplaySingleAcknowledge(pub CodedValue<AcknowledgeCode10>);
 message_data!{TerminalDisplaySingleAcknowledge, false, 10, 4}
+message_reply!{TerminalDisplaySingle, TerminalDisplaySingleAcknowledge}
+
+/// ## S10F5
+///
+/// **Terminal Display, Multi-Block**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from host to display a multi-line message on a single terminal.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TID]
+///    2. List - N
+///       - [TEXT]
+///
+/// N is the number of lines to display.
+///
+/// [TID]:  TerminalID
+/// [TEXT]: Text
+pub struct TerminalDisplayMultiBlock(pub (TerminalID, VecList<Text>));
+message_data!{TerminalDisplayMultiBlock, true, 10, 5}
+
+/// ## S10F6
+///
+/// **Terminal Display, Multi-Block - Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge multi-block terminal display request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC10]
+///
+/// [ACKC10]: AcknowledgeCode10
+pub struct TerminalDisplayMultiBlockAcknowledge(pub CodedValue<AcknowledgeCode10>);
+message_data!{TerminalDisplayMultiBlockAcknowledge, false, 10, 6}
+message_reply!{TerminalDisplayMultiBlock, TerminalDisplayMultiBlockAcknowledge}
+
+/// ## S10F7
+///
+/// **Multi-Block Not Allowed**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notification that the equipment cannot display a multi-block message on
+/// the terminal, sent in lieu of [S10F6].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [TID]
+///
+/// [S10F6]: TerminalDisplayMultiBlockAcknowledge
+/// [TID]:   TerminalID
+pub struct MultiBlockNotAllowed(pub TerminalID);
+message_data!{MultiBlockNotAllowed, false, 10, 7}
+
+/// ## S10F9
+///
+/// **Broadcast**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from host to display a message on all terminals.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [TEXT]
+///
+/// [TEXT]: Text
+pub struct Broadcast(pub Text);
+message_data!{Broadcast, true, 10, 9}
+
+/// ## S10F10
+///
+/// **Broadcast Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge broadcast request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC10]
+///
+/// [ACKC10]: AcknowledgeCode10
+pub struct BroadcastAcknowledge(pub CodedValue<AcknowledgeCode10>);
+message_data!{BroadcastAcknowledge, false, 10, 10}
+message_reply!{Broadcast, BroadcastAcknowledge}
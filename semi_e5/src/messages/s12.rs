@@ -0,0 +1,695 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 12: WAFER MAPPING
+//! **Based on SEMI E5§10.16**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with coordinate positions and data associated with
+//! those positions.
+//!
+//! This includes functions such as wafer mapping with the coordinates of die
+//! on wafer maps to and from the process equipment.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! S12F1 through S12F20 address the variations required by semiconductor
+//! equipment manufactureers in transmitting wafer maps to and from the
+//! process equipment.
+//!
+//! The functions include three basic formats:
+//!
+//! - Row/Column - A coordinate row starting position is given with die count
+//!   in the row and starting direction. The respective binning information
+//!   follows each die.
+//! - Array - A matrix array captures all or part of a wafer with the
+//!   associated binning information.
+//! - Coordinate - An X/Y location and bin code for die on the wafer.
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+
+/// ## S12F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 12, 0}
+
+/// ## S12F1
+///
+/// **Map Setup Data Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sends the setup data needed to interpret a wafer map prior to the map
+/// itself being requested or sent.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 15
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [FNLOC]
+///    4. [FFROT]
+///    5. [ORLOC]
+///    6. [RPSEL]
+///    7. List - N
+///       - [REFP]
+///    8. [DUTMS]
+///    9. [XDIES]
+///    10. [YDIES]
+///    11. [ROWCT]
+///    12. [COLCT]
+///    13. [NULBC]
+///    14. [PRDCT]
+///    15. [PRAXI]
+///
+/// N is the number of reference points.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [FNLOC]: FlatNotchLocation
+/// [FFROT]: FilmFrameRotation
+/// [ORLOC]: OriginLocation
+/// [RPSEL]: ReferencePointSelect
+/// [REFP]:  ReferencePoint
+/// [DUTMS]: DieUnitsOfMeasure
+/// [XDIES]: DieSizeX
+/// [YDIES]: DieSizeY
+/// [ROWCT]: RowCount
+/// [COLCT]: ColumnCount
+/// [NULBC]: NullBinCode
+/// [PRDCT]: ProcessDieCount
+/// [PRAXI]: ProcessAxis
+pub struct MapSetupDataSend(pub (MaterialID, IDType, FlatNotchLocation, FilmFrameRotation, OriginLocation, ReferencePointSelect, VecList<ReferencePoint>, DieUnitsOfMeasure, DieSizeX, DieSizeY, RowCount, ColumnCount, NullBinCode, ProcessDieCount, ProcessAxis));
+message_data!{MapSetupDataSend, true, 12, 1}
+
+/// ## S12F2
+///
+/// **Map Setup Data Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of the map setup data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [SDACK]
+///
+/// [SDACK]: MapSetupAcknowledge
+pub struct MapSetupDataAcknowledge(pub CodedValue<MapSetupAcknowledge>);
+message_data!{MapSetupDataAcknowledge, false, 12, 2}
+message_reply!{MapSetupDataSend, MapSetupDataAcknowledge}
+
+/// ## S12F3
+///
+/// **Map Setup Data Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from the equipment for the setup data of a wafer map held by the
+/// host.
+///
+/// Format-related fields which the equipment does not know may be sent as
+/// zero-length items.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 9
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [MAPFT]
+///    4. [FNLOC]
+///    5. [FFROT]
+///    6. [ORLOC]
+///    7. [PRAXI]
+///    8. [BCEQU]
+///    9. [NULBC]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [MAPFT]: MapFormat
+/// [FNLOC]: FlatNotchLocation
+/// [FFROT]: FilmFrameRotation
+/// [ORLOC]: OriginLocation
+/// [PRAXI]: ProcessAxis
+/// [BCEQU]: BinCodeEquivalents
+/// [NULBC]: NullBinCode
+pub struct MapSetupDataRequest(pub (MaterialID, IDType, MapFormat, FlatNotchLocation, FilmFrameRotation, OriginLocation, ProcessAxis, BinCodeEquivalents, NullBinCode));
+message_data!{MapSetupDataRequest, true, 12, 3}
+
+/// ## S12F4
+///
+/// **Map Setup Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Setup data of the requested wafer map.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 15
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [FNLOC]
+///    4. [ORLOC]
+///    5. [RPSEL]
+///    6. List - N
+///       - [REFP]
+///    7. [DUTMS]
+///    8. [XDIES]
+///    9. [YDIES]
+///    10. [ROWCT]
+///    11. [COLCT]
+///    12. [PRDCT]
+///    13. [BCEQU]
+///    14. [NULBC]
+///    15. [MLCL]
+///
+/// N is the number of reference points.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [FNLOC]: FlatNotchLocation
+/// [ORLOC]: OriginLocation
+/// [RPSEL]: ReferencePointSelect
+/// [REFP]:  ReferencePoint
+/// [DUTMS]: DieUnitsOfMeasure
+/// [XDIES]: DieSizeX
+/// [YDIES]: DieSizeY
+/// [ROWCT]: RowCount
+/// [COLCT]: ColumnCount
+/// [PRDCT]: ProcessDieCount
+/// [BCEQU]: BinCodeEquivalents
+/// [NULBC]: NullBinCode
+/// [MLCL]:  MessageLength
+pub struct MapSetupData(pub (MaterialID, IDType, FlatNotchLocation, OriginLocation, ReferencePointSelect, VecList<ReferencePoint>, DieUnitsOfMeasure, DieSizeX, DieSizeY, RowCount, ColumnCount, ProcessDieCount, BinCodeEquivalents, NullBinCode, MessageLength));
+message_data!{MapSetupData, false, 12, 4}
+message_reply!{MapSetupDataRequest, MapSetupData}
+
+/// ## S12F5
+///
+/// **Map Transmit Inquire**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from the equipment for permission to send a wafer map of the
+/// given format and length.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [MAPFT]
+///    4. [MLCL]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [MAPFT]: MapFormat
+/// [MLCL]:  MessageLength
+pub struct MapTransmitInquire(pub (MaterialID, IDType, MapFormat, MessageLength));
+message_data!{MapTransmitInquire, true, 12, 5}
+
+/// ## S12F6
+///
+/// **Map Transmit Grant**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant or deny permission to send the wafer map.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [GRNT1]
+///
+/// [GRNT1]: Grant12
+pub struct MapTransmitGrant(pub CodedValue<Grant12>);
+message_data!{MapTransmitGrant, false, 12, 6}
+message_reply!{MapTransmitInquire, MapTransmitGrant}
+impl InquireMessage for MapTransmitInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    reply.0.is(Grant12::Granted)
+  }
+}
+
+/// ## S12F7
+///
+/// **Map Data Send Type 1**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sends a wafer map to the host in row/column format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [IDTYP]
+///    3. List - N
+///       - List - 2
+///          1. [RSINF]
+///          2. [BINLT]
+///
+/// N is the number of rows or columns.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [RSINF]: StartingLocation
+/// [BINLT]: BinList
+pub struct MapDataSendType1(pub (MaterialID, IDType, VecList<(StartingLocation, BinList)>));
+message_data!{MapDataSendType1, true, 12, 7}
+
+/// ## S12F8
+///
+/// **Map Data Acknowledge Type 1**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a row/column format wafer map.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [MDACK]
+///
+/// [MDACK]: MapDataAcknowledge
+pub struct MapDataAcknowledgeType1(pub CodedValue<MapDataAcknowledge>);
+message_data!{MapDataAcknowledgeType1, false, 12, 8}
+message_reply!{MapDataSendType1, MapDataAcknowledgeType1}
+impl MultiBlockMessage for MapDataSendType1 {
+  type Inquire = MapTransmitInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MapTransmitInquire((self.0.0.clone(), self.0.1, MapFormat::Row, length.into()))
+  }
+}
+
+/// ## S12F9
+///
+/// **Map Data Send Type 2**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sends a wafer map to the host in array format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [STRP]
+///    4. [BINLT]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [STRP]:  StartingPosition
+/// [BINLT]: BinList
+pub struct MapDataSendType2(pub (MaterialID, IDType, StartingPosition, BinList));
+message_data!{MapDataSendType2, true, 12, 9}
+
+/// ## S12F10
+///
+/// **Map Data Acknowledge Type 2**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of an array format wafer map.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [MDACK]
+///
+/// [MDACK]: MapDataAcknowledge
+pub struct MapDataAcknowledgeType2(pub CodedValue<MapDataAcknowledge>);
+message_data!{MapDataAcknowledgeType2, false, 12, 10}
+message_reply!{MapDataSendType2, MapDataAcknowledgeType2}
+impl MultiBlockMessage for MapDataSendType2 {
+  type Inquire = MapTransmitInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MapTransmitInquire((self.0.0.clone(), self.0.1, MapFormat::Array, length.into()))
+  }
+}
+
+/// ## S12F11
+///
+/// **Map Data Send Type 3**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sends a wafer map to the host in coordinate format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [IDTYP]
+///    3. List - N
+///       - List - 2
+///          1. [XYPOS]
+///          2. [BINLT]
+///
+/// N is the number of die.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [XYPOS]: XYPosition
+/// [BINLT]: BinList
+pub struct MapDataSendType3(pub (MaterialID, IDType, VecList<(XYPosition, BinList)>));
+message_data!{MapDataSendType3, true, 12, 11}
+
+/// ## S12F12
+///
+/// **Map Data Acknowledge Type 3**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a coordinate format wafer map.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [MDACK]
+///
+/// [MDACK]: MapDataAcknowledge
+pub struct MapDataAcknowledgeType3(pub CodedValue<MapDataAcknowledge>);
+message_data!{MapDataAcknowledgeType3, false, 12, 12}
+message_reply!{MapDataSendType3, MapDataAcknowledgeType3}
+impl MultiBlockMessage for MapDataSendType3 {
+  type Inquire = MapTransmitInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MapTransmitInquire((self.0.0.clone(), self.0.1, MapFormat::Coordinate, length.into()))
+  }
+}
+
+/// ## S12F13
+///
+/// **Map Data Request Type 1**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from the equipment for a wafer map in row/column format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [MID]
+///    2. [IDTYP]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+pub struct MapDataRequestType1(pub (MaterialID, IDType));
+message_data!{MapDataRequestType1, true, 12, 13}
+
+/// ## S12F14
+///
+/// **Map Data Type 1**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Wafer map in row/column format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [IDTYP]
+///    3. List - N
+///       - List - 2
+///          1. [RSINF]
+///          2. [BINLT]
+///
+/// N is the number of rows or columns.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [RSINF]: StartingLocation
+/// [BINLT]: BinList
+pub struct MapDataType1(pub (MaterialID, IDType, VecList<(StartingLocation, BinList)>));
+message_data!{MapDataType1, false, 12, 14}
+message_reply!{MapDataRequestType1, MapDataType1}
+
+/// ## S12F15
+///
+/// **Map Data Request Type 2**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from the equipment for a wafer map in array format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [MID]
+///    2. [IDTYP]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+pub struct MapDataRequestType2(pub (MaterialID, IDType));
+message_data!{MapDataRequestType2, true, 12, 15}
+
+/// ## S12F16
+///
+/// **Map Data Type 2**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Wafer map in array format.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [STRP]
+///    4. [BINLT]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [STRP]:  StartingPosition
+/// [BINLT]: BinList
+pub struct MapDataType2(pub (MaterialID, IDType, StartingPosition, BinList));
+message_data!{MapDataType2, false, 12, 16}
+message_reply!{MapDataRequestType2, MapDataType2}
+
+/// ## S12F17
+///
+/// **Map Data Request Type 3**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request from the equipment for a wafer map in coordinate format, with
+/// or without bin information.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [IDTYP]
+///    3. [SDBIN]
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [SDBIN]: SendBinInformation
+pub struct MapDataRequestType3(pub (MaterialID, IDType, SendBinInformation));
+message_data!{MapDataRequestType3, true, 12, 17}
+
+/// ## S12F18
+///
+/// **Map Data Type 3**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Wafer map in coordinate format.
+///
+/// If bin information was not requested, each [BINLT] is zero-length.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [IDTYP]
+///    3. List - N
+///       - List - 2
+///          1. [XYPOS]
+///          2. [BINLT]
+///
+/// N is the number of die.
+///
+/// [MID]:   MaterialID
+/// [IDTYP]: IDType
+/// [XYPOS]: XYPosition
+/// [BINLT]: BinList
+pub struct MapDataType3(pub (MaterialID, IDType, VecList<(XYPosition, BinList)>));
+message_data!{MapDataType3, false, 12, 18}
+message_reply!{MapDataRequestType3, MapDataType3}
+
+/// ## S12F19
+///
+/// **Map Error Report Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Reports an error detected in a previously received wafer map
+/// transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [MAPER]
+///    2. [DATLC]
+///
+/// [MAPER]: MapError
+/// [DATLC]: DataLocation
+pub struct MapErrorReportSend(pub (MapError, DataLocation));
+message_data!{MapErrorReportSend, false, 12, 19}
@@ -0,0 +1,564 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 13: DATA SET TRANSFER
+//! **Based on SEMI E5§10.17**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with the transfer of data sets between systems.
+//!
+//! It is not intended to provide a general file access mechanism.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! ## TO BE DONE
+//!
+//! - Enumerate the remaining [ACKC13] values
+//!
+//! [Message]: crate::Message
+//! [ACKC13]:  crate::items::AcknowledgeCode13
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+
+/// ## S13F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 13, 0}
+
+/// ## S13F1
+///
+/// **Send Data Set Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the receiver open the named data set on the sender and
+/// read it with [S13F3], [S13F5], and [S13F7].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 1
+///    1. [DSNAME]
+///
+/// [S13F3]:  OpenDataSetRequest
+/// [S13F5]:  ReadDataSetRequest
+/// [S13F7]:  CloseDataSetSend
+/// [DSNAME]: DataSetName
+pub struct SendDataSetSend(pub (DataSetName,));
+message_data!{SendDataSetSend, true, 13, 1}
+
+/// ## S13F2
+///
+/// **Send Data Set Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request to send a data set.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DSNAME]
+///    2. [ACKC13]
+///
+/// [DSNAME]: DataSetName
+/// [ACKC13]: AcknowledgeCode13
+pub struct SendDataSetAcknowledge(pub (DataSetName, CodedValue<AcknowledgeCode13>));
+message_data!{SendDataSetAcknowledge, false, 13, 2}
+message_reply!{SendDataSetSend, SendDataSetAcknowledge}
+
+/// ## S13F3
+///
+/// **Open Data Set Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a data set be opened for reading under the given handle,
+/// starting from the given checkpoint.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [HANDLE]
+///    2. [DSNAME]
+///    3. [CKPNT]
+///
+/// [HANDLE]: Handle
+/// [DSNAME]: DataSetName
+/// [CKPNT]:  Checkpoint
+pub struct OpenDataSetRequest(pub (Handle, DataSetName, Checkpoint));
+message_data!{OpenDataSetRequest, true, 13, 3}
+
+/// ## S13F4
+///
+/// **Open Data Set Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to open a data set.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [HANDLE]
+///    2. [DSNAME]
+///    3. [ACKC13]
+///    4. [RTYPE]
+///    5. [RECLEN]
+///
+/// [HANDLE]: Handle
+/// [DSNAME]: DataSetName
+/// [ACKC13]: AcknowledgeCode13
+/// [RTYPE]:  RecordType
+/// [RECLEN]: RecordLength
+pub struct OpenDataSetData(pub (Handle, DataSetName, CodedValue<AcknowledgeCode13>, RecordType, RecordLength));
+message_data!{OpenDataSetData, false, 13, 4}
+message_reply!{OpenDataSetRequest, OpenDataSetData}
+
+/// ## S13F5
+///
+/// **Read Data Set Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the next data from an open data set.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [HANDLE]
+///    2. [READLN]
+///
+/// [HANDLE]: Handle
+/// [READLN]: ReadLength
+pub struct ReadDataSetRequest(pub (Handle, ReadLength));
+message_data!{ReadDataSetRequest, true, 13, 5}
+
+/// ## S13F6
+///
+/// **Read Data Set Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Data read from an open data set, along with the checkpoint at which the
+/// read may be resumed.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [HANDLE]
+///    2. [ACKC13]
+///    3. [CKPNT]
+///    4. List - N
+///       - [FILDAT]
+///
+/// N is the number of records read.
+///
+/// [HANDLE]: Handle
+/// [ACKC13]: AcknowledgeCode13
+/// [CKPNT]:  Checkpoint
+/// [FILDAT]: FileData
+pub struct ReadDataSetData(pub (Handle, CodedValue<AcknowledgeCode13>, Checkpoint, VecList<FileData>));
+message_data!{ReadDataSetData, false, 13, 6}
+message_reply!{ReadDataSetRequest, ReadDataSetData}
+
+/// ## S13F7
+///
+/// **Close Data Set Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that an open data set be closed.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 1
+///    1. [HANDLE]
+///
+/// [HANDLE]: Handle
+pub struct CloseDataSetSend(pub (Handle,));
+message_data!{CloseDataSetSend, true, 13, 7}
+
+/// ## S13F8
+///
+/// **Close Data Set Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request to close a data set.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [HANDLE]
+///    2. [ACKC13]
+///
+/// [HANDLE]: Handle
+/// [ACKC13]: AcknowledgeCode13
+pub struct CloseDataSetAcknowledge(pub (Handle, CodedValue<AcknowledgeCode13>));
+message_data!{CloseDataSetAcknowledge, false, 13, 8}
+message_reply!{CloseDataSetSend, CloseDataSetAcknowledge}
+
+/// ## S13F9
+///
+/// **Reset Data Set Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that all open data sets be closed and their handles released.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ResetDataSetSend;
+message_headeronly!{ResetDataSetSend, true, 13, 9}
+
+/// ## S13F10
+///
+/// **Reset Data Set Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request to reset data sets.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ResetDataSetAcknowledge;
+message_headeronly!{ResetDataSetAcknowledge, false, 13, 10}
+message_reply!{ResetDataSetSend, ResetDataSetAcknowledge}
+
+/// ## S13F11
+///
+/// **Data Set Object Multi-Block Inquire (DSOI)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Establish that sending a multi-block message is allowed prior to sending
+/// [S13F13].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [OBJSPEC]
+///    3. [DATALENGTH]
+///
+/// [DATAID]:     DataID
+/// [OBJSPEC]:    ObjectSpecifier
+/// [DATALENGTH]: DataLength
+/// [S13F13]:     TableDataSend
+pub struct DataSetObjectMultiBlockInquire(pub (DataID, ObjectSpecifier, DataLength));
+message_data!{DataSetObjectMultiBlockInquire, true, 13, 11}
+
+/// ## S13F12
+///
+/// **Data Set Object Multi-Block Grant (DSOG)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant permission to send a multi-block message.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [GRANT]
+///
+/// [GRANT]: Grant
+pub struct DataSetObjectMultiBlockGrant(pub Grant);
+message_data!{DataSetObjectMultiBlockGrant, false, 13, 12}
+message_reply!{DataSetObjectMultiBlockInquire, DataSetObjectMultiBlockGrant}
+impl InquireMessage for DataSetObjectMultiBlockInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    matches!(reply.0, Grant::Granted)
+  }
+}
+
+/// ## S13F13
+///
+/// **Table Data Send**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sends table data, to be handled according to the given [TBLCMD].
+///
+/// [TBLCMD]: TableCommand
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 8
+///    1. [DATAID]
+///    2. [OBJSPEC]
+///    3. [TBLTYP]
+///    4. [TBLID]
+///    5. [TBLCMD]
+///    6. List - P
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    7. List - N
+///       - [COLHDR]
+///    8. List - M
+///       - List - N
+///          - [TBLELT]
+///
+/// - P is the number of table attributes.
+/// - N is the number of columns.
+/// - M is the number of rows.
+///
+/// [DATAID]:   DataID
+/// [OBJSPEC]:  ObjectSpecifier
+/// [TBLTYP]:   TableType
+/// [TBLID]:    TableID
+/// [TBLCMD]:   TableCommand
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [COLHDR]:   ColumnHeader
+/// [TBLELT]:   TableElement
+pub struct TableDataSend(pub (DataID, ObjectSpecifier, TableType, TableID, TableCommand, VecList<(AttributeID, AttributeValue)>, VecList<ColumnHeader>, VecList<VecList<TableElement>>));
+message_data!{TableDataSend, true, 13, 13}
+impl MultiBlockMessage for TableDataSend {
+  type Inquire = DataSetObjectMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    DataSetObjectMultiBlockInquire((self.0.0.clone(), self.0.1.clone(), length.into()))
+  }
+}
+
+/// ## S13F14
+///
+/// **Table Data Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the sent table data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TBLACK]
+///    2. List - N
+///       - List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [TBLACK]:  TableAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct TableDataAcknowledge(pub (CodedValue<TableAcknowledge>, VecList<(ErrorCode, ErrorText)>));
+message_data!{TableDataAcknowledge, false, 13, 14}
+message_reply!{TableDataSend, TableDataAcknowledge}
+
+/// ## S13F15
+///
+/// **Table Data Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests table data, optionally restricted to the given columns and to
+/// the rows whose first column matches the given values.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 7
+///    1. [DATAID]
+///    2. [OBJSPEC]
+///    3. [TBLTYP]
+///    4. [TBLID]
+///    5. [TBLCMD]
+///    6. List - N
+///       - [COLHDR]
+///    7. List - M
+///       - [TBLELT]
+///
+/// - N is the number of columns requested.
+/// - M is the number of rows requested.
+///
+/// [DATAID]:  DataID
+/// [OBJSPEC]: ObjectSpecifier
+/// [TBLTYP]:  TableType
+/// [TBLID]:   TableID
+/// [TBLCMD]:  TableCommand
+/// [COLHDR]:  ColumnHeader
+/// [TBLELT]:  TableElement
+pub struct TableDataRequest(pub (DataID, ObjectSpecifier, TableType, TableID, TableCommand, VecList<ColumnHeader>, VecList<TableElement>));
+message_data!{TableDataRequest, true, 13, 15}
+
+/// ## S13F16
+///
+/// **Table Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requested table data, along with the result of the request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 6
+///    1. [TBLTYP]
+///    2. [TBLID]
+///    3. List - P
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    4. List - N
+///       - [COLHDR]
+///    5. List - M
+///       - List - N
+///          - [TBLELT]
+///    6. List - 2
+///       1. [TBLACK]
+///       2. List - R
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - P is the number of table attributes.
+/// - N is the number of columns.
+/// - M is the number of rows.
+/// - R is the number of errors reported.
+///
+/// [TBLTYP]:   TableType
+/// [TBLID]:    TableID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [COLHDR]:   ColumnHeader
+/// [TBLELT]:   TableElement
+/// [TBLACK]:   TableAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct TableData(pub (TableType, TableID, VecList<(AttributeID, AttributeValue)>, VecList<ColumnHeader>, VecList<VecList<TableElement>>, (CodedValue<TableAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{TableData, false, 13, 16}
+message_reply!{TableDataRequest, TableData}
@@ -0,0 +1,1104 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 14: OBJECT SERVICES
+//! **Based on SEMI E5§10.18**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with generic functions concerning objects,
+//! including obtaining information about objects and setting values for an
+//! object.
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+
+/// ## S14F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 14, 0}
+
+/// ## S14F1
+///
+/// **GetAttr Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the values of the given attributes of the specified objects,
+/// optionally restricted to the objects which satisfy the given
+/// qualifiers.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [OBJSPEC]
+///    2. [OBJTYPE]
+///    3. List - N
+///       - [OBJID]
+///    4. List - Q
+///       - List - 3
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///          3. [ATTRRELN]
+///    5. List - A
+///       - [ATTRID]
+///
+/// - N is the number of objects, zero meaning all objects of [OBJTYPE].
+/// - Q is the number of qualifiers.
+/// - A is the number of attributes requested, zero meaning all attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [OBJTYPE]:  ObjectType
+/// [OBJID]:    ObjectID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [ATTRRELN]: AttributeRelation
+pub struct GetAttributeRequest(pub (ObjectSpecifier, ObjectType, VecList<ObjectID>, VecList<(AttributeID, AttributeValue, AttributeRelation)>, VecList<AttributeID>));
+message_data!{GetAttributeRequest, true, 14, 1}
+
+/// ## S14F2
+///
+/// **GetAttr Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requested attribute values of each object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - List - 2
+///          1. [OBJID]
+///          2. List - A
+///             - List - 2
+///                1. [ATTRID]
+///                2. [ATTRDATA]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of objects.
+/// - A is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [OBJID]:    ObjectID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct GetAttributeData(pub (VecList<(ObjectID, VecList<(AttributeID, AttributeValue)>)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{GetAttributeData, false, 14, 2}
+message_reply!{GetAttributeRequest, GetAttributeData}
+
+/// ## S14F3
+///
+/// **SetAttr Request**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given attributes of the specified objects be set to
+/// the given values.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [OBJSPEC]
+///    2. [OBJTYPE]
+///    3. List - N
+///       - [OBJID]
+///    4. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// - N is the number of objects.
+/// - A is the number of attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [OBJTYPE]:  ObjectType
+/// [OBJID]:    ObjectID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct SetAttributeRequest(pub (ObjectSpecifier, ObjectType, VecList<ObjectID>, VecList<(AttributeID, AttributeValue)>));
+message_data!{SetAttributeRequest, true, 14, 3}
+
+/// ## S14F4
+///
+/// **SetAttr Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Resulting attribute values of each object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - List - 2
+///          1. [OBJID]
+///          2. List - A
+///             - List - 2
+///                1. [ATTRID]
+///                2. [ATTRDATA]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of objects.
+/// - A is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [OBJID]:    ObjectID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct SetAttributeData(pub (VecList<(ObjectID, VecList<(AttributeID, AttributeValue)>)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{SetAttributeData, false, 14, 4}
+message_reply!{SetAttributeRequest, SetAttributeData}
+
+/// ## S14F5
+///
+/// **GetType Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the types of the objects which are owned by the specified
+/// object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [OBJSPEC]
+///
+/// [OBJSPEC]: ObjectSpecifier
+pub struct GetTypeRequest(pub ObjectSpecifier);
+message_data!{GetTypeRequest, true, 14, 5}
+
+/// ## S14F6
+///
+/// **GetType Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requested object types.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - [OBJTYPE]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of object types.
+/// - P is the number of errors reported.
+///
+/// [OBJTYPE]:  ObjectType
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct GetTypeData(pub (VecList<ObjectType>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{GetTypeData, false, 14, 6}
+message_reply!{GetTypeRequest, GetTypeData}
+
+/// ## S14F7
+///
+/// **GetAttrName Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the names of the attributes of the given object types.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [OBJSPEC]
+///    2. List - N
+///       - [OBJTYPE]
+///
+/// N is the number of object types.
+///
+/// [OBJSPEC]: ObjectSpecifier
+/// [OBJTYPE]: ObjectType
+pub struct GetAttributeNameRequest(pub (ObjectSpecifier, VecList<ObjectType>));
+message_data!{GetAttributeNameRequest, true, 14, 7}
+
+/// ## S14F8
+///
+/// **GetAttrName Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requested attribute names of each object type.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - List - 2
+///          1. [OBJTYPE]
+///          2. List - A
+///             - [ATTRID]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of object types.
+/// - A is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [OBJTYPE]:  ObjectType
+/// [ATTRID]:   AttributeID
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct GetAttributeNameData(pub (VecList<(ObjectType, VecList<AttributeID>)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{GetAttributeNameData, false, 14, 8}
+message_reply!{GetAttributeNameRequest, GetAttributeNameData}
+
+/// ## S14F9
+///
+/// **Create Object Request**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that an object of the given type be created, with the given
+/// initial attribute values.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [OBJSPEC]
+///    2. [OBJTYPE]
+///    3. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// A is the number of attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [OBJTYPE]:  ObjectType
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct CreateObjectRequest(pub (ObjectSpecifier, ObjectType, VecList<(AttributeID, AttributeValue)>));
+message_data!{CreateObjectRequest, true, 14, 9}
+
+/// ## S14F10
+///
+/// **Create Object Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to create an object, including the specifier and
+/// attribute values of the created object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [OBJSPEC]
+///    2. List - B
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    3. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - B is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct CreateObjectAcknowledge(pub (ObjectSpecifier, VecList<(AttributeID, AttributeValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{CreateObjectAcknowledge, false, 14, 10}
+message_reply!{CreateObjectRequest, CreateObjectAcknowledge}
+
+/// ## S14F11
+///
+/// **Delete Object Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the specified object be deleted.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [OBJSPEC]
+///    2. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// A is the number of attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct DeleteObjectRequest(pub (ObjectSpecifier, VecList<(AttributeID, AttributeValue)>));
+message_data!{DeleteObjectRequest, true, 14, 11}
+
+/// ## S14F12
+///
+/// **Delete Object Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to delete an object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - A is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct DeleteObjectAcknowledge(pub (VecList<(AttributeID, AttributeValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{DeleteObjectAcknowledge, false, 14, 12}
+message_reply!{DeleteObjectRequest, DeleteObjectAcknowledge}
+
+/// ## S14F13
+///
+/// **Object Attach Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests a token with which the specified object may later be acted
+/// upon with [S14F15].
+///
+/// [S14F15]: AttachedObjectActionRequest
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [OBJSPEC]
+///    2. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// A is the number of attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct ObjectAttachRequest(pub (ObjectSpecifier, VecList<(AttributeID, AttributeValue)>));
+message_data!{ObjectAttachRequest, true, 14, 13}
+
+/// ## S14F14
+///
+/// **Object Attach Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to attach to an object, including the token with
+/// which it may be acted upon.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [OBJTOKEN]
+///    2. List - B
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    3. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - B is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [OBJTOKEN]: ObjectToken
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct ObjectAttachAcknowledge(pub (ObjectToken, VecList<(AttributeID, AttributeValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ObjectAttachAcknowledge, false, 14, 14}
+message_reply!{ObjectAttachRequest, ObjectAttachAcknowledge}
+
+/// ## S14F15
+///
+/// **Attached Object Action Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that an action be performed on an attached object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [OBJSPEC]
+///    2. [OBJCMD]
+///    3. [OBJTOKEN]
+///    4. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// A is the number of attributes.
+///
+/// [OBJSPEC]:  ObjectSpecifier
+/// [OBJCMD]:   ObjectCommand
+/// [OBJTOKEN]: ObjectToken
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct AttachedObjectActionRequest(pub (ObjectSpecifier, ObjectCommand, ObjectToken, VecList<(AttributeID, AttributeValue)>));
+message_data!{AttachedObjectActionRequest, true, 14, 15}
+
+/// ## S14F16
+///
+/// **Attached Object Action Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the requested action on an attached object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - B
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - B is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct AttachedObjectActionAcknowledge(pub (VecList<(AttributeID, AttributeValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{AttachedObjectActionAcknowledge, false, 14, 16}
+message_reply!{AttachedObjectActionRequest, AttachedObjectActionAcknowledge}
+
+/// ## S14F17
+///
+/// **Supervised Object Action Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the specified supervisor object perform an action on the
+/// specified target object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [OBJSPEC]
+///    2. [OBJCMD]
+///    3. [TARGETSPEC]
+///    4. List - A
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// A is the number of attributes.
+///
+/// [OBJSPEC]:    ObjectSpecifier
+/// [OBJCMD]:     ObjectCommand
+/// [TARGETSPEC]: TargetSpecifier
+/// [ATTRID]:     AttributeID
+/// [ATTRDATA]:   AttributeValue
+pub struct SupervisedObjectActionRequest(pub (ObjectSpecifier, ObjectCommand, TargetSpecifier, VecList<(AttributeID, AttributeValue)>));
+message_data!{SupervisedObjectActionRequest, true, 14, 17}
+
+/// ## S14F18
+///
+/// **Supervised Object Action Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the requested action on a supervised object.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - B
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - B is the number of attributes.
+/// - P is the number of errors reported.
+///
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+/// [OBJACK]:   ObjectAcknowledge
+/// [ERRCODE]:  ErrorCode
+/// [ERRTEXT]:  ErrorText
+pub struct SupervisedObjectActionAcknowledge(pub (VecList<(AttributeID, AttributeValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{SupervisedObjectActionAcknowledge, false, 14, 18}
+message_reply!{SupervisedObjectActionRequest, SupervisedObjectActionAcknowledge}
+
+/// ## S14F19
+///
+/// **Generic Service Request (GSR)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request that the named service be performed by the specified object,
+/// with the given parameters.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [DATAID]
+///    2. [OPID]
+///    3. [OBJSPEC]
+///    4. [SVCNAME]
+///    5. List - N
+///       - List - 2
+///          1. [SPNAME]
+///          2. [SPVAL]
+///
+/// N is the number of service parameters.
+///
+/// [DATAID]:  DataID
+/// [OPID]:    OperationID
+/// [OBJSPEC]: ObjectSpecifier
+/// [SVCNAME]: ServiceName
+/// [SPNAME]:  ServiceParameterName
+/// [SPVAL]:   ServiceParameterValue
+pub struct GenericServiceRequest(pub (DataID, OperationID, ObjectSpecifier, ServiceName, VecList<(ServiceParameterName, ServiceParameterValue)>));
+message_data!{GenericServiceRequest, true, 14, 19}
+impl MultiBlockMessage for GenericServiceRequest {
+  type Inquire = GenericServiceMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    GenericServiceMultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S14F20
+///
+/// **Generic Service Acknowledge (GSA)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge a generic service request, returning any parameters of the
+/// result. If the service completes later, its completion is reported by
+/// [S14F21] with the same [LINKID].
+///
+/// [S14F21]: GenericServiceCompletion
+/// [LINKID]: LinkID
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [LINKID]
+///    2. List - N
+///       - List - 2
+///          1. [SPNAME]
+///          2. [SPVAL]
+///    3. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of service parameters.
+/// - P is the number of errors reported.
+///
+/// [LINKID]:  LinkID
+/// [SPNAME]:  ServiceParameterName
+/// [SPVAL]:   ServiceParameterValue
+/// [OBJACK]:  ObjectAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct GenericServiceAcknowledge(pub (LinkID, VecList<(ServiceParameterName, ServiceParameterValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{GenericServiceAcknowledge, false, 14, 20}
+message_reply!{GenericServiceRequest, GenericServiceAcknowledge}
+
+/// ## S14F21
+///
+/// **Generic Service Completion (GSC)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Report the completion of a generic service previously acknowledged by
+/// [S14F20] with the same [LINKID].
+///
+/// [S14F20]: GenericServiceAcknowledge
+/// [LINKID]: LinkID
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [DATAID]
+///    2. [OPID]
+///    3. [LINKID]
+///    4. List - N
+///       - List - 2
+///          1. [SPNAME]
+///          2. [SPVAL]
+///    5. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of service parameters.
+/// - P is the number of errors reported.
+///
+/// [DATAID]:  DataID
+/// [OPID]:    OperationID
+/// [LINKID]:  LinkID
+/// [SPNAME]:  ServiceParameterName
+/// [SPVAL]:   ServiceParameterValue
+/// [OBJACK]:  ObjectAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct GenericServiceCompletion(pub (DataID, OperationID, LinkID, VecList<(ServiceParameterName, ServiceParameterValue)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{GenericServiceCompletion, true, 14, 21}
+impl MultiBlockMessage for GenericServiceCompletion {
+  type Inquire = GenericServiceMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    GenericServiceMultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S14F22
+///
+/// **Generic Service Completion Acknowledge (GSCA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the completion report of a generic service.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DATAACK]
+///
+/// [DATAACK]: DataAcknowledge
+pub struct GenericServiceCompletionAcknowledge(pub DataAcknowledge);
+message_data!{GenericServiceCompletionAcknowledge, false, 14, 22}
+message_reply!{GenericServiceCompletion, GenericServiceCompletionAcknowledge}
+
+/// ## S14F23
+///
+/// **Generic Service Multi-Block Inquire (GSMI)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Establish that sending a multi-block message is allowed prior to sending
+/// [S14F19] or [S14F21].
+///
+/// [S14F19]: GenericServiceRequest
+/// [S14F21]: GenericServiceCompletion
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DATAID]
+///    2. [DATALENGTH]
+///
+/// [DATAID]:     DataID
+/// [DATALENGTH]: DataLength
+pub struct GenericServiceMultiBlockInquire(pub (DataID, DataLength));
+message_data!{GenericServiceMultiBlockInquire, true, 14, 23}
+
+/// ## S14F24
+///
+/// **Generic Service Multi-Block Grant (GSMG)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant permission to send a multi-block message.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [GRANT]
+///
+/// [GRANT]: Grant
+pub struct GenericServiceMultiBlockGrant(pub Grant);
+message_data!{GenericServiceMultiBlockGrant, false, 14, 24}
+message_reply!{GenericServiceMultiBlockInquire, GenericServiceMultiBlockGrant}
+impl InquireMessage for GenericServiceMultiBlockInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    matches!(reply.0, Grant::Granted)
+  }
+}
+
+/// ## S14F25
+///
+/// **Service Name Request (SNR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the names of the services supported by the given object types.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [OBJSPEC]
+///    2. List - N
+///       - [OBJTYPE]
+///
+/// N is the number of object types.
+///
+/// [OBJSPEC]: ObjectSpecifier
+/// [OBJTYPE]: ObjectType
+pub struct ServiceNameRequest(pub (ObjectSpecifier, VecList<ObjectType>));
+message_data!{ServiceNameRequest, true, 14, 25}
+
+/// ## S14F26
+///
+/// **Service Name Data (SND)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The names of the services supported by the requested object types.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - List - 2
+///          1. [OBJTYPE]
+///          2. List - S
+///             - [SVCNAME]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of object types.
+/// - S is the number of services of an object type.
+/// - P is the number of errors reported.
+///
+/// [OBJTYPE]: ObjectType
+/// [SVCNAME]: ServiceName
+/// [OBJACK]:  ObjectAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ServiceNameData(pub (VecList<(ObjectType, VecList<ServiceName>)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ServiceNameData, false, 14, 26}
+message_reply!{ServiceNameRequest, ServiceNameData}
+
+/// ## S14F27
+///
+/// **Service Parameter Request (SPR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the names of the parameters of the given services of an object
+/// type.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [OBJSPEC]
+///    2. [OBJTYPE]
+///    3. List - N
+///       - [SVCNAME]
+///
+/// N is the number of services.
+///
+/// [OBJSPEC]: ObjectSpecifier
+/// [OBJTYPE]: ObjectType
+/// [SVCNAME]: ServiceName
+pub struct ServiceParameterRequest(pub (ObjectSpecifier, ObjectType, VecList<ServiceName>));
+message_data!{ServiceParameterRequest, true, 14, 27}
+
+/// ## S14F28
+///
+/// **Service Parameter Data (SPD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The names of the parameters of the requested services.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - N
+///       - List - 2
+///          1. [SVCNAME]
+///          2. List - A
+///             - [SPNAME]
+///    2. List - 2
+///       1. [OBJACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of services.
+/// - A is the number of parameters of a service.
+/// - P is the number of errors reported.
+///
+/// [SVCNAME]: ServiceName
+/// [SPNAME]:  ServiceParameterName
+/// [OBJACK]:  ObjectAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ServiceParameterData(pub (VecList<(ServiceName, VecList<ServiceParameterName>)>, (CodedValue<ObjectAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ServiceParameterData, false, 14, 28}
+message_reply!{ServiceParameterRequest, ServiceParameterData}
@@ -0,0 +1,288 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 15: RECIPE MANAGEMENT
+//! **Based on SEMI E5§10.19**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with requestion information and operations
+//! concerning recipes, recipe namespaces, and recipe executors.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! A recipe is an object that is transferred in sections, where a section
+//! consists of either recipe attributes, agent-specific dataset attributes,
+//! or the body of the recipe.
+//!
+//! An attribute is information concerning the recipe body, the recipe as a
+//! whole, or the application of the recipe, and consists of a name/value
+//! pair.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! ## TO BE DONE
+//!
+//! - S15F3 through S15F26
+//! - S15F29, S15F30
+//! - S15F33 through S15F53
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+
+/// ## S15F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 15, 0}
+
+/// ## S15F1
+///
+/// **Recipe Management Multi-Block Inquire**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests permission to send a recipe management message which spans
+/// multiple blocks, such as the download of a large recipe body.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [RCPSPEC]
+///    3. [RMDATASIZE]
+///
+/// [DATAID]:     DataID
+/// [RCPSPEC]:    RecipeSpecifier
+/// [RMDATASIZE]: RecipeDataSize
+pub struct RecipeManagementMultiBlockInquire(pub (DataID, RecipeSpecifier, RecipeDataSize));
+message_data!{RecipeManagementMultiBlockInquire, true, 15, 1}
+impl InquireMessage for RecipeManagementMultiBlockInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    reply.0.is(RecipeManagementGrant::Granted)
+  }
+}
+
+/// ## S15F2
+///
+/// **Recipe Management Multi-Block Grant**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grants or denies permission to send a multi-block recipe management
+/// message.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RMGRNT]
+///
+/// [RMGRNT]: RecipeManagementGrant
+pub struct RecipeManagementMultiBlockGrant(pub CodedValue<RecipeManagementGrant>);
+message_data!{RecipeManagementMultiBlockGrant, false, 15, 2}
+message_reply!{RecipeManagementMultiBlockInquire, RecipeManagementMultiBlockGrant}
+
+/// ## S15F27
+///
+/// **Recipe Download Request**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Downloads a recipe to the equipment, including its attributes and body.
+///
+/// Where the message spans multiple blocks, permission to send it must
+/// first be granted by use of [S15F1].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [DATAID]
+///    2. [RCPOWCODE]
+///    3. [RCPSPEC]
+///    4. List - M
+///       - List - 2
+///          1. [RCPATTRID]
+///          2. [RCPATTRDATA]
+///    5. [RCPBODY]
+///
+/// - M is the number of recipe attributes.
+///
+/// [S15F1]:       RecipeManagementMultiBlockInquire
+/// [DATAID]:      DataID
+/// [RCPOWCODE]:   RecipeOverwriteCode
+/// [RCPSPEC]:     RecipeSpecifier
+/// [RCPATTRID]:   RecipeAttributeID
+/// [RCPATTRDATA]: RecipeAttributeData
+/// [RCPBODY]:     RecipeBody
+pub struct RecipeDownloadRequest(pub (DataID, RecipeOverwriteCode, RecipeSpecifier, VecList<(RecipeAttributeID, RecipeAttributeData)>, RecipeBody));
+message_data!{RecipeDownloadRequest, true, 15, 27}
+impl MultiBlockMessage for RecipeDownloadRequest {
+  type Inquire = RecipeManagementMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    RecipeManagementMultiBlockInquire((self.0.0.clone(), self.0.2.clone(), length.into()))
+  }
+}
+
+/// ## S15F28
+///
+/// **Recipe Download Acknowledge**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledges the download of a recipe, providing the [RCPSPEC] under
+/// which it was stored and the attributes of the recipe as stored.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [RCPSPEC]
+///    2. List - N
+///       - List - 2
+///          1. [RCPATTRID]
+///          2. [RCPATTRDATA]
+///    3. List - 2
+///       1. [RMACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - N is the number of recipe attributes.
+/// - P is the number of errors reported.
+///
+/// [RCPSPEC]:     RecipeSpecifier
+/// [RCPATTRID]:   RecipeAttributeID
+/// [RCPATTRDATA]: RecipeAttributeData
+/// [RMACK]:       RecipeManagementAcknowledge
+/// [ERRCODE]:     ErrorCode
+/// [ERRTEXT]:     ErrorText
+pub struct RecipeDownloadAcknowledge(pub (RecipeSpecifier, VecList<(RecipeAttributeID, RecipeAttributeData)>, (CodedValue<RecipeManagementAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{RecipeDownloadAcknowledge, false, 15, 28}
+message_reply!{RecipeDownloadRequest, RecipeDownloadAcknowledge}
+
+/// ## S15F31
+///
+/// **Recipe Upload Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests a recipe kept by the equipment.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RCPSPEC]
+///
+/// [RCPSPEC]: RecipeSpecifier
+pub struct RecipeUploadRequest(pub RecipeSpecifier);
+message_data!{RecipeUploadRequest, true, 15, 31}
+
+/// ## S15F32
+///
+/// **Recipe Upload Data**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The requested recipe, including its attributes and body.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [RCPSPEC]
+///    2. List - M
+///       - List - 2
+///          1. [RCPATTRID]
+///          2. [RCPATTRDATA]
+///    3. [RCPBODY]
+///    4. List - 2
+///       1. [RMACK]
+///       2. List - P
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - M is the number of recipe attributes.
+/// - P is the number of errors reported.
+///
+/// Where the recipe could not be provided, its body is zero-length.
+///
+/// [RCPSPEC]:     RecipeSpecifier
+/// [RCPATTRID]:   RecipeAttributeID
+/// [RCPATTRDATA]: RecipeAttributeData
+/// [RCPBODY]:     RecipeBody
+/// [RMACK]:       RecipeManagementAcknowledge
+/// [ERRCODE]:     ErrorCode
+/// [ERRTEXT]:     ErrorText
+pub struct RecipeUploadData(pub (RecipeSpecifier, VecList<(RecipeAttributeID, RecipeAttributeData)>, RecipeBody, (CodedValue<RecipeManagementAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{RecipeUploadData, false, 15, 32}
+message_reply!{RecipeUploadRequest, RecipeUploadData}
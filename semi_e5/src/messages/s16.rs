@@ -0,0 +1,729 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 16: PROCESSING MANAGEMENT
+//! **Based on SEMI E5§10.20**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with control of material processing at equipment
+//! and equipment resources.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! Control is implemented by supporting two job types; the control job and
+//! the process job.
+//!
+//! A process job is a single unit of work that ensures that the appropriate
+//! processing is applied to a particular material by a processing resource.
+//! It provides a widely applicable supervisory control capability for
+//! automated processing of material in equipment, irrespective of the
+//! particular process being used. It also creates a transient link between
+//! the three elements of the manufacturing process (material, equipment,
+//! and recipe). When a process job has been completed, it ceases to exist;
+//! its Job ID is no longer valid.
+//!
+//! A control job is used to group a set of related process jobs. The group
+//! is logically related from the host's viewpoint. It also provides
+//! mechanisms for specifying the destination for processed material.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! ## TO BE DONE
+//!
+//! - S16F3, S16F4 - PRJobCreate
+//! - S16F9, S16F10 - PRJobEvent
+//! - S16F13, S16F14 - PRJobDuplicateCreate
+//! - S16F23, S16F24 - PRJobSetRecipeVariable
+//! - S16F29, S16F30 - PRSetMtrlOrder
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+
+/// ## S16F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 16, 0}
+
+/// ## S16F1
+///
+/// **PRJob Multi-Block Inquire**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests permission to send a multi-block process job message.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DATAID]
+///    2. [DATALENGTH]
+///
+/// [DATAID]:     DataID
+/// [DATALENGTH]: DataLength
+pub struct ProcessJobMultiBlockInquire(pub (DataID, DataLength));
+message_data!{ProcessJobMultiBlockInquire, true, 16, 1}
+
+/// ## S16F2
+///
+/// **PRJob Multi-Block Grant**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grants or denies permission to send a multi-block process job message.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [GRANT]
+///
+/// [GRANT]: Grant
+pub struct ProcessJobMultiBlockGrant(pub Grant);
+message_data!{ProcessJobMultiBlockGrant, false, 16, 2}
+message_reply!{ProcessJobMultiBlockInquire, ProcessJobMultiBlockGrant}
+impl InquireMessage for ProcessJobMultiBlockInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    matches!(reply.0, Grant::Granted)
+  }
+}
+
+/// ## S16F5
+///
+/// **PRJob Command**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given command be performed on a process job.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [DATAID]
+///    2. [PRJOBID]
+///    3. [PRCMDNAME]
+///    4. List - N
+///       - List - 2
+///          1. [CPNAME]
+///          2. [CPVAL]
+///
+/// N is the number of command parameters.
+///
+/// [DATAID]:    DataID
+/// [PRJOBID]:   ProcessJobID
+/// [PRCMDNAME]: ProcessJobCommandName
+/// [CPNAME]:    CommandParameterName
+/// [CPVAL]:     CommandParameterValue
+pub struct ProcessJobCommand(pub (DataID, ProcessJobID, ProcessJobCommandName, VecList<(CommandParameterName, CommandParameterValue)>));
+message_data!{ProcessJobCommand, true, 16, 5}
+
+/// ## S16F6
+///
+/// **PRJob Command Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the requested process job command.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PRJOBID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [PRJOBID]: ProcessJobID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ProcessJobCommandAcknowledge(pub (ProcessJobID, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobCommandAcknowledge, false, 16, 6}
+message_reply!{ProcessJobCommand, ProcessJobCommandAcknowledge}
+
+/// ## S16F7
+///
+/// **PRJob Alert Notify**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notifies the host that a process job has reached a milestone or
+/// encountered an error.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TIMESTAMP]
+///    2. [PRJOBID]
+///    3. [PRJOBMILESTONE]
+///    4. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [TIMESTAMP]:      TimeStamp
+/// [PRJOBID]:        ProcessJobID
+/// [PRJOBMILESTONE]: ProcessJobMilestone
+/// [ACKA]:           AcknowledgeAny
+/// [ERRCODE]:        ErrorCode
+/// [ERRTEXT]:        ErrorText
+pub struct ProcessJobAlertNotify(pub (TimeStamp, ProcessJobID, ProcessJobMilestone, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobAlertNotify, true, 16, 7}
+
+/// ## S16F8
+///
+/// **PRJob Alert Confirm**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Confirms receipt of a process job alert.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ProcessJobAlertConfirm;
+message_headeronly!{ProcessJobAlertConfirm, false, 16, 8}
+message_reply!{ProcessJobAlertNotify, ProcessJobAlertConfirm}
+
+/// ## S16F11
+///
+/// **PRJob Create Enhanced**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a process job be created for the given material and
+/// recipe.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 7
+///    1. [DATAID]
+///    2. [PRJOBID]
+///    3. [MF]
+///    4. [ProcessJobMaterial]
+///    5. List - 3
+///       1. [PRRECIPEMETHOD]
+///       2. [RCPSPEC]
+///       3. List - M
+///          - List - 2
+///             1. [RCPPARNM]
+///             2. [RCPPARVAL]
+///    6. [PRPROCESSSTART]
+///    7. List - P
+///       - [PRPAUSEEVENT]
+///
+/// - M is the number of recipe variables.
+/// - P is the number of pause events.
+///
+/// [DATAID]:             DataID
+/// [PRJOBID]:            ProcessJobID
+/// [MF]:                 MaterialFormat
+/// [ProcessJobMaterial]: ProcessJobMaterial
+/// [PRRECIPEMETHOD]:     ProcessJobRecipeMethod
+/// [RCPSPEC]:            RecipeSpecifier
+/// [RCPPARNM]:           RecipeParameterName
+/// [RCPPARVAL]:          RecipeParameterValue
+/// [PRPROCESSSTART]:     ProcessJobProcessStart
+/// [PRPAUSEEVENT]:       ProcessJobPauseEvent
+pub struct ProcessJobCreateEnhanced(pub (DataID, ProcessJobID, MaterialFormat, ProcessJobMaterial, (ProcessJobRecipeMethod, RecipeSpecifier, VecList<(RecipeParameterName, RecipeParameterValue)>), ProcessJobProcessStart, VecList<ProcessJobPauseEvent>));
+message_data!{ProcessJobCreateEnhanced, true, 16, 11}
+
+/// ## S16F12
+///
+/// **PRJob Create Enhanced Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to create a process job.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PRJOBID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [PRJOBID]: ProcessJobID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ProcessJobCreateEnhancedAcknowledge(pub (ProcessJobID, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobCreateEnhancedAcknowledge, false, 16, 12}
+message_reply!{ProcessJobCreateEnhanced, ProcessJobCreateEnhancedAcknowledge}
+impl MultiBlockMessage for ProcessJobCreateEnhanced {
+  type Inquire = ProcessJobMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    ProcessJobMultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S16F15
+///
+/// **PRJob Multi Create**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that several process jobs be created at once.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DATAID]
+///    2. List - J
+///       - List - 6
+///          1. [PRJOBID]
+///          2. [MF]
+///          3. [ProcessJobMaterial]
+///          4. List - 3
+///             1. [PRRECIPEMETHOD]
+///             2. [RCPSPEC]
+///             3. List - M
+///                - List - 2
+///                   1. [RCPPARNM]
+///                   2. [RCPPARVAL]
+///          5. [PRPROCESSSTART]
+///          6. List - P
+///             - [PRPAUSEEVENT]
+///
+/// - J is the number of process jobs.
+/// - M is the number of recipe variables.
+/// - P is the number of pause events.
+///
+/// [DATAID]:             DataID
+/// [PRJOBID]:            ProcessJobID
+/// [MF]:                 MaterialFormat
+/// [ProcessJobMaterial]: ProcessJobMaterial
+/// [PRRECIPEMETHOD]:     ProcessJobRecipeMethod
+/// [RCPSPEC]:            RecipeSpecifier
+/// [RCPPARNM]:           RecipeParameterName
+/// [RCPPARVAL]:          RecipeParameterValue
+/// [PRPROCESSSTART]:     ProcessJobProcessStart
+/// [PRPAUSEEVENT]:       ProcessJobPauseEvent
+pub struct ProcessJobMultiCreate(pub (DataID, VecList<(ProcessJobID, MaterialFormat, ProcessJobMaterial, (ProcessJobRecipeMethod, RecipeSpecifier, VecList<(RecipeParameterName, RecipeParameterValue)>), ProcessJobProcessStart, VecList<ProcessJobPauseEvent>)>));
+message_data!{ProcessJobMultiCreate, true, 16, 15}
+
+/// ## S16F16
+///
+/// **PRJob Multi Create Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to create several process jobs.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - J
+///       - [PRJOBID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - J is the number of process jobs created.
+/// - N is the number of errors reported.
+///
+/// [PRJOBID]: ProcessJobID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ProcessJobMultiCreateAcknowledge(pub (VecList<ProcessJobID>, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobMultiCreateAcknowledge, false, 16, 16}
+message_reply!{ProcessJobMultiCreate, ProcessJobMultiCreateAcknowledge}
+impl MultiBlockMessage for ProcessJobMultiCreate {
+  type Inquire = ProcessJobMultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    ProcessJobMultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S16F17
+///
+/// **PRJob Dequeue**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given queued process jobs be removed from the queue.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - J
+///    - [PRJOBID]
+///
+/// J is the number of process jobs, zero meaning all queued process jobs.
+///
+/// [PRJOBID]: ProcessJobID
+pub struct ProcessJobDequeue(pub VecList<ProcessJobID>);
+message_data!{ProcessJobDequeue, true, 16, 17}
+
+/// ## S16F18
+///
+/// **PRJob Dequeue Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to dequeue process jobs.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - J
+///       - [PRJOBID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - J is the number of process jobs dequeued.
+/// - N is the number of errors reported.
+///
+/// [PRJOBID]: ProcessJobID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ProcessJobDequeueAcknowledge(pub (VecList<ProcessJobID>, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobDequeueAcknowledge, false, 16, 18}
+message_reply!{ProcessJobDequeue, ProcessJobDequeueAcknowledge}
+
+/// ## S16F19
+///
+/// **PRJob Get All Jobs**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the list of process jobs which have not yet completed.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ProcessJobGetAllJobs;
+message_headeronly!{ProcessJobGetAllJobs, true, 16, 19}
+
+/// ## S16F20
+///
+/// **PRJob Get All Jobs Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// List of process jobs and the state of each.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - J
+///    - List - 2
+///       1. [PRJOBID]
+///       2. [PRSTATE]
+///
+/// J is the number of process jobs.
+///
+/// [PRJOBID]: ProcessJobID
+/// [PRSTATE]: ProcessJobState
+pub struct ProcessJobGetAllJobsSend(pub VecList<(ProcessJobID, CodedValue<ProcessJobState>)>);
+message_data!{ProcessJobGetAllJobsSend, false, 16, 20}
+message_reply!{ProcessJobGetAllJobs, ProcessJobGetAllJobsSend}
+
+/// ## S16F21
+///
+/// **PRJob Get Space**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the number of process jobs which may still be created.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ProcessJobGetSpace;
+message_headeronly!{ProcessJobGetSpace, true, 16, 21}
+
+/// ## S16F22
+///
+/// **PRJob Get Space Send**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Number of process jobs which may still be created.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PRJOBSPACE]
+///
+/// [PRJOBSPACE]: ProcessJobSpace
+pub struct ProcessJobGetSpaceSend(pub ProcessJobSpace);
+message_data!{ProcessJobGetSpaceSend, false, 16, 22}
+message_reply!{ProcessJobGetSpace, ProcessJobGetSpaceSend}
+
+/// ## S16F25
+///
+/// **PRJob Set Start Method**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the start method of the given process jobs be changed.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PRPROCESSSTART]
+///    2. List - J
+///       - [PRJOBID]
+///
+/// J is the number of process jobs.
+///
+/// [PRPROCESSSTART]: ProcessJobProcessStart
+/// [PRJOBID]:        ProcessJobID
+pub struct ProcessJobSetStartMethod(pub (ProcessJobProcessStart, VecList<ProcessJobID>));
+message_data!{ProcessJobSetStartMethod, true, 16, 25}
+
+/// ## S16F26
+///
+/// **PRJob Set Start Method Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to change the start method of process jobs.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. List - J
+///       - [PRJOBID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - N
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// - J is the number of process jobs changed.
+/// - N is the number of errors reported.
+///
+/// [PRJOBID]: ProcessJobID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ProcessJobSetStartMethodAcknowledge(pub (VecList<ProcessJobID>, (AcknowledgeAny, VecList<(ErrorCode, ErrorText)>)));
+message_data!{ProcessJobSetStartMethodAcknowledge, false, 16, 26}
+message_reply!{ProcessJobSetStartMethod, ProcessJobSetStartMethodAcknowledge}
+
+/// ## S16F27
+///
+/// **Control Job Command Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given command be performed on a control job.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [CTLJOBID]
+///    2. [CTLJOBCMD]
+///    3. List - 2
+///       1. [CPNAME]
+///       2. [CPVAL]
+///
+/// [CTLJOBID]:  ControlJobID
+/// [CTLJOBCMD]: ControlJobCommand
+/// [CPNAME]:    CommandParameterName
+/// [CPVAL]:     CommandParameterValue
+pub struct ControlJobCommandRequest(pub (ControlJobID, ControlJobCommand, (CommandParameterName, CommandParameterValue)));
+message_data!{ControlJobCommandRequest, true, 16, 27}
+
+/// ## S16F28
+///
+/// **Control Job Command Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the requested control job command.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [ACKA]
+///    2. List - 2
+///       1. [ERRCODE]
+///       2. [ERRTEXT]
+///
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ControlJobCommandAcknowledge(pub (AcknowledgeAny, (ErrorCode, ErrorText)));
+message_data!{ControlJobCommandAcknowledge, false, 16, 28}
+message_reply!{ControlJobCommandRequest, ControlJobCommandAcknowledge}
@@ -0,0 +1,501 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 17: EQUIPMENT CONTROL AND DIAGNOSTICS
+//! **Based on SEMI E5§10.21**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with control of the equipment from the host.
+//!
+//! This includes all remote operations and equipment self-diagnostics and
+//! calibration but specifically excluses:
+//!
+//! - Control operations associated with material transfer ([Stream 4]).
+//! - Loading of executive and boot programs ([Stream 8]).
+//! - File and operating system calls ([Stream 10], [Stream 13]).
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! This is a continuation of [Stream 2].
+//!
+//! [Message]: crate::Message
+//! [Stream 2]: crate::messages::s2
+//! [Stream 4]: crate::messages::s4
+//! [Stream 8]: crate::messages::s8
+//! [Stream 10]: crate::messages::s10
+//! [Stream 13]: crate::messages::s13
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S17F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 17, 0}
+
+/// ## S17F1
+///
+/// **Data Report Create Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the equipment create a data report from the given
+/// variables of the given data source.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [DATAID]
+///    2. [RPTID]
+///    3. [DATASRC]
+///    4. List - N
+///       - [VID]
+///
+/// N is the number of variables.
+///
+/// [DATAID]:  DataID
+/// [RPTID]:   ReportID
+/// [DATASRC]: DataSource
+/// [VID]:     VariableID
+pub struct DataReportCreateRequest(pub (DataID, ReportID, DataSource, VecList<VariableID>));
+message_data!{DataReportCreateRequest, true, 17, 1}
+
+/// ## S17F2
+///
+/// **Data Report Create Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to create a data report.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [RPTID]
+///    2. [ERRCODE]
+///
+/// [RPTID]:   ReportID
+/// [ERRCODE]: ErrorCode
+pub struct DataReportCreateAcknowledge(pub (ReportID, ErrorCode));
+message_data!{DataReportCreateAcknowledge, false, 17, 2}
+message_reply!{DataReportCreateRequest, DataReportCreateAcknowledge}
+
+/// ## S17F3
+///
+/// **Data Report Delete Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the equipment delete the given data reports.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [RPTID]
+///
+/// N is the number of data reports, zero meaning all data reports.
+///
+/// [RPTID]: ReportID
+pub struct DataReportDeleteRequest(pub VecList<ReportID>);
+message_data!{DataReportDeleteRequest, true, 17, 3}
+
+/// ## S17F4
+///
+/// **Data Report Delete Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to delete data reports.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [ACKA]
+///    2. List - N
+///       - List - 3
+///          1. [RPTID]
+///          2. [ERRCODE]
+///          3. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [ACKA]:    AcknowledgeAny
+/// [RPTID]:   ReportID
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct DataReportDeleteAcknowledge(pub (AcknowledgeAny, VecList<(ReportID, ErrorCode, ErrorText)>));
+message_data!{DataReportDeleteAcknowledge, false, 17, 4}
+message_reply!{DataReportDeleteRequest, DataReportDeleteAcknowledge}
+
+/// ## S17F5
+///
+/// **Trace Create Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the equipment create a trace which samples the variables of
+/// the given data reports, started and stopped by the given collection
+/// events.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 9
+///    1. [DATAID]
+///    2. [TRID]
+///    3. [DSPER]
+///    4. [TOTSMP]
+///    5. [REPGSZ]
+///    6. [TRAUTOD]
+///    7. [RPTOC]
+///    8. List - E
+///       - List - 2
+///          1. [CEID]
+///          2. [CEED]
+///    9. List - R
+///       - [RPTID]
+///
+/// - E is the number of collection events, each of which starts the trace
+///   if its [CEED] is true and stops it if false.
+/// - R is the number of data reports, as created by [S17F1].
+///
+/// [DATAID]:  DataID
+/// [TRID]:    TraceRequestID
+/// [DSPER]:   DataSamplePeriod
+/// [TOTSMP]:  TotalSamples
+/// [REPGSZ]:  ReportingGroupSize
+/// [TRAUTOD]: TraceAutoDelete
+/// [RPTOC]:   ReportOnChange
+/// [CEID]:    CollectionEventID
+/// [CEED]:    CollectionEventEnableDisable
+/// [RPTID]:   ReportID
+/// [S17F1]:   DataReportCreateRequest
+pub struct TraceCreateRequest(pub (DataID, TraceRequestID, DataSamplePeriod, TotalSamples, ReportingGroupSize, TraceAutoDelete, ReportOnChange, VecList<(CollectionEventID, CollectionEventEnableDisable)>, VecList<ReportID>));
+message_data!{TraceCreateRequest, true, 17, 5}
+
+/// ## S17F6
+///
+/// **Trace Create Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to create a trace.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TRID]
+///    2. [ERRCODE]
+///
+/// [TRID]:    TraceRequestID
+/// [ERRCODE]: ErrorCode
+pub struct TraceCreateAcknowledge(pub (TraceRequestID, ErrorCode));
+message_data!{TraceCreateAcknowledge, false, 17, 6}
+message_reply!{TraceCreateRequest, TraceCreateAcknowledge}
+
+/// ## S17F7
+///
+/// **Trace Delete Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the equipment delete the given traces.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [TRID]
+///
+/// N is the number of traces, zero meaning all traces.
+///
+/// [TRID]: TraceRequestID
+pub struct TraceDeleteRequest(pub VecList<TraceRequestID>);
+message_data!{TraceDeleteRequest, true, 17, 7}
+
+/// ## S17F8
+///
+/// **Trace Delete Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to delete traces.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [ACKA]
+///    2. List - N
+///       - List - 3
+///          1. [TRID]
+///          2. [ERRCODE]
+///          3. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [ACKA]:    AcknowledgeAny
+/// [TRID]:    TraceRequestID
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct TraceDeleteAcknowledge(pub (AcknowledgeAny, VecList<(TraceRequestID, ErrorCode, ErrorText)>));
+message_data!{TraceDeleteAcknowledge, false, 17, 8}
+message_reply!{TraceDeleteRequest, TraceDeleteAcknowledge}
+
+/// ## S17F9
+///
+/// **Collection Event Link Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given data reports be linked to a collection event of
+/// the given event source, so that they are collected when it occurs.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [DATAID]
+///    2. [EVNTSRC]
+///    3. [CEID]
+///    4. List - N
+///       - [RPTID]
+///
+/// N is the number of data reports.
+///
+/// [DATAID]:  DataID
+/// [EVNTSRC]: EventSource
+/// [CEID]:    CollectionEventID
+/// [RPTID]:   ReportID
+pub struct CollectionEventLinkRequest(pub (DataID, EventSource, CollectionEventID, VecList<ReportID>));
+message_data!{CollectionEventLinkRequest, true, 17, 9}
+
+/// ## S17F10
+///
+/// **Collection Event Link Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to link data reports to a collection event.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [EVNTSRC]
+///    2. [CEID]
+///    3. [ERRCODE]
+///
+/// [EVNTSRC]: EventSource
+/// [CEID]:    CollectionEventID
+/// [ERRCODE]: ErrorCode
+pub struct CollectionEventLinkAcknowledge(pub (EventSource, CollectionEventID, ErrorCode));
+message_data!{CollectionEventLinkAcknowledge, false, 17, 10}
+message_reply!{CollectionEventLinkRequest, CollectionEventLinkAcknowledge}
+
+/// ## S17F11
+///
+/// **Collection Event Unlink Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a data report be unlinked from a collection event of the
+/// given event source.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [EVNTSRC]
+///    2. [CEID]
+///    3. [RPTID]
+///
+/// [EVNTSRC]: EventSource
+/// [CEID]:    CollectionEventID
+/// [RPTID]:   ReportID
+pub struct CollectionEventUnlinkRequest(pub (EventSource, CollectionEventID, ReportID));
+message_data!{CollectionEventUnlinkRequest, true, 17, 11}
+
+/// ## S17F12
+///
+/// **Collection Event Unlink Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to unlink a data report from a collection event.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [EVNTSRC]
+///    2. [CEID]
+///    3. [RPTID]
+///    4. [ERRCODE]
+///
+/// [EVNTSRC]: EventSource
+/// [CEID]:    CollectionEventID
+/// [RPTID]:   ReportID
+/// [ERRCODE]: ErrorCode
+pub struct CollectionEventUnlinkAcknowledge(pub (EventSource, CollectionEventID, ReportID, ErrorCode));
+message_data!{CollectionEventUnlinkAcknowledge, false, 17, 12}
+message_reply!{CollectionEventUnlinkRequest, CollectionEventUnlinkAcknowledge}
+
+/// ## S17F13
+///
+/// **Trace Reset Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the equipment reset the given traces to their initial
+/// state.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [TRID]
+///
+/// N is the number of traces, zero meaning all traces.
+///
+/// [TRID]: TraceRequestID
+pub struct TraceResetRequest(pub VecList<TraceRequestID>);
+message_data!{TraceResetRequest, true, 17, 13}
+
+/// ## S17F14
+///
+/// **Trace Reset Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to reset traces.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [ACKA]
+///    2. List - N
+///       - List - 3
+///          1. [TRID]
+///          2. [ERRCODE]
+///          3. [ERRTEXT]
+///
+/// N is the number of errors reported.
+///
+/// [ACKA]:    AcknowledgeAny
+/// [TRID]:    TraceRequestID
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct TraceResetAcknowledge(pub (AcknowledgeAny, VecList<(TraceRequestID, ErrorCode, ErrorText)>));
+message_data!{TraceResetAcknowledge, false, 17, 14}
+message_reply!{TraceResetRequest, TraceResetAcknowledge}
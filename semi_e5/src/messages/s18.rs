@@ -0,0 +1,533 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 18: SUBSYSTEM CONTROL AND DATA
+//! **Based on SEMI E5§10.22**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with interfacing between component subsystems and
+//! higher level controllers.
+//!
+//! Compared to similar mesages exchanged between equipment and host,
+//! subsystem messages are less complex.
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S18F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 18, 0}
+
+/// ## S18F1
+///
+/// **Read Attribute Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests the values of the given attributes of a subsystem component.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TARGETID]
+///    2. List - N
+///       - [ATTRID]
+///
+/// N is the number of attributes.
+///
+/// [TARGETID]: TargetID
+/// [ATTRID]:   AttributeID
+pub struct ReadAttributeRequest(pub (TargetID, VecList<AttributeID>));
+message_data!{ReadAttributeRequest, true, 18, 1}
+
+/// ## S18F2
+///
+/// **Read Attribute Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requested attribute values, in the order requested.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. List - N
+///       - [ATTRDATA]
+///    4. List - S
+///       - [STATUS]
+///
+/// - N is the number of attributes.
+/// - S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [ATTRDATA]: AttributeValue
+/// [STATUS]:   Status
+pub struct ReadAttributeData(pub (TargetID, SubsystemAcknowledge, VecList<AttributeValue>, VecList<Status>));
+message_data!{ReadAttributeData, false, 18, 2}
+message_reply!{ReadAttributeRequest, ReadAttributeData}
+
+/// ## S18F3
+///
+/// **Write Attribute Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that the given attributes of a subsystem component be set to
+/// the given values.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TARGETID]
+///    2. List - N
+///       - List - 2
+///          1. [ATTRID]
+///          2. [ATTRDATA]
+///
+/// N is the number of attributes.
+///
+/// [TARGETID]: TargetID
+/// [ATTRID]:   AttributeID
+/// [ATTRDATA]: AttributeValue
+pub struct WriteAttributeRequest(pub (TargetID, VecList<(AttributeID, AttributeValue)>));
+message_data!{WriteAttributeRequest, true, 18, 3}
+
+/// ## S18F4
+///
+/// **Write Attribute Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to write attributes.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [STATUS]:   Status
+pub struct WriteAttributeAcknowledge(pub (TargetID, SubsystemAcknowledge, VecList<Status>));
+message_data!{WriteAttributeAcknowledge, false, 18, 4}
+message_reply!{WriteAttributeRequest, WriteAttributeAcknowledge}
+
+/// ## S18F5
+///
+/// **Read Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests data from a segment of a subsystem component.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [DATASEG]
+///    3. [DATALENGTH]
+///
+/// [TARGETID]:   TargetID
+/// [DATASEG]:    DataSegment
+/// [DATALENGTH]: DataLength
+pub struct ReadRequest(pub (TargetID, DataSegment, DataLength));
+message_data!{ReadRequest, true, 18, 5}
+
+/// ## S18F6
+///
+/// **Read Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Data read from a segment of a subsystem component.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. [DATA]
+///    4. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [DATA]:     Data
+/// [STATUS]:   Status
+pub struct ReadData(pub (TargetID, SubsystemAcknowledge, Data, VecList<Status>));
+message_data!{ReadData, false, 18, 6}
+message_reply!{ReadRequest, ReadData}
+
+/// ## S18F7
+///
+/// **Write Data Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that data be written to a segment of a subsystem component.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TARGETID]
+///    2. [DATASEG]
+///    3. [DATALENGTH]
+///    4. [DATA]
+///
+/// [TARGETID]:   TargetID
+/// [DATASEG]:    DataSegment
+/// [DATALENGTH]: DataLength
+/// [DATA]:       Data
+pub struct WriteDataRequest(pub (TargetID, DataSegment, DataLength, Data));
+message_data!{WriteDataRequest, true, 18, 7}
+
+/// ## S18F8
+///
+/// **Write Data Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to write data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [STATUS]:   Status
+pub struct WriteDataAcknowledge(pub (TargetID, SubsystemAcknowledge, VecList<Status>));
+message_data!{WriteDataAcknowledge, false, 18, 8}
+message_reply!{WriteDataRequest, WriteDataAcknowledge}
+
+/// ## S18F9
+///
+/// **Read ID Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a subsystem component read the ID of its material.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [TARGETID]
+///
+/// [TARGETID]: TargetID
+pub struct ReadIDRequest(pub TargetID);
+message_data!{ReadIDRequest, true, 18, 9}
+
+/// ## S18F10
+///
+/// **Read ID Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Material ID read by a subsystem component.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. [MID]
+///    4. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [MID]:      MaterialID
+/// [STATUS]:   Status
+pub struct ReadIDData(pub (TargetID, SubsystemAcknowledge, MaterialID, VecList<Status>));
+message_data!{ReadIDData, false, 18, 10}
+message_reply!{ReadIDRequest, ReadIDData}
+
+/// ## S18F11
+///
+/// **Write ID Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a subsystem component write the given ID to its material.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [TARGETID]
+///    2. [MID]
+///
+/// [TARGETID]: TargetID
+/// [MID]:      MaterialID
+pub struct WriteIDRequest(pub (TargetID, MaterialID));
+message_data!{WriteIDRequest, true, 18, 11}
+
+/// ## S18F12
+///
+/// **Write ID Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the request to write a material ID.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [STATUS]:   Status
+pub struct WriteIDAcknowledge(pub (TargetID, SubsystemAcknowledge, VecList<Status>));
+message_data!{WriteIDAcknowledge, false, 18, 12}
+message_reply!{WriteIDRequest, WriteIDAcknowledge}
+
+/// ## S18F13
+///
+/// **Subsystem Command Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a subsystem component perform the given command.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [SSCMD]
+///    3. List - N
+///       - [CPVAL]
+///
+/// N is the number of command parameters.
+///
+/// [TARGETID]: TargetID
+/// [SSCMD]:    SubsystemCommand
+/// [CPVAL]:    CommandParameterValue
+pub struct SubsystemCommandRequest(pub (TargetID, SubsystemCommand, VecList<CommandParameterValue>));
+message_data!{SubsystemCommandRequest, true, 18, 13}
+
+/// ## S18F14
+///
+/// **Subsystem Command Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Result of the requested subsystem command.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. List - S
+///       - [STATUS]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]: TargetID
+/// [SSACK]:    SubsystemAcknowledge
+/// [STATUS]:   Status
+pub struct SubsystemCommandAcknowledge(pub (TargetID, SubsystemAcknowledge, VecList<Status>));
+message_data!{SubsystemCommandAcknowledge, false, 18, 14}
+message_reply!{SubsystemCommandRequest, SubsystemCommandAcknowledge}
+
+/// ## S18F15
+///
+/// **Read 2D Code Condition Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that a subsystem component read a 2D code and report the
+/// condition of the read.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 1
+///    1. [TARGETID]
+///
+/// [TARGETID]: TargetID
+pub struct Read2DCodeConditionRequest(pub (TargetID,));
+message_data!{Read2DCodeConditionRequest, true, 18, 15}
+
+/// ## S18F16
+///
+/// **Read 2D Code Condition Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Material ID read from a 2D code, along with the condition of the read.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [TARGETID]
+///    2. [SSACK]
+///    3. [MID]
+///    4. List - S
+///       - [STATUS]
+///    5. [CONDITIONLIST]
+///
+/// S is the number of status items.
+///
+/// [TARGETID]:      TargetID
+/// [SSACK]:         SubsystemAcknowledge
+/// [MID]:           MaterialID
+/// [STATUS]:        Status
+/// [CONDITIONLIST]: ConditionList
+pub struct Read2DCodeConditionData(pub (TargetID, SubsystemAcknowledge, MaterialID, VecList<Status>, ConditionList));
+message_data!{Read2DCodeConditionData, false, 18, 16}
+message_reply!{Read2DCodeConditionRequest, Read2DCodeConditionData}
@@ -46,6 +46,8 @@
 use crate::*;
 use crate::Error::*;
 use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+use crate::messages::wire;
 
 /// ## S2F0
 /// 
@@ -113,6 +115,7 @@ message_data!{ServiceProgramLoadInquire, true, 2, 1}
 /// [GRANT]: Grant
 pub struct ServiceProgramLoadGrant(pub Grant);
 message_data!{ServiceProgramLoadGrant, false, 2, 2}
+message_reply!{ServiceProgramLoadInquire, ServiceProgramLoadGrant}
 
 /// ## S2F3
 /// 
@@ -159,6 +162,7 @@ message_data!{ServiceProgramSend, true, 2, 3}
 /// [SPAACK]: ServiceProgramAcknowledge
 pub struct ServiceProgramSendAcknowledge(pub ServiceProgramAcknowledge);
 message_data!{ServiceProgramSendAcknowledge, false, 2, 4}
+message_reply!{ServiceProgramSend, ServiceProgramSendAcknowledge}
 
 /// ## S2F5
 /// 
@@ -205,6 +209,7 @@ message_data!{ServiceProgramLoadRequest, true, 2, 5}
 /// [SPD]: ServiceProgramData
 pub struct ServiceProgramLoadData(pub ServiceProgramData);
 message_data!{ServiceProgramLoadData, false, 2, 6}
+message_reply!{ServiceProgramLoadRequest, ServiceProgramLoadData}
 
 /// ## S2F7
 /// 
@@ -250,6 +255,7 @@ message_data!{ServiceProgramRunSend, true, 2, 7}
 /// [CSAACK]: ServiceAcknowledgeCode
 pub struct ServiceProgramRunAcknowledge(pub ServiceAcknowledgeCode);
 message_data!{ServiceProgramRunAcknowledge, false, 2, 8}
+message_reply!{ServiceProgramRunSend, ServiceProgramRunAcknowledge}
 
 /// ## S2F9
 /// 
@@ -296,6 +302,7 @@ message_data!{ServiceProgramResultsRequest, true, 2, 9}
 /// [SPR]: ServiceProgramResults
 pub struct ServiceProgramResultsData(pub ServiceProgramResults);
 message_item!{ServiceProgramResultsData, false, 2, 10}
+message_reply!{ServiceProgramResultsRequest, ServiceProgramResultsData}
 
 /// ## S2F11
 /// 
@@ -341,10 +348,11 @@ message_headeronly!{ServiceProgramDirectoryRequest, true, 2, 11}
 /// [SPID]: ServiceProgramID
 pub struct ServiceProgramDirectoryData(pub VecList<ServiceProgramID>);
 message_data!{ServiceProgramDirectoryData, false, 2, 12}
+message_reply!{ServiceProgramDirectoryRequest, ServiceProgramDirectoryData}
 
 /// ## S2F13
 /// 
-/// **Equipment Calibration Request (ECR)**
+/// **Equipment Constant Request (ECR)**
 /// 
 /// - **SINGLE-BLOCK**
 /// - **HOST -> EQUIPMENT**
@@ -400,6 +408,7 @@ message_data!{EquipmentConstantRequest, true, 2, 13}
 /// [ECV]:  EquipmentConstantValue
 pub struct EquipmentConstantData(pub VecList<OptionItem<EquipmentConstantValue>>);
 message_data!{EquipmentConstantData, false, 2, 14}
+message_reply!{EquipmentConstantRequest, EquipmentConstantData}
 
 /// ## S2F15
 /// 
@@ -454,6 +463,7 @@ message_data!{NewEquipmentConstantSend, true, 2, 15}
 /// [S2F15]: NewEquipmentConstantSend
 pub struct NewEquipmentConstantAcknowledge(pub EquipmentAcknowledgeCode);
 message_data!{NewEquipmentConstantAcknowledge, false, 2, 16}
+message_reply!{NewEquipmentConstantSend, NewEquipmentConstantAcknowledge}
 
 /// ## S2F17
 /// 
@@ -474,6 +484,26 @@ message_data!{NewEquipmentConstantAcknowledge, false, 2, 16}
 /// Header only.
 pub struct DateTimeRequest;
 message_headeronly!{DateTimeRequest, true, 2, 17}
+impl DateTimeRequest {
+  /// ### TO BYTES
+  /// 
+  /// Serializes the message's text directly into binary data, which is
+  /// empty as the message is header only.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    vec![]
+  }
+}
+impl TryFrom<&[u8]> for DateTimeRequest {
+  type Error = Error;
+
+  /// ### BINARY DATA -> DATE AND TIME REQUEST
+  /// 
+  /// Deserializes the message's text directly from binary data, which must
+  /// be empty as the message is header only.
+  fn try_from(text: &[u8]) -> Result<Self, Self::Error> {
+    if text.is_empty() {Ok(Self)} else {Err(WrongFormat)}
+  }
+}
 
 /// ## S2F18
 /// 
@@ -498,6 +528,31 @@ message_headeronly!{DateTimeRequest, true, 2, 17}
 /// [TIME]: Time
 pub struct DateTimeData(pub Time);
 message_data!{DateTimeData, false, 2, 18}
+message_reply!{DateTimeRequest, DateTimeData}
+impl DateTimeData {
+  /// ### TO BYTES
+  /// 
+  /// Serializes the message's text directly into binary data.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut vec: Vec<u8> = vec![];
+    wire::write_item(&mut vec, &self.0);
+    vec
+  }
+}
+impl TryFrom<&[u8]> for DateTimeData {
+  type Error = Error;
+
+  /// ### BINARY DATA -> DATE AND TIME DATA
+  /// 
+  /// Deserializes the message's text directly from binary data.
+  /// 
+  /// The default [Decode Limits] are enforced.
+  /// 
+  /// [Decode Limits]: crate::DecodeLimits
+  fn try_from(text: &[u8]) -> Result<Self, Self::Error> {
+    Ok(Self(wire::Reader::new(text, &DecodeLimits::default())?.item()?))
+  }
+}
 
 /// ## S2F19
 /// 
@@ -542,6 +597,7 @@ message_data!{ResetInitializeSend, true, 2, 19}
 /// [RAC]: ResetAcknowledgeCode
 pub struct ResetAcknowledge(pub ResetAcknowledgeCode);
 message_data!{ResetAcknowledge, false, 2, 20}
+message_reply!{ResetInitializeSend, ResetAcknowledge}
 
 /// ## S2F21
 /// 
@@ -551,21 +607,22 @@ message_data!{ResetAcknowledge, false, 2, 20}
 /// - **HOST -> EQUIPMENT**
 /// - **REPLY OPTIONAL**
 /// 
-/// TODO: Implement optional reply.
-/// 
 /// ---------------------------------------------------------------------------
 /// 
 /// Cause activity on equipment to commence or cease.
 /// 
+/// The second field is the [Reply Bit], set if a reply is requested.
+/// 
 /// ---------------------------------------------------------------------------
 /// 
 /// #### Structure
 /// 
 /// - [RCMD]
 /// 
-/// [RCMD]: RemoteCommand
-pub struct RemoteCommandSend(pub RemoteCommand);
-message_data!{RemoteCommandSend, true, 2, 21}
+/// [RCMD]:      RemoteCommand
+/// [Reply Bit]: crate::Message::w
+pub struct RemoteCommandSend(pub RemoteCommand, pub bool);
+message_data!{RemoteCommandSend, optional, 2, 21}
 
 /// ## S2F22
 /// 
@@ -588,6 +645,7 @@ message_data!{RemoteCommandSend, true, 2, 21}
 /// [CMDA]: CommandAcknowledge
 pub struct RemoteCommandAcknowledge(pub CommandAcknowledge);
 message_data!{RemoteCommandAcknowledge, false, 2, 22}
+message_reply!{RemoteCommandSend, RemoteCommandAcknowledge}
 
 /// ## S2F23
 /// 
@@ -663,6 +721,7 @@ message_data!{TraceInitializeSend, true, 2, 23}
 /// [TIAACK]: TraceInitializeAcknowledgeCode
 pub struct TraceInitializeAcknowledge(pub TraceInitializeAcknowledgeCode);
 message_data!{TraceInitializeAcknowledge, false, 2, 24}
+message_reply!{TraceInitializeSend, TraceInitializeAcknowledge}
 
 /// ## S2F25
 /// 
@@ -709,6 +768,7 @@ message_data!{LoopbackDiagnosticRequest, true, 2, 25}
 /// [ABS]: AnyBinaryString
 pub struct LoopbackDiagnosticData(pub AnyBinaryString);
 message_data!{LoopbackDiagnosticData, false, 2, 26}
+message_reply!{LoopbackDiagnosticRequest, LoopbackDiagnosticData}
 
 /// ## S2F27
 /// 
@@ -767,6 +827,7 @@ message_data!{InitiateProcessingRequest, true, 2, 27}
 /// [CMDA]: CommandAcknowledge
 pub struct InitiateProcessingAcknowledge(pub CommandAcknowledge);
 message_data!{InitiateProcessingAcknowledge, false, 2, 28}
+message_reply!{InitiateProcessingRequest, InitiateProcessingAcknowledge}
 
 /// ## S2F29
 /// 
@@ -833,6 +894,7 @@ message_data!{EquipmentConstantNamelistRequest, true, 2, 29}
 /// [UNITS]:  Units
 pub struct EquipmentConstantNamelist(pub VecList<(EquipmentConstantID, EquipmentConstantName, EquipmentConstantMinimumValue, EquipmentConstantMaximumValue, EquipmentConstantDefaultValue, Units)>);
 message_data!{EquipmentConstantNamelist, false, 2, 30}
+message_reply!{EquipmentConstantNamelistRequest, EquipmentConstantNamelist}
 
 /// ## S2F31
 /// 
@@ -877,6 +939,7 @@ message_data!{DateTimeSetRequest, true, 2, 31}
 /// [TIACK]: TimeAcknowledgeCode
 pub struct DateTimeSetAcknowledge(pub TimeAcknowledgeCode);
 message_data!{DateTimeSetAcknowledge, false, 2, 32}
+message_reply!{DateTimeSetRequest, DateTimeSetAcknowledge}
 
 /// ## S2F33
 /// 
@@ -916,6 +979,91 @@ message_data!{DateTimeSetAcknowledge, false, 2, 32}
 /// [CEID]:   CollectionEventID
 pub struct DefineReport(pub (DataID, VecList<(ReportID, VecList<VariableID>)>));
 message_data!{DefineReport, true, 2, 33}
+impl DefineReport {
+  /// ### BUILDER
+  /// 
+  /// Begins constructing the message with the given [DATAID].
+  /// 
+  /// [DATAID]: DataID
+  pub fn builder(data_id: DataID) -> DefineReportBuilder {
+    DefineReportBuilder {
+      data_id,
+      reports: vec![],
+    }
+  }
+}
+
+/// ## DEFINE REPORT BUILDER
+/// 
+/// Assembles a [Define Report] message from its report definitions, checking
+/// that the result is well formed.
+/// 
+/// A builder to which no reports are added produces a message which deletes
+/// all report definitions and associated links.
+/// 
+/// [Define Report]: DefineReport
+pub struct DefineReportBuilder {
+  data_id: DataID,
+  reports: Vec<(ReportID, Option<Vec<VariableID>>)>,
+}
+impl DefineReportBuilder {
+  /// ### REPORT
+  /// 
+  /// Defines a report with the given [RPTID] as containing the given [VID]s.
+  /// 
+  /// The list of [VID]s must not be empty; use [delete] to delete a report.
+  /// 
+  /// [RPTID]:  ReportID
+  /// [VID]:    VariableID
+  /// [delete]: Self::delete
+  pub fn report(mut self, id: ReportID, variables: impl IntoIterator<Item = VariableID>) -> Self {
+    self.reports.push((id, Some(variables.into_iter().collect())));
+    self
+  }
+
+  /// ### DELETE
+  /// 
+  /// Deletes the report with the given [RPTID] and any [CEID]s linked to it,
+  /// by sending it with a zero-length list of [VID]s.
+  /// 
+  /// [RPTID]: ReportID
+  /// [CEID]:  CollectionEventID
+  /// [VID]:   VariableID
+  pub fn delete(mut self, id: ReportID) -> Self {
+    self.reports.push((id, None));
+    self
+  }
+
+  /// ### BUILD
+  /// 
+  /// Produces the message.
+  /// 
+  /// Fails with [Duplicate ID] if an [RPTID] appears more than once, or with
+  /// [Invalid Length] if a report defined with [report] has no [VID]s.
+  /// 
+  /// [RPTID]:          ReportID
+  /// [VID]:            VariableID
+  /// [report]:         Self::report
+  /// [Duplicate ID]:   crate::Error::DuplicateID
+  /// [Invalid Length]: crate::Error::InvalidLength
+  pub fn build(self) -> Result<DefineReport, Error> {
+    let mut reports = Vec::with_capacity(self.reports.len());
+    for (id, variables) in self.reports {
+      if reports.iter().any(|(existing, _): &(ReportID, VecList<VariableID>)| *existing == id) {
+        return Err(DuplicateID)
+      }
+      let variables = match variables {
+        Some(variables) if variables.is_empty() => {
+          return Err(InvalidLength {length: 0, min: 1, max: usize::MAX})
+        },
+        Some(variables) => variables,
+        None => vec![],
+      };
+      reports.push((id, VecList(variables)));
+    }
+    Ok(DefineReport((self.data_id, VecList(reports))))
+  }
+}
 
 /// ## S2F34
 /// 
@@ -939,6 +1087,14 @@ message_data!{DefineReport, true, 2, 33}
 /// [DRACK]: DefineReportAcknowledgeCode
 pub struct DefineReportAcknowledge(pub DefineReportAcknowledgeCode);
 message_data!{DefineReportAcknowledge, false, 2, 34}
+message_reply!{DefineReport, DefineReportAcknowledge}
+impl MultiBlockMessage for DefineReport {
+  type Inquire = MultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
 
 /// ## S2F35
 /// 
@@ -978,6 +1134,94 @@ message_data!{DefineReportAcknowledge, false, 2, 34}
 /// [RPTID]:  ReportID
 pub struct LinkEventReport(pub (DataID, VecList<(CollectionEventID, VecList<ReportID>)>));
 message_data!{LinkEventReport, true, 2, 35}
+impl LinkEventReport {
+  /// ### BUILDER
+  /// 
+  /// Begins constructing the message with the given [DATAID].
+  /// 
+  /// [DATAID]: DataID
+  pub fn builder(data_id: DataID) -> LinkEventReportBuilder {
+    LinkEventReportBuilder {
+      data_id,
+      links: vec![],
+    }
+  }
+}
+
+/// ## LINK EVENT REPORT BUILDER
+/// 
+/// Assembles a [Link Event Report] message from links between collection
+/// events and reports, checking that the result is well formed.
+/// 
+/// [Link Event Report]: LinkEventReport
+pub struct LinkEventReportBuilder {
+  data_id: DataID,
+  links: Vec<(CollectionEventID, Option<Vec<ReportID>>)>,
+}
+impl LinkEventReportBuilder {
+  /// ### LINK
+  /// 
+  /// Links the given [RPTID]s to the collection event with the given [CEID].
+  /// 
+  /// The list of [RPTID]s must not be empty; use [unlink] to remove all
+  /// reports from a collection event.
+  /// 
+  /// [CEID]:   CollectionEventID
+  /// [RPTID]:  ReportID
+  /// [unlink]: Self::unlink
+  pub fn link(mut self, event: CollectionEventID, reports: impl IntoIterator<Item = ReportID>) -> Self {
+    self.links.push((event, Some(reports.into_iter().collect())));
+    self
+  }
+
+  /// ### UNLINK
+  /// 
+  /// Deletes all reports associated with the collection event with the given
+  /// [CEID], by sending it with a zero-length list of [RPTID]s.
+  /// 
+  /// [CEID]:  CollectionEventID
+  /// [RPTID]: ReportID
+  pub fn unlink(mut self, event: CollectionEventID) -> Self {
+    self.links.push((event, None));
+    self
+  }
+
+  /// ### BUILD
+  /// 
+  /// Produces the message.
+  /// 
+  /// Fails with [Duplicate ID] if a [CEID] appears more than once, or an
+  /// [RPTID] appears more than once for the same [CEID], or with
+  /// [Invalid Length] if a collection event given to [link] has no [RPTID]s.
+  /// 
+  /// [CEID]:           CollectionEventID
+  /// [RPTID]:          ReportID
+  /// [link]:           Self::link
+  /// [Duplicate ID]:   crate::Error::DuplicateID
+  /// [Invalid Length]: crate::Error::InvalidLength
+  pub fn build(self) -> Result<LinkEventReport, Error> {
+    let mut links = Vec::with_capacity(self.links.len());
+    for (event, reports) in self.links {
+      if links.iter().any(|(existing, _): &(CollectionEventID, VecList<ReportID>)| *existing == event) {
+        return Err(DuplicateID)
+      }
+      let reports = match reports {
+        Some(reports) if reports.is_empty() => {
+          return Err(InvalidLength {length: 0, min: 1, max: usize::MAX})
+        },
+        Some(reports) => reports,
+        None => vec![],
+      };
+      for (i, report) in reports.iter().enumerate() {
+        if reports[..i].contains(report) {
+          return Err(DuplicateID)
+        }
+      }
+      links.push((event, VecList(reports)));
+    }
+    Ok(LinkEventReport((self.data_id, VecList(links))))
+  }
+}
 
 /// ## S2F36
 /// 
@@ -1001,6 +1245,14 @@ message_data!{LinkEventReport, true, 2, 35}
 /// [LRACK]: LinkReportAcknowledgeCode
 pub struct LinkEventReportAcknowledge(pub LinkReportAcknowledgeCode);
 message_data!{LinkEventReportAcknowledge, false, 2, 36}
+message_reply!{LinkEventReport, LinkEventReportAcknowledge}
+impl MultiBlockMessage for LinkEventReport {
+  type Inquire = MultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
 
 /// ## S2F37
 /// 
@@ -1031,6 +1283,63 @@ message_data!{LinkEventReportAcknowledge, false, 2, 36}
 /// [CEID]: CollectionEventID
 pub struct EnableDisableEventReport(pub (CollectionEventEnableDisable, VecList<CollectionEventID>));
 message_data!{EnableDisableEventReport, true, 2, 37}
+impl EnableDisableEventReport {
+  /// ### ENABLE
+  /// 
+  /// Enables reporting for the collection events with the given [CEID]s.
+  /// 
+  /// An empty list of [CEID]s enables reporting for all collection events,
+  /// as with [enable all].
+  /// 
+  /// [CEID]:       CollectionEventID
+  /// [enable all]: Self::enable_all
+  pub fn enable(events: impl IntoIterator<Item = CollectionEventID>) -> Self {
+    Self((CollectionEventEnableDisable(true), VecList(events.into_iter().collect())))
+  }
+
+  /// ### DISABLE
+  /// 
+  /// Disables reporting for the collection events with the given [CEID]s.
+  /// 
+  /// An empty list of [CEID]s disables reporting for all collection events,
+  /// as with [disable all].
+  /// 
+  /// [CEID]:        CollectionEventID
+  /// [disable all]: Self::disable_all
+  pub fn disable(events: impl IntoIterator<Item = CollectionEventID>) -> Self {
+    Self((CollectionEventEnableDisable(false), VecList(events.into_iter().collect())))
+  }
+
+  /// ### ENABLE ALL
+  /// 
+  /// Enables reporting for all collection events, by sending a zero-length
+  /// list of [CEID]s.
+  /// 
+  /// [CEID]: CollectionEventID
+  pub fn enable_all() -> Self {
+    Self::enable([])
+  }
+
+  /// ### DISABLE ALL
+  /// 
+  /// Disables reporting for all collection events, by sending a zero-length
+  /// list of [CEID]s.
+  /// 
+  /// [CEID]: CollectionEventID
+  pub fn disable_all() -> Self {
+    Self::disable([])
+  }
+
+  /// ### IS ALL EVENTS
+  /// 
+  /// Returns whether the message applies to all collection events, which is
+  /// the case when its list of [CEID]s is empty.
+  /// 
+  /// [CEID]: CollectionEventID
+  pub fn is_all_events(&self) -> bool {
+    self.0.1.0.is_empty()
+  }
+}
 
 /// ## S2F38
 /// 
@@ -1054,6 +1363,7 @@ message_data!{EnableDisableEventReport, true, 2, 37}
 /// [ERACK]: EnableDisableEventReportAcknowledgeCode
 pub struct EnableDisableEventReportAcknowledge(pub EnableDisableEventReportAcknowledgeCode);
 message_data!{EnableDisableEventReportAcknowledge, false, 2, 38}
+message_reply!{EnableDisableEventReport, EnableDisableEventReportAcknowledge}
 
 /// ## S2F39
 /// 
@@ -1107,6 +1417,12 @@ message_data!{MultiBlockInquire, true, 2, 39}
 /// [GRANT]: Grant
 pub struct MultiBlockGrant(pub Grant);
 message_data!{MultiBlockGrant, false, 2, 40}
+message_reply!{MultiBlockInquire, MultiBlockGrant}
+impl InquireMessage for MultiBlockInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    matches!(reply.0, Grant::Granted)
+  }
+}
 
 /// ## S2F41
 /// 
@@ -1168,6 +1484,7 @@ message_data!{HostCommandSend, true, 2, 41}
 /// [CPACK]:  CommandParameterAcknowledgeCode
 pub struct HostCommandAcknowledge(pub (HostCommandAcknowledgeCode, VecList<(CommandParameterName, CommandParameterAcknowledgeCode)>));
 message_data!{HostCommandAcknowledge, false, 2, 42}
+message_reply!{HostCommandSend, HostCommandAcknowledge}
 
 /// ## S2F43
 /// 
@@ -1254,6 +1571,7 @@ message_data!{ResetSpoolingStreamsAndFunctions, true, 2, 43}
 /// [FCNID]:  FunctionID
 pub struct ResetSpoolingAcknowledge(pub (ResetSpoolingAcknowledgeCode, VecList<(StreamID, SpoolStreamAcknowledgeCode, VecList<FunctionID>)>));
 message_data!{ResetSpoolingAcknowledge, false, 2, 44}
+message_reply!{ResetSpoolingStreamsAndFunctions, ResetSpoolingAcknowledge}
 
 /// ## S2F45
 /// 
@@ -1342,6 +1660,14 @@ message_data!{DefineVariableLimitAttributes, true, 2, 45}
 /// [LIMITACK]: VariableLimitAttributeSetAcknowledgeCode
 pub struct VariableLimitAttributeAcknowledge(pub (VariableLimitAttributeAcknowledgeCode, VecList<(VariableID, VariableLimitDefinitonAcknowledgeCode, OptionItem<(LimitID, VariableLimitAttributeSetAcknowledgeCode)>)>));
 message_data!{VariableLimitAttributeAcknowledge, false, 2, 46}
+message_reply!{DefineVariableLimitAttributes, VariableLimitAttributeAcknowledge}
+impl MultiBlockMessage for DefineVariableLimitAttributes {
+  type Inquire = MultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
 
 /// ## S2F47
 /// 
@@ -1417,6 +1743,7 @@ message_data!{VariableLimitAttributeRequest, true, 2, 47}
 /// [LOWERDB]:  LowerDeadband
 pub struct VariableLimitAttributeSend(pub VecList<(VariableID, OptionItem<(Units, LimitMinimum, LimitMaximum, VecList<(LimitID, UpperDeadband, LowerDeadband)>)>)>);
 message_data!{VariableLimitAttributeSend, false, 2, 48}
+message_reply!{VariableLimitAttributeRequest, VariableLimitAttributeSend}
 
 /// ## S2F49
 /// 
@@ -1453,6 +1780,28 @@ message_data!{VariableLimitAttributeSend, false, 2, 48}
 /// [CEPVAL]:  CommandEnhancedParameterValue
 pub struct EnhancedRemoteCommand(pub (DataID, ObjectSpecifier, RemoteCommand, VecList<(CommandParameterName, CommandEnhancedParameterValue)>));
 message_data!{EnhancedRemoteCommand, true, 2, 49}
+impl EnhancedRemoteCommand {
+  /// ### NEW
+  /// 
+  /// Constructs the command without any parameters, which may then be added
+  /// with [parameter].
+  /// 
+  /// [parameter]: Self::parameter
+  pub fn new(data_id: DataID, object_specifier: ObjectSpecifier, command: RemoteCommand) -> Self {
+    Self((data_id, object_specifier, command, VecList(vec![])))
+  }
+
+  /// ### PARAMETER
+  /// 
+  /// Adds a parameter to the command. The [CEPVAL] may be a single value, a
+  /// list of values, or a list of nested name-value pairs.
+  /// 
+  /// [CEPVAL]: CommandEnhancedParameterValue
+  pub fn parameter(mut self, name: CommandParameterName, value: CommandEnhancedParameterValue) -> Self {
+    self.0.3.0.push((name, value));
+    self
+  }
+}
 
 /// ## S2F50
 /// 
@@ -1484,6 +1833,37 @@ message_data!{EnhancedRemoteCommand, true, 2, 49}
 /// 
 /// [HCACK]:  HostCommandAcknowledgeCode
 /// [CPNAME]: CommandParameterName
-/// [CEPACK]: CommandEnhancedParameterAcknowledgeCode
-pub struct EnhancedRemoteCommandAcknowledge(pub (HostCommandAcknowledgeCode, VecList<(CommandParameterName, CommandParameterAcknowledgeCode)>));
+/// [CEPACK]: CommandEnhancedParameterAcknowledge
+pub struct EnhancedRemoteCommandAcknowledge(pub (HostCommandAcknowledgeCode, VecList<(CommandParameterName, CommandEnhancedParameterAcknowledge)>));
 message_data!{EnhancedRemoteCommandAcknowledge, false, 2, 50}
+impl EnhancedRemoteCommandAcknowledge {
+  /// ### NEW
+  /// 
+  /// Constructs the acknowledge without any parameters in error, which may
+  /// then be added with [parameter].
+  /// 
+  /// [parameter]: Self::parameter
+  pub fn new(code: HostCommandAcknowledgeCode) -> Self {
+    Self((code, VecList(vec![])))
+  }
+
+  /// ### PARAMETER
+  /// 
+  /// Adds a parameter in error to the acknowledge. The [CEPACK] should
+  /// mirror the form of the [CEPVAL] it is acknowledging.
+  /// 
+  /// [CEPACK]: CommandEnhancedParameterAcknowledge
+  /// [CEPVAL]: CommandEnhancedParameterValue
+  pub fn parameter(mut self, name: CommandParameterName, acknowledge: CommandEnhancedParameterAcknowledge) -> Self {
+    self.0.1.0.push((name, acknowledge));
+    self
+  }
+}
+message_reply!{EnhancedRemoteCommand, EnhancedRemoteCommandAcknowledge}
+impl MultiBlockMessage for EnhancedRemoteCommand {
+  type Inquire = MultiBlockInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockInquire((self.0.0.clone(), length.into()))
+  }
+}
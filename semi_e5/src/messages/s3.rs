@@ -0,0 +1,567 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 3: MATERIAL STATUS
+//! **Based on SEMI E5§10.7**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with communicating information and actions related
+//! to material, including carriers and material-in-process,
+//! time-to-completion information, and extraordinary material circumstances.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! ## TO BE DONE
+//!
+//! - S3F1 through S3F10
+//! - S3F13 through S3F16
+//! - S3F19 through S3F26
+//! - S3F33 onward
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S3F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 3, 0}
+
+/// ## S3F11
+///
+/// **Material ID Request (MIDR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the [MID] of the material at the given port.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PTN]
+///
+/// [MID]: MaterialID
+/// [PTN]: PortNumber
+pub struct MaterialIDRequest(pub PortNumber);
+message_data!{MaterialIDRequest, true, 3, 11}
+
+/// ## S3F12
+///
+/// **Material ID Request Acknowledge (MIDRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The [MID] of the material at the requested port.
+///
+/// The [MID] is present if and only if the [MIDRA] is accepted, being
+/// zero-length otherwise, which is enforced on conversion from a [Message].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [PTN]
+///    2. [MIDRA]
+///    3. [MID]
+///
+/// [PTN]:     PortNumber
+/// [MIDRA]:   MaterialIDRequestAcknowledgeCode
+/// [MID]:     MaterialID
+/// [Message]: crate::Message
+pub struct MaterialIDRequestAcknowledge(pub (PortNumber, CodedValue<MaterialIDRequestAcknowledgeCode>, MaterialID));
+impl crate::messages::MessageHeader for MaterialIDRequestAcknowledge {
+  const STREAM:   u8   = 3;
+  const FUNCTION: u8   = 12;
+  const W:        bool = false;
+  const REPLY:    crate::messages::Reply = crate::messages::Reply::Forbidden;
+}
+impl From<MaterialIDRequestAcknowledge> for Message {
+  fn from(value: MaterialIDRequestAcknowledge) -> Self {
+    Message {
+      stream:   3,
+      function: 12,
+      w:        false,
+      text:     Some(value.0.into()),
+    }
+  }
+}
+impl TryFrom<Message> for MaterialIDRequestAcknowledge {
+  type Error = Error;
+
+  fn try_from(message: Message) -> Result<Self, Self::Error> {
+    if message.stream   != 3  {return Err(WrongStream)}
+    if message.function != 12 {return Err(WrongFunction)}
+    if message.w              {return Err(WrongReply)}
+    let (ptn, midra, mid): (PortNumber, CodedValue<MaterialIDRequestAcknowledgeCode>, MaterialID) = match message.text {
+      Some(item) => item.try_into()?,
+      None => return Err(WrongFormat),
+    };
+    let empty = match &mid {
+      MaterialID::Ascii(vec) => vec.is_empty(),
+      MaterialID::Bin(vec)   => vec.is_empty(),
+    };
+    if midra.is(MaterialIDRequestAcknowledgeCode::Accepted) == empty {
+      return Err(WrongFormat)
+    }
+    Ok(Self((ptn, midra, mid)))
+  }
+}
+message_reply!{MaterialIDRequest, MaterialIDRequestAcknowledge}
+
+/// ## S3F17
+///
+/// **Carrier Action Request (CAR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request for an action to be performed on a carrier, such as proceeding
+/// with a carrier whose ID and slot map have been verified, or moving a
+/// carrier out of the equipment.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [DATAID]
+///    2. [CARRIERACTION]
+///    3. [CARRIERID]
+///    4. [PTN]
+///    5. List - N
+///       - List - 2
+///          1. [CATTRID]
+///          2. [CATTRDATA]
+///
+/// N is the number of carrier attributes.
+///
+/// [DATAID]:        DataID
+/// [CARRIERACTION]: CarrierAction
+/// [CARRIERID]:     CarrierID
+/// [PTN]:           PortNumber
+/// [CATTRID]:       CarrierAttributeID
+/// [CATTRDATA]:     CarrierAttributeData
+pub struct CarrierActionRequest(pub (DataID, CarrierAction, CarrierID, PortNumber, VecList<(CarrierAttributeID, CarrierAttributeData)>));
+message_data!{CarrierActionRequest, true, 3, 17}
+
+/// ## S3F18
+///
+/// **Carrier Action Acknowledge (CAA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge or error.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [CAACK]
+///    2. List - S
+///       - List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// S is the number of errors.
+///
+/// [CAACK]:   CarrierActionAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct CarrierActionRequestAcknowledge(pub (CodedValue<CarrierActionAcknowledge>, VecList<(ErrorCode, ErrorText)>));
+message_data!{CarrierActionRequestAcknowledge, false, 3, 18}
+message_reply!{CarrierActionRequest, CarrierActionRequestAcknowledge}
+
+/// ## S3F27
+///
+/// **Change Access (CA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request to change the access mode of load ports.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [ACCESSMODE]
+///    2. List - N
+///       - [PTN]
+///
+/// N is the number of load ports.
+///
+/// Zero-length N means all load ports.
+///
+/// [ACCESSMODE]: AccessMode
+/// [PTN]:        PortNumber
+pub struct ChangeAccess(pub (AccessMode, VecList<PortNumber>));
+message_data!{ChangeAccess, true, 3, 27}
+
+/// ## S3F28
+///
+/// **Change Access Acknowledge (CAA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge or error.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [CAACK]
+///    2. List - S
+///       - List - 3
+///          1. [PTN]
+///          2. [ERRCODE]
+///          3. [ERRTEXT]
+///
+/// S is the number of load ports in error.
+///
+/// [CAACK]:   CarrierActionAcknowledge
+/// [PTN]:     PortNumber
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ChangeAccessAcknowledge(pub (CodedValue<CarrierActionAcknowledge>, VecList<(PortNumber, ErrorCode, ErrorText)>));
+message_data!{ChangeAccessAcknowledge, false, 3, 28}
+message_reply!{ChangeAccess, ChangeAccessAcknowledge}
+
+/// ## S3F29
+///
+/// **Carrier Tag Read Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that data be read from the tag of a carrier, identified by its
+/// location, by its object specifier, or by both.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Conditional Fields
+///
+/// At least one of [LOCID] and [CARRIERSPEC] must be provided, a field which
+/// is not provided being sent with zero length. Use [new] to construct the
+/// message with this rule enforced.
+///
+/// Messages received from the other party are not checked against this
+/// rule; the accessors of fields which were sent with zero length return
+/// [None].
+///
+/// [new]: Self::new
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [LOCID]
+///    2. [CARRIERSPEC]
+///    3. [DATASEG]
+///    4. [DATALENGTH]
+///
+/// [LOCID]:       LocationID
+/// [CARRIERSPEC]: CarrierSpecifier
+/// [DATASEG]:     DataSegment
+/// [DATALENGTH]:  DataLength
+pub struct CarrierTagReadRequest((LocationID, CarrierSpecifier, DataSegment, DataLength));
+message_data!{CarrierTagReadRequest, true, 3, 29}
+impl CarrierTagReadRequest {
+  /// ### NEW
+  ///
+  /// Constructs the message to read from the tag of a carrier.
+  ///
+  /// A [LOCID] or [CARRIERSPEC] which is not provided is sent with zero
+  /// length, and one which is provided with zero length is treated as not
+  /// provided.
+  ///
+  /// Fails with [MissingField] if neither is provided.
+  ///
+  /// [LOCID]:        LocationID
+  /// [CARRIERSPEC]:  CarrierSpecifier
+  /// [MissingField]: Error::MissingField
+  pub fn new(
+    location_id: Option<LocationID>,
+    carrier_specifier: Option<CarrierSpecifier>,
+    data_segment: DataSegment,
+    data_length: DataLength,
+  ) -> Result<Self, Error> {
+    let location_id = location_id.unwrap_or(LocationID(vec![]));
+    let carrier_specifier = carrier_specifier.unwrap_or(CarrierSpecifier(vec![]));
+    if location_id.0.is_empty() && carrier_specifier.0.is_empty() {
+      return Err(MissingField)
+    }
+    Ok(Self((location_id, carrier_specifier, data_segment, data_length)))
+  }
+
+  /// ### LOCATION ID
+  ///
+  /// Returns the [LOCID], if one was provided.
+  ///
+  /// [LOCID]: LocationID
+  pub fn location_id(&self) -> Option<&LocationID> {
+    Some(&self.0.0).filter(|id| !id.0.is_empty())
+  }
+
+  /// ### CARRIER SPECIFIER
+  ///
+  /// Returns the [CARRIERSPEC], if one was provided.
+  ///
+  /// [CARRIERSPEC]: CarrierSpecifier
+  pub fn carrier_specifier(&self) -> Option<&CarrierSpecifier> {
+    Some(&self.0.1).filter(|spec| !spec.0.is_empty())
+  }
+
+  /// ### DATA SEGMENT
+  pub fn data_segment(&self) -> &DataSegment {
+    &self.0.2
+  }
+
+  /// ### DATA LENGTH
+  pub fn data_length(&self) -> &DataLength {
+    &self.0.3
+  }
+}
+
+/// ## S3F30
+///
+/// **Carrier Tag Read Data**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Data read from the tag of a carrier, or the errors which prevented it
+/// from being read.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DATA]
+///    2. List - 2
+///       1. [CAACK]
+///       2. List - S
+///          - List - 2
+///             1. [ERRCODE]
+///             2. [ERRTEXT]
+///
+/// S is the number of errors.
+///
+/// [DATA]:    Data
+/// [CAACK]:   CarrierActionAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct CarrierTagReadData(pub (Data, (CodedValue<CarrierActionAcknowledge>, VecList<(ErrorCode, ErrorText)>)));
+message_data!{CarrierTagReadData, false, 3, 30}
+message_reply!{CarrierTagReadRequest, CarrierTagReadData}
+
+/// ## S3F31
+///
+/// **Carrier Tag Write Data Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY EXPECTED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Requests that data be written to the tag of a carrier, identified by its
+/// location, by its object specifier, or by both.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Conditional Fields
+///
+/// At least one of [LOCID] and [CARRIERSPEC] must be provided, a field which
+/// is not provided being sent with zero length. Use [new] to construct the
+/// message with this rule enforced.
+///
+/// Messages received from the other party are not checked against this
+/// rule; the accessors of fields which were sent with zero length return
+/// [None].
+///
+/// [new]: Self::new
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [LOCID]
+///    2. [CARRIERSPEC]
+///    3. [DATASEG]
+///    4. [DATALENGTH]
+///    5. [DATA]
+///
+/// [LOCID]:       LocationID
+/// [CARRIERSPEC]: CarrierSpecifier
+/// [DATASEG]:     DataSegment
+/// [DATALENGTH]:  DataLength
+/// [DATA]:        Data
+pub struct CarrierTagWriteDataRequest((LocationID, CarrierSpecifier, DataSegment, DataLength, Data));
+message_data!{CarrierTagWriteDataRequest, true, 3, 31}
+impl CarrierTagWriteDataRequest {
+  /// ### NEW
+  ///
+  /// Constructs the message to write the given data to the tag of a
+  /// carrier.
+  ///
+  /// A [LOCID] or [CARRIERSPEC] which is not provided is sent with zero
+  /// length, and one which is provided with zero length is treated as not
+  /// provided.
+  ///
+  /// Fails with [MissingField] if neither is provided.
+  ///
+  /// [LOCID]:        LocationID
+  /// [CARRIERSPEC]:  CarrierSpecifier
+  /// [MissingField]: Error::MissingField
+  pub fn new(
+    location_id: Option<LocationID>,
+    carrier_specifier: Option<CarrierSpecifier>,
+    data_segment: DataSegment,
+    data_length: DataLength,
+    data: Data,
+  ) -> Result<Self, Error> {
+    let location_id = location_id.unwrap_or(LocationID(vec![]));
+    let carrier_specifier = carrier_specifier.unwrap_or(CarrierSpecifier(vec![]));
+    if location_id.0.is_empty() && carrier_specifier.0.is_empty() {
+      return Err(MissingField)
+    }
+    Ok(Self((location_id, carrier_specifier, data_segment, data_length, data)))
+  }
+
+  /// ### LOCATION ID
+  ///
+  /// Returns the [LOCID], if one was provided.
+  ///
+  /// [LOCID]: LocationID
+  pub fn location_id(&self) -> Option<&LocationID> {
+    Some(&self.0.0).filter(|id| !id.0.is_empty())
+  }
+
+  /// ### CARRIER SPECIFIER
+  ///
+  /// Returns the [CARRIERSPEC], if one was provided.
+  ///
+  /// [CARRIERSPEC]: CarrierSpecifier
+  pub fn carrier_specifier(&self) -> Option<&CarrierSpecifier> {
+    Some(&self.0.1).filter(|spec| !spec.0.is_empty())
+  }
+
+  /// ### DATA SEGMENT
+  pub fn data_segment(&self) -> &DataSegment {
+    &self.0.2
+  }
+
+  /// ### DATA LENGTH
+  pub fn data_length(&self) -> &DataLength {
+    &self.0.3
+  }
+
+  /// ### DATA
+  pub fn data(&self) -> &Data {
+    &self.0.4
+  }
+}
+
+/// ## S3F32
+///
+/// **Carrier Tag Write Data Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledges the request to write to the tag of a carrier, including any
+/// errors encountered.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [CAACK]
+///    2. List - S
+///       - List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// S is the number of errors.
+///
+/// [CAACK]:   CarrierActionAcknowledge
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct CarrierTagWriteDataAcknowledge(pub (CodedValue<CarrierActionAcknowledge>, VecList<(ErrorCode, ErrorText)>));
+message_data!{CarrierTagWriteDataAcknowledge, false, 3, 32}
+message_reply!{CarrierTagWriteDataRequest, CarrierTagWriteDataAcknowledge}
@@ -131,8 +131,9 @@ message_data!{AlarmReportSend, true, 5, 1}
 /// - [ACKC5]
 ///
 /// [ACKC5]: AcknowledgeCode5
-pub struct AlarmReportAcknowledge(pub AcknowledgeCode5);
+pub struct AlarmReportAcknowledge(pub CodedValue<AcknowledgeCode5>);
 message_data!{AlarmReportAcknowledge, false, 5, 2}
+message_reply!{AlarmReportSend, AlarmReportAcknowledge}
 
 /// ## S5F3
 ///
@@ -177,16 +178,27 @@ message_data!{EnableDisableAlarmSend, true, 5, 3}
 ///
 /// - List - 2
 ///    1. [ALED]
-///    2. List - 0
+///    2. [ALID]
 ///
-/// Zero-length N means to enable/disable all alarms.
+/// A zero-length integer [ALID] means to enable/disable all alarms, and is
+/// the only form accepted.
 ///
 /// [ALED]: AlarmEnableDisable
-/// [ALID]: AlarmID
-///
-/// Note: User need to manually validate empty list, VecList<AlarmID> is a placeholder for now.
+/// [ALID]: AllAlarmID
 pub struct EnableDisableAllAlarmSend(pub (AlarmEnableDisable, AllAlarmID));
 message_data!{EnableDisableAllAlarmSend, true, 5, 3}
+impl EnableDisableAllAlarmSend {
+  /// ### NEW
+  ///
+  /// Constructs the message to enable or disable all alarms, sending the
+  /// [ALID] as a zero-length [U4].
+  ///
+  /// [ALID]: AllAlarmID
+  /// [U4]:   crate::Item::U4
+  pub fn new(aled: AlarmEnableDisable) -> Self {
+    Self((aled, AllAlarmID::ALL))
+  }
+}
 
 /// ## S5F4
 ///
@@ -207,8 +219,10 @@ message_data!{EnableDisableAllAlarmSend, true, 5, 3}
 /// - [ACKC5]
 ///
 /// [ACKC5]: AcknowledgeCode5
-pub struct EnableDisableAlarmAcknowledge(pub AcknowledgeCode5);
+pub struct EnableDisableAlarmAcknowledge(pub CodedValue<AcknowledgeCode5>);
 message_data!{EnableDisableAlarmAcknowledge, false, 5, 4}
+message_reply!{EnableDisableAlarmSend, EnableDisableAlarmAcknowledge}
+message_reply!{EnableDisableAllAlarmSend, EnableDisableAlarmAcknowledge}
 
 /// ## S5F5
 ///
@@ -266,6 +280,7 @@ message_data!{ListAlarmsRequest, true, 5, 5}
 /// [ALTX]: AlarmText
 pub struct ListAlarmsData(pub VecList<(AlarmCode, AlarmID, AlarmText)>);
 message_data!{ListAlarmsData, false, 5, 6}
+message_reply!{ListAlarmsRequest, ListAlarmsData}
 
 /// ## S5F7
 ///
@@ -316,3 +331,277 @@ message_headeronly!{ListEnabledAlarmsRequest, true, 5, 7}
 /// [ALTX]: AlarmText
 pub struct ListEnabledAlarmsData(pub VecList<(AlarmCode, AlarmID, AlarmText)>);
 message_data!{ListEnabledAlarmsData, false, 5, 8}
+message_reply!{ListEnabledAlarmsRequest, ListEnabledAlarmsData}
+
+/// ## S5F9
+///
+/// **Exception Post Notify**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notification that an exception has occurred, along with the recovery
+/// actions which the equipment is able to perform.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [TIMESTAMP]
+///    2. [EXID]
+///    3. [EXTYPE]
+///    4. [EXMESSAGE]
+///    5. List - N
+///       - [EXRECVRA]
+///
+/// N is the number of available recovery actions.
+///
+/// [TIMESTAMP]: TimeStamp
+/// [EXID]:      ExceptionID
+/// [EXTYPE]:    ExceptionType
+/// [EXMESSAGE]: ExceptionMessage
+/// [EXRECVRA]:  ExceptionRecoveryAction
+pub struct ExceptionPostNotify(pub (TimeStamp, ExceptionID, ExceptionType, ExceptionMessage, VecList<ExceptionRecoveryAction>));
+message_data!{ExceptionPostNotify, true, 5, 9}
+
+/// ## S5F10
+///
+/// **Exception Post Confirm**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Confirm receipt of an exception post.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ExceptionPostConfirm;
+message_headeronly!{ExceptionPostConfirm, false, 5, 10}
+message_reply!{ExceptionPostNotify, ExceptionPostConfirm}
+
+/// ## S5F11
+///
+/// **Exception Clear Notify**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notification that an exception has been cleared.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TIMESTAMP]
+///    2. [EXID]
+///    3. [EXTYPE]
+///    4. [EXMESSAGE]
+///
+/// [TIMESTAMP]: TimeStamp
+/// [EXID]:      ExceptionID
+/// [EXTYPE]:    ExceptionType
+/// [EXMESSAGE]: ExceptionMessage
+pub struct ExceptionClearNotify(pub (TimeStamp, ExceptionID, ExceptionType, ExceptionMessage));
+message_data!{ExceptionClearNotify, true, 5, 11}
+
+/// ## S5F12
+///
+/// **Exception Clear Confirm**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Confirm receipt of an exception clear.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ExceptionClearConfirm;
+message_headeronly!{ExceptionClearConfirm, false, 5, 12}
+message_reply!{ExceptionClearNotify, ExceptionClearConfirm}
+
+/// ## S5F13
+///
+/// **Exception Recover Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request that the equipment perform one of the recovery actions it
+/// provided for an exception.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [EXID]
+///    2. [EXRECVRA]
+///
+/// [EXID]:     ExceptionID
+/// [EXRECVRA]: ExceptionRecoveryAction
+pub struct ExceptionRecoverRequest(pub (ExceptionID, ExceptionRecoveryAction));
+message_data!{ExceptionRecoverRequest, true, 5, 13}
+
+/// ## S5F14
+///
+/// **Exception Recover Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge whether the requested recovery action has been started.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [EXID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// [EXID]:    ExceptionID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ExceptionRecoverAcknowledge(pub (ExceptionID, (AcknowledgeAny, (ErrorCode, ErrorText))));
+message_data!{ExceptionRecoverAcknowledge, false, 5, 14}
+message_reply!{ExceptionRecoverRequest, ExceptionRecoverAcknowledge}
+
+/// ## S5F15
+///
+/// **Exception Recovery Complete Notify**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notification that a recovery action has completed, successfully or
+/// otherwise.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TIMESTAMP]
+///    2. [EXID]
+///    3. List - 2
+///       1. [ACKA]
+///       2. List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// [TIMESTAMP]: TimeStamp
+/// [EXID]:      ExceptionID
+/// [ACKA]:      AcknowledgeAny
+/// [ERRCODE]:   ErrorCode
+/// [ERRTEXT]:   ErrorText
+pub struct ExceptionRecoveryCompleteNotify(pub (TimeStamp, ExceptionID, (AcknowledgeAny, (ErrorCode, ErrorText))));
+message_data!{ExceptionRecoveryCompleteNotify, true, 5, 15}
+
+/// ## S5F16
+///
+/// **Exception Recovery Complete Confirm**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Confirm receipt of a recovery completion.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ExceptionRecoveryCompleteConfirm;
+message_headeronly!{ExceptionRecoveryCompleteConfirm, false, 5, 16}
+message_reply!{ExceptionRecoveryCompleteNotify, ExceptionRecoveryCompleteConfirm}
+
+/// ## S5F17
+///
+/// **Exception Recovery Abort Request**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request that the equipment abort a recovery action in progress.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [EXID]
+///
+/// [EXID]: ExceptionID
+pub struct ExceptionRecoveryAbortRequest(pub ExceptionID);
+message_data!{ExceptionRecoveryAbortRequest, true, 5, 17}
+
+/// ## S5F18
+///
+/// **Exception Recovery Abort Acknowledge**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge whether the recovery action has been aborted.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [EXID]
+///    2. List - 2
+///       1. [ACKA]
+///       2. List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// [EXID]:    ExceptionID
+/// [ACKA]:    AcknowledgeAny
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct ExceptionRecoveryAbortAcknowledge(pub (ExceptionID, (AcknowledgeAny, (ErrorCode, ErrorText))));
+message_data!{ExceptionRecoveryAbortAcknowledge, false, 5, 18}
+message_reply!{ExceptionRecoveryAbortRequest, ExceptionRecoveryAbortAcknowledge}
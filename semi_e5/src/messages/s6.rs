@@ -33,6 +33,8 @@
 use crate::*;
 use crate::Error::*;
 use crate::items::*;
+use crate::messages::{InquireMessage, MultiBlockMessage};
+use crate::messages::wire;
 
 /// ## S6F0
 ///
@@ -54,6 +56,320 @@ use crate::items::*;
 pub struct Abort;
 message_headeronly!{Abort, false, 6, 0}
 
+/// ## S6F1
+///
+/// **Trace Data Send (TDS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY OPTIONAL**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sample of the status variables requested by a trace, sent at the
+/// trace's sampling period.
+///
+/// The second field is the [Reply Bit], set if a reply is requested.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [TRID]
+///    2. [SMPLN]
+///    3. [STIME]
+///    4. List - N
+///       - [SV]
+///
+/// N is the number of status variables in the trace.
+///
+/// [TRID]:      TraceRequestID
+/// [SMPLN]:     SampleNumber
+/// [STIME]:     SampleTime
+/// [SV]:        StatusVariableValue
+/// [Reply Bit]: crate::Message::w
+pub struct TraceDataSend(pub (TraceRequestID, SampleNumber, SampleTime, VecList<StatusVariableValue>), pub bool);
+message_data!{TraceDataSend, optional, 6, 1}
+
+/// ## S6F2
+///
+/// **Trace Data Acknowledge (TDA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge trace data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC6]
+///
+/// [ACKC6]: AcknowledgeCode6
+pub struct TraceDataAcknowledge(pub CodedValue<AcknowledgeCode6>);
+message_data!{TraceDataAcknowledge, false, 6, 2}
+message_reply!{TraceDataSend, TraceDataAcknowledge}
+
+/// ## S6F3
+///
+/// **Discrete Variable Data Send (DVS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Send data sets of named values associated with a collection event.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [CEID]
+///    3. List - N
+///       - List - 2
+///          1. [DSID]
+///          2. List - M
+///             - List - 2
+///                1. [DVNAME]
+///                2. [DVVAL]
+///
+/// N is the number of data sets.
+///
+/// M is the number of data values in a data set.
+///
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [DSID]:   DataSetID
+/// [DVNAME]: DataValueName
+/// [DVVAL]:  DataValue
+pub struct DiscreteVariableDataSend(pub (DataID, CollectionEventID, VecList<(DataSetID, VecList<(DataValueName, DataValue)>)>));
+message_data!{DiscreteVariableDataSend, true, 6, 3}
+
+/// ## S6F4
+///
+/// **Discrete Variable Data Acknowledge (DVA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge discrete variable data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC6]
+///
+/// [ACKC6]: AcknowledgeCode6
+pub struct DiscreteVariableDataAcknowledge(pub CodedValue<AcknowledgeCode6>);
+message_data!{DiscreteVariableDataAcknowledge, false, 6, 4}
+message_reply!{DiscreteVariableDataSend, DiscreteVariableDataAcknowledge}
+impl MultiBlockMessage for DiscreteVariableDataSend {
+  type Inquire = MultiBlockDataSendInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockDataSendInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S6F5
+///
+/// **Multi-Block Data Send Inquire (MBI)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request permission to send a multi-block message in Stream 6.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [DATAID]
+///    2. [DATALENGTH]
+///
+/// [DATAID]:     DataID
+/// [DATALENGTH]: DataLength
+pub struct MultiBlockDataSendInquire(pub (DataID, DataLength));
+message_data!{MultiBlockDataSendInquire, true, 6, 5}
+
+/// ## S6F6
+///
+/// **Multi-Block Grant (MBG)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant or deny permission to send a multi-block message in Stream 6.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [GRANT6]
+///
+/// [GRANT6]: Grant6
+pub struct MultiBlockGrant(pub CodedValue<Grant6>);
+message_data!{MultiBlockGrant, false, 6, 6}
+message_reply!{MultiBlockDataSendInquire, MultiBlockGrant}
+impl InquireMessage for MultiBlockDataSendInquire {
+  fn is_granted(reply: &Self::Reply) -> bool {
+    reply.0.is(Grant6::Granted)
+  }
+}
+
+/// ## S6F7
+///
+/// **Data Transfer Request (DDR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the data identified by a [DATAID], such as data which the host
+/// previously declined to receive.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DATAID]
+///
+/// [DATAID]: DataID
+pub struct DataTransferRequest(pub DataID);
+message_data!{DataTransferRequest, true, 6, 7}
+
+/// ## S6F8
+///
+/// **Data Transfer Data (DDD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Data requested by [S6F7].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [CEID]
+///    3. List - N
+///       - List - 2
+///          1. [DSID]
+///          2. List - M
+///             - List - 2
+///                1. [DVNAME]
+///                2. [DVVAL]
+///
+/// N is the number of data sets.
+///
+/// M is the number of data values in a data set.
+///
+/// [S6F7]:   DataTransferRequest
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [DSID]:   DataSetID
+/// [DVNAME]: DataValueName
+/// [DVVAL]:  DataValue
+pub struct DataTransferData(pub (DataID, CollectionEventID, VecList<(DataSetID, VecList<(DataValueName, DataValue)>)>));
+message_data!{DataTransferData, false, 6, 8}
+message_reply!{DataTransferRequest, DataTransferData}
+
+/// ## S6F9
+///
+/// **Formatted Variable Send (FVS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Send data sets in a predefined form, where values are identified by
+/// their position rather than by name.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [PFCD]
+///    2. [DATAID]
+///    3. [CEID]
+///    4. List - N
+///       - List - 2
+///          1. [DSID]
+///          2. List - M
+///             - [DVVAL]
+///
+/// N is the number of data sets.
+///
+/// M is the number of data values in a data set.
+///
+/// [PFCD]:   PredefinedFormCode
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [DSID]:   DataSetID
+/// [DVVAL]:  DataValue
+pub struct FormattedVariableSend(pub (PredefinedFormCode, DataID, CollectionEventID, VecList<(DataSetID, VecList<DataValue>)>));
+message_data!{FormattedVariableSend, true, 6, 9}
+
+/// ## S6F10
+///
+/// **Formatted Variable Acknowledge (FVA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge formatted variable data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC6]
+///
+/// [ACKC6]: AcknowledgeCode6
+pub struct FormattedVariableAcknowledge(pub CodedValue<AcknowledgeCode6>);
+message_data!{FormattedVariableAcknowledge, false, 6, 10}
+message_reply!{FormattedVariableSend, FormattedVariableAcknowledge}
+impl MultiBlockMessage for FormattedVariableSend {
+  type Inquire = MultiBlockDataSendInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockDataSendInquire((self.0.1.clone(), length.into()))
+  }
+}
+
 /// ## S6F11
 ///
 /// **Event Report**
@@ -86,9 +402,126 @@ message_headeronly!{Abort, false, 6, 0}
 /// [DATAID]: DataID
 /// [CEID]:   CollectionEventID
 /// [RPTID]:  ReportID
-/// [V]:      Item
-pub struct EventReport(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<Item>)>));
+/// [V]:      VariableValue
+pub struct EventReport(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<VariableValue>)>));
 message_data!{EventReport, true, 6, 11}
+impl EventReport {
+  /// ### DECODE
+  ///
+  /// Converts the message into its [Decoded Event Report].
+  ///
+  /// [Decoded Event Report]: DecodedEventReport
+  pub fn decode(self) -> DecodedEventReport {
+    self.into()
+  }
+
+  /// ### ACKNOWLEDGE
+  ///
+  /// Constructs the [Event Report Acknowledge] to be sent in reply to this
+  /// message with the given [ACKC6].
+  ///
+  /// [Event Report Acknowledge]: EventReportAcknowledge
+  /// [ACKC6]:                    AcknowledgeCode6
+  pub fn acknowledge(&self, code: AcknowledgeCode6) -> EventReportAcknowledge {
+    EventReportAcknowledge(CodedValue::Known(code))
+  }
+
+  /// ### TO BYTES
+  ///
+  /// Serializes the message's text directly into binary data, without first
+  /// constructing a tree of [Item]s.
+  ///
+  /// [Item]: crate::Item
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let (data_id, ceid, reports) = &self.0;
+    let mut vec: Vec<u8> = vec![];
+    wire::write_list(&mut vec, 3);
+    wire::write_item(&mut vec, data_id);
+    wire::write_item(&mut vec, ceid);
+    wire::write_list(&mut vec, reports.0.len());
+    for (rptid, values) in &reports.0 {
+      wire::write_list(&mut vec, 2);
+      wire::write_item(&mut vec, rptid);
+      wire::write_list(&mut vec, values.0.len());
+      for value in &values.0 {
+        wire::write_item(&mut vec, value);
+      }
+    }
+    vec
+  }
+}
+impl TryFrom<&[u8]> for EventReport {
+  type Error = Error;
+
+  /// ### BINARY DATA -> EVENT REPORT
+  ///
+  /// Deserializes the message's text directly from binary data, without
+  /// first constructing a tree of [Item]s.
+  ///
+  /// The default [Decode Limits] are enforced.
+  ///
+  /// [Item]:          crate::Item
+  /// [Decode Limits]: crate::DecodeLimits
+  fn try_from(text: &[u8]) -> Result<Self, Self::Error> {
+    let mut reader = wire::Reader::new(text, &DecodeLimits::default())?;
+    reader.list_of(3)?;
+    let data_id = reader.item()?;
+    let ceid = reader.item()?;
+    let reports = reader.vec(|reader| {
+      reader.list_of(2)?;
+      Ok((reader.item()?, VecList(reader.vec(wire::Reader::item)?)))
+    })?;
+    Ok(Self((data_id, ceid, VecList(reports))))
+  }
+}
+
+/// ## DECODED EVENT REPORT
+///
+/// The contents of an [Event Report] in structured form, with the values of
+/// each report's variables as generic [Item]s, in the order in which the
+/// variables were defined with [S2F33].
+///
+/// May be obtained from a [Message] with [TryFrom], such that a host can
+/// handle incoming event reports without unpacking the nested lists itself.
+///
+/// [Event Report]: EventReport
+/// [Item]:         crate::Item
+/// [Message]:      crate::Message
+/// [S2F33]:        crate::messages::s2::DefineReport
+#[derive(Clone, Debug)]
+pub struct DecodedEventReport {
+  /// ### DATA ID
+  pub data_id: DataID,
+
+  /// ### COLLECTION EVENT ID
+  pub ceid: CollectionEventID,
+
+  /// ### REPORTS
+  ///
+  /// Each report's [RPTID] and the values of its variables.
+  ///
+  /// [RPTID]: ReportID
+  pub reports: Vec<(ReportID, Vec<Item>)>,
+}
+impl From<EventReport> for DecodedEventReport {
+  fn from(value: EventReport) -> Self {
+    let (data_id, ceid, reports) = value.0;
+    Self {
+      data_id,
+      ceid,
+      reports: reports.0.into_iter().map(|(id, values)| {
+        (id, values.0.into_iter().map(Item::from).collect())
+      }).collect(),
+    }
+  }
+}
+impl TryFrom<Message> for DecodedEventReport {
+  type Error = Error;
+
+  fn try_from(value: Message) -> Result<Self, Self::Error> {
+    Ok(EventReport::try_from(value)?.into())
+  }
+}
 
 /// ## S6F12
 ///
@@ -109,8 +542,110 @@ message_data!{EventReport, true, 6, 11}
 /// - [ACKC6]
 ///
 /// [ACKC6]: AcknowledgeCode6
-pub struct EventReportAcknowledge(pub AcknowledgeCode6);
+pub struct EventReportAcknowledge(pub CodedValue<AcknowledgeCode6>);
 message_data!{EventReportAcknowledge, false, 6, 12}
+message_reply!{EventReport, EventReportAcknowledge}
+impl EventReportAcknowledge {
+  /// ### TO BYTES
+  ///
+  /// Serializes the message's text directly into binary data.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut vec: Vec<u8> = vec![];
+    wire::write_item(&mut vec, &self.0);
+    vec
+  }
+}
+impl TryFrom<&[u8]> for EventReportAcknowledge {
+  type Error = Error;
+
+  /// ### BINARY DATA -> EVENT REPORT ACKNOWLEDGE
+  ///
+  /// Deserializes the message's text directly from binary data.
+  ///
+  /// The default [Decode Limits] are enforced.
+  ///
+  /// [Decode Limits]: crate::DecodeLimits
+  fn try_from(text: &[u8]) -> Result<Self, Self::Error> {
+    Ok(Self(wire::Reader::new(text, &DecodeLimits::default())?.item()?))
+  }
+}
+impl MultiBlockMessage for EventReport {
+  type Inquire = MultiBlockDataSendInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockDataSendInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S6F13
+///
+/// **Annotated Event Report Send (AERS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Unsolicited event report from equipment, with each variable value
+/// accompanied by its variable ID.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [CEID]
+///    3. List - N
+///       - List - 2
+///          1. [RPTID]
+///          2. List - M
+///             - List - 2
+///                1. [VID]
+///                2. [V]
+///
+/// N is the number of reports.
+///
+/// M is the number of variables in a report.
+///
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [RPTID]:  ReportID
+/// [VID]:    VariableID
+/// [V]:      VariableValue
+pub struct AnnotatedEventReport(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<(VariableID, VariableValue)>)>));
+message_data!{AnnotatedEventReport, true, 6, 13}
+
+/// ## S6F14
+///
+/// **Annotated Event Report Acknowledge (AERA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge annotated event report.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC6]
+///
+/// [ACKC6]: AcknowledgeCode6
+pub struct AnnotatedEventReportAcknowledge(pub CodedValue<AcknowledgeCode6>);
+message_data!{AnnotatedEventReportAcknowledge, false, 6, 14}
+message_reply!{AnnotatedEventReport, AnnotatedEventReportAcknowledge}
+impl MultiBlockMessage for AnnotatedEventReport {
+  type Inquire = MultiBlockDataSendInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockDataSendInquire((self.0.0.clone(), length.into()))
+  }
+}
 
 /// ## S6F15
 ///
@@ -166,6 +701,403 @@ message_data!{EventReportRequest, true, 6, 15}
 /// [DATAID]: DataID
 /// [CEID]:   CollectionEventID
 /// [RPTID]:  ReportID
-/// [V]:      Item
-pub struct EventReportData(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<Item>)>));
+/// [V]:      VariableValue
+pub struct EventReportData(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<VariableValue>)>));
 message_data!{EventReportData, false, 6, 16}
+message_reply!{EventReportRequest, EventReportData}
+
+/// ## S6F17
+///
+/// **Annotated Event Report Request (AERR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request to send the annotated data associated with a specific
+/// collection event.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [CEID]
+///
+/// [CEID]: CollectionEventID
+pub struct AnnotatedEventReportRequest(pub CollectionEventID);
+message_data!{AnnotatedEventReportRequest, true, 6, 17}
+
+/// ## S6F18
+///
+/// **Annotated Event Report Data (AERD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Annotated event report data in response to [S6F17] request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [CEID]
+///    3. List - N
+///       - List - 2
+///          1. [RPTID]
+///          2. List - M
+///             - List - 2
+///                1. [VID]
+///                2. [V]
+///
+/// N is the number of reports.
+///
+/// M is the number of variables in a report.
+///
+/// [S6F17]:  AnnotatedEventReportRequest
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [RPTID]:  ReportID
+/// [VID]:    VariableID
+/// [V]:      VariableValue
+pub struct AnnotatedEventReportData(pub (DataID, CollectionEventID, VecList<(ReportID, VecList<(VariableID, VariableValue)>)>));
+message_data!{AnnotatedEventReportData, false, 6, 18}
+message_reply!{AnnotatedEventReportRequest, AnnotatedEventReportData}
+
+/// ## S6F19
+///
+/// **Individual Report Request (IRR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the current values of the variables in a specific report.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RPTID]
+///
+/// [RPTID]: ReportID
+pub struct IndividualReportRequest(pub ReportID);
+message_data!{IndividualReportRequest, true, 6, 19}
+
+/// ## S6F20
+///
+/// **Individual Report Data (IRD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Report data in response to [S6F19] request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [V]
+///
+/// N is the number of variables in the report.
+///
+/// [S6F19]: IndividualReportRequest
+/// [V]:     VariableValue
+pub struct IndividualReportData(pub VecList<VariableValue>);
+message_data!{IndividualReportData, false, 6, 20}
+message_reply!{IndividualReportRequest, IndividualReportData}
+
+/// ## S6F21
+///
+/// **Annotated Individual Report Request (AIRR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the current values of the variables in a specific report,
+/// each accompanied by its variable ID.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RPTID]
+///
+/// [RPTID]: ReportID
+pub struct AnnotatedIndividualReportRequest(pub ReportID);
+message_data!{AnnotatedIndividualReportRequest, true, 6, 21}
+
+/// ## S6F22
+///
+/// **Annotated Individual Report Data (AIRD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Annotated report data in response to [S6F21] request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - List - 2
+///       1. [VID]
+///       2. [V]
+///
+/// N is the number of variables in the report.
+///
+/// [S6F21]: AnnotatedIndividualReportRequest
+/// [VID]:   VariableID
+/// [V]:     VariableValue
+pub struct AnnotatedIndividualReportData(pub VecList<(VariableID, VariableValue)>);
+message_data!{AnnotatedIndividualReportData, false, 6, 22}
+message_reply!{AnnotatedIndividualReportRequest, AnnotatedIndividualReportData}
+
+/// ## S6F23
+///
+/// **Request Spooled Data (RSD)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request that the equipment either transmit or purge the messages it
+/// has spooled.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RSDC]
+///
+/// [RSDC]: RequestSpoolDataCode
+pub struct RequestSpooledData(pub RequestSpoolDataCode);
+message_data!{RequestSpooledData, true, 6, 23}
+
+/// ## S6F24
+///
+/// **Request Spooled Data Acknowledgement Send (RSDAS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge request for spooled data.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [RSDA]
+///
+/// [RSDA]: RequestSpoolDataAcknowledge
+pub struct RequestSpooledDataAcknowledge(pub CodedValue<RequestSpoolDataAcknowledge>);
+message_data!{RequestSpooledDataAcknowledge, false, 6, 24}
+message_reply!{RequestSpooledData, RequestSpooledDataAcknowledge}
+
+/// ## S6F25
+///
+/// **Notification Report Send (NRS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Notification of the completion of a previously requested operation,
+/// along with any errors encountered.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [DATAID]
+///    2. [OPID]
+///    3. [LINKID]
+///    4. List - N
+///       - List - 2
+///          1. [ERRCODE]
+///          2. [ERRTEXT]
+///
+/// N is the number of errors.
+///
+/// [DATAID]:  DataID
+/// [OPID]:    OperationID
+/// [LINKID]:  LinkID
+/// [ERRCODE]: ErrorCode
+/// [ERRTEXT]: ErrorText
+pub struct NotificationReportSend(pub (DataID, OperationID, LinkID, VecList<(ErrorCode, ErrorText)>));
+message_data!{NotificationReportSend, true, 6, 25}
+
+/// ## S6F26
+///
+/// **Notification Report Send Acknowledge (NRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a notification report.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct NotificationReportSendAcknowledge;
+message_headeronly!{NotificationReportSendAcknowledge, false, 6, 26}
+message_reply!{NotificationReportSend, NotificationReportSendAcknowledge}
+
+/// ## S6F27
+///
+/// **Trace Report Send (TRS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Sample of the reports requested by a trace, sent at the trace's
+/// sampling period.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [DATAID]
+///    2. [TRID]
+///    3. List - N
+///       - List - 2
+///          1. [RPTID]
+///          2. List - M
+///             - [V]
+///
+/// N is the number of reports.
+///
+/// M is the number of variables in a report.
+///
+/// [DATAID]: DataID
+/// [TRID]:   TraceRequestID
+/// [RPTID]:  ReportID
+/// [V]:      VariableValue
+pub struct TraceReportSend(pub (DataID, TraceRequestID, VecList<(ReportID, VecList<VariableValue>)>));
+message_data!{TraceReportSend, true, 6, 27}
+
+/// ## S6F28
+///
+/// **Trace Report Send Acknowledge (TRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a trace report.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [TRID]
+///
+/// [TRID]: TraceRequestID
+pub struct TraceReportSendAcknowledge(pub TraceRequestID);
+message_data!{TraceReportSendAcknowledge, false, 6, 28}
+message_reply!{TraceReportSend, TraceReportSendAcknowledge}
+impl MultiBlockMessage for TraceReportSend {
+  type Inquire = MultiBlockDataSendInquire;
+
+  fn inquire(&self, length: usize) -> Self::Inquire {
+    MultiBlockDataSendInquire((self.0.0.clone(), length.into()))
+  }
+}
+
+/// ## S6F29
+///
+/// **Trace Report Request (TRR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the current sample of the reports requested by a trace.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [TRID]
+///
+/// [TRID]: TraceRequestID
+pub struct TraceReportRequest(pub TraceRequestID);
+message_data!{TraceReportRequest, true, 6, 29}
+
+/// ## S6F30
+///
+/// **Trace Report Data (TRD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Trace report data in response to [S6F29] request.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [TRID]
+///    2. List - N
+///       - List - 2
+///          1. [RPTID]
+///          2. List - M
+///             - [V]
+///    3. [ERRCODE]
+///
+/// N is the number of reports.
+///
+/// M is the number of variables in a report.
+///
+/// [S6F29]:   TraceReportRequest
+/// [TRID]:    TraceRequestID
+/// [RPTID]:   ReportID
+/// [V]:       VariableValue
+/// [ERRCODE]: ErrorCode
+pub struct TraceReportData(pub (TraceRequestID, VecList<(ReportID, VecList<VariableValue>)>, ErrorCode));
+message_data!{TraceReportData, false, 6, 30}
+message_reply!{TraceReportRequest, TraceReportData}
@@ -0,0 +1,1014 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 7: PROCESS PROGRAM MANAGEMENT
+//! **Based on SEMI E5§10.11**
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]s which deal with the management and transfer of Process Programs.
+//!
+//! Process Programs are the equipment-specific descriptions that determine
+//! the procedure to be conducted on the material by a single piece of
+//! equipment.
+//!
+//! Methods are provided to transfer programs as well as establish the link
+//! between the process program and the material to be processed with that
+//! program.
+//!
+//! ---------------------------------------------------------------------------
+//!
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S7F0
+///
+/// **Abort Transaction**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Used in lieu of an expected reply to abort a transaction.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 7, 0}
+
+/// ## S7F1
+///
+/// **Process Program Load Inquire (PPI)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request permission to send a process program which would exceed a
+/// single block.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PPID]
+///    2. [LENGTH]
+///
+/// [PPID]:   ProcessProgramID
+/// [LENGTH]: Length
+pub struct ProcessProgramLoadInquire(pub (ProcessProgramID, Length));
+message_data!{ProcessProgramLoadInquire, true, 7, 1}
+
+/// ## S7F2
+///
+/// **Process Program Load Grant (PPG)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant or deny permission to send a process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PPGNT]
+///
+/// [PPGNT]: ProcessProgramGrant
+pub struct ProcessProgramLoadGrant(pub CodedValue<ProcessProgramGrant>);
+message_data!{ProcessProgramLoadGrant, false, 7, 2}
+message_reply!{ProcessProgramLoadInquire, ProcessProgramLoadGrant}
+
+/// ## S7F3
+///
+/// **Process Program Send (PPS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Send an unformatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PPID]
+///    2. [PPBODY]
+///
+/// [PPID]:   ProcessProgramID
+/// [PPBODY]: ProcessProgramBody
+pub struct ProcessProgramSend(pub (ProcessProgramID, ProcessProgramBody));
+message_data!{ProcessProgramSend, true, 7, 3}
+
+/// ## S7F4
+///
+/// **Process Program Acknowledge (PPA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct ProcessProgramAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{ProcessProgramAcknowledge, false, 7, 4}
+message_reply!{ProcessProgramSend, ProcessProgramAcknowledge}
+
+/// ## S7F5
+///
+/// **Process Program Request (PPR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the transfer of an unformatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PPID]
+///
+/// [PPID]: ProcessProgramID
+pub struct ProcessProgramRequest(pub ProcessProgramID);
+message_data!{ProcessProgramRequest, true, 7, 5}
+
+/// ## S7F6
+///
+/// **Process Program Data (PPD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Unformatted process program requested by [S7F5].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 0 or 2
+///    1. [PPID]
+///    2. [PPBODY]
+///
+/// Zero-length list means the request was denied.
+///
+/// [S7F5]:   ProcessProgramRequest
+/// [PPID]:   ProcessProgramID
+/// [PPBODY]: ProcessProgramBody
+pub struct ProcessProgramData(pub OptionItem<(ProcessProgramID, ProcessProgramBody)>);
+message_data!{ProcessProgramData, false, 7, 6}
+message_reply!{ProcessProgramRequest, ProcessProgramData}
+
+/// ## S7F17
+///
+/// **Delete Process Program Send (DPS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request to delete process programs from the equipment.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [PPID]
+///
+/// N is the number of process programs to delete.
+///
+/// Zero-length N means to delete all process programs.
+///
+/// [PPID]: ProcessProgramID
+pub struct DeleteProcessProgramSend(pub VecList<ProcessProgramID>);
+message_data!{DeleteProcessProgramSend, true, 7, 17}
+
+/// ## S7F18
+///
+/// **Delete Process Program Acknowledge (DPA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge deletion of process programs.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct DeleteProcessProgramAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{DeleteProcessProgramAcknowledge, false, 7, 18}
+message_reply!{DeleteProcessProgramSend, DeleteProcessProgramAcknowledge}
+
+/// ## S7F19
+///
+/// **Current Equipment Process Program Directory Request (RER)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the list of process programs stored on the equipment.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct CurrentProcessProgramDirectoryRequest;
+message_headeronly!{CurrentProcessProgramDirectoryRequest, true, 7, 19}
+
+/// ## S7F20
+///
+/// **Current Equipment Process Program Directory Data (RED)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// List of process programs stored on the equipment.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - N
+///    - [PPID]
+///
+/// N is the number of process programs.
+///
+/// [PPID]: ProcessProgramID
+pub struct CurrentProcessProgramDirectoryData(pub VecList<ProcessProgramID>);
+message_data!{CurrentProcessProgramDirectoryData, false, 7, 20}
+message_reply!{CurrentProcessProgramDirectoryRequest, CurrentProcessProgramDirectoryData}
+
+/// ## S7F21
+///
+/// **Equipment Process Capabilities Request (PCR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the commands and parameters from which the equipment's formatted
+/// process programs may be constructed.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ProcessCapabilitiesRequest;
+message_headeronly!{ProcessCapabilitiesRequest, true, 7, 21}
+
+/// ## S7F22
+///
+/// **Equipment Process Capabilities Data (PCD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The commands and parameters from which the equipment's formatted process
+/// programs may be constructed, along with the rules governing the order of
+/// the commands.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 5
+///    1. [MDLN]
+///    2. [SOFTREV]
+///    3. [CMDMAX]
+///    4. [BYTMAX]
+///    5. List - C
+///       - List - 11
+///          1. [CCODE]
+///          2. [CNAME]
+///          3. [RQCMD]
+///          4. [BLKDEF]
+///          5. [BCDS]
+///          6. [IBCDS]
+///          7. [NBCDS]
+///          8. [ACDS]
+///          9. [IACDS]
+///          10. [NACDS]
+///          11. List - P
+///              - List - 6
+///                 1. [PNAME]
+///                 2. [RQPAR]
+///                 3. [PNDESC]
+///                 4. [UNITS]
+///                 5. [PDFLT]
+///                 6. [PMAX]
+///
+/// C is the number of commands.
+///
+/// P is the number of parameters of a command.
+///
+/// [MDLN]:    ModelName
+/// [SOFTREV]: SoftwareRevision
+/// [CMDMAX]:  CommandMaximum
+/// [BYTMAX]:  ByteMaximum
+/// [CCODE]:   CommandCode
+/// [CNAME]:   CommandName
+/// [RQCMD]:   RequiredCommand
+/// [BLKDEF]:  BlockDefinition
+/// [BCDS]:    BeforeCommandCodes
+/// [IBCDS]:   IndependentBeforeCommandCodes
+/// [NBCDS]:   NotBeforeCommandCodes
+/// [ACDS]:    AfterCommandCodes
+/// [IACDS]:   IndependentAfterCommandCodes
+/// [NACDS]:   NotAfterCommandCodes
+/// [PNAME]:   ParameterName
+/// [RQPAR]:   RequiredParameter
+/// [PNDESC]:  ParameterDescription
+/// [UNITS]:   Units
+/// [PDFLT]:   ParameterDefault
+/// [PMAX]:    ParameterMaximum
+pub struct ProcessCapabilitiesData(pub (
+  ModelName,
+  SoftwareRevision,
+  CommandMaximum,
+  ByteMaximum,
+  VecList<(
+    CommandCode,
+    CommandName,
+    RequiredCommand,
+    BlockDefinition,
+    BeforeCommandCodes,
+    IndependentBeforeCommandCodes,
+    NotBeforeCommandCodes,
+    AfterCommandCodes,
+    IndependentAfterCommandCodes,
+    NotAfterCommandCodes,
+    VecList<(ParameterName, RequiredParameter, ParameterDescription, Units, ParameterDefault, ParameterMaximum)>,
+  )>,
+));
+message_data!{ProcessCapabilitiesData, false, 7, 22}
+message_reply!{ProcessCapabilitiesRequest, ProcessCapabilitiesData}
+
+/// ## S7F23
+///
+/// **Formatted Process Program Send (FPS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Send a formatted process program, as a sequence of command codes each
+/// with its parameters.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [PPID]
+///    2. [MDLN]
+///    3. [SOFTREV]
+///    4. List - N
+///       - List - 2
+///          1. [CCODE]
+///          2. List - M
+///             - [PPARM]
+///
+/// N is the number of process commands.
+///
+/// M is the number of parameters of a process command.
+///
+/// [PPID]:    ProcessProgramID
+/// [MDLN]:    ModelName
+/// [SOFTREV]: SoftwareRevision
+/// [CCODE]:   CommandCode
+/// [PPARM]:   ProcessParameter
+pub struct FormattedProcessProgramSend(pub (ProcessProgramID, ModelName, SoftwareRevision, VecList<(CommandCode, VecList<ProcessParameter>)>));
+message_data!{FormattedProcessProgramSend, true, 7, 23}
+
+/// ## S7F24
+///
+/// **Formatted Process Program Acknowledge (FPA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a formatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct FormattedProcessProgramAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{FormattedProcessProgramAcknowledge, false, 7, 24}
+message_reply!{FormattedProcessProgramSend, FormattedProcessProgramAcknowledge}
+
+/// ## S7F25
+///
+/// **Formatted Process Program Request (FPR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the transfer of a formatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PPID]
+///
+/// [PPID]: ProcessProgramID
+pub struct FormattedProcessProgramRequest(pub ProcessProgramID);
+message_data!{FormattedProcessProgramRequest, true, 7, 25}
+
+/// ## S7F26
+///
+/// **Formatted Process Program Data (FPD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Formatted process program requested by [S7F25].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [PPID]
+///    2. [MDLN]
+///    3. [SOFTREV]
+///    4. List - N
+///       - List - 2
+///          1. [CCODE]
+///          2. List - M
+///             - [PPARM]
+///
+/// N is the number of process commands.
+///
+/// M is the number of parameters of a process command.
+///
+/// Zero-length list means the request was denied.
+///
+/// [S7F25]:   FormattedProcessProgramRequest
+/// [PPID]:    ProcessProgramID
+/// [MDLN]:    ModelName
+/// [SOFTREV]: SoftwareRevision
+/// [CCODE]:   CommandCode
+/// [PPARM]:   ProcessParameter
+pub struct FormattedProcessProgramData(pub OptionItem<(ProcessProgramID, ModelName, SoftwareRevision, VecList<(CommandCode, VecList<ProcessParameter>)>)>);
+message_data!{FormattedProcessProgramData, false, 7, 26}
+message_reply!{FormattedProcessProgramRequest, FormattedProcessProgramData}
+
+/// ## S7F27
+///
+/// **Process Program Verification Send (PVS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Report the result of verifying a formatted process program, listing
+/// each error found.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PPID]
+///    2. List - N
+///       - List - 3
+///          1. [ACKC7A]
+///          2. [SEQNUM]
+///          3. [ERRW7]
+///
+/// N is the number of errors found.
+///
+/// [PPID]:   ProcessProgramID
+/// [ACKC7A]: AcknowledgeCode7A
+/// [SEQNUM]: SequenceNumber
+/// [ERRW7]:  ProcessProgramErrorText
+pub struct ProcessProgramVerificationSend(pub (ProcessProgramID, VecList<(CodedValue<AcknowledgeCode7A>, SequenceNumber, ProcessProgramErrorText)>));
+message_data!{ProcessProgramVerificationSend, true, 7, 27}
+
+/// ## S7F28
+///
+/// **Process Program Verification Acknowledge (PVA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a process program verification.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// Header only.
+pub struct ProcessProgramVerificationAcknowledge;
+message_headeronly!{ProcessProgramVerificationAcknowledge, false, 7, 28}
+message_reply!{ProcessProgramVerificationSend, ProcessProgramVerificationAcknowledge}
+
+/// ## S7F29
+///
+/// **Process Program Verification Inquire (PVI)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request permission to send a process program verification which would
+/// exceed a single block.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 2
+///    1. [PPID]
+///    2. [LENGTH]
+///
+/// [PPID]:   ProcessProgramID
+/// [LENGTH]: Length
+pub struct ProcessProgramVerificationInquire(pub (ProcessProgramID, Length));
+message_data!{ProcessProgramVerificationInquire, true, 7, 29}
+
+/// ## S7F30
+///
+/// **Process Program Verification Grant (PVG)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Grant or deny permission to send a process program verification.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PPGNT]
+///
+/// [PPGNT]: ProcessProgramGrant
+pub struct ProcessProgramVerificationGrant(pub CodedValue<ProcessProgramGrant>);
+message_data!{ProcessProgramVerificationGrant, false, 7, 30}
+message_reply!{ProcessProgramVerificationInquire, ProcessProgramVerificationGrant}
+
+/// ## S7F31
+///
+/// **Verification Request Send (VRS)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Send a formatted process program to the equipment to be verified but
+/// not stored.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 4
+///    1. [PPID]
+///    2. [MDLN]
+///    3. [SOFTREV]
+///    4. List - N
+///       - List - 2
+///          1. [CCODE]
+///          2. List - M
+///             - [PPARM]
+///
+/// N is the number of process commands.
+///
+/// M is the number of parameters of a process command.
+///
+/// [PPID]:    ProcessProgramID
+/// [MDLN]:    ModelName
+/// [SOFTREV]: SoftwareRevision
+/// [CCODE]:   CommandCode
+/// [PPARM]:   ProcessParameter
+pub struct VerificationRequestSend(pub (ProcessProgramID, ModelName, SoftwareRevision, VecList<(CommandCode, VecList<ProcessParameter>)>));
+message_data!{VerificationRequestSend, true, 7, 31}
+
+/// ## S7F32
+///
+/// **Verification Request Acknowledge (VRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge receipt of a process program to be verified. The result of
+/// the verification is sent later by [S7F27].
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [S7F27]: ProcessProgramVerificationSend
+/// [ACKC7]: AcknowledgeCode7
+pub struct VerificationRequestAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{VerificationRequestAcknowledge, false, 7, 32}
+message_reply!{VerificationRequestSend, VerificationRequestAcknowledge}
+
+/// ## S7F33
+///
+/// **Process Program Available Request (PAR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the lengths of the forms in which a process program is
+/// available.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [PPID]
+///
+/// [PPID]: ProcessProgramID
+pub struct ProcessProgramAvailableRequest(pub ProcessProgramID);
+message_data!{ProcessProgramAvailableRequest, true, 7, 33}
+
+/// ## S7F34
+///
+/// **Process Program Availability Data (PAD)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Lengths of the unformatted and formatted forms of the process program
+/// requested by [S7F33], where a zero length means the form is not
+/// available.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [PPID]
+///    2. [UNFLEN]
+///    3. [FRMLEN]
+///
+/// [S7F33]:  ProcessProgramAvailableRequest
+/// [PPID]:   ProcessProgramID
+/// [UNFLEN]: UnformattedLength
+/// [FRMLEN]: FormattedLength
+pub struct ProcessProgramAvailabilityData(pub (ProcessProgramID, UnformattedLength, FormattedLength));
+message_data!{ProcessProgramAvailabilityData, false, 7, 34}
+message_reply!{ProcessProgramAvailableRequest, ProcessProgramAvailabilityData}
+
+/// ## S7F35
+///
+/// **Process Program for MID Request (PPMR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request the process program to be used for the given material.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [MID]
+///
+/// [MID]: MaterialID
+pub struct ProcessProgramForMaterialRequest(pub MaterialID);
+message_data!{ProcessProgramForMaterialRequest, true, 7, 35}
+
+/// ## S7F36
+///
+/// **Process Program for MID Data (PPMD)**
+///
+/// - **MULTI-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// The process program to be used for the given material.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - List - 3
+///    1. [MID]
+///    2. [PPID]
+///    3. [PPBODY]
+///
+/// [MID]:    MaterialID
+/// [PPID]:   ProcessProgramID
+/// [PPBODY]: ProcessProgramBody
+pub struct ProcessProgramForMaterialData(pub (MaterialID, ProcessProgramID, ProcessProgramBody));
+message_data!{ProcessProgramForMaterialData, false, 7, 36}
+message_reply!{ProcessProgramForMaterialRequest, ProcessProgramForMaterialData}
+
+/// ## S7F37
+///
+/// **Large Process Program Send (LPPS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Initiate the transfer of a process program too large to be sent with
+/// [S7F3], which is then sent with [Stream 13] as the named data set.
+///
+/// [S7F3]:      ProcessProgramSend
+/// [Stream 13]: crate::messages::s13
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DSNAME]
+///
+/// [DSNAME]: DataSetName
+pub struct LargeProcessProgramSend(pub DataSetName);
+message_data!{LargeProcessProgramSend, true, 7, 37}
+
+/// ## S7F38
+///
+/// **Large Process Program Acknowledge (LPPA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request to send a large process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct LargeProcessProgramAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{LargeProcessProgramAcknowledge, false, 7, 38}
+message_reply!{LargeProcessProgramSend, LargeProcessProgramAcknowledge}
+
+/// ## S7F39
+///
+/// **Large Formatted Process Program Send (LFPS)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Initiate the transfer of a formatted process program too large to be sent
+/// with [S7F23], which is then sent with [Stream 13] as the named data set,
+/// holding the text of an [S7F23].
+///
+/// [S7F23]:     FormattedProcessProgramSend
+/// [Stream 13]: crate::messages::s13
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DSNAME]
+///
+/// [DSNAME]: DataSetName
+pub struct LargeFormattedProcessProgramSend(pub DataSetName);
+message_data!{LargeFormattedProcessProgramSend, true, 7, 39}
+
+/// ## S7F40
+///
+/// **Large Formatted Process Program Acknowledge (LFPA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request to send a large formatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct LargeFormattedProcessProgramAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{LargeFormattedProcessProgramAcknowledge, false, 7, 40}
+message_reply!{LargeFormattedProcessProgramSend, LargeFormattedProcessProgramAcknowledge}
+
+/// ## S7F41
+///
+/// **Large Process Program Request (LPPR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request a process program too large to be sent with [S7F6], which is then
+/// sent with [Stream 13] as the named data set.
+///
+/// [S7F6]:      ProcessProgramData
+/// [Stream 13]: crate::messages::s13
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DSNAME]
+///
+/// [DSNAME]: DataSetName
+pub struct LargeProcessProgramRequest(pub DataSetName);
+message_data!{LargeProcessProgramRequest, true, 7, 41}
+
+/// ## S7F42
+///
+/// **Large Process Program Request Acknowledge (LPRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request for a large process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct LargeProcessProgramRequestAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{LargeProcessProgramRequestAcknowledge, false, 7, 42}
+message_reply!{LargeProcessProgramRequest, LargeProcessProgramRequestAcknowledge}
+
+/// ## S7F43
+///
+/// **Large Formatted Process Program Request (LFPR)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Request a formatted process program too large to be sent with [S7F26],
+/// which is then sent with [Stream 13] as the named data set, holding the
+/// text of an [S7F23].
+///
+/// [S7F23]:     FormattedProcessProgramSend
+/// [S7F26]:     FormattedProcessProgramData
+/// [Stream 13]: crate::messages::s13
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [DSNAME]
+///
+/// [DSNAME]: DataSetName
+pub struct LargeFormattedProcessProgramRequest(pub DataSetName);
+message_data!{LargeFormattedProcessProgramRequest, true, 7, 43}
+
+/// ## S7F44
+///
+/// **Large Formatted Process Program Request Acknowledge (LFRA)**
+///
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+///
+/// ---------------------------------------------------------------------------
+///
+/// Acknowledge the request for a large formatted process program.
+///
+/// ---------------------------------------------------------------------------
+///
+/// #### Structure
+///
+/// - [ACKC7]
+///
+/// [ACKC7]: AcknowledgeCode7
+pub struct LargeFormattedProcessProgramRequestAcknowledge(pub CodedValue<AcknowledgeCode7>);
+message_data!{LargeFormattedProcessProgramRequestAcknowledge, false, 7, 44}
+message_reply!{LargeFormattedProcessProgramRequest, LargeFormattedProcessProgramRequestAcknowledge}
@@ -0,0 +1,239 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 9: SYSTEM ERRORS
+//! **Based on SEMI E5§10.13**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with informing the host of communication errors,
+//! particularly that a message block has been received which cannot be
+//! handled or that a timeout on a transaction reception timer has occurred.
+//! 
+//! The messages indicate either a Message Fault or a Communications Fault
+//! has occurred but do not indicate a Communications Failure has occurred.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Error Message] function selects the appropriate [Message] to send
+//! when an inbound [Message] cannot be converted.
+//! 
+//! [Message]:       crate::Message
+//! [Error Message]: error_message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## ERROR MESSAGE
+/// 
+/// Builds the [Message] reporting that an inbound [Message] with the
+/// provided header could not be converted, given the resulting [Error]:
+/// 
+/// - [Wrong Stream] - [S9F3]
+/// - [Wrong Function] - [S9F5]
+/// - [Limit Exceeded] - [S9F11]
+/// - Otherwise - [S9F7]
+/// 
+/// An unrecognized Device ID is not detected during conversion, and should
+/// instead be reported directly with [S9F1].
+/// 
+/// [Message]:        crate::Message
+/// [Error]:          crate::Error
+/// [Wrong Stream]:   crate::Error::WrongStream
+/// [Wrong Function]: crate::Error::WrongFunction
+/// [Limit Exceeded]: crate::Error::LimitExceeded
+/// [S9F1]:           UnrecognizedDeviceID
+/// [S9F3]:           UnrecognizedStreamType
+/// [S9F5]:           UnrecognizedFunctionType
+/// [S9F7]:           IllegalData
+/// [S9F11]:          DataTooLong
+pub fn error_message(header: [u8; 10], error: Error) -> Message {
+  let mhead = MessageBlockHeader::from(header);
+  match error {
+    WrongStream   => UnrecognizedStreamType(mhead).into(),
+    WrongFunction => UnrecognizedFunctionType(mhead).into(),
+    LimitExceeded => DataTooLong(mhead).into(),
+    _             => IllegalData(mhead).into(),
+  }
+}
+
+/// ## S9F1
+/// 
+/// **Unrecognized Device ID (UDN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The Device ID in the message block header did not correspond to any
+/// known device ID.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageBlockHeader
+pub struct UnrecognizedDeviceID(pub MessageBlockHeader);
+message_data!{UnrecognizedDeviceID, false, 9, 1}
+
+/// ## S9F3
+/// 
+/// **Unrecognized Stream Type (USN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Equipment does not recognize the Stream type in the message block header.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageBlockHeader
+pub struct UnrecognizedStreamType(pub MessageBlockHeader);
+message_data!{UnrecognizedStreamType, false, 9, 3}
+
+/// ## S9F5
+/// 
+/// **Unrecognized Function Type (UFN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The Function in the message ID is not recognized by the receiver.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageBlockHeader
+pub struct UnrecognizedFunctionType(pub MessageBlockHeader);
+message_data!{UnrecognizedFunctionType, false, 9, 5}
+
+/// ## S9F7
+/// 
+/// **Illegal Data (IDN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The Stream and Function were recognized, but the associated data format
+/// could not be interpreted.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageBlockHeader
+pub struct IllegalData(pub MessageBlockHeader);
+message_data!{IllegalData, false, 9, 7}
+
+/// ## S9F9
+/// 
+/// **Transaction Timer Timeout (TTN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that a transaction (receive) timer has timed out and that the
+/// corresponding transaction has been aborted.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [SHEAD]
+/// 
+/// [SHEAD]: StoredHeader
+pub struct TransactionTimerTimeout(pub StoredHeader);
+message_data!{TransactionTimerTimeout, false, 9, 9}
+
+/// ## S9F11
+/// 
+/// **Data Too Long (DLN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The Stream and Function were recognized, but there is more data than
+/// can be handled.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageBlockHeader
+pub struct DataTooLong(pub MessageBlockHeader);
+message_data!{DataTooLong, false, 9, 11}
+
+/// ## S9F13
+/// 
+/// **Conversation Timeout (CTN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the equipment was expecting a message, in the course of a
+/// conversation, which has not been received in the allotted time, and that
+/// the resources set aside for it have been released.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [MEXP]
+///    2. [EDID]
+/// 
+/// [MEXP]: MessageExpected
+/// [EDID]: ExpectedDataID
+pub struct ConversationTimeout(pub (MessageExpected, ExpectedDataID));
+message_data!{ConversationTimeout, false, 9, 13}
@@ -0,0 +1,336 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # WIRE CONVERSIONS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Conversion of a [Message]'s text directly to and from binary data, such
+//! that the tree of [Item]s which the text would otherwise be converted
+//! through is never constructed.
+//! 
+//! Data items are written directly from the values which hold them, and
+//! are read through a lone [Item] each, while the [List]s enclosing them are
+//! handled by their headers alone.
+//! 
+//! [Message]: crate::messages
+//! [Item]:    crate::Item
+//! [List]:    crate::Item::List
+
+use crate::{format, encode_header, DecodeLimits, Error, Item};
+use crate::items::{AcknowledgeCode6, Char, CodedValue, CollectionEventID, DataID, ReportID, Time, VariableValue};
+use encoding::{all::ISO_2022_JP, Encoding};
+
+/// ## ITEM HEADER
+/// 
+/// Parses the Format Byte and Length Bytes at the start of the binary data,
+/// returning the format, the length of the item's data, and the number of
+/// bytes occupied by the header.
+fn header(text: &[u8]) -> Option<(u8, usize, usize)> {
+  let (&format_byte, rest) = text.split_first()?;
+  let length_length = (format_byte & 0b000000_11) as usize;
+  if length_length == 0 {return None}
+  let mut bytes = [0u8;4];
+  bytes[4-length_length..].copy_from_slice(rest.get(..length_length)?);
+  Some((format_byte & 0b111111_00, u32::from_be_bytes(bytes) as usize, 1 + length_length))
+}
+
+/// ## CHECK
+/// 
+/// Walks the item headers of the binary data without converting any items,
+/// ensuring that it holds exactly one well formed item which is within the
+/// [Decode Limits], measured in the same way as when converting into an
+/// [Item].
+/// 
+/// [Item]:          Item
+/// [Decode Limits]: DecodeLimits
+fn check(text: &[u8], limits: &DecodeLimits) -> Result<(), Error> {
+  if text.is_empty() {return Err(Error::EmptyText)}
+  let mut offset: usize = 0;
+  let mut elements: usize = 0;
+  let mut size: usize = 0;
+  // Each entry holds the number of items remaining in an enclosing List.
+  let mut stack: Vec<usize> = vec![];
+  loop {
+    let (item, length, header_length) = header(&text[offset..]).ok_or(Error::InvalidText)?;
+    offset += header_length;
+    // Limits
+    elements += 1;
+    if item != format::LIST {size += length}
+    if stack.len() + 1 > limits.max_depth
+    || elements > limits.max_elements
+    || size > limits.max_size {
+      return Err(Error::LimitExceeded)
+    }
+    if item == format::LIST {
+      // Non-Empty List: Check Its Items Next
+      if length > 0 {
+        stack.push(length);
+        continue
+      }
+    } else {
+      if text.len() - offset < length {return Err(Error::InvalidText)}
+      offset += length;
+    }
+    // Count the item against its List, finishing each List filled.
+    loop {
+      let Some(remaining) = stack.last_mut() else {
+        // Check that all text has been handled.
+        return if offset == text.len() {Ok(())} else {Err(Error::InvalidText)}
+      };
+      *remaining -= 1;
+      if *remaining > 0 {break}
+      stack.pop();
+    }
+  }
+}
+
+/// ## READER
+/// 
+/// Reads the items of binary data in order, where [List]s are read by their
+/// headers alone and any other item is converted into a data item through a
+/// lone [Item].
+/// 
+/// A [List] whose length or position does not match the structure being read
+/// results in a [Wrong Format] error.
+/// 
+/// [Item]:         Item
+/// [List]:         Item::List
+/// [Wrong Format]: Error::WrongFormat
+pub(crate) struct Reader<'a> {
+  text: &'a [u8],
+  limits: DecodeLimits,
+}
+impl<'a> Reader<'a> {
+  /// ### NEW
+  /// 
+  /// Checks the binary data against the provided [Decode Limits] before it
+  /// is read, such that only the [Maximum Resident] limit needs to be
+  /// applied to each item as it is converted.
+  /// 
+  /// [Decode Limits]:    DecodeLimits
+  /// [Maximum Resident]: DecodeLimits::max_resident
+  pub fn new(text: &'a [u8], limits: &DecodeLimits) -> Result<Self, Error> {
+    check(text, limits)?;
+    Ok(Self {
+      text,
+      limits: DecodeLimits {
+        max_resident: limits.max_resident,
+        ..DecodeLimits::UNLIMITED
+      },
+    })
+  }
+
+  /// ### LIST
+  /// 
+  /// Reads the header of a [List], returning its length.
+  /// 
+  /// [List]: Item::List
+  pub fn list(&mut self) -> Result<usize, Error> {
+    match header(self.text) {
+      Some((format::LIST, length, header_length)) => {
+        self.text = &self.text[header_length..];
+        Ok(length)
+      },
+      _ => Err(Error::WrongFormat),
+    }
+  }
+
+  /// ### LIST OF LENGTH
+  /// 
+  /// Reads the header of a [List] which must be of the provided length.
+  /// 
+  /// [List]: Item::List
+  pub fn list_of(&mut self, length: usize) -> Result<(), Error> {
+    if self.list()? == length {Ok(())} else {Err(Error::WrongFormat)}
+  }
+
+  /// ### VECTORIZED LIST
+  /// 
+  /// Reads a [List], reading each of its items with the provided function.
+  /// 
+  /// [List]: Item::List
+  pub fn vec<T>(&mut self, mut read: impl FnMut(&mut Self) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    let length = self.list()?;
+    // Every item occupies at least two bytes.
+    let mut vec: Vec<T> = Vec::with_capacity(length.min(self.text.len() / 2));
+    for _ in 0..length {vec.push(read(self)?)}
+    Ok(vec)
+  }
+
+  /// ### ITEM
+  /// 
+  /// Reads a data item, which may itself be a [List] where the data item
+  /// allows it.
+  /// 
+  /// [List]: Item::List
+  pub fn item<T: TryFrom<Item, Error = Error>>(&mut self) -> Result<T, Error> {
+    // Find the end of the item, including the items of any List.
+    let mut length: usize = 0;
+    let mut remaining: usize = 1;
+    while remaining > 0 {
+      let (item, data_length, header_length) = header(&self.text[length..]).ok_or(Error::WrongFormat)?;
+      length += header_length;
+      if item == format::LIST {remaining += data_length} else {length += data_length}
+      remaining -= 1;
+    }
+    let (text, rest) = self.text.split_at(length);
+    self.text = rest;
+    Item::decode(text, &self.limits)?.try_into()
+  }
+}
+
+/// ## WRITE LIST
+/// 
+/// Appends the header of a [List] of the provided length.
+/// 
+/// [List]: Item::List
+pub(crate) fn write_list(vec: &mut Vec<u8>, length: usize) {
+  encode_header(vec, format::LIST, length);
+}
+
+/// ## WRITE ITEM
+/// 
+/// Appends a data item, written directly from the borrowed value.
+pub(crate) fn write_item(vec: &mut Vec<u8>, value: &impl WriteItem) {
+  value.write_item(vec);
+}
+
+/// ## WRITE VALUES
+/// 
+/// Appends an item of the provided format holding the provided values, each
+/// occupying a fixed number of bytes.
+fn write_values<T: Copy, const N: usize>(
+  vec: &mut Vec<u8>,
+  format: u8,
+  values: &[T],
+  bytes: fn(T) -> [u8; N],
+) {
+  encode_header(vec, format, values.len() * N);
+  for value in values {vec.extend_from_slice(&bytes(*value))}
+}
+
+/// ## ASCII BYTE
+fn ascii(value: Char) -> [u8; 1] {
+  [u8::from(value)]
+}
+
+/// ## BOOLEAN BYTE
+fn boolean(value: bool) -> [u8; 1] {
+  [value as u8]
+}
+
+/// ## WRITE DATA ITEM
+/// 
+/// Implemented by the data items of [Message]s converted directly into
+/// binary data, which are written from borrowed values rather than converted
+/// into an [Item] which would need to own a copy of their contents.
+/// 
+/// [Message]: crate::messages
+/// [Item]:    Item
+pub(crate) trait WriteItem {
+  /// ### WRITE ITEM
+  /// 
+  /// Appends the binary data of the data item.
+  fn write_item(&self, vec: &mut Vec<u8>);
+}
+
+/// ## ITEM
+impl WriteItem for Item {
+  fn write_item(&self, vec: &mut Vec<u8>) {
+    self.encode_into(vec);
+  }
+}
+
+/// ## ACKC6
+impl WriteItem for CodedValue<AcknowledgeCode6> {
+  fn write_item(&self, vec: &mut Vec<u8>) {
+    write_values(vec, format::BIN, &[u8::from(*self)], u8::to_be_bytes);
+  }
+}
+
+/// ## TIME
+impl WriteItem for Time {
+  fn write_item(&self, vec: &mut Vec<u8>) {
+    write_values(vec, format::ASCII, &self.0, ascii);
+  }
+}
+
+/// ## VARIABLE VALUE
+/// 
+/// A [List] is written from the [Item]s it holds by reference.
+/// 
+/// [Item]: Item
+/// [List]: VariableValue::List
+impl WriteItem for VariableValue {
+  fn write_item(&self, vec: &mut Vec<u8>) {
+    match self {
+      VariableValue::List(items) => {
+        write_list(vec, items.len());
+        for item in items {item.encode_into(vec)}
+      },
+      VariableValue::Bin(values)   => write_values(vec, format::BIN,   values, u8::to_be_bytes),
+      VariableValue::Bool(values)  => write_values(vec, format::BOOL,  values, boolean),
+      VariableValue::Ascii(values) => write_values(vec, format::ASCII, values, ascii),
+      VariableValue::Jis8(string)  => {
+        let encoded = ISO_2022_JP.encode(string, encoding::EncoderTrap::Ignore).unwrap();
+        write_values(vec, format::JIS8, &encoded, u8::to_be_bytes);
+      },
+      VariableValue::I1(values) => write_values(vec, format::I1, values, i8::to_be_bytes),
+      VariableValue::I2(values) => write_values(vec, format::I2, values, i16::to_be_bytes),
+      VariableValue::I4(values) => write_values(vec, format::I4, values, i32::to_be_bytes),
+      VariableValue::I8(values) => write_values(vec, format::I8, values, i64::to_be_bytes),
+      VariableValue::U1(values) => write_values(vec, format::U1, values, u8::to_be_bytes),
+      VariableValue::U2(values) => write_values(vec, format::U2, values, u16::to_be_bytes),
+      VariableValue::U4(values) => write_values(vec, format::U4, values, u32::to_be_bytes),
+      VariableValue::U8(values) => write_values(vec, format::U8, values, u64::to_be_bytes),
+      VariableValue::F4(values) => write_values(vec, format::F4, values, f32::to_be_bytes),
+      VariableValue::F8(values) => write_values(vec, format::F8, values, f64::to_be_bytes),
+    }
+  }
+}
+
+/// ## WRITE DATA ITEM MACRO: MULTIFORMAT + ASCII
+/// 
+/// Implements [Write Data Item] for enums expanded by the
+/// [multiformat_ascii] macro.
+/// 
+/// [Write Data Item]:   WriteItem
+/// [multiformat_ascii]: crate::multiformat_ascii
+macro_rules! write_multiformat_ascii {
+  ($($name:ident),* $(,)?) => {$(
+    impl WriteItem for $name {
+      fn write_item(&self, vec: &mut Vec<u8>) {
+        match self {
+          $name::Ascii(values) => write_values(vec, format::ASCII, values, ascii),
+          $name::I1(value) => write_values(vec, format::I1, &[*value], i8::to_be_bytes),
+          $name::I2(value) => write_values(vec, format::I2, &[*value], i16::to_be_bytes),
+          $name::I4(value) => write_values(vec, format::I4, &[*value], i32::to_be_bytes),
+          $name::I8(value) => write_values(vec, format::I8, &[*value], i64::to_be_bytes),
+          $name::U1(value) => write_values(vec, format::U1, &[*value], u8::to_be_bytes),
+          $name::U2(value) => write_values(vec, format::U2, &[*value], u16::to_be_bytes),
+          $name::U4(value) => write_values(vec, format::U4, &[*value], u32::to_be_bytes),
+          $name::U8(value) => write_values(vec, format::U8, &[*value], u64::to_be_bytes),
+        }
+      }
+    }
+  )*}
+}
+write_multiformat_ascii!{CollectionEventID, DataID, ReportID}
@@ -0,0 +1,141 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SPILLED PAYLOADS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Storage for the [Payload]s of [ASCII] and [Binary] [Item]s too large to
+//! be kept in memory, backed by an anonymous temporary file mapped into
+//! memory, such that the operating system may page its contents in and out
+//! as they are accessed.
+//! 
+//! - **spill** feature: provides this module.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! When decoding, [Payload]s larger than the [Maximum Resident] size of the
+//! [Decode Limits] are [Spilled] rather than resulting in an error. The
+//! temporary file is created in the directory given by [temp_dir], and is
+//! removed by the operating system once every clone of the [Payload] has
+//! been dropped.
+//! 
+//! The binary data being decoded is not spilled, and must be held in memory
+//! in full until decoding is complete, so spilling only limits the memory
+//! kept by the decoded [Item]s afterwards.
+//! 
+//! [Item]:             crate::Item
+//! [ASCII]:            crate::Item::Ascii
+//! [Binary]:           crate::Item::Bin
+//! [Payload]:          crate::Payload
+//! [Spilled]:          crate::Payload::Spilled
+//! [Decode Limits]:    crate::DecodeLimits
+//! [Maximum Resident]: crate::DecodeLimits::max_resident
+//! [temp_dir]:         std::env::temp_dir
+
+use std::{
+  io::{
+    Error,
+    ErrorKind,
+    Write,
+  },
+  marker::PhantomData,
+  sync::Arc,
+};
+use memmap2::Mmap;
+use crate::items::Char;
+
+/// ## SPILLED
+/// 
+/// The contents of a [Payload] stored in a memory-mapped temporary file.
+/// 
+/// Only elements which are single bytes, [u8] and [Char], may be spilled.
+/// Clones share the same file.
+/// 
+/// [Payload]: crate::Payload
+/// [Char]:    Char
+pub struct Spilled<T> {
+  map: Arc<Mmap>,
+  element: PhantomData<T>,
+}
+impl<T> Spilled<T> {
+  /// ### NEW SPILLED
+  /// 
+  /// Writes raw bytes to a new temporary file and maps it into memory.
+  /// 
+  /// Results in an error of kind [InvalidData] if any byte is not a valid
+  /// element, or any error encountered creating, writing, or mapping the
+  /// file.
+  /// 
+  /// [InvalidData]: std::io::ErrorKind::InvalidData
+  pub(crate) fn new(bytes: &[u8]) -> Result<Self, Error>
+  where T: Byte
+  {
+    if !bytes.iter().all(|byte| T::is_valid(*byte)) {
+      return Err(Error::from(ErrorKind::InvalidData))
+    }
+    let mut file = tempfile::tempfile()?;
+    file.write_all(bytes)?;
+    // SAFETY: The file is anonymous, having no path by which another process
+    // could modify it, and is never written to again once mapped.
+    let map = unsafe {Mmap::map(&file)}?;
+    Ok(Self {map: Arc::new(map), element: PhantomData})
+  }
+}
+impl<T> Clone for Spilled<T> {
+  fn clone(&self) -> Self {
+    Self {map: self.map.clone(), element: PhantomData}
+  }
+}
+impl<T> std::ops::Deref for Spilled<T> {
+  type Target = [T];
+
+  fn deref(&self) -> &[T] {
+    // SAFETY: A Spilled is only constructed for a Byte, whose layout is that
+    // of a u8, and whose validity was checked for every byte when written.
+    unsafe {std::slice::from_raw_parts(self.map.as_ptr() as *const T, self.map.len())}
+  }
+}
+
+/// ## BYTE
+/// 
+/// An element of a [Payload] which may be [Spilled], being represented by a
+/// single byte.
+/// 
+/// # Safety
+/// 
+/// The type must have the size and alignment of a [u8], and every byte for
+/// which [is_valid] is true must be a valid value of the type.
+/// 
+/// [Payload]:  crate::Payload
+/// [Spilled]:  Spilled
+/// [is_valid]: Byte::is_valid
+pub(crate) unsafe trait Byte: Copy {
+  /// ### IS VALID
+  /// 
+  /// Whether the byte is a valid value of the type.
+  fn is_valid(byte: u8) -> bool;
+}
+unsafe impl Byte for u8 {
+  fn is_valid(_byte: u8) -> bool {true}
+}
+unsafe impl Byte for Char {
+  fn is_valid(byte: u8) -> bool {byte.is_ascii()}
+}
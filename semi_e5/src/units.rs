@@ -23,15 +23,156 @@
 //! 
 //! ---------------------------------------------------------------------------
 //! 
-//! ## TO BE DONE
+//! Typed representation of the units identifiers used by items such as
+//! [UNITS], parsed from and formatted into their textual form.
 //! 
-//! - Fully implement this module.
+//! A [Units Expression] is made of [Unit]s joined by multiplication, `*`,
+//! and division, `/`, where each [Unit] is an [Identifier] written with its
+//! optional [Prefix] or [Suffix], followed by an optional exponent such as
+//! in `m2` or `s-1`. The empty string represents a unitless quantity.
+//! 
+//! - `kg` - Kilogram.
+//! - `m/s2` - Meter per second squared.
+//! - `kW*h` - Kilowatt hour.
+//! - `css25` - Cassette holding 25 wafers.
+//! 
+//! [UNITS]:            crate::items::Units
+//! [Units Expression]: Expression
+//! [Unit]:             Unit
+//! [Identifier]:       Identifier
+//! [Prefix]:           Prefix
+//! [Suffix]:           Suffix
+
+use crate::Error::{self, WrongFormat};
+
+/// ## UNITS EXPRESSION
+/// 
+/// A product of [Unit]s divided by a product of [Unit]s.
+/// 
+/// An expression with no [Unit]s at all is unitless, written as the empty
+/// string.
+/// 
+/// [Unit]: Unit
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expression {
+  /// ### NUMERATOR
+  /// 
+  /// The [Unit]s which are multiplied together.
+  /// 
+  /// [Unit]: Unit
+  pub numerator: Vec<Unit>,
+
+  /// ### DENOMINATOR
+  /// 
+  /// The [Unit]s which are divided by.
+  /// 
+  /// [Unit]: Unit
+  pub denominator: Vec<Unit>,
+}
+impl Expression {
+  /// ### IS UNITLESS
+  pub fn is_unitless(&self) -> bool {
+    self.numerator.iter().chain(&self.denominator).all(|unit| unit.identifier == Identifier::None)
+  }
+}
+impl From<Unit> for Expression {
+  fn from(unit: Unit) -> Self {
+    Self {numerator: vec![unit], denominator: vec![]}
+  }
+}
+impl std::str::FromStr for Expression {
+  type Err = Error;
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    let mut expression = Self::default();
+    if text.is_empty() {return Ok(expression)}
+    let mut divide = false;
+    let mut start = 0;
+    for (index, operator) in text.char_indices().filter(|(_, c)| *c == '*' || *c == '/').chain([(text.len(), '*')]) {
+      let term = &text[start..index];
+      if term.is_empty() {return Err(WrongFormat)}
+      // A numerator of only 1 is written when there are only denominators.
+      if !(start == 0 && term == "1") {
+        let unit = term.parse()?;
+        if divide {expression.denominator.push(unit)} else {expression.numerator.push(unit)}
+      }
+      divide = operator == '/';
+      start = index + 1;
+    }
+    Ok(expression)
+  }
+}
+impl std::fmt::Display for Expression {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (index, unit) in self.numerator.iter().enumerate() {
+      if index > 0 {write!(f, "*")?}
+      write!(f, "{}", unit)?;
+    }
+    if self.numerator.is_empty() && !self.denominator.is_empty() {
+      write!(f, "1")?;
+    }
+    for unit in &self.denominator {
+      write!(f, "/{}", unit)?;
+    }
+    Ok(())
+  }
+}
 
+/// ## UNIT
+/// 
+/// A single [Identifier] raised to an optional exponent.
+/// 
+/// [Identifier]: Identifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Unit {
   pub identifier: Identifier,
   pub exponent: Option<i64>,
 }
+impl From<Identifier> for Unit {
+  fn from(identifier: Identifier) -> Self {
+    Self {identifier, exponent: None}
+  }
+}
+impl std::str::FromStr for Unit {
+  type Err = Error;
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    if let Some(identifier) = Identifier::from_symbol(text) {
+      return Ok(identifier.into())
+    }
+    let digits = text.len() - text.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 {return Err(WrongFormat)}
+    let mut split = text.len() - digits;
+    if text[..split].ends_with('-') {split -= 1}
+    let exponent = text[split..].parse().map_err(|_| WrongFormat)?;
+    let symbol = text[..split].strip_suffix('^').unwrap_or(&text[..split]);
+    match Identifier::from_symbol(symbol) {
+      Some(identifier) if !symbol.is_empty() => Ok(Self {identifier, exponent: Some(exponent)}),
+      _ => Err(WrongFormat),
+    }
+  }
+}
+impl std::fmt::Display for Unit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.identifier)?;
+    if let Some(exponent) = self.exponent {
+      write!(f, "{}", exponent)?;
+    }
+    Ok(())
+  }
+}
 
+/// ## UNIT IDENTIFIER
+/// 
+/// The units of measure defined by the standard, along with their symbol,
+/// conversion factor, and the unit the conversion factor refers to.
+/// 
+/// Units which accept a [Prefix] are scaled by it, and units which accept a
+/// [Suffix] use it to specify their capacity.
+/// 
+/// [Prefix]: Prefix
+/// [Suffix]: Suffix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Identifier {
   // ==== UNITLESS ==========================================================
   None                                 , //Null String
@@ -83,7 +224,7 @@ pub enum Identifier {
   MinutePlanar                         , //mins   | 1/60         | deg
   SecondPlanar                         , //sec    | 1/60         | mins
   //                                 S+1 ===== SOLID ANGLE ==================
-  Steradian            (Option<Prefix>), //Sr     |              | SI
+  Steradian            (Option<Prefix>), //sr     |              | SI
   // ===== KINEMATICS =======================================================
   // T-1                         P+1     ===== FREQUENCY ====================
   Hertz                (Option<Prefix>), //Hz     | 1            | c/s
@@ -129,7 +270,7 @@ pub enum Identifier {
   WattHour             (Option<Prefix>), //Wh     | 3600         | J
   BritishThermal                       , //Btu    | 1054.35      | J
   Therm                                , //thm    | 1e5          | Btu
-  CalorieInternational (Option<Prefix>), //callIT | 4.1868       | J
+  CalorieInternational (Option<Prefix>), //calIT  | 4.1868       | J
   Calorie              (Option<Prefix>), //cal    | 4.1840       | J
   ElectronVolt         (Option<Prefix>), //eV     | 1.60209e-19  | J
   Erg                  (Option<Prefix>), //erg    | 1e-7         | J
@@ -138,7 +279,7 @@ pub enum Identifier {
   Horsepower                           , //hp     | 746          | W
   Var                  (Option<Prefix>), //var    |              |
   // T-1 L-1 M+1                         ===== DYNAMIC VISCOSITY ============
-  Poise                (Option<Prefix>), //P      , 36           | kg/m*s
+  Poise                (Option<Prefix>), //P      | 36           | kg/m*s
   // T-2 L-1 M+1                         ===== PRESSURE =====================
   Pascal               (Option<Prefix>), //Pa     | 1            | N/m^2
   Bar                  (Option<Prefix>), //bar    | 100          | kPa
@@ -218,6 +359,256 @@ pub enum Identifier {
   WaferFrame           (Option<Suffix>), //wffr      | Temporary fixture for wafers, whose capacity is specified by the suffix.
 }
 
+/// ## INTERNAL SYMBOL TABLE
+/// 
+/// Generates the conversions between each [Identifier] and its symbol.
+/// 
+/// [Identifier]: Identifier
+macro_rules! symbols {
+  (
+    plain {$($plain:ident => $plain_symbol:expr,)*}
+    prefixed {$($prefixed:ident => $prefixed_symbol:expr,)*}
+    suffixed {$($suffixed:ident => $suffixed_symbol:expr,)*}
+  ) => {
+    impl Identifier {
+      /// ### SYMBOL
+      /// 
+      /// The symbol of the [Identifier], without any [Prefix] or [Suffix].
+      /// 
+      /// [Identifier]: Identifier
+      /// [Prefix]:     Prefix
+      /// [Suffix]:     Suffix
+      pub fn symbol(&self) -> &'static str {
+        match self {
+          $(Identifier::$plain => $plain_symbol,)*
+          $(Identifier::$prefixed(_) => $prefixed_symbol,)*
+          $(Identifier::$suffixed(_) => $suffixed_symbol,)*
+        }
+      }
+
+      /// ### FROM SYMBOL
+      /// 
+      /// Parses an [Identifier] written with its optional [Prefix] or
+      /// [Suffix], preferring a symbol without a [Prefix] where both would
+      /// match.
+      /// 
+      /// [Identifier]: Identifier
+      /// [Prefix]:     Prefix
+      /// [Suffix]:     Suffix
+      pub fn from_symbol(text: &str) -> Option<Self> {
+        $(if text == $plain_symbol {return Some(Identifier::$plain)})*
+        $(if text == $prefixed_symbol {return Some(Identifier::$prefixed(None))})*
+        for prefix in Prefix::ALL {
+          if let Some(rest) = text.strip_prefix(prefix.symbol()) {
+            $(if rest == $prefixed_symbol {return Some(Identifier::$prefixed(Some(prefix)))})*
+          }
+        }
+        $(if let Some(rest) = text.strip_prefix($suffixed_symbol) {
+          if rest.is_empty() {return Some(Identifier::$suffixed(None))}
+          if rest.bytes().all(|byte| byte.is_ascii_digit()) {
+            return rest.parse().ok().map(|capacity| Identifier::$suffixed(Some(Suffix(capacity))))
+          }
+        })*
+        None
+      }
+
+      /// ### PREFIX
+      /// 
+      /// The [Prefix] of the [Identifier], if any.
+      /// 
+      /// [Identifier]: Identifier
+      /// [Prefix]:     Prefix
+      pub fn prefix(&self) -> Option<Prefix> {
+        match self {
+          $(Identifier::$prefixed(prefix) => *prefix,)*
+          _ => None,
+        }
+      }
+
+      /// ### SUFFIX
+      /// 
+      /// The [Suffix] of the [Identifier], if any.
+      /// 
+      /// [Identifier]: Identifier
+      /// [Suffix]:     Suffix
+      pub fn suffix(&self) -> Option<Suffix> {
+        match self {
+          $(Identifier::$suffixed(suffix) => *suffix,)*
+          _ => None,
+        }
+      }
+    }
+  }
+}
+symbols!{
+  plain {
+    None                             => "",
+    Percent                          => "%",
+    PartsPerMillion                  => "ppm",
+    PH                               => "pH",
+    Minute                           => "min",
+    Hour                             => "h",
+    DayMeanSolar                     => "d",
+    Month                            => "mo",
+    Year                             => "yr",
+    Micron                           => "um",
+    MilliMicron                      => "nm",
+    NauticalMile                     => "nmi",
+    Inch                             => "in",
+    Foot                             => "ft",
+    Mil                              => "mil",
+    Mile                             => "mile",
+    AtomicMass                       => "u",
+    Slug                             => "slug",
+    Pound                            => "lb",
+    Kelvin                           => "K",
+    DegreeCelsius                    => "degC",
+    DegreeFarenheit                  => "degF",
+    Mole                             => "mol",
+    Revolution                       => "r",
+    DegreePlanar                     => "deg",
+    MinutePlanar                     => "mins",
+    SecondPlanar                     => "sec",
+    Curie                            => "Ci",
+    Knot                             => "kn",
+    Darcy                            => "D",
+    Barrel                           => "bbl",
+    Gallon                           => "gal",
+    GallonUK                         => "galUK",
+    PintUK                           => "ptUK",
+    PintUSDry                        => "ptUS",
+    PintUSLiquid                     => "pt",
+    QuartUK                          => "qtUK",
+    QuartUSDry                       => "qtUS",
+    QuartUSLiquid                    => "qt",
+    StandardCubicCentimeterPerMinute => "sccm",
+    StandardLiterPerMinute           => "slpm",
+    MetricTon                        => "t",
+    PoundForce                       => "lbf",
+    TonShort                         => "ton",
+    KiloPoundForce                   => "klbf",
+    Poundal                          => "pdl",
+    OunceAvoirdupois                 => "oz",
+    Grain                            => "gr",
+    BritishThermal                   => "Btu",
+    Therm                            => "thm",
+    Horsepower                       => "hp",
+    AtmosphereStandard               => "atm",
+    AtmosphereTechnical              => "at",
+    InchMercury                      => "inHg",
+    InchWater                        => "inH2O",
+    MicronMercury                    => "umHg",
+    MilliMeterMercury                => "mmHg",
+    FootLambert                      => "FL",
+    FootCandle                       => "Fc",
+    Roentgen                         => "R",
+    Ion                              => "ion",
+    Substrate                        => "substrate",
+    Ingot                            => "ing",
+    Wafer                            => "wfr",
+    Die                              => "die",
+    Package                          => "pkg",
+    Lot                              => "lot",
+  }
+  prefixed {
+    Bel                              => "B",
+    Neper                            => "Np",
+    Second                           => "s",
+    Meter                            => "m",
+    Angstrom                         => "Ang",
+    Gram                             => "g",
+    Ampere                           => "A",
+    Candela                          => "cd",
+    Radian                           => "rad",
+    Cycle                            => "c",
+    Steradian                        => "sr",
+    Hertz                            => "Hz",
+    Becquerel                        => "Bq",
+    Gal                              => "Gal",
+    Barn                             => "barn",
+    Stokes                           => "St",
+    Liter                            => "l",
+    Newton                           => "N",
+    Dyne                             => "dyn",
+    GramForce                        => "gf",
+    Joule                            => "J",
+    WattHour                         => "Wh",
+    CalorieInternational             => "calIT",
+    Calorie                          => "cal",
+    ElectronVolt                     => "eV",
+    Erg                              => "erg",
+    Watt                             => "W",
+    Var                              => "var",
+    Poise                            => "P",
+    Pascal                           => "Pa",
+    Bar                              => "bar",
+    Torr                             => "torr",
+    Coulomb                          => "C",
+    Oersted                          => "Oe",
+    Siemens                          => "S",
+    Mho                              => "mho",
+    Farad                            => "F",
+    Tesla                            => "T",
+    Gauss                            => "G",
+    Weber                            => "Wb",
+    Maxwell                          => "Mx",
+    Volt                             => "V",
+    Henry                            => "H",
+    Ohm                              => "ohm",
+    AmpereTurn                       => "AT",
+    Gilbert                          => "Gb",
+    Lumen                            => "lm",
+    Nit                              => "nt",
+    Stilb                            => "sb",
+    Lambert                          => "L",
+    Lux                              => "lx",
+    Phot                             => "ph",
+    Sievert                          => "Sv",
+    Rem                              => "rem",
+    Gray                             => "Gy",
+    Rad                              => "rd",
+    Bit                              => "bit",
+    Byte                             => "byte",
+    Baud                             => "Bd",
+  }
+  suffixed {
+    Boat                             => "boat",
+    Carrier                          => "carrier",
+    Cassette                         => "css",
+    LeadFrame                        => "ldfr",
+    Magazine                         => "mgz",
+    Plate                            => "plt",
+    Tube                             => "tube",
+    WaferFrame                       => "wffr",
+  }
+}
+impl std::str::FromStr for Identifier {
+  type Err = Error;
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    Identifier::from_symbol(text).ok_or(WrongFormat)
+  }
+}
+impl std::fmt::Display for Identifier {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(prefix) = self.prefix() {
+      write!(f, "{}", prefix.symbol())?;
+    }
+    write!(f, "{}", self.symbol())?;
+    if let Some(Suffix(capacity)) = self.suffix() {
+      write!(f, "{}", capacity)?;
+    }
+    Ok(())
+  }
+}
+
+/// ## PREFIX
+/// 
+/// The decimal multiples which scale an [Identifier], along with their
+/// symbol and factor.
+/// 
+/// [Identifier]: Identifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Prefix {
   Exa,   //E  | 1e18
   Peta,  //P  | 1e15
@@ -226,8 +617,8 @@ pub enum Prefix {
   Mega,  //M  | 1e6
   Kilo,  //k  | 1e3
   Hecto, //h  | 1e2
-  Deca,  //d  | 1e1
-  Deci,  //da | 1e-1
+  Deca,  //da | 1e1
+  Deci,  //d  | 1e-1
   Centi, //c  | 1e-2
   Milli, //m  | 1e-3
   Micro, //u  | 1e-6
@@ -237,4 +628,69 @@ pub enum Prefix {
   Atto,  //a  | 1e-18
 }
 
+impl Prefix {
+  /// ### ALL PREFIXES
+  pub const ALL: [Prefix; 16] = [
+    Prefix::Exa, Prefix::Peta, Prefix::Tera, Prefix::Giga,
+    Prefix::Mega, Prefix::Kilo, Prefix::Hecto, Prefix::Deca,
+    Prefix::Deci, Prefix::Centi, Prefix::Milli, Prefix::Micro,
+    Prefix::Nano, Prefix::Pico, Prefix::Femto, Prefix::Atto,
+  ];
+
+  /// ### SYMBOL
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      Prefix::Exa   => "E",
+      Prefix::Peta  => "P",
+      Prefix::Tera  => "T",
+      Prefix::Giga  => "G",
+      Prefix::Mega  => "M",
+      Prefix::Kilo  => "k",
+      Prefix::Hecto => "h",
+      Prefix::Deca  => "da",
+      Prefix::Deci  => "d",
+      Prefix::Centi => "c",
+      Prefix::Milli => "m",
+      Prefix::Micro => "u",
+      Prefix::Nano  => "n",
+      Prefix::Pico  => "p",
+      Prefix::Femto => "f",
+      Prefix::Atto  => "a",
+    }
+  }
+
+  /// ### EXPONENT
+  /// 
+  /// The power of ten by which the [Prefix] scales.
+  /// 
+  /// [Prefix]: Prefix
+  pub fn exponent(&self) -> i32 {
+    match self {
+      Prefix::Exa   => 18,
+      Prefix::Peta  => 15,
+      Prefix::Tera  => 12,
+      Prefix::Giga  => 9,
+      Prefix::Mega  => 6,
+      Prefix::Kilo  => 3,
+      Prefix::Hecto => 2,
+      Prefix::Deca  => 1,
+      Prefix::Deci  => -1,
+      Prefix::Centi => -2,
+      Prefix::Milli => -3,
+      Prefix::Micro => -6,
+      Prefix::Nano  => -9,
+      Prefix::Pico  => -12,
+      Prefix::Femto => -15,
+      Prefix::Atto  => -18,
+    }
+  }
+}
+
+/// ## SUFFIX
+/// 
+/// The capacity of a holder of material, such as the number of wafers a
+/// [Cassette] is able to hold.
+/// 
+/// [Cassette]: Identifier::Cassette
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Suffix(pub u64);
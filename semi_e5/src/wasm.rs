@@ -0,0 +1,122 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # WEBASSEMBLY BINDINGS
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Functions exported to JavaScript with wasm-bindgen when built for the
+//! `wasm32-unknown-unknown` target, so that web-based log viewers may decode
+//! captured [SECS-II] traffic in the browser.
+//! 
+//! - **wasm-bindgen** feature: provides this module.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Message text is the binary data following the message header, which may
+//! be empty for a header-only message. Decoding is subject to the default
+//! [Decode Limits], and errors are thrown as JavaScript `Error`s naming the
+//! [Error].
+//! 
+//! [SECS-II]:       crate
+//! [Decode Limits]: crate::DecodeLimits
+//! [Error]:         crate::Error
+
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+use crate::{
+  json::{JsonItem, JsonMessage},
+  Error,
+  Item,
+  Message,
+};
+
+/// ### DECODE MESSAGE
+/// 
+/// Decodes a message's text, resulting in the message written in SML, such
+/// as `S1F13 W <L [0]>`.
+#[wasm_bindgen(js_name = decodeMessage)]
+pub fn decode_message(stream: u8, function: u8, w: bool, text: &[u8]) -> Result<String, JsError> {
+  Ok(message(stream, function, w, text)?.to_string())
+}
+
+/// ### DECODE MESSAGE TO JSON
+/// 
+/// Decodes a message's text, resulting in the message written as a
+/// [JSON Message].
+/// 
+/// [JSON Message]: JsonMessage
+#[wasm_bindgen(js_name = decodeMessageJson)]
+pub fn decode_message_json(stream: u8, function: u8, w: bool, text: &[u8]) -> Result<String, JsError> {
+  Ok(JsonMessage(&message(stream, function, w, text)?).to_string())
+}
+
+/// ### DECODE ITEM
+/// 
+/// Decodes an item, resulting in the item written in SML.
+#[wasm_bindgen(js_name = decodeItem)]
+pub fn decode_item(text: &[u8]) -> Result<String, JsError> {
+  Ok(item(text)?.to_string())
+}
+
+/// ### DECODE ITEM TO JSON
+/// 
+/// Decodes an item, resulting in the item written as a [JSON Item].
+/// 
+/// [JSON Item]: JsonItem
+#[wasm_bindgen(js_name = decodeItemJson)]
+pub fn decode_item_json(text: &[u8]) -> Result<String, JsError> {
+  Ok(JsonItem(&item(text)?).to_string())
+}
+
+/// ### ENCODE ITEM
+/// 
+/// Parses an item written in SML, resulting in its binary form.
+#[wasm_bindgen(js_name = encodeItem)]
+pub fn encode_item(sml: &str) -> Result<Vec<u8>, JsError> {
+  let item: Item = sml.parse().map_err(error)?;
+  Ok(item.into())
+}
+
+/// ### MESSAGE
+/// 
+/// Decodes a message's text along with its header fields.
+fn message(stream: u8, function: u8, w: bool, text: &[u8]) -> Result<Message, JsError> {
+  let text: Option<Item> = match text {
+    [] => None,
+    text => Some(item(text)?),
+  };
+  Ok(Message {stream, function, w, text})
+}
+
+/// ### ITEM
+/// 
+/// Decodes an item.
+fn item(text: &[u8]) -> Result<Item, JsError> {
+  Item::try_from(text.to_vec()).map_err(error)
+}
+
+/// ### ERROR
+/// 
+/// Converts an [Error] into a JavaScript `Error`.
+/// 
+/// [Error]: Error
+fn error(error: Error) -> JsError {
+  JsError::new(&format!("{error:?}"))
+}
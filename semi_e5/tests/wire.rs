@@ -0,0 +1,121 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # DIRECT BYTE CONVERSIONS
+//! 
+//! Checks that the typed messages converted directly to and from binary
+//! data agree with the conversions through [Item]s.
+
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    AcknowledgeCode6,
+    Char,
+    CodedValue,
+    CollectionEventID,
+    DataID,
+    ReportID,
+    Time,
+    VariableValue,
+    VecList,
+  },
+  messages::{s2, s6},
+};
+
+fn ascii(text: &str) -> Vec<Char> {
+  text.bytes().map(|byte| Char::try_from(byte).unwrap()).collect()
+}
+
+fn event_report() -> s6::EventReport {
+  s6::EventReport((
+    DataID::U4(7),
+    CollectionEventID::U2(300),
+    VecList(vec![
+      (ReportID::U4(1), VecList(vec![
+        VariableValue::U4(vec![1, 2, 3]),
+        VariableValue::Ascii(ascii("LOT-01")),
+        VariableValue::Jis8("ロット".to_string()),
+      ])),
+      (ReportID::Ascii(ascii("RPT")), VecList(vec![
+        VariableValue::List(vec![
+          Item::u1(5),
+          Item::List(vec![
+            Item::Jis8("装置".to_string()),
+            Item::Ascii(ascii("WAFER").into()),
+          ]),
+          Item::List(vec![]),
+        ]),
+        VariableValue::F8(vec![0.5]),
+      ])),
+      (ReportID::U1(2), VecList(vec![])),
+    ]),
+  ))
+}
+
+#[test]
+fn event_report_to_bytes() {
+  let message: s6::EventReport = event_report();
+  let bytes: Vec<u8> = message.to_bytes();
+  assert_eq!(bytes, Vec::<u8>::from(Item::from(message.0)));
+}
+
+#[test]
+fn event_report_from_bytes() {
+  let bytes: Vec<u8> = event_report().to_bytes();
+  let message: s6::EventReport = s6::EventReport::try_from(bytes.as_slice()).unwrap();
+  assert_eq!(message.to_bytes(), bytes);
+  assert_eq!(Item::from(message.0), Item::try_from(bytes).unwrap());
+}
+
+#[test]
+fn event_report_from_invalid_bytes() {
+  let bytes: Vec<u8> = event_report().to_bytes();
+  assert!(s6::EventReport::try_from(&bytes[..bytes.len() - 1]).is_err());
+  assert!(s6::EventReport::try_from(Vec::<u8>::from(Item::List(vec![])).as_slice()).is_err());
+}
+
+#[test]
+fn event_report_acknowledge() {
+  let message = s6::EventReportAcknowledge(CodedValue::Known(AcknowledgeCode6::NotAccepted));
+  let bytes: Vec<u8> = message.to_bytes();
+  assert_eq!(bytes, Vec::<u8>::from(Item::from(message.0)));
+  let message = s6::EventReportAcknowledge::try_from(bytes.as_slice()).unwrap();
+  assert!(message.0.is(AcknowledgeCode6::NotAccepted));
+}
+
+#[test]
+fn date_time_request() {
+  let message: Message = s2::DateTimeRequest.into();
+  assert!(message.text.is_none());
+  assert!(s2::DateTimeRequest.to_bytes().is_empty());
+  assert!(s2::DateTimeRequest::try_from(&[][..]).is_ok());
+  assert!(s2::DateTimeRequest::try_from(Vec::<u8>::from(Item::List(vec![])).as_slice()).is_err());
+}
+
+#[test]
+fn date_time_data() {
+  let message = s2::DateTimeData(Time::new_from_str("2024010112000000").unwrap());
+  let bytes: Vec<u8> = message.to_bytes();
+  assert_eq!(bytes, Vec::<u8>::from(Item::from(message.0)));
+  let message: s2::DateTimeData = s2::DateTimeData::try_from(bytes.as_slice()).unwrap();
+  assert_eq!(message.to_bytes(), bytes);
+  assert_eq!(Item::from(message.0), Item::try_from(bytes).unwrap());
+}
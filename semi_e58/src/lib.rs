@@ -0,0 +1,72 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # AUTOMATED RELIABILITY, AVAILABILITY, AND MAINTAINABILITY STANDARD (ARAMS)
+//! 
+//! Copyright © 2024 Nathaniel Hardesty, Licensed under the MIT License
+//! 
+//! This software is created by a third-party and not endorsed or supported by
+//! SEMI.
+//! 
+//! The codebase will be updated to reflect more up-to-date SEMI standards
+//! if/when they can be acquired for this purpose.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! **Based on:**
+//! - **[SEMI E10]**
+//! - **[SEMI E58]**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ARAMS defines how equipment tracks which of the equipment states defined
+//! by [SEMI E10] it is in, so that its reliability, availability, and
+//! maintainability may be measured, and how it reports this to the host as
+//! described by [GEM] ([SEMI E30]).
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! For ease of programming and extension, the functionality of the standard
+//! has been divided into a few subsets:
+//! 
+//! - [Equipment States] - The six basic states of equipment, such as
+//!   productive, standby, and scheduled or unscheduled downtime.
+//! - [Reliability Tracker] - Tracks the current state and the time spent in
+//!   each state, keeping the associated status variables up to date and
+//!   resulting in the collection event to report upon each transition.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TODO
+//! 
+//! - The substates of each state
+//! - Symptom and reason codes for downtime
+//! - Sending the event report of each transition, once the equipment keeps
+//!   the reports defined by the host
+//! 
+//! [SEMI E10]: https://store-us.semi.org/products/e01000-semi-e10-specification-for-definition-and-measurement-of-equipment-reliability-availability-and-maintainability-ram-and-utilization
+//! [SEMI E30]: https://store-us.semi.org/products/e03000-semi-e30-specification-for-the-generic-model-for-communications-and-control-of-manufacturing-equipment-gem
+//! [SEMI E58]: https://store-us.semi.org/products/e05800-semi-e58-specification-for-automated-reliability-availability-and-maintainability-standard-arams-concepts-behavior-and-services
+//! 
+//! [GEM]:                 semi_e30
+//! [Equipment States]:    state
+//! [Reliability Tracker]: tracker
+
+pub mod state;
+pub mod tracker;
@@ -0,0 +1,108 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # EQUIPMENT STATES
+//! **Based on SEMI E10**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! At any time, equipment is in exactly one of six basic [Equipment State]s.
+//! The time spent in each is the basis of the reliability, availability, and
+//! maintainability measurements defined by [SEMI E10].
+//! 
+//! The equipment may transition directly between any two states.
+//! 
+//! [Equipment State]: EquipmentState
+//! [SEMI E10]:        https://store-us.semi.org/products/e01000-semi-e10-specification-for-definition-and-measurement-of-equipment-reliability-availability-and-maintainability-ram-and-utilization
+
+/// ## EQUIPMENT STATE
+/// 
+/// The value reported to the host for each state is its discriminant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum EquipmentState {
+  /// ### PRODUCTIVE
+  /// 
+  /// The equipment is performing its intended function.
+  Productive = 1,
+
+  /// ### STANDBY
+  /// 
+  /// The equipment is available, but not operating, such as for lack of an
+  /// operator, product, or input from a support tool.
+  Standby = 2,
+
+  /// ### ENGINEERING
+  /// 
+  /// The equipment is available, but performing engineering experiments.
+  Engineering = 3,
+
+  /// ### SCHEDULED DOWNTIME
+  /// 
+  /// The equipment is unavailable due to planned downtime events, such as
+  /// preventive maintenance.
+  ScheduledDowntime = 4,
+
+  /// ### UNSCHEDULED DOWNTIME
+  /// 
+  /// The equipment is unavailable due to unplanned downtime events, such as
+  /// a failure and its repair.
+  UnscheduledDowntime = 5,
+
+  /// ### NON-SCHEDULED
+  /// 
+  /// The equipment is not scheduled to be used, such as during unworked
+  /// shifts or installation.
+  NonScheduled = 6,
+}
+impl EquipmentState {
+  /// ### ALL STATES
+  pub const ALL: [EquipmentState; 6] = [
+    EquipmentState::Productive,
+    EquipmentState::Standby,
+    EquipmentState::Engineering,
+    EquipmentState::ScheduledDowntime,
+    EquipmentState::UnscheduledDowntime,
+    EquipmentState::NonScheduled,
+  ];
+
+  /// ### IS UPTIME
+  /// 
+  /// Whether the equipment is available in this state, being
+  /// [Productive], in [Standby], or in [Engineering].
+  /// 
+  /// [Productive]:  EquipmentState::Productive
+  /// [Standby]:     EquipmentState::Standby
+  /// [Engineering]: EquipmentState::Engineering
+  pub fn is_uptime(&self) -> bool {
+    matches!(self, EquipmentState::Productive | EquipmentState::Standby | EquipmentState::Engineering)
+  }
+
+  /// ### IS DOWNTIME
+  pub fn is_downtime(&self) -> bool {
+    matches!(self, EquipmentState::ScheduledDowntime | EquipmentState::UnscheduledDowntime)
+  }
+}
+impl TryFrom<u8> for EquipmentState {
+  type Error = u8;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    EquipmentState::ALL.into_iter().find(|state| *state as u8 == value).ok_or(value)
+  }
+}
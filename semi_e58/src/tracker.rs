@@ -0,0 +1,207 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # RELIABILITY TRACKER
+//! **Based on SEMI E58**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Reliability Tracker] keeps the current [Equipment State] and the
+//! total time spent in each state, and may be bound to the variables and
+//! collection events of a [Variable Registry]:
+//! 
+//! - A status variable holding the current state, as a U1.
+//! - A status variable for each state, holding the seconds spent in it, as
+//!   a U4.
+//! - A collection event for each state, occurring upon entering it.
+//! 
+//! Each [Transition] updates the bound status variables, and results in the
+//! collection event to be reported to the host, if one is bound. As the
+//! time spent in the current state grows continuously, the [Update]
+//! function should be called before the status variables are sampled.
+//! 
+//! The tracker does not send the [S6F11] itself. As the equipment does not
+//! yet keep the reports defined and linked by the host, it is left to the
+//! caller to build the event report for the collection event and send it
+//! with the [Send Procedure].
+//! 
+//! [Reliability Tracker]: ReliabilityTracker
+//! [Equipment State]:     EquipmentState
+//! [Variable Registry]:   VariableRegistry
+//! [Transition]:          ReliabilityTracker::transition
+//! [Update]:              ReliabilityTracker::update
+//! [S6F11]:               semi_e5::messages::s6::EventReport
+//! [Send Procedure]:      semi_e30::equipment::Equipment::send
+
+use std::{
+  collections::HashMap,
+  time::{Duration, Instant},
+};
+use semi_e5::{
+  Item,
+  items::{CollectionEventID, VariableID},
+};
+use semi_e30::variables::VariableRegistry;
+use crate::state::EquipmentState;
+
+/// ## TRANSITION
+/// 
+/// A change of [Equipment State].
+/// 
+/// [Equipment State]: EquipmentState
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+  pub from: EquipmentState,
+  pub to: EquipmentState,
+
+  /// ### TIME IN STATE
+  /// 
+  /// The time spent in the state left, since it was entered.
+  pub time_in_state: Duration,
+
+  /// ### EVENT
+  /// 
+  /// The collection event bound to the state entered, to be reported to
+  /// the host by the caller.
+  pub event: Option<CollectionEventID>,
+}
+
+/// ## RELIABILITY TRACKER
+#[derive(Clone, Debug)]
+pub struct ReliabilityTracker {
+  state: EquipmentState,
+  entered: Instant,
+  totals: HashMap<EquipmentState, Duration>,
+  state_variable: Option<VariableID>,
+  time_variables: HashMap<EquipmentState, VariableID>,
+  events: HashMap<EquipmentState, CollectionEventID>,
+}
+impl ReliabilityTracker {
+  /// ### NEW RELIABILITY TRACKER
+  /// 
+  /// Creates a [Reliability Tracker] which has just entered the state
+  /// provided, with no variables or collection events bound.
+  /// 
+  /// [Reliability Tracker]: ReliabilityTracker
+  pub fn new(state: EquipmentState) -> Self {
+    ReliabilityTracker {
+      state,
+      entered: Instant::now(),
+      totals: HashMap::new(),
+      state_variable: None,
+      time_variables: HashMap::new(),
+      events: HashMap::new(),
+    }
+  }
+
+  /// ### SET STATE VARIABLE
+  /// 
+  /// Binds the status variable holding the current state.
+  pub fn set_state_variable(&mut self, id: VariableID) {
+    self.state_variable = Some(id);
+  }
+
+  /// ### SET TIME VARIABLE
+  /// 
+  /// Binds the status variable holding the seconds spent in a state.
+  pub fn set_time_variable(&mut self, state: EquipmentState, id: VariableID) {
+    self.time_variables.insert(state, id);
+  }
+
+  /// ### SET EVENT
+  /// 
+  /// Binds the collection event occurring upon entering a state.
+  pub fn set_event(&mut self, state: EquipmentState, id: CollectionEventID) {
+    self.events.insert(state, id);
+  }
+
+  /// ### STATE
+  pub fn state(&self) -> EquipmentState {
+    self.state
+  }
+
+  /// ### TIME IN CURRENT STATE
+  pub fn time_in_current_state(&self) -> Duration {
+    self.entered.elapsed()
+  }
+
+  /// ### TOTAL TIME
+  /// 
+  /// The total time spent in a state, including the time spent in the
+  /// current state so far.
+  pub fn total_time(&self, state: EquipmentState) -> Duration {
+    let total: Duration = self.totals.get(&state).copied().unwrap_or_default();
+    match state == self.state {
+      true => total + self.entered.elapsed(),
+      false => total,
+    }
+  }
+
+  /// ### TRANSITION
+  /// 
+  /// Enters a state, updating the bound status variables.
+  /// 
+  /// No message is sent to the host; the collection event of the resulting
+  /// [Transition], if any, should be reported by the caller.
+  /// 
+  /// Results in [None] if the equipment is already in the state.
+  pub fn transition(&mut self, to: EquipmentState, registry: &mut VariableRegistry) -> Option<Transition> {
+    if to == self.state {
+      return None
+    }
+    let now: Instant = Instant::now();
+    let time_in_state: Duration = now - self.entered;
+    *self.totals.entry(self.state).or_default() += time_in_state;
+    let transition = Transition {
+      from: self.state,
+      to,
+      time_in_state,
+      event: self.events.get(&to).cloned(),
+    };
+    self.state = to;
+    self.entered = now;
+    self.update(registry);
+    Some(transition)
+  }
+
+  /// ### RESET
+  /// 
+  /// Clears the total time spent in each state, such as at the start of a
+  /// new measurement period, and restarts the time spent in the current
+  /// state.
+  pub fn reset(&mut self, registry: &mut VariableRegistry) {
+    self.totals.clear();
+    self.entered = Instant::now();
+    self.update(registry);
+  }
+
+  /// ### UPDATE
+  /// 
+  /// Updates the bound status variables with the current state and the
+  /// total time spent in each state.
+  pub fn update(&self, registry: &mut VariableRegistry) {
+    if let Some(id) = &self.state_variable {
+      registry.set_status_value(id, Item::U1(vec![self.state as u8].into()));
+    }
+    for (state, id) in &self.time_variables {
+      let seconds: u32 = self.total_time(*state).as_secs().try_into().unwrap_or(u32::MAX);
+      registry.set_status_value(id, Item::U4(vec![seconds].into()));
+    }
+  }
+}
@@ -0,0 +1,193 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # CARRIER STATE MODEL
+//! **Based on SEMI E87**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each carrier known to the equipment is tracked by three concurrent state
+//! machines:
+//! 
+//! - [Carrier ID Status] - Whether the ID read from the carrier has been
+//!   verified by the host.
+//! - [Slot Map Status] - Whether the slot map read from the carrier has been
+//!   verified by the host.
+//! - [Carrier Accessing Status] - Whether the equipment has accessed the
+//!   substrates in the carrier.
+//! 
+//! The slot map is only read once the carrier ID has been verified, and the
+//! carrier is only accessed once the slot map has been verified.
+//! 
+//! [Carrier ID Status]:        CarrierIDStatus
+//! [Slot Map Status]:          SlotMapStatus
+//! [Carrier Accessing Status]: CarrierAccessingStatus
+
+/// ## CARRIER ID STATUS
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CarrierIDStatus {
+  /// ### ID NOT READ
+  /// 
+  /// The carrier has been instantiated, but its ID has yet to be read.
+  IDNotRead,
+
+  /// ### WAITING FOR HOST
+  /// 
+  /// The carrier ID has been read, or could not be read, and the equipment
+  /// is waiting for the host to proceed with or cancel the carrier.
+  WaitingForHost,
+
+  /// ### ID VERIFICATION OK
+  IDVerificationOk,
+
+  /// ### ID VERIFICATION FAILED
+  IDVerificationFailed,
+}
+
+/// ## SLOT MAP STATUS
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlotMapStatus {
+  /// ### SLOT MAP NOT READ
+  SlotMapNotRead,
+
+  /// ### WAITING FOR HOST
+  /// 
+  /// The slot map has been read, and the equipment is waiting for the host
+  /// to proceed with or cancel the carrier.
+  WaitingForHost,
+
+  /// ### SLOT MAP VERIFICATION OK
+  SlotMapVerificationOk,
+
+  /// ### SLOT MAP VERIFICATION FAILED
+  SlotMapVerificationFailed,
+}
+
+/// ## CARRIER ACCESSING STATUS
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CarrierAccessingStatus {
+  /// ### NOT ACCESSED
+  NotAccessed,
+
+  /// ### IN ACCESS
+  InAccess,
+
+  /// ### CARRIER COMPLETE
+  /// 
+  /// The equipment has finished accessing the carrier normally.
+  CarrierComplete,
+
+  /// ### CARRIER STOPPED
+  /// 
+  /// The equipment stopped accessing the carrier abnormally.
+  CarrierStopped,
+}
+
+/// ## SLOT STATE
+/// 
+/// The state of a single slot in a carrier's slot map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SlotState {
+  Undefined         = 0,
+  Empty             = 1,
+  NotEmpty          = 2,
+  CorrectlyOccupied = 3,
+  DoubleSlotted     = 4,
+  CrossSlotted      = 5,
+}
+impl TryFrom<u8> for SlotState {
+  type Error = u8;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(SlotState::Undefined),
+      1 => Ok(SlotState::Empty),
+      2 => Ok(SlotState::NotEmpty),
+      3 => Ok(SlotState::CorrectlyOccupied),
+      4 => Ok(SlotState::DoubleSlotted),
+      5 => Ok(SlotState::CrossSlotted),
+      _ => Err(value),
+    }
+  }
+}
+
+/// ## CARRIER
+/// 
+/// A carrier known to the equipment, along with the state of each of its
+/// state machines.
+#[derive(Clone, Debug)]
+pub struct Carrier {
+  pub(crate) id: String,
+  pub(crate) port: Option<u8>,
+  pub(crate) id_status: CarrierIDStatus,
+  pub(crate) slot_map_status: SlotMapStatus,
+  pub(crate) accessing_status: CarrierAccessingStatus,
+  pub(crate) slot_map: Option<Vec<SlotState>>,
+  pub(crate) expected_slot_map: Option<Vec<SlotState>>,
+  pub(crate) id_read_failed: bool,
+}
+impl Carrier {
+  pub(crate) fn new(id: String, port: Option<u8>) -> Self {
+    Carrier {
+      id,
+      port,
+      id_status: CarrierIDStatus::IDNotRead,
+      slot_map_status: SlotMapStatus::SlotMapNotRead,
+      accessing_status: CarrierAccessingStatus::NotAccessed,
+      slot_map: None,
+      expected_slot_map: None,
+      id_read_failed: false,
+    }
+  }
+
+  /// ### ID
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// ### PORT
+  /// 
+  /// The load port the carrier is located at, if any.
+  pub fn port(&self) -> Option<u8> {
+    self.port
+  }
+
+  /// ### CARRIER ID STATUS
+  pub fn id_status(&self) -> CarrierIDStatus {
+    self.id_status
+  }
+
+  /// ### SLOT MAP STATUS
+  pub fn slot_map_status(&self) -> SlotMapStatus {
+    self.slot_map_status
+  }
+
+  /// ### CARRIER ACCESSING STATUS
+  pub fn accessing_status(&self) -> CarrierAccessingStatus {
+    self.accessing_status
+  }
+
+  /// ### SLOT MAP
+  /// 
+  /// The slot map read from the carrier, if it has been read.
+  pub fn slot_map(&self) -> Option<&[SlotState]> {
+    self.slot_map.as_deref()
+  }
+}
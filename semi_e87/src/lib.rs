@@ -0,0 +1,78 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # CARRIER MANAGEMENT (CMS)
+//! 
+//! Copyright © 2024 Nathaniel Hardesty, Licensed under the MIT License
+//! 
+//! This software is created by a third-party and not endorsed or supported by
+//! SEMI.
+//! 
+//! The codebase will be updated to reflect more up-to-date SEMI standards
+//! if/when they can be acquired for this purpose.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! **Based on:**
+//! - **[SEMI E87]**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! CMS defines how equipment with load ports manages the carriers placed on
+//! them, verifying each carrier's ID and slot map with the host before its
+//! contents are accessed, using the messages defined by [SECS-II]
+//! ([SEMI E5]).
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! For ease of programming and extension, the functionality of the standard
+//! has been divided into a few subsets:
+//! 
+//! - [Carrier State Model] - Tracks the ID verification, slot map
+//!   verification, and accessing status of each carrier.
+//! - [Load Port State Model] - Tracks whether each load port is ready to load
+//!   or unload a carrier, and whether transfers to it are manual or
+//!   automated.
+//! - [Carrier Management] - Drives both state models from the equipment's
+//!   observations and the host's [S3F17] carrier actions and [S3F27] access
+//!   mode changes, reporting each transition as an event.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TODO
+//! 
+//! - Load port reservation and carrier association state models
+//! - Carrier tag read and write services, [S3F29] through [S3F32]
+//! - Equipment with internal buffers
+//! 
+//! [SEMI E5]:  https://store-us.semi.org/products/e00500-semi-e5-specification-for-semi-equipment-communications-standard-2-message-content-secs-ii
+//! [SEMI E87]: https://store-us.semi.org/products/e08700-semi-e87-specification-for-carrier-management-cms
+//! 
+//! [SECS-II]:               semi_e5
+//! [S3F17]:                 semi_e5::messages::s3::CarrierActionRequest
+//! [S3F27]:                 semi_e5::messages::s3::ChangeAccess
+//! [S3F29]:                 semi_e5::messages::s3::CarrierTagReadRequest
+//! [S3F32]:                 semi_e5::messages::s3::CarrierTagWriteDataAcknowledge
+//! [Carrier State Model]:   carrier
+//! [Load Port State Model]: load_port
+//! [Carrier Management]:    management
+
+pub mod carrier;
+pub mod load_port;
+pub mod management;
@@ -0,0 +1,102 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # LOAD PORT STATE MODEL
+//! **Based on SEMI E87**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each load port is tracked by its [Load Port Transfer State], which
+//! determines whether a carrier may be placed on or removed from it, and
+//! its [Access Mode], which determines whether those transfers are
+//! performed manually by the operator or by automated material handling.
+//! 
+//! [Load Port Transfer State]: TransferState
+//! [Access Mode]:              AccessMode
+
+use semi_e5::items::AccessMode;
+
+/// ## LOAD PORT TRANSFER STATE
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransferState {
+  /// ### OUT OF SERVICE
+  /// 
+  /// The load port may not be used for transfers.
+  OutOfService,
+
+  /// ### TRANSFER BLOCKED
+  /// 
+  /// The load port is in service, but a carrier may neither be placed on
+  /// nor removed from it, such as while a carrier on it is in use.
+  TransferBlocked,
+
+  /// ### READY TO LOAD
+  /// 
+  /// The load port is empty, and a carrier may be placed on it.
+  ReadyToLoad,
+
+  /// ### READY TO UNLOAD
+  /// 
+  /// The carrier on the load port may be removed.
+  ReadyToUnload,
+}
+
+/// ## LOAD PORT
+/// 
+/// A load port of the equipment, along with the state of each of its state
+/// machines.
+#[derive(Clone, Debug)]
+pub struct LoadPort {
+  pub(crate) number: u8,
+  pub(crate) transfer_state: TransferState,
+  pub(crate) access_mode: AccessMode,
+  pub(crate) carrier: Option<String>,
+}
+impl LoadPort {
+  pub(crate) fn new(number: u8, access_mode: AccessMode) -> Self {
+    LoadPort {
+      number,
+      transfer_state: TransferState::ReadyToLoad,
+      access_mode,
+      carrier: None,
+    }
+  }
+
+  /// ### NUMBER
+  pub fn number(&self) -> u8 {
+    self.number
+  }
+
+  /// ### LOAD PORT TRANSFER STATE
+  pub fn transfer_state(&self) -> TransferState {
+    self.transfer_state
+  }
+
+  /// ### ACCESS MODE
+  pub fn access_mode(&self) -> AccessMode {
+    self.access_mode
+  }
+
+  /// ### CARRIER
+  /// 
+  /// The ID of the carrier located at the load port, if any.
+  pub fn carrier(&self) -> Option<&str> {
+    self.carrier.as_deref()
+  }
+}
@@ -0,0 +1,747 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+//! # CARRIER MANAGEMENT
+//! **Based on SEMI E87**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Carrier Management] drives the [Carrier State Model] and the
+//! [Load Port State Model] of the equipment.
+//! 
+//! The equipment reports what it observes, such as a carrier being placed
+//! on a load port or its ID being read, with the corresponding functions,
+//! while the host's [S3F17] carrier actions and [S3F27] access mode changes
+//! are answered with the [Service] function, or may be performed directly
+//! with the functions of the same name.
+//! 
+//! Every transition of either state model results in a [Carrier Event],
+//! which is kept until taken by the application, so that it may be reported
+//! to the host as a collection event.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## CARRIER ACTIONS
+//! 
+//! The following carrier actions are supported by [S3F17]:
+//! 
+//! - **ProceedWithCarrier** - Verifies the carrier ID or slot map,
+//!   whichever the equipment is waiting for. The expected slot map may be
+//!   provided with the **SlotMap** attribute.
+//! - **CancelCarrier** - Fails verification of the carrier, making its load
+//!   port ready to unload.
+//! - **CancelCarrierAtPort** - Cancels the carrier at a load port, or the
+//!   placement of a carrier whose ID has yet to be read.
+//! - **CarrierOut** - Makes the load port of a carrier which is not being
+//!   accessed ready to unload.
+//! - **CarrierRelease** - Identical to **CarrierOut**, as equipment with
+//!   internal buffers is not supported.
+//! 
+//! [Carrier Management]:    CarrierManagement
+//! [Carrier State Model]:   crate::carrier
+//! [Load Port State Model]: crate::load_port
+//! [Carrier Event]:         CarrierEvent
+//! [Service]:               CarrierManagement::service
+//! [S3F17]:                 s3::CarrierActionRequest
+//! [S3F27]:                 s3::ChangeAccess
+
+use std::collections::BTreeMap;
+use semi_e5::{
+  Item,
+  Message,
+  items::{
+    AccessMode,
+    CarrierActionAcknowledge,
+    CarrierAttributeData,
+    CarrierAttributeID,
+    Char,
+    CodedValue,
+    ErrorCode,
+    ErrorText,
+    KnownErrorCode,
+    PortNumber,
+    VecList,
+  },
+  messages::{Abort, s3},
+};
+use crate::{
+  carrier::{
+    Carrier,
+    CarrierAccessingStatus,
+    CarrierIDStatus,
+    SlotMapStatus,
+    SlotState,
+  },
+  load_port::{LoadPort, TransferState},
+};
+
+/// ## CARRIER EVENT
+/// 
+/// A transition of the [Carrier State Model] or [Load Port State Model].
+/// 
+/// [Carrier State Model]:   crate::carrier
+/// [Load Port State Model]: crate::load_port
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarrierEvent {
+  /// ### LOAD PORT TRANSFER STATE
+  TransferState {port: u8, state: TransferState},
+
+  /// ### ACCESS MODE
+  AccessMode {port: u8, mode: AccessMode},
+
+  /// ### CARRIER ID STATUS
+  CarrierIDStatus {carrier: String, status: CarrierIDStatus},
+
+  /// ### SLOT MAP STATUS
+  SlotMapStatus {carrier: String, status: SlotMapStatus},
+
+  /// ### CARRIER ACCESSING STATUS
+  CarrierAccessingStatus {carrier: String, status: CarrierAccessingStatus},
+
+  /// ### CARRIER REMOVED
+  /// 
+  /// The carrier has been removed from its load port, and is no longer
+  /// known to the equipment.
+  CarrierRemoved {carrier: String, port: u8},
+}
+
+/// ## CARRIER ERROR
+/// 
+/// The reason an operation could not be performed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarrierError {
+  /// ### UNKNOWN PORT
+  UnknownPort(u8),
+
+  /// ### PORT IN USE
+  /// 
+  /// The load port is not ready for a carrier to be placed on it.
+  PortInUse(u8),
+
+  /// ### MISSING CARRIER
+  /// 
+  /// There is no carrier at the load port.
+  MissingCarrier(u8),
+
+  /// ### WRONG PORT
+  /// 
+  /// The carrier is not located at the load port specified.
+  WrongPort(u8),
+
+  /// ### UNKNOWN CARRIER
+  UnknownCarrier(String),
+
+  /// ### DUPLICATE CARRIER
+  /// 
+  /// A carrier with the same ID is already known to the equipment.
+  DuplicateCarrier(String),
+
+  /// ### INVALID STATE
+  /// 
+  /// The operation is not valid in the current state of the carrier or
+  /// load port.
+  InvalidState,
+
+  /// ### UNKNOWN ACTION
+  UnknownAction(String),
+
+  /// ### UNKNOWN ATTRIBUTE
+  UnknownAttribute(String),
+
+  /// ### INVALID ATTRIBUTE
+  InvalidAttribute(String),
+
+  /// ### SLOT MAP MISMATCH
+  /// 
+  /// The slot map read from the carrier differs from the one provided by
+  /// the host, failing its verification.
+  SlotMapMismatch,
+}
+impl CarrierError {
+  /// ### ERROR CODE
+  pub fn code(&self) -> KnownErrorCode {
+    match self {
+      CarrierError::UnknownPort(_)      => KnownErrorCode::LoadPortDoesNotExist,
+      CarrierError::PortInUse(_)        => KnownErrorCode::LoadPortAlreadyInUse,
+      CarrierError::MissingCarrier(_)   => KnownErrorCode::MissingCarrier,
+      CarrierError::WrongPort(_)        => KnownErrorCode::ParametersImproperlySpecified,
+      CarrierError::UnknownCarrier(_)   => KnownErrorCode::UnknownObjectInstance,
+      CarrierError::DuplicateCarrier(_) => KnownErrorCode::ObjectIdentifierInUse,
+      CarrierError::InvalidState        => KnownErrorCode::CommandNotValidForCurrentState,
+      CarrierError::UnknownAction(_)    => KnownErrorCode::InvalidCommand,
+      CarrierError::UnknownAttribute(_) => KnownErrorCode::UnknownAttributeName,
+      CarrierError::InvalidAttribute(_) => KnownErrorCode::InvalidAttributeValue,
+      CarrierError::SlotMapMismatch     => KnownErrorCode::VerificationError,
+    }
+  }
+
+  /// ### CARRIER ACTION ACKNOWLEDGE
+  /// 
+  /// The acknowledge code reported to the host along with the error.
+  pub fn acknowledge(&self) -> CarrierActionAcknowledge {
+    match self {
+      CarrierError::UnknownAction(_) => CarrierActionAcknowledge::InvalidCommand,
+      CarrierError::PortInUse(_)
+      | CarrierError::InvalidState => CarrierActionAcknowledge::InvalidState,
+      CarrierError::SlotMapMismatch => CarrierActionAcknowledge::PerformedErrors,
+      _ => CarrierActionAcknowledge::InvalidData,
+    }
+  }
+}
+impl std::fmt::Display for CarrierError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      CarrierError::UnknownPort(port)      => write!(f, "load port {port} does not exist"),
+      CarrierError::PortInUse(port)        => write!(f, "load port {port} is in use"),
+      CarrierError::MissingCarrier(port)   => write!(f, "no carrier at load port {port}"),
+      CarrierError::WrongPort(port)        => write!(f, "carrier is not at load port {port}"),
+      CarrierError::UnknownCarrier(id)     => write!(f, "unknown carrier {id}"),
+      CarrierError::DuplicateCarrier(id)   => write!(f, "carrier {id} already exists"),
+      CarrierError::InvalidState           => write!(f, "not valid in current state"),
+      CarrierError::UnknownAction(action)  => write!(f, "unknown carrier action {action}"),
+      CarrierError::UnknownAttribute(name) => write!(f, "unknown attribute {name}"),
+      CarrierError::InvalidAttribute(name) => write!(f, "invalid value for attribute {name}"),
+      CarrierError::SlotMapMismatch        => write!(f, "slot map verification failed"),
+    }
+  }
+}
+impl std::error::Error for CarrierError {}
+
+/// ## CARRIER MANAGEMENT
+/// 
+/// The load ports of the equipment and the carriers located at them.
+#[derive(Clone, Debug, Default)]
+pub struct CarrierManagement {
+  ports: BTreeMap<u8, LoadPort>,
+  carriers: BTreeMap<String, Carrier>,
+  events: Vec<CarrierEvent>,
+}
+impl CarrierManagement {
+  /// ### NEW CARRIER MANAGEMENT
+  /// 
+  /// Creates [Carrier Management] with no load ports or carriers.
+  /// 
+  /// [Carrier Management]: CarrierManagement
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### ADD PORT
+  /// 
+  /// Adds a load port, which is initially [Ready To Load], replacing any
+  /// with the same number.
+  /// 
+  /// [Ready To Load]: TransferState::ReadyToLoad
+  pub fn add_port(&mut self, number: u8, access_mode: AccessMode) {
+    self.ports.insert(number, LoadPort::new(number, access_mode));
+  }
+
+  /// ### PORT
+  pub fn port(&self, number: u8) -> Option<&LoadPort> {
+    self.ports.get(&number)
+  }
+
+  /// ### PORTS
+  pub fn ports(&self) -> impl Iterator<Item = &LoadPort> {
+    self.ports.values()
+  }
+
+  /// ### CARRIER
+  pub fn carrier(&self, id: &str) -> Option<&Carrier> {
+    self.carriers.get(id)
+  }
+
+  /// ### CARRIERS
+  pub fn carriers(&self) -> impl Iterator<Item = &Carrier> {
+    self.carriers.values()
+  }
+
+  /// ### TAKE EVENTS
+  /// 
+  /// Results in the [Carrier Event]s which have occurred since this
+  /// function was last called, in the order they occurred.
+  /// 
+  /// [Carrier Event]: CarrierEvent
+  pub fn take_events(&mut self) -> Vec<CarrierEvent> {
+    std::mem::take(&mut self.events)
+  }
+
+  // EQUIPMENT OBSERVATIONS
+
+  /// ### SET IN SERVICE
+  /// 
+  /// Returns an [Out Of Service] load port to service, where it is
+  /// [Ready To Load] if empty, and [Ready To Unload] otherwise.
+  /// 
+  /// [Out Of Service]:  TransferState::OutOfService
+  /// [Ready To Load]:   TransferState::ReadyToLoad
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn set_in_service(&mut self, port: u8) -> Result<(), CarrierError> {
+    let load_port = self.ports.get(&port).ok_or(CarrierError::UnknownPort(port))?;
+    if load_port.transfer_state != TransferState::OutOfService {
+      return Err(CarrierError::InvalidState)
+    }
+    let state = match load_port.carrier {
+      Some(_) => TransferState::ReadyToUnload,
+      None => TransferState::ReadyToLoad,
+    };
+    self.transfer(port, state);
+    Ok(())
+  }
+
+  /// ### SET OUT OF SERVICE
+  pub fn set_out_of_service(&mut self, port: u8) -> Result<(), CarrierError> {
+    if !self.ports.contains_key(&port) {
+      return Err(CarrierError::UnknownPort(port))
+    }
+    self.transfer(port, TransferState::OutOfService);
+    Ok(())
+  }
+
+  /// ### CARRIER PLACED
+  /// 
+  /// Notes that a carrier has been placed on a load port which was
+  /// [Ready To Load], blocking further transfers to it.
+  /// 
+  /// [Ready To Load]: TransferState::ReadyToLoad
+  pub fn carrier_placed(&mut self, port: u8) -> Result<(), CarrierError> {
+    let load_port = self.ports.get(&port).ok_or(CarrierError::UnknownPort(port))?;
+    match load_port.transfer_state {
+      TransferState::ReadyToLoad => {},
+      TransferState::OutOfService => return Err(CarrierError::InvalidState),
+      _ => return Err(CarrierError::PortInUse(port)),
+    }
+    self.transfer(port, TransferState::TransferBlocked);
+    Ok(())
+  }
+
+  /// ### CARRIER ID READ
+  /// 
+  /// Instantiates the carrier whose ID was read at a load port, which then
+  /// waits for the host to proceed with or cancel it.
+  pub fn carrier_id_read(&mut self, port: u8, id: &str) -> Result<(), CarrierError> {
+    self.instantiate(port, id, false)
+  }
+
+  /// ### CARRIER ID READ FAILED
+  /// 
+  /// Instantiates a carrier whose ID could not be read at a load port under
+  /// an ID assigned by the equipment, which then waits for the host to
+  /// proceed with it under its actual ID, or to cancel it.
+  pub fn carrier_id_read_failed(&mut self, port: u8, id: &str) -> Result<(), CarrierError> {
+    self.instantiate(port, id, true)
+  }
+
+  /// ### SLOT MAP READ
+  /// 
+  /// Notes the slot map read from a carrier whose ID has been verified.
+  /// 
+  /// If the host provided the expected slot map when proceeding with the
+  /// carrier, the slot map is verified against it immediately, and a
+  /// [Slot Map Mismatch] results if they differ. Otherwise, the carrier
+  /// waits for the host to proceed with or cancel it.
+  /// 
+  /// [Slot Map Mismatch]: CarrierError::SlotMapMismatch
+  pub fn slot_map_read(&mut self, id: &str, slot_map: Vec<SlotState>) -> Result<(), CarrierError> {
+    let carrier = self.carriers.get_mut(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if carrier.id_status != CarrierIDStatus::IDVerificationOk
+    || carrier.slot_map_status != SlotMapStatus::SlotMapNotRead {
+      return Err(CarrierError::InvalidState)
+    }
+    let (status, result) = match carrier.expected_slot_map.take() {
+      None => (SlotMapStatus::WaitingForHost, Ok(())),
+      Some(expected) if expected == slot_map => (SlotMapStatus::SlotMapVerificationOk, Ok(())),
+      Some(_) => (SlotMapStatus::SlotMapVerificationFailed, Err(CarrierError::SlotMapMismatch)),
+    };
+    carrier.slot_map = Some(slot_map);
+    carrier.slot_map_status = status;
+    self.events.push(CarrierEvent::SlotMapStatus {carrier: id.to_string(), status});
+    result
+  }
+
+  /// ### ACCESS STARTED
+  /// 
+  /// Notes that the equipment has begun accessing a carrier whose slot map
+  /// has been verified.
+  pub fn access_started(&mut self, id: &str) -> Result<(), CarrierError> {
+    let carrier = self.carriers.get(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if carrier.slot_map_status != SlotMapStatus::SlotMapVerificationOk
+    || carrier.accessing_status != CarrierAccessingStatus::NotAccessed {
+      return Err(CarrierError::InvalidState)
+    }
+    self.access(id, CarrierAccessingStatus::InAccess);
+    Ok(())
+  }
+
+  /// ### ACCESS COMPLETE
+  /// 
+  /// Notes that the equipment has finished accessing a carrier normally,
+  /// making its load port [Ready To Unload].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn access_complete(&mut self, id: &str) -> Result<(), CarrierError> {
+    self.end_access(id, CarrierAccessingStatus::CarrierComplete)
+  }
+
+  /// ### ACCESS STOPPED
+  /// 
+  /// Notes that the equipment has stopped accessing a carrier abnormally,
+  /// making its load port [Ready To Unload].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn access_stopped(&mut self, id: &str) -> Result<(), CarrierError> {
+    self.end_access(id, CarrierAccessingStatus::CarrierStopped)
+  }
+
+  /// ### CARRIER REMOVED
+  /// 
+  /// Notes that the carrier, if any, has been removed from a load port
+  /// which was [Ready To Unload], making it [Ready To Load].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  /// [Ready To Load]:   TransferState::ReadyToLoad
+  pub fn carrier_removed(&mut self, port: u8) -> Result<(), CarrierError> {
+    let load_port = self.ports.get_mut(&port).ok_or(CarrierError::UnknownPort(port))?;
+    if load_port.transfer_state != TransferState::ReadyToUnload {
+      return Err(CarrierError::InvalidState)
+    }
+    if let Some(id) = load_port.carrier.take() {
+      self.carriers.remove(&id);
+      self.events.push(CarrierEvent::CarrierRemoved {carrier: id, port});
+    }
+    self.transfer(port, TransferState::ReadyToLoad);
+    Ok(())
+  }
+
+  // HOST COMMANDS
+
+  /// ### PROCEED WITH CARRIER
+  /// 
+  /// Verifies the ID of a carrier waiting for the host, or otherwise its
+  /// slot map.
+  /// 
+  /// If the carrier ID could not be read, the carrier at the port specified
+  /// is proceeded with under the ID provided by the host.
+  /// 
+  /// A slot map provided along with the carrier ID is kept to verify the
+  /// slot map once read. A slot map provided along with the slot map is
+  /// compared to the one read, and a [Slot Map Mismatch] results if they
+  /// differ.
+  /// 
+  /// [Slot Map Mismatch]: CarrierError::SlotMapMismatch
+  pub fn proceed_with_carrier(
+    &mut self,
+    id: &str,
+    port: Option<u8>,
+    slot_map: Option<Vec<SlotState>>,
+  ) -> Result<(), CarrierError> {
+    if !self.carriers.contains_key(id) {
+      self.rebind(id, port)?;
+    }
+    let carrier = self.carriers.get_mut(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if let Some(port) = port {
+      if carrier.port != Some(port) {
+        return Err(CarrierError::WrongPort(port))
+      }
+    }
+    if carrier.id_status == CarrierIDStatus::WaitingForHost {
+      carrier.id_status = CarrierIDStatus::IDVerificationOk;
+      carrier.expected_slot_map = slot_map;
+      self.events.push(CarrierEvent::CarrierIDStatus {
+        carrier: id.to_string(),
+        status: CarrierIDStatus::IDVerificationOk,
+      });
+      Ok(())
+    } else if carrier.slot_map_status == SlotMapStatus::WaitingForHost {
+      let (status, result) = match slot_map {
+        Some(expected) if carrier.slot_map.as_ref() != Some(&expected) => {
+          (SlotMapStatus::SlotMapVerificationFailed, Err(CarrierError::SlotMapMismatch))
+        },
+        _ => (SlotMapStatus::SlotMapVerificationOk, Ok(())),
+      };
+      carrier.slot_map_status = status;
+      self.events.push(CarrierEvent::SlotMapStatus {carrier: id.to_string(), status});
+      result
+    } else {
+      Err(CarrierError::InvalidState)
+    }
+  }
+
+  /// ### CANCEL CARRIER
+  /// 
+  /// Fails verification of a carrier which has not been accessed, making
+  /// its load port [Ready To Unload].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn cancel_carrier(&mut self, id: &str) -> Result<(), CarrierError> {
+    let carrier = self.carriers.get_mut(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if carrier.accessing_status != CarrierAccessingStatus::NotAccessed {
+      return Err(CarrierError::InvalidState)
+    }
+    if carrier.id_status == CarrierIDStatus::WaitingForHost {
+      carrier.id_status = CarrierIDStatus::IDVerificationFailed;
+      self.events.push(CarrierEvent::CarrierIDStatus {
+        carrier: id.to_string(),
+        status: CarrierIDStatus::IDVerificationFailed,
+      });
+    } else if carrier.slot_map_status == SlotMapStatus::WaitingForHost {
+      carrier.slot_map_status = SlotMapStatus::SlotMapVerificationFailed;
+      self.events.push(CarrierEvent::SlotMapStatus {
+        carrier: id.to_string(),
+        status: SlotMapStatus::SlotMapVerificationFailed,
+      });
+    }
+    if let Some(port) = carrier.port {
+      self.transfer(port, TransferState::ReadyToUnload);
+    }
+    Ok(())
+  }
+
+  /// ### CANCEL CARRIER AT PORT
+  /// 
+  /// Cancels the carrier at a load port, or if its ID has yet to be read,
+  /// the placement of the carrier, making the load port
+  /// [Ready To Unload].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn cancel_carrier_at_port(&mut self, port: u8) -> Result<(), CarrierError> {
+    let load_port = self.ports.get(&port).ok_or(CarrierError::UnknownPort(port))?;
+    match (&load_port.carrier, load_port.transfer_state) {
+      (Some(id), _) => self.cancel_carrier(&id.clone()),
+      (None, TransferState::TransferBlocked) => {
+        self.transfer(port, TransferState::ReadyToUnload);
+        Ok(())
+      },
+      (None, _) => Err(CarrierError::MissingCarrier(port)),
+    }
+  }
+
+  /// ### CARRIER OUT
+  /// 
+  /// Makes the load port of a carrier which is not being accessed
+  /// [Ready To Unload].
+  /// 
+  /// [Ready To Unload]: TransferState::ReadyToUnload
+  pub fn carrier_out(&mut self, id: &str) -> Result<(), CarrierError> {
+    let carrier = self.carriers.get(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if carrier.accessing_status == CarrierAccessingStatus::InAccess {
+      return Err(CarrierError::InvalidState)
+    }
+    if let Some(port) = carrier.port {
+      self.transfer(port, TransferState::ReadyToUnload);
+    }
+    Ok(())
+  }
+
+  /// ### CARRIER RELEASE
+  /// 
+  /// Identical to [Carrier Out], as equipment with internal buffers is not
+  /// supported.
+  /// 
+  /// [Carrier Out]: CarrierManagement::carrier_out
+  pub fn carrier_release(&mut self, id: &str) -> Result<(), CarrierError> {
+    self.carrier_out(id)
+  }
+
+  /// ### CHANGE ACCESS
+  /// 
+  /// Changes the access mode of the load ports specified, or of all load
+  /// ports if none are specified.
+  /// 
+  /// The access mode of a load port may not be changed while transfers to
+  /// it are blocked. Results in the load ports whose access mode could not
+  /// be changed, along with the reason.
+  pub fn change_access(&mut self, mode: AccessMode, ports: &[u8]) -> Vec<(u8, CarrierError)> {
+    let ports: Vec<u8> = match ports.is_empty() {
+      true => self.ports.keys().copied().collect(),
+      false => ports.to_vec(),
+    };
+    let mut errors: Vec<(u8, CarrierError)> = vec![];
+    for port in ports {
+      let Some(load_port) = self.ports.get_mut(&port) else {
+        errors.push((port, CarrierError::UnknownPort(port)));
+        continue
+      };
+      if load_port.transfer_state == TransferState::TransferBlocked {
+        errors.push((port, CarrierError::InvalidState));
+        continue
+      }
+      if load_port.access_mode != mode {
+        load_port.access_mode = mode;
+        self.events.push(CarrierEvent::AccessMode {port, mode});
+      }
+    }
+    errors
+  }
+
+  /// ### SERVICE
+  /// 
+  /// Provides the reply to an [S3F17] or [S3F27], or an Abort Transaction
+  /// if it cannot be understood.
+  /// 
+  /// Results in [None] if the message is not one carrier management
+  /// answers.
+  /// 
+  /// [S3F17]: s3::CarrierActionRequest
+  /// [S3F27]: s3::ChangeAccess
+  pub fn service(&mut self, message: &Message) -> Option<Message> {
+    let reply: Option<Message> = match (message.stream, message.function) {
+      (3, 17) => s3::CarrierActionRequest::try_from(message.clone()).ok().map(|request| {
+        let (_, action, carrier, port, VecList(attributes)) = request.0;
+        let result = self.carrier_action(
+          &Char::chars_to_str(&action.0),
+          &Char::chars_to_str(&carrier.0),
+          port.number(),
+          attributes,
+        );
+        let (ack, errors) = match result {
+          Ok(()) => (CarrierActionAcknowledge::Ok, vec![]),
+          Err(error) => (error.acknowledge(), vec![(ErrorCode::Known(error.code()), error_text(&error))]),
+        };
+        s3::CarrierActionRequestAcknowledge((CodedValue::Known(ack), VecList(errors))).into()
+      }),
+      (3, 27) => s3::ChangeAccess::try_from(message.clone()).ok().map(|request| {
+        let (mode, VecList(ports)) = request.0;
+        let ports: Vec<u8> = ports.iter().map(PortNumber::number).collect();
+        let errors = self.change_access(mode, &ports);
+        let ack = match errors.is_empty() {
+          true => CarrierActionAcknowledge::Ok,
+          false => CarrierActionAcknowledge::PerformedErrors,
+        };
+        let errors = errors.iter().map(|(port, error)| {
+          (PortNumber::U1(*port), ErrorCode::Known(error.code()), error_text(error))
+        }).collect();
+        s3::ChangeAccessAcknowledge((CodedValue::Known(ack), VecList(errors))).into()
+      }),
+      _ => return None,
+    };
+    Some(reply.unwrap_or_else(|| Abort::for_stream(3).into()))
+  }
+
+  fn carrier_action(
+    &mut self,
+    action: &str,
+    id: &str,
+    port: u8,
+    attributes: Vec<(CarrierAttributeID, CarrierAttributeData)>,
+  ) -> Result<(), CarrierError> {
+    let mut slot_map: Option<Vec<SlotState>> = None;
+    for (name, data) in attributes {
+      let name = Char::chars_to_str(&name.0);
+      match name.as_str() {
+        "SlotMap" => slot_map = Some(parse_slot_map(data).ok_or(CarrierError::InvalidAttribute(name))?),
+        "Capacity" | "ContentMap" | "SubstrateCount" | "Usage" => {},
+        _ => return Err(CarrierError::UnknownAttribute(name)),
+      }
+    }
+    match action {
+      "ProceedWithCarrier"  => self.proceed_with_carrier(id, Some(port), slot_map),
+      "CancelCarrier"       => self.cancel_carrier(id),
+      "CancelCarrierAtPort" => self.cancel_carrier_at_port(port),
+      "CarrierOut"          => self.carrier_out(id),
+      "CarrierRelease"      => self.carrier_release(id),
+      _ => Err(CarrierError::UnknownAction(action.to_string())),
+    }
+  }
+
+  fn instantiate(&mut self, port: u8, id: &str, id_read_failed: bool) -> Result<(), CarrierError> {
+    let load_port = self.ports.get_mut(&port).ok_or(CarrierError::UnknownPort(port))?;
+    if load_port.transfer_state != TransferState::TransferBlocked || load_port.carrier.is_some() {
+      return Err(CarrierError::InvalidState)
+    }
+    if self.carriers.contains_key(id) {
+      return Err(CarrierError::DuplicateCarrier(id.to_string()))
+    }
+    let mut carrier = Carrier::new(id.to_string(), Some(port));
+    carrier.id_status = CarrierIDStatus::WaitingForHost;
+    carrier.id_read_failed = id_read_failed;
+    load_port.carrier = Some(id.to_string());
+    self.carriers.insert(id.to_string(), carrier);
+    self.events.push(CarrierEvent::CarrierIDStatus {
+      carrier: id.to_string(),
+      status: CarrierIDStatus::WaitingForHost,
+    });
+    Ok(())
+  }
+
+  fn rebind(&mut self, id: &str, port: Option<u8>) -> Result<(), CarrierError> {
+    let unknown = || CarrierError::UnknownCarrier(id.to_string());
+    let load_port = self.ports.get_mut(&port.ok_or_else(unknown)?).ok_or_else(unknown)?;
+    let assigned = load_port.carrier.clone().ok_or_else(unknown)?;
+    match self.carriers.get(&assigned) {
+      Some(carrier) if carrier.id_read_failed => {},
+      _ => return Err(unknown()),
+    }
+    let mut carrier = self.carriers.remove(&assigned).ok_or_else(unknown)?;
+    carrier.id = id.to_string();
+    carrier.id_read_failed = false;
+    load_port.carrier = Some(id.to_string());
+    self.carriers.insert(id.to_string(), carrier);
+    Ok(())
+  }
+
+  fn end_access(&mut self, id: &str, status: CarrierAccessingStatus) -> Result<(), CarrierError> {
+    let carrier = self.carriers.get(id).ok_or_else(|| CarrierError::UnknownCarrier(id.to_string()))?;
+    if carrier.accessing_status != CarrierAccessingStatus::InAccess {
+      return Err(CarrierError::InvalidState)
+    }
+    let port = carrier.port;
+    self.access(id, status);
+    if let Some(port) = port {
+      self.transfer(port, TransferState::ReadyToUnload);
+    }
+    Ok(())
+  }
+
+  fn access(&mut self, id: &str, status: CarrierAccessingStatus) {
+    if let Some(carrier) = self.carriers.get_mut(id) {
+      carrier.accessing_status = status;
+      self.events.push(CarrierEvent::CarrierAccessingStatus {carrier: id.to_string(), status});
+    }
+  }
+
+  fn transfer(&mut self, port: u8, state: TransferState) {
+    if let Some(load_port) = self.ports.get_mut(&port) {
+      if load_port.transfer_state != state {
+        load_port.transfer_state = state;
+        self.events.push(CarrierEvent::TransferState {port, state});
+      }
+    }
+  }
+}
+
+/// Interprets the SlotMap attribute, either as a list of single U1 items or
+/// as a single U1 array.
+fn parse_slot_map(data: CarrierAttributeData) -> Option<Vec<SlotState>> {
+  match data {
+    CarrierAttributeData::List(items) => items.into_iter().map(|item| match item {
+      Item::U1(values) if values.len() == 1 => SlotState::try_from(values[0]).ok(),
+      _ => None,
+    }).collect(),
+    CarrierAttributeData::U1(values) => values.into_iter().map(|value| SlotState::try_from(value).ok()).collect(),
+    _ => None,
+  }
+}
+
+/// Describes an error within the length allowed for ERRTEXT.
+fn error_text(error: &CarrierError) -> ErrorText {
+  let mut chars: Vec<Char> = Char::safe_str_to_chars(&error.to_string());
+  chars.truncate(120);
+  ErrorText::new(chars).expect("ERRTEXT within length")
+}